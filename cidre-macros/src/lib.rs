@@ -4,6 +4,8 @@ use std::{borrow::Cow, str::FromStr};
 
 use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 
+mod msl;
+
 enum Attr {
     Optional,
     MsgSend(String),
@@ -1590,6 +1592,71 @@ fn try_replace_return(tokens: &mut Vec<TokenTree>) -> bool {
     true
 }
 
+/// Checks a `#[repr(C)]` struct against Metal Shading Language layout rules and adds
+/// `MSL` (the MSL `struct` declaration), `MSL_SIZE` and `MSL_ALIGN` consts.
+///
+/// Field types are matched by name: scalars, `half::f16`, `simd` vectors and matrices,
+/// `simd::packed` vectors and arrays of them. Compilation fails if a field size,
+/// field offset, the struct size or alignment differs from MSL.
+///
+/// Re-exported as `cidre::simd::MslLayout`. `cidre::simd` vectors are aligned to their
+/// scalar, so structs with them need `#[repr(C, align(16))]` or alike.
+///
+/// ```
+/// use cidre_macros::MslLayout;
+///
+/// # #[allow(non_camel_case_types)]
+/// # #[repr(C, align(16))]
+/// # struct f32x4([f32; 4]);
+/// #[derive(MslLayout)]
+/// #[repr(C)]
+/// struct Uniforms {
+///     color: f32x4,
+///     scale: f32,
+/// }
+///
+/// assert_eq!(
+///     Uniforms::MSL,
+///     "struct Uniforms {\n    float4 color;\n    float scale;\n};\n"
+/// );
+/// assert_eq!(Uniforms::MSL_SIZE, 32);
+/// ```
+///
+/// `float3` is 16-byte aligned, so `dir` is at offset 16 in MSL and this doesn't compile:
+///
+/// ```compile_fail
+/// use cidre_macros::MslLayout;
+///
+/// #[derive(MslLayout)]
+/// #[repr(C)]
+/// struct Light {
+///     power: f32,
+///     dir: f32x3,
+/// }
+/// # #[allow(non_camel_case_types)]
+/// # struct f32x3([f32; 4]);
+/// ```
+///
+/// Neither does a struct which is laid out right but aligned less than MSL one:
+///
+/// ```compile_fail
+/// use cidre_macros::MslLayout;
+///
+/// #[derive(MslLayout)]
+/// #[repr(C)]
+/// struct Light {
+///     dir: f32x3,
+///     power: f32,
+///     pad: [f32; 3],
+/// }
+/// # #[allow(non_camel_case_types)]
+/// # struct f32x3([f32; 4]);
+/// ```
+#[proc_macro_derive(MslLayout)]
+pub fn msl_layout(ts: TokenStream) -> TokenStream {
+    msl::derive(ts)
+}
+
 // Super simple, but stable upper_case impl
 fn upper_case(str: &str) -> String {
     let len = str.len();
@@ -0,0 +1,391 @@
+//! Metal Shading Language struct layout for `#[derive(MslLayout)]`.
+//!
+//! Types are matched by name, so only scalars, `half::f16`, `simd` vectors and
+//! matrices, `simd::packed` vectors and arrays of them are known.
+
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Ty {
+    /// MSL type name
+    pub name: String,
+    /// Array dimensions, outermost first
+    pub dims: Vec<usize>,
+    pub size: usize,
+    pub align: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Field {
+    pub name: String,
+    pub ty: Ty,
+    pub offset: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Layout {
+    pub fields: Vec<Field>,
+    pub size: usize,
+    pub align: usize,
+}
+
+fn scalar(name: &str) -> Option<(&'static str, usize)> {
+    Some(match name {
+        "bool" => ("bool", 1),
+        "i8" => ("char", 1),
+        "u8" => ("uchar", 1),
+        "i16" => ("short", 2),
+        "u16" => ("ushort", 2),
+        "f16" => ("half", 2),
+        "i32" => ("int", 4),
+        "u32" => ("uint", 4),
+        "f32" => ("float", 4),
+        "i64" => ("long", 8),
+        "u64" => ("ulong", 8),
+        _ => return None,
+    })
+}
+
+fn vector(elem: &str, n: &str, packed: bool) -> Option<Ty> {
+    let (msl, s) = scalar(elem).filter(|(msl, _)| *msl != "bool")?;
+    let n: usize = n.parse().ok().filter(|n| (2..=4).contains(n))?;
+    let ty = if packed {
+        Ty {
+            name: format!("packed_{msl}{n}"),
+            dims: vec![],
+            size: s * n,
+            align: s,
+        }
+    } else {
+        // 3 lane vectors are padded to 4
+        let size = s * if n == 3 { 4 } else { n };
+        Ty {
+            name: format!("{msl}{n}"),
+            dims: vec![],
+            size,
+            align: size,
+        }
+    };
+    Some(ty)
+}
+
+fn matrix(elem: &str, cols: &str, rows: &str) -> Option<Ty> {
+    if elem != "f32" && elem != "f16" {
+        return None;
+    }
+    let c: usize = cols.parse().ok().filter(|n| (2..=4).contains(n))?;
+    let col = vector(elem, rows, false)?;
+    Some(Ty {
+        name: format!("{}{c}x{rows}", &col.name[..col.name.len() - 1]),
+        dims: vec![],
+        size: col.size * c,
+        align: col.align,
+    })
+}
+
+/// MSL type of a Rust type as written in a struct field.
+pub(crate) fn msl_ty(ty: &str) -> Result<Ty, String> {
+    let ty: String = ty.chars().filter(|c| !c.is_whitespace()).collect();
+    let unknown = || format!("`{ty}` has no known MSL layout");
+
+    if let Some(inner) = ty.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        let Some((elem, len)) = inner.rsplit_once(';') else {
+            return Err(format!("`{ty}`: slices have no MSL layout"));
+        };
+        let len: usize = len.parse().map_err(|_| unknown())?;
+        let mut elem = msl_ty(elem)?;
+        elem.dims.insert(0, len);
+        elem.size *= len;
+        return Ok(elem);
+    }
+
+    let segments: Vec<&str> = ty.split("::").collect();
+    let packed = segments.contains(&"packed");
+    let name = segments.last().copied().unwrap_or_default();
+    let parts: Vec<&str> = name.split('x').collect();
+    let res = match parts[..] {
+        [s] if !packed => scalar(s).map(|(name, size)| Ty {
+            name: name.to_string(),
+            dims: vec![],
+            size,
+            align: size,
+        }),
+        [elem, n] => vector(elem, n, packed),
+        [elem, c, r] if !packed => matrix(elem, c, r),
+        _ => None,
+    };
+    res.ok_or_else(unknown)
+}
+
+/// Lays out `(name, type)` fields with MSL rules.
+pub(crate) fn layout(fields: &[(String, String)]) -> Result<Layout, String> {
+    if fields.is_empty() {
+        return Err("MSL structs need at least one field".to_string());
+    }
+    let mut res = Vec::with_capacity(fields.len());
+    let mut offset = 0usize;
+    let mut align = 1;
+    for (name, ty) in fields {
+        let ty = msl_ty(ty).map_err(|e| format!("field `{name}`: {e}"))?;
+        offset = offset.next_multiple_of(ty.align);
+        align = align.max(ty.align);
+        let size = ty.size;
+        res.push(Field {
+            name: name.trim_start_matches("r#").to_string(),
+            ty,
+            offset,
+        });
+        offset += size;
+    }
+    Ok(Layout {
+        fields: res,
+        size: offset.next_multiple_of(align),
+        align,
+    })
+}
+
+impl Layout {
+    /// MSL `struct` declaration.
+    pub fn decl(&self, name: &str) -> String {
+        let mut res = format!("struct {name} {{\n");
+        for f in &self.fields {
+            res.push_str(&format!("    {} {}", f.ty.name, f.name));
+            for d in &f.ty.dims {
+                res.push_str(&format!("[{d}]"));
+            }
+            res.push_str(";\n");
+        }
+        res.push_str("};\n");
+        res
+    }
+}
+
+/// Rust source with the MSL consts and compile time layout checks.
+pub(crate) fn expand(name: &str, fields: &[(String, String)]) -> Result<String, String> {
+    let layout = layout(fields)?;
+    let mut checks = String::new();
+    for ((field, ty), f) in fields.iter().zip(layout.fields.iter()) {
+        let (size, offset, msl) = (f.ty.size, f.offset, &f.ty.name);
+        checks.push_str(&format!(
+            "
+    assert!(
+        std::mem::size_of::<{ty}>() == {size},
+        \"`{name}::{field}`: `{msl}` is {size} bytes in MSL\"
+    );
+    assert!(
+        std::mem::offset_of!({name}, {field}) == {offset},
+        \"`{name}::{field}` is at offset {offset} in MSL\"
+    );"
+        ));
+    }
+    let (size, align) = (layout.size, layout.align);
+    Ok(format!(
+        "
+impl {name} {{
+    /// Metal Shading Language declaration of the struct.
+    pub const MSL: &'static str = {decl:?};
+    pub const MSL_SIZE: usize = {size};
+    pub const MSL_ALIGN: usize = {align};
+}}
+
+const _: () = {{{checks}
+    assert!(
+        std::mem::size_of::<{name}>() == {size},
+        \"`{name}` is {size} bytes in MSL\"
+    );
+    assert!(
+        std::mem::align_of::<{name}>() == {align},
+        \"`{name}` is {align} byte aligned in MSL\"
+    );
+}};
+",
+        decl = layout.decl(name)
+    ))
+}
+
+pub(crate) fn derive(ts: TokenStream) -> TokenStream {
+    let mut iter = ts.into_iter();
+    let mut repr_c = false;
+    let mut name = None;
+    while let Some(tt) = iter.next() {
+        match tt {
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Bracket => {
+                let mut attr = g.stream().into_iter();
+                if !matches!(attr.next(), Some(TokenTree::Ident(i)) if i.to_string() == "repr") {
+                    continue;
+                }
+                let Some(TokenTree::Group(args)) = attr.next() else {
+                    continue;
+                };
+                repr_c |= args
+                    .stream()
+                    .into_iter()
+                    .any(|tt| matches!(tt, TokenTree::Ident(i) if i.to_string() == "C"));
+            }
+            TokenTree::Ident(i) if i.to_string() == "struct" => {
+                let Some(TokenTree::Ident(ident)) = iter.next() else {
+                    panic!("expect struct name");
+                };
+                name = Some(ident.to_string());
+                break;
+            }
+            TokenTree::Ident(i) if i.to_string() == "enum" || i.to_string() == "union" => {
+                panic!("MslLayout supports only structs")
+            }
+            _ => {}
+        }
+    }
+    let Some(name) = name else {
+        panic!("expect struct");
+    };
+    assert!(repr_c, "MslLayout requires #[repr(C)] on `{name}`");
+    let Some(TokenTree::Group(body)) = iter.next() else {
+        panic!("MslLayout supports only non generic structs with named fields");
+    };
+    if body.delimiter() != Delimiter::Brace {
+        panic!("MslLayout supports only non generic structs with named fields");
+    }
+
+    let mut fields = vec![];
+    let mut body = body.stream().into_iter().peekable();
+    'fields: loop {
+        let field = loop {
+            match body.next() {
+                None => break 'fields,
+                // attribute
+                Some(TokenTree::Punct(p)) if p == '#' => {
+                    body.next();
+                }
+                Some(TokenTree::Ident(i)) if i.to_string() == "pub" => {
+                    if matches!(body.peek(), Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis)
+                    {
+                        body.next();
+                    }
+                }
+                Some(TokenTree::Ident(i)) => break i.to_string(),
+                Some(tt) => panic!("unexpected {tt} in `{name}`"),
+            }
+        };
+        let Some(TokenTree::Punct(p)) = body.next() else {
+            panic!("expect `:` after `{name}::{field}`");
+        };
+        assert_eq!(p, ':');
+        let mut ty = String::new();
+        let mut depth = 0;
+        // no space after joint punct, so `::` stays a path separator
+        let mut joint = false;
+        for tt in body.by_ref() {
+            if let TokenTree::Punct(p) = &tt {
+                match p.as_char() {
+                    ',' if depth == 0 => break,
+                    '<' => depth += 1,
+                    '>' => depth -= 1,
+                    _ => {}
+                }
+            }
+            if !ty.is_empty() && !joint {
+                ty.push(' ');
+            }
+            joint = matches!(&tt, TokenTree::Punct(p) if p.spacing() == Spacing::Joint);
+            ty.push_str(&tt.to_string());
+        }
+        fields.push((field, ty));
+    }
+
+    match expand(&name, &fields) {
+        Ok(code) => code.parse().unwrap(),
+        Err(err) => panic!("MslLayout `{name}`: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand, layout, msl_ty};
+
+    fn fields(f: &[(&str, &str)]) -> Vec<(String, String)> {
+        f.iter()
+            .map(|(n, t)| (n.to_string(), t.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn types() {
+        let t = |s| {
+            let ty = msl_ty(s).unwrap();
+            (ty.name, ty.size, ty.align)
+        };
+        assert_eq!(t("f32"), ("float".to_string(), 4, 4));
+        assert_eq!(t("half :: f16"), ("half".to_string(), 2, 2));
+        assert_eq!(t("simd :: f32x3"), ("float3".to_string(), 16, 16));
+        assert_eq!(t("cidre :: simd :: u8x4"), ("uchar4".to_string(), 4, 4));
+        assert_eq!(t("f16x3"), ("half3".to_string(), 8, 8));
+        assert_eq!(
+            t("simd :: packed :: f32x4"),
+            ("packed_float4".to_string(), 16, 4)
+        );
+        assert_eq!(t("f32x4x3"), ("float4x3".to_string(), 64, 16));
+        assert_eq!(t("f32x3x2"), ("float3x2".to_string(), 24, 8));
+        assert_eq!(t("f16x2x2"), ("half2x2".to_string(), 8, 4));
+
+        let arr = msl_ty("[[f32 ; 3] ; 2]").unwrap();
+        assert_eq!(arr.dims, [2, 3]);
+        assert_eq!((arr.size, arr.align), (24, 4));
+
+        assert!(msl_ty("f64").is_err());
+        assert!(msl_ty("boolx2").is_err());
+        assert!(msl_ty("packed :: f32x4x4").is_err());
+        assert!(msl_ty("f32x5").is_err());
+        assert!(msl_ty("[f32]").is_err());
+    }
+
+    #[test]
+    fn offsets() {
+        let l = layout(&fields(&[
+            ("a", "f32"),
+            ("b", "f32x3"),
+            ("c", "u8"),
+            ("d", "packed :: f32x2"),
+            ("e", "[f16 ; 3]"),
+        ]))
+        .unwrap();
+        let offsets: Vec<_> = l.fields.iter().map(|f| f.offset).collect();
+        assert_eq!(offsets, [0, 16, 32, 36, 44]);
+        assert_eq!((l.size, l.align), (64, 16));
+
+        let l = layout(&fields(&[("a", "u16"), ("b", "u8")])).unwrap();
+        assert_eq!((l.size, l.align), (4, 2));
+
+        assert!(layout(&[]).is_err());
+    }
+
+    #[test]
+    fn decl() {
+        let l = layout(&fields(&[
+            ("mvp", "f32x4x4"),
+            ("r#type", "u32"),
+            ("weights", "[[f32 ; 4] ; 2]"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            l.decl("Uniforms"),
+            "struct Uniforms {\n    float4x4 mvp;\n    uint type;\n    float weights[2][4];\n};\n"
+        );
+    }
+
+    #[test]
+    fn expansion() {
+        let code = expand("Light", &fields(&[("dir", "f32x3"), ("power", "f32")])).unwrap();
+        assert!(code.contains(
+            "pub const MSL: &'static str = \"struct Light {\\n    float3 dir;\\n    float power;\\n};\\n\";"
+        ));
+        assert!(code.contains("pub const MSL_SIZE: usize = 32;"));
+        assert!(code.contains("pub const MSL_ALIGN: usize = 16;"));
+        assert!(code.contains("std::mem::size_of::<f32x3>() == 16"));
+        assert!(code.contains("std::mem::offset_of!(Light, power) == 16"));
+        assert!(code.contains("std::mem::size_of::<Light>() == 32"));
+        assert!(code.contains("std::mem::align_of::<Light>() == 16"));
+
+        let err = expand("Bad", &fields(&[("d", "f64")])).unwrap_err();
+        assert_eq!(err, "field `d`: `f64` has no known MSL layout");
+    }
+}
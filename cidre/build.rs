@@ -234,6 +234,11 @@ fn main() {
         return;
     }
 
+    // nothing to link on other platforms, only pure Rust modules are available there
+    if env::var("CARGO_CFG_TARGET_VENDOR").as_deref() != Ok("apple") {
+        return;
+    }
+
     let versions = parse_deployment_targets();
 
     let sdk = match env::var("TARGET").unwrap().as_ref() {
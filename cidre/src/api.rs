@@ -326,7 +326,7 @@ pub use cidre_macros::api_available as available;
 pub use cidre_macros::api_weak as weak;
 pub use version;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "ns"))]
 mod tests {
     use crate::{api, ns};
 
@@ -75,15 +75,25 @@ impl std::fmt::Debug for ExtAudioFilePropId {
 pub mod err {
     use crate::os::Error;
 
+    #[doc(alias = "kExtAudioFileError_InvalidProperty")]
     pub const INVALID_PROP: Error = Error::new_unchecked(-66561);
+    #[doc(alias = "kExtAudioFileError_InvalidPropertySize")]
     pub const INVALID_PROP_SIZE: Error = Error::new_unchecked(-66562);
+    #[doc(alias = "kExtAudioFileError_NonPCMClientFormat")]
     pub const NON_PCM_CLIENT_FORMAT: Error = Error::new_unchecked(-66563);
+    #[doc(alias = "kExtAudioFileError_InvalidChannelMap")]
     pub const INVALID_CHANNEL_MAP: Error = Error::new_unchecked(-66564);
+    #[doc(alias = "kExtAudioFileError_InvalidOperationOrder")]
     pub const INVALID_OP_ORDER: Error = Error::new_unchecked(-66565);
+    #[doc(alias = "kExtAudioFileError_InvalidDataFormat")]
     pub const INVALID_DATA_FORMAT: Error = Error::new_unchecked(-66566);
+    #[doc(alias = "kExtAudioFileError_MaxPacketSizeUnknown")]
     pub const MAX_PACKET_SIZE_UNKNOWN: Error = Error::new_unchecked(-66567);
+    #[doc(alias = "kExtAudioFileError_InvalidSeek")]
     pub const INVALID_SEEK: Error = Error::new_unchecked(-66568);
+    #[doc(alias = "kExtAudioFileError_AsyncWriteTooLarge")]
     pub const ASYNC_WRITE_TOO_LARGE: Error = Error::new_unchecked(-66569);
+    #[doc(alias = "kExtAudioFileError_AsyncWriteBufferOverflow")]
     pub const ASYNC_WRITE_BUF_OVERFLOW: Error = Error::new_unchecked(-66570);
 }
 
@@ -0,0 +1,182 @@
+/// Big-endian byte cursor used by the pure-Rust box and record parsers.
+pub(crate) struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    #[inline]
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    #[inline]
+    pub fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.pos.checked_add(len)?;
+        let res = self.data.get(self.pos..end)?;
+        self.pos = end;
+        Some(res)
+    }
+
+    #[inline]
+    pub fn u8(&mut self) -> Option<u8> {
+        let res = *self.data.get(self.pos)?;
+        self.pos += 1;
+        Some(res)
+    }
+
+    #[inline]
+    pub fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_be_bytes(self.take(2)?.try_into().ok()?))
+    }
+
+    #[inline]
+    pub fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_be_bytes(self.take(4)?.try_into().ok()?))
+    }
+}
+
+/// MSB-first bit reader with Exp-Golomb support (H.264/H.265 RBSP syntax).
+pub(crate) struct BitReader<'a> {
+    data: &'a [u8],
+    /// position in bits
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    #[inline]
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    #[inline]
+    pub fn bits_left(&self) -> usize {
+        self.data.len() * 8 - self.pos
+    }
+
+    #[inline]
+    pub fn bit(&mut self) -> Option<bool> {
+        let byte = *self.data.get(self.pos / 8)?;
+        let res = (byte >> (7 - (self.pos % 8))) & 1 == 1;
+        self.pos += 1;
+        Some(res)
+    }
+
+    /// Reads up to 32 bits.
+    #[inline]
+    pub fn bits(&mut self, n: u32) -> Option<u32> {
+        debug_assert!(n <= 32);
+        let mut res = 0u32;
+        for _ in 0..n {
+            res = (res << 1) | self.bit()? as u32;
+        }
+        Some(res)
+    }
+
+    #[inline]
+    pub fn u8(&mut self, n: u32) -> Option<u8> {
+        debug_assert!(n <= 8);
+        Some(self.bits(n)? as u8)
+    }
+
+    #[inline]
+    pub fn skip(&mut self, n: usize) -> Option<()> {
+        if self.bits_left() < n {
+            return None;
+        }
+        self.pos += n;
+        Some(())
+    }
+
+    /// Unsigned Exp-Golomb code, ue(v).
+    pub fn ue(&mut self) -> Option<u32> {
+        let mut zeros = 0u32;
+        while !self.bit()? {
+            zeros += 1;
+            if zeros > 31 {
+                return None;
+            }
+        }
+        let rest = self.bits(zeros)?;
+        Some(((1u64 << zeros) - 1 + rest as u64) as u32)
+    }
+
+    /// Signed Exp-Golomb code, se(v).
+    pub fn se(&mut self) -> Option<i32> {
+        let k = self.ue()? as i64;
+        let res = if k & 1 == 1 { (k + 1) / 2 } else { -(k / 2) };
+        Some(res as i32)
+    }
+}
+
+/// MSB-first bit writer appending to byte vector, the last byte is zero padded.
+pub(crate) struct BitWriter<'a> {
+    buf: &'a mut Vec<u8>,
+    /// free bits in the last byte
+    free: u32,
+}
+
+impl<'a> BitWriter<'a> {
+    #[inline]
+    pub fn new(buf: &'a mut Vec<u8>) -> Self {
+        Self { buf, free: 0 }
+    }
+
+    #[inline]
+    pub fn bit(&mut self, val: bool) {
+        if self.free == 0 {
+            self.buf.push(0);
+            self.free = 8;
+        }
+        self.free -= 1;
+        if val {
+            *self.buf.last_mut().unwrap() |= 1 << self.free;
+        }
+    }
+
+    /// Writes `n` low bits of `val`, up to 32.
+    #[inline]
+    pub fn bits(&mut self, n: u32, val: u32) {
+        debug_assert!(n <= 32);
+        for i in (0..n).rev() {
+            self.bit((val >> i) & 1 == 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BitReader, BitWriter};
+
+    #[test]
+    fn exp_golomb() {
+        // 1 | 010 | 011 | 00100 | 00101
+        let data = [0b1010_0110, 0b0100_0010, 0b1000_0000];
+        let mut r = BitReader::new(&data);
+        assert_eq!(r.ue(), Some(0));
+        assert_eq!(r.ue(), Some(1));
+        assert_eq!(r.ue(), Some(2));
+        assert_eq!(r.se(), Some(2));
+        assert_eq!(r.se(), Some(-2));
+        assert_eq!(r.bits_left(), 7);
+        assert_eq!(r.ue(), None);
+    }
+
+    #[test]
+    fn writer() {
+        let mut buf = vec![0xaa];
+        let mut w = BitWriter::new(&mut buf);
+        w.bit(true);
+        w.bits(5, 0b10011);
+        w.bits(17, 0x1_2345);
+        assert_eq!(buf, [0xaa, 0xce, 0x46, 0x8a]);
+        let mut r = BitReader::new(&buf[1..]);
+        assert_eq!(r.bits(6), Some(0b110011));
+        assert_eq!(r.bits(17), Some(0x1_2345));
+    }
+}
@@ -7,3 +7,10 @@ pub use base_types::*;
 mod session_types;
 pub use session_types::ErrorCode as SessionErrorCode;
 pub use session_types::SessionId;
+
+pub mod aac;
+pub mod caf;
+pub mod channel_layout;
+pub mod pcm;
+pub mod resampler;
+pub mod riff_iff;
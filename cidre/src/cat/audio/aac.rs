@@ -0,0 +1,799 @@
+//! MPEG-4 AudioSpecificConfig, `esds` magic cookie and ADTS header codec.
+//!
+//! AudioToolbox magic cookie of AAC formats is ES_Descriptor with AudioSpecificConfig
+//! inside, see `at::audio::File::magic_cookie_data`. Raw `.aac` streams, TS and RTMP
+//! carry the same config as ADTS headers or bare AudioSpecificConfig.
+
+use std::ffi::c_long;
+
+use crate::{
+    bits::{BitReader, BitWriter},
+    cat::audio::{Format, FormatFlags, Mpeg4Object, StreamBasicDesc},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Truncated or malformed data with the reason.
+    Invalid(&'static str),
+
+    /// Valid data this codec doesn't handle, like program config element.
+    Unsupported(&'static str),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid(reason) => write!(f, "invalid AAC config: {reason}"),
+            Self::Unsupported(reason) => write!(f, "unsupported AAC config: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T = ()> = std::result::Result<T, Error>;
+
+const TRUNCATED: Error = Error::Invalid("truncated");
+
+/// Sample rates of `samplingFrequencyIndex` 0..=12.
+pub const SAMPLE_RATES: [u32; 13] = [
+    96000, 88200, 64000, 48000, 44100, 32000, 24000, 22050, 16000, 12000, 11025, 8000, 7350,
+];
+
+pub fn sample_rate_index(sample_rate: u32) -> Option<u8> {
+    SAMPLE_RATES
+        .iter()
+        .position(|r| *r == sample_rate)
+        .map(|i| i as u8)
+}
+
+impl Mpeg4Object {
+    #[doc(alias = "kMPEG4Object_AAC_LD")]
+    pub const AAC_LD: Self = Self(23);
+
+    /// Parametric stereo, used for HE-AAC v2 signaling only.
+    pub const PS: Self = Self(29);
+
+    /// AAC Main, LC, SSR, LTP or LD, coded with GASpecificConfig.
+    #[inline]
+    pub fn is_aac(&self) -> bool {
+        matches!(self.0, 1..=4 | 23)
+    }
+}
+
+/// MPEG-4 AudioSpecificConfig (ISO/IEC 14496-3 1.6.2.1) of AAC formats.
+///
+/// Describes the core AAC stream, SBR and PS are optional extensions. Explicit
+/// and backward compatible SBR signaling are both parsed, [`Asc::write_to`]
+/// uses the explicit hierarchical one like AudioToolbox encoder.
+#[doc(alias = "AudioSpecificConfig")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Asc {
+    /// Core object type, AAC LC for HE-AAC.
+    pub object_type: Mpeg4Object,
+
+    /// Core sample rate.
+    pub sample_rate: u32,
+
+    /// 1..=6 is the number of channels, 7 is 7.1.
+    pub channel_cfg: u8,
+
+    /// 960 (480 for LD) samples per frame instead of 1024 (512).
+    pub frame_len_960: bool,
+
+    /// Output sample rate of SBR, usually twice the core one.
+    pub sbr_sample_rate: Option<u32>,
+
+    /// Parametric stereo, mono core decoded as stereo.
+    pub ps: bool,
+}
+
+impl Asc {
+    pub fn lc(sample_rate: u32, channel_cfg: u8) -> Self {
+        Self {
+            object_type: Mpeg4Object::AAC_LC,
+            sample_rate,
+            channel_cfg,
+            frame_len_960: false,
+            sbr_sample_rate: None,
+            ps: false,
+        }
+    }
+
+    /// HE-AAC with `sample_rate` of the output.
+    pub fn he(sample_rate: u32, channel_cfg: u8) -> Self {
+        Self {
+            sample_rate: sample_rate / 2,
+            sbr_sample_rate: Some(sample_rate),
+            ..Self::lc(sample_rate, channel_cfg)
+        }
+    }
+
+    /// HE-AAC v2, `sample_rate` of the stereo output.
+    pub fn he_v2(sample_rate: u32) -> Self {
+        Self {
+            ps: true,
+            ..Self::he(sample_rate, 1)
+        }
+    }
+
+    /// Number of channels after decoding.
+    pub fn channels(&self) -> u32 {
+        match self.channel_cfg {
+            _ if self.ps => 2,
+            7 => 8,
+            n => n as u32,
+        }
+    }
+
+    /// Decoded frames per packet, including SBR upsampling.
+    pub fn frames_per_packet(&self) -> u32 {
+        let n = match (self.object_type == Mpeg4Object::AAC_LD, self.frame_len_960) {
+            (false, false) => 1024,
+            (false, true) => 960,
+            (true, false) => 512,
+            (true, true) => 480,
+        };
+        if self.sbr_sample_rate.is_some() {
+            n * 2
+        } else {
+            n
+        }
+    }
+
+    /// Output sample rate.
+    #[inline]
+    pub fn output_sample_rate(&self) -> u32 {
+        self.sbr_sample_rate.unwrap_or(self.sample_rate)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut r = BitReader::new(bytes);
+        let mut object_type = read_object_type(&mut r)?;
+        let sample_rate = read_sample_rate(&mut r)?;
+        let channel_cfg = r.bits(4).ok_or(TRUNCATED)? as u8;
+        let mut sbr_sample_rate = None;
+        let mut ps = false;
+        let explicit = object_type == 5 || object_type == 29;
+        if explicit {
+            ps = object_type == 29;
+            sbr_sample_rate = Some(read_sample_rate(&mut r)?);
+            object_type = read_object_type(&mut r)?;
+        }
+        let object_type = Mpeg4Object(object_type as _);
+        if !object_type.is_aac() {
+            return Err(Error::Unsupported("audio object type"));
+        }
+
+        // GASpecificConfig
+        let frame_len_960 = r.bit().ok_or(TRUNCATED)?;
+        if r.bit().ok_or(TRUNCATED)? {
+            // core coder delay
+            r.bits(14).ok_or(TRUNCATED)?;
+        }
+        let ext_flag = r.bit().ok_or(TRUNCATED)?;
+        if channel_cfg == 0 {
+            return Err(Error::Unsupported("program config element"));
+        }
+        if ext_flag {
+            if object_type == Mpeg4Object::AAC_LD {
+                // resilience flags
+                r.bits(3).ok_or(TRUNCATED)?;
+            }
+            // extensionFlag3
+            r.bit().ok_or(TRUNCATED)?;
+        }
+        if object_type == Mpeg4Object::AAC_LD && r.bits(2).ok_or(TRUNCATED)? != 0 {
+            return Err(Error::Unsupported("error protection config"));
+        }
+
+        // backward compatible SBR signaling in the tail
+        let sync_ext = |r: &mut BitReader| -> Result<bool> {
+            Ok(r.bits_left() >= 16
+                && r.bits(11).ok_or(TRUNCATED)? == 0x2b7
+                && r.bits(5).ok_or(TRUNCATED)? == 5)
+        };
+        if !explicit && sync_ext(&mut r)? && r.bit().ok_or(TRUNCATED)? {
+            sbr_sample_rate = Some(read_sample_rate(&mut r)?);
+            if r.bits_left() >= 12 && r.bits(11).ok_or(TRUNCATED)? == 0x548 {
+                ps = r.bit().ok_or(TRUNCATED)?;
+            }
+        }
+        if sbr_sample_rate.is_none() && ps {
+            return Err(Error::Invalid("PS without SBR"));
+        }
+
+        Ok(Self {
+            object_type,
+            sample_rate,
+            channel_cfg,
+            frame_len_960,
+            sbr_sample_rate,
+            ps,
+        })
+    }
+
+    pub fn write_to(&self, buf: &mut Vec<u8>) -> Result {
+        if !self.object_type.is_aac() {
+            return Err(Error::Unsupported("audio object type"));
+        }
+        if !(1..=7).contains(&self.channel_cfg) {
+            return Err(Error::Unsupported("channel config"));
+        }
+        if self.ps && self.sbr_sample_rate.is_none() {
+            return Err(Error::Invalid("PS without SBR"));
+        }
+        let mut w = BitWriter::new(buf);
+        let object_type = self.object_type.0 as u32;
+        match self.sbr_sample_rate {
+            Some(_) if self.ps => w.bits(5, 29),
+            Some(_) => w.bits(5, 5),
+            None => w.bits(5, object_type),
+        }
+        write_sample_rate(&mut w, self.sample_rate);
+        w.bits(4, self.channel_cfg as u32);
+        if let Some(sbr_sample_rate) = self.sbr_sample_rate {
+            write_sample_rate(&mut w, sbr_sample_rate);
+            w.bits(5, object_type);
+        }
+        // frameLengthFlag, dependsOnCoreCoder, extensionFlag
+        w.bits(3, (self.frame_len_960 as u32) << 2);
+        if self.object_type == Mpeg4Object::AAC_LD {
+            // epConfig
+            w.bits(2, 0);
+        }
+        Ok(())
+    }
+
+    pub fn to_vec(&self) -> Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(4);
+        self.write_to(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Compressed stream description AudioToolbox uses for this config.
+    pub fn to_asbd(&self) -> StreamBasicDesc {
+        let (format, format_flags) = match (self.sbr_sample_rate, self.ps) {
+            (Some(_), true) => (Format::MPEG4_AAC_HE_V2, FormatFlags(0)),
+            (Some(_), false) => (Format::MPEG4_AAC_HE, FormatFlags(0)),
+            _ if self.object_type == Mpeg4Object::AAC_LD => (Format::MPEG4_AAC_LD, FormatFlags(0)),
+            _ => (Format::MPEG4_AAC, FormatFlags(self.object_type.0 as u32)),
+        };
+        StreamBasicDesc {
+            sample_rate: self.output_sample_rate() as f64,
+            format,
+            format_flags,
+            bytes_per_packet: 0,
+            frames_per_packet: self.frames_per_packet(),
+            bytes_per_frame: 0,
+            channels_per_frame: self.channels(),
+            bits_per_channel: 0,
+            reserved: 0,
+        }
+    }
+
+    /// Config for AAC, HE-AAC, HE-AAC v2 or AAC LD stream description.
+    ///
+    /// `format_flags` of `MPEG4_AAC` may carry [`Mpeg4Object`], AAC LC is used otherwise.
+    pub fn with_asbd(asbd: &StreamBasicDesc) -> Result<Self> {
+        let sample_rate = asbd.sample_rate as u32;
+        if sample_rate == 0 || asbd.sample_rate.fract() != 0.0 {
+            return Err(Error::Unsupported("sample rate"));
+        }
+        let channel_cfg = match asbd.channels_per_frame {
+            n @ 1..=6 => n as u8,
+            8 => 7,
+            _ => return Err(Error::Unsupported("channel count")),
+        };
+        let mut res = match asbd.format {
+            Format::MPEG4_AAC => {
+                let mut res = Self::lc(sample_rate, channel_cfg);
+                if (1..=4).contains(&asbd.format_flags.0) {
+                    res.object_type = Mpeg4Object(asbd.format_flags.0 as _);
+                }
+                res
+            }
+            Format::MPEG4_AAC_LD => Self {
+                object_type: Mpeg4Object::AAC_LD,
+                ..Self::lc(sample_rate, channel_cfg)
+            },
+            Format::MPEG4_AAC_HE => Self::he(sample_rate, channel_cfg),
+            Format::MPEG4_AAC_HE_V2 if channel_cfg == 2 => Self::he_v2(sample_rate),
+            _ => return Err(Error::Unsupported("format")),
+        };
+        if asbd.frames_per_packet != 0 && asbd.frames_per_packet != res.frames_per_packet() {
+            res.frame_len_960 = true;
+            if asbd.frames_per_packet != res.frames_per_packet() {
+                return Err(Error::Invalid("frames per packet"));
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// ES_Descriptor (ISO/IEC 14496-1 7.2.6.5), payload of `esds` box and
+/// AudioToolbox magic cookie of AAC formats.
+#[doc(alias = "ES_Descriptor")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Esds {
+    pub es_id: u16,
+
+    /// 0x40 for MPEG-4 audio.
+    pub object_type_indication: u8,
+
+    /// 24 bit decoding buffer size.
+    pub buffer_size: u32,
+    pub max_bitrate: u32,
+    pub avg_bitrate: u32,
+
+    /// DecoderSpecificInfo, AudioSpecificConfig for MPEG-4 audio.
+    pub decoder_specific_info: Vec<u8>,
+}
+
+impl Esds {
+    const ES_DESC_TAG: u8 = 0x03;
+    const DECODER_CFG_DESC_TAG: u8 = 0x04;
+    const DECODER_SPECIFIC_INFO_TAG: u8 = 0x05;
+    const SL_CFG_DESC_TAG: u8 = 0x06;
+
+    /// MPEG-4 audio descriptor with `asc` inside.
+    pub fn with_asc(asc: &Asc) -> Result<Self> {
+        Ok(Self {
+            es_id: 0,
+            object_type_indication: 0x40,
+            buffer_size: 0,
+            max_bitrate: 0,
+            avg_bitrate: 0,
+            decoder_specific_info: asc.to_vec()?,
+        })
+    }
+
+    #[inline]
+    pub fn asc(&self) -> Result<Asc> {
+        Asc::from_bytes(&self.decoder_specific_info)
+    }
+
+    /// Parses ES_Descriptor, also with leading version and flags of `esds` box.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let bytes = match bytes {
+            [0, _, _, _, Self::ES_DESC_TAG, ..] => &bytes[4..],
+            _ => bytes,
+        };
+        let (Self::ES_DESC_TAG, mut es) = read_desc(bytes)? else {
+            return Err(Error::Invalid("ES_Descriptor tag"));
+        };
+        let es_id = u16::from_be_bytes([es[0], es[1]]);
+        let flags = es[2];
+        es = &es[3..];
+        let mut skip = 0;
+        if flags & 0x80 != 0 {
+            // dependsOn_ES_ID
+            skip += 2;
+        }
+        if flags & 0x40 != 0 {
+            // URL
+            skip += 1 + *es.get(skip).ok_or(TRUNCATED)? as usize;
+        }
+        if flags & 0x20 != 0 {
+            // OCR_ES_Id
+            skip += 2;
+        }
+        es = es.get(skip..).ok_or(TRUNCATED)?;
+
+        while !es.is_empty() {
+            let (tag, body) = read_desc(es)?;
+            let end = body.as_ptr() as usize - es.as_ptr() as usize + body.len();
+            es = &es[end..];
+            if tag != Self::DECODER_CFG_DESC_TAG {
+                continue;
+            }
+            let Some(&[oti, _, b0, b1, b2, ..]) = body.get(..13) else {
+                return Err(TRUNCATED);
+            };
+            let be32 = |b: &[u8]| u32::from_be_bytes([b[0], b[1], b[2], b[3]]);
+            let mut decoder_specific_info = Vec::new();
+            let mut rest = &body[13..];
+            while !rest.is_empty() {
+                let (tag, info) = read_desc(rest)?;
+                if tag == Self::DECODER_SPECIFIC_INFO_TAG {
+                    decoder_specific_info = info.to_vec();
+                    break;
+                }
+                let end = info.as_ptr() as usize - rest.as_ptr() as usize + info.len();
+                rest = &rest[end..];
+            }
+            return Ok(Self {
+                es_id,
+                object_type_indication: oti,
+                buffer_size: u32::from_be_bytes([0, b0, b1, b2]),
+                max_bitrate: be32(&body[5..]),
+                avg_bitrate: be32(&body[9..]),
+                decoder_specific_info,
+            });
+        }
+        Err(Error::Invalid("missing DecoderConfigDescriptor"))
+    }
+
+    /// Writes ES_Descriptor without `esds` box version and flags.
+    pub fn write_to(&self, buf: &mut Vec<u8>) -> Result {
+        if self.buffer_size >= 1 << 24 {
+            return Err(Error::Invalid("buffer size"));
+        }
+        let mut dsi = Vec::with_capacity(self.decoder_specific_info.len() + 5);
+        write_desc(
+            &mut dsi,
+            Self::DECODER_SPECIFIC_INFO_TAG,
+            &self.decoder_specific_info,
+        )?;
+        let mut dc = Vec::with_capacity(dsi.len() + 13);
+        dc.push(self.object_type_indication);
+        // audio stream, upstream 0, reserved 1
+        dc.push(0x15);
+        dc.extend_from_slice(&self.buffer_size.to_be_bytes()[1..]);
+        dc.extend_from_slice(&self.max_bitrate.to_be_bytes());
+        dc.extend_from_slice(&self.avg_bitrate.to_be_bytes());
+        dc.extend_from_slice(&dsi);
+
+        let mut es = Vec::with_capacity(dc.len() + 16);
+        es.extend_from_slice(&self.es_id.to_be_bytes());
+        es.push(0);
+        write_desc(&mut es, Self::DECODER_CFG_DESC_TAG, &dc)?;
+        // SLConfigDescriptor, predefined MP4
+        write_desc(&mut es, Self::SL_CFG_DESC_TAG, &[0x02])?;
+        write_desc(buf, Self::ES_DESC_TAG, &es)
+    }
+
+    pub fn to_vec(&self) -> Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(self.decoder_specific_info.len() + 32);
+        self.write_to(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+/// Tag and body of descriptor with expandable size.
+fn read_desc(bytes: &[u8]) -> Result<(u8, &[u8])> {
+    let (&tag, mut rest) = bytes.split_first().ok_or(TRUNCATED)?;
+    let mut len = 0usize;
+    for i in 0..4 {
+        let (&b, r) = rest.split_first().ok_or(TRUNCATED)?;
+        rest = r;
+        len = (len << 7) | (b & 0x7f) as usize;
+        if b & 0x80 == 0 {
+            break;
+        }
+        if i == 3 {
+            return Err(Error::Invalid("descriptor size"));
+        }
+    }
+    let body = rest.get(..len).ok_or(TRUNCATED)?;
+    if tag == Esds::ES_DESC_TAG && body.len() < 3 {
+        return Err(TRUNCATED);
+    }
+    Ok((tag, body))
+}
+
+/// Writes descriptor with the shortest size field.
+fn write_desc(buf: &mut Vec<u8>, tag: u8, body: &[u8]) -> Result {
+    let len = body.len();
+    if len >= 1 << 28 {
+        return Err(Error::Invalid("descriptor size"));
+    }
+    buf.push(tag);
+    let n = (1..4).find(|i| len >> (7 * i) == 0).unwrap_or(4);
+    for i in (0..n).rev() {
+        let b = ((len >> (7 * i)) & 0x7f) as u8;
+        buf.push(if i == 0 { b } else { b | 0x80 });
+    }
+    buf.extend_from_slice(body);
+    Ok(())
+}
+
+/// ADTS fixed and variable header (ISO/IEC 13818-7 6.2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Adts {
+    /// MPEG-2 ID bit, MPEG-4 is used otherwise.
+    pub mpeg2: bool,
+
+    /// AAC Main, LC, SSR or LTP.
+    pub object_type: Mpeg4Object,
+    pub sample_rate_index: u8,
+    pub channel_cfg: u8,
+
+    /// Whole frame length including the header.
+    pub frame_len: u16,
+
+    /// 0x7ff for variable bit rate.
+    pub buffer_fullness: u16,
+
+    /// Number of raw data blocks in the frame.
+    pub raw_data_blocks: u8,
+
+    /// CRC of 9 byte header, computed by the caller.
+    pub crc: Option<u16>,
+}
+
+impl Adts {
+    pub const LEN: usize = 7;
+    pub const LEN_WITH_CRC: usize = 9;
+
+    /// Header of one raw data block frame with `payload_len` bytes.
+    ///
+    /// ADTS carries the core config only, SBR and PS are left to implicit signaling.
+    pub fn with_asc(asc: &Asc, payload_len: usize) -> Result<Self> {
+        if !(1..=4).contains(&asc.object_type.0) || asc.frame_len_960 {
+            return Err(Error::Unsupported("audio object type"));
+        }
+        if !(1..=7).contains(&asc.channel_cfg) {
+            return Err(Error::Unsupported("channel config"));
+        }
+        let sample_rate_index =
+            sample_rate_index(asc.sample_rate).ok_or(Error::Unsupported("sample rate"))?;
+        let frame_len = u16::try_from(payload_len + Self::LEN)
+            .ok()
+            .filter(|len| *len < 1 << 13)
+            .ok_or(Error::Invalid("frame length"))?;
+        Ok(Self {
+            mpeg2: false,
+            object_type: Mpeg4Object(asc.object_type.0),
+            sample_rate_index,
+            channel_cfg: asc.channel_cfg,
+            frame_len,
+            buffer_fullness: 0x7ff,
+            raw_data_blocks: 1,
+            crc: None,
+        })
+    }
+
+    /// Implicit SBR and PS are not signaled by ADTS, so it is the core config.
+    pub fn asc(&self) -> Asc {
+        Asc {
+            object_type: Mpeg4Object(self.object_type.0),
+            ..Asc::lc(
+                SAMPLE_RATES[self.sample_rate_index as usize],
+                self.channel_cfg,
+            )
+        }
+    }
+
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        if self.crc.is_some() {
+            Self::LEN_WITH_CRC
+        } else {
+            Self::LEN
+        }
+    }
+
+    #[inline]
+    pub fn payload_len(&self) -> usize {
+        (self.frame_len as usize).saturating_sub(self.header_len())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let Some(&[b0, b1, b2, b3, b4, b5, b6]) = bytes.get(..Self::LEN) else {
+            return Err(TRUNCATED);
+        };
+        if b0 != 0xff || b1 & 0xf6 != 0xf0 {
+            return Err(Error::Invalid("ADTS sync word"));
+        }
+        let sample_rate_index = (b2 >> 2) & 0xf;
+        if sample_rate_index > 12 {
+            return Err(Error::Invalid("sample rate index"));
+        }
+        let crc = if b1 & 1 == 0 {
+            let Some(&[c0, c1]) = bytes.get(7..9) else {
+                return Err(TRUNCATED);
+            };
+            Some(u16::from_be_bytes([c0, c1]))
+        } else {
+            None
+        };
+        let res = Self {
+            mpeg2: b1 & 0x08 != 0,
+            object_type: Mpeg4Object((b2 >> 6) as c_long + 1),
+            sample_rate_index,
+            channel_cfg: ((b2 & 1) << 2) | (b3 >> 6),
+            frame_len: ((b3 as u16 & 3) << 11) | ((b4 as u16) << 3) | (b5 as u16 >> 5),
+            buffer_fullness: ((b5 as u16 & 0x1f) << 6) | (b6 as u16 >> 2),
+            raw_data_blocks: (b6 & 3) + 1,
+            crc,
+        };
+        if (res.frame_len as usize) < res.header_len() {
+            return Err(Error::Invalid("frame length"));
+        }
+        Ok(res)
+    }
+
+    pub fn write_to(&self, buf: &mut Vec<u8>) -> Result {
+        if !(1..=4).contains(&self.object_type.0)
+            || self.sample_rate_index > 12
+            || self.channel_cfg > 7
+            || self.frame_len >= 1 << 13
+            || self.buffer_fullness >= 1 << 11
+            || !(1..=4).contains(&self.raw_data_blocks)
+        {
+            return Err(Error::Invalid("ADTS field out of range"));
+        }
+        let len = self.frame_len;
+        buf.extend_from_slice(&[
+            0xff,
+            0xf0 | ((self.mpeg2 as u8) << 3) | self.crc.is_none() as u8,
+            (((self.object_type.0 - 1) as u8) << 6)
+                | (self.sample_rate_index << 2)
+                | (self.channel_cfg >> 2),
+            ((self.channel_cfg & 3) << 6) | (len >> 11) as u8,
+            (len >> 3) as u8,
+            ((len & 7) << 5) as u8 | (self.buffer_fullness >> 6) as u8,
+            ((self.buffer_fullness & 0x3f) << 2) as u8 | (self.raw_data_blocks - 1),
+        ]);
+        if let Some(crc) = self.crc {
+            buf.extend_from_slice(&crc.to_be_bytes());
+        }
+        Ok(())
+    }
+
+    pub fn to_vec(&self) -> Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(Self::LEN_WITH_CRC);
+        self.write_to(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+fn read_object_type(r: &mut BitReader) -> Result<u32> {
+    match r.bits(5).ok_or(TRUNCATED)? {
+        31 => Ok(32 + r.bits(6).ok_or(TRUNCATED)?),
+        t => Ok(t),
+    }
+}
+
+fn read_sample_rate(r: &mut BitReader) -> Result<u32> {
+    match r.bits(4).ok_or(TRUNCATED)? {
+        15 => r.bits(24).ok_or(TRUNCATED),
+        i @ 0..=12 => Ok(SAMPLE_RATES[i as usize]),
+        _ => Err(Error::Invalid("sample rate index")),
+    }
+}
+
+fn write_sample_rate(w: &mut BitWriter, sample_rate: u32) {
+    match sample_rate_index(sample_rate) {
+        Some(i) => w.bits(4, i as u32),
+        None => {
+            w.bits(4, 15);
+            w.bits(24, sample_rate);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cat::audio::{self, aac};
+
+    fn ld_asc() -> aac::Asc {
+        aac::Asc {
+            object_type: audio::Mpeg4Object::AAC_LD,
+            ..aac::Asc::lc(48000, 1)
+        }
+    }
+
+    #[test]
+    fn asc() {
+        let lc = aac::Asc::from_bytes(&[0x12, 0x10]).unwrap();
+        assert_eq!(lc, aac::Asc::lc(44100, 2));
+        assert_eq!(lc.to_vec().unwrap(), [0x12, 0x10]);
+
+        let asbd = lc.to_asbd();
+        assert_eq!(asbd.format, audio::Format::MPEG4_AAC);
+        assert_eq!(asbd.format_flags.0, 2);
+        assert_eq!(asbd.frames_per_packet, 1024);
+        assert_eq!(aac::Asc::with_asbd(&asbd).unwrap(), lc);
+
+        // explicit hierarchical SBR
+        let he = aac::Asc::from_bytes(&[0x2b, 0x92, 0x08, 0x00]).unwrap();
+        assert_eq!(he, aac::Asc::he(44100, 2));
+        assert_eq!(he.to_vec().unwrap(), [0x2b, 0x92, 0x08, 0x00]);
+        let asbd = he.to_asbd();
+        assert_eq!(asbd.format, audio::Format::MPEG4_AAC_HE);
+        assert_eq!(asbd.sample_rate, 44100.0);
+        assert_eq!(asbd.frames_per_packet, 2048);
+        assert_eq!(aac::Asc::with_asbd(&asbd).unwrap(), he);
+
+        // PS
+        let v2 = aac::Asc::from_bytes(&[0xeb, 0x09, 0x88, 0x00]).unwrap();
+        assert_eq!(v2, aac::Asc::he_v2(48000));
+        assert_eq!(v2.to_vec().unwrap(), [0xeb, 0x09, 0x88, 0x00]);
+        assert_eq!(v2.channels(), 2);
+        assert_eq!(v2.to_asbd().format, audio::Format::MPEG4_AAC_HE_V2);
+        assert_eq!(aac::Asc::with_asbd(&v2.to_asbd()).unwrap(), v2);
+
+        // backward compatible SBR signaling after GASpecificConfig
+        let implicit = aac::Asc::from_bytes(&[0x13, 0x10, 0x56, 0xe5, 0x98]).unwrap();
+        assert_eq!(implicit, aac::Asc::he(48000, 2));
+
+        // explicit sample rate, 960 frames
+        let asc = aac::Asc {
+            frame_len_960: true,
+            ..aac::Asc::lc(44000, 1)
+        };
+        let bytes = asc.to_vec().unwrap();
+        assert_eq!(aac::Asc::from_bytes(&bytes).unwrap(), asc);
+        assert_eq!(aac::Asc::with_asbd(&asc.to_asbd()).unwrap(), asc);
+
+        let ld = ld_asc();
+        assert_eq!(ld.to_asbd().frames_per_packet, 512);
+        assert_eq!(aac::Asc::from_bytes(&ld.to_vec().unwrap()).unwrap(), ld);
+
+        assert!(matches!(
+            aac::Asc::from_bytes(&[0x12, 0x00]),
+            Err(aac::Error::Unsupported(_))
+        ));
+        assert!(matches!(
+            aac::Asc::from_bytes(&[0x12]),
+            Err(aac::Error::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn esds() {
+        // AAC LC 44.1 kHz stereo with 4 byte descriptor sizes like AudioToolbox writes
+        let cookie = [
+            0x03, 0x80, 0x80, 0x80, 0x22, 0x00, 0x00, 0x00, 0x04, 0x80, 0x80, 0x80, 0x14, 0x40,
+            0x15, 0x00, 0x18, 0x00, 0x00, 0x01, 0xf4, 0x00, 0x00, 0x01, 0xf4, 0x00, 0x05, 0x80,
+            0x80, 0x80, 0x02, 0x12, 0x10, 0x06, 0x80, 0x80, 0x80, 0x01, 0x02,
+        ];
+        let esds = aac::Esds::from_bytes(&cookie).unwrap();
+        assert_eq!(esds.object_type_indication, 0x40);
+        assert_eq!(esds.buffer_size, 0x1800);
+        assert_eq!(esds.max_bitrate, 128_000);
+        assert_eq!(esds.avg_bitrate, 128_000);
+        assert_eq!(esds.asc().unwrap(), aac::Asc::lc(44100, 2));
+
+        let bytes = esds.to_vec().unwrap();
+        assert_eq!(
+            bytes,
+            [
+                0x03, 0x19, 0x00, 0x00, 0x00, 0x04, 0x11, 0x40, 0x15, 0x00, 0x18, 0x00, 0x00, 0x01,
+                0xf4, 0x00, 0x00, 0x01, 0xf4, 0x00, 0x05, 0x02, 0x12, 0x10, 0x06, 0x01, 0x02
+            ]
+        );
+        assert_eq!(aac::Esds::from_bytes(&bytes).unwrap(), esds);
+
+        // `esds` box payload with version and flags
+        let mut payload = vec![0, 0, 0, 0];
+        payload.extend_from_slice(&bytes);
+        assert_eq!(aac::Esds::from_bytes(&payload).unwrap(), esds);
+
+        let esds = aac::Esds::with_asc(&aac::Asc::he(48000, 2)).unwrap();
+        assert_eq!(esds.asc().unwrap(), aac::Asc::he(48000, 2));
+        assert!(aac::Esds::from_bytes(&bytes[..bytes.len() - 4]).is_err());
+        assert!(aac::Esds::from_bytes(&[0x04, 0x00]).is_err());
+    }
+
+    #[test]
+    fn adts() {
+        let asc = aac::Asc::lc(44100, 2);
+        let adts = aac::Adts::with_asc(&asc, 10).unwrap();
+        let bytes = adts.to_vec().unwrap();
+        assert_eq!(bytes, [0xff, 0xf1, 0x50, 0x80, 0x02, 0x3f, 0xfc]);
+        assert_eq!(aac::Adts::from_bytes(&bytes).unwrap(), adts);
+        assert_eq!(adts.payload_len(), 10);
+        assert_eq!(adts.asc(), asc);
+
+        let with_crc = aac::Adts {
+            frame_len: 100,
+            crc: Some(0xbeef),
+            ..adts
+        };
+        let bytes = with_crc.to_vec().unwrap();
+        assert_eq!(bytes.len(), aac::Adts::LEN_WITH_CRC);
+        assert_eq!(bytes[1], 0xf0);
+        assert_eq!(aac::Adts::from_bytes(&bytes).unwrap(), with_crc);
+        assert_eq!(with_crc.payload_len(), 91);
+
+        let core = aac::Adts::with_asc(&aac::Asc::he_v2(48000), 10).unwrap();
+        assert_eq!(core.asc(), aac::Asc::lc(24000, 1));
+        assert!(aac::Adts::with_asc(&ld_asc(), 10).is_err());
+        assert!(aac::Adts::with_asc(&asc, 8192).is_err());
+        assert!(aac::Adts::from_bytes(&[0xff, 0xf1, 0x50]).is_err());
+        assert!(aac::Adts::from_bytes(&[0xff, 0xe1, 0x50, 0x80, 0x02, 0x3f, 0xfc]).is_err());
+    }
+}
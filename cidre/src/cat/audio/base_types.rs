@@ -3,10 +3,13 @@ use std::{
     ptr::{slice_from_raw_parts, slice_from_raw_parts_mut},
 };
 
-use crate::{cf, define_opts, four_cc_to_str, objc::Obj, os};
+use crate::{define_opts, four_cc_to_str, os};
 
-#[cfg(feature = "ns")]
-use crate::ns;
+#[cfg(all(target_vendor = "apple", feature = "cf"))]
+use crate::cf;
+
+#[cfg(all(target_vendor = "apple", feature = "ns"))]
+use crate::{ns, objc::Obj};
 
 /// These are the error codes returned from the APIs found through Core Audio related frameworks.
 pub mod err {
@@ -122,6 +125,13 @@ impl BufListN {
         bufs
     }
 
+    pub fn buffers_mut(&mut self) -> &mut [Buf] {
+        let n = self.number_buffers();
+        let (_pre, bufs, _post) = unsafe { self.inner[4..].align_to_mut::<Buf>() };
+        let n = n.min(bufs.len());
+        &mut bufs[..n]
+    }
+
     pub fn as_mut_ptr(&mut self) -> *mut u8 {
         self.inner.as_mut_ptr()
     }
@@ -447,30 +457,34 @@ impl Format {
     #[doc(alias = "kAudioFormatAPAC")]
     pub const APAC: Self = Self(u32::from_be_bytes(*b"apac"));
 
-    #[cfg(feature = "ns")]
+    #[cfg(all(target_vendor = "apple", feature = "ns"))]
     pub fn to_ns_number(self) -> &'static ns::Number {
         ns::Number::tagged_i32(self.0 as _)
     }
 }
 
+#[cfg(all(target_vendor = "apple", feature = "cf"))]
 impl AsRef<cf::Number> for Format {
     fn as_ref(&self) -> &'static cf::Number {
         cf::Number::tagged_i32(self.0 as _)
     }
 }
 
+#[cfg(all(target_vendor = "apple", feature = "cf"))]
 impl AsRef<cf::Type> for Format {
     fn as_ref(&self) -> &'static cf::Type {
         cf::Number::tagged_i32(self.0 as _).as_type_ref()
     }
 }
 
+#[cfg(all(target_vendor = "apple", feature = "ns"))]
 impl AsRef<ns::Id> for Format {
     fn as_ref(&self) -> &'static ns::Id {
         self.to_ns_number().as_id_ref()
     }
 }
 
+#[cfg(all(target_vendor = "apple", feature = "ns"))]
 impl AsRef<ns::Number> for Format {
     #[inline]
     fn as_ref(&self) -> &'static ns::Number {
@@ -744,6 +758,50 @@ impl StreamBasicDesc {
             reserved: 0,
         }
     }
+
+    /// Linear PCM with `valid_bits` in `total_bits` wide samples, aligned high when they differ.
+    #[doc(alias = "FillOutASBDForLPCM")]
+    pub const fn with_lpcm(
+        sample_rate: f64,
+        channels_per_frame: u32,
+        valid_bits: u32,
+        total_bits: u32,
+        is_float: bool,
+        is_big_endian: bool,
+        is_non_interleaved: bool,
+    ) -> Self {
+        let mut flags = if is_float {
+            FormatFlags::IS_FLOAT.0
+        } else {
+            FormatFlags::IS_SIGNED_INTEGER.0
+        };
+        if is_big_endian {
+            flags |= FormatFlags::IS_BIG_ENDIAN.0;
+        }
+        if valid_bits == total_bits {
+            flags |= FormatFlags::IS_PACKED.0;
+        } else {
+            flags |= FormatFlags::IS_ALIGNED_HIGH.0;
+        }
+        let channels = if is_non_interleaved {
+            flags |= FormatFlags::IS_NON_INTERLEAVED.0;
+            1
+        } else {
+            channels_per_frame
+        };
+        let bytes_per_frame = channels * (total_bits / 8);
+        Self {
+            sample_rate,
+            format: Format::LINEAR_PCM,
+            format_flags: FormatFlags(flags),
+            bytes_per_packet: bytes_per_frame,
+            frames_per_packet: 1,
+            bytes_per_frame,
+            channels_per_frame,
+            bits_per_channel: valid_bits,
+            reserved: 0,
+        }
+    }
 }
 
 /// This structure describes the packet layout of a buffer of data where the size of
@@ -768,7 +826,7 @@ pub struct StreamPacketDesc {
 #[doc(alias = "kAudioStreamAnyRate")]
 pub const STREAM_ANY_RATE: f64 = 0.0;
 
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
 #[repr(transparent)]
 pub struct SMPTETimeType(pub u32);
 
@@ -1116,7 +1174,7 @@ impl ChannelLabel {
 /// These constants are for use in the mChannelBitmap field of an
 /// AudioChannelLayout structure
 #[doc(alias = "AudioChannelBitmap")]
-#[derive(Debug, PartialEq, Eq, Default)]
+#[derive(Debug, PartialEq, Eq, Default, Copy, Clone)]
 #[repr(transparent)]
 pub struct ChannelBitmap(pub u32);
 
@@ -1428,30 +1486,30 @@ impl ChannelLayoutTag {
     /// L R C LFE Ls Rs Lc Rc
     pub const AUDIO_UNIT_7_1_FRONT: Self = Self::MPEG_7_1_A;
 
-    pub const AAC_3_0: Self = Self::MPEG_3_0_B;
     /// C L R
-    pub const AAC_QUADRAPHONIC: Self = Self::QUADRAPHONIC;
+    pub const AAC_3_0: Self = Self::MPEG_3_0_B;
     /// L R Ls Rs
-    pub const AAC_4_0: Self = Self::MPEG_4_0_B;
+    pub const AAC_QUADRAPHONIC: Self = Self::QUADRAPHONIC;
     /// C L R Cs
-    pub const AAC_5_0: Self = Self::MPEG_5_0_D;
+    pub const AAC_4_0: Self = Self::MPEG_4_0_B;
     /// C L R Ls Rs
-    pub const AAC_5_1: Self = Self::MPEG_5_1_D;
+    pub const AAC_5_0: Self = Self::MPEG_5_0_D;
     /// C L R Ls Rs Lfe
-    pub const AAC_6_0: Self = Self((141u32 << 16) | 6);
+    pub const AAC_5_1: Self = Self::MPEG_5_1_D;
     /// C L R Ls Rs Cs
-    pub const AAC_6_1: Self = Self((142u32 << 16) | 7);
+    pub const AAC_6_0: Self = Self((141u32 << 16) | 6);
     /// C L R Ls Rs Cs Lfe
-    pub const AAC_7_0: Self = Self((143u32 << 16) | 7);
+    pub const AAC_6_1: Self = Self((142u32 << 16) | 7);
     /// C L R Ls Rs Rls Rrs
-    pub const AAC_7_1: Self = Self::MPEG_7_1_B;
+    pub const AAC_7_0: Self = Self((143u32 << 16) | 7);
     /// C Lc Rc L R Ls Rs Lfe
-    pub const AAC_7_1_B: Self = Self((183u32 << 16) | 8);
+    pub const AAC_7_1: Self = Self::MPEG_7_1_B;
     /// C L R Ls Rs Rls Rrs LFE
-    pub const AAC_7_1_C: Self = Self((184u32 << 16) | 8);
+    pub const AAC_7_1_B: Self = Self((183u32 << 16) | 8);
     /// C L R Ls Rs LFE Vhl Vhr
-    pub const AAC_OCTAGONAL: Self = Self((144u32 << 16) | 8);
+    pub const AAC_7_1_C: Self = Self((184u32 << 16) | 8);
     /// C L R Ls Rs Rls Rrs Cs
+    pub const AAC_OCTAGONAL: Self = Self((144u32 << 16) | 8);
 
     /// L R C Vhc Lsd Rsd Ls Rs Vhl Vhr Lw Rw Csd Cs LFE1 LFE2
     pub const TMH_10_2_STD: Self = Self((145u32 << 16) | 16);
@@ -1693,7 +1751,7 @@ pub struct FormatListItem {
 }
 
 #[doc(alias = "MPEG4ObjectID")]
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct Mpeg4Object(pub c_long);
 
@@ -1728,11 +1786,11 @@ impl Mpeg4Object {
 
 #[cfg(test)]
 mod tests {
-    use crate::at;
+    use crate::cat;
 
     #[test]
     fn basics() {
-        let asbd = at::audio::StreamBasicDesc::common_f32(44100.0, 2, false);
+        let asbd = cat::audio::StreamBasicDesc::common_f32(44100.0, 2, false);
         assert_eq!(asbd.interleaved_channels_num(), 1);
         assert!(!asbd.is_interleaved());
         assert!(asbd.is_common_f32());
@@ -0,0 +1,1361 @@
+//! Core Audio Format (CAF) reader and writer.
+//!
+//! Pure Rust, so it works where AudioToolbox is not available. Files produced
+//! by [`Writer`] are the same as `at::audio::FileTypeId::CAF` ones.
+//!
+//! The writer keeps the `data` chunk size unknown (-1) until [`Writer::finish`],
+//! so capture interrupted by a crash still leaves a readable file for constant
+//! bit rate formats.
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+use crate::cat::audio::{
+    ChannelBitmap, ChannelDesc, ChannelFlags, ChannelLabel, ChannelLayout as AudioChannelLayout,
+    ChannelLayoutTag, Format, FormatFlags, SMPTETimeType, StreamBasicDesc, StreamPacketDesc,
+    channel_layout,
+};
+
+const FILE_TYPE: [u8; 4] = *b"caff";
+const FILE_VERSION: u16 = 1;
+
+const DESC: [u8; 4] = *b"desc";
+const DATA: [u8; 4] = *b"data";
+const PAKT: [u8; 4] = *b"pakt";
+const KUKI: [u8; 4] = *b"kuki";
+const CHAN: [u8; 4] = *b"chan";
+const INFO: [u8; 4] = *b"info";
+const MARK: [u8; 4] = *b"mark";
+const REGN: [u8; 4] = *b"regn";
+
+const DESC_LEN: usize = 32;
+const MARKER_LEN: usize = 28;
+const CHANNEL_DESC_LEN: usize = 20;
+
+/// kCAFLinearPCMFormatFlagIsFloat
+const LPCM_IS_FLOAT: u32 = 1 << 0;
+/// kCAFLinearPCMFormatFlagIsLittleEndian
+const LPCM_IS_LITTLE_ENDIAN: u32 = 1 << 1;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+
+    /// Not a CAF file or unsupported file version.
+    NotCaf,
+
+    /// Required chunk is missing, `desc` or `data`.
+    MissingChunk([u8; 4]),
+
+    /// Malformed chunk with the reason.
+    Corrupt([u8; 4], &'static str),
+
+    /// Stream description can't be stored in CAF or packets don't match it.
+    InvalidFormat(&'static str),
+
+    /// Chunks preceding audio data can't be changed after the first write.
+    DataStarted,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => err.fmt(f),
+            Self::NotCaf => f.write_str("not a CAF file"),
+            Self::MissingChunk(id) => {
+                write!(f, "missing '{}' chunk", String::from_utf8_lossy(id))
+            }
+            Self::Corrupt(id, reason) => {
+                write!(
+                    f,
+                    "invalid '{}' chunk: {reason}",
+                    String::from_utf8_lossy(id)
+                )
+            }
+            Self::InvalidFormat(reason) => write!(f, "invalid format: {reason}"),
+            Self::DataStarted => f.write_str("audio data already started"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+pub type Result<T = ()> = std::result::Result<T, Error>;
+
+/// Frame accounting of the packet table.
+///
+/// `valid_frames + priming_frames + remainder_frames` is the total number of
+/// frames in all packets.
+#[doc(alias = "AudioFilePacketTableInfo")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct PacketTableInfo {
+    pub valid_frames: i64,
+    pub priming_frames: i32,
+    pub remainder_frames: i32,
+}
+
+/// Owned channel layout as stored in `chan` chunk.
+#[doc(alias = "CAFChannelLayout")]
+#[derive(Debug, Clone)]
+pub struct ChannelLayout {
+    pub tag: ChannelLayoutTag,
+    pub bitmap: ChannelBitmap,
+    pub descs: Vec<ChannelDesc>,
+}
+
+impl ChannelLayout {
+    pub fn with_tag(tag: ChannelLayoutTag) -> Self {
+        Self {
+            tag,
+            bitmap: ChannelBitmap(0),
+            descs: Vec::new(),
+        }
+    }
+
+    pub fn with_layout<const N: usize>(layout: &AudioChannelLayout<N>) -> Self {
+        let n = (layout.number_channel_descriptions as usize).min(N);
+        Self {
+            tag: layout.channel_layout_tag,
+            bitmap: layout.channel_bitmap,
+            descs: layout.channel_descriptions[..n].to_vec(),
+        }
+    }
+
+    /// None if there are more than `N` channel descriptions.
+    pub fn to_layout<const N: usize>(&self) -> Option<AudioChannelLayout<N>> {
+        if self.descs.len() > N {
+            return None;
+        }
+        let mut channel_descriptions = [ChannelDesc::default(); N];
+        channel_descriptions[..self.descs.len()].copy_from_slice(&self.descs);
+        Some(AudioChannelLayout {
+            channel_layout_tag: self.tag,
+            channel_bitmap: self.bitmap,
+            number_channel_descriptions: self.descs.len() as u32,
+            channel_descriptions,
+        })
+    }
+
+    /// Ordered channel labels, see [`channel_layout::resolve`].
+    pub fn labels(&self) -> Option<Vec<ChannelLabel>> {
+        channel_layout::resolve(self.tag, self.bitmap, &self.descs)
+    }
+}
+
+#[doc(alias = "CAFMarkerType")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct MarkerType(pub u32);
+
+impl MarkerType {
+    #[doc(alias = "kCAFMarkerType_Generic")]
+    pub const GENERIC: Self = Self(0);
+
+    #[doc(alias = "kCAFMarkerType_ProgramStart")]
+    pub const PROGRAM_START: Self = Self(u32::from_be_bytes(*b"pbeg"));
+
+    #[doc(alias = "kCAFMarkerType_ProgramEnd")]
+    pub const PROGRAM_END: Self = Self(u32::from_be_bytes(*b"pend"));
+
+    #[doc(alias = "kCAFMarkerType_TrackStart")]
+    pub const TRACK_START: Self = Self(u32::from_be_bytes(*b"tbeg"));
+
+    #[doc(alias = "kCAFMarkerType_TrackEnd")]
+    pub const TRACK_END: Self = Self(u32::from_be_bytes(*b"tend"));
+
+    #[doc(alias = "kCAFMarkerType_Index")]
+    pub const INDEX: Self = Self(u32::from_be_bytes(*b"indx"));
+
+    #[doc(alias = "kCAFMarkerType_RegionStart")]
+    pub const REGION_START: Self = Self(u32::from_be_bytes(*b"rbeg"));
+
+    #[doc(alias = "kCAFMarkerType_RegionEnd")]
+    pub const REGION_END: Self = Self(u32::from_be_bytes(*b"rend"));
+
+    #[doc(alias = "kCAFMarkerType_RegionSyncPoint")]
+    pub const REGION_SYNC_POINT: Self = Self(u32::from_be_bytes(*b"rsyc"));
+
+    #[doc(alias = "kCAFMarkerType_SelectionStart")]
+    pub const SELECTION_START: Self = Self(u32::from_be_bytes(*b"sbeg"));
+
+    #[doc(alias = "kCAFMarkerType_SelectionEnd")]
+    pub const SELECTION_END: Self = Self(u32::from_be_bytes(*b"send"));
+
+    #[doc(alias = "kCAFMarkerType_EditSourceBegin")]
+    pub const EDIT_SOURCE_BEGIN: Self = Self(u32::from_be_bytes(*b"cbeg"));
+
+    #[doc(alias = "kCAFMarkerType_EditSourceEnd")]
+    pub const EDIT_SOURCE_END: Self = Self(u32::from_be_bytes(*b"cend"));
+
+    #[doc(alias = "kCAFMarkerType_EditDestinationBegin")]
+    pub const EDIT_DESTINATION_BEGIN: Self = Self(u32::from_be_bytes(*b"dbeg"));
+
+    #[doc(alias = "kCAFMarkerType_EditDestinationEnd")]
+    pub const EDIT_DESTINATION_END: Self = Self(u32::from_be_bytes(*b"dend"));
+
+    #[doc(alias = "kCAFMarkerType_SustainLoopStart")]
+    pub const SUSTAIN_LOOP_START: Self = Self(u32::from_be_bytes(*b"slbg"));
+
+    #[doc(alias = "kCAFMarkerType_SustainLoopEnd")]
+    pub const SUSTAIN_LOOP_END: Self = Self(u32::from_be_bytes(*b"slen"));
+
+    #[doc(alias = "kCAFMarkerType_ReleaseLoopStart")]
+    pub const RELEASE_LOOP_START: Self = Self(u32::from_be_bytes(*b"rlbg"));
+
+    #[doc(alias = "kCAFMarkerType_ReleaseLoopEnd")]
+    pub const RELEASE_LOOP_END: Self = Self(u32::from_be_bytes(*b"rlen"));
+
+    #[doc(alias = "kCAFMarkerType_SavedPlayPosition")]
+    pub const SAVED_PLAY_POSITION: Self = Self(u32::from_be_bytes(*b"sply"));
+
+    #[doc(alias = "kCAFMarkerType_Tempo")]
+    pub const TEMPO: Self = Self(u32::from_be_bytes(*b"tmpo"));
+
+    #[doc(alias = "kCAFMarkerType_TimeSignature")]
+    pub const TIME_SIGNATURE: Self = Self(u32::from_be_bytes(*b"tsig"));
+
+    #[doc(alias = "kCAFMarkerType_KeySignature")]
+    pub const KEY_SIGNATURE: Self = Self(u32::from_be_bytes(*b"ksig"));
+}
+
+/// SMPTE time of a marker. Meaningful only if the list has SMPTE time type.
+#[doc(alias = "CAF_SMPTE_Time")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct SmpteTime {
+    pub hours: i8,
+    pub minutes: i8,
+    pub seconds: i8,
+    pub frames: i8,
+    pub sub_frame_sample_offset: u32,
+}
+
+#[doc(alias = "CAFMarker")]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Marker {
+    pub type_: MarkerType,
+    pub frame_pos: f64,
+    pub id: u32,
+    pub smpte_time: SmpteTime,
+    /// 0 means all channels.
+    pub channel: u32,
+}
+
+#[doc(alias = "CAFMarkerChunk")]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Markers {
+    pub smpte_time_type: Option<SMPTETimeType>,
+    pub list: Vec<Marker>,
+}
+
+crate::define_opts!(
+    #[doc(alias = "CAFRegionFlags")]
+    pub RegionFlags(u32)
+);
+
+impl RegionFlags {
+    #[doc(alias = "kCAFRegionFlag_LoopEnable")]
+    pub const LOOP_ENABLE: Self = Self(1);
+
+    #[doc(alias = "kCAFRegionFlag_PlayForward")]
+    pub const PLAY_FORWARD: Self = Self(2);
+
+    #[doc(alias = "kCAFRegionFlag_PlayBackward")]
+    pub const PLAY_BACKWARD: Self = Self(4);
+}
+
+#[doc(alias = "CAFRegion")]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Region {
+    pub id: u32,
+    pub flags: RegionFlags,
+    pub markers: Vec<Marker>,
+}
+
+#[doc(alias = "CAFRegionChunk")]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Regions {
+    pub smpte_time_type: Option<SMPTETimeType>,
+    pub list: Vec<Region>,
+}
+
+/// CAF stores SMPTE time type shifted by one, with 0 for none.
+fn smpte_type_from_caf(val: u32) -> Option<SMPTETimeType> {
+    val.checked_sub(1).map(SMPTETimeType)
+}
+
+fn smpte_type_to_caf(val: Option<SMPTETimeType>) -> u32 {
+    val.map_or(0, |t| t.0 + 1)
+}
+
+fn desc_from_caf(b: &[u8]) -> StreamBasicDesc {
+    let u32_at = |i: usize| u32::from_be_bytes(b[i..i + 4].try_into().unwrap());
+    let sample_rate = f64::from_be_bytes(b[0..8].try_into().unwrap());
+    let format = Format(u32_at(8));
+    let flags = u32_at(12);
+    let bytes_per_packet = u32_at(16);
+    let frames_per_packet = u32_at(20);
+    let channels_per_frame = u32_at(24);
+    let bits_per_channel = u32_at(28);
+
+    let format_flags = if format == Format::LINEAR_PCM {
+        let mut res = if flags & LPCM_IS_FLOAT != 0 {
+            FormatFlags::IS_FLOAT
+        } else {
+            FormatFlags::IS_SIGNED_INTEGER
+        };
+        if flags & LPCM_IS_LITTLE_ENDIAN == 0 {
+            res |= FormatFlags::IS_BIG_ENDIAN;
+        }
+        if bits_per_channel as u64 * channels_per_frame as u64 == bytes_per_packet as u64 * 8 {
+            res |= FormatFlags::IS_PACKED;
+        }
+        res
+    } else {
+        FormatFlags(flags)
+    };
+
+    StreamBasicDesc {
+        sample_rate,
+        format,
+        format_flags,
+        bytes_per_packet,
+        frames_per_packet,
+        bytes_per_frame: if frames_per_packet == 1 {
+            bytes_per_packet
+        } else {
+            0
+        },
+        channels_per_frame,
+        bits_per_channel,
+        reserved: 0,
+    }
+}
+
+fn desc_to_caf(desc: &StreamBasicDesc) -> Result<[u8; DESC_LEN]> {
+    let flags = if desc.format == Format::LINEAR_PCM {
+        if !desc.is_interleaved() && desc.channels_per_frame > 1 {
+            return Err(Error::InvalidFormat("CAF audio data is always interleaved"));
+        }
+        if !desc.format_flags.contains(FormatFlags::IS_FLOAT)
+            && !desc.format_flags.contains(FormatFlags::IS_SIGNED_INTEGER)
+        {
+            return Err(Error::InvalidFormat("CAF integer samples are signed"));
+        }
+        if desc.frames_per_packet != 1 || desc.bytes_per_packet == 0 {
+            return Err(Error::InvalidFormat("linear PCM packet is one frame"));
+        }
+        let mut res = 0;
+        if desc.format_flags.contains(FormatFlags::IS_FLOAT) {
+            res |= LPCM_IS_FLOAT;
+        }
+        if !desc.format_flags.contains(FormatFlags::IS_BIG_ENDIAN) {
+            res |= LPCM_IS_LITTLE_ENDIAN;
+        }
+        res
+    } else {
+        desc.format_flags.0
+    };
+    if desc.sample_rate <= 0.0 || desc.channels_per_frame == 0 {
+        return Err(Error::InvalidFormat("sample rate and channels must be set"));
+    }
+
+    let mut b = [0u8; DESC_LEN];
+    b[0..8].copy_from_slice(&desc.sample_rate.to_be_bytes());
+    let fields = [
+        desc.format.0,
+        flags,
+        desc.bytes_per_packet,
+        desc.frames_per_packet,
+        desc.channels_per_frame,
+        desc.bits_per_channel,
+    ];
+    for (i, v) in fields.iter().enumerate() {
+        b[8 + i * 4..12 + i * 4].copy_from_slice(&v.to_be_bytes());
+    }
+    Ok(b)
+}
+
+/// Big endian base 128 integer used in packet table.
+fn read_var_int(b: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut res = 0u64;
+    for _ in 0..10 {
+        let byte = *b.get(*pos)?;
+        *pos += 1;
+        res = (res << 7) | (byte & 0x7f) as u64;
+        if byte & 0x80 == 0 {
+            return Some(res);
+        }
+    }
+    None
+}
+
+fn write_var_int(dst: &mut Vec<u8>, val: u64) {
+    let mut n = 1;
+    while n < 10 && val >> (7 * n) != 0 {
+        n += 1;
+    }
+    for i in (0..n).rev() {
+        let byte = ((val >> (7 * i)) & 0x7f) as u8;
+        dst.push(if i == 0 { byte } else { byte | 0x80 });
+    }
+}
+
+/// Reads a big endian field, `pos` is advanced.
+struct Cursor<'a> {
+    id: [u8; 4],
+    b: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(id: [u8; 4], b: &'a [u8]) -> Self {
+        Self { id, b, pos: 0 }
+    }
+
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        let res = self
+            .b
+            .get(self.pos..self.pos + n)
+            .ok_or(Error::Corrupt(self.id, "truncated"))?;
+        self.pos += n;
+        Ok(res)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> Result<i64> {
+        Ok(i64::from_be_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    fn f32(&mut self) -> Result<f32> {
+        Ok(f32::from_bits(self.u32()?))
+    }
+
+    fn f64(&mut self) -> Result<f64> {
+        Ok(f64::from_bits(self.i64()? as u64))
+    }
+
+    fn c_str(&mut self) -> Result<String> {
+        let rest = &self.b[self.pos..];
+        let len = rest
+            .iter()
+            .position(|&c| c == 0)
+            .ok_or(Error::Corrupt(self.id, "unterminated string"))?;
+        self.pos += len + 1;
+        Ok(String::from_utf8_lossy(&rest[..len]).into_owned())
+    }
+
+    fn marker(&mut self) -> Result<Marker> {
+        let type_ = MarkerType(self.u32()?);
+        let frame_pos = self.f64()?;
+        let id = self.u32()?;
+        let t = self.bytes(4)?;
+        let smpte_time = SmpteTime {
+            hours: t[0] as i8,
+            minutes: t[1] as i8,
+            seconds: t[2] as i8,
+            frames: t[3] as i8,
+            sub_frame_sample_offset: self.u32()?,
+        };
+        Ok(Marker {
+            type_,
+            frame_pos,
+            id,
+            smpte_time,
+            channel: self.u32()?,
+        })
+    }
+}
+
+fn read_chan(b: &[u8]) -> Result<ChannelLayout> {
+    let mut c = Cursor::new(CHAN, b);
+    let tag = ChannelLayoutTag(c.u32()?);
+    let bitmap = ChannelBitmap(c.u32()?);
+    let n = c.u32()? as usize;
+    if n > (b.len() - 12) / CHANNEL_DESC_LEN {
+        return Err(Error::Corrupt(CHAN, "truncated"));
+    }
+    let mut descs = Vec::with_capacity(n);
+    for _ in 0..n {
+        descs.push(ChannelDesc {
+            channel_label: ChannelLabel(c.u32()?),
+            channel_flags: ChannelFlags(c.u32()?),
+            coordinates: [c.f32()?, c.f32()?, c.f32()?],
+        });
+    }
+    Ok(ChannelLayout { tag, bitmap, descs })
+}
+
+fn write_chan(dst: &mut Vec<u8>, layout: &ChannelLayout) {
+    dst.extend_from_slice(&layout.tag.0.to_be_bytes());
+    dst.extend_from_slice(&layout.bitmap.0.to_be_bytes());
+    dst.extend_from_slice(&(layout.descs.len() as u32).to_be_bytes());
+    for d in &layout.descs {
+        dst.extend_from_slice(&d.channel_label.0.to_be_bytes());
+        dst.extend_from_slice(&d.channel_flags.0.to_be_bytes());
+        for c in d.coordinates {
+            dst.extend_from_slice(&c.to_be_bytes());
+        }
+    }
+}
+
+fn read_info(b: &[u8]) -> Result<Vec<(String, String)>> {
+    let mut c = Cursor::new(INFO, b);
+    let n = c.u32()? as usize;
+    let mut res = Vec::with_capacity(n.min(b.len() / 2));
+    for _ in 0..n {
+        res.push((c.c_str()?, c.c_str()?));
+    }
+    Ok(res)
+}
+
+fn write_info(dst: &mut Vec<u8>, info: &[(String, String)]) {
+    dst.extend_from_slice(&(info.len() as u32).to_be_bytes());
+    for (key, value) in info {
+        for s in [key, value] {
+            // strings are NUL terminated, anything after inner NUL would be lost anyway
+            dst.extend(s.bytes().take_while(|&c| c != 0));
+            dst.push(0);
+        }
+    }
+}
+
+fn write_marker(dst: &mut Vec<u8>, m: &Marker) {
+    dst.extend_from_slice(&m.type_.0.to_be_bytes());
+    dst.extend_from_slice(&m.frame_pos.to_be_bytes());
+    dst.extend_from_slice(&m.id.to_be_bytes());
+    let t = &m.smpte_time;
+    dst.extend_from_slice(&[
+        t.hours as u8,
+        t.minutes as u8,
+        t.seconds as u8,
+        t.frames as u8,
+    ]);
+    dst.extend_from_slice(&t.sub_frame_sample_offset.to_be_bytes());
+    dst.extend_from_slice(&m.channel.to_be_bytes());
+}
+
+fn read_mark(b: &[u8]) -> Result<Markers> {
+    let mut c = Cursor::new(MARK, b);
+    let smpte_time_type = smpte_type_from_caf(c.u32()?);
+    let n = c.u32()? as usize;
+    let mut list = Vec::with_capacity(n.min(b.len() / MARKER_LEN));
+    for _ in 0..n {
+        list.push(c.marker()?);
+    }
+    Ok(Markers {
+        smpte_time_type,
+        list,
+    })
+}
+
+fn write_mark(dst: &mut Vec<u8>, markers: &Markers) {
+    dst.extend_from_slice(&smpte_type_to_caf(markers.smpte_time_type).to_be_bytes());
+    dst.extend_from_slice(&(markers.list.len() as u32).to_be_bytes());
+    for m in &markers.list {
+        write_marker(dst, m);
+    }
+}
+
+fn read_regn(b: &[u8]) -> Result<Regions> {
+    let mut c = Cursor::new(REGN, b);
+    let smpte_time_type = smpte_type_from_caf(c.u32()?);
+    let n = c.u32()? as usize;
+    let mut list = Vec::with_capacity(n.min(b.len() / 12));
+    for _ in 0..n {
+        let id = c.u32()?;
+        let flags = RegionFlags(c.u32()?);
+        let markers_n = c.u32()? as usize;
+        let mut markers = Vec::with_capacity(markers_n.min(b.len() / MARKER_LEN));
+        for _ in 0..markers_n {
+            markers.push(c.marker()?);
+        }
+        list.push(Region { id, flags, markers });
+    }
+    Ok(Regions {
+        smpte_time_type,
+        list,
+    })
+}
+
+fn write_regn(dst: &mut Vec<u8>, regions: &Regions) {
+    dst.extend_from_slice(&smpte_type_to_caf(regions.smpte_time_type).to_be_bytes());
+    dst.extend_from_slice(&(regions.list.len() as u32).to_be_bytes());
+    for r in &regions.list {
+        dst.extend_from_slice(&r.id.to_be_bytes());
+        dst.extend_from_slice(&r.flags.0.to_be_bytes());
+        dst.extend_from_slice(&(r.markers.len() as u32).to_be_bytes());
+        for m in &r.markers {
+            write_marker(dst, m);
+        }
+    }
+}
+
+fn write_chunk<W: Write>(w: &mut W, id: [u8; 4], body: &[u8]) -> io::Result<()> {
+    w.write_all(&id)?;
+    w.write_all(&(body.len() as i64).to_be_bytes())?;
+    w.write_all(body)
+}
+
+/// Demuxes CAF file.
+///
+/// All chunks except audio data are read upfront by [`Reader::new`].
+pub struct Reader<R> {
+    inner: R,
+    desc: StreamBasicDesc,
+    magic_cookie: Option<Vec<u8>>,
+    channel_layout: Option<ChannelLayout>,
+    info: Vec<(String, String)>,
+    markers: Option<Markers>,
+    regions: Option<Regions>,
+    packet_table_info: Option<PacketTableInfo>,
+    /// Variable bit rate packets, offsets are relative to data start.
+    packet_descs: Vec<StreamPacketDesc>,
+    edit_count: u32,
+    data_offset: u64,
+    data_len: u64,
+}
+
+impl<R: Read + Seek> Reader<R> {
+    pub fn new(mut inner: R) -> Result<Self> {
+        let file_len = inner.seek(SeekFrom::End(0))?;
+        inner.seek(SeekFrom::Start(0))?;
+
+        let mut header = [0u8; 8];
+        inner.read_exact(&mut header).map_err(|_| Error::NotCaf)?;
+        if header[0..4] != FILE_TYPE || u16::from_be_bytes([header[4], header[5]]) != FILE_VERSION {
+            return Err(Error::NotCaf);
+        }
+
+        let mut desc = None;
+        let mut data = None;
+        let mut pakt = None;
+        let mut res = Self {
+            inner,
+            desc: Default::default(),
+            magic_cookie: None,
+            channel_layout: None,
+            info: Vec::new(),
+            markers: None,
+            regions: None,
+            packet_table_info: None,
+            packet_descs: Vec::new(),
+            edit_count: 0,
+            data_offset: 0,
+            data_len: 0,
+        };
+
+        let mut pos = 8u64;
+        while pos + 12 <= file_len {
+            let mut h = [0u8; 12];
+            res.inner.read_exact(&mut h)?;
+            let id: [u8; 4] = h[0..4].try_into().unwrap();
+            let size = i64::from_be_bytes(h[4..12].try_into().unwrap());
+            pos += 12;
+            let avail = file_len - pos;
+
+            if id == DATA {
+                let mut edit_count = [0u8; 4];
+                res.inner
+                    .read_exact(&mut edit_count)
+                    .map_err(|_| Error::Corrupt(DATA, "truncated"))?;
+                res.edit_count = u32::from_be_bytes(edit_count);
+                // -1 is the size of data chunk still being written (or never finished).
+                let len = if size == -1 {
+                    avail
+                } else if size < 4 {
+                    return Err(Error::Corrupt(DATA, "invalid size"));
+                } else {
+                    (size as u64).min(avail)
+                };
+                data = Some((pos + 4, len - 4));
+                if size == -1 {
+                    break;
+                }
+                pos += len;
+                res.inner.seek(SeekFrom::Start(pos))?;
+                continue;
+            }
+
+            if size < 0 {
+                return Err(Error::Corrupt(id, "invalid size"));
+            }
+            let size = size as u64;
+            let wanted = matches!(id, DESC | PAKT | KUKI | CHAN | INFO | MARK | REGN);
+            if !wanted {
+                // unknown and `free` chunks
+                pos = pos.saturating_add(size);
+                res.inner.seek(SeekFrom::Start(pos.min(file_len)))?;
+                continue;
+            }
+            if size > avail {
+                return Err(Error::Corrupt(id, "truncated"));
+            }
+            let mut body = vec![0u8; size as usize];
+            res.inner.read_exact(&mut body)?;
+            pos += size;
+
+            match id {
+                DESC => {
+                    if body.len() < DESC_LEN {
+                        return Err(Error::Corrupt(DESC, "truncated"));
+                    }
+                    desc = Some(desc_from_caf(&body));
+                }
+                PAKT => pakt = Some(body),
+                KUKI => res.magic_cookie = Some(body),
+                CHAN => res.channel_layout = Some(read_chan(&body)?),
+                INFO => res.info = read_info(&body)?,
+                MARK => res.markers = Some(read_mark(&body)?),
+                REGN => res.regions = Some(read_regn(&body)?),
+                _ => unreachable!(),
+            }
+        }
+
+        res.desc = desc.ok_or(Error::MissingChunk(DESC))?;
+        (res.data_offset, res.data_len) = data.ok_or(Error::MissingChunk(DATA))?;
+        if let Some(pakt) = pakt {
+            res.read_pakt(&pakt)?;
+        } else if res.is_vbr() && res.data_len > 0 {
+            return Err(Error::MissingChunk(PAKT));
+        }
+        Ok(res)
+    }
+
+    fn read_pakt(&mut self, b: &[u8]) -> Result {
+        let mut c = Cursor::new(PAKT, b);
+        let packets_num = c.i64()?;
+        let valid_frames = c.i64()?;
+        let priming_frames = c.u32()? as i32;
+        let remainder_frames = c.u32()? as i32;
+        if packets_num < 0 {
+            return Err(Error::Corrupt(PAKT, "invalid packets number"));
+        }
+        self.packet_table_info = Some(PacketTableInfo {
+            valid_frames,
+            priming_frames,
+            remainder_frames,
+        });
+        if !self.is_vbr() {
+            return Ok(());
+        }
+
+        let table = &b[c.pos..];
+        let mut pos = 0;
+        let mut offset = 0i64;
+        let cap = (packets_num as usize).min(table.len());
+        self.packet_descs = Vec::with_capacity(cap);
+        for _ in 0..packets_num {
+            let mut next =
+                || read_var_int(table, &mut pos).ok_or(Error::Corrupt(PAKT, "truncated"));
+            let data_byte_size = if self.desc.bytes_per_packet == 0 {
+                next()? as u32
+            } else {
+                self.desc.bytes_per_packet
+            };
+            let variable_frames_in_packet = if self.desc.frames_per_packet == 0 {
+                next()? as u32
+            } else {
+                0
+            };
+            self.packet_descs.push(StreamPacketDesc {
+                start_offset: offset,
+                variable_frames_in_packet,
+                data_byte_size,
+            });
+            offset += data_byte_size as i64;
+        }
+        if offset as u64 > self.data_len {
+            return Err(Error::Corrupt(PAKT, "packets exceed audio data"));
+        }
+        Ok(())
+    }
+
+    fn is_vbr(&self) -> bool {
+        self.desc.bytes_per_packet == 0 || self.desc.frames_per_packet == 0
+    }
+
+    #[inline]
+    pub fn desc(&self) -> &StreamBasicDesc {
+        &self.desc
+    }
+
+    #[inline]
+    pub fn magic_cookie(&self) -> Option<&[u8]> {
+        self.magic_cookie.as_deref()
+    }
+
+    #[inline]
+    pub fn channel_layout(&self) -> Option<&ChannelLayout> {
+        self.channel_layout.as_ref()
+    }
+
+    /// Key value pairs of `info` chunk in file order.
+    #[inline]
+    pub fn info(&self) -> &[(String, String)] {
+        &self.info
+    }
+
+    #[inline]
+    pub fn markers(&self) -> Option<&Markers> {
+        self.markers.as_ref()
+    }
+
+    #[inline]
+    pub fn regions(&self) -> Option<&Regions> {
+        self.regions.as_ref()
+    }
+
+    #[inline]
+    pub fn packet_table_info(&self) -> Option<PacketTableInfo> {
+        self.packet_table_info
+    }
+
+    #[inline]
+    pub fn edit_count(&self) -> u32 {
+        self.edit_count
+    }
+
+    /// Byte offset of the audio data in the file.
+    #[inline]
+    pub fn data_offset(&self) -> u64 {
+        self.data_offset
+    }
+
+    #[inline]
+    pub fn data_byte_count(&self) -> u64 {
+        self.data_len
+    }
+
+    /// Packet descriptions with offsets relative to the audio data start.
+    ///
+    /// Empty for constant bit rate formats.
+    #[inline]
+    pub fn packet_descs(&self) -> &[StreamPacketDesc] {
+        &self.packet_descs
+    }
+
+    pub fn packets_num(&self) -> u64 {
+        if self.is_vbr() {
+            self.packet_descs.len() as u64
+        } else {
+            self.data_len / self.desc.bytes_per_packet as u64
+        }
+    }
+
+    /// Total number of frames in all packets, including priming and remainder.
+    pub fn frames_num(&self) -> u64 {
+        if self.desc.frames_per_packet != 0 {
+            self.packets_num() * self.desc.frames_per_packet as u64
+        } else {
+            self.packet_descs
+                .iter()
+                .map(|p| p.variable_frames_in_packet as u64)
+                .sum()
+        }
+    }
+
+    /// Reads up to `max` packets starting with `start` packet into `buf`.
+    ///
+    /// `buf` is cleared. For variable bit rate formats `descs` is filled with
+    /// offsets relative to `buf`, otherwise it is left empty.
+    /// Returns the number of packets read.
+    pub fn read_packets(
+        &mut self,
+        start: u64,
+        max: usize,
+        buf: &mut Vec<u8>,
+        descs: &mut Vec<StreamPacketDesc>,
+    ) -> Result<usize> {
+        buf.clear();
+        descs.clear();
+        let total = self.packets_num();
+        if start >= total || max == 0 {
+            return Ok(0);
+        }
+        let n = (total - start).min(max as u64) as usize;
+        let (offset, len) = if self.is_vbr() {
+            let packets = &self.packet_descs[start as usize..start as usize + n];
+            let first = packets[0].start_offset;
+            let last = packets[n - 1];
+            descs.extend(packets.iter().map(|p| StreamPacketDesc {
+                start_offset: p.start_offset - first,
+                ..*p
+            }));
+            (
+                first as u64,
+                (last.start_offset - first) as u64 + last.data_byte_size as u64,
+            )
+        } else {
+            let bpp = self.desc.bytes_per_packet as u64;
+            (start * bpp, n as u64 * bpp)
+        };
+        self.inner
+            .seek(SeekFrom::Start(self.data_offset + offset))?;
+        buf.resize(len as usize, 0);
+        self.inner.read_exact(buf)?;
+        Ok(n)
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Muxes CAF file.
+///
+/// `desc`, `chan`, `kuki` and `info` chunks are written before the first
+/// packet, `pakt`, `mark` and `regn` go after audio data on [`Writer::finish`].
+pub struct Writer<W: Write + Seek> {
+    inner: W,
+    desc: StreamBasicDesc,
+    magic_cookie: Option<Vec<u8>>,
+    channel_layout: Option<ChannelLayout>,
+    info: Vec<(String, String)>,
+    markers: Option<Markers>,
+    regions: Option<Regions>,
+    packet_table_info: Option<PacketTableInfo>,
+    /// Position of `data` chunk size, once header is written.
+    data_size_pos: Option<u64>,
+    data_len: u64,
+    packets_num: u64,
+    frames_num: u64,
+    pakt: Vec<u8>,
+}
+
+impl<W: Write + Seek> Writer<W> {
+    pub fn new(inner: W, desc: &StreamBasicDesc) -> Result<Self> {
+        desc_to_caf(desc)?;
+        Ok(Self {
+            inner,
+            desc: *desc,
+            magic_cookie: None,
+            channel_layout: None,
+            info: Vec::new(),
+            markers: None,
+            regions: None,
+            packet_table_info: None,
+            data_size_pos: None,
+            data_len: 0,
+            packets_num: 0,
+            frames_num: 0,
+            pakt: Vec::new(),
+        })
+    }
+
+    fn is_vbr(&self) -> bool {
+        self.desc.bytes_per_packet == 0 || self.desc.frames_per_packet == 0
+    }
+
+    fn ensure_header_not_written(&self) -> Result {
+        if self.data_size_pos.is_some() {
+            return Err(Error::DataStarted);
+        }
+        Ok(())
+    }
+
+    pub fn set_magic_cookie(&mut self, val: &[u8]) -> Result {
+        self.ensure_header_not_written()?;
+        self.magic_cookie = Some(val.to_vec());
+        Ok(())
+    }
+
+    pub fn set_channel_layout(&mut self, val: ChannelLayout) -> Result {
+        self.ensure_header_not_written()?;
+        self.channel_layout = Some(val);
+        Ok(())
+    }
+
+    pub fn add_info(&mut self, key: &str, value: &str) -> Result {
+        self.ensure_header_not_written()?;
+        self.info.push((key.to_string(), value.to_string()));
+        Ok(())
+    }
+
+    pub fn set_markers(&mut self, val: Markers) {
+        self.markers = Some(val);
+    }
+
+    pub fn set_regions(&mut self, val: Regions) {
+        self.regions = Some(val);
+    }
+
+    /// Priming and remainder frames. If not set all frames are valid.
+    pub fn set_packet_table_info(&mut self, val: PacketTableInfo) {
+        self.packet_table_info = Some(val);
+    }
+
+    fn write_header(&mut self) -> Result<u64> {
+        if let Some(pos) = self.data_size_pos {
+            return Ok(pos);
+        }
+        let w = &mut self.inner;
+        w.write_all(&FILE_TYPE)?;
+        w.write_all(&FILE_VERSION.to_be_bytes())?;
+        w.write_all(&0u16.to_be_bytes())?;
+        write_chunk(w, DESC, &desc_to_caf(&self.desc)?)?;
+        let mut body = Vec::new();
+        if let Some(layout) = &self.channel_layout {
+            write_chan(&mut body, layout);
+            write_chunk(w, CHAN, &body)?;
+        }
+        if let Some(cookie) = &self.magic_cookie {
+            write_chunk(w, KUKI, cookie)?;
+        }
+        if !self.info.is_empty() {
+            body.clear();
+            write_info(&mut body, &self.info);
+            write_chunk(w, INFO, &body)?;
+        }
+        w.write_all(&DATA)?;
+        let pos = w.stream_position()?;
+        w.write_all(&(-1i64).to_be_bytes())?;
+        // edit count
+        w.write_all(&0u32.to_be_bytes())?;
+        self.data_size_pos = Some(pos);
+        Ok(pos)
+    }
+
+    /// Appends packets from `data`.
+    ///
+    /// `descs` are required for variable bit rate formats, offsets are relative
+    /// to `data`. Constant bit rate data must be whole packets.
+    pub fn write_packets(&mut self, data: &[u8], descs: &[StreamPacketDesc]) -> Result {
+        self.write_header()?;
+        if !self.is_vbr() {
+            let bpp = self.desc.bytes_per_packet as usize;
+            if !data.len().is_multiple_of(bpp) {
+                return Err(Error::InvalidFormat("partial packet"));
+            }
+            self.inner.write_all(data)?;
+            self.data_len += data.len() as u64;
+            self.packets_num += (data.len() / bpp) as u64;
+            self.frames_num = self.packets_num * self.desc.frames_per_packet as u64;
+            return Ok(());
+        }
+
+        if descs.is_empty() && !data.is_empty() {
+            return Err(Error::InvalidFormat("packet descriptions are required"));
+        }
+        for d in descs {
+            let start = usize::try_from(d.start_offset)
+                .map_err(|_| Error::InvalidFormat("negative packet offset"))?;
+            let bytes = data
+                .get(start..start + d.data_byte_size as usize)
+                .ok_or(Error::InvalidFormat("packet is out of data"))?;
+            if self.desc.bytes_per_packet != 0 && bytes.len() != self.desc.bytes_per_packet as usize
+            {
+                return Err(Error::InvalidFormat("packet size mismatch"));
+            }
+            if self.desc.frames_per_packet == 0 && d.variable_frames_in_packet == 0 {
+                return Err(Error::InvalidFormat("packet frames are required"));
+            }
+        }
+        for d in descs {
+            let start = d.start_offset as usize;
+            self.inner
+                .write_all(&data[start..start + d.data_byte_size as usize])?;
+            self.data_len += d.data_byte_size as u64;
+            if self.desc.bytes_per_packet == 0 {
+                write_var_int(&mut self.pakt, d.data_byte_size as u64);
+            }
+            if self.desc.frames_per_packet == 0 {
+                write_var_int(&mut self.pakt, d.variable_frames_in_packet as u64);
+                self.frames_num += d.variable_frames_in_packet as u64;
+            } else {
+                self.frames_num += self.desc.frames_per_packet as u64;
+            }
+            self.packets_num += 1;
+        }
+        Ok(())
+    }
+
+    /// Patches `data` chunk size and writes trailing chunks.
+    pub fn finish(mut self) -> Result<W> {
+        let size_pos = self.write_header()?;
+        let end = self.inner.stream_position()?;
+        self.inner.seek(SeekFrom::Start(size_pos))?;
+        self.inner
+            .write_all(&(self.data_len as i64 + 4).to_be_bytes())?;
+        self.inner.seek(SeekFrom::Start(end))?;
+
+        if self.is_vbr() || self.packet_table_info.is_some() {
+            let info = self.packet_table_info.unwrap_or(PacketTableInfo {
+                valid_frames: self.frames_num as i64,
+                ..Default::default()
+            });
+            let mut body = Vec::with_capacity(24 + self.pakt.len());
+            body.extend_from_slice(&(self.packets_num as i64).to_be_bytes());
+            body.extend_from_slice(&info.valid_frames.to_be_bytes());
+            body.extend_from_slice(&info.priming_frames.to_be_bytes());
+            body.extend_from_slice(&info.remainder_frames.to_be_bytes());
+            body.extend_from_slice(&self.pakt);
+            write_chunk(&mut self.inner, PAKT, &body)?;
+        }
+        let mut body = Vec::new();
+        if let Some(markers) = &self.markers {
+            write_mark(&mut body, markers);
+            write_chunk(&mut self.inner, MARK, &body)?;
+        }
+        if let Some(regions) = &self.regions {
+            body.clear();
+            write_regn(&mut body, regions);
+            write_chunk(&mut self.inner, REGN, &body)?;
+        }
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::cat::audio::{
+        self, ChannelLabel, ChannelLayoutTag, Format, FormatFlags, SMPTETimeType, StreamBasicDesc,
+        StreamPacketDesc,
+        caf::{self, Marker, MarkerType, Markers, PacketTableInfo, Region, RegionFlags, Regions},
+    };
+
+    fn pcm_i16(channels: u32) -> StreamBasicDesc {
+        StreamBasicDesc {
+            sample_rate: 48_000.0,
+            format: Format::LINEAR_PCM,
+            format_flags: FormatFlags::IS_SIGNED_INTEGER | FormatFlags::IS_PACKED,
+            bytes_per_packet: 2 * channels,
+            frames_per_packet: 1,
+            bytes_per_frame: 2 * channels,
+            channels_per_frame: channels,
+            bits_per_channel: 16,
+            reserved: 0,
+        }
+    }
+
+    fn aac() -> StreamBasicDesc {
+        StreamBasicDesc {
+            sample_rate: 44_100.0,
+            format: Format::MPEG4_AAC,
+            frames_per_packet: 1024,
+            channels_per_frame: 2,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn var_int() {
+        let mut b = Vec::new();
+        for v in [0, 127, 128, 300, u32::MAX as u64] {
+            caf::write_var_int(&mut b, v);
+        }
+        assert_eq!(
+            b,
+            [
+                0x00, 0x7f, 0x81, 0x00, 0x82, 0x2c, 0x8f, 0xff, 0xff, 0xff, 0x7f
+            ]
+        );
+        let mut pos = 0;
+        for v in [0, 127, 128, 300, u32::MAX as u64] {
+            assert_eq!(caf::read_var_int(&b, &mut pos), Some(v));
+        }
+        assert_eq!(caf::read_var_int(&[0x80], &mut 0), None);
+    }
+
+    #[test]
+    fn lpcm() {
+        let desc = pcm_i16(2);
+        let mut w = caf::Writer::new(Cursor::new(Vec::new()), &desc).unwrap();
+        let mut layout = caf::ChannelLayout::with_tag(ChannelLayoutTag::STEREO);
+        layout.descs.push(audio::ChannelDesc {
+            channel_label: ChannelLabel::LEFT,
+            ..Default::default()
+        });
+        w.set_channel_layout(layout).unwrap();
+        w.add_info("title", "take 1").unwrap();
+        w.write_packets(&[1, 0, 2, 0, 3, 0, 4, 0], &[]).unwrap();
+        assert!(matches!(
+            w.add_info("artist", "nobody"),
+            Err(caf::Error::DataStarted)
+        ));
+        assert!(w.write_packets(&[1, 2, 3], &[]).is_err());
+        w.set_markers(Markers {
+            smpte_time_type: Some(SMPTETimeType::_25),
+            list: vec![Marker {
+                type_: MarkerType::INDEX,
+                frame_pos: 1.0,
+                id: 7,
+                ..Default::default()
+            }],
+        });
+        w.set_regions(Regions {
+            smpte_time_type: None,
+            list: vec![Region {
+                id: 1,
+                flags: RegionFlags::LOOP_ENABLE | RegionFlags::PLAY_FORWARD,
+                markers: vec![Marker {
+                    type_: MarkerType::REGION_START,
+                    ..Default::default()
+                }],
+            }],
+        });
+        let file = w.finish().unwrap().into_inner();
+
+        assert_eq!(&file[..8], b"caff\0\x01\0\0");
+        assert_eq!(&file[8..20], b"desc\0\0\0\0\0\0\0\x20");
+        // little endian integer samples
+        assert_eq!(&file[28..36], b"lpcm\0\0\0\x02");
+
+        let mut r = caf::Reader::new(Cursor::new(file)).unwrap();
+        assert_eq!(r.desc(), &desc);
+        assert_eq!(r.packets_num(), 2);
+        assert_eq!(r.frames_num(), 2);
+        assert_eq!(r.info(), [("title".to_string(), "take 1".to_string())]);
+        let layout = r.channel_layout().unwrap();
+        assert_eq!(layout.tag, ChannelLayoutTag::STEREO);
+        assert_eq!(layout.descs[0].channel_label, ChannelLabel::LEFT);
+        let layout = layout.to_layout::<1>().unwrap();
+        assert_eq!(layout.number_channel_descriptions, 1);
+        assert_eq!(r.markers().unwrap().list[0].id, 7);
+        assert_eq!(
+            r.markers().unwrap().smpte_time_type,
+            Some(SMPTETimeType::_25)
+        );
+        assert_eq!(r.regions().unwrap().list[0].markers.len(), 1);
+        assert!(r.packet_table_info().is_none());
+
+        let mut buf = Vec::new();
+        let mut descs = Vec::new();
+        assert_eq!(r.read_packets(1, 10, &mut buf, &mut descs).unwrap(), 1);
+        assert_eq!(buf, [3, 0, 4, 0]);
+        assert!(descs.is_empty());
+    }
+
+    #[test]
+    fn vbr() {
+        let mut w = caf::Writer::new(Cursor::new(Vec::new()), &aac()).unwrap();
+        w.set_magic_cookie(&[0x12, 0x10]).unwrap();
+        w.set_packet_table_info(PacketTableInfo {
+            valid_frames: 3 * 1024 - 2112 - 100,
+            priming_frames: 2112,
+            remainder_frames: 100,
+        });
+        let data = [0u8; 400];
+        let descs = [
+            StreamPacketDesc {
+                start_offset: 0,
+                variable_frames_in_packet: 0,
+                data_byte_size: 100,
+            },
+            StreamPacketDesc {
+                start_offset: 100,
+                variable_frames_in_packet: 0,
+                data_byte_size: 300,
+            },
+        ];
+        w.write_packets(&data, &descs).unwrap();
+        w.write_packets(&[], &[]).unwrap();
+        assert!(w.write_packets(&data[..7], &[]).is_err());
+        w.write_packets(
+            &data[..7],
+            &[StreamPacketDesc {
+                start_offset: 0,
+                variable_frames_in_packet: 0,
+                data_byte_size: 7,
+            }],
+        )
+        .unwrap();
+        let file = w.finish().unwrap().into_inner();
+
+        let mut r = caf::Reader::new(Cursor::new(file)).unwrap();
+        assert_eq!(r.desc(), &aac());
+        assert_eq!(r.magic_cookie(), Some(&[0x12, 0x10][..]));
+        assert_eq!(r.packets_num(), 3);
+        assert_eq!(r.frames_num(), 3 * 1024);
+        assert_eq!(r.packet_table_info().unwrap().priming_frames, 2112);
+        assert_eq!(r.packet_descs()[2].start_offset, 400);
+
+        let mut buf = Vec::new();
+        let mut descs = Vec::new();
+        assert_eq!(r.read_packets(1, 2, &mut buf, &mut descs).unwrap(), 2);
+        assert_eq!(buf.len(), 307);
+        assert_eq!(descs[0].start_offset, 0);
+        assert_eq!(descs[1].start_offset, 300);
+        assert_eq!(descs[1].data_byte_size, 7);
+    }
+
+    #[test]
+    fn unfinished() {
+        let desc = pcm_i16(1);
+        let mut file = Cursor::new(Vec::new());
+        {
+            let mut w = caf::Writer::new(&mut file, &desc).unwrap();
+            w.write_packets(&[1, 2, 3, 4, 5, 6], &[]).unwrap();
+            // dropped without finish, like after a crash
+        }
+        let mut r = caf::Reader::new(Cursor::new(file.into_inner())).unwrap();
+        assert_eq!(r.packets_num(), 3);
+        let mut buf = Vec::new();
+        r.read_packets(0, 3, &mut buf, &mut Vec::new()).unwrap();
+        assert_eq!(buf, [1, 2, 3, 4, 5, 6]);
+
+        let mut w = caf::Writer::new(Cursor::new(Vec::new()), &aac()).unwrap();
+        w.write_packets(
+            &[1],
+            &[StreamPacketDesc {
+                start_offset: 0,
+                variable_frames_in_packet: 0,
+                data_byte_size: 1,
+            }],
+        )
+        .unwrap();
+        let file = w.inner.get_ref().clone();
+        assert!(matches!(
+            caf::Reader::new(Cursor::new(file)),
+            Err(caf::Error::MissingChunk(id)) if &id == b"pakt"
+        ));
+    }
+
+    #[test]
+    fn invalid() {
+        let mut desc = pcm_i16(2);
+        desc.format_flags |= FormatFlags::IS_NON_INTERLEAVED;
+        assert!(caf::Writer::new(Cursor::new(Vec::new()), &desc).is_err());
+
+        assert!(matches!(
+            caf::Reader::new(Cursor::new(b"RIFF\0\0\0\0WAVE".to_vec())),
+            Err(caf::Error::NotCaf)
+        ));
+        assert!(matches!(
+            caf::Reader::new(Cursor::new(b"caff\0\x01\0\0".to_vec())),
+            Err(caf::Error::MissingChunk(_))
+        ));
+
+        // lpcm fields which overflow u32 when multiplied
+        let mut b = [0u8; 32];
+        b[8..12].copy_from_slice(b"lpcm");
+        b[24..28].copy_from_slice(&0x1000_0000u32.to_be_bytes());
+        b[28..32].copy_from_slice(&16u32.to_be_bytes());
+        let desc = caf::desc_from_caf(&b);
+        assert!(!desc.format_flags.contains(FormatFlags::IS_PACKED));
+        b[16..20].copy_from_slice(&0x2000_0000u32.to_be_bytes());
+        let desc = caf::desc_from_caf(&b);
+        assert!(desc.format_flags.contains(FormatFlags::IS_PACKED));
+        b[16..20].copy_from_slice(&u32::MAX.to_be_bytes());
+        b[28..32].copy_from_slice(&u32::MAX.to_be_bytes());
+        let desc = caf::desc_from_caf(&b);
+        assert!(!desc.format_flags.contains(FormatFlags::IS_PACKED));
+    }
+}
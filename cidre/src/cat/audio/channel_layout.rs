@@ -0,0 +1,676 @@
+//! Channel layout tag tables and remapping without AudioToolbox.
+//!
+//! Tags expand to the same ordered labels as `kAudioFormatProperty_ChannelLayoutForTag`,
+//! [`MixMatrix`] folds missing speakers to their neighbours with ITU-R BS.775 coefficients.
+
+use crate::cat::audio::{
+    ChannelBitmap, ChannelDesc, ChannelLabel as Label, ChannelLayout, ChannelLayoutTag as Tag,
+};
+
+const L: Label = Label::LEFT;
+const R: Label = Label::RIGHT;
+const C: Label = Label::CENTER;
+const LFE: Label = Label::LFE_SCREEN;
+const LFE2: Label = Label::LFE2;
+const LS: Label = Label::LEFT_SURROUND;
+const RS: Label = Label::RIGHT_SURROUND;
+const LC: Label = Label::LEFT_CENTER;
+const RC: Label = Label::RIGHT_CENTER;
+const CS: Label = Label::CENTER_SURROUND;
+const LSD: Label = Label::LEFT_SURROUND_DIRECT;
+const RSD: Label = Label::RIGHT_SURROUND_DIRECT;
+const CSD: Label = Label::CENTER_SURROUND_DIRECT;
+const TS: Label = Label::TOP_CENTER_SURROUND;
+const VHL: Label = Label::VERTICAL_HEIGHT_LEFT;
+const VHC: Label = Label::VERTICAL_HEIGHT_CENTER;
+const VHR: Label = Label::VERTICAL_HEIGHT_RIGHT;
+const TBL: Label = Label::TOP_BACK_LEFT;
+const TBC: Label = Label::TOP_BACK_CENTER;
+const TBR: Label = Label::TOP_BACK_RIGHT;
+const RLS: Label = Label::REAR_SURROUND_LEFT;
+const RRS: Label = Label::REAR_SURROUND_RIGHT;
+const LW: Label = Label::LEFT_WIDE;
+const RW: Label = Label::RIGHT_WIDE;
+const LT: Label = Label::LEFT_TOTAL;
+const RT: Label = Label::RIGHT_TOTAL;
+const LTM: Label = Label::LEFT_TOP_MIDDLE;
+const RTM: Label = Label::RIGHT_TOP_MIDDLE;
+const LTR: Label = Label::LEFT_TOP_REAR;
+const CTR: Label = Label::CENTER_TOP_REAR;
+const RTR: Label = Label::RIGHT_TOP_REAR;
+
+/// Fixed layout tags with their ordered labels, aliases resolve to the same entries.
+static LAYOUTS: &[(Tag, &[Label])] = &[
+    (Tag::MONO, &[Label::MONO]),
+    (Tag::STEREO, &[L, R]),
+    (
+        Tag::STEREO_HEADPHONES,
+        &[Label::HEADPHONES_LEFT, Label::HEADPHONES_RIGHT],
+    ),
+    (Tag::MATRIX_STEREO, &[LT, RT]),
+    (Tag::MID_SIDE, &[Label::MS_MID, Label::MS_SIDE]),
+    (Tag::XY, &[Label::XY_X, Label::XY_Y]),
+    (
+        Tag::BINAURAL,
+        &[Label::BINAURAL_LEFT, Label::BINAURAL_RIGHT],
+    ),
+    (
+        Tag::AMBISONIC_B_FORMAT,
+        &[
+            Label::AMBISONIC_W,
+            Label::AMBISONIC_X,
+            Label::AMBISONIC_Y,
+            Label::AMBISONIC_Z,
+        ],
+    ),
+    (Tag::QUADRAPHONIC, &[L, R, LS, RS]),
+    (Tag::PENTAGONAL, &[L, R, LS, RS, C]),
+    (Tag::HEXAGONAL, &[L, R, LS, RS, C, CS]),
+    (Tag::OCTAGONAL, &[L, R, LS, RS, C, CS, LW, RW]),
+    (Tag::CUBE, &[L, R, LS, RS, VHL, VHR, TBL, TBR]),
+    (Tag::MPEG_3_0_A, &[L, R, C]),
+    (Tag::MPEG_3_0_B, &[C, L, R]),
+    (Tag::MPEG_4_0_A, &[L, R, C, CS]),
+    (Tag::MPEG_4_0_B, &[C, L, R, CS]),
+    (Tag::MPEG_5_0_A, &[L, R, C, LS, RS]),
+    (Tag::MPEG_5_0_B, &[L, R, LS, RS, C]),
+    (Tag::MPEG_5_0_C, &[L, C, R, LS, RS]),
+    (Tag::MPEG_5_0_D, &[C, L, R, LS, RS]),
+    (Tag::MPEG_5_1_A, &[L, R, C, LFE, LS, RS]),
+    (Tag::MPEG_5_1_B, &[L, R, LS, RS, C, LFE]),
+    (Tag::MPEG_5_1_C, &[L, C, R, LS, RS, LFE]),
+    (Tag::MPEG_5_1_D, &[C, L, R, LS, RS, LFE]),
+    (Tag::MPEG_6_1_A, &[L, R, C, LFE, LS, RS, CS]),
+    (Tag::MPEG_7_1_A, &[L, R, C, LFE, LS, RS, LC, RC]),
+    (Tag::MPEG_7_1_B, &[C, LC, RC, L, R, LS, RS, LFE]),
+    (Tag::MPEG_7_1_C, &[L, R, C, LFE, LS, RS, RLS, RRS]),
+    (Tag::EMAGIC_DEFAULT_7_1, &[L, R, LS, RS, C, LFE, LC, RC]),
+    (Tag::SMPTE_DTV, &[L, R, C, LFE, LS, RS, LT, RT]),
+    (Tag::ITU_2_1, &[L, R, CS]),
+    (Tag::ITU_2_2, &[L, R, LS, RS]),
+    (Tag::DVD_4, &[L, R, LFE]),
+    (Tag::DVD_5, &[L, R, LFE, CS]),
+    (Tag::DVD_6, &[L, R, LFE, LS, RS]),
+    (Tag::DVD_10, &[L, R, C, LFE]),
+    (Tag::DVD_11, &[L, R, C, LFE, CS]),
+    (Tag::DVD_18, &[L, R, LS, RS, LFE]),
+    (Tag::AUDIO_UNIT_6_0, &[L, R, LS, RS, C, CS]),
+    (Tag::AUDIO_UNIT_7_0, &[L, R, LS, RS, C, RLS, RRS]),
+    (Tag::AUDIO_UNIT_7_0_FRONT, &[L, R, LS, RS, C, LC, RC]),
+    (Tag::AAC_6_0, &[C, L, R, LS, RS, CS]),
+    (Tag::AAC_6_1, &[C, L, R, LS, RS, CS, LFE]),
+    (Tag::AAC_7_0, &[C, L, R, LS, RS, RLS, RRS]),
+    (Tag::AAC_7_1_B, &[C, L, R, LS, RS, RLS, RRS, LFE]),
+    (Tag::AAC_7_1_C, &[C, L, R, LS, RS, LFE, VHL, VHR]),
+    (Tag::AAC_OCTAGONAL, &[C, L, R, LS, RS, RLS, RRS, CS]),
+    (
+        Tag::TMH_10_2_STD,
+        &[
+            L, R, C, VHC, LSD, RSD, LS, RS, VHL, VHR, LW, RW, CSD, CS, LFE, LFE2,
+        ],
+    ),
+    (
+        Tag::TMH_10_2_FULL,
+        &[
+            L,
+            R,
+            C,
+            VHC,
+            LSD,
+            RSD,
+            LS,
+            RS,
+            VHL,
+            VHR,
+            LW,
+            RW,
+            CSD,
+            CS,
+            LFE,
+            LFE2,
+            LC,
+            RC,
+            Label::HEARING_IMPAIRED,
+            Label::NARRATION,
+            Label::HAPTIC,
+        ],
+    ),
+    (Tag::AC3_1_0_1, &[C, LFE]),
+    (Tag::AC3_3_0, &[L, C, R]),
+    (Tag::AC3_3_1, &[L, C, R, CS]),
+    (Tag::AC3_3_0_1, &[L, C, R, LFE]),
+    (Tag::AC3_2_1_1, &[L, R, CS, LFE]),
+    (Tag::AC3_3_1_1, &[L, C, R, CS, LFE]),
+    (Tag::EAC_6_0_A, &[L, C, R, LS, RS, CS]),
+    (Tag::EAC_7_0_A, &[L, C, R, LS, RS, RLS, RRS]),
+    (Tag::EAC3_6_1_A, &[L, C, R, LS, RS, LFE, CS]),
+    (Tag::EAC3_6_1_B, &[L, C, R, LS, RS, LFE, TS]),
+    (Tag::EAC3_6_1_C, &[L, C, R, LS, RS, LFE, VHC]),
+    (Tag::EAC3_7_1_A, &[L, C, R, LS, RS, LFE, RLS, RRS]),
+    (Tag::EAC3_7_1_B, &[L, C, R, LS, RS, LFE, LC, RC]),
+    (Tag::EAC3_7_1_C, &[L, C, R, LS, RS, LFE, LSD, RSD]),
+    (Tag::EAC3_7_1_D, &[L, C, R, LS, RS, LFE, LW, RW]),
+    (Tag::EAC3_7_1_E, &[L, C, R, LS, RS, LFE, VHL, VHR]),
+    (Tag::EAC3_7_1_F, &[L, C, R, LS, RS, LFE, CS, TS]),
+    (Tag::EAC3_7_1_G, &[L, C, R, LS, RS, LFE, CS, VHC]),
+    (Tag::EAC3_7_1_H, &[L, C, R, LS, RS, LFE, TS, VHC]),
+    (Tag::DTS_3_1, &[C, L, R, LFE]),
+    (Tag::DTS_4_1, &[C, L, R, CS, LFE]),
+    (Tag::DTS_6_0_A, &[LC, RC, L, R, LS, RS]),
+    (Tag::DTS_6_0_B, &[C, L, R, RLS, RRS, TS]),
+    (Tag::DTS_6_0_C, &[C, CS, L, R, RLS, RRS]),
+    (Tag::DTS_6_1_A, &[LC, RC, L, R, LS, RS, LFE]),
+    (Tag::DTS_6_1_B, &[C, L, R, RLS, RRS, TS, LFE]),
+    (Tag::DTS_6_1_C, &[C, CS, L, R, RLS, RRS, LFE]),
+    (Tag::DTS_7_0, &[LC, C, RC, L, R, LS, RS]),
+    (Tag::DTS_7_1, &[LC, C, RC, L, R, LS, RS, LFE]),
+    (Tag::DTS_8_0_A, &[LC, RC, L, R, LS, RS, RLS, RRS]),
+    (Tag::DTS_8_0_B, &[LC, C, RC, L, R, LS, CS, RS]),
+    (Tag::DTS_8_1_A, &[LC, RC, L, R, LS, RS, RLS, RRS, LFE]),
+    (Tag::DTS_8_1_B, &[LC, C, RC, L, R, LS, CS, RS, LFE]),
+    (Tag::DTS_6_1_D, &[C, L, R, LS, RS, LFE, CS]),
+    (Tag::WAVE_4_0_B, &[L, R, RLS, RRS]),
+    (Tag::WAVE_5_0_B, &[L, R, C, RLS, RRS]),
+    (Tag::WAVE_5_1_B, &[L, R, C, LFE, RLS, RRS]),
+    (Tag::WAVE_6_1, &[L, R, C, LFE, CS, LS, RS]),
+    (Tag::WAVE_7_1, &[L, R, C, LFE, RLS, RRS, LS, RS]),
+    (Tag::ATMOS_5_1_2, &[L, R, C, LFE, LS, RS, LTM, RTM]),
+    (
+        Tag::ATMOS_5_1_4,
+        &[L, R, C, LFE, LS, RS, VHL, VHR, LTR, RTR],
+    ),
+    (
+        Tag::ATMOS_7_1_2,
+        &[L, R, C, LFE, LS, RS, RLS, RRS, LTM, RTM],
+    ),
+    (
+        Tag::ATMOS_7_1_4,
+        &[L, R, C, LFE, LS, RS, RLS, RRS, VHL, VHR, LTR, RTR],
+    ),
+    (
+        Tag::ATMOS_9_1_6,
+        &[
+            L, R, C, LFE, LS, RS, RLS, RRS, LW, RW, VHL, VHR, LTM, RTM, LTR, RTR,
+        ],
+    ),
+    (Tag::LOGIC_4_0_C, &[L, R, CS, C]),
+    (Tag::LOGIC_6_0_B, &[L, R, LS, RS, CS, C]),
+    (Tag::LOGIC_6_1_B, &[L, R, LS, RS, CS, C, LFE]),
+    (Tag::LOGIC_6_1_D, &[L, C, R, LS, CS, RS, LFE]),
+    (Tag::LOGIC_7_1_B, &[L, R, LS, RS, RLS, RRS, C, LFE]),
+    (
+        Tag::LOGIC_ATMOS_7_1_4_B,
+        &[L, R, RLS, RRS, LS, RS, C, LFE, VHL, VHR, LTR, RTR],
+    ),
+    (
+        Tag::LOGIC_ATMOS_7_1_6,
+        &[L, R, RLS, RRS, LS, RS, C, LFE, VHL, VHR, LTM, RTM, LTR, RTR],
+    ),
+];
+
+impl Tag {
+    /// Ordered labels of tags with fixed channels.
+    pub fn labels(&self) -> Option<&'static [Label]> {
+        LAYOUTS
+            .iter()
+            .find(|(tag, _)| tag == self)
+            .map(|(_, labels)| *labels)
+    }
+
+    /// Labels of fixed tags, discrete, HOA and unknown layouts.
+    ///
+    /// `None` for [`Tag::USE_CHANNEL_BITMAP`], [`Tag::USE_CHANNEL_DESCRIPTIONS`]
+    /// and unknown tags.
+    pub fn expand(&self) -> Option<Vec<Label>> {
+        if let Some(labels) = self.labels() {
+            return Some(labels.to_vec());
+        }
+        let n = self.number_of_channels();
+        let indexed = |base: Label| (0..n).map(|i| Label(base.0 | i)).collect();
+        match Tag(self.0 & 0xffff_0000) {
+            Tag::DISCRETE_IN_ORDER => Some(indexed(Label::DISCRETE_0)),
+            Tag::HOA_ACN_SN3D => Some(indexed(Label::HOA_SN3D)),
+            Tag::HOA_ACN_N3D => Some(indexed(Label::HOA_N3D)),
+            Tag::UNKNOWN => Some(vec![Label::UNKNOWN; n as usize]),
+            _ => None,
+        }
+    }
+
+    /// The first tag with exactly these labels in this order.
+    pub fn with_labels(labels: &[Label]) -> Option<Self> {
+        if labels.is_empty() || labels.len() > 0xffff {
+            return None;
+        }
+        if let Some((tag, _)) = LAYOUTS.iter().find(|(_, l)| *l == labels) {
+            return Some(*tag);
+        }
+        let n = labels.len() as u32;
+        [
+            (Tag::DISCRETE_IN_ORDER, Label::DISCRETE_0),
+            (Tag::HOA_ACN_SN3D, Label::HOA_SN3D),
+            (Tag::HOA_ACN_N3D, Label::HOA_N3D),
+        ]
+        .into_iter()
+        .find(|(_, base)| (0..n).all(|i| labels[i as usize] == Label(base.0 | i)))
+        .map(|(tag, _)| Tag(tag.0 | n))
+    }
+
+    pub fn with_bitmap(bitmap: ChannelBitmap) -> Option<Self> {
+        Self::with_labels(&bitmap.labels())
+    }
+
+    /// Bitmap of the tag if its labels are in bitmap order.
+    pub fn bitmap(&self) -> Option<ChannelBitmap> {
+        ChannelBitmap::with_labels(self.labels()?)
+    }
+}
+
+impl Label {
+    /// Bit of the label in channel bitmap.
+    pub const fn bitmap(&self) -> Option<ChannelBitmap> {
+        match self.0 {
+            1..=18 => Some(ChannelBitmap(1 << (self.0 - 1))),
+            49 | 51..=54 => Some(ChannelBitmap(1 << (self.0 - 28))),
+            _ => None,
+        }
+    }
+
+    /// Low frequency effects channel.
+    #[inline]
+    pub const fn is_lfe(&self) -> bool {
+        self.0 == LFE.0 || self.0 == LFE2.0
+    }
+}
+
+impl ChannelBitmap {
+    #[inline]
+    pub const fn channels_num(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Labels in bitmap order, the order of WAVE channel mask.
+    pub fn labels(&self) -> Vec<Label> {
+        (0..32)
+            .filter(|bit| self.0 & (1 << bit) != 0)
+            .filter_map(|bit| match bit {
+                0..=17 => Some(Label(bit + 1)),
+                21 | 23..=26 => Some(Label(bit + 28)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Bitmap for labels in bitmap order, `None` if some label has no bit or
+    /// the order differs.
+    pub fn with_labels(labels: &[Label]) -> Option<Self> {
+        let mut res = 0u32;
+        for label in labels {
+            let bit = label.bitmap()?.0;
+            if bit <= res {
+                return None;
+            }
+            res |= bit;
+        }
+        Some(Self(res))
+    }
+}
+
+impl ChannelDesc {
+    #[inline]
+    pub fn with_label(label: Label) -> Self {
+        Self {
+            channel_label: label,
+            ..Default::default()
+        }
+    }
+}
+
+/// Ordered labels of layout given by tag, bitmap or descriptions.
+pub fn resolve(tag: Tag, bitmap: ChannelBitmap, descs: &[ChannelDesc]) -> Option<Vec<Label>> {
+    match tag {
+        Tag::USE_CHANNEL_DESCRIPTIONS if !descs.is_empty() => {
+            Some(descs.iter().map(|d| d.channel_label).collect())
+        }
+        Tag::USE_CHANNEL_DESCRIPTIONS => None,
+        Tag::USE_CHANNEL_BITMAP => Some(bitmap.labels()),
+        tag => tag.expand(),
+    }
+}
+
+impl<const N: usize> ChannelLayout<N> {
+    pub fn labels(&self) -> Option<Vec<Label>> {
+        let n = (self.number_channel_descriptions as usize).min(N);
+        resolve(
+            self.channel_layout_tag,
+            self.channel_bitmap,
+            &self.channel_descriptions[..n],
+        )
+    }
+}
+
+const SQRT1_2: f32 = std::f32::consts::FRAC_1_SQRT_2;
+
+/// Where the sound of missing speaker goes, options are tried in order and the first one
+/// with all speakers present is used. Otherwise the last option is folded further.
+fn folds(label: Label) -> &'static [&'static [(Label, f32)]] {
+    match label {
+        L | Label::LEFT_TOTAL | Label::HEADPHONES_LEFT | Label::BINAURAL_LEFT => match label {
+            L => &[&[(C, SQRT1_2)], &[(Label::MONO, SQRT1_2)]],
+            _ => &[&[(L, 1.0)]],
+        },
+        R | Label::RIGHT_TOTAL | Label::HEADPHONES_RIGHT | Label::BINAURAL_RIGHT => match label {
+            R => &[&[(C, SQRT1_2)], &[(Label::MONO, SQRT1_2)]],
+            _ => &[&[(R, 1.0)]],
+        },
+        C => &[&[(L, SQRT1_2), (R, SQRT1_2)], &[(Label::MONO, 1.0)]],
+        Label::MONO => &[&[(C, 1.0)], &[(L, SQRT1_2), (R, SQRT1_2)]],
+        LC => &[&[(L, SQRT1_2), (C, SQRT1_2)], &[(L, 1.0)]],
+        RC => &[&[(R, SQRT1_2), (C, SQRT1_2)], &[(R, 1.0)]],
+        LW => &[&[(L, SQRT1_2), (LS, SQRT1_2)], &[(L, 1.0)]],
+        RW => &[&[(R, SQRT1_2), (RS, SQRT1_2)], &[(R, 1.0)]],
+        LS => &[&[(RLS, 1.0)], &[(L, SQRT1_2)]],
+        RS => &[&[(RRS, 1.0)], &[(R, SQRT1_2)]],
+        LSD => &[&[(LS, 1.0)]],
+        RSD => &[&[(RS, 1.0)]],
+        RLS => &[&[(LS, 1.0)], &[(L, SQRT1_2)]],
+        RRS => &[&[(RS, 1.0)], &[(R, SQRT1_2)]],
+        CSD => &[&[(CS, 1.0)]],
+        CS => &[
+            &[(RLS, SQRT1_2), (RRS, SQRT1_2)],
+            &[(LS, SQRT1_2), (RS, SQRT1_2)],
+        ],
+        VHL => &[&[(LTM, 1.0)], &[(L, SQRT1_2)]],
+        VHR => &[&[(RTM, 1.0)], &[(R, SQRT1_2)]],
+        VHC => &[&[(VHL, SQRT1_2), (VHR, SQRT1_2)], &[(C, SQRT1_2)]],
+        TS => &[&[(LTM, SQRT1_2), (RTM, SQRT1_2)], &[(L, 0.5), (R, 0.5)]],
+        LTM => &[&[(VHL, SQRT1_2), (LTR, SQRT1_2)], &[(LS, SQRT1_2)]],
+        RTM => &[&[(VHR, SQRT1_2), (RTR, SQRT1_2)], &[(RS, SQRT1_2)]],
+        TBL => &[&[(LTR, 1.0)]],
+        TBC => &[&[(CTR, 1.0)]],
+        TBR => &[&[(RTR, 1.0)]],
+        LTR => &[&[(TBL, 1.0)], &[(LTM, 1.0)], &[(RLS, SQRT1_2)]],
+        RTR => &[&[(TBR, 1.0)], &[(RTM, 1.0)], &[(RRS, SQRT1_2)]],
+        CTR => &[&[(TBC, 1.0)], &[(LTR, SQRT1_2), (RTR, SQRT1_2)]],
+        LFE => &[&[(LFE2, 1.0)]],
+        LFE2 => &[&[(LFE, 1.0)]],
+        _ => &[],
+    }
+}
+
+/// Gains from source to destination channels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixMatrix {
+    src_channels: usize,
+    dst_channels: usize,
+    /// Row per destination channel.
+    gains: Vec<f32>,
+}
+
+impl MixMatrix {
+    /// Same labels are copied, missing speakers are folded to the nearest
+    /// present ones, e.g. surrounds go to front with -3 dB.
+    ///
+    /// LFE is dropped if destination has no LFE channel, as are labels without
+    /// position like discrete or ambisonic ones.
+    pub fn new(src: &[Label], dst: &[Label]) -> Self {
+        let mut res = Self {
+            src_channels: src.len(),
+            dst_channels: dst.len(),
+            gains: vec![0.0; src.len() * dst.len()],
+        };
+        let mut visited = Vec::new();
+        for (i, label) in src.iter().enumerate() {
+            visited.clear();
+            res.fold(i, *label, 1.0, dst, &mut visited);
+        }
+        res
+    }
+
+    pub fn with_tags(src: Tag, dst: Tag) -> Option<Self> {
+        Some(Self::new(&src.expand()?, &dst.expand()?))
+    }
+
+    fn fold(
+        &mut self,
+        src: usize,
+        label: Label,
+        gain: f32,
+        dst: &[Label],
+        visited: &mut Vec<Label>,
+    ) {
+        if let Some(i) = dst.iter().position(|l| *l == label) {
+            self.gains[i * self.src_channels + src] += gain;
+            return;
+        }
+        if visited.contains(&label) {
+            return;
+        }
+        visited.push(label);
+        let options = folds(label);
+        let option = options
+            .iter()
+            .find(|o| o.iter().all(|(l, _)| dst.contains(l)))
+            .or(options.last());
+        for (l, g) in option.into_iter().flat_map(|o| o.iter()) {
+            self.fold(src, *l, gain * g, dst, visited);
+        }
+        visited.pop();
+    }
+
+    #[inline]
+    pub fn src_channels(&self) -> usize {
+        self.src_channels
+    }
+
+    #[inline]
+    pub fn dst_channels(&self) -> usize {
+        self.dst_channels
+    }
+
+    #[inline]
+    pub fn gain(&self, dst: usize, src: usize) -> f32 {
+        self.gains[dst * self.src_channels + src]
+    }
+
+    /// Row major, `dst_channels` rows of `src_channels` gains.
+    #[inline]
+    pub fn gains(&self) -> &[f32] {
+        &self.gains
+    }
+
+    /// Scales gains so no output can clip for full scale input.
+    pub fn normalized(mut self) -> Self {
+        let max = self
+            .gains
+            .chunks(self.src_channels.max(1))
+            .map(|row| row.iter().map(|g| g.abs()).sum::<f32>())
+            .fold(0.0f32, f32::max);
+        if max > 1.0 {
+            self.gains.iter_mut().for_each(|g| *g /= max);
+        }
+        self
+    }
+
+    /// Mixes interleaved frames, `dst` gets as many frames as `src` has.
+    pub fn mix(&self, src: &[f32], dst: &mut [f32]) {
+        if self.src_channels == 0 || self.dst_channels == 0 {
+            return;
+        }
+        let frames = src.chunks_exact(self.src_channels);
+        for (s, d) in frames.zip(dst.chunks_exact_mut(self.dst_channels)) {
+            for (out, row) in d.iter_mut().zip(self.gains.chunks_exact(self.src_channels)) {
+                *out = row.iter().zip(s).map(|(g, x)| g * x).sum();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cat::audio::{
+        ChannelBitmap, ChannelDesc, ChannelLabel, ChannelLayoutTag, channel_layout::*,
+    };
+
+    #[test]
+    fn tables() {
+        for (tag, labels) in LAYOUTS {
+            assert_eq!(tag.number_of_channels() as usize, labels.len(), "{tag:?}");
+            if let Some(bitmap) = tag.bitmap() {
+                assert_eq!(bitmap.labels(), *labels);
+            }
+        }
+        for (i, (a, _)) in LAYOUTS.iter().enumerate() {
+            assert!(LAYOUTS[..i].iter().all(|(b, _)| a != b), "{a:?}");
+        }
+
+        let tag = ChannelLayoutTag::MPEG_5_1_C;
+        assert_eq!(tag.labels().unwrap(), &[L, C, R, LS, RS, LFE]);
+        assert_eq!(
+            ChannelLayoutTag::DVD_20.labels(),
+            ChannelLayoutTag::MPEG_5_1_B.labels()
+        );
+        assert_eq!(
+            ChannelLayoutTag::with_labels(&[L, R, C, LFE, LS, RS]),
+            Some(ChannelLayoutTag::MPEG_5_1_A)
+        );
+        assert!(tag.bitmap().is_none());
+        assert!(ChannelLayoutTag::USE_CHANNEL_BITMAP.expand().is_none());
+
+        let discrete = ChannelLayoutTag(ChannelLayoutTag::DISCRETE_IN_ORDER.0 | 3);
+        let labels = discrete.expand().unwrap();
+        assert_eq!(
+            labels,
+            [
+                ChannelLabel::DISCRETE_0,
+                ChannelLabel::DISCRETE_1,
+                ChannelLabel::DISCRETE_2
+            ]
+        );
+        assert_eq!(ChannelLayoutTag::with_labels(&labels), Some(discrete));
+        let hoa = ChannelLayoutTag(ChannelLayoutTag::HOA_ACN_SN3D.0 | 4);
+        assert_eq!(
+            ChannelLayoutTag::with_labels(&hoa.expand().unwrap()),
+            Some(hoa)
+        );
+    }
+
+    #[test]
+    fn bitmap() {
+        let bitmap = ChannelBitmap(
+            ChannelBitmap::LEFT.0
+                | ChannelBitmap::RIGHT.0
+                | ChannelBitmap::CENTER.0
+                | ChannelBitmap::LFE_SCREEN.0
+                | ChannelBitmap::LEFT_SURROUND.0
+                | ChannelBitmap::RIGHT_SURROUND.0,
+        );
+        assert_eq!(bitmap.channels_num(), 6);
+        assert_eq!(
+            ChannelLayoutTag::with_bitmap(bitmap),
+            Some(ChannelLayoutTag::MPEG_5_1_A)
+        );
+        assert_eq!(ChannelLayoutTag::MPEG_5_1_A.bitmap(), Some(bitmap));
+        assert_eq!(ChannelLayoutTag::ATMOS_7_1_4.bitmap(), None);
+
+        let top = ChannelBitmap(ChannelBitmap::LEFT_TOP_MIDDLE.0 | ChannelBitmap::RIGHT_TOP_REAR.0);
+        assert_eq!(top.labels(), [LTM, RTR]);
+        assert_eq!(ChannelBitmap::with_labels(&[LTM, RTR]), Some(top));
+        assert_eq!(ChannelBitmap::with_labels(&[R, L]), None);
+        assert_eq!(ChannelBitmap::with_labels(&[L, RLS]), None);
+
+        let descs = [ChannelDesc::with_label(R), ChannelDesc::with_label(L)];
+        assert_eq!(
+            resolve(
+                ChannelLayoutTag::USE_CHANNEL_DESCRIPTIONS,
+                Default::default(),
+                &descs
+            ),
+            Some(vec![R, L])
+        );
+        assert_eq!(
+            resolve(ChannelLayoutTag::USE_CHANNEL_BITMAP, top, &[]),
+            Some(vec![LTM, RTR])
+        );
+    }
+
+    #[test]
+    fn mix() {
+        let m = MixMatrix::with_tags(ChannelLayoutTag::MPEG_5_1_A, ChannelLayoutTag::MPEG_5_1_C)
+            .unwrap();
+        // permutation only
+        assert_eq!(m.gains().iter().filter(|g| **g == 1.0).count(), 6);
+        assert_eq!(m.gains().iter().filter(|g| **g != 0.0).count(), 6);
+        assert_eq!(m.gain(1, 2), 1.0);
+
+        let m =
+            MixMatrix::with_tags(ChannelLayoutTag::ATMOS_7_1_4, ChannelLayoutTag::STEREO).unwrap();
+        let h = std::f32::consts::FRAC_1_SQRT_2;
+        // L R C LFE Ls Rs Rls Rrs Vhl Vhr Ltr Rtr
+        let left = [1.0, 0.0, h, 0.0, h, 0.0, h, 0.0, h, 0.0, 0.5, 0.0];
+        for (i, g) in left.iter().enumerate() {
+            assert!((m.gain(0, i) - g).abs() < 1e-6, "{i}");
+        }
+        for i in 0..12 {
+            let j = match i {
+                2 | 3 => i,
+                _ => i ^ 1,
+            };
+            assert!((m.gain(1, i) - m.gain(0, j)).abs() < 1e-6, "{i}");
+        }
+
+        let m = m.normalized();
+        let row: f32 = (0..12).map(|i| m.gain(0, i)).sum();
+        assert!((row - 1.0).abs() < 1e-6);
+
+        let mut out = [0.0; 4];
+        let m = MixMatrix::new(&[C], &[L, R]);
+        m.mix(&[1.0, 0.5], &mut out);
+        assert!((out[0] - h).abs() < 1e-6 && (out[3] - 0.5 * h).abs() < 1e-6);
+
+        // 5.1 to mono and back
+        let m = MixMatrix::with_tags(ChannelLayoutTag::MPEG_5_1_A, ChannelLayoutTag::MONO).unwrap();
+        let mono = [h, h, 1.0, 0.0, 0.5, 0.5];
+        assert!(
+            m.gains()
+                .iter()
+                .zip(mono)
+                .all(|(a, b)| (a - b).abs() < 1e-6)
+        );
+        let m = MixMatrix::with_tags(ChannelLayoutTag::MONO, ChannelLayoutTag::MPEG_5_1_A).unwrap();
+        assert_eq!(m.gains(), &[0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+    }
+}
+
+#[cfg(all(test, target_vendor = "apple", feature = "at"))]
+mod at_tests {
+    use crate::{at, cat::audio::channel_layout::LAYOUTS};
+
+    #[test]
+    fn layouts_match_audio_format() {
+        for (tag, labels) in LAYOUTS {
+            let mut layout = at::audio::ChannelLayout::<32> {
+                channel_layout_tag: at::audio::ChannelLayoutTag::USE_CHANNEL_DESCRIPTIONS,
+                channel_bitmap: Default::default(),
+                number_channel_descriptions: 0,
+                channel_descriptions: [Default::default(); 32],
+            };
+            let mut size = size_of_val(&layout) as u32;
+            unsafe {
+                at::audio::channel_layout_prop::CHANNEL_LAYOUT_FOR_TAG
+                    .value(
+                        4,
+                        tag as *const _ as _,
+                        &mut size,
+                        &mut layout as *mut _ as _,
+                    )
+                    .unwrap();
+            }
+            let n = layout.number_channel_descriptions as usize;
+            let res: Vec<_> = layout.channel_descriptions[..n]
+                .iter()
+                .map(|d| d.channel_label)
+                .collect();
+            assert_eq!(&res, labels, "{tag:?}");
+        }
+    }
+}
@@ -0,0 +1,574 @@
+//! Linear PCM format conversion without AudioToolbox.
+//!
+//! Converts sample format, endianness, alignment and interleaving between two
+//! [`StreamBasicDesc`] with the same sample rate and channel count. Sample rate
+//! conversion and channel mapping are left to `at::audio::Converter`.
+
+use crate::cat::audio::{Buf, BufList, BufListN, Format, FormatFlags, StreamBasicDesc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Inconsistent stream description or buffers with the reason.
+    Invalid(&'static str),
+
+    /// Valid stream description this converter doesn't handle, like fixed point samples.
+    Unsupported(&'static str),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid(reason) => write!(f, "invalid PCM format: {reason}"),
+            Self::Unsupported(reason) => write!(f, "unsupported PCM format: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T = ()> = std::result::Result<T, Error>;
+
+/// Sample layout of one channel of linear PCM stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleFormat {
+    pub is_float: bool,
+    pub is_signed: bool,
+    pub is_big_endian: bool,
+    /// Significant bits of the sample.
+    pub bits: u32,
+    /// Bytes the sample occupies.
+    pub size: usize,
+    /// Bits below the significant ones, non zero for samples aligned high.
+    pub shift: u32,
+}
+
+impl SampleFormat {
+    pub fn with_asbd(asbd: &StreamBasicDesc) -> Result<Self> {
+        if asbd.format != Format::LINEAR_PCM {
+            return Err(Error::Unsupported("not linear PCM"));
+        }
+        if asbd.frames_per_packet != 1 || asbd.bytes_per_packet != asbd.bytes_per_frame {
+            return Err(Error::Invalid("packet is not one frame"));
+        }
+        if asbd.channels_per_frame == 0 {
+            return Err(Error::Invalid("no channels"));
+        }
+        let flags = asbd.format_flags;
+        if flags.0 & FormatFlags::LINEAR_PCM_SAMPLE_FRACTION_MASK.0 != 0 {
+            return Err(Error::Unsupported("fixed point"));
+        }
+        let channels = asbd.interleaved_channels_num();
+        if asbd.bytes_per_frame == 0 || !asbd.bytes_per_frame.is_multiple_of(channels) {
+            return Err(Error::Invalid("bytes per frame"));
+        }
+        let size = (asbd.bytes_per_frame / channels) as usize;
+        let bits = asbd.bits_per_channel;
+        let is_float = flags.contains(FormatFlags::IS_FLOAT);
+        if is_float {
+            if !matches!((bits, size), (32, 4) | (64, 8)) {
+                return Err(Error::Unsupported("float is not 32 or 64 bit"));
+            }
+        } else {
+            if size > 4 {
+                return Err(Error::Unsupported("integer wider than 32 bit"));
+            }
+            if bits == 0 || bits as usize > size * 8 {
+                return Err(Error::Invalid("bits per channel"));
+            }
+        }
+        let shift = if flags.contains(FormatFlags::IS_ALIGNED_HIGH) {
+            size as u32 * 8 - bits
+        } else {
+            0
+        };
+        Ok(Self {
+            is_float,
+            is_signed: is_float || flags.contains(FormatFlags::IS_SIGNED_INTEGER),
+            is_big_endian: flags.contains(FormatFlags::IS_BIG_ENDIAN),
+            bits,
+            size,
+            shift,
+        })
+    }
+
+    /// Sample in -1.0..1.0 range for integers.
+    #[inline]
+    fn read(&self, bytes: &[u8]) -> f64 {
+        let bytes = &bytes[..self.size];
+        if self.is_float {
+            return match (self.size, self.is_big_endian) {
+                (4, false) => f32::from_le_bytes(bytes.try_into().unwrap()) as f64,
+                (4, true) => f32::from_be_bytes(bytes.try_into().unwrap()) as f64,
+                (_, false) => f64::from_le_bytes(bytes.try_into().unwrap()),
+                (_, true) => f64::from_be_bytes(bytes.try_into().unwrap()),
+            };
+        }
+        let mut raw = 0u32;
+        if self.is_big_endian {
+            bytes.iter().for_each(|b| raw = raw << 8 | *b as u32);
+        } else {
+            bytes.iter().rev().for_each(|b| raw = raw << 8 | *b as u32);
+        }
+        let unused = 32 - self.bits;
+        let val = if self.is_signed {
+            (((raw >> self.shift) << unused) as i32 >> unused) as i64
+        } else {
+            (((raw >> self.shift) << unused) >> unused) as i64 - (1i64 << (self.bits - 1))
+        };
+        val as f64 / self.scale()
+    }
+
+    #[inline]
+    fn write(&self, val: f64, bytes: &mut [u8]) {
+        let bytes = &mut bytes[..self.size];
+        if self.is_float {
+            match (self.size, self.is_big_endian) {
+                (4, false) => bytes.copy_from_slice(&(val as f32).to_le_bytes()),
+                (4, true) => bytes.copy_from_slice(&(val as f32).to_be_bytes()),
+                (_, false) => bytes.copy_from_slice(&val.to_le_bytes()),
+                (_, true) => bytes.copy_from_slice(&val.to_be_bytes()),
+            }
+            return;
+        }
+        let scale = self.scale();
+        let mut val = (val * scale).round().clamp(-scale, scale - 1.0) as i64;
+        if !self.is_signed {
+            val += scale as i64;
+        }
+        let mut raw = (val as u32) << self.shift;
+        if self.is_big_endian {
+            bytes.iter_mut().rev().for_each(|b| {
+                *b = raw as u8;
+                raw >>= 8;
+            });
+        } else {
+            bytes.iter_mut().for_each(|b| {
+                *b = raw as u8;
+                raw >>= 8;
+            });
+        }
+    }
+
+    #[inline]
+    fn scale(&self) -> f64 {
+        (1u64 << (self.bits - 1)) as f64
+    }
+}
+
+#[doc(alias = "AudioConverterDitherAlgorithm")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Dither {
+    #[default]
+    None,
+    /// Triangular probability density noise of ±1 LSB, applied when integer
+    /// destination is narrower than the source.
+    Tpdf,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Layout {
+    sample: SampleFormat,
+    interleaved: bool,
+    bytes_per_frame: usize,
+}
+
+impl Layout {
+    fn with_asbd(asbd: &StreamBasicDesc) -> Result<Self> {
+        Ok(Self {
+            sample: SampleFormat::with_asbd(asbd)?,
+            interleaved: asbd.is_interleaved(),
+            bytes_per_frame: asbd.bytes_per_frame as usize,
+        })
+    }
+
+    fn buffers_num(&self, channels: usize) -> usize {
+        if self.interleaved { 1 } else { channels }
+    }
+
+    /// Buffer and byte offset of the sample.
+    #[inline]
+    fn pos(&self, frame: usize, channel: usize) -> (usize, usize) {
+        if self.interleaved {
+            (0, frame * self.bytes_per_frame + channel * self.sample.size)
+        } else {
+            (channel, frame * self.bytes_per_frame)
+        }
+    }
+}
+
+/// Linear PCM converter between formats of the same sample rate and channel count.
+#[derive(Debug, Clone)]
+pub struct Converter {
+    src: Layout,
+    dst: Layout,
+    channels: usize,
+    dither: Dither,
+    rng: u32,
+}
+
+impl Converter {
+    pub fn new(src: &StreamBasicDesc, dst: &StreamBasicDesc) -> Result<Self> {
+        if src.sample_rate != dst.sample_rate {
+            return Err(Error::Unsupported("sample rate conversion"));
+        }
+        if src.channels_per_frame != dst.channels_per_frame {
+            return Err(Error::Unsupported("channel mapping"));
+        }
+        Ok(Self {
+            src: Layout::with_asbd(src)?,
+            dst: Layout::with_asbd(dst)?,
+            channels: src.channels_per_frame as usize,
+            dither: Dither::None,
+            rng: 0x2545_f491,
+        })
+    }
+
+    #[inline]
+    pub fn dither(&self) -> Dither {
+        self.dither
+    }
+
+    #[inline]
+    pub fn set_dither(&mut self, val: Dither) {
+        self.dither = val;
+    }
+
+    #[inline]
+    pub fn src_format(&self) -> &SampleFormat {
+        &self.src.sample
+    }
+
+    #[inline]
+    pub fn dst_format(&self) -> &SampleFormat {
+        &self.dst.sample
+    }
+
+    /// Whether dither is applied with [`Dither::Tpdf`].
+    pub fn is_narrowing(&self) -> bool {
+        let (src, dst) = (&self.src.sample, &self.dst.sample);
+        !dst.is_float && (src.is_float || src.bits > dst.bits)
+    }
+
+    /// Converts as many frames as fit into `dst`, one slice per buffer.
+    ///
+    /// Returns number of converted frames.
+    pub fn convert<S: AsRef<[u8]>, D: AsMut<[u8]>>(
+        &mut self,
+        src: &[S],
+        dst: &mut [D],
+    ) -> Result<usize> {
+        if src.len() != self.src.buffers_num(self.channels) {
+            return Err(Error::Invalid("source buffers number"));
+        }
+        if dst.len() != self.dst.buffers_num(self.channels) {
+            return Err(Error::Invalid("destination buffers number"));
+        }
+        let src_frames = src.iter().map(|b| b.as_ref().len()).min().unwrap_or(0);
+        let dst_frames = dst.iter_mut().map(|b| b.as_mut().len()).min().unwrap_or(0);
+        let frames =
+            (src_frames / self.src.bytes_per_frame).min(dst_frames / self.dst.bytes_per_frame);
+
+        let (sf, df) = (self.src.sample, self.dst.sample);
+        if sf == df && self.src.interleaved == self.dst.interleaved {
+            for (s, d) in src.iter().zip(dst.iter_mut()) {
+                let len = frames * self.src.bytes_per_frame;
+                d.as_mut()[..len].copy_from_slice(&s.as_ref()[..len]);
+            }
+            return Ok(frames);
+        }
+
+        let dither = self.dither == Dither::Tpdf && self.is_narrowing();
+        let lsb = 1.0 / df.scale();
+        for frame in 0..frames {
+            for ch in 0..self.channels {
+                let (sb, so) = self.src.pos(frame, ch);
+                let (db, doff) = self.dst.pos(frame, ch);
+                let mut val = sf.read(&src[sb].as_ref()[so..]);
+                if dither {
+                    val += self.tpdf() * lsb;
+                }
+                df.write(val, &mut dst[db].as_mut()[doff..]);
+            }
+        }
+        Ok(frames)
+    }
+
+    /// Converts buffers of [`BufList`], sets `data_bytes_size` of destination buffers to
+    /// converted bytes.
+    ///
+    /// # Safety
+    ///
+    /// Buffers must point to `data_bytes_size` valid bytes and destination must not
+    /// overlap source.
+    pub unsafe fn convert_list<const N: usize, const M: usize>(
+        &mut self,
+        src: &BufList<N>,
+        dst: &mut BufList<M>,
+    ) -> Result<usize> {
+        unsafe { self.convert_bufs(src.as_slice(), dst.as_mut_slice()) }
+    }
+
+    /// # Safety
+    ///
+    /// See [`Self::convert_list`].
+    pub unsafe fn convert_list_n(&mut self, src: &BufListN, dst: &mut BufListN) -> Result<usize> {
+        unsafe { self.convert_bufs(src.buffers(), dst.buffers_mut()) }
+    }
+
+    /// # Safety
+    ///
+    /// See [`Self::convert_list`].
+    pub unsafe fn convert_bufs(&mut self, src: &[Buf], dst: &mut [Buf]) -> Result<usize> {
+        let src = unsafe { &*(src as *const [Buf] as *const [RawBuf]) };
+        let dst = unsafe { &mut *(dst as *mut [Buf] as *mut [RawBuf]) };
+        let frames = self.convert(src, dst)?;
+        for buf in dst.iter_mut() {
+            buf.0.data_bytes_size = (frames * self.dst.bytes_per_frame) as u32;
+        }
+        Ok(frames)
+    }
+
+    /// Difference of two uniform values in 0..1.
+    #[inline]
+    fn tpdf(&mut self) -> f64 {
+        let a = self.next_rand();
+        let b = self.next_rand();
+        (a as f64 - b as f64) / (u32::MAX as f64 + 1.0)
+    }
+
+    /// xorshift32
+    #[inline]
+    fn next_rand(&mut self) -> u32 {
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng = x;
+        x
+    }
+}
+
+#[repr(transparent)]
+struct RawBuf(Buf);
+
+impl AsRef<[u8]> for RawBuf {
+    fn as_ref(&self) -> &[u8] {
+        if self.0.data.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.0.data, self.0.data_bytes_size as usize) }
+    }
+}
+
+impl AsMut<[u8]> for RawBuf {
+    fn as_mut(&mut self) -> &mut [u8] {
+        if self.0.data.is_null() {
+            return &mut [];
+        }
+        unsafe { std::slice::from_raw_parts_mut(self.0.data, self.0.data_bytes_size as usize) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cat::audio::{
+        Buf, BufList, Format, FormatFlags, StreamBasicDesc,
+        pcm::{Converter, Dither, Error},
+    };
+
+    fn asbd(
+        bits: u32,
+        total: u32,
+        float: bool,
+        be: bool,
+        non_interleaved: bool,
+    ) -> StreamBasicDesc {
+        StreamBasicDesc::with_lpcm(48_000.0, 2, bits, total, float, be, non_interleaved)
+    }
+
+    #[test]
+    fn int_float() {
+        let i16 = asbd(16, 16, false, false, false);
+        let f32 = asbd(32, 32, true, false, false);
+        let src: Vec<u8> = [i16::MIN, i16::MAX, 0, -1, 16384, -16384]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        let mut mid = vec![0u8; 24];
+        let mut conv = Converter::new(&i16, &f32).unwrap();
+        assert_eq!(conv.convert(&[&src], &mut [&mut mid]).unwrap(), 3);
+        let floats: Vec<f32> = mid
+            .chunks(4)
+            .map(|c| f32::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        assert_eq!(floats[0], -1.0);
+        assert_eq!(floats[1], 32767.0 / 32768.0);
+        assert_eq!(floats[4], 0.5);
+
+        let mut back = vec![0u8; 12];
+        let mut conv = Converter::new(&f32, &i16).unwrap();
+        conv.convert(&[&mid], &mut [&mut back]).unwrap();
+        assert_eq!(back, src);
+
+        // clipping
+        let loud: Vec<u8> = [1.5f32, -2.0]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        conv.convert(&[&loud], &mut [&mut back]).unwrap();
+        assert_eq!(&back[..4], &[0xff, 0x7f, 0x00, 0x80]);
+    }
+
+    #[test]
+    fn layouts() {
+        // 24 bit packed big endian interleaved to 24 bit aligned high in 32 little endian planar
+        let src_asbd = asbd(24, 24, false, true, false);
+        let dst_asbd = asbd(24, 32, false, false, true);
+        let src = [
+            0x12, 0x34, 0x56, 0xff, 0xff, 0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x01,
+        ];
+        let mut l = [0u8; 8];
+        let mut r = [0u8; 8];
+        let mut conv = Converter::new(&src_asbd, &dst_asbd).unwrap();
+        assert_eq!(
+            conv.convert(&[&src], &mut [&mut l[..], &mut r[..]])
+                .unwrap(),
+            2
+        );
+        assert_eq!(l, [0x00, 0x56, 0x34, 0x12, 0x00, 0x00, 0x00, 0x80]);
+        assert_eq!(r, [0x00, 0xfe, 0xff, 0xff, 0x00, 0x01, 0x00, 0x00]);
+
+        let mut conv = Converter::new(&dst_asbd, &src_asbd).unwrap();
+        let mut back = [0u8; 12];
+        conv.convert(&[l, r], &mut [&mut back]).unwrap();
+        assert_eq!(back, src);
+
+        // unsigned 8 bit to 24 bit aligned low in 32
+        let mut u8_asbd = asbd(8, 8, false, false, false);
+        u8_asbd.format_flags = FormatFlags::IS_PACKED;
+        let mut low = asbd(24, 32, false, false, false);
+        low.format_flags = FormatFlags::IS_SIGNED_INTEGER;
+        let mut conv = Converter::new(&u8_asbd, &low).unwrap();
+        let mut out = [0u8; 8];
+        conv.convert(&[[0x80u8, 0x00]], &mut [&mut out]).unwrap();
+        assert_eq!(out, [0, 0, 0, 0, 0, 0, 0x80, 0xff]);
+
+        // f64 big endian to f32 planar
+        let f64_be = asbd(64, 64, true, true, false);
+        let f32_planar = StreamBasicDesc::common_f32(48_000.0, 2, false);
+        let src: Vec<u8> = [0.25f64, -0.5]
+            .iter()
+            .flat_map(|s| s.to_be_bytes())
+            .collect();
+        let mut conv = Converter::new(&f64_be, &f32_planar).unwrap();
+        let (mut l, mut r) = ([0u8; 4], [0u8; 4]);
+        conv.convert(&[src], &mut [&mut l, &mut r]).unwrap();
+        assert_eq!(f32::from_ne_bytes(l), 0.25);
+        assert_eq!(f32::from_ne_bytes(r), -0.5);
+    }
+
+    #[test]
+    fn dither() {
+        let i32 = asbd(32, 32, false, false, false);
+        let i16 = asbd(16, 16, false, false, false);
+        let mut conv = Converter::new(&i32, &i16).unwrap();
+        assert!(conv.is_narrowing());
+        conv.set_dither(Dither::Tpdf);
+
+        // 1000.25 LSB of 16 bit
+        let sample: i32 = (1000 << 16) + (1 << 14);
+        let frames = 4096;
+        let src: Vec<u8> = (0..frames * 2).flat_map(|_| sample.to_le_bytes()).collect();
+        let mut dst = vec![0u8; frames * 4];
+        assert_eq!(conv.convert(&[&src], &mut [&mut dst]).unwrap(), frames);
+        let vals: Vec<i16> = dst
+            .chunks(2)
+            .map(|c| i16::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        assert!(vals.iter().all(|v| (999..=1002).contains(v)));
+        let mean = vals.iter().map(|v| *v as f64).sum::<f64>() / vals.len() as f64;
+        assert!((mean - 1000.25).abs() < 0.05, "{mean}");
+
+        conv.set_dither(Dither::None);
+        conv.convert(&[&src], &mut [&mut dst]).unwrap();
+        assert!(dst.chunks(2).all(|c| c == 1000i16.to_le_bytes()));
+    }
+
+    #[test]
+    fn buf_list() {
+        let i16 = asbd(16, 16, false, false, false);
+        let f32 = StreamBasicDesc::common_f32(48_000.0, 2, false);
+        let mut src: Vec<u8> = [16384i16, -16384, 0, 8192]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        let (mut l, mut r) = ([0u8; 16], [0u8; 16]);
+        let src_list = BufList::<1> {
+            number_buffers: 1,
+            buffers: [Buf {
+                number_channels: 2,
+                data_bytes_size: src.len() as u32,
+                data: src.as_mut_ptr(),
+            }],
+        };
+        let mut dst_list = BufList::<2> {
+            number_buffers: 2,
+            buffers: [l.as_mut_ptr(), r.as_mut_ptr()].map(|data| Buf {
+                number_channels: 1,
+                data_bytes_size: 16,
+                data,
+            }),
+        };
+        let mut conv = Converter::new(&i16, &f32).unwrap();
+        let frames = unsafe { conv.convert_list(&src_list, &mut dst_list).unwrap() };
+        assert_eq!(frames, 2);
+        assert_eq!(dst_list.buffers[0].data_bytes_size, 8);
+        assert_eq!(dst_list.buffers[1].data_bytes_size, 8);
+        assert_eq!(f32::from_ne_bytes(l[..4].try_into().unwrap()), 0.5);
+        assert_eq!(f32::from_ne_bytes(r[4..8].try_into().unwrap()), 0.25);
+    }
+
+    #[test]
+    fn invalid() {
+        let f32 = StreamBasicDesc::common_f32(48_000.0, 2, true);
+        let mut aac = f32;
+        aac.format = Format::MPEG4_AAC;
+        assert_eq!(
+            Converter::new(&aac, &f32).err(),
+            Some(Error::Unsupported("not linear PCM"))
+        );
+
+        let mut odd = f32;
+        odd.bytes_per_frame = 7;
+        odd.bytes_per_packet = 7;
+        assert!(matches!(Converter::new(&f32, &odd), Err(Error::Invalid(_))));
+
+        let mut f16 = asbd(16, 16, true, false, false);
+        assert!(matches!(
+            Converter::new(&f16, &f32),
+            Err(Error::Unsupported(_))
+        ));
+        f16.format_flags = FormatFlags(
+            FormatFlags::IS_SIGNED_INTEGER.0
+                | (8 << FormatFlags::LINEAR_PCM_SAMPLE_FRACTION_SHIFT.0),
+        );
+        assert_eq!(
+            Converter::new(&f16, &f32).err(),
+            Some(Error::Unsupported("fixed point"))
+        );
+
+        let mono = StreamBasicDesc::common_f32(48_000.0, 1, true);
+        assert!(Converter::new(&mono, &f32).is_err());
+        let mut rate = f32;
+        rate.sample_rate = 44_100.0;
+        assert!(Converter::new(&rate, &f32).is_err());
+
+        let mut conv = Converter::new(&f32, &f32).unwrap();
+        let buf = [0u8; 8];
+        let mut out = [0u8; 8];
+        assert!(matches!(
+            conv.convert(&[buf, buf], &mut [&mut out]),
+            Err(Error::Invalid(_))
+        ));
+    }
+}
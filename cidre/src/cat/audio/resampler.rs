@@ -0,0 +1,667 @@
+//! Polyphase windowed sinc sample rate converter.
+//!
+//! Portable alternative to sample rate conversion of `at::audio::Converter` for
+//! native endian `f32` streams. Output is time aligned with input, the filter holds
+//! back [`Resampler::latency`] input frames which [`Resampler::flush`] drains.
+
+use crate::cat::audio::{Buf, BufList, FormatFlags, StreamBasicDesc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Inconsistent rates, channels or buffers with the reason.
+    Invalid(&'static str),
+
+    /// Valid format the resampler doesn't handle, like integer samples.
+    Unsupported(&'static str),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid(reason) => write!(f, "invalid resampler config: {reason}"),
+            Self::Unsupported(reason) => write!(f, "unsupported resampler config: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T = ()> = std::result::Result<T, Error>;
+
+/// Same values as `at::audio::ConverterQuality`.
+#[doc(alias = "kAudioConverterSampleRateConverterQuality")]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[repr(transparent)]
+pub struct Quality(pub u32);
+
+impl Quality {
+    #[doc(alias = "kAudioConverterQuality_Max")]
+    pub const MAX: Self = Self(0x7F);
+
+    #[doc(alias = "kAudioConverterQuality_High")]
+    pub const HIGH: Self = Self(0x60);
+
+    #[doc(alias = "kAudioConverterQuality_Medium")]
+    pub const MEDIUM: Self = Self(0x40);
+
+    #[doc(alias = "kAudioConverterQuality_Low")]
+    pub const LOW: Self = Self(0x20);
+
+    #[doc(alias = "kAudioConverterQuality_Min")]
+    pub const MIN: Self = Self(0);
+
+    /// Taps per phase at unity ratio, Kaiser beta and passband edge relative to
+    /// Nyquist of the lower rate.
+    fn filter_params(&self) -> (usize, f64, f64) {
+        match self.0 {
+            0x7f.. => (128, 12.0, 0.97),
+            0x60.. => (64, 10.0, 0.95),
+            0x40.. => (32, 8.0, 0.92),
+            0x20.. => (16, 6.0, 0.88),
+            _ => (8, 5.0, 0.8),
+        }
+    }
+}
+
+impl Default for Quality {
+    fn default() -> Self {
+        Self::MEDIUM
+    }
+}
+
+/// Phase tables above this number of coefficients are computed per output frame.
+const MAX_TABLE_LEN: usize = 1 << 18;
+
+#[derive(Debug, Clone)]
+pub struct Resampler {
+    channels: usize,
+    /// Interpolation factor.
+    up: u32,
+    /// Decimation factor.
+    down: u32,
+    taps: usize,
+    cutoff: f64,
+    beta: f64,
+    /// `up * taps` coefficients or empty if too large.
+    table: Vec<f32>,
+    /// Coefficients of the current phase if there is no table.
+    scratch: Vec<f32>,
+    /// Per channel `2 * taps` samples, each written twice so the last `taps`
+    /// are contiguous.
+    ring: Vec<f32>,
+    ring_pos: usize,
+    phase: u32,
+    /// Input frames to push before the next output frame.
+    need: usize,
+    frames_in: u64,
+    frames_out: u64,
+}
+
+impl Resampler {
+    /// Integer sample rates, reduced to the lowest terms.
+    pub fn new(src_rate: f64, dst_rate: f64, channels: u32, quality: Quality) -> Result<Self> {
+        if src_rate.fract() != 0.0 || dst_rate.fract() != 0.0 {
+            return Err(Error::Unsupported("fractional sample rate"));
+        }
+        if !(1.0..=u32::MAX as f64).contains(&src_rate)
+            || !(1.0..=u32::MAX as f64).contains(&dst_rate)
+        {
+            return Err(Error::Invalid("sample rate"));
+        }
+        Self::with_ratio(src_rate as u32, dst_rate as u32, channels, quality)
+    }
+
+    /// Native endian `f32` streams of the same channel count, interleaved or not.
+    pub fn with_asbd(
+        src: &StreamBasicDesc,
+        dst: &StreamBasicDesc,
+        quality: Quality,
+    ) -> Result<Self> {
+        let is_f32 = |asbd: &StreamBasicDesc| {
+            asbd.is_native_endian()
+                && asbd.format_flags.contains(FormatFlags::IS_FLOAT)
+                && asbd.bits_per_channel == 32
+                && asbd.frames_per_packet == 1
+                && asbd.bytes_per_frame == asbd.interleaved_channels_num() * 4
+        };
+        if !is_f32(src) || !is_f32(dst) {
+            return Err(Error::Unsupported("not native f32"));
+        }
+        if src.channels_per_frame != dst.channels_per_frame {
+            return Err(Error::Invalid("channels number differs"));
+        }
+        Self::new(
+            src.sample_rate,
+            dst.sample_rate,
+            src.channels_per_frame,
+            quality,
+        )
+    }
+
+    /// Produces `dst` output frames per `src` input frames, e.g. 160/147 for 44.1 to 48 kHz.
+    pub fn with_ratio(src: u32, dst: u32, channels: u32, quality: Quality) -> Result<Self> {
+        if src == 0 || dst == 0 {
+            return Err(Error::Invalid("sample rate"));
+        }
+        if channels == 0 {
+            return Err(Error::Invalid("no channels"));
+        }
+        let g = gcd(src, dst);
+        let (up, down) = (dst / g, src / g);
+        let (base_taps, beta, rolloff) = quality.filter_params();
+        let (taps, cutoff) = if up == down {
+            (0, 1.0)
+        } else if down > up {
+            // wider kernel keeps transition band width relative to output rate
+            let stretch = down.div_ceil(up) as usize;
+            (base_taps * stretch, rolloff * up as f64 / down as f64)
+        } else {
+            (base_taps, rolloff)
+        };
+        let channels = channels as usize;
+        let mut res = Self {
+            channels,
+            up,
+            down,
+            taps,
+            cutoff,
+            beta,
+            table: Vec::new(),
+            scratch: vec![0.0; taps],
+            ring: vec![0.0; channels * taps * 2],
+            ring_pos: 0,
+            phase: 0,
+            need: 0,
+            frames_in: 0,
+            frames_out: 0,
+        };
+        let len = up as usize * taps;
+        if len <= MAX_TABLE_LEN {
+            res.table = vec![0.0; len];
+            for p in 0..up {
+                res.fill_coefs(p, p as usize * taps..(p as usize + 1) * taps);
+            }
+            res.scratch = Vec::new();
+        }
+        res.reset();
+        Ok(res)
+    }
+
+    #[inline]
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Reduced `(src, dst)` ratio.
+    #[inline]
+    pub fn ratio(&self) -> (u32, u32) {
+        (self.down, self.up)
+    }
+
+    /// Input frames held back by the filter.
+    #[inline]
+    pub fn latency(&self) -> usize {
+        self.taps / 2
+    }
+
+    /// Output frames for `frames` of input after flush.
+    pub fn output_frames(&self, frames: u64) -> u64 {
+        (frames * self.up as u64).div_ceil(self.down as u64)
+    }
+
+    /// Drops buffered input to start a new stream.
+    pub fn reset(&mut self) {
+        self.ring.fill(0.0);
+        self.ring_pos = 0;
+        self.phase = 0;
+        self.need = if self.is_bypass() {
+            0
+        } else {
+            self.taps / 2 + 1
+        };
+        self.frames_in = 0;
+        self.frames_out = 0;
+    }
+
+    #[inline]
+    fn is_bypass(&self) -> bool {
+        self.up == self.down
+    }
+
+    /// Resamples interleaved frames.
+    ///
+    /// Returns numbers of consumed input and produced output frames. Input is
+    /// consumed only while there is room for output.
+    pub fn process(&mut self, input: &[f32], output: &mut [f32]) -> (usize, usize) {
+        let ch = self.channels;
+        self.run(
+            input.len() / ch,
+            |frame, c| input[frame * ch + c],
+            output.len() / ch,
+            |frame, c, val| output[frame * ch + c] = val,
+        )
+    }
+
+    /// Resamples one slice per channel.
+    pub fn process_planar<I: AsRef<[f32]>, O: AsMut<[f32]>>(
+        &mut self,
+        input: &[I],
+        output: &mut [O],
+    ) -> Result<(usize, usize)> {
+        if input.len() != self.channels || output.len() != self.channels {
+            return Err(Error::Invalid("buffers number differs from channels"));
+        }
+        let in_frames = input.iter().map(|b| b.as_ref().len()).min().unwrap_or(0);
+        let out_frames = output
+            .iter_mut()
+            .map(|b| b.as_mut().len())
+            .min()
+            .unwrap_or(0);
+        Ok(self.run(
+            in_frames,
+            |frame, c| input[c].as_ref()[frame],
+            out_frames,
+            |frame, c, val| output[c].as_mut()[frame] = val,
+        ))
+    }
+
+    /// Produces the tail of the stream into interleaved `output`.
+    ///
+    /// Returns number of produced frames, zero when the stream is complete.
+    pub fn flush(&mut self, output: &mut [f32]) -> usize {
+        let ch = self.channels;
+        self.run_flush(output.len() / ch, |frame, c, val| {
+            output[frame * ch + c] = val
+        })
+    }
+
+    pub fn flush_planar<O: AsMut<[f32]>>(&mut self, output: &mut [O]) -> Result<usize> {
+        if output.len() != self.channels {
+            return Err(Error::Invalid("buffers number differs from channels"));
+        }
+        let out_frames = output
+            .iter_mut()
+            .map(|b| b.as_mut().len())
+            .min()
+            .unwrap_or(0);
+        Ok(self.run_flush(out_frames, |frame, c, val| output[c].as_mut()[frame] = val))
+    }
+
+    /// Resamples `f32` buffers, one interleaved buffer or one buffer per channel.
+    /// Sets `data_bytes_size` of output buffers to produced bytes.
+    ///
+    /// # Safety
+    ///
+    /// Buffers must point to `data_bytes_size` valid bytes aligned for `f32` and output
+    /// must not overlap input.
+    pub unsafe fn process_list<const N: usize, const M: usize>(
+        &mut self,
+        input: &BufList<N>,
+        output: &mut BufList<M>,
+    ) -> Result<(usize, usize)> {
+        let interleaved = self.is_interleaved(input.as_slice())?;
+        if interleaved != self.is_interleaved(output.as_slice())? {
+            return Err(Error::Unsupported("interleaving differs"));
+        }
+        let input = input.as_slice();
+        let output = output.as_mut_slice();
+        let res = unsafe {
+            if interleaved {
+                self.process(samples(&input[0]), samples_mut(&mut output[0]))
+            } else {
+                let input: [&[f32]; N] = std::array::from_fn(|i| samples(&input[i]));
+                let mut iter = output.iter_mut();
+                let mut out: [&mut [f32]; M] =
+                    std::array::from_fn(|_| samples_mut(iter.next().unwrap()));
+                self.process_planar(&input, &mut out)?
+            }
+        };
+        let bytes = (res.1 * 4 * self.channels / output.len()) as u32;
+        output.iter_mut().for_each(|b| b.data_bytes_size = bytes);
+        Ok(res)
+    }
+
+    /// # Safety
+    ///
+    /// See [`Self::process_list`].
+    pub unsafe fn flush_list<const M: usize>(&mut self, output: &mut BufList<M>) -> Result<usize> {
+        let interleaved = self.is_interleaved(output.as_slice())?;
+        let output = output.as_mut_slice();
+        let frames = unsafe {
+            if interleaved {
+                self.flush(samples_mut(&mut output[0]))
+            } else {
+                let mut iter = output.iter_mut();
+                let mut out: [&mut [f32]; M] =
+                    std::array::from_fn(|_| samples_mut(iter.next().unwrap()));
+                self.flush_planar(&mut out)?
+            }
+        };
+        let bytes = (frames * 4 * self.channels / output.len()) as u32;
+        output.iter_mut().for_each(|b| b.data_bytes_size = bytes);
+        Ok(frames)
+    }
+
+    fn is_interleaved(&self, bufs: &[Buf]) -> Result<bool> {
+        match bufs {
+            [buf] if buf.number_channels as usize == self.channels => Ok(true),
+            bufs if bufs.len() == self.channels && bufs.iter().all(|b| b.number_channels == 1) => {
+                Ok(false)
+            }
+            _ => Err(Error::Invalid("buffers don't match channels")),
+        }
+    }
+
+    fn run(
+        &mut self,
+        in_frames: usize,
+        mut read: impl FnMut(usize, usize) -> f32,
+        out_frames: usize,
+        mut write: impl FnMut(usize, usize, f32),
+    ) -> (usize, usize) {
+        if self.is_bypass() {
+            let frames = in_frames.min(out_frames);
+            for frame in 0..frames {
+                for c in 0..self.channels {
+                    write(frame, c, read(frame, c));
+                }
+            }
+            self.frames_in += frames as u64;
+            self.frames_out += frames as u64;
+            return (frames, frames);
+        }
+        let (mut consumed, mut produced) = (0, 0);
+        loop {
+            while self.need > 0 && consumed < in_frames {
+                self.push(|c| read(consumed, c));
+                consumed += 1;
+                self.need -= 1;
+            }
+            if self.need > 0 || produced == out_frames {
+                break;
+            }
+            self.emit(|c, val| write(produced, c, val));
+            produced += 1;
+        }
+        self.frames_in += consumed as u64;
+        (consumed, produced)
+    }
+
+    fn run_flush(&mut self, out_frames: usize, mut write: impl FnMut(usize, usize, f32)) -> usize {
+        let target = self.output_frames(self.frames_in);
+        let mut produced = 0;
+        while self.frames_out < target && produced < out_frames {
+            while self.need > 0 {
+                self.push(|_| 0.0);
+                self.need -= 1;
+            }
+            self.emit(|c, val| write(produced, c, val));
+            produced += 1;
+        }
+        produced
+    }
+
+    #[inline]
+    fn push(&mut self, mut read: impl FnMut(usize) -> f32) {
+        let len = self.taps * 2;
+        for c in 0..self.channels {
+            let val = read(c);
+            let ring = &mut self.ring[c * len..(c + 1) * len];
+            ring[self.ring_pos] = val;
+            ring[self.ring_pos + self.taps] = val;
+        }
+        self.ring_pos = (self.ring_pos + 1) % self.taps;
+    }
+
+    #[inline]
+    fn emit(&mut self, mut write: impl FnMut(usize, f32)) {
+        let phase = self.phase;
+        let taps = self.taps;
+        if self.table.is_empty() {
+            let mut scratch = std::mem::take(&mut self.scratch);
+            self.fill_coefs_into(phase, &mut scratch);
+            self.scratch = scratch;
+        }
+        let coefs = if self.table.is_empty() {
+            &self.scratch[..]
+        } else {
+            &self.table[phase as usize * taps..(phase as usize + 1) * taps]
+        };
+        for c in 0..self.channels {
+            let start = c * taps * 2 + self.ring_pos;
+            let window = &self.ring[start..start + taps];
+            let val = window.iter().zip(coefs).map(|(x, h)| x * h).sum();
+            write(c, val);
+        }
+        self.frames_out += 1;
+        let next = self.phase + self.down;
+        self.need = (next / self.up) as usize;
+        self.phase = next % self.up;
+    }
+
+    fn fill_coefs(&mut self, phase: u32, range: std::ops::Range<usize>) {
+        let mut coefs = std::mem::take(&mut self.table);
+        self.fill_coefs_into(phase, &mut coefs[range]);
+        self.table = coefs;
+    }
+
+    /// Kaiser windowed sinc at offsets of `phase`, normalized to unity DC gain.
+    fn fill_coefs_into(&self, phase: u32, coefs: &mut [f32]) {
+        let half = (self.taps / 2) as f64;
+        let frac = phase as f64 / self.up as f64;
+        let i0_beta = bessel_i0(self.beta);
+        let mut sum = 0.0;
+        for (k, h) in coefs.iter_mut().enumerate() {
+            let t = frac + half - 1.0 - k as f64;
+            let x = t / half;
+            let w = if x.abs() >= 1.0 {
+                0.0
+            } else {
+                bessel_i0(self.beta * (1.0 - x * x).sqrt()) / i0_beta
+            };
+            let v = self.cutoff * sinc(self.cutoff * t) * w;
+            sum += v;
+            *h = v as f32;
+        }
+        let norm = (1.0 / sum) as f32;
+        coefs.iter_mut().for_each(|h| *h *= norm);
+    }
+}
+
+/// # Safety
+///
+/// `buf` must point to `data_bytes_size` bytes aligned for `f32`.
+unsafe fn samples<'a>(buf: &Buf) -> &'a [f32] {
+    if buf.data.is_null() {
+        return &[];
+    }
+    unsafe { std::slice::from_raw_parts(buf.data as *const f32, buf.data_bytes_size as usize / 4) }
+}
+
+unsafe fn samples_mut<'a>(buf: &mut Buf) -> &'a mut [f32] {
+    if buf.data.is_null() {
+        return &mut [];
+    }
+    unsafe {
+        std::slice::from_raw_parts_mut(buf.data as *mut f32, buf.data_bytes_size as usize / 4)
+    }
+}
+
+fn sinc(x: f64) -> f64 {
+    if x == 0.0 {
+        1.0
+    } else {
+        let x = x * std::f64::consts::PI;
+        x.sin() / x
+    }
+}
+
+/// Modified Bessel function of the first kind, order zero.
+fn bessel_i0(x: f64) -> f64 {
+    let q = x * x / 4.0;
+    let (mut sum, mut term, mut k) = (1.0, 1.0, 1.0);
+    while term > sum * 1e-12 {
+        term *= q / (k * k);
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
+
+const fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cat::audio::{
+        Buf, BufList, StreamBasicDesc,
+        resampler::{Error, Quality, Resampler},
+    };
+
+    fn sine(freq: f64, rate: f64, frames: usize) -> Vec<f32> {
+        (0..frames)
+            .map(|i| (0.5 * (std::f64::consts::TAU * freq * i as f64 / rate).sin()) as f32)
+            .collect()
+    }
+
+    fn resample(r: &mut Resampler, input: &[f32], chunk: usize) -> Vec<f32> {
+        let ch = r.channels();
+        let mut res = Vec::new();
+        let mut out = vec![0.0; 64 * ch];
+        for chunk in input.chunks(chunk * ch) {
+            let mut chunk = chunk;
+            while !chunk.is_empty() {
+                let (consumed, produced) = r.process(chunk, &mut out);
+                res.extend_from_slice(&out[..produced * ch]);
+                chunk = &chunk[consumed * ch..];
+            }
+        }
+        loop {
+            let produced = r.flush(&mut out);
+            if produced == 0 {
+                break;
+            }
+            res.extend_from_slice(&out[..produced * ch]);
+        }
+        res
+    }
+
+    #[test]
+    fn ratio() {
+        let r = Resampler::new(48_000.0, 16_000.0, 1, Quality::HIGH).unwrap();
+        assert_eq!(r.ratio(), (3, 1));
+        assert_eq!(r.latency(), 96);
+        let r = Resampler::new(44_100.0, 48_000.0, 2, Quality::default()).unwrap();
+        assert_eq!(r.ratio(), (147, 160));
+        assert_eq!(r.output_frames(1000), 1089);
+
+        let mut r = Resampler::with_ratio(2, 3, 2, Quality::MIN).unwrap();
+        let input: Vec<f32> = (0..2000).map(|i| i as f32).collect();
+        let one_shot = resample(&mut r, &input, 1000);
+        assert_eq!(one_shot.len(), 1500 * 2);
+        r.reset();
+        assert_eq!(resample(&mut r, &input, 7), one_shot);
+    }
+
+    #[test]
+    fn quality() {
+        let h = Resampler::new(48_000.0, 16_000.0, 1, Quality::HIGH).unwrap();
+        let l = Resampler::new(48_000.0, 16_000.0, 1, Quality::LOW).unwrap();
+        assert!(l.latency() < h.latency());
+
+        for (q, max_err) in [
+            (Quality::LOW, 1e-2),
+            (Quality::HIGH, 1e-3),
+            (Quality::MAX, 1e-4),
+        ] {
+            let mut r = Resampler::new(48_000.0, 16_000.0, 1, q).unwrap();
+            let out = resample(&mut r, &sine(1000.0, 48_000.0, 4800), 256);
+            assert_eq!(out.len(), 1600);
+            let expected = sine(1000.0, 16_000.0, 1600);
+            let err = out[200..1400]
+                .iter()
+                .zip(&expected[200..1400])
+                .map(|(a, b)| (a - b).abs())
+                .fold(0.0, f32::max);
+            assert!(err < max_err, "{q:?} {err}");
+        }
+
+        // 12 kHz can't be represented at 16 kHz
+        let mut r = Resampler::new(48_000.0, 16_000.0, 1, Quality::HIGH).unwrap();
+        let out = resample(&mut r, &sine(12_000.0, 48_000.0, 4800), 256);
+        assert!(out[200..1400].iter().all(|s| s.abs() < 1e-3));
+
+        // too many phases for a table
+        let mut r = Resampler::new(48_000.0, 48_001.0, 1, Quality::LOW).unwrap();
+        let out = resample(&mut r, &[0.5; 300], 300);
+        assert_eq!(out.len(), 301);
+        assert!(out[50..250].iter().all(|s| (s - 0.5).abs() < 1e-3));
+    }
+
+    #[test]
+    fn buf_list() {
+        let src_asbd = StreamBasicDesc::common_f32(44_100.0, 2, false);
+        let dst_asbd = StreamBasicDesc::common_f32(48_000.0, 2, false);
+        let mut r = Resampler::with_asbd(&src_asbd, &dst_asbd, Quality::MEDIUM).unwrap();
+
+        let mut l = sine(440.0, 44_100.0, 441);
+        let mut r_ch = sine(880.0, 44_100.0, 441);
+        let (mut out_l, mut out_r) = (vec![0.0f32; 600], vec![0.0f32; 600]);
+        let buf = |data: *mut f32, len: usize| Buf {
+            number_channels: 1,
+            data_bytes_size: (len * 4) as u32,
+            data: data as *mut u8,
+        };
+        let input = BufList::<2> {
+            number_buffers: 2,
+            buffers: [buf(l.as_mut_ptr(), 441), buf(r_ch.as_mut_ptr(), 441)],
+        };
+        let mut output = BufList::<2> {
+            number_buffers: 2,
+            buffers: [buf(out_l.as_mut_ptr(), 600), buf(out_r.as_mut_ptr(), 600)],
+        };
+        let (consumed, produced) = unsafe { r.process_list(&input, &mut output).unwrap() };
+        assert_eq!(consumed, 441);
+        assert_eq!(output.buffers[0].data_bytes_size as usize, produced * 4);
+        output.buffers[0] = buf(out_l[produced..].as_mut_ptr(), 600 - produced);
+        output.buffers[1] = buf(out_r[produced..].as_mut_ptr(), 600 - produced);
+        let tail = unsafe { r.flush_list(&mut output).unwrap() };
+        assert_eq!(produced + tail, 480);
+
+        let interleaved: Vec<f32> = l.iter().zip(&r_ch).flat_map(|(a, b)| [*a, *b]).collect();
+        r.reset();
+        let expected = resample(&mut r, &interleaved, 441);
+        for i in 0..480 {
+            assert_eq!(out_l[i], expected[i * 2]);
+            assert_eq!(out_r[i], expected[i * 2 + 1]);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Resampler::new(44_100.5, 48_000.0, 1, Quality::MAX).err(),
+            Some(Error::Unsupported("fractional sample rate"))
+        );
+        assert!(Resampler::new(0.0, 48_000.0, 1, Quality::MAX).is_err());
+        assert!(Resampler::with_ratio(1, 2, 0, Quality::MAX).is_err());
+        let f32 = StreamBasicDesc::common_f32(48_000.0, 2, true);
+        let i16 = StreamBasicDesc::with_lpcm(16_000.0, 2, 16, 16, false, false, false);
+        assert!(Resampler::with_asbd(&f32, &i16, Quality::MAX).is_err());
+
+        let mut r = Resampler::new(48_000.0, 48_000.0, 2, Quality::MAX).unwrap();
+        assert_eq!(r.latency(), 0);
+        let mut out = [0.0; 4];
+        assert_eq!(r.process(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &mut out), (2, 2));
+        assert_eq!(out, [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(r.flush(&mut out), 0);
+        assert!(
+            r.process_planar(&[[0.0f32; 4]], &mut [[0.0f32; 4]])
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,1661 @@
+//! WAVE, RF64, BW64, AIFF and AIFC reader and writer.
+//!
+//! Pure Rust counterpart of `at::audio::File` for linear PCM, A-law and µ-law in
+//! the RIFF and IFF families. Markers and regions follow `MARKER_LIST` and
+//! `REGION_LIST` file properties, info keys follow CAF `info` chunk keys.
+//!
+//! The writer leaves sizes at their maximum until [`Writer::sync`] or [`Writer::finish`]
+//! patch them, so a file interrupted by a crash is read up to the last written frame.
+//! WAVE files reserve space for `ds64` and become RF64 once they grow over 4 GB.
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+use crate::cat::audio::{
+    ChannelBitmap, Format, FormatFlags, StreamBasicDesc,
+    caf::{MarkerType, RegionFlags},
+};
+
+const RIFF: [u8; 4] = *b"RIFF";
+const RF64: [u8; 4] = *b"RF64";
+const BW64: [u8; 4] = *b"BW64";
+const WAVE: [u8; 4] = *b"WAVE";
+const DS64: [u8; 4] = *b"ds64";
+const JUNK: [u8; 4] = *b"JUNK";
+const FMT: [u8; 4] = *b"fmt ";
+const DATA: [u8; 4] = *b"data";
+const CUE: [u8; 4] = *b"cue ";
+const LIST: [u8; 4] = *b"LIST";
+const INFO: [u8; 4] = *b"INFO";
+const ADTL: [u8; 4] = *b"adtl";
+const LABL: [u8; 4] = *b"labl";
+const LTXT: [u8; 4] = *b"ltxt";
+
+const FORM: [u8; 4] = *b"FORM";
+const AIFF: [u8; 4] = *b"AIFF";
+const AIFC: [u8; 4] = *b"AIFC";
+const COMM: [u8; 4] = *b"COMM";
+const SSND: [u8; 4] = *b"SSND";
+const MARK: [u8; 4] = *b"MARK";
+const FVER: [u8; 4] = *b"FVER";
+
+/// AIFC version 1 timestamp.
+const AIFC_VERSION: u32 = 0xa280_5140;
+
+/// `riffSize`, `dataSize`, `sampleCount` and `tableLength`.
+const DS64_LEN: usize = 28;
+
+const WAVE_FORMAT_PCM: u16 = 1;
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 3;
+const WAVE_FORMAT_ALAW: u16 = 6;
+const WAVE_FORMAT_MULAW: u16 = 7;
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xfffe;
+
+/// Tail of `KSDATAFORMAT_SUBTYPE_*` GUIDs after the format tag.
+const SUBTYPE_GUID_TAIL: [u8; 14] = [
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xaa, 0x00, 0x38, 0x9b, 0x71,
+];
+
+/// `LIST/INFO` ids with CAF info keys.
+const RIFF_INFO_KEYS: [([u8; 4], &str); 8] = [
+    (*b"INAM", "title"),
+    (*b"IART", "artist"),
+    (*b"IPRD", "album"),
+    (*b"ICMT", "comments"),
+    (*b"IGNR", "genre"),
+    (*b"ICOP", "copyright"),
+    (*b"ICRD", "recorded date"),
+    (*b"ISFT", "encoding application"),
+];
+
+/// AIFF text chunks with CAF info keys.
+const AIFF_INFO_KEYS: [([u8; 4], &str); 4] = [
+    (*b"NAME", "title"),
+    (*b"AUTH", "artist"),
+    (*b"(c) ", "copyright"),
+    (*b"ANNO", "comments"),
+];
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+
+    /// Neither RIFF WAVE nor IFF AIFF file.
+    Unrecognized,
+
+    /// Required chunk is missing, `fmt `, `COMM` or audio data.
+    MissingChunk([u8; 4]),
+
+    /// Malformed chunk with the reason.
+    Corrupt([u8; 4], &'static str),
+
+    /// Stream description or metadata can't be stored in the file type.
+    InvalidFormat(&'static str),
+
+    /// Chunks preceding audio data can't be changed after the first write.
+    DataStarted,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => err.fmt(f),
+            Self::Unrecognized => f.write_str("not a WAVE or AIFF file"),
+            Self::MissingChunk(id) => {
+                write!(f, "missing '{}' chunk", String::from_utf8_lossy(id))
+            }
+            Self::Corrupt(id, reason) => {
+                write!(
+                    f,
+                    "invalid '{}' chunk: {reason}",
+                    String::from_utf8_lossy(id)
+                )
+            }
+            Self::InvalidFormat(reason) => write!(f, "invalid format: {reason}"),
+            Self::DataStarted => f.write_str("audio data already started"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+pub type Result<T = ()> = std::result::Result<T, Error>;
+
+/// Same values as `at::audio::FileTypeId`.
+#[doc(alias = "AudioFileTypeID")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct FileType(pub u32);
+
+impl FileType {
+    #[doc(alias = "kAudioFileAIFFType")]
+    pub const AIFF: Self = Self(u32::from_be_bytes(AIFF));
+
+    #[doc(alias = "kAudioFileAIFCType")]
+    pub const AIFC: Self = Self(u32::from_be_bytes(AIFC));
+
+    #[doc(alias = "kAudioFileWAVEType")]
+    pub const WAVE: Self = Self(u32::from_be_bytes(WAVE));
+
+    #[doc(alias = "kAudioFileRF64Type")]
+    pub const RF64: Self = Self(u32::from_be_bytes(RF64));
+
+    #[doc(alias = "kAudioFileBW64Type")]
+    pub const BW64: Self = Self(u32::from_be_bytes(BW64));
+
+    #[inline]
+    pub fn is_riff(&self) -> bool {
+        matches!(*self, Self::WAVE | Self::RF64 | Self::BW64)
+    }
+
+    #[inline]
+    pub fn is_iff(&self) -> bool {
+        matches!(*self, Self::AIFF | Self::AIFC)
+    }
+}
+
+#[cfg(feature = "at")]
+impl From<FileType> for crate::at::audio::FileTypeId {
+    fn from(val: FileType) -> Self {
+        Self(val.0)
+    }
+}
+
+/// Cue point of WAVE `cue ` or AIFF `MARK` chunk.
+#[doc(alias = "AudioFileMarker")]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Marker {
+    pub frame_pos: f64,
+    pub name: String,
+    pub id: u32,
+    pub type_: MarkerType,
+}
+
+/// WAVE labeled text region, `markers` are region start and end.
+#[doc(alias = "AudioFileRegion")]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Region {
+    pub id: u32,
+    pub name: String,
+    pub flags: RegionFlags,
+    pub markers: Vec<Marker>,
+}
+
+impl Region {
+    pub fn with_range(id: u32, name: &str, start: u64, end: u64) -> Self {
+        let marker = |type_, frame_pos: u64| Marker {
+            frame_pos: frame_pos as f64,
+            name: String::new(),
+            id,
+            type_,
+        };
+        Self {
+            id,
+            name: name.to_string(),
+            flags: Default::default(),
+            markers: vec![
+                marker(MarkerType::REGION_START, start),
+                marker(MarkerType::REGION_END, end),
+            ],
+        }
+    }
+
+    /// Frame range from the first to the last marker.
+    pub fn range(&self) -> Option<std::ops::Range<u64>> {
+        let start = self.markers.first()?.frame_pos as u64;
+        let end = self.markers.last()?.frame_pos as u64;
+        Some(start..end.max(start))
+    }
+}
+
+/// Reads a field, `pos` is advanced.
+struct Cursor<'a> {
+    id: [u8; 4],
+    b: &'a [u8],
+    pos: usize,
+    be: bool,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(id: [u8; 4], b: &'a [u8], be: bool) -> Self {
+        Self { id, b, pos: 0, be }
+    }
+
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        let res = self
+            .b
+            .get(self.pos..self.pos + n)
+            .ok_or(Error::Corrupt(self.id, "truncated"))?;
+        self.pos += n;
+        Ok(res)
+    }
+
+    fn id(&mut self) -> Result<[u8; 4]> {
+        Ok(self.bytes(4)?.try_into().unwrap())
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        let b = self.bytes(2)?.try_into().unwrap();
+        Ok(if self.be {
+            u16::from_be_bytes(b)
+        } else {
+            u16::from_le_bytes(b)
+        })
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let b = self.bytes(4)?.try_into().unwrap();
+        Ok(if self.be {
+            u32::from_be_bytes(b)
+        } else {
+            u32::from_le_bytes(b)
+        })
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        let b = self.bytes(8)?.try_into().unwrap();
+        Ok(if self.be {
+            u64::from_be_bytes(b)
+        } else {
+            u64::from_le_bytes(b)
+        })
+    }
+
+    /// NUL terminated or up to the end.
+    fn z_str(&mut self) -> String {
+        let rest = &self.b[self.pos.min(self.b.len())..];
+        let len = rest.iter().position(|&c| c == 0).unwrap_or(rest.len());
+        self.pos += rest.len();
+        String::from_utf8_lossy(&rest[..len]).into_owned()
+    }
+
+    /// Pascal string padded to even length with the count byte.
+    fn p_str(&mut self) -> Result<String> {
+        let len = self.bytes(1)?[0] as usize;
+        let s = self.bytes(len)?;
+        if len.is_multiple_of(2) {
+            self.bytes(1)?;
+        }
+        Ok(String::from_utf8_lossy(s).into_owned())
+    }
+}
+
+fn write_p_str(dst: &mut Vec<u8>, s: &str) {
+    let b = &s.as_bytes()[..s.len().min(255)];
+    dst.push(b.len() as u8);
+    dst.extend_from_slice(b);
+    if b.len().is_multiple_of(2) {
+        dst.push(0);
+    }
+}
+
+/// IEEE 754 80-bit extended sample rate of `COMM` chunk.
+fn ext80_to_f64(b: &[u8; 10]) -> f64 {
+    let exp = (u16::from_be_bytes([b[0], b[1]]) & 0x7fff) as i32;
+    let mant = u64::from_be_bytes(b[2..10].try_into().unwrap());
+    if exp == 0 && mant == 0 {
+        return 0.0;
+    }
+    let val = mant as f64 * 2f64.powi(exp - 16383 - 63);
+    if b[0] & 0x80 != 0 { -val } else { val }
+}
+
+fn f64_to_ext80(val: f64) -> [u8; 10] {
+    let mut res = [0u8; 10];
+    if val == 0.0 || !val.is_finite() {
+        return res;
+    }
+    let bits = val.abs().to_bits();
+    let exp = ((bits >> 52) & 0x7ff) as i32 - 1023 + 16383;
+    let mant = ((bits & ((1 << 52) - 1)) | (1 << 52)) << 11;
+    let sign = if val < 0.0 { 0x8000 } else { 0 };
+    res[0..2].copy_from_slice(&(sign | exp as u16).to_be_bytes());
+    res[2..10].copy_from_slice(&mant.to_be_bytes());
+    res
+}
+
+fn lpcm_flags(float: bool, signed: bool, be: bool, bits: u32, size: u32) -> FormatFlags {
+    let mut res = if float {
+        FormatFlags::IS_FLOAT
+    } else if signed {
+        FormatFlags::IS_SIGNED_INTEGER
+    } else {
+        FormatFlags(0)
+    };
+    if be {
+        res |= FormatFlags::IS_BIG_ENDIAN;
+    }
+    if bits == size * 8 {
+        res |= FormatFlags::IS_PACKED;
+    } else {
+        // both WAVE and AIFF samples are left justified
+        res |= FormatFlags::IS_ALIGNED_HIGH;
+    }
+    res
+}
+
+fn desc_with(
+    sample_rate: f64,
+    format: Format,
+    format_flags: FormatFlags,
+    channels: u32,
+    bits: u32,
+    size: u32,
+) -> StreamBasicDesc {
+    StreamBasicDesc {
+        sample_rate,
+        format,
+        format_flags,
+        bytes_per_packet: size * channels,
+        frames_per_packet: 1,
+        bytes_per_frame: size * channels,
+        channels_per_frame: channels,
+        bits_per_channel: bits,
+        reserved: 0,
+    }
+}
+
+fn read_fmt(b: &[u8]) -> Result<(StreamBasicDesc, Option<ChannelBitmap>)> {
+    let mut c = Cursor::new(FMT, b, false);
+    let mut tag = c.u16()?;
+    let channels = c.u16()? as u32;
+    let sample_rate = c.u32()? as f64;
+    let _bytes_per_sec = c.u32()?;
+    let block_align = c.u16()? as u32;
+    let container_bits = c.u16()? as u32;
+    let mut bits = container_bits;
+    let mut bitmap = None;
+    if tag == WAVE_FORMAT_EXTENSIBLE {
+        if c.u16()? < 22 {
+            return Err(Error::Corrupt(FMT, "extensible format is too short"));
+        }
+        let valid = c.u16()? as u32;
+        if valid != 0 {
+            bits = valid;
+        }
+        let mask = c.u32()?;
+        if mask != 0 {
+            bitmap = Some(ChannelBitmap(mask));
+        }
+        tag = c.u16()?;
+        if c.bytes(14)? != SUBTYPE_GUID_TAIL {
+            return Err(Error::InvalidFormat("unknown extensible sub format"));
+        }
+    }
+    if channels == 0 || block_align == 0 || !block_align.is_multiple_of(channels) {
+        return Err(Error::Corrupt(FMT, "invalid block align"));
+    }
+    let size = block_align / channels;
+    if bits == 0 || bits > size * 8 {
+        return Err(Error::Corrupt(FMT, "invalid bits per sample"));
+    }
+    let desc = match tag {
+        WAVE_FORMAT_PCM => {
+            let flags = lpcm_flags(false, size > 1, false, bits, size);
+            desc_with(sample_rate, Format::LINEAR_PCM, flags, channels, bits, size)
+        }
+        WAVE_FORMAT_IEEE_FLOAT if matches!(size, 4 | 8) => {
+            let flags = lpcm_flags(true, true, false, bits, size);
+            desc_with(sample_rate, Format::LINEAR_PCM, flags, channels, bits, size)
+        }
+        WAVE_FORMAT_ALAW | WAVE_FORMAT_MULAW if size == 1 => {
+            let format = if tag == WAVE_FORMAT_ALAW {
+                Format::A_LAW
+            } else {
+                Format::U_LAW
+            };
+            desc_with(sample_rate, format, FormatFlags(0), channels, 8, 1)
+        }
+        _ => return Err(Error::InvalidFormat("unsupported WAVE format")),
+    };
+    Ok((desc, bitmap))
+}
+
+/// `fmt ` chunk body, extensible if the plain one can't describe the stream.
+fn write_fmt(desc: &StreamBasicDesc, bitmap: Option<ChannelBitmap>) -> Result<Vec<u8>> {
+    let flags = desc.format_flags;
+    let size = desc.bytes_per_frame / desc.channels_per_frame.max(1);
+    let tag = match desc.format {
+        Format::A_LAW => WAVE_FORMAT_ALAW,
+        Format::U_LAW => WAVE_FORMAT_MULAW,
+        _ if flags.contains(FormatFlags::IS_FLOAT) => WAVE_FORMAT_IEEE_FLOAT,
+        _ => WAVE_FORMAT_PCM,
+    };
+    let extensible = bitmap.is_some()
+        || desc.channels_per_frame > 2
+        || desc.bits_per_channel != size * 8
+        || (tag == WAVE_FORMAT_PCM && size > 2);
+
+    let mut b = Vec::with_capacity(40);
+    let tag_field = if extensible {
+        WAVE_FORMAT_EXTENSIBLE
+    } else {
+        tag
+    };
+    b.extend_from_slice(&tag_field.to_le_bytes());
+    b.extend_from_slice(&(desc.channels_per_frame as u16).to_le_bytes());
+    b.extend_from_slice(&(desc.sample_rate.round() as u32).to_le_bytes());
+    let bytes_per_sec = desc.sample_rate.round() as u32 * desc.bytes_per_frame;
+    b.extend_from_slice(&bytes_per_sec.to_le_bytes());
+    b.extend_from_slice(&(desc.bytes_per_frame as u16).to_le_bytes());
+    b.extend_from_slice(&(size as u16 * 8).to_le_bytes());
+    if extensible {
+        b.extend_from_slice(&22u16.to_le_bytes());
+        b.extend_from_slice(&(desc.bits_per_channel as u16).to_le_bytes());
+        b.extend_from_slice(&bitmap.unwrap_or_default().0.to_le_bytes());
+        b.extend_from_slice(&tag.to_le_bytes());
+        b.extend_from_slice(&SUBTYPE_GUID_TAIL);
+    } else if tag != WAVE_FORMAT_PCM {
+        b.extend_from_slice(&0u16.to_le_bytes());
+    }
+    Ok(b)
+}
+
+fn read_comm(b: &[u8], aifc: bool) -> Result<(StreamBasicDesc, u32)> {
+    let mut c = Cursor::new(COMM, b, true);
+    let channels = c.u16()? as u32;
+    let frames = c.u32()?;
+    let bits = c.u16()? as u32;
+    let sample_rate = ext80_to_f64(c.bytes(10)?.try_into().unwrap());
+    let compression = if aifc { c.id()? } else { *b"NONE" };
+    if channels == 0 || bits == 0 || bits > 64 {
+        return Err(Error::Corrupt(COMM, "invalid channels or sample size"));
+    }
+    let size = bits.div_ceil(8);
+    let desc = match &compression {
+        b"NONE" | b"twos" | b"sowt" if bits <= 32 => {
+            let flags = lpcm_flags(false, true, &compression != b"sowt", bits, size);
+            desc_with(sample_rate, Format::LINEAR_PCM, flags, channels, bits, size)
+        }
+        b"fl32" | b"FL32" => {
+            let flags = lpcm_flags(true, true, true, 32, 4);
+            desc_with(sample_rate, Format::LINEAR_PCM, flags, channels, 32, 4)
+        }
+        b"fl64" | b"FL64" => {
+            let flags = lpcm_flags(true, true, true, 64, 8);
+            desc_with(sample_rate, Format::LINEAR_PCM, flags, channels, 64, 8)
+        }
+        b"ulaw" | b"ULAW" => desc_with(sample_rate, Format::U_LAW, FormatFlags(0), channels, 8, 1),
+        b"alaw" | b"ALAW" => desc_with(sample_rate, Format::A_LAW, FormatFlags(0), channels, 8, 1),
+        _ => return Err(Error::InvalidFormat("unsupported AIFC compression")),
+    };
+    Ok((desc, frames))
+}
+
+/// AIFC compression type and name.
+fn aifc_compression(desc: &StreamBasicDesc) -> ([u8; 4], &'static str) {
+    match desc.format {
+        Format::U_LAW => (*b"ulaw", "uLaw 2:1"),
+        Format::A_LAW => (*b"alaw", "aLaw 2:1"),
+        _ if desc.format_flags.contains(FormatFlags::IS_FLOAT) => {
+            if desc.bits_per_channel == 64 {
+                (*b"fl64", "64-bit floating point")
+            } else {
+                (*b"fl32", "32-bit floating point")
+            }
+        }
+        _ if !desc.format_flags.contains(FormatFlags::IS_BIG_ENDIAN) => (*b"sowt", ""),
+        _ => (*b"NONE", "not compressed"),
+    }
+}
+
+/// Checks the stream can be stored in the file type.
+fn validate(file_type: FileType, desc: &StreamBasicDesc) -> Result {
+    if desc.sample_rate <= 0.0 || desc.channels_per_frame == 0 {
+        return Err(Error::InvalidFormat("sample rate and channels must be set"));
+    }
+    if desc.frames_per_packet != 1
+        || desc.bytes_per_packet != desc.bytes_per_frame
+        || desc.bytes_per_frame == 0
+        || !desc.bytes_per_frame.is_multiple_of(desc.channels_per_frame)
+    {
+        return Err(Error::InvalidFormat("packet must be one frame"));
+    }
+    if !desc.is_interleaved() && desc.channels_per_frame > 1 {
+        return Err(Error::InvalidFormat("audio data is always interleaved"));
+    }
+    if desc.channels_per_frame > u16::MAX as u32 || desc.bytes_per_frame > u16::MAX as u32 {
+        return Err(Error::InvalidFormat("too many channels"));
+    }
+    let size = desc.bytes_per_frame / desc.channels_per_frame;
+    let flags = desc.format_flags;
+    match desc.format {
+        Format::A_LAW | Format::U_LAW if size == 1 => {
+            return if file_type == FileType::AIFF {
+                Err(Error::InvalidFormat("AIFF is not compressed"))
+            } else {
+                Ok(())
+            };
+        }
+        Format::LINEAR_PCM => {}
+        _ => return Err(Error::InvalidFormat("only linear PCM, A-law and u-law")),
+    }
+    if flags.0 & FormatFlags::LINEAR_PCM_SAMPLE_FRACTION_MASK.0 != 0 {
+        return Err(Error::InvalidFormat("fixed point"));
+    }
+    let bits = desc.bits_per_channel;
+    let float = flags.contains(FormatFlags::IS_FLOAT);
+    let be = flags.contains(FormatFlags::IS_BIG_ENDIAN) && size > 1;
+    let signed = float || flags.contains(FormatFlags::IS_SIGNED_INTEGER);
+    let aligned_high = bits == size * 8 || flags.contains(FormatFlags::IS_ALIGNED_HIGH);
+    if float && !matches!((bits, size), (32, 4) | (64, 8)) {
+        return Err(Error::InvalidFormat("float is 32 or 64 bit"));
+    }
+    if bits == 0 || bits > size * 8 || size > 4 && !float {
+        return Err(Error::InvalidFormat("bits per channel"));
+    }
+    if !aligned_high {
+        return Err(Error::InvalidFormat("samples are left justified"));
+    }
+    if file_type.is_riff() {
+        if be {
+            return Err(Error::InvalidFormat("WAVE is little endian"));
+        }
+        if signed != (size > 1 || float) {
+            return Err(Error::InvalidFormat(
+                "WAVE 8 bit is unsigned, wider is signed",
+            ));
+        }
+    } else {
+        if !signed {
+            return Err(Error::InvalidFormat("AIFF samples are signed"));
+        }
+        if file_type == FileType::AIFF && (float || (!be && size > 1)) {
+            return Err(Error::InvalidFormat("AIFF is big endian integer"));
+        }
+        if float && !be {
+            return Err(Error::InvalidFormat("AIFC float is big endian"));
+        }
+    }
+    Ok(())
+}
+
+fn write_chunk<W: Write>(w: &mut W, id: [u8; 4], body: &[u8], be: bool) -> io::Result<()> {
+    w.write_all(&id)?;
+    let len = body.len() as u32;
+    w.write_all(&if be {
+        len.to_be_bytes()
+    } else {
+        len.to_le_bytes()
+    })?;
+    w.write_all(body)?;
+    if !body.len().is_multiple_of(2) {
+        w.write_all(&[0])?;
+    }
+    Ok(())
+}
+
+fn read_riff_info(b: &[u8], info: &mut Vec<(String, String)>) {
+    let mut c = Cursor::new(INFO, b, false);
+    while let (Ok(id), Ok(len)) = (c.id(), c.u32()) {
+        let Ok(body) = c.bytes(len as usize) else {
+            break;
+        };
+        if !len.is_multiple_of(2) {
+            let _ = c.bytes(1);
+        }
+        let key = RIFF_INFO_KEYS.iter().find(|(k, _)| *k == id).map_or_else(
+            || String::from_utf8_lossy(&id).into_owned(),
+            |(_, k)| k.to_string(),
+        );
+        let value = Cursor::new(id, body, false).z_str();
+        info.push((key, value));
+    }
+}
+
+/// Chunk id of the info key, CAF key or four character id itself.
+fn info_id(keys: &[([u8; 4], &str)], key: &str) -> Option<[u8; 4]> {
+    keys.iter()
+        .find(|(_, k)| *k == key)
+        .map(|(id, _)| *id)
+        .or_else(|| key.as_bytes().try_into().ok())
+}
+
+/// Names and region lengths of `LIST/adtl` by cue id.
+#[derive(Default)]
+struct Adtl {
+    labels: Vec<(u32, String)>,
+    lengths: Vec<(u32, u32)>,
+}
+
+fn read_adtl(b: &[u8], adtl: &mut Adtl) {
+    let mut c = Cursor::new(ADTL, b, false);
+    while let (Ok(id), Ok(len)) = (c.id(), c.u32()) {
+        let Ok(body) = c.bytes(len as usize) else {
+            break;
+        };
+        if !len.is_multiple_of(2) {
+            let _ = c.bytes(1);
+        }
+        let mut sub = Cursor::new(id, body, false);
+        let Ok(cue_id) = sub.u32() else {
+            continue;
+        };
+        match id {
+            LABL => adtl.labels.push((cue_id, sub.z_str())),
+            LTXT => {
+                if let Ok(len) = sub.u32() {
+                    adtl.lengths.push((cue_id, len));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn read_cue(b: &[u8]) -> Result<Vec<(u32, u32)>> {
+    let mut c = Cursor::new(CUE, b, false);
+    let n = c.u32()? as usize;
+    let mut res = Vec::with_capacity(n.min(b.len() / 24));
+    for _ in 0..n {
+        let id = c.u32()?;
+        let _position = c.u32()?;
+        let _chunk = c.id()?;
+        let _chunk_start = c.u32()?;
+        let _block_start = c.u32()?;
+        res.push((id, c.u32()?));
+    }
+    Ok(res)
+}
+
+fn read_mark(b: &[u8]) -> Result<Vec<Marker>> {
+    let mut c = Cursor::new(MARK, b, true);
+    let n = c.u16()? as usize;
+    let mut res = Vec::with_capacity(n.min(b.len() / 8));
+    for _ in 0..n {
+        let id = c.u16()? as u32;
+        let frame_pos = c.u32()? as f64;
+        res.push(Marker {
+            frame_pos,
+            name: c.p_str()?,
+            id,
+            type_: MarkerType::GENERIC,
+        });
+    }
+    Ok(res)
+}
+
+/// Demuxes WAVE and AIFF files.
+///
+/// All chunks except audio data are read upfront by [`Reader::new`].
+pub struct Reader<R> {
+    inner: R,
+    file_type: FileType,
+    desc: StreamBasicDesc,
+    channel_bitmap: Option<ChannelBitmap>,
+    info: Vec<(String, String)>,
+    markers: Vec<Marker>,
+    regions: Vec<Region>,
+    data_offset: u64,
+    data_len: u64,
+}
+
+impl<R: Read + Seek> Reader<R> {
+    pub fn new(mut inner: R) -> Result<Self> {
+        let file_len = inner.seek(SeekFrom::End(0))?;
+        inner.seek(SeekFrom::Start(0))?;
+
+        let mut header = [0u8; 12];
+        inner
+            .read_exact(&mut header)
+            .map_err(|_| Error::Unrecognized)?;
+        let id: [u8; 4] = header[0..4].try_into().unwrap();
+        let form: [u8; 4] = header[8..12].try_into().unwrap();
+        let file_type = match (id, form) {
+            (RIFF, WAVE) => FileType::WAVE,
+            (RF64, WAVE) => FileType::RF64,
+            (BW64, WAVE) => FileType::BW64,
+            (FORM, AIFF) => FileType::AIFF,
+            (FORM, AIFC) => FileType::AIFC,
+            _ => return Err(Error::Unrecognized),
+        };
+        let mut res = Self {
+            inner,
+            file_type,
+            desc: Default::default(),
+            channel_bitmap: None,
+            info: Vec::new(),
+            markers: Vec::new(),
+            regions: Vec::new(),
+            data_offset: 0,
+            data_len: 0,
+        };
+        if file_type.is_riff() {
+            res.read_riff(file_len)?;
+        } else {
+            res.read_iff(file_len)?;
+        }
+        Ok(res)
+    }
+
+    /// Walks chunks calling `f` with id, body position and size clamped to the file.
+    /// Bodies of `wanted` chunks are passed to `f`, other chunks are skipped.
+    fn walk(
+        &mut self,
+        file_len: u64,
+        be: bool,
+        mut size_of: impl FnMut([u8; 4], u32) -> u64,
+        mut f: impl FnMut(&mut Self, [u8; 4], u64, u64, Option<Vec<u8>>) -> Result,
+        wanted: &[[u8; 4]],
+    ) -> Result {
+        let mut pos = 12u64;
+        self.inner.seek(SeekFrom::Start(pos))?;
+        while pos + 8 <= file_len {
+            let mut h = [0u8; 8];
+            self.inner.read_exact(&mut h)?;
+            let id: [u8; 4] = h[0..4].try_into().unwrap();
+            let size = h[4..8].try_into().unwrap();
+            let size = if be {
+                u32::from_be_bytes(size)
+            } else {
+                u32::from_le_bytes(size)
+            };
+            pos += 8;
+            let avail = file_len - pos;
+            let size = size_of(id, size);
+            let len = size.min(avail);
+            let body = if wanted.contains(&id) {
+                if size > avail {
+                    return Err(Error::Corrupt(id, "truncated"));
+                }
+                let mut body = vec![0u8; size as usize];
+                self.inner.read_exact(&mut body)?;
+                Some(body)
+            } else {
+                None
+            };
+            f(self, id, pos, len, body)?;
+            pos = pos.saturating_add(len + (len & 1));
+            self.inner.seek(SeekFrom::Start(pos.min(file_len)))?;
+        }
+        Ok(())
+    }
+
+    fn read_riff(&mut self, file_len: u64) -> Result {
+        // ds64 is the first chunk of RF64 and BW64
+        let mut ds64_data_len = None;
+        if self.file_type != FileType::WAVE {
+            let mut h = [0u8; 8 + DS64_LEN];
+            self.inner
+                .read_exact(&mut h)
+                .map_err(|_| Error::MissingChunk(DS64))?;
+            if h[0..4] != DS64 {
+                return Err(Error::MissingChunk(DS64));
+            }
+            let mut c = Cursor::new(DS64, &h[8..], false);
+            let _riff_len = c.u64()?;
+            ds64_data_len = Some(c.u64()?);
+        }
+
+        let mut fmt = None;
+        let mut data = None;
+        let mut cue = Vec::new();
+        let mut adtl = Adtl::default();
+        let size_of = |id: [u8; 4], size: u32| match (id, ds64_data_len) {
+            // 0 is a ds64 never patched after crash
+            (DATA, Some(len)) if size == u32::MAX => {
+                if len == 0 {
+                    u64::MAX
+                } else {
+                    len
+                }
+            }
+            // placeholder of unfinished writer
+            (DATA, None) if size == u32::MAX => u64::MAX,
+            _ => size as u64,
+        };
+        self.walk(
+            file_len,
+            false,
+            size_of,
+            |r, id, pos, len, body| {
+                match (id, body) {
+                    (DATA, _) if data.is_none() => data = Some((pos, len)),
+                    (FMT, Some(body)) => fmt = Some(read_fmt(&body)?),
+                    (CUE, Some(body)) => cue = read_cue(&body)?,
+                    (LIST, Some(body)) if body.len() >= 4 => match &body[0..4] {
+                        b"INFO" => read_riff_info(&body[4..], &mut r.info),
+                        b"adtl" => read_adtl(&body[4..], &mut adtl),
+                        _ => {}
+                    },
+                    _ => {}
+                }
+                Ok(())
+            },
+            &[FMT, CUE, LIST],
+        )?;
+
+        (self.desc, self.channel_bitmap) = fmt.ok_or(Error::MissingChunk(FMT))?;
+        (self.data_offset, self.data_len) = data.ok_or(Error::MissingChunk(DATA))?;
+        self.data_len -= self.data_len % self.desc.bytes_per_frame as u64;
+
+        let name = |id| {
+            adtl.labels
+                .iter()
+                .find(|(i, _)| *i == id)
+                .map(|(_, n)| n.clone())
+                .unwrap_or_default()
+        };
+        for (id, offset) in cue {
+            let marker = |type_, frame_pos: u64| Marker {
+                frame_pos: frame_pos as f64,
+                name: String::new(),
+                id,
+                type_,
+            };
+            if let Some((_, len)) = adtl.lengths.iter().find(|(i, _)| *i == id) {
+                let start = offset as u64;
+                self.regions.push(Region {
+                    id,
+                    name: name(id),
+                    flags: Default::default(),
+                    markers: vec![
+                        marker(MarkerType::REGION_START, start),
+                        marker(MarkerType::REGION_END, start + *len as u64),
+                    ],
+                });
+            } else {
+                self.markers.push(Marker {
+                    name: name(id),
+                    ..marker(MarkerType::GENERIC, offset as u64)
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn read_iff(&mut self, file_len: u64) -> Result {
+        let aifc = self.file_type == FileType::AIFC;
+        let mut comm = None;
+        let mut data = None;
+        self.walk(
+            file_len,
+            true,
+            |id, size| {
+                if id == SSND && size == u32::MAX {
+                    u64::MAX
+                } else {
+                    size as u64
+                }
+            },
+            |r, id, pos, len, body| {
+                match (id, body) {
+                    (SSND, _) => {
+                        let mut h = [0u8; 8];
+                        r.inner
+                            .read_exact(&mut h)
+                            .map_err(|_| Error::Corrupt(SSND, "truncated"))?;
+                        let offset = u32::from_be_bytes(h[0..4].try_into().unwrap()) as u64;
+                        if len < 8 + offset {
+                            return Err(Error::Corrupt(SSND, "invalid offset"));
+                        }
+                        data = Some((pos + 8 + offset, len - 8 - offset));
+                    }
+                    (COMM, Some(body)) => comm = Some(read_comm(&body, aifc)?),
+                    (MARK, Some(body)) => r.markers = read_mark(&body)?,
+                    (id, Some(body)) => {
+                        if let Some((_, key)) = AIFF_INFO_KEYS.iter().find(|(k, _)| *k == id) {
+                            let value = String::from_utf8_lossy(&body).into_owned();
+                            r.info.push((key.to_string(), value));
+                        }
+                    }
+                    _ => {}
+                }
+                Ok(())
+            },
+            &[
+                COMM,
+                MARK,
+                AIFF_INFO_KEYS[0].0,
+                AIFF_INFO_KEYS[1].0,
+                AIFF_INFO_KEYS[2].0,
+                AIFF_INFO_KEYS[3].0,
+            ],
+        )?;
+        let (desc, frames) = comm.ok_or(Error::MissingChunk(COMM))?;
+        self.desc = desc;
+        (self.data_offset, self.data_len) = data.ok_or(Error::MissingChunk(SSND))?;
+        let bpf = desc.bytes_per_frame as u64;
+        let len = frames as u64 * bpf;
+        // frames are 0 until writer is finished
+        if frames != 0 && len <= self.data_len {
+            self.data_len = len;
+        }
+        self.data_len -= self.data_len % bpf;
+        Ok(())
+    }
+
+    #[inline]
+    pub fn file_type(&self) -> FileType {
+        self.file_type
+    }
+
+    #[inline]
+    pub fn desc(&self) -> &StreamBasicDesc {
+        &self.desc
+    }
+
+    /// `dwChannelMask` of extensible WAVE format.
+    #[inline]
+    pub fn channel_bitmap(&self) -> Option<ChannelBitmap> {
+        self.channel_bitmap
+    }
+
+    /// Key value pairs of `LIST/INFO` or AIFF text chunks in file order.
+    #[inline]
+    pub fn info(&self) -> &[(String, String)] {
+        &self.info
+    }
+
+    #[inline]
+    pub fn markers(&self) -> &[Marker] {
+        &self.markers
+    }
+
+    #[inline]
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    /// Byte offset of the audio data in the file.
+    #[inline]
+    pub fn data_offset(&self) -> u64 {
+        self.data_offset
+    }
+
+    #[inline]
+    pub fn data_byte_count(&self) -> u64 {
+        self.data_len
+    }
+
+    #[inline]
+    pub fn frames_num(&self) -> u64 {
+        self.data_len / self.desc.bytes_per_frame as u64
+    }
+
+    /// Reads up to `max` frames starting with `start` frame into `buf`.
+    ///
+    /// `buf` is cleared. Returns the number of frames read.
+    pub fn read_frames(&mut self, start: u64, max: usize, buf: &mut Vec<u8>) -> Result<usize> {
+        buf.clear();
+        let total = self.frames_num();
+        if start >= total || max == 0 {
+            return Ok(0);
+        }
+        let n = (total - start).min(max as u64) as usize;
+        let bpf = self.desc.bytes_per_frame as u64;
+        self.inner
+            .seek(SeekFrom::Start(self.data_offset + start * bpf))?;
+        buf.resize(n * bpf as usize, 0);
+        self.inner.read_exact(buf)?;
+        Ok(n)
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Positions of header fields patched with final sizes.
+#[derive(Debug, Clone, Copy)]
+struct Header {
+    start: u64,
+    /// `JUNK` reserved for, or `ds64` chunk.
+    ds64_pos: Option<u64>,
+    /// `COMM` frames of AIFF.
+    frames_pos: Option<u64>,
+    data_size_pos: u64,
+    data_start: u64,
+}
+
+/// Muxes WAVE and AIFF files.
+///
+/// Format and info chunks are written before the first frame, markers and
+/// regions go after audio data on [`Writer::finish`].
+pub struct Writer<W: Write + Seek> {
+    inner: W,
+    file_type: FileType,
+    desc: StreamBasicDesc,
+    channel_bitmap: Option<ChannelBitmap>,
+    info: Vec<([u8; 4], String)>,
+    markers: Vec<Marker>,
+    regions: Vec<Region>,
+    header: Option<Header>,
+    data_len: u64,
+}
+
+impl<W: Write + Seek> Writer<W> {
+    pub fn new(inner: W, file_type: FileType, desc: &StreamBasicDesc) -> Result<Self> {
+        if !file_type.is_riff() && !file_type.is_iff() {
+            return Err(Error::InvalidFormat("unsupported file type"));
+        }
+        validate(file_type, desc)?;
+        Ok(Self {
+            inner,
+            file_type,
+            desc: *desc,
+            channel_bitmap: None,
+            info: Vec::new(),
+            markers: Vec::new(),
+            regions: Vec::new(),
+            header: None,
+            data_len: 0,
+        })
+    }
+
+    fn ensure_header_not_written(&self) -> Result {
+        if self.header.is_some() {
+            return Err(Error::DataStarted);
+        }
+        Ok(())
+    }
+
+    /// `dwChannelMask` of WAVE, bits must match channels number.
+    pub fn set_channel_bitmap(&mut self, val: ChannelBitmap) -> Result {
+        self.ensure_header_not_written()?;
+        if !self.file_type.is_riff() {
+            return Err(Error::InvalidFormat("channel mask is WAVE only"));
+        }
+        if val.0.count_ones() != self.desc.channels_per_frame {
+            return Err(Error::InvalidFormat("channel mask doesn't match channels"));
+        }
+        self.channel_bitmap = Some(val);
+        Ok(())
+    }
+
+    /// CAF info key like "title" or four character chunk id.
+    pub fn add_info(&mut self, key: &str, value: &str) -> Result {
+        self.ensure_header_not_written()?;
+        let keys: &[_] = if self.file_type.is_riff() {
+            &RIFF_INFO_KEYS
+        } else {
+            &AIFF_INFO_KEYS
+        };
+        let id = info_id(keys, key).ok_or(Error::InvalidFormat("unknown info key"))?;
+        self.info.push((id, value.to_string()));
+        Ok(())
+    }
+
+    pub fn set_markers(&mut self, val: Vec<Marker>) -> Result {
+        if self.file_type.is_iff() && val.iter().any(|m| m.id == 0 || m.id > i16::MAX as u32) {
+            return Err(Error::InvalidFormat("AIFF marker id is 1..=32767"));
+        }
+        self.markers = val;
+        Ok(())
+    }
+
+    pub fn set_regions(&mut self, val: Vec<Region>) -> Result {
+        if !self.file_type.is_riff() {
+            return Err(Error::InvalidFormat("regions are WAVE only"));
+        }
+        if val.iter().any(|r| r.range().is_none()) {
+            return Err(Error::InvalidFormat("region without markers"));
+        }
+        self.regions = val;
+        Ok(())
+    }
+
+    #[inline]
+    pub fn frames_num(&self) -> u64 {
+        self.data_len / self.desc.bytes_per_frame as u64
+    }
+
+    fn write_header(&mut self) -> Result<Header> {
+        if let Some(header) = self.header {
+            return Ok(header);
+        }
+        let header = if self.file_type.is_riff() {
+            self.write_riff_header()?
+        } else {
+            self.write_iff_header()?
+        };
+        self.header = Some(header);
+        Ok(header)
+    }
+
+    fn write_riff_header(&mut self) -> Result<Header> {
+        let w = &mut self.inner;
+        let start = w.stream_position()?;
+        let id = match self.file_type {
+            FileType::RF64 => RF64,
+            FileType::BW64 => BW64,
+            _ => RIFF,
+        };
+        w.write_all(&id)?;
+        w.write_all(&u32::MAX.to_le_bytes())?;
+        w.write_all(&WAVE)?;
+        let ds64_pos = w.stream_position()?;
+        let reserved = if id == RIFF { JUNK } else { DS64 };
+        write_chunk(w, reserved, &[0u8; DS64_LEN], false)?;
+        write_chunk(w, FMT, &write_fmt(&self.desc, self.channel_bitmap)?, false)?;
+        if !self.info.is_empty() {
+            let mut body = INFO.to_vec();
+            for (id, value) in &self.info {
+                let mut s: Vec<u8> = value.bytes().take_while(|&c| c != 0).collect();
+                s.push(0);
+                body.extend_from_slice(id);
+                body.extend_from_slice(&(s.len() as u32).to_le_bytes());
+                body.extend_from_slice(&s);
+                if !s.len().is_multiple_of(2) {
+                    body.push(0);
+                }
+            }
+            write_chunk(w, LIST, &body, false)?;
+        }
+        w.write_all(&DATA)?;
+        let data_size_pos = w.stream_position()?;
+        w.write_all(&u32::MAX.to_le_bytes())?;
+        Ok(Header {
+            start,
+            ds64_pos: Some(ds64_pos),
+            frames_pos: None,
+            data_size_pos,
+            data_start: w.stream_position()?,
+        })
+    }
+
+    fn write_iff_header(&mut self) -> Result<Header> {
+        let aifc = self.file_type == FileType::AIFC;
+        let w = &mut self.inner;
+        let start = w.stream_position()?;
+        w.write_all(&FORM)?;
+        w.write_all(&u32::MAX.to_be_bytes())?;
+        w.write_all(if aifc { &AIFC } else { &AIFF })?;
+        if aifc {
+            write_chunk(w, FVER, &AIFC_VERSION.to_be_bytes(), true)?;
+        }
+        let mut comm = Vec::with_capacity(40);
+        comm.extend_from_slice(&(self.desc.channels_per_frame as u16).to_be_bytes());
+        comm.extend_from_slice(&0u32.to_be_bytes());
+        comm.extend_from_slice(&(self.desc.bits_per_channel as u16).to_be_bytes());
+        comm.extend_from_slice(&f64_to_ext80(self.desc.sample_rate));
+        if aifc {
+            let (id, name) = aifc_compression(&self.desc);
+            comm.extend_from_slice(&id);
+            write_p_str(&mut comm, name);
+        }
+        let comm_pos = w.stream_position()?;
+        write_chunk(w, COMM, &comm, true)?;
+        for (id, value) in &self.info {
+            write_chunk(w, *id, value.as_bytes(), true)?;
+        }
+        w.write_all(&SSND)?;
+        let data_size_pos = w.stream_position()?;
+        w.write_all(&u32::MAX.to_be_bytes())?;
+        // offset and block size
+        w.write_all(&[0u8; 8])?;
+        Ok(Header {
+            start,
+            ds64_pos: None,
+            frames_pos: Some(comm_pos + 10),
+            data_size_pos,
+            data_start: w.stream_position()?,
+        })
+    }
+
+    /// Appends whole interleaved frames.
+    pub fn write_frames(&mut self, data: &[u8]) -> Result {
+        let bpf = self.desc.bytes_per_frame as usize;
+        if !data.len().is_multiple_of(bpf) {
+            return Err(Error::InvalidFormat("partial frame"));
+        }
+        let header = self.write_header()?;
+        let len = self.data_len + data.len() as u64;
+        let limit = match self.file_type {
+            FileType::AIFF | FileType::AIFC => u32::MAX as u64 - (header.data_start - header.start),
+            _ => u64::MAX,
+        };
+        if len > limit {
+            return Err(Error::InvalidFormat("AIFF is limited to 4 GB"));
+        }
+        self.inner.write_all(data)?;
+        self.data_len = len;
+        Ok(())
+    }
+
+    /// Patches sizes to cover frames written so far, so the file is complete
+    /// without markers and regions if the process dies.
+    pub fn sync(&mut self) -> Result {
+        let header = self.write_header()?;
+        let end = header.data_start + self.data_len;
+        self.patch_sizes(&header, end)?;
+        self.inner.seek(SeekFrom::Start(end))?;
+        self.inner.flush()?;
+        Ok(())
+    }
+
+    fn patch_sizes(&mut self, header: &Header, end: u64) -> Result {
+        let frames = self.frames_num();
+        let w = &mut self.inner;
+        let form_len = end - header.start - 8;
+        if let Some(frames_pos) = header.frames_pos {
+            // frames and sound data are within the form
+            let Ok(form_len) = u32::try_from(form_len) else {
+                return Err(Error::InvalidFormat("AIFF is limited to 4 GB"));
+            };
+            w.seek(SeekFrom::Start(header.start + 4))?;
+            w.write_all(&form_len.to_be_bytes())?;
+            w.seek(SeekFrom::Start(frames_pos))?;
+            w.write_all(&(frames as u32).to_be_bytes())?;
+            w.seek(SeekFrom::Start(header.data_size_pos))?;
+            w.write_all(&(self.data_len as u32 + 8).to_be_bytes())?;
+            return Ok(());
+        }
+
+        let ds64_pos = header.ds64_pos.unwrap();
+        let is_64 = self.file_type != FileType::WAVE || form_len > u32::MAX as u64;
+        if is_64 {
+            let id = if self.file_type == FileType::BW64 {
+                BW64
+            } else {
+                RF64
+            };
+            let mut ds64 = Vec::with_capacity(8 + DS64_LEN);
+            ds64.extend_from_slice(&DS64);
+            ds64.extend_from_slice(&(DS64_LEN as u32).to_le_bytes());
+            ds64.extend_from_slice(&form_len.to_le_bytes());
+            ds64.extend_from_slice(&self.data_len.to_le_bytes());
+            ds64.extend_from_slice(&frames.to_le_bytes());
+            ds64.extend_from_slice(&0u32.to_le_bytes());
+            w.seek(SeekFrom::Start(header.start))?;
+            w.write_all(&id)?;
+            w.write_all(&u32::MAX.to_le_bytes())?;
+            w.seek(SeekFrom::Start(ds64_pos))?;
+            w.write_all(&ds64)?;
+            w.seek(SeekFrom::Start(header.data_size_pos))?;
+            w.write_all(&u32::MAX.to_le_bytes())?;
+        } else {
+            w.seek(SeekFrom::Start(header.start + 4))?;
+            w.write_all(&(form_len as u32).to_le_bytes())?;
+            w.seek(SeekFrom::Start(header.data_size_pos))?;
+            w.write_all(&(self.data_len as u32).to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Writes trailing chunks and patches sizes.
+    pub fn finish(mut self) -> Result<W> {
+        let header = self.write_header()?;
+        let be = self.file_type.is_iff();
+        let mut tail = Vec::new();
+        if self.data_len % 2 == 1 {
+            tail.push(0);
+        }
+        if be {
+            if !self.markers.is_empty() {
+                let n = u16::try_from(self.markers.len())
+                    .map_err(|_| Error::InvalidFormat("too many markers"))?;
+                let mut body = n.to_be_bytes().to_vec();
+                for m in &self.markers {
+                    body.extend_from_slice(&(m.id as u16).to_be_bytes());
+                    body.extend_from_slice(&frame_pos_u32(m.frame_pos)?.to_be_bytes());
+                    write_p_str(&mut body, &m.name);
+                }
+                write_chunk(&mut tail, MARK, &body, true)?;
+            }
+            let form_len = header.data_start + self.data_len + tail.len() as u64 - header.start - 8;
+            if form_len > u32::MAX as u64 {
+                return Err(Error::InvalidFormat("AIFF is limited to 4 GB"));
+            }
+        } else if !self.markers.is_empty() || !self.regions.is_empty() {
+            let mut cue = Vec::new();
+            let mut adtl = ADTL.to_vec();
+            let mut points = Vec::with_capacity(self.markers.len() + self.regions.len());
+            for m in &self.markers {
+                points.push((m.id, frame_pos_u32(m.frame_pos)?, &m.name, None));
+            }
+            for r in &self.regions {
+                let range = r.range().unwrap();
+                let start = u32::try_from(range.start)
+                    .map_err(|_| Error::InvalidFormat("marker position over 32 bit"))?;
+                let len = u32::try_from(range.end - range.start)
+                    .map_err(|_| Error::InvalidFormat("region length over 32 bit"))?;
+                points.push((r.id, start, &r.name, Some(len)));
+            }
+            let n = u32::try_from(points.len())
+                .map_err(|_| Error::InvalidFormat("too many markers"))?;
+            for (id, pos, name, len) in points {
+                cue.extend_from_slice(&id.to_le_bytes());
+                cue.extend_from_slice(&pos.to_le_bytes());
+                cue.extend_from_slice(&DATA);
+                cue.extend_from_slice(&[0u8; 8]);
+                cue.extend_from_slice(&pos.to_le_bytes());
+                if !name.is_empty() {
+                    let mut body = id.to_le_bytes().to_vec();
+                    body.extend(name.bytes().take_while(|&c| c != 0));
+                    body.push(0);
+                    write_sub_chunk(&mut adtl, LABL, &body);
+                }
+                if let Some(len) = len {
+                    let mut body = id.to_le_bytes().to_vec();
+                    body.extend_from_slice(&len.to_le_bytes());
+                    body.extend_from_slice(b"rgn ");
+                    // country, language, dialect and code page
+                    body.extend_from_slice(&[0u8; 8]);
+                    write_sub_chunk(&mut adtl, LTXT, &body);
+                }
+            }
+            let mut body = n.to_le_bytes().to_vec();
+            body.extend_from_slice(&cue);
+            write_chunk(&mut tail, CUE, &body, false)?;
+            if adtl.len() > 4 {
+                write_chunk(&mut tail, LIST, &adtl, false)?;
+            }
+        }
+        let w = &mut self.inner;
+        w.seek(SeekFrom::Start(header.data_start + self.data_len))?;
+        w.write_all(&tail)?;
+        let end = w.stream_position()?;
+        self.patch_sizes(&header, end)?;
+        self.inner.seek(SeekFrom::Start(end))?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+/// Marker positions of `cue ` and `MARK` chunks are 32 bit.
+fn frame_pos_u32(frame_pos: f64) -> Result<u32> {
+    if (0.0..=u32::MAX as f64).contains(&frame_pos) {
+        Ok(frame_pos as u32)
+    } else {
+        Err(Error::InvalidFormat("marker position over 32 bit"))
+    }
+}
+
+fn write_sub_chunk(dst: &mut Vec<u8>, id: [u8; 4], body: &[u8]) {
+    dst.extend_from_slice(&id);
+    dst.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    dst.extend_from_slice(body);
+    if !body.len().is_multiple_of(2) {
+        dst.push(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::cat::audio::{
+        ChannelBitmap, Format, FormatFlags, StreamBasicDesc,
+        caf::MarkerType,
+        riff_iff::{self, FileType, Marker, Region},
+    };
+
+    fn frames(desc: &StreamBasicDesc, n: usize) -> Vec<u8> {
+        (0..n * desc.bytes_per_frame as usize)
+            .map(|i| (i * 7) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn wave_roundtrip() {
+        let desc = StreamBasicDesc::with_lpcm(44_100.0, 2, 16, 16, false, false, false);
+        let mut w = riff_iff::Writer::new(Cursor::new(Vec::new()), FileType::WAVE, &desc).unwrap();
+        w.add_info("title", "Song").unwrap();
+        w.add_info("IENG", "me").unwrap();
+        assert!(w.add_info("unknown key", "").is_err());
+        w.set_markers(vec![Marker {
+            frame_pos: 10.0,
+            name: "hit".to_string(),
+            id: 1,
+            type_: MarkerType::GENERIC,
+        }])
+        .unwrap();
+        w.set_regions(vec![Region::with_range(2, "verse", 20, 90)])
+            .unwrap();
+        let data = frames(&desc, 100);
+        w.write_frames(&data).unwrap();
+        assert!(matches!(
+            w.add_info("artist", ""),
+            Err(riff_iff::Error::DataStarted)
+        ));
+        assert!(w.write_frames(&data[..3]).is_err());
+        let buf = w.finish().unwrap().into_inner();
+
+        assert_eq!(&buf[0..4], b"RIFF");
+        let riff_len = u32::from_le_bytes(buf[4..8].try_into().unwrap()) as usize;
+        assert_eq!(riff_len + 8, buf.len());
+
+        let mut r = riff_iff::Reader::new(Cursor::new(buf)).unwrap();
+        assert_eq!(r.file_type(), FileType::WAVE);
+        assert_eq!(r.desc(), &desc);
+        assert_eq!(r.channel_bitmap(), None);
+        assert_eq!(r.frames_num(), 100);
+        assert_eq!(
+            r.info(),
+            &[
+                ("title".to_string(), "Song".to_string()),
+                ("IENG".to_string(), "me".to_string())
+            ]
+        );
+        assert_eq!(r.markers().len(), 1);
+        assert_eq!(r.markers()[0].name, "hit");
+        assert_eq!(r.markers()[0].frame_pos, 10.0);
+        assert_eq!(
+            r.regions(),
+            &[Region {
+                name: "verse".to_string(),
+                ..Region::with_range(2, "", 20, 90)
+            }]
+        );
+
+        let mut out = Vec::new();
+        assert_eq!(r.read_frames(90, 64, &mut out).unwrap(), 10);
+        assert_eq!(out, &data[360..]);
+        assert_eq!(r.read_frames(100, 1, &mut out).unwrap(), 0);
+    }
+
+    #[test]
+    fn canonical_wave() {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(b"RIFF");
+        buf.extend_from_slice(&40u32.to_le_bytes());
+        buf.extend_from_slice(b"WAVEfmt ");
+        buf.extend_from_slice(&16u32.to_le_bytes());
+        buf.extend_from_slice(&[1, 0, 1, 0]);
+        buf.extend_from_slice(&8_000u32.to_le_bytes());
+        buf.extend_from_slice(&8_000u32.to_le_bytes());
+        buf.extend_from_slice(&[1, 0, 8, 0]);
+        buf.extend_from_slice(b"data");
+        buf.extend_from_slice(&4u32.to_le_bytes());
+        buf.extend_from_slice(&[0x80, 0xff, 0x00, 0x80]);
+        assert_eq!(buf.len(), 48);
+
+        let r = riff_iff::Reader::new(Cursor::new(buf)).unwrap();
+        let desc = r.desc();
+        assert_eq!(desc.sample_rate, 8_000.0);
+        assert_eq!(desc.format_flags, FormatFlags::IS_PACKED);
+        assert_eq!(desc.bits_per_channel, 8);
+        assert_eq!(r.data_offset(), 44);
+        assert_eq!(r.frames_num(), 4);
+
+        assert!(matches!(
+            riff_iff::Reader::new(Cursor::new(b"RIFF\0\0\0\0AVI ".to_vec())),
+            Err(riff_iff::Error::Unrecognized)
+        ));
+    }
+
+    #[test]
+    fn extensible() {
+        let desc = StreamBasicDesc::with_lpcm(48_000.0, 6, 24, 32, false, false, false);
+        let mut w = riff_iff::Writer::new(Cursor::new(Vec::new()), FileType::WAVE, &desc).unwrap();
+        assert!(w.set_channel_bitmap(ChannelBitmap(0x3)).is_err());
+        w.set_channel_bitmap(ChannelBitmap(0x3f)).unwrap();
+        w.write_frames(&frames(&desc, 3)).unwrap();
+        let buf = w.finish().unwrap().into_inner();
+
+        let r = riff_iff::Reader::new(Cursor::new(buf)).unwrap();
+        assert_eq!(r.desc(), &desc);
+        assert!(r.desc().format_flags.contains(FormatFlags::IS_ALIGNED_HIGH));
+        assert_eq!(r.channel_bitmap(), Some(ChannelBitmap(0x3f)));
+
+        let desc = StreamBasicDesc::common_f32(96_000.0, 2, true);
+        let mut w = riff_iff::Writer::new(Cursor::new(Vec::new()), FileType::WAVE, &desc).unwrap();
+        w.write_frames(&frames(&desc, 5)).unwrap();
+        let r = riff_iff::Reader::new(Cursor::new(w.finish().unwrap().into_inner())).unwrap();
+        assert_eq!(r.desc(), &desc);
+
+        let be = StreamBasicDesc::with_lpcm(48_000.0, 2, 16, 16, false, true, false);
+        assert!(riff_iff::Writer::new(Cursor::new(Vec::new()), FileType::WAVE, &be).is_err());
+    }
+
+    #[test]
+    fn rf64() {
+        let desc = StreamBasicDesc::with_lpcm(48_000.0, 1, 16, 16, false, false, false);
+        let data = frames(&desc, 9);
+        for file_type in [FileType::RF64, FileType::BW64] {
+            let mut w = riff_iff::Writer::new(Cursor::new(Vec::new()), file_type, &desc).unwrap();
+            w.write_frames(&data).unwrap();
+            let buf = w.finish().unwrap().into_inner();
+            assert_eq!(&buf[4..8], &u32::MAX.to_le_bytes());
+            assert_eq!(&buf[12..16], b"ds64");
+            let data_len = u64::from_le_bytes(buf[28..36].try_into().unwrap());
+            assert_eq!(data_len, 18);
+
+            let mut r = riff_iff::Reader::new(Cursor::new(buf)).unwrap();
+            assert_eq!(r.file_type(), file_type);
+            assert_eq!(r.frames_num(), 9);
+            let mut out = Vec::new();
+            r.read_frames(0, 9, &mut out).unwrap();
+            assert_eq!(out, data);
+        }
+    }
+
+    #[test]
+    fn aiff() {
+        assert_eq!(
+            riff_iff::f64_to_ext80(44_100.0),
+            [0x40, 0x0e, 0xac, 0x44, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            riff_iff::ext80_to_f64(&riff_iff::f64_to_ext80(22_050.5)),
+            22_050.5
+        );
+
+        let descs = [
+            (
+                FileType::AIFF,
+                StreamBasicDesc::with_lpcm(44_100.0, 2, 24, 24, false, true, false),
+            ),
+            (
+                FileType::AIFC,
+                StreamBasicDesc::with_lpcm(44_100.0, 2, 16, 16, false, false, false),
+            ),
+            (
+                FileType::AIFC,
+                StreamBasicDesc::with_lpcm(48_000.0, 1, 32, 32, true, true, false),
+            ),
+        ];
+        for (file_type, desc) in descs {
+            let mut w = riff_iff::Writer::new(Cursor::new(Vec::new()), file_type, &desc).unwrap();
+            w.add_info("title", "Take 1").unwrap();
+            w.set_markers(vec![Marker {
+                frame_pos: 3.0,
+                name: "cue".to_string(),
+                id: 1,
+                type_: MarkerType::GENERIC,
+            }])
+            .unwrap();
+            assert!(
+                w.set_regions(vec![Region::with_range(1, "", 0, 1)])
+                    .is_err()
+            );
+            let data = frames(&desc, 7);
+            w.write_frames(&data).unwrap();
+            let buf = w.finish().unwrap().into_inner();
+            assert_eq!(&buf[0..4], b"FORM");
+
+            let mut r = riff_iff::Reader::new(Cursor::new(buf)).unwrap();
+            assert_eq!(r.file_type(), file_type);
+            assert_eq!(r.desc(), &desc);
+            assert_eq!(r.info(), &[("title".to_string(), "Take 1".to_string())]);
+            assert_eq!(r.markers()[0].name, "cue");
+            assert_eq!(r.markers()[0].frame_pos, 3.0);
+            let mut out = Vec::new();
+            assert_eq!(r.read_frames(0, 100, &mut out).unwrap(), 7);
+            assert_eq!(out, data);
+        }
+
+        let le = StreamBasicDesc::with_lpcm(44_100.0, 2, 16, 16, false, false, false);
+        assert!(riff_iff::Writer::new(Cursor::new(Vec::new()), FileType::AIFF, &le).is_err());
+    }
+
+    #[test]
+    fn unfinished() {
+        for file_type in [FileType::WAVE, FileType::RF64, FileType::AIFF] {
+            let desc =
+                StreamBasicDesc::with_lpcm(8_000.0, 1, 16, 16, false, file_type.is_iff(), false);
+            let mut buf = Cursor::new(Vec::new());
+            let mut w = riff_iff::Writer::new(&mut buf, file_type, &desc).unwrap();
+            w.write_frames(&frames(&desc, 5)).unwrap();
+            w.sync().unwrap();
+            w.write_frames(&frames(&desc, 3)).unwrap();
+            drop(w);
+
+            // frames after sync are not covered by patched sizes
+            let r = riff_iff::Reader::new(Cursor::new(buf.get_ref().clone())).unwrap();
+            assert_eq!(r.frames_num(), 5);
+
+            let mut w = riff_iff::Writer::new(Cursor::new(Vec::new()), file_type, &desc).unwrap();
+            w.write_frames(&frames(&desc, 8)).unwrap();
+            let buf = w.inner.into_inner();
+            let r = riff_iff::Reader::new(Cursor::new(buf)).unwrap();
+            assert_eq!(r.frames_num(), 8);
+        }
+
+        let desc = StreamBasicDesc {
+            format: Format::U_LAW,
+            ..StreamBasicDesc::with_lpcm(8_000.0, 1, 8, 8, false, false, false)
+        };
+        let mut buf = Cursor::new(Vec::new());
+        let mut w = riff_iff::Writer::new(&mut buf, FileType::WAVE, &desc).unwrap();
+        w.write_frames(&[1, 2, 3]).unwrap();
+        drop(w);
+        let r = riff_iff::Reader::new(Cursor::new(buf.into_inner())).unwrap();
+        assert_eq!(r.desc().format, Format::U_LAW);
+        assert_eq!(r.frames_num(), 3);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let marker = |frame_pos| Marker {
+            frame_pos,
+            name: String::new(),
+            id: 1,
+            type_: MarkerType::GENERIC,
+        };
+        let invalid = |res| matches!(res, Err(riff_iff::Error::InvalidFormat(_)));
+
+        let desc = StreamBasicDesc::with_lpcm(8_000.0, 1, 16, 16, false, false, false);
+        for pos in [5e9, -1.0, f64::NAN] {
+            let mut w =
+                riff_iff::Writer::new(Cursor::new(Vec::new()), FileType::WAVE, &desc).unwrap();
+            w.set_markers(vec![marker(pos)]).unwrap();
+            assert!(invalid(w.finish().map(|_| ())));
+        }
+        let mut w = riff_iff::Writer::new(Cursor::new(Vec::new()), FileType::RF64, &desc).unwrap();
+        w.set_regions(vec![Region::with_range(1, "", 0, 5_000_000_000)])
+            .unwrap();
+        assert!(invalid(w.finish().map(|_| ())));
+
+        let desc = StreamBasicDesc::with_lpcm(8_000.0, 1, 16, 16, false, true, false);
+        let mut w = riff_iff::Writer::new(Cursor::new(Vec::new()), FileType::AIFF, &desc).unwrap();
+        w.set_markers(vec![marker(0.0); 70_000]).unwrap();
+        assert!(invalid(w.finish().map(|_| ())));
+        let mut w = riff_iff::Writer::new(Cursor::new(Vec::new()), FileType::AIFF, &desc).unwrap();
+        w.set_markers(vec![marker(5e9)]).unwrap();
+        assert!(invalid(w.finish().map(|_| ())));
+    }
+}
@@ -1,19 +1,28 @@
+#[cfg(target_vendor = "apple")]
 mod context;
+#[cfg(target_vendor = "apple")]
 pub use context::Context;
 
 mod geometry;
 pub use geometry::Float;
 pub use geometry::Point;
 pub use geometry::Rect;
+pub use geometry::RectEdge;
 pub use geometry::Size;
 pub use geometry::Vector;
 
+#[cfg(target_vendor = "apple")]
 pub mod color_space;
+#[cfg(target_vendor = "apple")]
 pub use color_space::ColorRenderingIntent;
+#[cfg(target_vendor = "apple")]
 pub use color_space::ColorSpace;
+#[cfg(target_vendor = "apple")]
 pub use color_space::ColorSpaceModel;
 
+#[cfg(target_vendor = "apple")]
 mod color;
+#[cfg(target_vendor = "apple")]
 pub use color::Color;
 
 mod window;
@@ -30,6 +39,7 @@ pub use affine_transform::AffineTransform;
 pub use affine_transform::Components as AffineTransformComponents;
 
 mod error;
+#[cfg(target_vendor = "apple")]
 pub use error::Cb as ErrorCallback;
 pub use error::Error;
 pub use error::Status;
@@ -51,36 +61,46 @@ pub mod event_types;
 #[cfg(target_os = "macos")]
 pub use event_types::*;
 
+#[cfg(target_vendor = "apple")]
 pub mod image;
-#[cfg(feature = "iio")]
+#[cfg(all(target_vendor = "apple", feature = "iio"))]
 pub use image::AnimationBlock as ImageAnimationBlock;
-#[cfg(feature = "iio")]
+#[cfg(all(target_vendor = "apple", feature = "iio"))]
 pub use image::AnimationOptKey as ImageAnimationOptKey;
-#[cfg(feature = "iio")]
+#[cfg(all(target_vendor = "apple", feature = "iio"))]
 pub use image::ImageDst;
-#[cfg(feature = "iio")]
+#[cfg(all(target_vendor = "apple", feature = "iio"))]
 pub use image::ImageSrc;
-#[cfg(feature = "iio")]
+#[cfg(all(target_vendor = "apple", feature = "iio"))]
 pub use image::animate_image_at_url;
-#[cfg(feature = "iio")]
+#[cfg(all(target_vendor = "apple", feature = "iio"))]
 pub use image::animate_image_at_url_with_block;
-#[cfg(feature = "iio")]
+#[cfg(all(target_vendor = "apple", feature = "iio"))]
 pub use image::animate_image_data;
-#[cfg(feature = "iio")]
+#[cfg(all(target_vendor = "apple", feature = "iio"))]
 pub use image::animate_image_data_with_block;
-#[cfg(feature = "iio")]
+#[cfg(all(target_vendor = "apple", feature = "iio"))]
 pub use image::animation_err as image_animation_err;
 
+#[cfg(target_vendor = "apple")]
 pub use image::AlphaInfo as ImageAlphaInfo;
+#[cfg(target_vendor = "apple")]
 pub use image::Image;
 
+#[cfg(target_vendor = "apple")]
 pub mod image_properties;
+#[cfg(target_vendor = "apple")]
 pub use image_properties::Orientation as ImagePropOrientation;
 
+#[cfg(target_vendor = "apple")]
 mod font;
+#[cfg(target_vendor = "apple")]
 pub use font::Font;
+#[cfg(target_vendor = "apple")]
 pub use font::FontPostScriptFormat;
+#[cfg(target_vendor = "apple")]
 pub use font::Glyph;
+#[cfg(target_vendor = "apple")]
 pub use font::Index as FontIndex;
 
 mod path;
@@ -91,10 +111,18 @@ pub use path::Element as PathElement;
 pub use path::ElementType as PathElementType;
 pub use path::LineCap;
 pub use path::LineJoin;
+#[cfg(target_vendor = "apple")]
 pub use path::Path;
 pub use path::PathApplierFn;
+#[cfg(target_vendor = "apple")]
 pub use path::PathMut;
 
+pub mod path_data;
+pub use path_data::Builder as PathBuilder;
+pub use path_data::PathData;
+pub use path_data::Segment as PathSegment;
+pub use path_data::SvgError as PathSvgError;
+
 #[cfg(target_os = "macos")]
 mod display_stream;
 #[cfg(target_os = "macos")]
@@ -112,5 +140,6 @@ pub use display_stream::UpdateRectType as DisplayStreamUpdateRectType;
 #[cfg(target_os = "macos")]
 pub use display_stream::YCbCrMatrix as DisplayStreamYCbCrMatrix;
 
+#[cfg(target_vendor = "apple")]
 #[link(name = "CoreGraphics", kind = "framework")]
 unsafe extern "C" {}
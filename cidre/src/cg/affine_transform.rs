@@ -42,69 +42,171 @@ pub struct Components {
 }
 
 impl AffineTransform {
+    #[doc(alias = "CGAffineTransformIdentity")]
+    pub const IDENTITY: Self = Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+    #[doc(alias = "CGAffineTransformMake")]
     #[inline]
-    pub fn with_components(components: Components) -> Self {
-        unsafe { CGAffineTransformMakeWithComponents(components) }
+    pub const fn new(
+        a: cg::Float,
+        b: cg::Float,
+        c: cg::Float,
+        d: cg::Float,
+        tx: cg::Float,
+        ty: cg::Float,
+    ) -> Self {
+        Self { a, b, c, d, tx, ty }
     }
 
+    /// Builds `scale * shear * rotation * translation`.
+    #[doc(alias = "CGAffineTransformMakeWithComponents")]
     #[inline]
-    pub fn identity() -> Self {
-        unsafe { CGAffineTransformIdentity }
+    pub fn with_components(components: Components) -> Self {
+        let Components {
+            scale,
+            horizontal_shear: sh,
+            rotation,
+            translation,
+        } = components;
+        let (sin, cos) = rotation.sin_cos();
+        Self {
+            a: scale.width * cos,
+            b: scale.width * sin,
+            c: scale.height * (sh * cos - sin),
+            d: scale.height * (sh * sin + cos),
+            tx: translation.dx,
+            ty: translation.dy,
+        }
     }
 
+    #[doc(alias = "CGAffineTransformIdentity")]
     #[inline]
-    pub fn new_translation(tx: f64, ty: f64) -> Self {
-        unsafe { CGAffineTransformMakeTranslation(tx, ty) }
+    pub const fn identity() -> Self {
+        Self::IDENTITY
     }
 
+    #[doc(alias = "CGAffineTransformMakeTranslation")]
     #[inline]
-    pub fn new_scale(sx: f64, sy: f64) -> Self {
-        unsafe { CGAffineTransformMakeScale(sx, sy) }
+    pub const fn new_translation(tx: cg::Float, ty: cg::Float) -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, tx, ty)
     }
 
+    #[doc(alias = "CGAffineTransformMakeScale")]
     #[inline]
-    pub fn new_rotation(angle: f64) -> Self {
-        unsafe { CGAffineTransformMakeRotation(angle) }
+    pub const fn new_scale(sx: cg::Float, sy: cg::Float) -> Self {
+        Self::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
     }
 
+    #[doc(alias = "CGAffineTransformMakeRotation")]
     #[inline]
-    pub fn is_identity(&self) -> bool {
-        unsafe { CGAffineTransformIsIdentity(*self) }
+    pub fn new_rotation(angle: cg::Float) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin, -sin, cos, 0.0, 0.0)
     }
 
+    #[doc(alias = "CGAffineTransformIsIdentity")]
     #[inline]
-    pub fn translate(&self, tx: f64, ty: f64) -> Self {
-        unsafe { CGAffineTransformTranslate(*self, tx, ty) }
+    pub const fn is_identity(&self) -> bool {
+        self.equal_to(&Self::IDENTITY)
     }
 
+    /// Translates before applying `self`.
+    #[doc(alias = "CGAffineTransformTranslate")]
     #[inline]
-    pub fn scale(&self, sx: f64, sy: f64) -> Self {
-        unsafe { CGAffineTransformScale(*self, sx, sy) }
+    pub const fn translate(&self, tx: cg::Float, ty: cg::Float) -> Self {
+        Self::new_translation(tx, ty).concat(self)
     }
 
+    /// Scales before applying `self`.
+    #[doc(alias = "CGAffineTransformScale")]
     #[inline]
-    pub fn rotate(&self, angle: f64) -> Self {
-        unsafe { CGAffineTransformRotate(*self, angle) }
+    pub const fn scale(&self, sx: cg::Float, sy: cg::Float) -> Self {
+        Self::new_scale(sx, sy).concat(self)
     }
 
+    /// Rotates before applying `self`.
+    #[doc(alias = "CGAffineTransformRotate")]
     #[inline]
-    pub fn invert(&self) -> Self {
-        unsafe { CGAffineTransformInvert(*self) }
+    pub fn rotate(&self, angle: cg::Float) -> Self {
+        Self::new_rotation(angle).concat(self)
     }
 
+    /// Returns `self` unchanged if the transform is not invertible.
+    #[doc(alias = "CGAffineTransformInvert")]
     #[inline]
-    pub fn concat(&self, other: &Self) -> Self {
-        unsafe { CGAffineTransformConcat(*self, *other) }
+    pub const fn invert(&self) -> Self {
+        let det = self.a * self.d - self.b * self.c;
+        if det == 0.0 {
+            return *self;
+        }
+        Self {
+            a: self.d / det,
+            b: -self.b / det,
+            c: -self.c / det,
+            d: self.a / det,
+            tx: (self.c * self.ty - self.d * self.tx) / det,
+            ty: (self.b * self.tx - self.a * self.ty) / det,
+        }
     }
 
+    /// `self` followed by `other`, same as `self * other`.
+    #[doc(alias = "CGAffineTransformConcat")]
     #[inline]
-    pub fn equal_to(&self, other: &Self) -> bool {
-        unsafe { CGAffineTransformEqualToTransform(*self, *other) }
+    pub const fn concat(&self, other: &Self) -> Self {
+        Self {
+            a: self.a * other.a + self.b * other.c,
+            b: self.a * other.b + self.b * other.d,
+            c: self.c * other.a + self.d * other.c,
+            d: self.c * other.b + self.d * other.d,
+            tx: self.tx * other.a + self.ty * other.c + other.tx,
+            ty: self.tx * other.b + self.ty * other.d + other.ty,
+        }
     }
 
+    #[doc(alias = "CGAffineTransformEqualToTransform")]
     #[inline]
+    pub const fn equal_to(&self, other: &Self) -> bool {
+        self.a == other.a
+            && self.b == other.b
+            && self.c == other.c
+            && self.d == other.d
+            && self.tx == other.tx
+            && self.ty == other.ty
+    }
+
+    /// Inverse of [`AffineTransform::with_components`].
+    ///
+    /// Rotation is kept in `(-pi/2, pi/2]`, so a flip is reported as a negative
+    /// scale rather than a half turn.
+    #[doc(alias = "CGAffineTransformDecompose")]
     pub fn decompose(&self) -> Components {
-        unsafe { CGAffineTransformDecompose(*self) }
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        let det = self.a * self.d - self.b * self.c;
+        let mut sx = self.a.hypot(self.b);
+        let (mut sy, mut rotation, horizontal_shear) = if sx == 0.0 {
+            // degenerate first row, put everything into the second one
+            (self.c.hypot(self.d), (-self.c).atan2(self.d), 0.0)
+        } else if det == 0.0 {
+            (0.0, self.b.atan2(self.a), 0.0)
+        } else {
+            (
+                det / sx,
+                self.b.atan2(self.a),
+                (self.a * self.c + self.b * self.d) / det,
+            )
+        };
+        if rotation > FRAC_PI_2 as cg::Float || rotation <= -FRAC_PI_2 as cg::Float {
+            rotation -= (PI as cg::Float).copysign(rotation);
+            sx = -sx;
+            sy = -sy;
+        }
+        Components {
+            scale: cg::Size::new(sx, sy),
+            horizontal_shear,
+            rotation,
+            translation: cg::Vector::new(self.tx, self.ty),
+        }
     }
 }
 
@@ -115,6 +217,7 @@ impl PartialEq for AffineTransform {
     /// let a = AffineTransform::identity();
     /// assert!(a.is_identity());
     /// assert_eq!(a, AffineTransform::identity());
+    /// assert_eq!(a.translate(1.0, 2.0), AffineTransform::new_translation(1.0, 2.0));
     /// ```
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -125,7 +228,7 @@ impl PartialEq for AffineTransform {
 impl cg::Point {
     #[doc(alias = "CGPointApplyAffineTransform")]
     #[inline]
-    pub fn apply_affine_transform(&self, t: &AffineTransform) -> Self {
+    pub const fn apply_affine_transform(&self, t: &AffineTransform) -> Self {
         cg::Point {
             x: t.a * self.x + t.c * self.y + t.tx,
             y: t.b * self.x + t.d * self.y + t.ty,
@@ -136,7 +239,7 @@ impl cg::Point {
 impl cg::Size {
     #[doc(alias = "CGSizeApplyAffineTransform")]
     #[inline]
-    pub fn apply_affine_transform(&self, t: &AffineTransform) -> Self {
+    pub const fn apply_affine_transform(&self, t: &AffineTransform) -> Self {
         cg::Size {
             width: t.a * self.width + t.c * self.height,
             height: t.b * self.width + t.d * self.height,
@@ -145,28 +248,273 @@ impl cg::Size {
 }
 
 impl cg::Rect {
+    /// Bounding box of the transformed corners. Null and infinite rects are returned unchanged.
     #[doc(alias = "CGRectApplyAffineTransform")]
     #[inline]
     pub fn apply_affine_transform(&self, t: &AffineTransform) -> Self {
+        if self.is_null() || self.is_infinite() {
+            return *self;
+        }
+        let (x0, y0, x1, y1) = (self.min_x(), self.min_y(), self.max_x(), self.max_y());
+        let corners = [
+            cg::Point::new(x0, y0).apply_affine_transform(t),
+            cg::Point::new(x1, y0).apply_affine_transform(t),
+            cg::Point::new(x0, y1).apply_affine_transform(t),
+            cg::Point::new(x1, y1).apply_affine_transform(t),
+        ];
+        let (mut min, mut max) = (corners[0], corners[0]);
+        for p in &corners[1..] {
+            min.x = min.x.min(p.x);
+            min.y = min.y.min(p.y);
+            max.x = max.x.max(p.x);
+            max.y = max.y.max(p.y);
+        }
+        cg::Rect::new(min.x, min.y, max.x - min.x, max.y - min.y)
+    }
+}
+
+impl std::ops::Mul for AffineTransform {
+    type Output = Self;
+
+    /// Same as [`AffineTransform::concat`].
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.concat(&rhs)
+    }
+}
+
+impl std::ops::MulAssign for AffineTransform {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.concat(&rhs);
+    }
+}
+
+impl std::ops::Mul<AffineTransform> for cg::Point {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: AffineTransform) -> Self {
+        self.apply_affine_transform(&rhs)
+    }
+}
+
+impl std::ops::Mul<AffineTransform> for cg::Size {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: AffineTransform) -> Self {
+        self.apply_affine_transform(&rhs)
+    }
+}
+
+impl std::ops::Mul<AffineTransform> for cg::Rect {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: AffineTransform) -> Self {
+        self.apply_affine_transform(&rhs)
+    }
+}
+
+/// CoreGraphics implementations, useful for cross-checking.
+#[cfg(target_vendor = "apple")]
+impl AffineTransform {
+    #[doc(alias = "CGAffineTransformMakeWithComponents")]
+    #[inline]
+    pub fn cg_with_components(components: Components) -> Self {
+        unsafe { CGAffineTransformMakeWithComponents(components) }
+    }
+
+    #[doc(alias = "CGAffineTransformMakeRotation")]
+    #[inline]
+    pub fn cg_new_rotation(angle: cg::Float) -> Self {
+        unsafe { CGAffineTransformMakeRotation(angle) }
+    }
+
+    #[doc(alias = "CGAffineTransformTranslate")]
+    #[inline]
+    pub fn cg_translate(&self, tx: cg::Float, ty: cg::Float) -> Self {
+        unsafe { CGAffineTransformTranslate(*self, tx, ty) }
+    }
+
+    #[doc(alias = "CGAffineTransformScale")]
+    #[inline]
+    pub fn cg_scale(&self, sx: cg::Float, sy: cg::Float) -> Self {
+        unsafe { CGAffineTransformScale(*self, sx, sy) }
+    }
+
+    #[doc(alias = "CGAffineTransformRotate")]
+    #[inline]
+    pub fn cg_rotate(&self, angle: cg::Float) -> Self {
+        unsafe { CGAffineTransformRotate(*self, angle) }
+    }
+
+    #[doc(alias = "CGAffineTransformInvert")]
+    #[inline]
+    pub fn cg_invert(&self) -> Self {
+        unsafe { CGAffineTransformInvert(*self) }
+    }
+
+    #[doc(alias = "CGAffineTransformConcat")]
+    #[inline]
+    pub fn cg_concat(&self, other: &Self) -> Self {
+        unsafe { CGAffineTransformConcat(*self, *other) }
+    }
+
+    #[doc(alias = "CGAffineTransformDecompose")]
+    #[inline]
+    pub fn cg_decompose(&self) -> Components {
+        unsafe { CGAffineTransformDecompose(*self) }
+    }
+}
+
+#[cfg(target_vendor = "apple")]
+impl cg::Rect {
+    #[doc(alias = "CGRectApplyAffineTransform")]
+    #[inline]
+    pub fn cg_apply_affine_transform(&self, t: &AffineTransform) -> Self {
         unsafe { CGRectApplyAffineTransform(*self, *t) }
     }
 }
 
+#[cfg(target_vendor = "apple")]
 unsafe extern "C-unwind" {
-    static CGAffineTransformIdentity: AffineTransform;
-
-    fn CGAffineTransformMakeTranslation(tx: f64, ty: f64) -> AffineTransform;
-    fn CGAffineTransformMakeScale(sx: f64, sy: f64) -> AffineTransform;
-    fn CGAffineTransformMakeRotation(angle: f64) -> AffineTransform;
-    fn CGAffineTransformIsIdentity(t: AffineTransform) -> bool;
-    fn CGAffineTransformTranslate(t: AffineTransform, tx: f64, ty: f64) -> AffineTransform;
-    fn CGAffineTransformScale(t: AffineTransform, sx: f64, sy: f64) -> AffineTransform;
-    fn CGAffineTransformRotate(t: AffineTransform, angle: f64) -> AffineTransform;
+    fn CGAffineTransformMakeRotation(angle: cg::Float) -> AffineTransform;
+    fn CGAffineTransformTranslate(
+        t: AffineTransform,
+        tx: cg::Float,
+        ty: cg::Float,
+    ) -> AffineTransform;
+    fn CGAffineTransformScale(t: AffineTransform, sx: cg::Float, sy: cg::Float) -> AffineTransform;
+    fn CGAffineTransformRotate(t: AffineTransform, angle: cg::Float) -> AffineTransform;
     fn CGAffineTransformInvert(t: AffineTransform) -> AffineTransform;
     fn CGAffineTransformConcat(t: AffineTransform, other: AffineTransform) -> AffineTransform;
-    fn CGAffineTransformEqualToTransform(t: AffineTransform, other: AffineTransform) -> bool;
     fn CGRectApplyAffineTransform(rect: cg::Rect, t: AffineTransform) -> cg::Rect;
     fn CGAffineTransformDecompose(t: AffineTransform) -> Components;
     fn CGAffineTransformMakeWithComponents(components: Components) -> AffineTransform;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::cg;
+
+    const T: cg::AffineTransform = cg::AffineTransform::new_translation(10.0, 20.0)
+        .scale(2.0, 3.0)
+        .translate(-1.0, 1.0);
+
+    fn approx(a: cg::Float, b: cg::Float) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    fn approx_t(a: &cg::AffineTransform, b: &cg::AffineTransform) -> bool {
+        approx(a.a, b.a)
+            && approx(a.b, b.b)
+            && approx(a.c, b.c)
+            && approx(a.d, b.d)
+            && approx(a.tx, b.tx)
+            && approx(a.ty, b.ty)
+    }
+
+    #[test]
+    fn basics() {
+        // translate and scale apply before the receiver
+        assert_eq!(T, cg::AffineTransform::new(2.0, 0.0, 0.0, 3.0, 8.0, 23.0));
+        assert_eq!(cg::Point::zero() * T, cg::Point::new(8.0, 23.0));
+        assert_eq!(
+            cg::AffineTransform::new_scale(2.0, 2.0)
+                * cg::AffineTransform::new_translation(1.0, 0.0),
+            cg::AffineTransform::new(2.0, 0.0, 0.0, 2.0, 1.0, 0.0)
+        );
+        assert!(approx_t(
+            &(T * T.invert()),
+            &cg::AffineTransform::identity()
+        ));
+
+        let singular = cg::AffineTransform::new_scale(0.0, 1.0);
+        assert_eq!(singular.invert(), singular);
 
+        let p = cg::Point::new(1.0, 0.0) * cg::AffineTransform::new_rotation(FRAC_PI_2);
+        assert!(approx(p.x, 0.0) && approx(p.y, 1.0));
+        assert!(approx_t(
+            &cg::AffineTransform::identity().rotate(0.5).rotate(-0.5),
+            &cg::AffineTransform::identity()
+        ));
+    }
+
+    #[test]
+    fn decompose() {
+        let cases = [
+            cg::AffineTransform::identity(),
+            T,
+            cg::AffineTransform::new_scale(-1.0, 1.0),
+            cg::AffineTransform::new_scale(-2.0, -3.0),
+            cg::AffineTransform::new_scale(0.0, 2.0),
+            cg::AffineTransform::new_rotation(2.5).scale(1.5, -0.5),
+            cg::AffineTransform::new(1.0, 0.5, 0.25, 2.0, 3.0, 4.0),
+            cg::AffineTransform::new(0.0, 0.0, 1.0, -1.0, 0.0, 0.0),
+        ];
+        for t in cases {
+            let c = t.decompose();
+            assert!(c.rotation > -FRAC_PI_2 && c.rotation <= FRAC_PI_2, "{c:?}");
+            let back = cg::AffineTransform::with_components(c);
+            assert!(approx_t(&t, &back), "{t:?} {c:?} {back:?}");
+        }
+
+        let c = cg::AffineTransform::new_scale(-1.0, 1.0).decompose();
+        assert_eq!(c.scale, cg::Size::new(-1.0, 1.0));
+        assert_eq!(c.rotation, 0.0);
+
+        let c = cg::AffineTransform::new_rotation(0.5)
+            .translate(3.0, 4.0)
+            .decompose();
+        assert!(approx(c.rotation, 0.5));
+        assert!(approx(c.scale.width, 1.0) && approx(c.scale.height, 1.0));
+        assert!(approx(c.horizontal_shear, 0.0));
+    }
+
+    #[test]
+    fn rect() {
+        let r = cg::Rect::new(0.0, 0.0, 2.0, 1.0);
+        assert_eq!(r * T, cg::Rect::new(8.0, 23.0, 4.0, 3.0));
+
+        let out = r * cg::AffineTransform::new_rotation(FRAC_PI_2);
+        assert!(approx(out.origin.x, -1.0) && approx(out.origin.y, 0.0));
+        assert!(approx(out.size.width, 1.0) && approx(out.size.height, 2.0));
+
+        assert!((cg::Rect::null() * T).is_null());
+        assert!((cg::Rect::infinite() * T).is_infinite());
+        assert_eq!(cg::Size::new(1.0, 1.0) * T, cg::Size::new(2.0, 3.0));
+    }
+
+    #[cfg(target_vendor = "apple")]
+    #[test]
+    fn matches_cg() {
+        let t = cg::AffineTransform::new(1.0, 0.5, 0.25, 2.0, 3.0, 4.0);
+        assert!(approx_t(&t.translate(1.0, 2.0), &t.cg_translate(1.0, 2.0)));
+        assert!(approx_t(&t.scale(1.0, -2.0), &t.cg_scale(1.0, -2.0)));
+        assert!(approx_t(&t.rotate(0.7), &t.cg_rotate(0.7)));
+        assert!(approx_t(&t.invert(), &t.cg_invert()));
+        assert!(approx_t(&t.concat(&T), &t.cg_concat(&T)));
+        assert!(approx_t(
+            &cg::AffineTransform::new_rotation(0.7),
+            &cg::AffineTransform::cg_new_rotation(0.7)
+        ));
+
+        let c = t.decompose();
+        assert!(approx_t(
+            &cg::AffineTransform::with_components(c),
+            &cg::AffineTransform::cg_with_components(c)
+        ));
+        let back = cg::AffineTransform::with_components(t.cg_decompose());
+        assert!(approx_t(&back, &t));
+
+        let r = cg::Rect::new(1.0, 2.0, -3.0, 4.0);
+        let a = r.apply_affine_transform(&t);
+        let b = r.cg_apply_affine_transform(&t);
+        assert!(approx(a.min_x(), b.min_x()) && approx(a.max_x(), b.max_x()));
+        assert!(approx(a.min_y(), b.min_y()) && approx(a.max_y(), b.max_y()));
+    }
 }
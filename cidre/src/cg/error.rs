@@ -57,13 +57,16 @@ impl Error {
     /// Set a callback for easier detection of error conditions
     /// causing CoreGraphics to raise an error.
     /// Pass None to reset the callback.
+    #[cfg(target_vendor = "apple")]
     pub fn set_cb(callback: Option<Cb>) {
         unsafe { CGErrorSetCallback(callback) }
     }
 }
 
+#[cfg(target_vendor = "apple")]
 pub type Cb = extern "C" fn();
 
+#[cfg(target_vendor = "apple")]
 unsafe extern "C" {
     fn CGErrorSetCallback(callback: Option<Cb>);
 }
@@ -1,3 +1,4 @@
+#[cfg(target_vendor = "apple")]
 use crate::{arc, cf};
 
 #[cfg(target_pointer_width = "32")]
@@ -25,6 +26,7 @@ impl Point {
     /// let d = cg::Point::zero().dictionary_representaion();
     /// assert_eq!(d.len(), 2);
     /// ```
+    #[cfg(target_vendor = "apple")]
     pub fn dictionary_representaion(&self) -> arc::R<cf::Dictionary> {
         unsafe { CGPointCreateDictionaryRepresentation(*self) }
     }
@@ -57,6 +59,7 @@ impl Size {
     /// let d = cg::Size::zero().dictionary_representaion();
     /// assert_eq!(d.len(), 2);
     /// ```
+    #[cfg(target_vendor = "apple")]
     pub fn dictionary_representaion(&self) -> arc::R<cf::Dictionary> {
         unsafe { CGSizeCreateDictionaryRepresentation(*self) }
     }
@@ -83,9 +86,25 @@ impl Rect {
         }
     }
 
+    #[doc(alias = "CGRectNull")]
+    pub const NULL: Self = Self::new(Float::INFINITY, Float::INFINITY, 0.0, 0.0);
+
+    #[doc(alias = "CGRectInfinite")]
+    pub const INFINITE: Self =
+        Self::new(-Float::MAX / 2.0, -Float::MAX / 2.0, Float::MAX, Float::MAX);
+
+    /// The rect returned when two rects don't intersect.
+    #[doc(alias = "CGRectNull")]
+    #[inline]
+    pub const fn null() -> Self {
+        Self::NULL
+    }
+
+    /// A rect that has no defined bounds.
+    #[doc(alias = "CGRectInfinite")]
     #[inline]
-    pub fn null() -> Self {
-        unsafe { CGRectNull }
+    pub const fn infinite() -> Self {
+        Self::INFINITE
     }
 
     /// ```
@@ -95,6 +114,7 @@ impl Rect {
     /// assert_eq!(d.len(), 4);
     /// ```
     #[inline]
+    #[cfg(target_vendor = "apple")]
     pub fn dictionary_representaion(&self) -> arc::R<cf::Dictionary> {
         unsafe { CGRectCreateDictionaryRepresentation(*self) }
     }
@@ -114,6 +134,255 @@ impl Rect {
             size: Size { width, height },
         }
     }
+
+    #[doc(alias = "CGRectGetMinX")]
+    #[inline]
+    pub const fn min_x(&self) -> Float {
+        self.origin.x + self.size.width.min(0.0)
+    }
+
+    #[doc(alias = "CGRectGetMidX")]
+    #[inline]
+    pub const fn mid_x(&self) -> Float {
+        self.origin.x + self.size.width * 0.5
+    }
+
+    #[doc(alias = "CGRectGetMaxX")]
+    #[inline]
+    pub const fn max_x(&self) -> Float {
+        self.origin.x + self.size.width.max(0.0)
+    }
+
+    #[doc(alias = "CGRectGetMinY")]
+    #[inline]
+    pub const fn min_y(&self) -> Float {
+        self.origin.y + self.size.height.min(0.0)
+    }
+
+    #[doc(alias = "CGRectGetMidY")]
+    #[inline]
+    pub const fn mid_y(&self) -> Float {
+        self.origin.y + self.size.height * 0.5
+    }
+
+    #[doc(alias = "CGRectGetMaxY")]
+    #[inline]
+    pub const fn max_y(&self) -> Float {
+        self.origin.y + self.size.height.max(0.0)
+    }
+
+    /// Width of the rect, always positive.
+    #[doc(alias = "CGRectGetWidth")]
+    #[inline]
+    pub const fn width(&self) -> Float {
+        self.size.width.abs()
+    }
+
+    /// Height of the rect, always positive.
+    #[doc(alias = "CGRectGetHeight")]
+    #[inline]
+    pub const fn height(&self) -> Float {
+        self.size.height.abs()
+    }
+
+    #[doc(alias = "CGRectIsNull")]
+    #[inline]
+    pub const fn is_null(&self) -> bool {
+        self.origin.x == Float::INFINITY || self.origin.y == Float::INFINITY
+    }
+
+    /// Null rects and rects with zero width or height are empty.
+    #[doc(alias = "CGRectIsEmpty")]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.is_null() || self.size.width == 0.0 || self.size.height == 0.0
+    }
+
+    #[doc(alias = "CGRectIsInfinite")]
+    #[inline]
+    pub const fn is_infinite(&self) -> bool {
+        self.origin.x == Self::INFINITE.origin.x
+            && self.origin.y == Self::INFINITE.origin.y
+            && self.size.width == Self::INFINITE.size.width
+            && self.size.height == Self::INFINITE.size.height
+    }
+
+    /// Same rect with positive width and height.
+    #[doc(alias = "CGRectStandardize")]
+    #[inline]
+    pub const fn standardize(&self) -> Self {
+        if self.is_null() {
+            return Self::NULL;
+        }
+        Self::new(self.min_x(), self.min_y(), self.width(), self.height())
+    }
+
+    /// The smallest rect with integral origin and size that contains `self`.
+    #[doc(alias = "CGRectIntegral")]
+    pub fn integral(&self) -> Self {
+        if self.is_null() || self.is_infinite() {
+            return *self;
+        }
+        let x = self.min_x().floor();
+        let y = self.min_y().floor();
+        Self::new(x, y, self.max_x().ceil() - x, self.max_y().ceil() - y)
+    }
+
+    /// Shrinks (or grows with negative values) the standardized rect around its center.
+    ///
+    /// Returns the null rect if the result has negative width or height.
+    #[doc(alias = "CGRectInset")]
+    #[inline]
+    pub const fn inset(&self, dx: Float, dy: Float) -> Self {
+        if self.is_null() || self.is_infinite() {
+            return *self;
+        }
+        let r = self.standardize();
+        let width = r.size.width - dx * 2.0;
+        let height = r.size.height - dy * 2.0;
+        if width < 0.0 || height < 0.0 {
+            return Self::NULL;
+        }
+        Self::new(r.origin.x + dx, r.origin.y + dy, width, height)
+    }
+
+    #[doc(alias = "CGRectOffset")]
+    #[inline]
+    pub const fn offset(&self, dx: Float, dy: Float) -> Self {
+        if self.is_null() || self.is_infinite() {
+            return *self;
+        }
+        let r = self.standardize();
+        Self::new(
+            r.origin.x + dx,
+            r.origin.y + dy,
+            r.size.width,
+            r.size.height,
+        )
+    }
+
+    /// The smallest rect that contains both rects. Null rects are ignored.
+    #[doc(alias = "CGRectUnion")]
+    #[inline]
+    pub const fn union(&self, other: &Self) -> Self {
+        if self.is_null() {
+            return *other;
+        }
+        if other.is_null() {
+            return *self;
+        }
+        let x = self.min_x().min(other.min_x());
+        let y = self.min_y().min(other.min_y());
+        let max_x = self.max_x().max(other.max_x());
+        let max_y = self.max_y().max(other.max_y());
+        Self::new(x, y, max_x - x, max_y - y)
+    }
+
+    /// The overlapping part of two rects, or the null rect if they don't overlap.
+    ///
+    /// Rects sharing only an edge intersect in a zero sized rect.
+    #[doc(alias = "CGRectIntersection")]
+    #[inline]
+    pub const fn intersection(&self, other: &Self) -> Self {
+        if self.is_null() || other.is_null() {
+            return Self::NULL;
+        }
+        let x = self.min_x().max(other.min_x());
+        let y = self.min_y().max(other.min_y());
+        let max_x = self.max_x().min(other.max_x());
+        let max_y = self.max_y().min(other.max_y());
+        if max_x < x || max_y < y {
+            return Self::NULL;
+        }
+        Self::new(x, y, max_x - x, max_y - y)
+    }
+
+    #[doc(alias = "CGRectIntersectsRect")]
+    #[inline]
+    pub const fn intersects(&self, other: &Self) -> bool {
+        !self.intersection(other).is_null()
+    }
+
+    /// Splits the rect into `(slice, remainder)`, where slice is `amount` wide
+    /// (or high) from the `edge`.
+    #[doc(alias = "CGRectDivide")]
+    pub const fn divide(&self, amount: Float, edge: RectEdge) -> (Self, Self) {
+        if self.is_null() {
+            return (Self::NULL, Self::NULL);
+        }
+        let r = self.standardize();
+        let amount = amount.max(0.0);
+        let mut slice = r;
+        let mut remainder = r;
+        match edge {
+            RectEdge::MinX | RectEdge::MaxX => {
+                let amount = amount.min(r.size.width);
+                slice.size.width = amount;
+                remainder.size.width = r.size.width - amount;
+                match edge {
+                    RectEdge::MinX => remainder.origin.x += amount,
+                    _ => slice.origin.x += remainder.size.width,
+                }
+            }
+            RectEdge::MinY | RectEdge::MaxY => {
+                let amount = amount.min(r.size.height);
+                slice.size.height = amount;
+                remainder.size.height = r.size.height - amount;
+                match edge {
+                    RectEdge::MinY => remainder.origin.y += amount,
+                    _ => slice.origin.y += remainder.size.height,
+                }
+            }
+        }
+        (slice, remainder)
+    }
+
+    /// Min edges are inclusive, max edges are exclusive.
+    #[doc(alias = "CGRectContainsPoint")]
+    #[inline]
+    pub const fn contains_point(&self, point: Point) -> bool {
+        !self.is_null()
+            && point.x >= self.min_x()
+            && point.x < self.max_x()
+            && point.y >= self.min_y()
+            && point.y < self.max_y()
+    }
+
+    #[doc(alias = "CGRectContainsRect")]
+    #[inline]
+    pub const fn contains_rect(&self, other: &Self) -> bool {
+        !self.is_null()
+            && !other.is_null()
+            && other.min_x() >= self.min_x()
+            && other.max_x() <= self.max_x()
+            && other.min_y() >= self.min_y()
+            && other.max_y() <= self.max_y()
+    }
+
+    /// Compares standardized rects, unlike `==`.
+    #[doc(alias = "CGRectEqualToRect")]
+    #[inline]
+    pub const fn equal_to(&self, other: &Self) -> bool {
+        if self.is_null() || other.is_null() {
+            return self.is_null() && other.is_null();
+        }
+        let a = self.standardize();
+        let b = other.standardize();
+        a.origin.x == b.origin.x
+            && a.origin.y == b.origin.y
+            && a.size.width == b.size.width
+            && a.size.height == b.size.height
+    }
+}
+
+#[doc(alias = "CGRectEdge")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum RectEdge {
+    MinX,
+    MinY,
+    MaxX,
+    MaxY,
 }
 
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
@@ -123,10 +392,450 @@ pub struct Vector {
     pub dy: Float,
 }
 
-unsafe extern "C" {
-    static CGRectNull: Rect;
+impl Vector {
+    #[inline]
+    pub const fn zero() -> Self {
+        Self { dx: 0.0, dy: 0.0 }
+    }
+
+    #[inline]
+    pub const fn new(dx: Float, dy: Float) -> Self {
+        Self { dx, dy }
+    }
+}
+
+impl std::ops::Add<Vector> for Point {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Vector) -> Self {
+        Self::new(self.x + rhs.dx, self.y + rhs.dy)
+    }
+}
+
+impl std::ops::AddAssign<Vector> for Point {
+    #[inline]
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::Sub<Vector> for Point {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Vector) -> Self {
+        Self::new(self.x - rhs.dx, self.y - rhs.dy)
+    }
+}
+
+impl std::ops::SubAssign<Vector> for Point {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Vector;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Vector {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl std::ops::Add for Vector {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.dx + rhs.dx, self.dy + rhs.dy)
+    }
+}
+
+impl std::ops::Sub for Vector {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.dx - rhs.dx, self.dy - rhs.dy)
+    }
+}
+
+impl std::ops::Neg for Vector {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.dx, -self.dy)
+    }
+}
+
+impl std::ops::Mul<Float> for Vector {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Float) -> Self {
+        Self::new(self.dx * rhs, self.dy * rhs)
+    }
+}
+
+impl std::ops::Add for Size {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.width + rhs.width, self.height + rhs.height)
+    }
+}
+
+impl std::ops::Sub for Size {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.width - rhs.width, self.height - rhs.height)
+    }
+}
+
+impl std::ops::Mul<Float> for Size {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Float) -> Self {
+        Self::new(self.width * rhs, self.height * rhs)
+    }
+}
+
+impl std::ops::Div<Float> for Size {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Float) -> Self {
+        Self::new(self.width / rhs, self.height / rhs)
+    }
+}
+
+impl std::ops::Add<Vector> for Rect {
+    type Output = Self;
+
+    /// Same as [`Rect::offset`].
+    #[inline]
+    fn add(self, rhs: Vector) -> Self {
+        self.offset(rhs.dx, rhs.dy)
+    }
+}
+
+impl std::ops::Sub<Vector> for Rect {
+    type Output = Self;
 
+    #[inline]
+    fn sub(self, rhs: Vector) -> Self {
+        self.offset(-rhs.dx, -rhs.dy)
+    }
+}
+
+impl std::ops::BitOr for Rect {
+    type Output = Self;
+
+    /// Same as [`Rect::union`].
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(&rhs)
+    }
+}
+
+impl std::ops::BitAnd for Rect {
+    type Output = Self;
+
+    /// Same as [`Rect::intersection`].
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(&rhs)
+    }
+}
+
+/// CoreGraphics implementations, useful for cross-checking.
+#[cfg(target_vendor = "apple")]
+impl Rect {
+    #[doc(alias = "CGRectStandardize")]
+    #[inline]
+    pub fn cg_standardize(&self) -> Self {
+        unsafe { CGRectStandardize(*self) }
+    }
+
+    #[doc(alias = "CGRectIntegral")]
+    #[inline]
+    pub fn cg_integral(&self) -> Self {
+        unsafe { CGRectIntegral(*self) }
+    }
+
+    #[doc(alias = "CGRectInset")]
+    #[inline]
+    pub fn cg_inset(&self, dx: Float, dy: Float) -> Self {
+        unsafe { CGRectInset(*self, dx, dy) }
+    }
+
+    #[doc(alias = "CGRectOffset")]
+    #[inline]
+    pub fn cg_offset(&self, dx: Float, dy: Float) -> Self {
+        unsafe { CGRectOffset(*self, dx, dy) }
+    }
+
+    #[doc(alias = "CGRectUnion")]
+    #[inline]
+    pub fn cg_union(&self, other: &Self) -> Self {
+        unsafe { CGRectUnion(*self, *other) }
+    }
+
+    #[doc(alias = "CGRectIntersection")]
+    #[inline]
+    pub fn cg_intersection(&self, other: &Self) -> Self {
+        unsafe { CGRectIntersection(*self, *other) }
+    }
+
+    #[doc(alias = "CGRectDivide")]
+    #[inline]
+    pub fn cg_divide(&self, amount: Float, edge: RectEdge) -> (Self, Self) {
+        let mut slice = Self::zero();
+        let mut remainder = Self::zero();
+        unsafe { CGRectDivide(*self, &mut slice, &mut remainder, amount, edge) };
+        (slice, remainder)
+    }
+
+    #[doc(alias = "CGRectContainsPoint")]
+    #[inline]
+    pub fn cg_contains_point(&self, point: Point) -> bool {
+        unsafe { CGRectContainsPoint(*self, point) }
+    }
+
+    #[doc(alias = "CGRectContainsRect")]
+    #[inline]
+    pub fn cg_contains_rect(&self, other: &Self) -> bool {
+        unsafe { CGRectContainsRect(*self, *other) }
+    }
+
+    #[doc(alias = "CGRectIntersectsRect")]
+    #[inline]
+    pub fn cg_intersects(&self, other: &Self) -> bool {
+        unsafe { CGRectIntersectsRect(*self, *other) }
+    }
+}
+
+#[cfg(target_vendor = "apple")]
+unsafe extern "C" {
     fn CGPointCreateDictionaryRepresentation(point: Point) -> arc::R<cf::Dictionary>;
     fn CGSizeCreateDictionaryRepresentation(size: Size) -> arc::R<cf::Dictionary>;
     fn CGRectCreateDictionaryRepresentation(rect: Rect) -> arc::R<cf::Dictionary>;
+
+    fn CGRectStandardize(rect: Rect) -> Rect;
+    fn CGRectIntegral(rect: Rect) -> Rect;
+    fn CGRectInset(rect: Rect, dx: Float, dy: Float) -> Rect;
+    fn CGRectOffset(rect: Rect, dx: Float, dy: Float) -> Rect;
+    fn CGRectUnion(r1: Rect, r2: Rect) -> Rect;
+    fn CGRectIntersection(r1: Rect, r2: Rect) -> Rect;
+    fn CGRectDivide(
+        rect: Rect,
+        slice: *mut Rect,
+        remainder: *mut Rect,
+        amount: Float,
+        edge: RectEdge,
+    );
+    fn CGRectContainsPoint(rect: Rect, point: Point) -> bool;
+    fn CGRectContainsRect(rect1: Rect, rect2: Rect) -> bool;
+    fn CGRectIntersectsRect(rect1: Rect, rect2: Rect) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cg;
+
+    const R: cg::Rect = cg::Rect::new(10.0, 20.0, -10.0, 30.0);
+    const STD: cg::Rect = R.standardize();
+
+    #[test]
+    fn basics() {
+        assert_eq!(STD, cg::Rect::new(0.0, 20.0, 10.0, 30.0));
+        assert_eq!((R.min_x(), R.mid_x(), R.max_x()), (0.0, 5.0, 10.0));
+        assert_eq!((R.min_y(), R.mid_y(), R.max_y()), (20.0, 35.0, 50.0));
+        assert!(R.equal_to(&STD));
+        assert_ne!(R, STD);
+
+        let null = cg::Rect::null();
+        assert!(null.is_null());
+        assert!(null.is_empty());
+        assert!(!null.is_infinite());
+        assert!(null.standardize().is_null());
+        assert!(null.offset(1.0, 1.0).is_null());
+        assert!(null.inset(1.0, 1.0).is_null());
+        assert!(null.integral().is_null());
+
+        let inf = cg::Rect::infinite();
+        assert!(inf.is_infinite());
+        assert!(!inf.is_null());
+        assert!(!inf.is_empty());
+        assert!(inf.offset(1.0, 1.0).is_infinite());
+        assert!(inf.inset(1.0, 1.0).is_infinite());
+        assert!(inf.integral().is_infinite());
+
+        assert!(cg::Rect::new(1.0, 1.0, 0.0, 5.0).is_empty());
+        assert!(!STD.is_empty());
+    }
+
+    #[test]
+    fn union_intersection() {
+        let a = cg::Rect::new(0.0, 0.0, 10.0, 10.0);
+        let b = cg::Rect::new(5.0, 5.0, 10.0, 10.0);
+        assert_eq!(a.union(&b), cg::Rect::new(0.0, 0.0, 15.0, 15.0));
+        assert_eq!(a | b, a.union(&b));
+        assert_eq!(a & b, cg::Rect::new(5.0, 5.0, 5.0, 5.0));
+        assert!(a.intersects(&b));
+
+        assert_eq!(a.union(&cg::Rect::null()), a);
+        assert_eq!(cg::Rect::null().union(&a), a);
+        assert!(a.intersection(&cg::Rect::null()).is_null());
+
+        // flipped rects are standardized first
+        let flipped = cg::Rect::new(15.0, 15.0, -10.0, -10.0);
+        assert_eq!(a & flipped, cg::Rect::new(5.0, 5.0, 5.0, 5.0));
+
+        // shared edge gives zero sized intersection
+        let c = cg::Rect::new(10.0, 0.0, 10.0, 10.0);
+        assert_eq!(a & c, cg::Rect::new(10.0, 0.0, 0.0, 10.0));
+        assert!(a.intersects(&c));
+
+        let far = cg::Rect::new(100.0, 100.0, 1.0, 1.0);
+        assert!((a & far).is_null());
+        assert!(!a.intersects(&far));
+
+        let inf = cg::Rect::infinite();
+        assert_eq!(inf & a, a);
+        assert!((inf | a).is_infinite());
+    }
+
+    #[test]
+    fn inset_offset_integral() {
+        let r = cg::Rect::new(0.0, 0.0, 10.0, 20.0);
+        assert_eq!(r.inset(2.0, 3.0), cg::Rect::new(2.0, 3.0, 6.0, 14.0));
+        assert_eq!(r.inset(-1.0, -1.0), cg::Rect::new(-1.0, -1.0, 12.0, 22.0));
+        assert_eq!(r.inset(5.0, 0.0), cg::Rect::new(5.0, 0.0, 0.0, 20.0));
+        assert!(r.inset(6.0, 0.0).is_null());
+
+        assert_eq!(R.offset(1.0, 2.0), cg::Rect::new(1.0, 22.0, 10.0, 30.0));
+        assert_eq!(R + cg::Vector::new(1.0, 2.0), R.offset(1.0, 2.0));
+        assert_eq!(R - cg::Vector::new(1.0, 2.0), R.offset(-1.0, -2.0));
+
+        let r = cg::Rect::new(0.5, -0.5, 1.2, 1.0);
+        assert_eq!(r.integral(), cg::Rect::new(0.0, -1.0, 2.0, 2.0));
+        let r = cg::Rect::new(2.5, 0.0, -2.0, 1.0);
+        assert_eq!(r.integral(), cg::Rect::new(0.0, 0.0, 3.0, 1.0));
+    }
+
+    #[test]
+    fn divide() {
+        let r = cg::Rect::new(0.0, 0.0, 10.0, 20.0);
+        let (slice, rem) = r.divide(3.0, cg::RectEdge::MinX);
+        assert_eq!(slice, cg::Rect::new(0.0, 0.0, 3.0, 20.0));
+        assert_eq!(rem, cg::Rect::new(3.0, 0.0, 7.0, 20.0));
+
+        let (slice, rem) = r.divide(3.0, cg::RectEdge::MaxX);
+        assert_eq!(slice, cg::Rect::new(7.0, 0.0, 3.0, 20.0));
+        assert_eq!(rem, cg::Rect::new(0.0, 0.0, 7.0, 20.0));
+
+        let (slice, rem) = r.divide(5.0, cg::RectEdge::MinY);
+        assert_eq!(slice, cg::Rect::new(0.0, 0.0, 10.0, 5.0));
+        assert_eq!(rem, cg::Rect::new(0.0, 5.0, 10.0, 15.0));
+
+        let (slice, rem) = r.divide(50.0, cg::RectEdge::MaxY);
+        assert_eq!(slice, r);
+        assert_eq!(rem, cg::Rect::new(0.0, 0.0, 10.0, 0.0));
+
+        let (slice, rem) = r.divide(-1.0, cg::RectEdge::MinX);
+        assert_eq!(slice, cg::Rect::new(0.0, 0.0, 0.0, 20.0));
+        assert_eq!(rem, r);
+
+        let (slice, rem) = cg::Rect::null().divide(1.0, cg::RectEdge::MinX);
+        assert!(slice.is_null() && rem.is_null());
+    }
+
+    #[test]
+    fn contains() {
+        let r = cg::Rect::new(0.0, 0.0, 10.0, 10.0);
+        assert!(r.contains_point(cg::Point::zero()));
+        assert!(r.contains_point(cg::Point::new(9.9, 5.0)));
+        assert!(!r.contains_point(cg::Point::new(10.0, 5.0)));
+        assert!(!cg::Rect::null().contains_point(cg::Point::zero()));
+
+        assert!(r.contains_rect(&r));
+        assert!(r.contains_rect(&cg::Rect::new(10.0, 10.0, -5.0, -5.0)));
+        assert!(!r.contains_rect(&cg::Rect::new(5.0, 5.0, 6.0, 1.0)));
+        assert!(!r.contains_rect(&cg::Rect::null()));
+        assert!(cg::Rect::infinite().contains_rect(&r));
+    }
+
+    #[test]
+    fn ops() {
+        let p = cg::Point::new(1.0, 2.0);
+        let v = cg::Vector::new(3.0, 4.0);
+        assert_eq!(p + v, cg::Point::new(4.0, 6.0));
+        assert_eq!(p + v - v, p);
+        assert_eq!(cg::Point::new(4.0, 6.0) - p, v);
+        assert_eq!(-v * 2.0, cg::Vector::new(-6.0, -8.0));
+
+        let mut q = p;
+        q += v;
+        q -= v * 2.0;
+        assert_eq!(q, cg::Point::new(-2.0, -2.0));
+
+        let s = cg::Size::new(2.0, 4.0);
+        assert_eq!(s * 2.0, cg::Size::new(4.0, 8.0));
+        assert_eq!(s / 2.0, cg::Size::new(1.0, 2.0));
+        assert_eq!(s + s - s, s);
+    }
+
+    #[cfg(target_vendor = "apple")]
+    #[test]
+    fn matches_cg() {
+        let rects = [
+            cg::Rect::new(0.0, 0.0, 10.0, 10.0),
+            cg::Rect::new(5.5, -3.25, -7.0, 12.5),
+            cg::Rect::new(10.0, 0.0, 10.0, 10.0),
+            cg::Rect::new(1.0, 1.0, 0.0, 0.0),
+            cg::Rect::new(100.0, 100.0, 1.0, 1.0),
+            cg::Rect::null(),
+            cg::Rect::infinite(),
+        ];
+        let edges = [
+            cg::RectEdge::MinX,
+            cg::RectEdge::MinY,
+            cg::RectEdge::MaxX,
+            cg::RectEdge::MaxY,
+        ];
+        for a in rects {
+            assert!(a.standardize().equal_to(&a.cg_standardize()), "{a:?}");
+            assert!(a.integral().equal_to(&a.cg_integral()), "{a:?}");
+            assert!(a.inset(1.5, 2.0).equal_to(&a.cg_inset(1.5, 2.0)), "{a:?}");
+            assert!(a.offset(1.5, 2.0).equal_to(&a.cg_offset(1.5, 2.0)), "{a:?}");
+            for edge in edges {
+                let (s, r) = a.divide(2.5, edge);
+                let (cg_s, cg_r) = a.cg_divide(2.5, edge);
+                assert!(s.equal_to(&cg_s) && r.equal_to(&cg_r), "{a:?} {edge:?}");
+            }
+            for b in rects {
+                assert!(a.union(&b).equal_to(&a.cg_union(&b)), "{a:?} {b:?}");
+                assert!(
+                    a.intersection(&b).equal_to(&a.cg_intersection(&b)),
+                    "{a:?} {b:?}"
+                );
+                assert_eq!(a.intersects(&b), a.cg_intersects(&b), "{a:?} {b:?}");
+                assert_eq!(a.contains_rect(&b), a.cg_contains_rect(&b), "{a:?} {b:?}");
+                assert_eq!(
+                    a.contains_point(b.origin),
+                    a.cg_contains_point(b.origin),
+                    "{a:?} {b:?}"
+                );
+            }
+        }
+    }
 }
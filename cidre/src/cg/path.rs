@@ -1,6 +1,9 @@
+#[cfg(target_vendor = "apple")]
 use std::ffi::c_void;
 
-use crate::{arc, cf, cg, define_cf_type};
+use crate::cg;
+#[cfg(target_vendor = "apple")]
+use crate::{arc, cf, define_cf_type};
 
 #[cfg(feature = "blocks")]
 use crate::blocks;
@@ -74,11 +77,13 @@ impl Element {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 define_cf_type!(
     #[doc(alias = "CGPathRef")]
     Path(cf::Type)
 );
 
+#[cfg(target_vendor = "apple")]
 impl Path {
     #[doc(alias = "CGPathGetTypeID")]
     #[inline]
@@ -319,6 +324,7 @@ impl Path {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 impl PartialEq for Path {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -326,7 +332,9 @@ impl PartialEq for Path {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 define_cf_type!(PathMut(Path));
+#[cfg(target_vendor = "apple")]
 impl PathMut {
     #[inline]
     pub fn new() -> arc::R<Self> {
@@ -541,6 +549,7 @@ impl PathMut {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 #[link(name = "CoreGraphics", kind = "framework")]
 unsafe extern "C-unwind" {
     fn CGPathGetTypeID() -> cf::TypeId;
@@ -740,7 +749,7 @@ unsafe extern "C-unwind" {
     fn CGPathIntersectsPath(path: &Path, other: &Path, even_odd_fill_rule: bool) -> bool;
 }
 
-#[cfg(test)]
+#[cfg(all(test, target_vendor = "apple"))]
 mod tests {
     use crate::{blocks, cg};
 
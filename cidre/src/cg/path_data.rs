@@ -0,0 +1,1090 @@
+//! Pure Rust path model with SVG path data import and export.
+//!
+//! [`PathData`] holds the same elements [`cg::Path`] does, so it can be built,
+//! measured and transformed without CoreGraphics and converted to [`cg::PathMut`]
+//! when it is time to draw.
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+#[cfg(target_vendor = "apple")]
+use crate::arc;
+use crate::cg;
+
+type Float = cg::Float;
+
+/// Owned counterpart of [`cg::PathElement`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Segment {
+    MoveTo(cg::Point),
+    LineTo(cg::Point),
+    QuadTo(cg::Point, cg::Point),
+    CurveTo(cg::Point, cg::Point, cg::Point),
+    Close,
+}
+
+impl Segment {
+    #[inline]
+    pub fn type_(&self) -> cg::PathElementType {
+        match self {
+            Self::MoveTo(..) => cg::PathElementType::MoveToPoint,
+            Self::LineTo(..) => cg::PathElementType::AddLineToPoint,
+            Self::QuadTo(..) => cg::PathElementType::AddQuadCurveToPoint,
+            Self::CurveTo(..) => cg::PathElementType::AddCurveToPoint,
+            Self::Close => cg::PathElementType::CloseSubpath,
+        }
+    }
+
+    /// The last point of the segment, `None` for close.
+    #[inline]
+    pub fn end_point(&self) -> Option<cg::Point> {
+        match *self {
+            Self::MoveTo(p) | Self::LineTo(p) | Self::QuadTo(_, p) | Self::CurveTo(_, _, p) => {
+                Some(p)
+            }
+            Self::Close => None,
+        }
+    }
+
+    pub fn transformed(&self, t: &cg::AffineTransform) -> Self {
+        let f = |p: cg::Point| p.apply_affine_transform(t);
+        match *self {
+            Self::MoveTo(p) => Self::MoveTo(f(p)),
+            Self::LineTo(p) => Self::LineTo(f(p)),
+            Self::QuadTo(c, p) => Self::QuadTo(f(c), f(p)),
+            Self::CurveTo(c1, c2, p) => Self::CurveTo(f(c1), f(c2), f(p)),
+            Self::Close => Self::Close,
+        }
+    }
+}
+
+/// Invalid SVG path data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SvgError {
+    /// Byte offset into the source string.
+    pub offset: usize,
+    pub reason: &'static str,
+}
+
+impl std::fmt::Display for SvgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid path data at {}: {}", self.offset, self.reason)
+    }
+}
+
+impl std::error::Error for SvgError {}
+
+/// A path as a list of segments.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PathData {
+    segments: Vec<Segment>,
+}
+
+/// A segment resolved against the current point.
+#[derive(Debug, Copy, Clone)]
+enum Curve {
+    Line(cg::Point, cg::Point),
+    Quad(cg::Point, cg::Point, cg::Point),
+    Cubic(cg::Point, cg::Point, cg::Point, cg::Point),
+}
+
+impl PathData {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    #[inline]
+    pub fn push(&mut self, segment: Segment) {
+        self.segments.push(segment)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Parses SVG path data, the `d` attribute of `<path>`.
+    ///
+    /// Elliptical arcs are converted to cubic curves.
+    ///
+    /// ```
+    /// use cidre::cg;
+    ///
+    /// let path = cg::PathData::from_svg("M0 0 h10 v10 z").unwrap();
+    /// assert_eq!(path.segments().len(), 4);
+    /// assert_eq!(path.to_svg(), "M0 0 L10 0 L10 10 Z");
+    /// ```
+    pub fn from_svg(d: &str) -> Result<Self, SvgError> {
+        Parser {
+            s: d.as_bytes(),
+            pos: 0,
+        }
+        .parse()
+    }
+
+    /// SVG path data using absolute commands only.
+    #[inline]
+    pub fn to_svg(&self) -> String {
+        self.to_string()
+    }
+
+    /// The point the next segment would start from.
+    #[doc(alias = "CGPathGetCurrentPoint")]
+    pub fn current_point(&self) -> Option<cg::Point> {
+        let mut start = None;
+        let mut current = None;
+        for s in &self.segments {
+            match s {
+                Segment::MoveTo(p) => {
+                    start = Some(*p);
+                    current = start;
+                }
+                Segment::Close => current = start,
+                s => current = s.end_point(),
+            }
+        }
+        current
+    }
+
+    fn curves(&self) -> impl Iterator<Item = Curve> + '_ {
+        let mut start = cg::Point::zero();
+        let mut current = cg::Point::zero();
+        self.segments.iter().filter_map(move |s| {
+            let from = current;
+            let curve = match *s {
+                Segment::MoveTo(p) => {
+                    start = p;
+                    current = p;
+                    return None;
+                }
+                Segment::LineTo(p) => Curve::Line(from, p),
+                Segment::QuadTo(c, p) => Curve::Quad(from, c, p),
+                Segment::CurveTo(c1, c2, p) => Curve::Cubic(from, c1, c2, p),
+                Segment::Close => Curve::Line(from, start),
+            };
+            current = curve.end();
+            Some(curve)
+        })
+    }
+
+    /// Bounding box including control points, the null rect for empty paths.
+    #[doc(alias = "CGPathGetBoundingBox")]
+    pub fn bounding_box(&self) -> cg::Rect {
+        let mut bounds = Bounds::new();
+        for s in &self.segments {
+            match *s {
+                Segment::MoveTo(p) | Segment::LineTo(p) => bounds.add(p),
+                Segment::QuadTo(c, p) => {
+                    bounds.add(c);
+                    bounds.add(p);
+                }
+                Segment::CurveTo(c1, c2, p) => {
+                    bounds.add(c1);
+                    bounds.add(c2);
+                    bounds.add(p);
+                }
+                Segment::Close => {}
+            }
+        }
+        bounds.rect()
+    }
+
+    /// Tight bounding box of the path itself, the null rect for empty paths.
+    #[doc(alias = "CGPathGetPathBoundingBox")]
+    pub fn path_bounding_box(&self) -> cg::Rect {
+        let mut bounds = Bounds::new();
+        for s in &self.segments {
+            if let Segment::MoveTo(p) = s {
+                bounds.add(*p);
+            }
+        }
+        for curve in self.curves() {
+            curve.add_extrema(&mut bounds);
+        }
+        bounds.rect()
+    }
+
+    /// Same path with curves replaced by lines no further than `threshold` from them.
+    #[doc(alias = "CGPathCreateCopyByFlattening")]
+    pub fn flattened(&self, threshold: Float) -> Self {
+        let threshold = threshold.max(Float::EPSILON);
+        let mut res = Self::new();
+        let mut curves = self.curves();
+        for s in &self.segments {
+            match s {
+                Segment::MoveTo(p) => res.push(Segment::MoveTo(*p)),
+                Segment::Close => {
+                    curves.next();
+                    res.push(Segment::Close);
+                }
+                _ => {
+                    let Some(curve) = curves.next() else {
+                        break;
+                    };
+                    let n = curve.flatten_steps(threshold);
+                    for i in 1..n {
+                        res.push(Segment::LineTo(curve.eval(i as Float / n as Float)));
+                    }
+                    res.push(Segment::LineTo(curve.end()));
+                }
+            }
+        }
+        res
+    }
+
+    /// Total length of all subpaths, including closing lines.
+    pub fn length(&self) -> Float {
+        self.curves().map(|c| c.length()).sum()
+    }
+
+    /// The point `distance` along the path, clamped to its ends.
+    pub fn point_at_length(&self, distance: Float) -> Option<cg::Point> {
+        let mut last = None;
+        let mut remaining = distance.max(0.0);
+        for curve in self.curves() {
+            let len = curve.length();
+            if remaining <= len {
+                return Some(curve.point_at_length(remaining, len));
+            }
+            remaining -= len;
+            last = Some(curve.end());
+        }
+        last.or_else(|| self.current_point())
+    }
+
+    #[doc(alias = "CGPathCreateCopyByTransformingPath")]
+    pub fn transformed(&self, t: &cg::AffineTransform) -> Self {
+        Self {
+            segments: self.segments.iter().map(|s| s.transformed(t)).collect(),
+        }
+    }
+
+    /// Copies elements of a CoreGraphics path.
+    #[cfg(target_vendor = "apple")]
+    pub fn with_cg_path(path: &cg::Path) -> Self {
+        extern "C" fn push(data: *mut PathData, element: *mut cg::PathElement) {
+            let (data, element) = unsafe { (&mut *data, &*element) };
+            let p = element.points();
+            data.push(match element.type_ {
+                cg::PathElementType::MoveToPoint => Segment::MoveTo(p[0]),
+                cg::PathElementType::AddLineToPoint => Segment::LineTo(p[0]),
+                cg::PathElementType::AddQuadCurveToPoint => Segment::QuadTo(p[0], p[1]),
+                cg::PathElementType::AddCurveToPoint => Segment::CurveTo(p[0], p[1], p[2]),
+                cg::PathElementType::CloseSubpath => Segment::Close,
+            });
+        }
+        let mut res = Self::new();
+        path.apply(&mut res, push);
+        res
+    }
+
+    #[cfg(target_vendor = "apple")]
+    pub fn cg_path(&self) -> arc::R<cg::PathMut> {
+        let mut path = cg::PathMut::new();
+        path.add_path_data(None, self);
+        path
+    }
+}
+
+#[cfg(target_vendor = "apple")]
+impl cg::PathMut {
+    pub fn add_path_data(&mut self, m: Option<&cg::AffineTransform>, data: &PathData) {
+        for s in data.segments() {
+            match *s {
+                Segment::MoveTo(p) => self.move_to_point(m, p.x, p.y),
+                Segment::LineTo(p) => self.line_to_point(m, p.x, p.y),
+                Segment::QuadTo(c, p) => self.quad_curve_to_point(m, c.x, c.y, p.x, p.y),
+                Segment::CurveTo(c1, c2, p) => {
+                    self.curve_to_point(m, c1.x, c1.y, c2.x, c2.y, p.x, p.y)
+                }
+                Segment::Close => self.close_subpath(),
+            }
+        }
+    }
+}
+
+#[cfg(target_vendor = "apple")]
+impl From<&cg::Path> for PathData {
+    #[inline]
+    fn from(path: &cg::Path) -> Self {
+        Self::with_cg_path(path)
+    }
+}
+
+impl std::ops::Mul<cg::AffineTransform> for PathData {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: cg::AffineTransform) -> Self {
+        self.transformed(&rhs)
+    }
+}
+
+impl std::str::FromStr for PathData {
+    type Err = SvgError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_svg(s)
+    }
+}
+
+impl std::fmt::Display for PathData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, s) in self.segments.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            match s {
+                Segment::MoveTo(p) => write!(f, "M{} {}", p.x, p.y)?,
+                Segment::LineTo(p) => write!(f, "L{} {}", p.x, p.y)?,
+                Segment::QuadTo(c, p) => write!(f, "Q{} {} {} {}", c.x, c.y, p.x, p.y)?,
+                Segment::CurveTo(c1, c2, p) => {
+                    write!(f, "C{} {} {} {} {} {}", c1.x, c1.y, c2.x, c2.y, p.x, p.y)?
+                }
+                Segment::Close => f.write_str("Z")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builds [`PathData`] the way [`cg::PathMut`] builds paths.
+#[derive(Debug, Clone, Default)]
+pub struct Builder {
+    data: PathData,
+    start: Option<cg::Point>,
+    current: Option<cg::Point>,
+}
+
+impl Builder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn current_point(&self) -> Option<cg::Point> {
+        self.current
+    }
+
+    #[inline]
+    pub fn data(&self) -> &PathData {
+        &self.data
+    }
+
+    #[inline]
+    pub fn finish(self) -> PathData {
+        self.data
+    }
+
+    pub fn move_to(&mut self, p: cg::Point) -> &mut Self {
+        self.data.push(Segment::MoveTo(p));
+        self.start = Some(p);
+        self.current = Some(p);
+        self
+    }
+
+    /// Segments added without a current point start a new subpath at their end point.
+    fn advance(&mut self, p: cg::Point) -> bool {
+        if self.current.is_none() {
+            self.move_to(p);
+            return false;
+        }
+        self.current = Some(p);
+        true
+    }
+
+    pub fn line_to(&mut self, p: cg::Point) -> &mut Self {
+        if self.advance(p) {
+            self.data.push(Segment::LineTo(p));
+        }
+        self
+    }
+
+    pub fn quad_to(&mut self, c: cg::Point, p: cg::Point) -> &mut Self {
+        if self.advance(p) {
+            self.data.push(Segment::QuadTo(c, p));
+        }
+        self
+    }
+
+    pub fn curve_to(&mut self, c1: cg::Point, c2: cg::Point, p: cg::Point) -> &mut Self {
+        if self.advance(p) {
+            self.data.push(Segment::CurveTo(c1, c2, p));
+        }
+        self
+    }
+
+    pub fn close(&mut self) -> &mut Self {
+        if self.current.is_some() {
+            self.data.push(Segment::Close);
+            self.current = self.start;
+        }
+        self
+    }
+
+    /// SVG elliptical arc from the current point to `p`, as up to four cubic curves.
+    ///
+    /// `x_axis_rotation` is in degrees. Radii too small to reach `p` are scaled up.
+    pub fn svg_arc_to(
+        &mut self,
+        radii: cg::Size,
+        x_axis_rotation: Float,
+        large_arc: bool,
+        sweep: bool,
+        p: cg::Point,
+    ) -> &mut Self {
+        let Some(from) = self.current else {
+            return self.move_to(p);
+        };
+        if from == p {
+            return self;
+        }
+        let (mut rx, mut ry) = (radii.width.abs(), radii.height.abs());
+        if rx == 0.0 || ry == 0.0 {
+            return self.line_to(p);
+        }
+
+        // https://www.w3.org/TR/SVG11/implnote.html#ArcConversionEndpointToCenter
+        let (sin_phi, cos_phi) = x_axis_rotation.to_radians().sin_cos();
+        let dx2 = (from.x - p.x) * 0.5;
+        let dy2 = (from.y - p.y) * 0.5;
+        let x1 = cos_phi * dx2 + sin_phi * dy2;
+        let y1 = -sin_phi * dx2 + cos_phi * dy2;
+
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+        let (rx2, ry2) = (rx * rx, ry * ry);
+        let num = rx2 * ry2 - rx2 * y1 * y1 - ry2 * x1 * x1;
+        let den = rx2 * y1 * y1 + ry2 * x1 * x1;
+        let mut coef = (num / den).max(0.0).sqrt();
+        if large_arc == sweep {
+            coef = -coef;
+        }
+        let cx1 = coef * rx * y1 / ry;
+        let cy1 = -coef * ry * x1 / rx;
+        let cx = cos_phi * cx1 - sin_phi * cy1 + (from.x + p.x) * 0.5;
+        let cy = sin_phi * cx1 + cos_phi * cy1 + (from.y + p.y) * 0.5;
+
+        let angle = |ux: Float, uy: Float, vx: Float, vy: Float| {
+            (ux * vy - uy * vx).atan2(ux * vx + uy * vy)
+        };
+        let (ux, uy) = ((x1 - cx1) / rx, (y1 - cy1) / ry);
+        let (vx, vy) = ((-x1 - cx1) / rx, (-y1 - cy1) / ry);
+        let theta = angle(1.0, 0.0, ux, uy);
+        let mut delta = angle(ux, uy, vx, vy);
+        if !sweep && delta > 0.0 {
+            delta -= 2.0 * PI as Float;
+        } else if sweep && delta < 0.0 {
+            delta += 2.0 * PI as Float;
+        }
+
+        let n = (delta.abs() / FRAC_PI_2 as Float).ceil().max(1.0) as usize;
+        let step = delta / n as Float;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+        let map = |x: Float, y: Float| {
+            cg::Point::new(
+                cx + rx * cos_phi * x - ry * sin_phi * y,
+                cy + rx * sin_phi * x + ry * cos_phi * y,
+            )
+        };
+        for i in 0..n {
+            let (s1, c1) = (theta + step * i as Float).sin_cos();
+            let (s2, c2) = (theta + step * (i + 1) as Float).sin_cos();
+            let end = if i + 1 == n { p } else { map(c2, s2) };
+            self.curve_to(
+                map(c1 - k * s1, s1 + k * c1),
+                map(c2 + k * s2, s2 - k * c2),
+                end,
+            );
+        }
+        self
+    }
+
+    /// Same elements as `CGPathAddRect`.
+    #[doc(alias = "CGPathAddRect")]
+    pub fn add_rect(&mut self, rect: cg::Rect) -> &mut Self {
+        let r = rect.standardize();
+        self.move_to(cg::Point::new(r.min_x(), r.min_y()))
+            .line_to(cg::Point::new(r.max_x(), r.min_y()))
+            .line_to(cg::Point::new(r.max_x(), r.max_y()))
+            .line_to(cg::Point::new(r.min_x(), r.max_y()))
+            .close()
+    }
+
+    /// Four cubic curves starting at the max x, mid y point, counter-clockwise.
+    #[doc(alias = "CGPathAddEllipseInRect")]
+    pub fn add_ellipse_in_rect(&mut self, rect: cg::Rect) -> &mut Self {
+        const KAPPA: Float = 0.552_284_749_830_793_4;
+        let r = rect.standardize();
+        let (rx, ry) = (r.width() * 0.5, r.height() * 0.5);
+        let (cx, cy) = (r.mid_x(), r.mid_y());
+        let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+        let p = cg::Point::new;
+        self.move_to(p(cx + rx, cy))
+            .curve_to(p(cx + rx, cy + ky), p(cx + kx, cy + ry), p(cx, cy + ry))
+            .curve_to(p(cx - kx, cy + ry), p(cx - rx, cy + ky), p(cx - rx, cy))
+            .curve_to(p(cx - rx, cy - ky), p(cx - kx, cy - ry), p(cx, cy - ry))
+            .curve_to(p(cx + kx, cy - ry), p(cx + rx, cy - ky), p(cx + rx, cy))
+            .close()
+    }
+}
+
+struct Bounds {
+    min: cg::Point,
+    max: cg::Point,
+}
+
+impl Bounds {
+    fn new() -> Self {
+        Self {
+            min: cg::Point::new(Float::INFINITY, Float::INFINITY),
+            max: cg::Point::new(Float::NEG_INFINITY, Float::NEG_INFINITY),
+        }
+    }
+
+    fn add(&mut self, p: cg::Point) {
+        self.min.x = self.min.x.min(p.x);
+        self.min.y = self.min.y.min(p.y);
+        self.max.x = self.max.x.max(p.x);
+        self.max.y = self.max.y.max(p.y);
+    }
+
+    fn rect(&self) -> cg::Rect {
+        if self.min.x > self.max.x {
+            return cg::Rect::null();
+        }
+        let size = self.max - self.min;
+        cg::Rect::new(self.min.x, self.min.y, size.dx, size.dy)
+    }
+}
+
+#[inline]
+fn lerp(a: cg::Point, b: cg::Point, t: Float) -> cg::Point {
+    cg::Point::new(a.x + (b.x - a.x) * t, a.y + (b.y - a.y) * t)
+}
+
+#[inline]
+fn dist(a: cg::Point, b: cg::Point) -> Float {
+    (b.x - a.x).hypot(b.y - a.y)
+}
+
+/// Second difference, used to bound the flatness of a curve.
+#[inline]
+fn dd(a: cg::Point, b: cg::Point, c: cg::Point) -> Float {
+    (a.x - 2.0 * b.x + c.x).hypot(a.y - 2.0 * b.y + c.y)
+}
+
+/// Parameters in `(0, 1)` where `a t^2 + b t + c` crosses zero.
+fn quadratic_roots(a: Float, b: Float, c: Float) -> impl Iterator<Item = Float> {
+    let mut roots = [Float::NAN; 2];
+    if a.abs() < 1e-12 {
+        if b != 0.0 {
+            roots[0] = -c / b;
+        }
+    } else {
+        let disc = b * b - 4.0 * a * c;
+        if disc >= 0.0 {
+            let sq = disc.sqrt();
+            roots = [(-b + sq) / (2.0 * a), (-b - sq) / (2.0 * a)];
+        }
+    }
+    roots.into_iter().filter(|t| *t > 0.0 && *t < 1.0)
+}
+
+impl Curve {
+    fn end(&self) -> cg::Point {
+        match *self {
+            Self::Line(_, p) | Self::Quad(_, _, p) | Self::Cubic(_, _, _, p) => p,
+        }
+    }
+
+    fn eval(&self, t: Float) -> cg::Point {
+        match *self {
+            Self::Line(a, b) => lerp(a, b, t),
+            Self::Quad(a, b, c) => lerp(lerp(a, b, t), lerp(b, c, t), t),
+            Self::Cubic(a, b, c, d) => {
+                let (ab, bc, cd) = (lerp(a, b, t), lerp(b, c, t), lerp(c, d, t));
+                lerp(lerp(ab, bc, t), lerp(bc, cd, t), t)
+            }
+        }
+    }
+
+    /// Number of lines needed to stay within `threshold` (Wang's formula).
+    fn flatten_steps(&self, threshold: Float) -> usize {
+        let (m, k) = match *self {
+            Self::Line(..) => return 1,
+            Self::Quad(a, b, c) => (dd(a, b, c), 0.25),
+            Self::Cubic(a, b, c, d) => (dd(a, b, c).max(dd(b, c, d)), 0.75),
+        };
+        ((k * m / threshold).sqrt().ceil() as usize).clamp(1, 1 << 12)
+    }
+
+    fn add_extrema(&self, bounds: &mut Bounds) {
+        bounds.add(self.end());
+        let ts: Vec<Float> = match *self {
+            Self::Line(..) => return,
+            Self::Quad(a, b, c) => [(a.x, b.x, c.x), (a.y, b.y, c.y)]
+                .into_iter()
+                .flat_map(|(p0, p1, p2)| quadratic_roots(0.0, p0 - 2.0 * p1 + p2, p1 - p0))
+                .collect(),
+            Self::Cubic(a, b, c, d) => [(a.x, b.x, c.x, d.x), (a.y, b.y, c.y, d.y)]
+                .into_iter()
+                .flat_map(|(p0, p1, p2, p3)| {
+                    quadratic_roots(
+                        -p0 + 3.0 * p1 - 3.0 * p2 + p3,
+                        2.0 * (p0 - 2.0 * p1 + p2),
+                        p1 - p0,
+                    )
+                })
+                .collect(),
+        };
+        for t in ts {
+            bounds.add(self.eval(t));
+        }
+    }
+
+    fn to_cubic(self) -> [cg::Point; 4] {
+        match self {
+            Self::Line(a, b) => [a, lerp(a, b, 1.0 / 3.0), lerp(a, b, 2.0 / 3.0), b],
+            Self::Quad(a, b, c) => [a, lerp(a, b, 2.0 / 3.0), lerp(c, b, 2.0 / 3.0), c],
+            Self::Cubic(a, b, c, d) => [a, b, c, d],
+        }
+    }
+
+    fn length(&self) -> Float {
+        match *self {
+            Self::Line(a, b) => dist(a, b),
+            _ => cubic_length(self.to_cubic(), 16),
+        }
+    }
+
+    /// Point at `distance` along the curve of total length `len`.
+    fn point_at_length(&self, distance: Float, len: Float) -> cg::Point {
+        if len <= 0.0 {
+            return self.end();
+        }
+        if let Self::Line(..) = self {
+            return self.eval(distance / len);
+        }
+        let p = self.to_cubic();
+        let (mut lo, mut hi) = (0.0, 1.0);
+        for _ in 0..48 {
+            let t = (lo + hi) * 0.5;
+            if cubic_length(split_cubic(p, t).0, 16) < distance {
+                lo = t;
+            } else {
+                hi = t;
+            }
+        }
+        self.eval((lo + hi) * 0.5)
+    }
+}
+
+fn split_cubic(p: [cg::Point; 4], t: Float) -> ([cg::Point; 4], [cg::Point; 4]) {
+    let (ab, bc, cd) = (
+        lerp(p[0], p[1], t),
+        lerp(p[1], p[2], t),
+        lerp(p[2], p[3], t),
+    );
+    let (abc, bcd) = (lerp(ab, bc, t), lerp(bc, cd, t));
+    let m = lerp(abc, bcd, t);
+    ([p[0], ab, abc, m], [m, bcd, cd, p[3]])
+}
+
+/// Averages chord and control polygon lengths, subdividing until they agree.
+fn cubic_length(p: [cg::Point; 4], depth: u32) -> Float {
+    let chord = dist(p[0], p[3]);
+    let poly = dist(p[0], p[1]) + dist(p[1], p[2]) + dist(p[2], p[3]);
+    if depth == 0 || poly - chord <= poly * 1e-6 {
+        return (chord + poly) * 0.5;
+    }
+    let (l, r) = split_cubic(p, 0.5);
+    cubic_length(l, depth - 1) + cubic_length(r, depth - 1)
+}
+
+struct Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn err<T>(&self, reason: &'static str) -> Result<T, SvgError> {
+        Err(SvgError {
+            offset: self.pos,
+            reason,
+        })
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0c')) {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_sep(&mut self) {
+        self.skip_ws();
+        if self.peek() == Some(b',') {
+            self.pos += 1;
+            self.skip_ws();
+        }
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn number(&mut self) -> Result<Float, SvgError> {
+        self.skip_ws();
+        let start = self.pos;
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.pos += 1;
+        }
+        let mut n = self.digits();
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            n += self.digits();
+        }
+        if n == 0 {
+            self.pos = start;
+            return self.err("expected number");
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let mantissa_end = self.pos;
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            if self.digits() == 0 {
+                self.pos = mantissa_end;
+            }
+        }
+        let s = std::str::from_utf8(&self.s[start..self.pos]).unwrap();
+        let Some(v) = s.parse::<Float>().ok().filter(|v| v.is_finite()) else {
+            self.pos = start;
+            return self.err("expected number");
+        };
+        self.skip_sep();
+        Ok(v)
+    }
+
+    fn point(&mut self) -> Result<cg::Point, SvgError> {
+        Ok(cg::Point::new(self.number()?, self.number()?))
+    }
+
+    fn flag(&mut self) -> Result<bool, SvgError> {
+        self.skip_ws();
+        let v = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return self.err("expected flag"),
+        };
+        self.pos += 1;
+        self.skip_sep();
+        Ok(v)
+    }
+
+    fn parse(mut self) -> Result<PathData, SvgError> {
+        let mut b = Builder::new();
+        let mut cmd = None;
+        // reflected by the smooth curve commands
+        let mut last_cubic_ctrl = None;
+        let mut last_quad_ctrl = None;
+
+        loop {
+            self.skip_ws();
+            let Some(c) = self.peek() else {
+                break;
+            };
+            let c = if c.is_ascii_alphabetic() {
+                self.pos += 1;
+                c
+            } else {
+                match cmd {
+                    Some(b'M') => b'L',
+                    Some(b'm') => b'l',
+                    Some(c) if !matches!(c, b'Z' | b'z') => c,
+                    _ => return self.err("expected command"),
+                }
+            };
+            if cmd.is_none() && !matches!(c, b'M' | b'm') {
+                self.pos -= 1;
+                return self.err("path data must start with move to");
+            }
+            cmd = Some(c);
+
+            let current = b.current_point().unwrap_or(cg::Point::zero());
+            let base = if c.is_ascii_lowercase() {
+                cg::Vector::new(current.x, current.y)
+            } else {
+                cg::Vector::zero()
+            };
+            let mut cubic_ctrl = None;
+            let mut quad_ctrl = None;
+            match c.to_ascii_uppercase() {
+                b'M' => {
+                    b.move_to(self.point()? + base);
+                }
+                b'L' => {
+                    b.line_to(self.point()? + base);
+                }
+                b'H' => {
+                    b.line_to(cg::Point::new(self.number()? + base.dx, current.y));
+                }
+                b'V' => {
+                    b.line_to(cg::Point::new(current.x, self.number()? + base.dy));
+                }
+                b'C' => {
+                    let c1 = self.point()? + base;
+                    let c2 = self.point()? + base;
+                    b.curve_to(c1, c2, self.point()? + base);
+                    cubic_ctrl = Some(c2);
+                }
+                b'S' => {
+                    let c1 = last_cubic_ctrl.map_or(current, |c| current + (current - c));
+                    let c2 = self.point()? + base;
+                    b.curve_to(c1, c2, self.point()? + base);
+                    cubic_ctrl = Some(c2);
+                }
+                b'Q' => {
+                    let ctrl = self.point()? + base;
+                    b.quad_to(ctrl, self.point()? + base);
+                    quad_ctrl = Some(ctrl);
+                }
+                b'T' => {
+                    let ctrl = last_quad_ctrl.map_or(current, |c| current + (current - c));
+                    b.quad_to(ctrl, self.point()? + base);
+                    quad_ctrl = Some(ctrl);
+                }
+                b'A' => {
+                    let radii = cg::Size::new(self.number()?, self.number()?);
+                    let rotation = self.number()?;
+                    let large_arc = self.flag()?;
+                    let sweep = self.flag()?;
+                    b.svg_arc_to(radii, rotation, large_arc, sweep, self.point()? + base);
+                }
+                b'Z' => {
+                    b.close();
+                    self.skip_sep();
+                }
+                _ => {
+                    self.pos -= 1;
+                    return self.err("unknown command");
+                }
+            }
+            last_cubic_ctrl = cubic_ctrl;
+            last_quad_ctrl = quad_ctrl;
+        }
+        Ok(b.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cg;
+
+    use super::Segment::*;
+
+    fn p(x: cg::Float, y: cg::Float) -> cg::Point {
+        cg::Point::new(x, y)
+    }
+
+    fn approx(a: cg::Point, b: cg::Point) -> bool {
+        (a.x - b.x).abs() < 1e-6 && (a.y - b.y).abs() < 1e-6
+    }
+
+    #[test]
+    fn svg() {
+        let path = cg::PathData::from_svg(
+            "M10,10 l10-10 20 0H50v5 C50 10 60 10 60 5 s10 -5 10 0 \
+             Q80 0 90 5 t10 0 z m1.5.5 1e1,-1E-1",
+        )
+        .unwrap();
+        assert_eq!(
+            path.segments(),
+            &[
+                MoveTo(p(10.0, 10.0)),
+                LineTo(p(20.0, 0.0)),
+                LineTo(p(40.0, 0.0)),
+                LineTo(p(50.0, 0.0)),
+                LineTo(p(50.0, 5.0)),
+                CurveTo(p(50.0, 10.0), p(60.0, 10.0), p(60.0, 5.0)),
+                CurveTo(p(60.0, 0.0), p(70.0, 0.0), p(70.0, 5.0)),
+                QuadTo(p(80.0, 0.0), p(90.0, 5.0)),
+                QuadTo(p(100.0, 10.0), p(100.0, 5.0)),
+                Close,
+                MoveTo(p(11.5, 10.5)),
+                LineTo(p(21.5, 10.4)),
+            ]
+        );
+        assert_eq!(path.current_point(), Some(p(21.5, 10.4)));
+
+        let svg = path.to_svg();
+        assert!(svg.starts_with("M10 10 L20 0 L40 0"));
+        assert_eq!(svg.parse::<cg::PathData>().unwrap(), path);
+        assert!(cg::PathData::from_svg("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn svg_errors() {
+        let err = |d: &str| cg::PathData::from_svg(d).unwrap_err();
+        assert_eq!(err("L10 10").offset, 0);
+        assert_eq!(err("M10").offset, 3);
+        assert_eq!(err("M10 10 X").offset, 7);
+        assert_eq!(err("M10 10 Z 5").offset, 9);
+        assert_eq!(err("M0 0 A1 1 0 2 0 1 1").reason, "expected flag");
+        assert_eq!(
+            err("M0 0 L-").to_string(),
+            "invalid path data at 6: expected number"
+        );
+        // overflows to infinity
+        let e = err("M0 0 L1e999 0");
+        assert_eq!((e.offset, e.reason), (6, "expected number"));
+        assert_eq!(err("M-1e400 0").offset, 1);
+    }
+
+    #[test]
+    fn arc() {
+        // compact flags and a half circle of radius 10 around (10, 0)
+        let path = cg::PathData::from_svg("M0 0 A10 10 0 0120 0").unwrap();
+        assert_eq!(path.segments().len(), 3);
+        assert_eq!(path.current_point(), Some(p(20.0, 0.0)));
+        for s in path.segments() {
+            let e = s.end_point().unwrap();
+            assert!(((e.x - 10.0).hypot(e.y) - 10.0).abs() < 1e-9);
+        }
+        // sweep flag means increasing angles, from pi through 3pi/2 to 2pi
+        assert!(approx(
+            path.point_at_length(path.length() / 2.0).unwrap(),
+            p(10.0, -10.0)
+        ));
+        assert!((path.length() - std::f64::consts::PI * 10.0).abs() < 1e-2);
+
+        // radii too small are scaled up
+        let small = cg::PathData::from_svg("M0 0 A1 1 0 0 0 20 0").unwrap();
+        let b = small.path_bounding_box();
+        assert!(b.min_y().abs() < 1e-9 && (b.max_y() - 10.0).abs() < 1e-6);
+
+        let line = cg::PathData::from_svg("M0 0 A0 5 0 0 0 20 0").unwrap();
+        assert_eq!(
+            line.segments(),
+            &[MoveTo(p(0.0, 0.0)), LineTo(p(20.0, 0.0))]
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        assert!(cg::PathData::new().bounding_box().is_null());
+
+        let path = cg::PathData::from_svg("M0 0 C0 10 10 10 10 0").unwrap();
+        assert_eq!(path.bounding_box(), cg::Rect::new(0.0, 0.0, 10.0, 10.0));
+        assert_eq!(path.path_bounding_box(), cg::Rect::new(0.0, 0.0, 10.0, 7.5));
+
+        let path = cg::PathData::from_svg("M0 0 Q5 10 10 0").unwrap();
+        assert_eq!(path.path_bounding_box(), cg::Rect::new(0.0, 0.0, 10.0, 5.0));
+
+        let mut b = cg::PathBuilder::new();
+        b.add_ellipse_in_rect(cg::Rect::new(0.0, 0.0, 20.0, 10.0));
+        let ellipse = b.finish();
+        let tight = ellipse.path_bounding_box();
+        assert!(approx(tight.origin, p(0.0, 0.0)));
+        assert!((tight.size.width - 20.0).abs() < 1e-9);
+        assert!((tight.size.height - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn measure() {
+        let mut b = cg::PathBuilder::new();
+        b.add_rect(cg::Rect::new(0.0, 0.0, 10.0, 5.0));
+        let rect = b.finish();
+        assert_eq!(rect.length(), 30.0);
+        assert_eq!(rect.point_at_length(12.0), Some(p(10.0, 2.0)));
+        assert_eq!(rect.point_at_length(29.0), Some(p(0.0, 1.0)));
+        assert_eq!(rect.point_at_length(100.0), Some(p(0.0, 0.0)));
+        assert_eq!(rect.point_at_length(-1.0), Some(p(0.0, 0.0)));
+        assert_eq!(cg::PathData::new().point_at_length(1.0), None);
+
+        // quarter circle approximation
+        let mut b = cg::PathBuilder::new();
+        b.add_ellipse_in_rect(cg::Rect::new(-1.0, -1.0, 2.0, 2.0));
+        let circle = b.finish();
+        assert!((circle.length() - 2.0 * std::f64::consts::PI).abs() < 1e-3);
+        let q = circle.point_at_length(circle.length() / 4.0).unwrap();
+        assert!(approx(q, p(0.0, 1.0)));
+
+        let flat = circle.flattened(0.01);
+        assert!(
+            flat.segments()
+                .iter()
+                .all(|s| matches!(s, MoveTo(_) | LineTo(_) | Close))
+        );
+        assert!(flat.segments().len() > 8);
+        assert!((flat.length() - circle.length()).abs() < 0.05);
+        for s in flat.segments() {
+            if let Some(e) = s.end_point() {
+                assert!((e.x.hypot(e.y) - 1.0).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn transform() {
+        let path = cg::PathData::from_svg("M1 1 Q2 2 3 1 Z").unwrap();
+        let t = cg::AffineTransform::new_translation(10.0, 0.0).scale(2.0, 2.0);
+        let moved = path.clone() * t;
+        assert_eq!(
+            moved.segments(),
+            &[
+                MoveTo(p(12.0, 2.0)),
+                QuadTo(p(14.0, 4.0), p(16.0, 2.0)),
+                Close
+            ]
+        );
+        assert_eq!(
+            moved.segments()[1].type_(),
+            cg::PathElementType::AddQuadCurveToPoint
+        );
+        assert_eq!(path.transformed(&t), moved);
+    }
+
+    #[cfg(target_vendor = "apple")]
+    #[test]
+    fn cg_roundtrip() {
+        let data = cg::PathData::from_svg("M0 0 L10 0 Q15 5 10 10 C5 15 0 15 0 10 Z").unwrap();
+        let path = data.cg_path();
+        assert_eq!(cg::PathData::with_cg_path(&path), data);
+        assert_eq!(path.bounding_box(), data.bounding_box());
+
+        let rect = cg::Rect::new(1.0, 2.0, 30.0, 20.0);
+        let mut ellipse = cg::PathMut::new();
+        ellipse.add_ellipse_in_rect(None, rect);
+        let from_cg = cg::PathData::with_cg_path(&ellipse);
+        let mut b = cg::PathBuilder::new();
+        b.add_ellipse_in_rect(rect);
+        let ours = b.finish();
+        assert_eq!(from_cg.segments().len(), ours.segments().len());
+        for (a, b) in from_cg.segments().iter().zip(ours.segments()) {
+            assert_eq!(a.type_(), b.type_());
+            if let (Some(a), Some(b)) = (a.end_point(), b.end_point()) {
+                assert!(approx(a, b));
+            }
+        }
+    }
+}
@@ -1,46 +1,99 @@
+#[cfg(target_vendor = "apple")]
 mod base;
+#[cfg(target_vendor = "apple")]
 pub use base::ItemCount;
+#[cfg(target_vendor = "apple")]
 pub use base::ItemIndex;
+#[cfg(target_vendor = "apple")]
 pub use base::PersistentTrackId;
 
 mod format_description;
+#[cfg(target_vendor = "apple")]
 pub use format_description::AudioFormatDesc;
+#[cfg(target_vendor = "apple")]
 pub use format_description::FormatDesc;
+#[cfg(target_vendor = "apple")]
 pub use format_description::FormatDescExtKey;
+#[cfg(target_vendor = "apple")]
 pub use format_description::LogTransferFn;
 pub use format_description::MediaType;
+#[cfg(target_vendor = "apple")]
 pub use format_description::MuxedFormatDesc;
 pub use format_description::MuxedStreamType;
 pub use format_description::PixelFormat;
+#[cfg(target_vendor = "apple")]
 pub use format_description::TaggedBufGroupFormatDesc;
 pub use format_description::TaggedBufGroupFormatType;
+#[cfg(target_vendor = "apple")]
+pub use format_description::TimeCodeFormatDesc;
 pub use format_description::VideoCodec;
 pub use format_description::VideoDimensions;
+#[cfg(target_vendor = "apple")]
 pub use format_description::VideoFormatDesc;
+pub use format_description::err as format_desc_err;
 
+pub mod video_cfg;
+pub use video_cfg::AvcCfg;
+pub use video_cfg::AvcCfgExt;
+pub use video_cfg::HevcCfg;
+pub use video_cfg::HevcNalArray;
+pub use video_cfg::Sps;
+
+pub mod nal;
+pub use nal::NalCodec;
+
+pub mod fmp4;
+pub mod ts;
+
+pub mod hdr;
+
+pub mod timecode;
+pub use timecode::Timecode;
+
+#[cfg(target_vendor = "apple")]
 mod format_description_bridge;
+#[cfg(target_vendor = "apple")]
 pub use format_description_bridge::ImageDescFlavor;
+#[cfg(target_vendor = "apple")]
 pub use format_description_bridge::SoundDescFlavor;
+#[cfg(target_vendor = "apple")]
 pub use format_description_bridge::TextDescFlavor;
+#[cfg(target_vendor = "apple")]
 pub use format_description_bridge::err as format_desc_bridge_err;
+#[cfg(target_vendor = "apple")]
 pub use format_description_bridge::swap_be_image_desc_to_host;
+#[cfg(target_vendor = "apple")]
 pub use format_description_bridge::swap_be_sound_desc_to_host;
+#[cfg(target_vendor = "apple")]
 pub use format_description_bridge::swap_host_image_desc_to_be;
+#[cfg(target_vendor = "apple")]
 pub use format_description_bridge::swap_host_sound_desc_to_be;
 
+#[cfg(target_vendor = "apple")]
 mod tag;
+#[cfg(target_vendor = "apple")]
 pub use tag::Tag;
+#[cfg(target_vendor = "apple")]
 pub use tag::TagCategory;
+#[cfg(target_vendor = "apple")]
 pub use tag::TagDType;
+#[cfg(target_vendor = "apple")]
 pub use tag::err as tag_err;
 
+#[cfg(target_vendor = "apple")]
 mod tag_collection;
+#[cfg(target_vendor = "apple")]
 pub use tag_collection::TagCollection;
+#[cfg(target_vendor = "apple")]
 pub use tag_collection::TagCollectionMut;
+#[cfg(target_vendor = "apple")]
 pub use tag_collection::err as tag_collection_err;
 
+#[cfg(target_vendor = "apple")]
 mod tagged_buffer_group;
+#[cfg(target_vendor = "apple")]
 pub use tagged_buffer_group::TaggedBufGroup;
+#[cfg(target_vendor = "apple")]
 pub use tagged_buffer_group::err as tagged_buf_group_err;
 
 mod time;
@@ -54,66 +107,96 @@ pub use time::TimeRoundingMethod;
 pub use time::TimeScale;
 pub use time::TimeValue;
 
+#[cfg(target_vendor = "apple")]
 pub mod buffer_queue;
+#[cfg(target_vendor = "apple")]
 pub use buffer_queue::Buf;
+#[cfg(target_vendor = "apple")]
 pub use buffer_queue::BufBoolCb;
-#[cfg(feature = "blocks")]
+#[cfg(all(target_vendor = "apple", feature = "blocks"))]
 pub use buffer_queue::BufBoolHandler;
+#[cfg(target_vendor = "apple")]
 pub use buffer_queue::BufCompareCb;
-#[cfg(feature = "blocks")]
+#[cfg(all(target_vendor = "apple", feature = "blocks"))]
 pub use buffer_queue::BufCompareHandler;
-#[cfg(feature = "blocks")]
+#[cfg(all(target_vendor = "apple", feature = "blocks"))]
 pub use buffer_queue::BufHandlers;
+#[cfg(target_vendor = "apple")]
 pub use buffer_queue::BufQueue;
+#[cfg(target_vendor = "apple")]
 pub use buffer_queue::BufQueueOf;
+#[cfg(target_vendor = "apple")]
 pub use buffer_queue::BufSizeCb;
-#[cfg(feature = "blocks")]
+#[cfg(all(target_vendor = "apple", feature = "blocks"))]
 pub use buffer_queue::BufSizeHandler;
+#[cfg(target_vendor = "apple")]
 pub use buffer_queue::BufTimeCb;
-#[cfg(feature = "blocks")]
+#[cfg(all(target_vendor = "apple", feature = "blocks"))]
 pub use buffer_queue::BufTimeHandler;
+#[cfg(target_vendor = "apple")]
 pub use buffer_queue::SampleBufQueue;
+#[cfg(target_vendor = "apple")]
 pub use buffer_queue::err as buf_queue_err;
 
+#[cfg(target_vendor = "apple")]
 pub mod sample_buffer;
 
-#[cfg(feature = "cat")]
+#[cfg(all(target_vendor = "apple", feature = "cat"))]
 pub use sample_buffer::BlockBufAudioBufList;
-#[cfg(feature = "cat")]
+#[cfg(all(target_vendor = "apple", feature = "cat"))]
 pub use sample_buffer::BlockBufAudioBufListN;
+#[cfg(target_vendor = "apple")]
 pub use sample_buffer::Flags as SampleBufFlags;
+#[cfg(target_vendor = "apple")]
 pub use sample_buffer::SampleBuf;
+#[cfg(target_vendor = "apple")]
 pub use sample_buffer::SampleTimingInfo;
+#[cfg(target_vendor = "apple")]
 pub use sample_buffer::err as sample_buf_err;
 
+#[cfg(target_vendor = "apple")]
 pub mod attachment;
+#[cfg(target_vendor = "apple")]
 pub use attachment::Bearer as AttachBearer;
+#[cfg(target_vendor = "apple")]
 pub use attachment::Mode as AttachMode;
 
 pub mod block_buffer;
+#[cfg(target_vendor = "apple")]
 pub use block_buffer::BlockBuf;
 pub use block_buffer::Flags as BlockBufFlags;
 pub use block_buffer::err as block_buf_err;
 
+#[cfg(target_vendor = "apple")]
 pub mod sync;
+#[cfg(target_vendor = "apple")]
 pub use sync::Clock;
+#[cfg(target_vendor = "apple")]
 pub use sync::ClockOrTimebase;
+#[cfg(target_vendor = "apple")]
 pub use sync::Timebase;
 
-#[cfg(not(target_os = "macos"))]
+#[cfg(all(target_vendor = "apple", not(target_os = "macos")))]
 pub mod audio_clock;
 
 #[cfg(target_os = "macos")]
 pub mod audio_device_clock;
 
+#[cfg(target_vendor = "apple")]
 pub mod memory_pool;
+#[cfg(target_vendor = "apple")]
 pub use memory_pool::MemPool;
+#[cfg(target_vendor = "apple")]
 pub use memory_pool::keys as mem_pool_keys;
 
+#[cfg(target_vendor = "apple")]
 pub mod simple_queue;
+#[cfg(target_vendor = "apple")]
 pub use simple_queue::SimpleQueue;
+#[cfg(target_vendor = "apple")]
 pub use simple_queue::err as simple_queue_err;
 
+#[cfg(target_vendor = "apple")]
 #[link(name = "CoreMedia", kind = "framework")]
 unsafe extern "C" {}
 
@@ -1,9 +1,12 @@
+#[cfg(target_vendor = "apple")]
 use std::{
     ffi::c_void,
     ptr::{slice_from_raw_parts, slice_from_raw_parts_mut},
 };
 
-use crate::{arc, cf, define_cf_type, define_opts, os};
+use crate::define_opts;
+#[cfg(target_vendor = "apple")]
+use crate::{arc, cf, cm, define_cf_type, os};
 
 define_opts!(
     #[doc(alias = "CMBlockBufferFlags")]
@@ -34,14 +37,18 @@ impl Flags {
     pub const PERMIT_EMPTY_REFERENCE: Self = Self(1u32 << 3);
 }
 
+#[cfg(target_vendor = "apple")]
 define_cf_type!(
     #[doc(alias = "CMBlockBufferRef")]
     BlockBuf(cf::Type)
 );
 // TODO: termporary...
+#[cfg(target_vendor = "apple")]
 unsafe impl Send for BlockBuf {}
+#[cfg(target_vendor = "apple")]
 unsafe impl Sync for BlockBuf {}
 
+#[cfg(target_vendor = "apple")]
 impl BlockBuf {
     /// # Example
     /// ```
@@ -248,6 +255,25 @@ impl BlockBuf {
         }
     }
 
+    /// Copies bytes from a cm::BlockBuf into provided memory.
+    #[doc(alias = "CMBlockBufferCopyDataBytes")]
+    #[inline]
+    pub fn copy_data_bytes(&self, offset: usize, dst: &mut [u8]) -> os::Result {
+        unsafe { CMBlockBufferCopyDataBytes(self, offset, dst.len(), dst.as_mut_ptr()).result() }
+    }
+
+    /// Appends length-prefixed NAL units (VideoToolbox encoder output) to `dst`
+    /// as Annex-B byte stream.
+    pub fn nal_units_to_annex_b(&self, nal_unit_len: u8, dst: &mut Vec<u8>) -> os::Result {
+        let len = self.data_len();
+        if self.is_range_contiguous(0, len) {
+            return cm::nal::len_prefixed_to_annex_b(self.as_slice()?, nal_unit_len, dst);
+        }
+        let mut bytes = vec![0u8; len];
+        self.copy_data_bytes(0, &mut bytes)?;
+        cm::nal::len_prefixed_to_annex_b(&bytes, nal_unit_len, dst)
+    }
+
     #[inline]
     pub fn as_mut_slice(&mut self) -> os::Result<&mut [u8]> {
         let mut length_at_offset_out = 0;
@@ -322,6 +348,7 @@ impl BlockBuf {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 unsafe extern "C-unwind" {
     fn CMBlockBufferGetTypeID() -> cf::TypeId;
     fn CMBlockBufferIsEmpty(the_buffer: &BlockBuf) -> bool;
@@ -349,6 +376,13 @@ unsafe extern "C-unwind" {
 
     fn CMBlockBufferIsRangeContiguous(the_buffer: &BlockBuf, offset: usize, length: usize) -> bool;
 
+    fn CMBlockBufferCopyDataBytes(
+        the_source_buffer: &BlockBuf,
+        offset_to_data: usize,
+        data_length: usize,
+        destination: *mut u8,
+    ) -> os::Status;
+
     fn CMBlockBufferGetDataPointer(
         the_buffer: &BlockBuf,
         offset: usize,
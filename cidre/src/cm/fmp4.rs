@@ -0,0 +1,1168 @@
+//! Fragmented MP4 (ISO/IEC 14496-12) writer producing CMAF style init segment
+//! and `moof`/`mdat` fragments for HLS and LL-HLS.
+//!
+//! Samples come through [`Sample`] trait, implemented for `cm::SampleBuf`,
+//! so the box writer itself doesn't need CoreMedia.
+
+use crate::{
+    cm::{self, format_desc_err as err},
+    define_opts, os,
+};
+
+#[cfg(target_vendor = "apple")]
+use crate::{cf, cm::sample_buf_err};
+
+define_opts!(
+    /// `sample_flags` of `trun` and `trex` boxes.
+    pub SampleFlags(u32)
+);
+
+impl SampleFlags {
+    /// `sample_depends_on` = 1, not an I picture.
+    pub const DEPENDS_ON_OTHERS: Self = Self(1 << 24);
+
+    /// `sample_depends_on` = 2, I picture.
+    pub const DEPENDS_ON_NONE: Self = Self(2 << 24);
+
+    /// `sample_is_depended_on` = 1
+    pub const IS_DEPENDED_ON: Self = Self(1 << 22);
+
+    /// `sample_is_depended_on` = 2, disposable.
+    pub const IS_NOT_DEPENDED_ON: Self = Self(2 << 22);
+
+    /// `sample_has_redundancy` = 1
+    pub const HAS_REDUNDANCY: Self = Self(1 << 20);
+
+    pub const IS_NON_SYNC: Self = Self(1 << 16);
+
+    /// Flags of sync sample.
+    pub const SYNC: Self = Self::DEPENDS_ON_NONE;
+
+    #[inline]
+    pub fn is_sync(&self) -> bool {
+        !self.contains(Self::IS_NON_SYNC)
+    }
+}
+
+/// Access unit to be written into fragment.
+pub trait Sample {
+    /// Invalid decode time means it is the same as presentation time.
+    fn dts(&self) -> cm::Time;
+
+    fn pts(&self) -> cm::Time;
+
+    /// May be invalid, then it is derived from the next sample decode time.
+    fn duration(&self) -> cm::Time;
+
+    fn flags(&self) -> SampleFlags;
+
+    /// Appends sample bytes: length prefixed NAL units for video, raw frame for AAC.
+    fn append_data(&self, dst: &mut Vec<u8>) -> os::Result;
+}
+
+/// Uses the whole buffer as one sample.
+///
+/// Audio buffers with several packets should be split first.
+#[cfg(target_vendor = "apple")]
+impl Sample for cm::SampleBuf {
+    fn dts(&self) -> cm::Time {
+        cm::SampleBuf::dts(self)
+    }
+
+    fn pts(&self) -> cm::Time {
+        cm::SampleBuf::pts(self)
+    }
+
+    fn duration(&self) -> cm::Time {
+        cm::SampleBuf::duration(self)
+    }
+
+    fn flags(&self) -> SampleFlags {
+        use cm::sample_buffer::attach_keys;
+
+        let Some(dict) = self
+            .attaches(false)
+            .filter(|arr| !arr.is_empty())
+            .map(|arr| &arr[0])
+        else {
+            return SampleFlags::SYNC;
+        };
+        // attachment values are cf::Boolean singletons
+        let bool_attach = |key: &cf::String| {
+            dict.get(key)
+                .map(|v| unsafe { v.as_type_ptr() == cf::Boolean::value_true().as_type_ptr() })
+        };
+        let mut res = SampleFlags::default();
+        if !self.is_key_frame() {
+            res |= SampleFlags::IS_NON_SYNC;
+        }
+        res |= match bool_attach(attach_keys::depends_on_others()) {
+            Some(true) => SampleFlags::DEPENDS_ON_OTHERS,
+            Some(false) => SampleFlags::DEPENDS_ON_NONE,
+            None if res.is_sync() => SampleFlags::DEPENDS_ON_NONE,
+            None => SampleFlags::DEPENDS_ON_OTHERS,
+        };
+        match bool_attach(attach_keys::is_depended_on_by_others()) {
+            Some(true) => res |= SampleFlags::IS_DEPENDED_ON,
+            Some(false) => res |= SampleFlags::IS_NOT_DEPENDED_ON,
+            None => {}
+        }
+        if bool_attach(attach_keys::has_redundant_coding()) == Some(true) {
+            res |= SampleFlags::HAS_REDUNDANCY;
+        }
+        res
+    }
+
+    fn append_data(&self, dst: &mut Vec<u8>) -> os::Result {
+        let Some(buf) = self.data_buf() else {
+            return Err(sample_buf_err::REQUIRED_PARAMETER_MISSING);
+        };
+        let start = dst.len();
+        dst.resize(start + buf.data_len(), 0);
+        buf.copy_data_bytes(0, &mut dst[start..])
+    }
+}
+
+/// Codec configuration of `stsd` sample entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SampleEntry {
+    /// `avc1` with `avcC` record.
+    Avc {
+        width: u16,
+        height: u16,
+        avcc: Vec<u8>,
+    },
+
+    /// `hvc1` with `hvcC` record.
+    Hevc {
+        width: u16,
+        height: u16,
+        hvcc: Vec<u8>,
+    },
+
+    /// `mp4a` with `esds` built from AudioSpecificConfig.
+    Aac {
+        sample_rate: u32,
+        channels: u16,
+        asc: Vec<u8>,
+    },
+}
+
+impl SampleEntry {
+    #[inline]
+    pub fn is_video(&self) -> bool {
+        !matches!(self, Self::Aac { .. })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Track {
+    /// Non zero track id.
+    pub id: u32,
+    pub timescale: u32,
+    pub entry: SampleEntry,
+}
+
+fn dims(sps: cm::Sps) -> os::Result<(u16, u16)> {
+    match (u16::try_from(sps.width), u16::try_from(sps.height)) {
+        (Ok(w), Ok(h)) => Ok((w, h)),
+        _ => Err(err::INVALID_PARAM),
+    }
+}
+
+impl Track {
+    /// H.264 track with 90 kHz timescale, dimensions are taken from SPS.
+    pub fn avc(id: u32, cfg: &cm::AvcCfg) -> os::Result<Self> {
+        let (width, height) = dims(cfg.sps_info()?)?;
+        Ok(Self {
+            id,
+            timescale: 90_000,
+            entry: SampleEntry::Avc {
+                width,
+                height,
+                avcc: cfg.to_vec()?,
+            },
+        })
+    }
+
+    /// HEVC track with 90 kHz timescale, dimensions are taken from SPS.
+    pub fn hevc(id: u32, cfg: &cm::HevcCfg) -> os::Result<Self> {
+        let (width, height) = dims(cfg.sps_info()?)?;
+        Ok(Self {
+            id,
+            timescale: 90_000,
+            entry: SampleEntry::Hevc {
+                width,
+                height,
+                hvcc: cfg.to_vec()?,
+            },
+        })
+    }
+
+    /// AAC track with sample rate timescale.
+    ///
+    /// `mp4a` stores sample rate as 16.16 fixed point, so [`Segmenter::new`]
+    /// rejects rates above 65535.
+    pub fn aac(id: u32, sample_rate: u32, channels: u16, asc: &[u8]) -> Self {
+        Self {
+            id,
+            timescale: sample_rate,
+            entry: SampleEntry::Aac {
+                sample_rate,
+                channels,
+                asc: asc.to_vec(),
+            },
+        }
+    }
+
+    /// H.264, HEVC or AAC track from format description of compressed samples.
+    ///
+    /// AAC magic cookie may be either `esds` descriptor or bare AudioSpecificConfig.
+    #[cfg(target_vendor = "apple")]
+    pub fn with_format_desc(id: u32, desc: &cm::FormatDesc) -> os::Result<Self> {
+        match desc.media_sub_type().to_be_bytes() {
+            [b'a', b'v', b'c', b'1'] => Self::avc(id, &desc.avc_cfg()?),
+            [b'h', b'v', b'c', b'1'] | [b'h', b'e', b'v', b'1'] => {
+                Self::hevc(id, &desc.hevc_cfg()?)
+            }
+            #[cfg(feature = "cat")]
+            [b'a', b'a', b'c', b' '] => {
+                let Some(asbd) = desc.stream_basic_desc() else {
+                    return Err(err::INVALID_PARAM);
+                };
+                let Some(cookie) = desc.magic_cookie() else {
+                    return Err(err::VALUE_NOT_AVAILABLE);
+                };
+                let asc = asc_from_cookie(cookie).ok_or(err::INVALID_PARAM)?;
+                Ok(Self::aac(
+                    id,
+                    asbd.sample_rate as u32,
+                    asbd.channels_per_frame as u16,
+                    &asc,
+                ))
+            }
+            _ => Err(err::INVALID_PARAM),
+        }
+    }
+}
+
+/// Takes DecoderSpecificInfo of ES_Descriptor, or cookie as is.
+#[cfg(all(target_vendor = "apple", feature = "cat"))]
+pub(super) fn asc_from_cookie(cookie: &[u8]) -> Option<Vec<u8>> {
+    if cookie.first() != Some(&0x03) {
+        return Some(cookie.to_vec());
+    }
+    let esds = crate::cat::audio::aac::Esds::from_bytes(cookie).ok()?;
+    Some(esds.decoder_specific_info)
+}
+
+/// Target durations of LL-HLS parts and segments.
+#[derive(Debug, Clone, Copy)]
+pub struct SegmenterCfg {
+    /// Fragment duration, new fragment is started once it is reached.
+    pub part_duration: cm::Time,
+
+    /// New segment starts with the first sync sample after this duration.
+    pub segment_duration: cm::Time,
+
+    /// Subtracted from every timestamp, decode times must not go below it.
+    pub base_time: cm::Time,
+}
+
+impl Default for SegmenterCfg {
+    fn default() -> Self {
+        Self {
+            part_duration: cm::Time::new(1, 1),
+            segment_duration: cm::Time::new(6, 1),
+            base_time: cm::Time::zero(),
+        }
+    }
+}
+
+/// Fragment (`moof` + `mdat`), one LL-HLS part.
+#[derive(Debug, Clone)]
+pub struct Part {
+    pub data: Vec<u8>,
+
+    /// `mfhd` sequence number.
+    pub seq: u32,
+
+    /// Decode time of the first sample of the primary track.
+    pub start: cm::Time,
+    pub duration: cm::Time,
+
+    /// Starts with sync sample of the primary track.
+    pub independent: bool,
+
+    /// First part of a new segment.
+    pub segment_start: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PendingSample {
+    /// in track timescale
+    dts: i64,
+    cto: i32,
+    duration: Option<u32>,
+    size: u32,
+    flags: SampleFlags,
+}
+
+#[derive(Debug)]
+struct TrackState {
+    track: Track,
+    /// `track.timescale` checked to fit `cm::TimeScale`
+    scale: i32,
+    pending: Vec<PendingSample>,
+    data: Vec<u8>,
+    last_duration: u32,
+}
+
+/// Cuts samples of several tracks into fragments.
+///
+/// Boundaries follow the primary track, the first video track or the first
+/// track if there is no video.
+#[derive(Debug)]
+pub struct Segmenter {
+    cfg: SegmenterCfg,
+    tracks: Vec<TrackState>,
+    primary: usize,
+    seq: u32,
+    part_start: Option<cm::Time>,
+    segment_start: Option<cm::Time>,
+    part_is_segment_start: bool,
+}
+
+impl Segmenter {
+    pub fn new(tracks: Vec<Track>, cfg: SegmenterCfg) -> os::Result<Self> {
+        if tracks.is_empty() {
+            return Err(err::INVALID_PARAM);
+        }
+        let mut states = Vec::with_capacity(tracks.len());
+        for track in tracks {
+            let Ok(scale @ 1..) = i32::try_from(track.timescale) else {
+                return Err(err::INVALID_PARAM);
+            };
+            if track.id == 0 || states.iter().any(|s: &TrackState| s.track.id == track.id) {
+                return Err(err::INVALID_PARAM);
+            }
+            if let SampleEntry::Aac { sample_rate, .. } = track.entry
+                && sample_rate > 0xffff
+            {
+                return Err(err::INVALID_PARAM);
+            }
+            states.push(TrackState {
+                track,
+                scale,
+                pending: Vec::new(),
+                data: Vec::new(),
+                last_duration: 0,
+            });
+        }
+        let primary = states
+            .iter()
+            .position(|s| s.track.entry.is_video())
+            .unwrap_or(0);
+        Ok(Self {
+            cfg,
+            tracks: states,
+            primary,
+            seq: 0,
+            part_start: None,
+            segment_start: None,
+            part_is_segment_start: true,
+        })
+    }
+
+    pub fn tracks(&self) -> impl Iterator<Item = &Track> {
+        self.tracks.iter().map(|t| &t.track)
+    }
+
+    /// `ftyp` and `moov` boxes.
+    pub fn init_segment(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(1024);
+        write_ftyp(&mut buf);
+        write_moov(&mut buf, &self.tracks().collect::<Vec<_>>());
+        buf
+    }
+
+    /// Adds sample of track at `track_index`.
+    ///
+    /// Returns finished part, if the sample starts a new one.
+    pub fn push<S: Sample + ?Sized>(
+        &mut self,
+        track_index: usize,
+        sample: &S,
+    ) -> os::Result<Option<Part>> {
+        let Some(state) = self.tracks.get(track_index) else {
+            return Err(err::INVALID_PARAM);
+        };
+        let timescale = state.scale;
+        let pts = sample.pts();
+        let dts = match sample.dts() {
+            dts if dts.is_numeric() => dts,
+            _ => pts,
+        };
+        let dts = dts.sub(self.cfg.base_time);
+        let dts_ts = to_timescale(dts, timescale)?;
+        if dts_ts < 0 {
+            return Err(err::INVALID_PARAM);
+        }
+        let cto = if pts.is_numeric() {
+            to_timescale(pts.sub(self.cfg.base_time), timescale)? - dts_ts
+        } else {
+            0
+        };
+        let cto = i32::try_from(cto).map_err(|_| err::INVALID_PARAM)?;
+        let duration = sample.duration();
+        let duration = if duration.is_numeric() && duration.value > 0 {
+            let d = to_timescale(duration, timescale)?;
+            Some(u32::try_from(d).map_err(|_| err::INVALID_PARAM)?)
+        } else {
+            None
+        };
+        let flags = sample.flags();
+
+        // bytes of pending samples are taken from the front by `cut`,
+        // so the new sample can be appended before the part is cut
+        let state = &mut self.tracks[track_index];
+        let start = state.data.len();
+        let size = sample
+            .append_data(&mut state.data)
+            .and_then(|_| u32::try_from(state.data.len() - start).map_err(|_| err::INVALID_PARAM));
+        let size = match size {
+            Ok(size) => size,
+            Err(e) => {
+                state.data.truncate(start);
+                return Err(e);
+            }
+        };
+
+        let mut res = None;
+        if track_index == self.primary {
+            match self.part_start {
+                None => {
+                    self.part_start = Some(dts);
+                    self.segment_start = Some(dts);
+                    self.part_is_segment_start = true;
+                }
+                Some(part_start) => {
+                    let segment_start = self.segment_start.unwrap_or(part_start);
+                    let new_segment =
+                        flags.is_sync() && dts.sub(segment_start) >= self.cfg.segment_duration;
+                    if new_segment || dts.sub(part_start) >= self.cfg.part_duration {
+                        res = self.cut(Some(dts));
+                        self.part_start = Some(dts);
+                        self.part_is_segment_start = new_segment;
+                        if new_segment {
+                            self.segment_start = Some(dts);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.tracks[track_index].pending.push(PendingSample {
+            dts: dts_ts,
+            cto,
+            duration,
+            size,
+            flags,
+        });
+        Ok(res)
+    }
+
+    /// Writes all pending samples into the last part.
+    pub fn flush(&mut self) -> Option<Part> {
+        let res = self.cut(None);
+        self.part_start = None;
+        self.segment_start = None;
+        res
+    }
+
+    /// Builds fragment of pending samples decoded before `end`, or all of them.
+    fn cut(&mut self, end: Option<cm::Time>) -> Option<Part> {
+        let start = self.part_start?;
+        let mut trafs = Vec::with_capacity(self.tracks.len());
+        for (i, state) in self.tracks.iter_mut().enumerate() {
+            let end_ts = end.and_then(|t| to_timescale(t, state.scale).ok());
+            let n = match end_ts {
+                Some(end_ts) if i != self.primary => state
+                    .pending
+                    .iter()
+                    .position(|s| s.dts >= end_ts)
+                    .unwrap_or(state.pending.len()),
+                _ => state.pending.len(),
+            };
+            if n == 0 {
+                continue;
+            }
+            // the last sample of other tracks has to wait for the next one
+            let next_dts = if i == self.primary {
+                end_ts
+            } else {
+                state.pending.get(n).map(|s| s.dts)
+            };
+            let mut samples: Vec<PendingSample> = state.pending.drain(..n).collect();
+            for j in 0..samples.len() {
+                let next = samples.get(j + 1).map(|s| s.dts).or(next_dts);
+                let d = match (samples[j].duration, next) {
+                    (Some(d), None) => d,
+                    (_, Some(next)) if next > samples[j].dts => {
+                        u32::try_from(next - samples[j].dts).unwrap_or(u32::MAX)
+                    }
+                    (Some(d), _) => d,
+                    (None, _) => state.last_duration,
+                };
+                samples[j].duration = Some(d);
+                state.last_duration = d;
+            }
+            let len: usize = samples.iter().map(|s| s.size as usize).sum();
+            let data: Vec<u8> = state.data.drain(..len).collect();
+            trafs.push((i, samples, data));
+        }
+
+        let primary = trafs.iter().find(|t| t.0 == self.primary)?;
+        let scale = self.tracks[self.primary].scale;
+        let independent = primary.1[0].flags.is_sync();
+        let duration = match end {
+            Some(end) => end.sub(start),
+            None => {
+                let ticks: i64 = primary
+                    .1
+                    .iter()
+                    .map(|s| s.duration.unwrap_or(0) as i64)
+                    .sum();
+                cm::Time::new(ticks, scale)
+            }
+        };
+
+        self.seq += 1;
+        let mut data = Vec::with_capacity(trafs.iter().map(|t| t.2.len()).sum::<usize>() + 1024);
+        write_fragment(
+            &mut data,
+            self.seq,
+            trafs
+                .iter()
+                .map(|(i, samples, data)| (self.tracks[*i].track.id, &samples[..], &data[..])),
+        );
+        Some(Part {
+            data,
+            seq: self.seq,
+            start: start.add(self.cfg.base_time),
+            duration,
+            independent,
+            segment_start: std::mem::replace(&mut self.part_is_segment_start, false),
+        })
+    }
+}
+
+fn to_timescale(time: cm::Time, timescale: i32) -> os::Result<i64> {
+    let res = time.convert_scale(timescale, cm::TimeRoundingMethod::RoundHalfAwayFromZero);
+    if !res.is_numeric() {
+        return Err(err::INVALID_PARAM);
+    }
+    Ok(res.value)
+}
+
+trait Put {
+    fn u8(&mut self, val: u8);
+    fn u16(&mut self, val: u16);
+    fn u32(&mut self, val: u32);
+    fn u64(&mut self, val: u64);
+    fn zeros(&mut self, n: usize);
+}
+
+impl Put for Vec<u8> {
+    fn u8(&mut self, val: u8) {
+        self.push(val);
+    }
+
+    fn u16(&mut self, val: u16) {
+        self.extend_from_slice(&val.to_be_bytes());
+    }
+
+    fn u32(&mut self, val: u32) {
+        self.extend_from_slice(&val.to_be_bytes());
+    }
+
+    fn u64(&mut self, val: u64) {
+        self.extend_from_slice(&val.to_be_bytes());
+    }
+
+    fn zeros(&mut self, n: usize) {
+        self.resize(self.len() + n, 0);
+    }
+}
+
+fn write_box(buf: &mut Vec<u8>, typ: &[u8; 4], f: impl FnOnce(&mut Vec<u8>)) {
+    let start = buf.len();
+    buf.u32(0);
+    buf.extend_from_slice(typ);
+    f(buf);
+    let size = (buf.len() - start) as u32;
+    buf[start..start + 4].copy_from_slice(&size.to_be_bytes());
+}
+
+fn write_full_box(
+    buf: &mut Vec<u8>,
+    typ: &[u8; 4],
+    version: u8,
+    flags: u32,
+    f: impl FnOnce(&mut Vec<u8>),
+) {
+    write_box(buf, typ, |buf| {
+        buf.u32((version as u32) << 24 | flags);
+        f(buf);
+    });
+}
+
+const MATRIX: [u32; 9] = [0x10000, 0, 0, 0, 0x10000, 0, 0, 0, 0x40000000];
+
+fn write_ftyp(buf: &mut Vec<u8>) {
+    write_box(buf, b"ftyp", |buf| {
+        buf.extend_from_slice(b"iso6");
+        buf.u32(0);
+        for brand in [b"iso6", b"cmfc", b"mp41", b"dash"] {
+            buf.extend_from_slice(brand);
+        }
+    });
+}
+
+fn write_moov(buf: &mut Vec<u8>, tracks: &[&Track]) {
+    write_box(buf, b"moov", |buf| {
+        write_full_box(buf, b"mvhd", 0, 0, |buf| {
+            // creation and modification time
+            buf.zeros(8);
+            buf.u32(1000);
+            // duration is in fragments
+            buf.u32(0);
+            buf.u32(0x00010000);
+            buf.u16(0x0100);
+            buf.zeros(10);
+            MATRIX.iter().for_each(|&v| buf.u32(v));
+            buf.zeros(24);
+            buf.u32(tracks.iter().map(|t| t.id).max().unwrap_or(0) + 1);
+        });
+        for track in tracks {
+            write_trak(buf, track);
+        }
+        write_box(buf, b"mvex", |buf| {
+            for track in tracks {
+                write_full_box(buf, b"trex", 0, 0, |buf| {
+                    buf.u32(track.id);
+                    // default_sample_description_index
+                    buf.u32(1);
+                    buf.zeros(12);
+                });
+            }
+        });
+    });
+}
+
+fn write_trak(buf: &mut Vec<u8>, track: &Track) {
+    let (width, height) = match &track.entry {
+        SampleEntry::Avc { width, height, .. } | SampleEntry::Hevc { width, height, .. } => {
+            (*width, *height)
+        }
+        SampleEntry::Aac { .. } => (0, 0),
+    };
+    let video = track.entry.is_video();
+    write_box(buf, b"trak", |buf| {
+        // track enabled, in movie
+        write_full_box(buf, b"tkhd", 0, 3, |buf| {
+            buf.zeros(8);
+            buf.u32(track.id);
+            buf.zeros(4);
+            buf.u32(0);
+            buf.zeros(8);
+            // layer, alternate group
+            buf.zeros(4);
+            buf.u16(if video { 0 } else { 0x0100 });
+            buf.zeros(2);
+            MATRIX.iter().for_each(|&v| buf.u32(v));
+            buf.u32((width as u32) << 16);
+            buf.u32((height as u32) << 16);
+        });
+        write_box(buf, b"mdia", |buf| {
+            write_full_box(buf, b"mdhd", 0, 0, |buf| {
+                buf.zeros(8);
+                buf.u32(track.timescale);
+                buf.u32(0);
+                // 'und' packed ISO-639-2/T
+                buf.u16(0x55c4);
+                buf.zeros(2);
+            });
+            write_full_box(buf, b"hdlr", 0, 0, |buf| {
+                buf.zeros(4);
+                buf.extend_from_slice(if video { b"vide" } else { b"soun" });
+                buf.zeros(12);
+                buf.extend_from_slice(if video {
+                    b"VideoHandler\0"
+                } else {
+                    b"SoundHandler\0"
+                });
+            });
+            write_box(buf, b"minf", |buf| {
+                if video {
+                    write_full_box(buf, b"vmhd", 0, 1, |buf| buf.zeros(8));
+                } else {
+                    write_full_box(buf, b"smhd", 0, 0, |buf| buf.zeros(4));
+                }
+                write_box(buf, b"dinf", |buf| {
+                    write_full_box(buf, b"dref", 0, 0, |buf| {
+                        buf.u32(1);
+                        // media data is in the same file
+                        write_full_box(buf, b"url ", 0, 1, |_| {});
+                    });
+                });
+                write_box(buf, b"stbl", |buf| {
+                    write_full_box(buf, b"stsd", 0, 0, |buf| {
+                        buf.u32(1);
+                        write_sample_entry(buf, track);
+                    });
+                    write_full_box(buf, b"stts", 0, 0, |buf| buf.u32(0));
+                    write_full_box(buf, b"stsc", 0, 0, |buf| buf.u32(0));
+                    write_full_box(buf, b"stsz", 0, 0, |buf| buf.zeros(8));
+                    write_full_box(buf, b"stco", 0, 0, |buf| buf.u32(0));
+                });
+            });
+        });
+    });
+}
+
+fn write_sample_entry(buf: &mut Vec<u8>, track: &Track) {
+    match &track.entry {
+        SampleEntry::Avc {
+            width,
+            height,
+            avcc: cfg,
+        }
+        | SampleEntry::Hevc {
+            width,
+            height,
+            hvcc: cfg,
+        } => {
+            let (typ, cfg_typ) = match track.entry {
+                SampleEntry::Avc { .. } => (b"avc1", b"avcC"),
+                _ => (b"hvc1", b"hvcC"),
+            };
+            write_box(buf, typ, |buf| {
+                buf.zeros(6);
+                // data_reference_index
+                buf.u16(1);
+                buf.zeros(16);
+                buf.u16(*width);
+                buf.u16(*height);
+                // 72 dpi
+                buf.u32(0x00480000);
+                buf.u32(0x00480000);
+                buf.u32(0);
+                // frame_count
+                buf.u16(1);
+                // compressorname
+                buf.zeros(32);
+                buf.u16(0x0018);
+                buf.u16(0xffff);
+                write_box(buf, cfg_typ, |buf| buf.extend_from_slice(cfg));
+            });
+        }
+        SampleEntry::Aac {
+            sample_rate,
+            channels,
+            asc,
+        } => {
+            write_box(buf, b"mp4a", |buf| {
+                buf.zeros(6);
+                buf.u16(1);
+                buf.zeros(8);
+                buf.u16(*channels);
+                buf.u16(16);
+                buf.zeros(4);
+                buf.u32(sample_rate << 16);
+                write_full_box(buf, b"esds", 0, 0, |buf| write_es_desc(buf, track.id, asc));
+            });
+        }
+    }
+}
+
+fn write_desc(buf: &mut Vec<u8>, tag: u8, f: impl FnOnce(&mut Vec<u8>)) {
+    let mut body = Vec::new();
+    f(&mut body);
+    buf.u8(tag);
+    let len = body.len();
+    let n = (1..4).find(|i| len >> (7 * i) == 0).unwrap_or(4);
+    for i in (0..n).rev() {
+        let b = ((len >> (7 * i)) & 0x7f) as u8;
+        buf.u8(if i == 0 { b } else { b | 0x80 });
+    }
+    buf.extend_from_slice(&body);
+}
+
+/// ES_Descriptor (ISO/IEC 14496-1 7.2.6.5) for MPEG-4 audio.
+fn write_es_desc(buf: &mut Vec<u8>, es_id: u32, asc: &[u8]) {
+    write_desc(buf, 0x03, |buf| {
+        buf.u16(es_id as u16);
+        buf.u8(0);
+        write_desc(buf, 0x04, |buf| {
+            // MPEG-4 audio, audio stream
+            buf.u8(0x40);
+            buf.u8(0x15);
+            // buffer size, max and avg bitrates
+            buf.zeros(11);
+            write_desc(buf, 0x05, |buf| buf.extend_from_slice(asc));
+        });
+        write_desc(buf, 0x06, |buf| buf.u8(0x02));
+    });
+}
+
+const TRUN_DATA_OFFSET: u32 = 0x1;
+const TRUN_SAMPLE_DURATION: u32 = 0x100;
+const TRUN_SAMPLE_SIZE: u32 = 0x200;
+const TRUN_SAMPLE_FLAGS: u32 = 0x400;
+const TRUN_SAMPLE_CTO: u32 = 0x800;
+const TFHD_DEFAULT_BASE_IS_MOOF: u32 = 0x20000;
+
+fn write_fragment<'a>(
+    buf: &mut Vec<u8>,
+    seq: u32,
+    trafs: impl Iterator<Item = (u32, &'a [PendingSample], &'a [u8])> + Clone,
+) {
+    let moof_start = buf.len();
+    let mut data_offset_pos = Vec::new();
+    write_box(buf, b"moof", |buf| {
+        write_full_box(buf, b"mfhd", 0, 0, |buf| buf.u32(seq));
+        for (id, samples, _) in trafs.clone() {
+            write_box(buf, b"traf", |buf| {
+                write_full_box(buf, b"tfhd", 0, TFHD_DEFAULT_BASE_IS_MOOF, |buf| {
+                    buf.u32(id)
+                });
+                write_full_box(buf, b"tfdt", 1, 0, |buf| buf.u64(samples[0].dts as u64));
+                let flags = TRUN_DATA_OFFSET
+                    | TRUN_SAMPLE_DURATION
+                    | TRUN_SAMPLE_SIZE
+                    | TRUN_SAMPLE_FLAGS
+                    | TRUN_SAMPLE_CTO;
+                write_full_box(buf, b"trun", 1, flags, |buf| {
+                    buf.u32(samples.len() as u32);
+                    data_offset_pos.push(buf.len());
+                    buf.u32(0);
+                    for s in samples {
+                        buf.u32(s.duration.unwrap_or(0));
+                        buf.u32(s.size);
+                        buf.u32(s.flags.0);
+                        buf.u32(s.cto as u32);
+                    }
+                });
+            });
+        }
+    });
+    let mdat_len: usize = trafs.clone().map(|t| t.2.len()).sum();
+    // offsets are relative to moof start, data follows mdat header
+    let mut offset = buf.len() - moof_start + 8;
+    for ((_, _, data), pos) in trafs.clone().zip(data_offset_pos) {
+        buf[pos..pos + 4].copy_from_slice(&(offset as u32).to_be_bytes());
+        offset += data.len();
+    }
+    buf.u32((mdat_len + 8) as u32);
+    buf.extend_from_slice(b"mdat");
+    for (_, _, data) in trafs {
+        buf.extend_from_slice(data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cm::{
+            self,
+            fmp4::{Part, Sample, SampleFlags, Segmenter, SegmenterCfg, Track},
+        },
+        os,
+    };
+
+    struct TestSample {
+        dts: i64,
+        pts: i64,
+        scale: i32,
+        sync: bool,
+        data: Vec<u8>,
+    }
+
+    impl Sample for TestSample {
+        fn dts(&self) -> cm::Time {
+            cm::Time::new(self.dts, self.scale)
+        }
+
+        fn pts(&self) -> cm::Time {
+            cm::Time::new(self.pts, self.scale)
+        }
+
+        fn duration(&self) -> cm::Time {
+            cm::Time::invalid()
+        }
+
+        fn flags(&self) -> SampleFlags {
+            if self.sync {
+                SampleFlags::SYNC
+            } else {
+                SampleFlags::DEPENDS_ON_OTHERS | SampleFlags::IS_NON_SYNC
+            }
+        }
+
+        fn append_data(&self, dst: &mut Vec<u8>) -> os::Result {
+            dst.extend_from_slice(&self.data);
+            Ok(())
+        }
+    }
+
+    /// Top level boxes (type, body).
+    fn boxes(mut b: &[u8]) -> Vec<([u8; 4], &[u8])> {
+        let mut res = Vec::new();
+        while b.len() >= 8 {
+            let size = u32::from_be_bytes(b[0..4].try_into().unwrap()) as usize;
+            res.push((b[4..8].try_into().unwrap(), &b[8..size]));
+            b = &b[size..];
+        }
+        assert!(b.is_empty());
+        res
+    }
+
+    fn find<'a>(b: &'a [u8], path: &[&[u8; 4]]) -> Option<&'a [u8]> {
+        let (first, rest) = path.split_first()?;
+        let body = boxes(b).into_iter().find(|(t, _)| t == *first)?.1;
+        if rest.is_empty() {
+            Some(body)
+        } else {
+            find(body, rest)
+        }
+    }
+
+    fn avc_track() -> Track {
+        // 1920x1080 High@4.0
+        let sps = [
+            0x67, 0x64, 0x00, 0x28, 0xac, 0xd9, 0x40, 0x78, 0x02, 0x27, 0xe5, 0xc0, 0x5b, 0x80,
+            0x80, 0x80, 0xa0, 0x00, 0x00, 0x03, 0x00, 0x20, 0x00, 0x00, 0x0f, 0x10, 0x80,
+        ];
+        let pps = [0x68, 0xeb, 0xe3, 0xcb, 0x22, 0xc0];
+        let cfg = cm::AvcCfg::with_param_sets(&[&sps], &[&pps], 4).unwrap();
+        Track::avc(1, &cfg).unwrap()
+    }
+
+    #[test]
+    fn init_segment() {
+        let tracks = vec![avc_track(), Track::aac(2, 48_000, 2, &[0x11, 0x90])];
+        let seg = Segmenter::new(tracks, Default::default()).unwrap();
+        let init = seg.init_segment();
+        let top: Vec<_> = boxes(&init).into_iter().map(|b| b.0).collect();
+        assert_eq!(top, [*b"ftyp", *b"moov"]);
+
+        let tkhd = find(&init, &[b"moov", b"trak", b"tkhd"]).unwrap();
+        assert_eq!(&tkhd[76..84], &[0x07, 0x80, 0, 0, 0x04, 0x38, 0, 0]);
+        let stsd = find(
+            &init,
+            &[b"moov", b"trak", b"mdia", b"minf", b"stbl", b"stsd"],
+        )
+        .unwrap();
+        let avc1 = find(&stsd[8..], &[b"avc1"]).unwrap();
+        assert_eq!(&avc1[24..28], &[0x07, 0x80, 0x04, 0x38]);
+        let avcc = find(&avc1[78..], &[b"avcC"]).unwrap();
+        assert_eq!(avcc[1], 0x64);
+
+        let moov = find(&init, &[b"moov"]).unwrap();
+        let traks: Vec<_> = boxes(moov)
+            .into_iter()
+            .filter(|b| &b.0 == b"trak")
+            .collect();
+        let stsd = find(traks[1].1, &[b"mdia", b"minf", b"stbl", b"stsd"]).unwrap();
+        let mp4a = find(&stsd[8..], &[b"mp4a"]).unwrap();
+        assert_eq!(&mp4a[16..18], &[0, 2]);
+        assert_eq!(&mp4a[24..28], &[0xbb, 0x80, 0, 0]);
+        let esds = find(&mp4a[28..], &[b"esds"]).unwrap();
+        assert_eq!(
+            esds,
+            [
+                0, 0, 0, 0, 0x03, 0x19, 0x00, 0x02, 0x00, 0x04, 0x11, 0x40, 0x15, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0x05, 0x02, 0x11, 0x90, 0x06, 0x01, 0x02
+            ]
+        );
+        let trex = find(moov, &[b"mvex", b"trex"]).unwrap();
+        assert_eq!(&trex[4..8], &[0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn fragments() {
+        let tracks = vec![Track::aac(2, 48_000, 2, &[0x11, 0x90]), avc_track()];
+        let cfg = SegmenterCfg {
+            part_duration: cm::Time::new(1, 2),
+            segment_duration: cm::Time::new(2, 1),
+            ..Default::default()
+        };
+        let mut seg = Segmenter::new(tracks, cfg).unwrap();
+        let mut parts: Vec<Part> = Vec::new();
+
+        // 3 seconds of 30 fps video with key frame every second and one B frame delay,
+        // audio frames of 1024 samples.
+        let mut audio = 0;
+        for i in 0..90i64 {
+            while audio * 1024 * 30 <= i * 48_000 {
+                let s = TestSample {
+                    dts: audio * 1024,
+                    pts: audio * 1024,
+                    scale: 48_000,
+                    sync: true,
+                    data: vec![0xa0; 10],
+                };
+                parts.extend(seg.push(0, &s).unwrap());
+                audio += 1;
+            }
+            let s = TestSample {
+                dts: i * 3000,
+                pts: i * 3000 + 3000,
+                scale: 90_000,
+                sync: i % 30 == 0,
+                data: vec![i as u8; 5],
+            };
+            parts.extend(seg.push(1, &s).unwrap());
+        }
+        parts.extend(seg.flush());
+
+        assert_eq!(parts.len(), 6);
+        let starts: Vec<_> = parts.iter().map(|p| p.segment_start).collect();
+        assert_eq!(starts, [true, false, false, false, true, false]);
+        let independent: Vec<_> = parts.iter().map(|p| p.independent).collect();
+        assert_eq!(independent, [true, false, true, false, true, false]);
+        assert_eq!(parts[0].duration.as_secs(), 0.5);
+        assert_eq!(parts[5].start.as_secs(), 2.5);
+        assert_eq!(parts[5].duration.as_secs(), 0.5);
+        assert_eq!(parts[5].seq, 6);
+
+        let p = &parts[1];
+        let top: Vec<_> = boxes(&p.data).into_iter().map(|b| b.0).collect();
+        assert_eq!(top, [*b"moof", *b"mdat"]);
+        let moof = find(&p.data, &[b"moof"]).unwrap();
+        let trafs: Vec<_> = boxes(moof)
+            .into_iter()
+            .filter(|b| &b.0 == b"traf")
+            .collect();
+        assert_eq!(trafs.len(), 2);
+
+        // audio
+        let tfhd = find(trafs[0].1, &[b"tfhd"]).unwrap();
+        assert_eq!(&tfhd[4..8], &[0, 0, 0, 2]);
+        let tfdt = find(trafs[0].1, &[b"tfdt"]).unwrap();
+        let audio_start = u64::from_be_bytes(tfdt[4..12].try_into().unwrap());
+        assert_eq!(audio_start, 24 * 1024);
+
+        // video
+        let tfdt = find(trafs[1].1, &[b"tfdt"]).unwrap();
+        assert_eq!(u64::from_be_bytes(tfdt[4..12].try_into().unwrap()), 45_000);
+        let trun = find(trafs[1].1, &[b"trun"]).unwrap();
+        assert_eq!(trun[0], 1);
+        assert_eq!(u32::from_be_bytes(trun[4..8].try_into().unwrap()), 15);
+        let data_offset = u32::from_be_bytes(trun[8..12].try_into().unwrap()) as usize;
+        let first = &trun[12..28];
+        assert_eq!(&first[0..4], &3000u32.to_be_bytes());
+        assert_eq!(&first[4..8], &5u32.to_be_bytes());
+        assert_eq!(
+            &first[8..12],
+            &(SampleFlags::DEPENDS_ON_OTHERS | SampleFlags::IS_NON_SYNC)
+                .0
+                .to_be_bytes()
+        );
+        assert_eq!(&first[12..16], &3000u32.to_be_bytes());
+        assert_eq!(&p.data[data_offset..data_offset + 5], &[15; 5]);
+
+        // audio data precedes video in mdat
+        let trun = find(trafs[0].1, &[b"trun"]).unwrap();
+        let data_offset = u32::from_be_bytes(trun[8..12].try_into().unwrap()) as usize;
+        assert_eq!(p.data[data_offset], 0xa0);
+        assert_eq!(&p.data[data_offset - 4..data_offset], b"mdat");
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Segmenter::new(vec![], Default::default()).is_err());
+        let tracks = vec![Track::aac(1, 48_000, 2, &[]), Track::aac(1, 44_100, 2, &[])];
+        assert!(Segmenter::new(tracks, Default::default()).is_err());
+
+        let mut seg =
+            Segmenter::new(vec![Track::aac(1, 48_000, 2, &[])], Default::default()).unwrap();
+        let s = TestSample {
+            dts: -1,
+            pts: -1,
+            scale: 48_000,
+            sync: true,
+            data: vec![],
+        };
+        assert!(seg.push(0, &s).is_err());
+        assert!(seg.push(1, &s).is_err());
+        assert!(seg.flush().is_none());
+
+        let tracks = vec![Track::aac(1, u32::MAX, 2, &[])];
+        assert!(Segmenter::new(tracks, Default::default()).is_err());
+        let tracks = vec![Track::aac(1, 96_000, 2, &[0x10, 0x10])];
+        assert!(Segmenter::new(tracks, Default::default()).is_err());
+        let tracks = vec![Track::aac(1, 65_535, 2, &[])];
+        assert!(Segmenter::new(tracks, Default::default()).is_ok());
+    }
+
+    /// Writes part of its data and fails.
+    struct BrokenSample(TestSample);
+
+    impl Sample for BrokenSample {
+        fn dts(&self) -> cm::Time {
+            self.0.dts()
+        }
+
+        fn pts(&self) -> cm::Time {
+            self.0.pts()
+        }
+
+        fn duration(&self) -> cm::Time {
+            self.0.duration()
+        }
+
+        fn flags(&self) -> SampleFlags {
+            self.0.flags()
+        }
+
+        fn append_data(&self, dst: &mut Vec<u8>) -> os::Result {
+            dst.extend_from_slice(&self.0.data);
+            Err(cm::format_desc_err::INVALID_PARAM)
+        }
+    }
+
+    #[test]
+    fn failed_push() {
+        let cfg = SegmenterCfg {
+            part_duration: cm::Time::new(1, 1),
+            ..Default::default()
+        };
+        let mut seg = Segmenter::new(vec![Track::aac(1, 1000, 2, &[])], cfg).unwrap();
+        let sample = |dts: i64, pts: i64, data: u8| TestSample {
+            dts,
+            pts,
+            scale: 1000,
+            sync: true,
+            data: vec![data; 3],
+        };
+        assert!(seg.push(0, &sample(0, 0, 1)).unwrap().is_none());
+        assert!(seg.push(0, &sample(500, 500, 2)).unwrap().is_none());
+
+        // would cut the part, but nothing changes on error
+        let broken = BrokenSample(sample(1000, 1000, 0xff));
+        assert!(seg.push(0, &broken).is_err());
+        assert!(seg.push(0, &sample(1000, i64::MAX / 1000, 0xff)).is_err());
+
+        let part = seg.push(0, &sample(1000, 1000, 3)).unwrap().unwrap();
+        assert_eq!(part.seq, 1);
+        assert_eq!(part.duration.as_secs(), 1.0);
+        let mdat = find(&part.data, &[b"mdat"]).unwrap();
+        assert_eq!(mdat, [1, 1, 1, 2, 2, 2]);
+
+        let part = seg.flush().unwrap();
+        let mdat = find(&part.data, &[b"mdat"]).unwrap();
+        assert_eq!(mdat, [3, 3, 3]);
+    }
+}
@@ -1,20 +1,39 @@
+#[cfg(target_vendor = "apple")]
 use std::mem::transmute;
 
+use crate::FourCharCode;
+#[cfg(target_vendor = "apple")]
 use crate::{
-    FourCharCode, api, arc,
+    api, arc,
     cf::{self, Allocator},
     cm, define_cf_type, os,
 };
 
-#[cfg(feature = "cv")]
+#[cfg(all(target_vendor = "apple", feature = "cv"))]
 use crate::cv;
 
-#[cfg(feature = "cat")]
+#[cfg(all(target_vendor = "apple", feature = "cat"))]
 use crate::cat;
 
-#[cfg(feature = "cat")]
+#[cfg(all(target_vendor = "apple", feature = "cat"))]
 use std::ffi::c_void;
 
+pub mod err {
+    use crate::os::Error;
+
+    /// Invalid parameter.
+    #[doc(alias = "kCMFormatDescriptionError_InvalidParameter")]
+    pub const INVALID_PARAM: Error = Error::new_unchecked(-12710);
+
+    /// Returned when an allocation fails.
+    #[doc(alias = "kCMFormatDescriptionError_AllocationFailed")]
+    pub const ALLOC_FAILED: Error = Error::new_unchecked(-12711);
+
+    /// Returned when the cm::FormatDesc does not carry such a value.
+    #[doc(alias = "kCMFormatDescriptionError_ValueNotAvailable")]
+    pub const VALUE_NOT_AVAILABLE: Error = Error::new_unchecked(-12718);
+}
+
 #[doc(alias = "CMPixelFormatType")]
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 #[repr(transparent)]
@@ -186,13 +205,16 @@ impl std::fmt::Debug for VideoCodec {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 define_cf_type!(
     #[doc(alias = "CMFormatDescriptionRef")]
     FormatDesc(cf::Type)
 );
 
+#[cfg(target_vendor = "apple")]
 unsafe impl Send for FormatDesc {}
 
+#[cfg(target_vendor = "apple")]
 impl FormatDesc {
     #[doc(alias = "CMFormatDescriptionGetTypeID")]
     #[inline]
@@ -264,6 +286,22 @@ impl FormatDesc {
         self.video_cfg(cf::str!(c"hvcC"))
     }
 
+    /// Parsed `avcC` atom.
+    pub fn avc_cfg(&self) -> os::Result<cm::AvcCfg> {
+        let Some(avcc) = self.avcc() else {
+            return Err(err::VALUE_NOT_AVAILABLE);
+        };
+        cm::AvcCfg::from_bytes(&avcc)
+    }
+
+    /// Parsed `hvcC` atom.
+    pub fn hevc_cfg(&self) -> os::Result<cm::HevcCfg> {
+        let Some(hvcc) = self.hvcc() else {
+            return Err(err::VALUE_NOT_AVAILABLE);
+        };
+        cm::HevcCfg::from_bytes(&hvcc)
+    }
+
     pub fn verbatim_sample_desc(&self) -> Option<&cf::Data> {
         unsafe {
             let key = FormatDescExtKey::verbatim_sample_desc();
@@ -319,8 +357,10 @@ impl FormatDesc {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 pub type VideoFormatDesc = FormatDesc;
 
+#[cfg(target_vendor = "apple")]
 impl VideoFormatDesc {
     /// ```
     /// use cidre::cm;
@@ -447,6 +487,42 @@ impl VideoFormatDesc {
         }
     }
 
+    /// Creates H.264 format description from parameter sets of decoder configuration record.
+    #[doc(alias = "CMVideoFormatDescriptionCreateFromH264ParameterSets")]
+    pub fn with_avc_cfg(cfg: &cm::AvcCfg) -> os::Result<arc::R<VideoFormatDesc>> {
+        let (pointers, sizes): (Vec<_>, Vec<_>) =
+            cfg.param_sets().map(|ps| (ps.as_ptr(), ps.len())).unzip();
+        unsafe {
+            os::result_unchecked(|res| {
+                CMVideoFormatDescriptionCreateFromH264ParameterSets(
+                    None,
+                    pointers.len(),
+                    pointers.as_ptr(),
+                    sizes.as_ptr(),
+                    cfg.nal_unit_len as i32,
+                    res,
+                )
+            })
+        }
+    }
+
+    /// Creates HEVC format description from parameter sets of decoder configuration record.
+    #[doc(alias = "CMVideoFormatDescriptionCreateFromHEVCParameterSets")]
+    pub fn with_hevc_cfg(
+        cfg: &cm::HevcCfg,
+        extensions: Option<&cf::DictionaryOf<FormatDescExtKey, cf::Type>>,
+    ) -> os::Result<arc::R<VideoFormatDesc>> {
+        let (pointers, sizes): (Vec<_>, Vec<_>) =
+            cfg.param_sets().map(|ps| (ps.as_ptr(), ps.len())).unzip();
+        Self::with_hevc_param_sets(
+            pointers.len(),
+            &pointers,
+            &sizes,
+            cfg.nal_unit_len as i32,
+            extensions,
+        )
+    }
+
     #[doc(alias = "CMVideoFormatDescriptionCreateFromHEVCParameterSets")]
     #[inline]
     pub fn with_hevc_param_sets(
@@ -602,9 +678,10 @@ impl VideoFormatDesc {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 pub type AudioFormatDesc = FormatDesc;
 
-#[cfg(feature = "cat")]
+#[cfg(all(target_vendor = "apple", feature = "cat"))]
 impl AudioFormatDesc {
     pub fn with_asbd(asbd: &cat::audio::StreamBasicDesc) -> os::Result<arc::R<Self>> {
         unsafe {
@@ -643,14 +720,90 @@ impl AudioFormatDesc {
     pub fn stream_basic_desc(&self) -> Option<&cat::audio::StreamBasicDesc> {
         unsafe { CMAudioFormatDescriptionGetStreamBasicDescription(self) }
     }
+
+    /// Codec specific magic cookie, `esds` descriptor for AAC.
+    #[doc(alias = "CMAudioFormatDescriptionGetMagicCookie")]
+    pub fn magic_cookie(&self) -> Option<&[u8]> {
+        let mut size = 0;
+        unsafe {
+            let ptr = CMAudioFormatDescriptionGetMagicCookie(self, &mut size);
+            if ptr.is_null() {
+                None
+            } else {
+                Some(std::slice::from_raw_parts(ptr as *const u8, size))
+            }
+        }
+    }
 }
 
+#[cfg(target_vendor = "apple")]
+pub type TimeCodeFormatDesc = FormatDesc;
+
+#[cfg(target_vendor = "apple")]
+impl TimeCodeFormatDesc {
+    /// ```
+    /// use cidre::cm;
+    ///
+    /// let rate = cm::timecode::Rate::_29_97_DROP;
+    /// let desc = cm::TimeCodeFormatDesc::with_time_code(
+    ///     cm::timecode::FormatType::TIME_CODE_32,
+    ///     rate.frame_duration(),
+    ///     rate.frame_quanta,
+    ///     rate.flags(),
+    ///     None,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(desc.media_type(), cm::MediaType::TIME_CODE);
+    /// assert_eq!(cm::timecode::Rate::with_format_desc(&desc), rate);
+    /// ```
+    #[doc(alias = "CMTimeCodeFormatDescriptionCreate")]
+    pub fn with_time_code(
+        format_type: cm::timecode::FormatType,
+        frame_duration: cm::Time,
+        frame_quanta: u32,
+        flags: cm::timecode::Flags,
+        extensions: Option<&cf::DictionaryOf<FormatDescExtKey, cf::Type>>,
+    ) -> os::Result<arc::R<Self>> {
+        unsafe {
+            os::result_unchecked(|res| {
+                CMTimeCodeFormatDescriptionCreate(
+                    None,
+                    format_type,
+                    frame_duration,
+                    frame_quanta,
+                    flags,
+                    extensions,
+                    res,
+                )
+                .result()
+            })
+        }
+    }
+
+    #[doc(alias = "CMTimeCodeFormatDescriptionGetFrameDuration")]
+    pub fn time_code_frame_duration(&self) -> cm::Time {
+        unsafe { CMTimeCodeFormatDescriptionGetFrameDuration(self) }
+    }
+
+    #[doc(alias = "CMTimeCodeFormatDescriptionGetFrameQuanta")]
+    pub fn time_code_frame_quanta(&self) -> u32 {
+        unsafe { CMTimeCodeFormatDescriptionGetFrameQuanta(self) }
+    }
+
+    #[doc(alias = "CMTimeCodeFormatDescriptionGetTimeCodeFlags")]
+    pub fn time_code_flags(&self) -> cm::timecode::Flags {
+        unsafe { CMTimeCodeFormatDescriptionGetTimeCodeFlags(self) }
+    }
+}
+
+#[cfg(target_vendor = "apple")]
 define_cf_type!(
     #[doc(alias = "CMFormatDescription.Extensions.Key")]
     #[doc(alias = "CMFormatDescriptionExtension")]
     FormatDescExtKey(cf::String)
 );
 
+#[cfg(target_vendor = "apple")]
 impl FormatDescExtKey {
     /// [`cf::String`]
     #[doc(alias = "kCMFormatDescriptionExtension_FormatName")]
@@ -924,11 +1077,13 @@ impl FormatDescExtKey {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 define_cf_type!(
     #[doc(alias = "CMFormatDescriptionLogTransferFunction")]
     LogTransferFn(cf::String)
 );
 
+#[cfg(target_vendor = "apple")]
 impl LogTransferFn {
     #[doc(alias = "kCMFormatDescriptionLogTransferFunction_AppleLog")]
     #[api::available(macos = 14.2, ios = 17.2, tvos = 17.2, watchos = 10.2, visionos = 1.1)]
@@ -937,6 +1092,7 @@ impl LogTransferFn {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 #[doc(alias = "CMTaggedBufferGroupFormatDescriptionRef")]
 pub type TaggedBufGroupFormatDesc = FormatDesc;
 
@@ -951,6 +1107,7 @@ pub enum TaggedBufGroupFormatType {
     TaggedBufGroup = u32::from_be_bytes(*b"tbgr"),
 }
 
+#[cfg(target_vendor = "apple")]
 #[doc(alias = "CMMuxedFormatDescriptionRef")]
 pub type MuxedFormatDesc = FormatDesc;
 
@@ -990,6 +1147,7 @@ impl std::fmt::Debug for MuxedStreamType {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 #[link(name = "CoreMedia", kind = "framework")]
 #[api::weak]
 unsafe extern "C-unwind" {
@@ -1089,6 +1247,29 @@ unsafe extern "C-unwind" {
         desc: &AudioFormatDesc,
     ) -> Option<&cat::audio::StreamBasicDesc>;
 
+    fn CMAudioFormatDescriptionGetMagicCookie(
+        desc: &AudioFormatDesc,
+        size_out: *mut usize,
+    ) -> *const c_void;
+
+    fn CMTimeCodeFormatDescriptionCreate(
+        allocator: Option<&cf::Allocator>,
+        time_code_format_type: cm::timecode::FormatType,
+        frame_duration: cm::Time,
+        frame_quanta: u32,
+        flags: cm::timecode::Flags,
+        extensions: Option<&cf::DictionaryOf<FormatDescExtKey, cf::Type>>,
+        format_description_out: *mut Option<arc::R<TimeCodeFormatDesc>>,
+    ) -> os::Status;
+
+    fn CMTimeCodeFormatDescriptionGetFrameDuration(desc: &TimeCodeFormatDesc) -> cm::Time;
+
+    fn CMTimeCodeFormatDescriptionGetFrameQuanta(desc: &TimeCodeFormatDesc) -> u32;
+
+    fn CMTimeCodeFormatDescriptionGetTimeCodeFlags(
+        desc: &TimeCodeFormatDesc,
+    ) -> cm::timecode::Flags;
+
     fn CMFormatDescriptionCreate(
         allocator: Option<&cf::Allocator>,
         media_type: MediaType,
@@ -1145,7 +1326,7 @@ unsafe extern "C-unwind" {
     -> &'static cf::ArrayOf<FormatDescExtKey>;
 }
 
-#[cfg(test)]
+#[cfg(all(test, target_vendor = "apple"))]
 mod tests {
     use crate::cm;
 
@@ -0,0 +1,678 @@
+//! HDR static and dynamic metadata in the byte layouts CoreMedia and CoreVideo use
+//! for format description extensions and sample attachments.
+
+use crate::{
+    bits::{BitReader, BitWriter, ByteReader},
+    cm::format_desc_err as err,
+    os,
+};
+
+#[cfg(target_vendor = "apple")]
+use crate::{arc, cf};
+
+/// CIE 1931 chromaticity coordinate in increments of 0.00002.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Chromaticity {
+    pub x: u16,
+    pub y: u16,
+}
+
+impl Chromaticity {
+    pub const D65: Self = Self::new(15635, 16450);
+
+    #[inline]
+    pub const fn new(x: u16, y: u16) -> Self {
+        Self { x, y }
+    }
+
+    pub fn with_xy(x: f64, y: f64) -> Self {
+        Self {
+            x: (x * 50_000.0).round() as u16,
+            y: (y * 50_000.0).round() as u16,
+        }
+    }
+
+    #[inline]
+    pub fn xy(&self) -> (f64, f64) {
+        (self.x as f64 / 50_000.0, self.y as f64 / 50_000.0)
+    }
+}
+
+/// SMPTE ST 2086 mastering display colour volume.
+///
+/// 24 bytes big-endian, same as mastering display colour volume SEI message and `mdcv` box.
+#[doc(alias = "kCVImageBufferMasteringDisplayColorVolumeKey")]
+#[doc(alias = "kCMFormatDescriptionExtension_MasteringDisplayColorVolume")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MasteringDisplay {
+    /// Green, blue and red primaries.
+    pub primaries: [Chromaticity; 3],
+    pub white_point: Chromaticity,
+
+    /// In units of 0.0001 cd/m².
+    pub max_luminance: u32,
+
+    /// In units of 0.0001 cd/m².
+    pub min_luminance: u32,
+}
+
+impl MasteringDisplay {
+    pub const LEN: usize = 24;
+
+    /// Green, blue and red primaries of Display P3.
+    pub const P3_PRIMARIES: [Chromaticity; 3] = [
+        Chromaticity::new(13250, 34500),
+        Chromaticity::new(7500, 3000),
+        Chromaticity::new(34000, 16000),
+    ];
+
+    /// Green, blue and red primaries of ITU-R BT.2020.
+    pub const BT2020_PRIMARIES: [Chromaticity; 3] = [
+        Chromaticity::new(8500, 39850),
+        Chromaticity::new(6550, 2300),
+        Chromaticity::new(35400, 14600),
+    ];
+
+    pub fn with_nits(
+        primaries: [Chromaticity; 3],
+        white_point: Chromaticity,
+        max_nits: f64,
+        min_nits: f64,
+    ) -> Self {
+        Self {
+            primaries,
+            white_point,
+            max_luminance: (max_nits * 10_000.0).round() as u32,
+            min_luminance: (min_nits * 10_000.0).round() as u32,
+        }
+    }
+
+    #[inline]
+    pub fn max_nits(&self) -> f64 {
+        self.max_luminance as f64 / 10_000.0
+    }
+
+    #[inline]
+    pub fn min_nits(&self) -> f64 {
+        self.min_luminance as f64 / 10_000.0
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> os::Result<Self> {
+        if bytes.len() != Self::LEN {
+            return Err(err::INVALID_PARAM);
+        }
+        let mut r = ByteReader::new(bytes);
+        let mut chromaticity = || Some(Chromaticity::new(r.u16()?, r.u16()?));
+        let (Some(g), Some(b), Some(red), Some(white_point)) = (
+            chromaticity(),
+            chromaticity(),
+            chromaticity(),
+            chromaticity(),
+        ) else {
+            return Err(err::INVALID_PARAM);
+        };
+        let (Some(max_luminance), Some(min_luminance)) = (r.u32(), r.u32()) else {
+            return Err(err::INVALID_PARAM);
+        };
+        Ok(Self {
+            primaries: [g, b, red],
+            white_point,
+            max_luminance,
+            min_luminance,
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut res = [0u8; Self::LEN];
+        let chromaticities = self.primaries.iter().chain([&self.white_point]);
+        for (dst, c) in res.chunks_exact_mut(4).zip(chromaticities) {
+            dst[..2].copy_from_slice(&c.x.to_be_bytes());
+            dst[2..].copy_from_slice(&c.y.to_be_bytes());
+        }
+        res[16..20].copy_from_slice(&self.max_luminance.to_be_bytes());
+        res[20..].copy_from_slice(&self.min_luminance.to_be_bytes());
+        res
+    }
+
+    /// Value for format description extension or pixel buffer attachment.
+    #[cfg(target_vendor = "apple")]
+    pub fn to_cf_data(&self) -> Option<arc::R<cf::Data>> {
+        cf::Data::from_slice(&self.to_bytes())
+    }
+}
+
+/// CTA-861.3 content light level, 4 bytes big-endian, same as `clli` box.
+#[doc(alias = "kCVImageBufferContentLightLevelInfoKey")]
+#[doc(alias = "kCMFormatDescriptionExtension_ContentLightLevelInfo")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContentLightLevel {
+    /// MaxCLL in cd/m².
+    pub max_cll: u16,
+
+    /// MaxFALL in cd/m².
+    pub max_fall: u16,
+}
+
+impl ContentLightLevel {
+    pub const LEN: usize = 4;
+
+    #[inline]
+    pub const fn new(max_cll: u16, max_fall: u16) -> Self {
+        Self { max_cll, max_fall }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> os::Result<Self> {
+        match bytes {
+            &[a, b, c, d] => Ok(Self::new(
+                u16::from_be_bytes([a, b]),
+                u16::from_be_bytes([c, d]),
+            )),
+            _ => Err(err::INVALID_PARAM),
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let [a, b] = self.max_cll.to_be_bytes();
+        let [c, d] = self.max_fall.to_be_bytes();
+        [a, b, c, d]
+    }
+
+    #[cfg(target_vendor = "apple")]
+    pub fn to_cf_data(&self) -> Option<arc::R<cf::Data>> {
+        cf::Data::from_slice(&self.to_bytes())
+    }
+}
+
+/// Luminance matrix of targeted or mastering display, 4 bit values.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct PeakLuminance {
+    pub rows: u8,
+    pub cols: u8,
+
+    /// `rows * cols` values, row by row.
+    pub values: Vec<u8>,
+}
+
+/// Elliptical processing window, see ST 2094-40 5.3.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowGeometry {
+    pub upper_left_corner: (u16, u16),
+    pub lower_right_corner: (u16, u16),
+    pub center_of_ellipse: (u16, u16),
+    pub rotation_angle: u8,
+    pub semimajor_axis_internal_ellipse: u16,
+    pub semimajor_axis_external_ellipse: u16,
+    pub semiminor_axis_external_ellipse: u16,
+
+    /// `false` is weighted averaging, `true` is layering.
+    pub overlap_process_option: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaxRgbPercentile {
+    pub percentage: u8,
+    pub percentile: u32,
+}
+
+/// Bezier curve tone mapping, 12 bit knee point and 10 bit anchors.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ToneMapping {
+    pub knee_point_x: u16,
+    pub knee_point_y: u16,
+    pub bezier_curve_anchors: Vec<u16>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Hdr10PlusWindow {
+    /// Not coded for the first window which is the whole frame.
+    pub geometry: Option<WindowGeometry>,
+
+    /// 17 bit max of R, G and B in units of 0.00001.
+    pub maxscl: [u32; 3],
+    pub average_maxrgb: u32,
+    pub distribution_maxrgb: Vec<MaxRgbPercentile>,
+    pub fraction_bright_pixels: u16,
+    pub tone_mapping: Option<ToneMapping>,
+
+    /// 6 bit color saturation weight.
+    pub color_saturation_weight: Option<u8>,
+}
+
+/// SMPTE ST 2094-40 (HDR10+) dynamic metadata of one frame.
+///
+/// Bytes are User Data Registered ITU-T T.35 SEI payload starting with
+/// `itu_t_t35_country_code` 0xb5 as `hdr10plus_per_frame_data` sample attachment expects.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Hdr10Plus {
+    pub application_version: u8,
+
+    /// One to three windows.
+    pub windows: Vec<Hdr10PlusWindow>,
+
+    /// 27 bit value in cd/m².
+    pub targeted_system_display_max_luminance: u32,
+    pub targeted_system_display_actual_peak_luminance: Option<PeakLuminance>,
+    pub mastering_display_actual_peak_luminance: Option<PeakLuminance>,
+}
+
+impl Default for Hdr10Plus {
+    fn default() -> Self {
+        Self {
+            application_version: 1,
+            windows: vec![Default::default()],
+            targeted_system_display_max_luminance: 0,
+            targeted_system_display_actual_peak_luminance: None,
+            mastering_display_actual_peak_luminance: None,
+        }
+    }
+}
+
+impl Hdr10Plus {
+    /// `itu_t_t35_country_code`, `itu_t_t35_terminal_provider_code`,
+    /// `itu_t_t35_terminal_provider_oriented_code` and `application_identifier`.
+    const HEADER: [u8; 6] = [0xb5, 0x00, 0x3c, 0x00, 0x01, 0x04];
+
+    pub fn from_bytes(bytes: &[u8]) -> os::Result<Self> {
+        let Some(payload) = bytes.strip_prefix(&Self::HEADER) else {
+            return Err(err::INVALID_PARAM);
+        };
+        Self::read(&mut BitReader::new(payload)).ok_or(err::INVALID_PARAM)
+    }
+
+    fn read(r: &mut BitReader) -> Option<Self> {
+        let application_version = r.u8(8)?;
+        let num_windows = r.u8(2)? as usize;
+        if num_windows == 0 {
+            return None;
+        }
+        let mut windows = vec![Hdr10PlusWindow::default(); num_windows];
+        for w in &mut windows[1..] {
+            let mut u16 = || Some(r.bits(16)? as u16);
+            let upper_left_corner = (u16()?, u16()?);
+            let lower_right_corner = (u16()?, u16()?);
+            let center_of_ellipse = (u16()?, u16()?);
+            w.geometry = Some(WindowGeometry {
+                upper_left_corner,
+                lower_right_corner,
+                center_of_ellipse,
+                rotation_angle: r.u8(8)?,
+                semimajor_axis_internal_ellipse: r.bits(16)? as u16,
+                semimajor_axis_external_ellipse: r.bits(16)? as u16,
+                semiminor_axis_external_ellipse: r.bits(16)? as u16,
+                overlap_process_option: r.bit()?,
+            });
+        }
+        let targeted_system_display_max_luminance = r.bits(27)?;
+        let targeted_system_display_actual_peak_luminance = read_peak_luminance(r)?;
+        for w in &mut windows {
+            for v in &mut w.maxscl {
+                *v = r.bits(17)?;
+            }
+            w.average_maxrgb = r.bits(17)?;
+            let num = r.u8(4)?;
+            for _ in 0..num {
+                w.distribution_maxrgb.push(MaxRgbPercentile {
+                    percentage: r.u8(7)?,
+                    percentile: r.bits(17)?,
+                });
+            }
+            w.fraction_bright_pixels = r.bits(10)? as u16;
+        }
+        let mastering_display_actual_peak_luminance = read_peak_luminance(r)?;
+        for w in &mut windows {
+            if r.bit()? {
+                let knee_point_x = r.bits(12)? as u16;
+                let knee_point_y = r.bits(12)? as u16;
+                let num = r.u8(4)?;
+                let mut bezier_curve_anchors = Vec::with_capacity(num as usize);
+                for _ in 0..num {
+                    bezier_curve_anchors.push(r.bits(10)? as u16);
+                }
+                w.tone_mapping = Some(ToneMapping {
+                    knee_point_x,
+                    knee_point_y,
+                    bezier_curve_anchors,
+                });
+            }
+            if r.bit()? {
+                w.color_saturation_weight = Some(r.u8(6)?);
+            }
+        }
+        Some(Self {
+            application_version,
+            windows,
+            targeted_system_display_max_luminance,
+            targeted_system_display_actual_peak_luminance,
+            mastering_display_actual_peak_luminance,
+        })
+    }
+
+    /// Fails if number of windows, list lengths or field values don't fit their bit widths.
+    pub fn write_to(&self, buf: &mut Vec<u8>) -> os::Result {
+        if !self.is_valid() {
+            return Err(err::INVALID_PARAM);
+        }
+        buf.extend_from_slice(&Self::HEADER);
+        let mut w = BitWriter::new(buf);
+        w.bits(8, self.application_version as u32);
+        w.bits(2, self.windows.len() as u32);
+        for win in &self.windows[1..] {
+            let g = win.geometry.unwrap_or_default();
+            for v in [
+                g.upper_left_corner.0,
+                g.upper_left_corner.1,
+                g.lower_right_corner.0,
+                g.lower_right_corner.1,
+                g.center_of_ellipse.0,
+                g.center_of_ellipse.1,
+            ] {
+                w.bits(16, v as u32);
+            }
+            w.bits(8, g.rotation_angle as u32);
+            w.bits(16, g.semimajor_axis_internal_ellipse as u32);
+            w.bits(16, g.semimajor_axis_external_ellipse as u32);
+            w.bits(16, g.semiminor_axis_external_ellipse as u32);
+            w.bit(g.overlap_process_option);
+        }
+        w.bits(27, self.targeted_system_display_max_luminance);
+        write_peak_luminance(&mut w, &self.targeted_system_display_actual_peak_luminance);
+        for win in &self.windows {
+            for v in win.maxscl {
+                w.bits(17, v);
+            }
+            w.bits(17, win.average_maxrgb);
+            w.bits(4, win.distribution_maxrgb.len() as u32);
+            for p in &win.distribution_maxrgb {
+                w.bits(7, p.percentage as u32);
+                w.bits(17, p.percentile);
+            }
+            w.bits(10, win.fraction_bright_pixels as u32);
+        }
+        write_peak_luminance(&mut w, &self.mastering_display_actual_peak_luminance);
+        for win in &self.windows {
+            w.bit(win.tone_mapping.is_some());
+            if let Some(tm) = &win.tone_mapping {
+                w.bits(12, tm.knee_point_x as u32);
+                w.bits(12, tm.knee_point_y as u32);
+                w.bits(4, tm.bezier_curve_anchors.len() as u32);
+                for a in &tm.bezier_curve_anchors {
+                    w.bits(10, *a as u32);
+                }
+            }
+            w.bit(win.color_saturation_weight.is_some());
+            if let Some(weight) = win.color_saturation_weight {
+                w.bits(6, weight as u32);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_vec(&self) -> os::Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(64);
+        self.write_to(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Value for `hdr10plus_per_frame_data` sample attachment.
+    #[cfg(target_vendor = "apple")]
+    pub fn to_cf_data(&self) -> os::Result<arc::R<cf::Data>> {
+        cf::Data::from_slice(&self.to_vec()?).ok_or(err::ALLOC_FAILED)
+    }
+
+    fn is_valid(&self) -> bool {
+        let fits = |v: u32, bits: u32| v < 1 << bits;
+        let peak_ok = |p: &Option<PeakLuminance>| match p {
+            None => true,
+            Some(p) => {
+                fits(p.rows as u32, 5)
+                    && fits(p.cols as u32, 5)
+                    && p.values.len() == p.rows as usize * p.cols as usize
+                    && p.values.iter().all(|v| fits(*v as u32, 4))
+            }
+        };
+        (1..=3).contains(&self.windows.len())
+            && self.windows[1..].iter().all(|w| w.geometry.is_some())
+            && fits(self.targeted_system_display_max_luminance, 27)
+            && peak_ok(&self.targeted_system_display_actual_peak_luminance)
+            && peak_ok(&self.mastering_display_actual_peak_luminance)
+            && self.windows.iter().all(|w| {
+                w.maxscl.iter().all(|v| fits(*v, 17))
+                    && fits(w.average_maxrgb, 17)
+                    && w.distribution_maxrgb.len() < 16
+                    && w.distribution_maxrgb
+                        .iter()
+                        .all(|p| fits(p.percentage as u32, 7) && fits(p.percentile, 17))
+                    && fits(w.fraction_bright_pixels as u32, 10)
+                    && w.tone_mapping.as_ref().is_none_or(|tm| {
+                        fits(tm.knee_point_x as u32, 12)
+                            && fits(tm.knee_point_y as u32, 12)
+                            && tm.bezier_curve_anchors.len() < 16
+                            && tm.bezier_curve_anchors.iter().all(|a| fits(*a as u32, 10))
+                    })
+                    && w.color_saturation_weight.is_none_or(|v| fits(v as u32, 6))
+            })
+    }
+}
+
+fn read_peak_luminance(r: &mut BitReader) -> Option<Option<PeakLuminance>> {
+    if !r.bit()? {
+        return Some(None);
+    }
+    let rows = r.u8(5)?;
+    let cols = r.u8(5)?;
+    let mut values = Vec::with_capacity(rows as usize * cols as usize);
+    for _ in 0..rows as usize * cols as usize {
+        values.push(r.u8(4)?);
+    }
+    Some(Some(PeakLuminance { rows, cols, values }))
+}
+
+fn write_peak_luminance(w: &mut BitWriter, p: &Option<PeakLuminance>) {
+    w.bit(p.is_some());
+    if let Some(p) = p {
+        w.bits(5, p.rows as u32);
+        w.bits(5, p.cols as u32);
+        for v in &p.values {
+            w.bits(4, *v as u32);
+        }
+    }
+}
+
+/// Dolby Vision decoder configuration record, payload of `dvcC`, `dvvC` and `dvwC` boxes.
+///
+/// Goes into `SampleDescriptionExtensionAtoms` of format description under [`Self::atom_name`].
+#[doc(alias = "DOVIDecoderConfigurationRecord")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DoviCfg {
+    pub version_major: u8,
+    pub version_minor: u8,
+    /// 7 bit profile, e.g. 5, 8 or 10.
+    pub profile: u8,
+    /// 6 bit level.
+    pub level: u8,
+    pub rpu_present: bool,
+    pub el_present: bool,
+    pub bl_present: bool,
+    /// 4 bit base layer compatibility, 1 for HDR10, 2 for SDR, 4 for HLG.
+    pub bl_signal_compatibility_id: u8,
+}
+
+impl DoviCfg {
+    pub const LEN: usize = 24;
+
+    /// Single layer profile with RPU, e.g. 8.1 is `with_profile(8, level, 1)`.
+    pub fn with_profile(profile: u8, level: u8, bl_signal_compatibility_id: u8) -> Self {
+        Self {
+            version_major: 1,
+            version_minor: 0,
+            profile,
+            level,
+            rpu_present: true,
+            el_present: false,
+            bl_present: true,
+            bl_signal_compatibility_id,
+        }
+    }
+
+    /// `dvcC` up to profile 7, `dvvC` up to 10, `dvwC` above.
+    pub fn atom_name(&self) -> &'static str {
+        match self.profile {
+            0..=7 => "dvcC",
+            8..=10 => "dvvC",
+            _ => "dvwC",
+        }
+    }
+
+    /// Accepts truncated records without trailing reserved bytes.
+    pub fn from_bytes(bytes: &[u8]) -> os::Result<Self> {
+        let &[version_major, version_minor, a, b, c, ..] = bytes else {
+            return Err(err::INVALID_PARAM);
+        };
+        Ok(Self {
+            version_major,
+            version_minor,
+            profile: a >> 1,
+            level: ((a & 1) << 5) | (b >> 3),
+            rpu_present: b & 0b100 != 0,
+            el_present: b & 0b010 != 0,
+            bl_present: b & 0b001 != 0,
+            bl_signal_compatibility_id: c >> 4,
+        })
+    }
+
+    pub fn to_bytes(&self) -> os::Result<[u8; Self::LEN]> {
+        if self.profile > 0x7f || self.level > 0x3f || self.bl_signal_compatibility_id > 0xf {
+            return Err(err::INVALID_PARAM);
+        }
+        let mut res = [0u8; Self::LEN];
+        res[0] = self.version_major;
+        res[1] = self.version_minor;
+        res[2] = (self.profile << 1) | (self.level >> 5);
+        res[3] = ((self.level & 0x1f) << 3)
+            | ((self.rpu_present as u8) << 2)
+            | ((self.el_present as u8) << 1)
+            | self.bl_present as u8;
+        res[4] = self.bl_signal_compatibility_id << 4;
+        Ok(res)
+    }
+
+    #[cfg(target_vendor = "apple")]
+    pub fn to_cf_data(&self) -> os::Result<arc::R<cf::Data>> {
+        cf::Data::from_slice(&self.to_bytes()?).ok_or(err::ALLOC_FAILED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::hdr;
+
+    #[test]
+    fn mastering_display() {
+        let md = hdr::MasteringDisplay::with_nits(
+            hdr::MasteringDisplay::P3_PRIMARIES,
+            hdr::Chromaticity::D65,
+            1000.0,
+            0.0001,
+        );
+        let bytes = md.to_bytes();
+        assert_eq!(
+            bytes,
+            [
+                0x33, 0xc2, 0x86, 0xc4, 0x1d, 0x4c, 0x0b, 0xb8, 0x84, 0xd0, 0x3e, 0x80, 0x3d, 0x13,
+                0x40, 0x42, 0x00, 0x98, 0x96, 0x80, 0x00, 0x00, 0x00, 0x01
+            ]
+        );
+        assert_eq!(hdr::MasteringDisplay::from_bytes(&bytes).unwrap(), md);
+        assert_eq!(md.max_nits(), 1000.0);
+        assert_eq!(
+            hdr::Chromaticity::with_xy(0.3127, 0.329),
+            hdr::Chromaticity::D65
+        );
+        assert!(hdr::MasteringDisplay::from_bytes(&bytes[1..]).is_err());
+
+        let cll = hdr::ContentLightLevel::new(1000, 400);
+        assert_eq!(cll.to_bytes(), [0x03, 0xe8, 0x01, 0x90]);
+        assert_eq!(
+            hdr::ContentLightLevel::from_bytes(&cll.to_bytes()).unwrap(),
+            cll
+        );
+        assert!(hdr::ContentLightLevel::from_bytes(&[0; 5]).is_err());
+    }
+
+    #[test]
+    fn hdr10plus() {
+        let mut meta = hdr::Hdr10Plus {
+            targeted_system_display_max_luminance: 400,
+            ..Default::default()
+        };
+        let w = &mut meta.windows[0];
+        w.maxscl = [0x1_0000, 23_000, 100];
+        w.average_maxrgb = 1200;
+        w.distribution_maxrgb = [1, 5, 10, 25, 50, 75, 90, 95, 99]
+            .into_iter()
+            .map(|percentage| hdr::MaxRgbPercentile {
+                percentage,
+                percentile: percentage as u32 * 1000,
+            })
+            .collect();
+        w.fraction_bright_pixels = 2;
+        w.tone_mapping = Some(hdr::ToneMapping {
+            knee_point_x: 17,
+            knee_point_y: 64,
+            bezier_curve_anchors: vec![102, 205, 307, 410, 512, 614, 717, 819, 922],
+        });
+
+        let bytes = meta.to_vec().unwrap();
+        // header, version 1, one window and the first 6 bits of luminance
+        assert_eq!(
+            &bytes[..8],
+            &[0xb5, 0x00, 0x3c, 0x00, 0x01, 0x04, 0x01, 0x40]
+        );
+        assert_eq!(hdr::Hdr10Plus::from_bytes(&bytes).unwrap(), meta);
+
+        meta.windows.push(hdr::Hdr10PlusWindow {
+            geometry: Some(hdr::WindowGeometry {
+                lower_right_corner: (1919, 1079),
+                overlap_process_option: true,
+                ..Default::default()
+            }),
+            color_saturation_weight: Some(3),
+            ..Default::default()
+        });
+        meta.mastering_display_actual_peak_luminance = Some(hdr::PeakLuminance {
+            rows: 2,
+            cols: 3,
+            values: vec![1, 2, 3, 4, 5, 15],
+        });
+        let bytes = meta.to_vec().unwrap();
+        assert_eq!(hdr::Hdr10Plus::from_bytes(&bytes).unwrap(), meta);
+        assert!(hdr::Hdr10Plus::from_bytes(&bytes[..bytes.len() - 4]).is_err());
+        assert!(hdr::Hdr10Plus::from_bytes(&bytes[1..]).is_err());
+
+        meta.windows[1].geometry = None;
+        assert!(meta.to_vec().is_err());
+        meta.windows[1].geometry = Some(Default::default());
+        meta.windows[0].average_maxrgb = 1 << 17;
+        assert!(meta.to_vec().is_err());
+    }
+
+    #[test]
+    fn dovi() {
+        let cfg = hdr::DoviCfg::with_profile(8, 6, 1);
+        let bytes = cfg.to_bytes().unwrap();
+        assert_eq!(&bytes[..5], &[0x01, 0x00, 0x10, 0x35, 0x10]);
+        assert!(bytes[5..].iter().all(|b| *b == 0));
+        assert_eq!(cfg.atom_name(), "dvvC");
+        assert_eq!(hdr::DoviCfg::from_bytes(&bytes).unwrap(), cfg);
+
+        // profile 5, level 13
+        let cfg = hdr::DoviCfg::from_bytes(&[0x01, 0x00, 0x0a, 0x6d, 0x00]).unwrap();
+        assert_eq!((cfg.profile, cfg.level), (5, 13));
+        assert!(cfg.rpu_present && !cfg.el_present && cfg.bl_present);
+        assert_eq!(cfg.bl_signal_compatibility_id, 0);
+        assert_eq!(cfg.atom_name(), "dvcC");
+
+        assert!(hdr::DoviCfg::from_bytes(&[0x01]).is_err());
+        assert!(hdr::DoviCfg::with_profile(8, 64, 1).to_bytes().is_err());
+    }
+}
@@ -0,0 +1,392 @@
+use std::borrow::Cow;
+
+use crate::{
+    cm::{self, block_buf_err, format_desc_err},
+    os,
+};
+
+pub const START_CODE: [u8; 4] = [0, 0, 0, 1];
+
+/// Which NAL unit header syntax to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NalCodec {
+    /// One byte header, 5 bit `nal_unit_type`
+    H264,
+    /// Two byte header, 6 bit `nal_unit_type`
+    Hevc,
+}
+
+impl NalCodec {
+    pub fn with_video_codec(codec: cm::VideoCodec) -> Option<Self> {
+        match codec {
+            cm::VideoCodec::H264 => Some(Self::H264),
+            cm::VideoCodec::HEVC
+            | cm::VideoCodec::HEVC_WITH_ALPHA
+            | cm::VideoCodec::DOLBY_VISION_HEVC
+            | cm::VideoCodec::DISPARITY_HEVC
+            | cm::VideoCodec::DEPTH_HEVC => Some(Self::Hevc),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn nal_unit_type(self, nal: &[u8]) -> Option<u8> {
+        let header = *nal.first()?;
+        match self {
+            Self::H264 => Some(header & 0x1f),
+            Self::Hevc => Some((header >> 1) & 0x3f),
+        }
+    }
+
+    /// IDR slice for H.264, IRAP picture (BLA, IDR, CRA) for HEVC.
+    #[inline]
+    pub fn is_idr(self, nal: &[u8]) -> bool {
+        match (self, self.nal_unit_type(nal)) {
+            (Self::H264, Some(t)) => t == 5,
+            (Self::Hevc, Some(t)) => (16..=23).contains(&t),
+            _ => false,
+        }
+    }
+
+    /// SPS and PPS for H.264, VPS, SPS and PPS for HEVC.
+    #[inline]
+    pub fn is_param_set(self, nal: &[u8]) -> bool {
+        match (self, self.nal_unit_type(nal)) {
+            (Self::H264, Some(t)) => t == 7 || t == 8,
+            (Self::Hevc, Some(t)) => (32..=34).contains(&t),
+            _ => false,
+        }
+    }
+
+    /// Access unit delimiter.
+    #[inline]
+    pub fn is_aud(self, nal: &[u8]) -> bool {
+        match (self, self.nal_unit_type(nal)) {
+            (Self::H264, Some(t)) => t == 9,
+            (Self::Hevc, Some(t)) => t == 35,
+            _ => false,
+        }
+    }
+}
+
+#[inline]
+fn check_nal_unit_len(nal_unit_len: u8) -> os::Result {
+    match nal_unit_len {
+        1 | 2 | 4 => Ok(()),
+        _ => Err(format_desc_err::INVALID_PARAM),
+    }
+}
+
+/// Iterator over NAL units with big-endian length prefix (avcC/hvcC sample format).
+///
+/// Yields an error and stops if a length runs past the end of data.
+#[derive(Debug, Clone)]
+pub struct LenPrefixedIter<'a> {
+    data: &'a [u8],
+    nal_unit_len: usize,
+}
+
+impl<'a> Iterator for LenPrefixedIter<'a> {
+    type Item = os::Result<&'a [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let n = self.nal_unit_len;
+        if self.data.len() < n {
+            self.data = &[];
+            return Some(Err(block_buf_err::BAD_LEN_PARAM));
+        }
+        let len = self.data[..n]
+            .iter()
+            .fold(0usize, |acc, b| (acc << 8) | *b as usize);
+        let Some(nal) = self.data.get(n..n + len) else {
+            self.data = &[];
+            return Some(Err(block_buf_err::BAD_LEN_PARAM));
+        };
+        self.data = &self.data[n + len..];
+        Some(Ok(nal))
+    }
+}
+
+/// Iterates NAL units prefixed with `nal_unit_len` (1, 2 or 4) byte length.
+pub fn len_prefixed_nal_units(data: &[u8], nal_unit_len: u8) -> os::Result<LenPrefixedIter<'_>> {
+    check_nal_unit_len(nal_unit_len)?;
+    Ok(LenPrefixedIter {
+        data,
+        nal_unit_len: nal_unit_len as usize,
+    })
+}
+
+/// Iterator over NAL units of Annex-B byte stream.
+///
+/// Both 3 and 4 byte start codes are accepted. Leading bytes before first
+/// start code and trailing zero bytes of each NAL unit are skipped.
+#[derive(Debug, Clone)]
+pub struct AnnexBIter<'a> {
+    data: &'a [u8],
+}
+
+/// Returns position of next `00 00 01`.
+fn find_start_code(data: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    while i + 3 <= data.len() {
+        // fast skip: start code ends with 1 preceded by two zeros
+        if data[i + 2] > 1 {
+            i += 3;
+        } else if data[i] == 0 && data[i + 1] == 0 && data[i + 2] == 1 {
+            return Some(i);
+        } else {
+            i += 1;
+        }
+    }
+    None
+}
+
+impl<'a> Iterator for AnnexBIter<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let pos = find_start_code(self.data)?;
+            let rest = &self.data[pos + 3..];
+            let end = find_start_code(rest).unwrap_or(rest.len());
+            self.data = &rest[end..];
+            let mut nal = &rest[..end];
+            while let [head @ .., 0] = nal {
+                nal = head;
+            }
+            if !nal.is_empty() {
+                return Some(nal);
+            }
+        }
+    }
+}
+
+/// Iterates NAL units of Annex-B byte stream.
+pub fn annex_b_nal_units(data: &[u8]) -> AnnexBIter<'_> {
+    AnnexBIter { data }
+}
+
+fn push_len(dst: &mut Vec<u8>, len: usize, nal_unit_len: u8) -> os::Result {
+    let bytes = (len as u64).to_be_bytes();
+    let (head, tail) = bytes.split_at(8 - nal_unit_len as usize);
+    if head.iter().any(|b| *b != 0) {
+        return Err(block_buf_err::BAD_LEN_PARAM);
+    }
+    dst.extend_from_slice(tail);
+    Ok(())
+}
+
+/// Appends NAL units of Annex-B byte stream to `dst` with `nal_unit_len` byte length prefix.
+pub fn annex_b_to_len_prefixed(src: &[u8], nal_unit_len: u8, dst: &mut Vec<u8>) -> os::Result {
+    check_nal_unit_len(nal_unit_len)?;
+    for nal in annex_b_nal_units(src) {
+        push_len(dst, nal.len(), nal_unit_len)?;
+        dst.extend_from_slice(nal);
+    }
+    Ok(())
+}
+
+/// Appends length-prefixed NAL units to `dst` as Annex-B byte stream with 4 byte start codes.
+pub fn len_prefixed_to_annex_b(src: &[u8], nal_unit_len: u8, dst: &mut Vec<u8>) -> os::Result {
+    dst.reserve(src.len() + 16);
+    for nal in len_prefixed_nal_units(src, nal_unit_len)? {
+        dst.extend_from_slice(&START_CODE);
+        dst.extend_from_slice(nal?);
+    }
+    Ok(())
+}
+
+/// Same as [`len_prefixed_to_annex_b`] but inserts `param_sets` right before the first
+/// IDR (IRAP for HEVC) NAL unit if the access unit doesn't carry parameter sets itself.
+///
+/// Decoders of RTMP/SRT/TS streams need parameter sets in-band while VideoToolbox
+/// keeps them in format description. See [`cm::AvcCfg::param_sets`] and
+/// [`cm::HevcCfg::param_sets`].
+pub fn len_prefixed_to_annex_b_with_param_sets<'a>(
+    src: &[u8],
+    nal_unit_len: u8,
+    codec: NalCodec,
+    param_sets: impl IntoIterator<Item = &'a [u8]>,
+    dst: &mut Vec<u8>,
+) -> os::Result {
+    let mut has_param_sets = false;
+    let mut param_sets = Some(param_sets);
+    dst.reserve(src.len() + 16);
+    for nal in len_prefixed_nal_units(src, nal_unit_len)? {
+        let nal = nal?;
+        if codec.is_param_set(nal) {
+            has_param_sets = true;
+        } else if !has_param_sets
+            && codec.is_idr(nal)
+            && let Some(param_sets) = param_sets.take()
+        {
+            for ps in param_sets {
+                dst.extend_from_slice(&START_CODE);
+                dst.extend_from_slice(ps);
+            }
+        }
+        dst.extend_from_slice(&START_CODE);
+        dst.extend_from_slice(nal);
+    }
+    Ok(())
+}
+
+/// Removes emulation prevention bytes (`00 00 03` -> `00 00`), NAL unit payload to RBSP.
+pub fn unescape(data: &[u8]) -> Cow<'_, [u8]> {
+    let needs = data.windows(3).any(|w| w == [0, 0, 3]);
+    if !needs {
+        return Cow::Borrowed(data);
+    }
+    let mut res = Vec::with_capacity(data.len());
+    let mut zeros = 0;
+    for &b in data {
+        if zeros >= 2 && b == 3 {
+            zeros = 0;
+            continue;
+        }
+        zeros = if b == 0 { zeros + 1 } else { 0 };
+        res.push(b);
+    }
+    Cow::Owned(res)
+}
+
+/// Appends RBSP to `dst` inserting emulation prevention bytes so
+/// the payload never contains start code prefix.
+pub fn escape(rbsp: &[u8], dst: &mut Vec<u8>) {
+    dst.reserve(rbsp.len() + rbsp.len() / 64);
+    let mut zeros = 0;
+    for &b in rbsp {
+        if zeros >= 2 && b <= 3 {
+            dst.push(3);
+            zeros = 0;
+        }
+        zeros = if b == 0 { zeros + 1 } else { 0 };
+        dst.push(b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::{self, nal};
+
+    const SPS: &[u8] = &[0x67, 0x42, 0x00, 0x1e];
+    const PPS: &[u8] = &[0x68, 0xce, 0x38, 0x80];
+    const IDR: &[u8] = &[0x65, 0x88, 0x84, 0x21];
+    const SEI: &[u8] = &[0x06, 0x05, 0x01, 0x80];
+    const NON_IDR: &[u8] = &[0x41, 0x9a, 0x02];
+
+    #[test]
+    fn annex_b() {
+        let stream = [
+            &[0xff][..],
+            &[0, 0, 0, 1],
+            SPS,
+            &[0, 0, 1],
+            PPS,
+            &[0, 0],
+            &[0, 0, 0, 1],
+            IDR,
+        ]
+        .concat();
+        let units: Vec<_> = nal::annex_b_nal_units(&stream).collect();
+        assert_eq!(units, [SPS, PPS, IDR]);
+
+        let mut avcc = vec![];
+        nal::annex_b_to_len_prefixed(&stream, 2, &mut avcc).unwrap();
+        assert_eq!(&avcc[..6], &[0, 4, 0x67, 0x42, 0x00, 0x1e]);
+        let units: Vec<_> = nal::len_prefixed_nal_units(&avcc, 2)
+            .unwrap()
+            .map(|n| n.unwrap())
+            .collect();
+        assert_eq!(units, [SPS, PPS, IDR]);
+
+        let mut out = vec![];
+        nal::len_prefixed_to_annex_b(&avcc, 2, &mut out).unwrap();
+        assert_eq!(
+            out,
+            [
+                &nal::START_CODE,
+                SPS,
+                &nal::START_CODE,
+                PPS,
+                &nal::START_CODE,
+                IDR
+            ]
+            .concat()
+        );
+
+        assert!(nal::annex_b_nal_units(&[0, 0, 0]).next().is_none());
+        assert!(nal::len_prefixed_nal_units(&avcc, 3).is_err());
+        let mut out = vec![];
+        assert!(
+            nal::annex_b_to_len_prefixed(&[&[0, 0, 1][..], &[0x41; 300]].concat(), 1, &mut out)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn len_prefixed() {
+        let mut avcc = vec![];
+        for nal in [SEI, IDR] {
+            avcc.extend_from_slice(&(nal.len() as u32).to_be_bytes());
+            avcc.extend_from_slice(nal);
+        }
+        let mut out = vec![];
+        nal::len_prefixed_to_annex_b_with_param_sets(
+            &avcc,
+            4,
+            nal::NalCodec::H264,
+            [SPS, PPS],
+            &mut out,
+        )
+        .unwrap();
+        let sc = &nal::START_CODE[..];
+        assert_eq!(out, [sc, SEI, sc, SPS, sc, PPS, sc, IDR].concat());
+
+        let mut avcc = vec![];
+        avcc.extend_from_slice(&(NON_IDR.len() as u32).to_be_bytes());
+        avcc.extend_from_slice(NON_IDR);
+        let mut out = vec![];
+        nal::len_prefixed_to_annex_b_with_param_sets(
+            &avcc,
+            4,
+            nal::NalCodec::H264,
+            [SPS, PPS],
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(out, [sc, NON_IDR].concat());
+
+        let truncated = &avcc[..avcc.len() - 1];
+        let mut iter = nal::len_prefixed_nal_units(truncated, 4).unwrap();
+        assert_eq!(iter.next(), Some(Err(cm::block_buf_err::BAD_LEN_PARAM)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn emulation_prevention() {
+        let rbsp = [0, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 4, 0];
+        let mut ebsp = vec![];
+        nal::escape(&rbsp, &mut ebsp);
+        assert_eq!(ebsp, [0, 0, 3, 0, 0, 3, 0, 1, 0, 0, 3, 3, 0, 0, 4, 0]);
+        assert_eq!(nal::unescape(&ebsp).as_ref(), rbsp);
+        assert!(matches!(nal::unescape(SPS), std::borrow::Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn nal_types() {
+        let h264 = nal::NalCodec::H264;
+        assert!(h264.is_idr(IDR));
+        assert!(h264.is_param_set(SPS));
+        assert!(!h264.is_idr(NON_IDR));
+
+        let hevc = nal::NalCodec::with_video_codec(cm::VideoCodec::HEVC).unwrap();
+        assert!(hevc.is_param_set(&[0x40, 0x01]));
+        assert!(hevc.is_idr(&[0x26, 0x01]));
+        assert!(hevc.is_aud(&[0x46, 0x01]));
+        assert!(nal::NalCodec::with_video_codec(cm::VideoCodec::JPEG).is_none());
+    }
+}
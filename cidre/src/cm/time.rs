@@ -1,4 +1,6 @@
-use crate::{arc, cf, define_opts};
+use crate::define_opts;
+#[cfg(target_vendor = "apple")]
+use crate::{arc, cf};
 
 pub mod range;
 pub use range::Mapping as TimeMapping;
@@ -46,21 +48,26 @@ pub struct Time {
     pub epoch: TimeEpoch,
 }
 
-#[derive(Copy, Clone, Debug)]
+#[doc(alias = "CMTimeRoundingMethod")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(u32)]
 pub enum TimeRoundingMethod {
     RoundHalfAwayFromZero = 1,
     RoundTowardZero = 2,
     RoundAwayFromZero = 3,
+
+    /// Toward zero when converting to a smaller timescale, away from zero
+    /// when converting to a larger one. Negative values are never rounded to zero.
     QuickTime = 4,
     RoundTowardPositiveInfinity = 5,
     RoundTowardNegativeInfinity = 6,
 }
 
 impl Default for TimeRoundingMethod {
+    #[doc(alias = "kCMTimeRoundingMethod_Default")]
     #[inline]
     fn default() -> Self {
-        Self::RoundAwayFromZero
+        Self::RoundHalfAwayFromZero
     }
 }
 
@@ -75,9 +82,21 @@ impl Time {
     /// assert_eq!(t2.as_secs(), 5.0);
     /// ```
     #[doc(alias = "CMTimeAbsoluteValue")]
-    #[inline]
     pub fn abs(self) -> Time {
-        unsafe { CMTimeAbsoluteValue(self) }
+        if self.is_neg_infinity() {
+            Self::infinity()
+        } else if self.is_numeric() && self.value < 0 {
+            Self::with_rational(
+                -(self.value as i128),
+                self.scale as i128,
+                self.scale as i64,
+                self.epoch,
+                self.has_been_rounded(),
+                TimeRoundingMethod::RoundHalfAwayFromZero,
+            )
+        } else {
+            self
+        }
     }
 
     /// ```
@@ -90,9 +109,13 @@ impl Time {
     /// assert_eq!(t3.scale, 10);
     /// assert_eq!(t3.as_secs(), 300.0);
     /// ```
-    #[inline]
+    ///
+    /// Timescale of the result is LCM of operands' timescales (capped by `cm::TIME_SCALE_MAX`)
+    /// and is halved while the value overflows. Times with different nonzero epochs
+    /// can't be added; the same nonzero epoch gives a duration (epoch zero).
+    #[doc(alias = "CMTimeAdd")]
     pub fn add(self, rhs: Time) -> Time {
-        unsafe { CMTimeAdd(self, rhs) }
+        self.add_signed(rhs, false)
     }
 
     /// ```
@@ -101,40 +124,89 @@ impl Time {
     /// let time = cm::Time::default().convert_scale(100, cm::TimeRoundingMethod::default());
     /// assert!(time.is_valid());
     /// assert_eq!(time.scale, 100);
+    ///
+    /// let time = cm::Time::new(-1, 3).convert_scale(2, cm::TimeRoundingMethod::QuickTime);
+    /// assert_eq!(time.value, -1);
+    /// assert!(time.has_been_rounded());
     /// ```
-    #[inline]
+    #[doc(alias = "CMTimeConvertScale")]
     pub fn convert_scale(
         self,
         new_time_scale: TimeScale,
         rounding_method: TimeRoundingMethod,
     ) -> Time {
-        unsafe { CMTimeConvertScale(self, new_time_scale, rounding_method) }
+        if !self.is_numeric() {
+            return self;
+        }
+        if new_time_scale <= 0 || self.scale <= 0 {
+            return Self::invalid();
+        }
+        if new_time_scale == self.scale {
+            return self;
+        }
+        let (value, rounded) = div_round(
+            self.value as i128 * new_time_scale as i128,
+            self.scale as i128,
+            rounding_method,
+            new_time_scale > self.scale,
+        );
+        if value > TimeValue::MAX as i128 {
+            Self::infinity()
+        } else if value < TimeValue::MIN as i128 {
+            Self::neg_infinity()
+        } else {
+            Self::numeric(
+                value as TimeValue,
+                new_time_scale,
+                self.epoch,
+                rounded || self.has_been_rounded(),
+            )
+        }
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn desc_in(self, allocator: Option<&cf::Allocator>) -> Option<arc::R<cf::String>> {
         unsafe { CMTimeCopyDescription(allocator, self) }
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn desc(self) -> Option<arc::R<cf::String>> {
         unsafe { CMTimeCopyDescription(None, self) }
     }
 
     /// Converts a Time to seconds.
-    #[inline]
+    ///
+    /// Invalid and indefinite times are NaN, epoch is ignored.
+    #[doc(alias = "CMTimeGetSeconds")]
     pub fn as_secs(self) -> f64 {
-        unsafe { CMTimeGetSeconds(self) }
+        if self.is_pos_infinity() {
+            f64::INFINITY
+        } else if self.is_neg_infinity() {
+            f64::NEG_INFINITY
+        } else if self.is_numeric() {
+            self.value as f64 / self.scale as f64
+        } else {
+            f64::NAN
+        }
     }
 
+    #[doc(alias = "kCMTimeIndefinite")]
     #[inline]
-    pub fn indefinit() -> Time {
-        unsafe { kCMTimeIndefinite }
+    pub const fn indefinit() -> Time {
+        Self::implied(TimeFlags::INDEFINITE)
     }
 
+    #[doc(alias = "kCMTimeInvalid")]
     #[inline]
-    pub fn invalid() -> Time {
-        unsafe { kCMTimeInvalid }
+    pub const fn invalid() -> Time {
+        Self {
+            value: 0,
+            scale: 0,
+            flags: TimeFlags(0),
+            epoch: 0,
+        }
     }
 
     /// ```
@@ -220,15 +292,50 @@ impl Time {
     /// assert_eq!(t2.as_secs(), 10.0);
     /// ```
     #[doc(alias = "CMTimeMultiply")]
-    #[inline]
     pub fn mul_i32(self, multiplier: i32) -> Time {
-        unsafe { CMTimeMultiply(self, multiplier) }
+        if !self.is_numeric() {
+            return self.mul_non_numeric(multiplier.signum() as i64);
+        }
+        if self.scale <= 0 {
+            return Self::invalid();
+        }
+        Self::with_rational(
+            self.value as i128 * multiplier as i128,
+            self.scale as i128,
+            self.scale as i64,
+            self.epoch,
+            self.has_been_rounded(),
+            TimeRoundingMethod::RoundHalfAwayFromZero,
+        )
     }
 
     #[doc(alias = "CMTimeMultiplyByFloat64")]
-    #[inline]
     pub fn mul_f64(self, multiplier: f64) -> Time {
-        unsafe { CMTimeMultiplyByFloat64(self, multiplier) }
+        if multiplier.is_nan() {
+            return Self::invalid();
+        }
+        let sign = if multiplier > 0.0 {
+            1
+        } else if multiplier < 0.0 {
+            -1
+        } else {
+            0
+        };
+        if !self.is_numeric() {
+            return self.mul_non_numeric(sign);
+        }
+        if self.scale <= 0 {
+            return Self::invalid();
+        }
+        if multiplier.is_infinite() {
+            return Self::signed_infinity(sign * self.value.signum());
+        }
+        Self::with_f64(
+            self.value as f64 * multiplier,
+            self.scale,
+            self.epoch,
+            self.has_been_rounded(),
+        )
     }
 
     /// Returns valid Time with value and timescale. Epoch is implied to be 0.
@@ -242,22 +349,23 @@ impl Time {
     /// ```
     #[doc(alias = "CMTimeMake")]
     #[inline]
-    pub fn new(value: TimeValue, timescale: i32) -> Time {
-        unsafe { CMTimeMake(value, timescale) }
+    pub const fn new(value: TimeValue, timescale: i32) -> Time {
+        Self::with_epoch(value, timescale, 0)
     }
 
     #[doc(alias = "kCMTimePositiveInfinity")]
     #[inline]
-    pub fn infinity() -> Time {
-        unsafe { kCMTimePositiveInfinity }
+    pub const fn infinity() -> Time {
+        Self::implied(TimeFlags::POS_INFINITY)
     }
 
     #[doc(alias = "kCMTimeNegativeInfinity")]
     #[inline]
-    pub fn neg_infinity() -> Time {
-        unsafe { kCMTimeNegativeInfinity }
+    pub const fn neg_infinity() -> Time {
+        Self::implied(TimeFlags::NEG_INFINITY)
     }
 
+    #[cfg(target_vendor = "apple")]
     #[inline]
     pub fn show(self) {
         unsafe { CMTimeShow(self) }
@@ -273,9 +381,9 @@ impl Time {
     /// assert_eq!(t3.scale, 10);
     /// assert_eq!(t3.as_secs(), 0.0);
     /// ```
-    #[inline]
+    #[doc(alias = "CMTimeSubtract")]
     pub fn sub(self, rhs: Time) -> Time {
-        unsafe { CMTimeSubtract(self, rhs) }
+        self.add_signed(rhs, true)
     }
 
     /// ```
@@ -285,9 +393,13 @@ impl Time {
     /// assert!(time.is_valid());
     /// assert_eq!(time.epoch, 5);
     /// ```
+    #[doc(alias = "CMTimeMakeWithEpoch")]
     #[inline]
-    pub fn with_epoch(value: TimeValue, timescale: i32, epoch: TimeEpoch) -> Time {
-        unsafe { CMTimeMakeWithEpoch(value, timescale, epoch) }
+    pub const fn with_epoch(value: TimeValue, timescale: i32, epoch: TimeEpoch) -> Time {
+        if timescale <= 0 {
+            return Self::invalid();
+        }
+        Self::numeric(value, timescale, epoch, false)
     }
 
     /// Returns Time from a f64 number of seconds, and a preferred timescale.
@@ -300,27 +412,374 @@ impl Time {
     /// assert_eq!(time.scale, 10);
     /// assert_eq!(time.as_secs(), 100.0);
     /// ```
-    #[inline]
+    #[doc(alias = "CMTimeMakeWithSeconds")]
     pub fn with_secs(seconds: f64, preferred_timescale: TimeScale) -> Time {
-        unsafe { CMTimeMakeWithSeconds(seconds, preferred_timescale) }
+        if seconds.is_nan() || preferred_timescale <= 0 {
+            Self::invalid()
+        } else if seconds == f64::INFINITY {
+            Self::infinity()
+        } else if seconds == f64::NEG_INFINITY {
+            Self::neg_infinity()
+        } else {
+            let res = Self::with_f64(
+                seconds * preferred_timescale as f64,
+                preferred_timescale,
+                0,
+                false,
+            );
+            if res.is_numeric() && res.as_secs() != seconds {
+                Self::numeric(res.value, res.scale, 0, true)
+            } else {
+                res
+            }
+        }
     }
 
+    #[doc(alias = "kCMTimeZero")]
     #[inline]
     pub const fn zero() -> Time {
-        unsafe { kCMTimeZero }
+        Self::numeric(0, 1, 0, false)
     }
 
+    #[doc(alias = "CMTimeMaximum")]
     #[inline]
     pub fn max(l: Time, r: Time) -> Time {
-        unsafe { CMTimeMaximum(l, r) }
+        if r.compare(&l).is_gt() { r } else { l }
     }
 
+    #[doc(alias = "CMTimeMinimum")]
     #[inline]
     pub fn min(l: Time, r: Time) -> Time {
+        if r.compare(&l).is_lt() { r } else { l }
+    }
+
+    /// Total order of CoreMedia:
+    /// -infinity < finite values < indefinite < +infinity < invalid.
+    ///
+    /// Numeric times in greater epochs are greater.
+    #[doc(alias = "CMTimeCompare")]
+    pub fn compare(&self, other: &Time) -> std::cmp::Ordering {
+        let (l, r) = (self.rank(), other.rank());
+        if l != r || l != 1 {
+            return l.cmp(&r);
+        }
+        self.epoch.cmp(&other.epoch).then_with(|| {
+            let l = self.value as i128 * other.scale as i128;
+            let r = other.value as i128 * self.scale as i128;
+            l.cmp(&r)
+        })
+    }
+
+    const fn numeric(value: TimeValue, scale: TimeScale, epoch: TimeEpoch, rounded: bool) -> Self {
+        let flags = if rounded {
+            TimeFlags(TimeFlags::VALID.0 | TimeFlags::HAS_BEEN_ROUNDED.0)
+        } else {
+            TimeFlags::VALID
+        };
+        Self {
+            value,
+            scale,
+            flags,
+            epoch,
+        }
+    }
+
+    const fn implied(flags: TimeFlags) -> Self {
+        Self {
+            value: 0,
+            scale: 0,
+            flags: TimeFlags(TimeFlags::VALID.0 | flags.0),
+            epoch: 0,
+        }
+    }
+
+    const fn rank(&self) -> u8 {
+        if self.is_invalid() {
+            4
+        } else if self.is_pos_infinity() {
+            3
+        } else if self.is_neg_infinity() {
+            0
+        } else if self.is_indefinite() {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Exact `num / den` seconds expressed in `scale` (capped by `TIME_SCALE_MAX`),
+    /// halving the timescale while the value overflows.
+    fn with_rational(
+        num: i128,
+        den: i128,
+        scale: i64,
+        epoch: TimeEpoch,
+        mut rounded: bool,
+        method: TimeRoundingMethod,
+    ) -> Self {
+        let mut scale = if scale > TIME_SCALE_MAX as i64 {
+            TIME_SCALE_MAX as i64
+        } else {
+            scale
+        };
+        loop {
+            if let Some(n) = num.checked_mul(scale as i128) {
+                let (value, r) = div_round(n, den, method, scale as i128 > den);
+                if value >= TimeValue::MIN as i128 && value <= TimeValue::MAX as i128 {
+                    rounded |= r;
+                    return Self::numeric(value as TimeValue, scale as TimeScale, epoch, rounded);
+                }
+            }
+            if scale == 1 {
+                return Self::signed_infinity(num.signum() as i64);
+            }
+            scale /= 2;
+        }
+    }
+
+    /// `num / den` seconds in `scale` with default rounding.
+    pub(crate) fn with_rational_scale(
+        num: i128,
+        den: i128,
+        scale: TimeScale,
+        epoch: TimeEpoch,
+        rounded: bool,
+    ) -> Self {
+        Self::with_rational(
+            num,
+            den,
+            scale as i64,
+            epoch,
+            rounded,
+            TimeRoundingMethod::RoundHalfAwayFromZero,
+        )
+    }
+
+    fn with_f64(value: f64, mut scale: TimeScale, epoch: TimeEpoch, rounded: bool) -> Self {
+        // 2^63, first value that doesn't fit into TimeValue
+        const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+        let mut value = value;
+        loop {
+            let rounded_value = value.round();
+            if rounded_value.abs() < LIMIT {
+                let rounded = rounded || rounded_value != value;
+                return Self::numeric(rounded_value as TimeValue, scale, epoch, rounded);
+            }
+            if scale == 1 {
+                return Self::signed_infinity(value.signum() as i64);
+            }
+            value *= (scale / 2) as f64 / scale as f64;
+            scale /= 2;
+        }
+    }
+
+    /// Infinity with the sign, invalid for zero (0 * infinity).
+    const fn signed_infinity(sign: i64) -> Self {
+        if sign > 0 {
+            Self::infinity()
+        } else if sign < 0 {
+            Self::neg_infinity()
+        } else {
+            Self::invalid()
+        }
+    }
+
+    const fn mul_non_numeric(self, sign: i64) -> Self {
+        if self.is_pos_infinity() {
+            Self::signed_infinity(sign)
+        } else if self.is_neg_infinity() {
+            Self::signed_infinity(-sign)
+        } else if self.is_indefinite() {
+            Self::indefinit()
+        } else {
+            Self::invalid()
+        }
+    }
+
+    fn add_signed(self, rhs: Time, negate: bool) -> Self {
+        if self.is_invalid() || rhs.is_invalid() {
+            return Self::invalid();
+        }
+        let (rhs_pos_inf, rhs_neg_inf) = if negate {
+            (rhs.is_neg_infinity(), rhs.is_pos_infinity())
+        } else {
+            (rhs.is_pos_infinity(), rhs.is_neg_infinity())
+        };
+        let pos_inf = self.is_pos_infinity() || rhs_pos_inf;
+        let neg_inf = self.is_neg_infinity() || rhs_neg_inf;
+        match (pos_inf, neg_inf) {
+            (true, true) => return Self::invalid(),
+            (true, false) => return Self::infinity(),
+            (false, true) => return Self::neg_infinity(),
+            (false, false) => {}
+        }
+        if self.is_indefinite() || rhs.is_indefinite() {
+            return Self::indefinit();
+        }
+        if self.scale <= 0 || rhs.scale <= 0 {
+            return Self::invalid();
+        }
+
+        let epoch = if self.epoch == rhs.epoch {
+            0
+        } else if rhs.epoch == 0 {
+            self.epoch
+        } else if self.epoch == 0 {
+            rhs.epoch
+        } else {
+            return Self::invalid();
+        };
+
+        let rounded = self.has_been_rounded() || rhs.has_been_rounded();
+        let (l_scale, r_scale) = (self.scale as i64, rhs.scale as i64);
+        let scale = l_scale / gcd(l_scale, r_scale) * r_scale;
+        let l = self.value as i128 * (scale / l_scale) as i128;
+        let r = rhs.value as i128 * (scale / r_scale) as i128;
+        let num = if negate { l - r } else { l + r };
+        Self::with_rational(
+            num,
+            scale as i128,
+            scale,
+            epoch,
+            rounded,
+            TimeRoundingMethod::RoundHalfAwayFromZero,
+        )
+    }
+}
+
+/// CoreMedia implementations, useful for cross-checking.
+#[cfg(target_vendor = "apple")]
+impl Time {
+    #[doc(alias = "CMTimeAbsoluteValue")]
+    #[inline]
+    pub fn cm_abs(self) -> Time {
+        unsafe { CMTimeAbsoluteValue(self) }
+    }
+
+    #[doc(alias = "CMTimeAdd")]
+    #[inline]
+    pub fn cm_add(self, rhs: Time) -> Time {
+        unsafe { CMTimeAdd(self, rhs) }
+    }
+
+    #[doc(alias = "CMTimeSubtract")]
+    #[inline]
+    pub fn cm_sub(self, rhs: Time) -> Time {
+        unsafe { CMTimeSubtract(self, rhs) }
+    }
+
+    #[doc(alias = "CMTimeConvertScale")]
+    #[inline]
+    pub fn cm_convert_scale(
+        self,
+        new_time_scale: TimeScale,
+        rounding_method: TimeRoundingMethod,
+    ) -> Time {
+        unsafe { CMTimeConvertScale(self, new_time_scale, rounding_method) }
+    }
+
+    #[doc(alias = "CMTimeMultiply")]
+    #[inline]
+    pub fn cm_mul_i32(self, multiplier: i32) -> Time {
+        unsafe { CMTimeMultiply(self, multiplier) }
+    }
+
+    #[doc(alias = "CMTimeMultiplyByFloat64")]
+    #[inline]
+    pub fn cm_mul_f64(self, multiplier: f64) -> Time {
+        unsafe { CMTimeMultiplyByFloat64(self, multiplier) }
+    }
+
+    #[doc(alias = "CMTimeGetSeconds")]
+    #[inline]
+    pub fn cm_as_secs(self) -> f64 {
+        unsafe { CMTimeGetSeconds(self) }
+    }
+
+    #[doc(alias = "CMTimeMake")]
+    #[inline]
+    pub fn cm_new(value: TimeValue, timescale: i32) -> Time {
+        unsafe { CMTimeMake(value, timescale) }
+    }
+
+    #[doc(alias = "CMTimeMakeWithEpoch")]
+    #[inline]
+    pub fn cm_with_epoch(value: TimeValue, timescale: i32, epoch: TimeEpoch) -> Time {
+        unsafe { CMTimeMakeWithEpoch(value, timescale, epoch) }
+    }
+
+    #[doc(alias = "CMTimeMakeWithSeconds")]
+    #[inline]
+    pub fn cm_with_secs(seconds: f64, preferred_timescale: TimeScale) -> Time {
+        unsafe { CMTimeMakeWithSeconds(seconds, preferred_timescale) }
+    }
+
+    #[doc(alias = "CMTimeCompare")]
+    #[inline]
+    pub fn cm_compare(&self, other: &Time) -> std::cmp::Ordering {
+        unsafe { CMTimeCompare(*self, *other).cmp(&0) }
+    }
+
+    #[doc(alias = "CMTimeMaximum")]
+    #[inline]
+    pub fn cm_max(l: Time, r: Time) -> Time {
+        unsafe { CMTimeMaximum(l, r) }
+    }
+
+    #[doc(alias = "CMTimeMinimum")]
+    #[inline]
+    pub fn cm_min(l: Time, r: Time) -> Time {
         unsafe { CMTimeMinimum(l, r) }
     }
 }
 
+const fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Rounds `num / den` (`den > 0`), returns value and whether it was rounded.
+const fn div_round(
+    num: i128,
+    den: i128,
+    method: TimeRoundingMethod,
+    upscale: bool,
+) -> (i128, bool) {
+    let q = num / den;
+    let r = num % den;
+    if r == 0 {
+        return (q, false);
+    }
+    let away = q + num.signum();
+    let half_away = if 2 * r.abs() >= den { away } else { q };
+    let res = match method {
+        TimeRoundingMethod::RoundHalfAwayFromZero => half_away,
+        TimeRoundingMethod::RoundTowardZero => q,
+        TimeRoundingMethod::RoundAwayFromZero => away,
+        TimeRoundingMethod::RoundTowardPositiveInfinity => {
+            if num > 0 {
+                away
+            } else {
+                q
+            }
+        }
+        TimeRoundingMethod::RoundTowardNegativeInfinity => {
+            if num < 0 {
+                away
+            } else {
+                q
+            }
+        }
+        TimeRoundingMethod::QuickTime => {
+            let res = if upscale { away } else { q };
+            if res == 0 && num < 0 { -1 } else { res }
+        }
+    };
+    (res, true)
+}
+
 impl PartialEq for Time {
     /// ```
     /// use cidre::cm;
@@ -334,7 +793,7 @@ impl PartialEq for Time {
     /// ```
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        unsafe { CMTimeCompare(*self, *other) == 0 }
+        self.compare(other).is_eq()
     }
 }
 
@@ -343,7 +802,7 @@ impl Eq for Time {}
 impl Ord for Time {
     #[inline]
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        unsafe { std::mem::transmute(CMTimeCompare(*self, *other) as i8) }
+        self.compare(other)
     }
 
     fn max(self, other: Self) -> Self
@@ -418,14 +877,231 @@ mod tests {
         assert_eq!(zero, zero.min(zero_epoch_1));
         assert_eq!(zero_epoch_1, zero_epoch_1.min(zero_epoch_1));
     }
+
+    #[test]
+    fn arithmetic() {
+        let a = cm::Time::new(1, 3);
+        let b = cm::Time::new(1, 4);
+        let c = a.add(b);
+        assert_eq!((c.value, c.scale), (7, 12));
+        assert!(!c.has_been_rounded());
+        let c = a.sub(b);
+        assert_eq!((c.value, c.scale), (1, 12));
+
+        // LCM above TIME_SCALE_MAX is rounded
+        let a = cm::Time::new(1, cm::TIME_SCALE_MAX);
+        let b = cm::Time::new(1, cm::TIME_SCALE_MAX - 1);
+        let c = a.add(b);
+        assert_eq!(c.scale, cm::TIME_SCALE_MAX);
+        assert_eq!(c.value, 2);
+        assert!(c.has_been_rounded());
+
+        // value overflow halves timescale
+        let a = cm::Time::new(i64::MAX, 1000);
+        let c = a.add(a);
+        assert_eq!(c.scale, 500);
+        assert_eq!(c.value, i64::MAX);
+        assert!(!c.has_been_rounded());
+        assert!(
+            cm::Time::new(i64::MAX, 1)
+                .add(cm::Time::new(1, 1))
+                .is_pos_infinity()
+        );
+        assert!(cm::Time::new(i64::MIN, 1).abs().is_pos_infinity());
+
+        let c = cm::Time::new(3, 10).mul_i32(-4);
+        assert_eq!((c.value, c.scale), (-12, 10));
+        let c = cm::Time::new(3, 10).mul_f64(0.5);
+        assert_eq!((c.value, c.scale), (2, 10));
+        assert!(c.has_been_rounded());
+
+        // epochs
+        let e1 = cm::Time::with_epoch(5, 1, 1);
+        let e2 = cm::Time::with_epoch(3, 1, 2);
+        assert!(e1.add(e2).is_invalid());
+        assert_eq!(e1.sub(e1).epoch, 0);
+        assert_eq!(e1.add(cm::Time::new(1, 1)).epoch, 1);
+        assert!(e1 < e2);
+    }
+
+    #[test]
+    fn rounding() {
+        use cm::TimeRoundingMethod as M;
+        let cases = [
+            (M::RoundHalfAwayFromZero, [2, -2, 1, -1]),
+            (M::RoundTowardZero, [1, -1, 1, -1]),
+            (M::RoundAwayFromZero, [2, -2, 2, -2]),
+            (M::RoundTowardPositiveInfinity, [2, -1, 2, -1]),
+            (M::RoundTowardNegativeInfinity, [1, -2, 1, -2]),
+            (M::QuickTime, [1, -1, 1, -1]),
+        ];
+        for (method, expected) in cases {
+            let res =
+                [15, -15, 12, -12].map(|v| cm::Time::new(v, 10).convert_scale(1, method).value);
+            assert_eq!(res, expected, "{method:?}");
+        }
+
+        // QuickTime rounds away when increasing precision, never rounds negative to zero
+        let t = cm::Time::new(5, 7).convert_scale(10, M::QuickTime);
+        assert_eq!(t.value, 8);
+        let t = cm::Time::new(-5, 7).convert_scale(10, M::QuickTime);
+        assert_eq!(t.value, -8);
+        let t = cm::Time::new(-1, 100).convert_scale(10, M::QuickTime);
+        assert_eq!(t.value, -1);
+        assert_eq!(cm::TimeRoundingMethod::default(), M::RoundHalfAwayFromZero);
+
+        let t = cm::Time::with_secs(0.1, 3);
+        assert_eq!((t.value, t.scale), (0, 3));
+        assert!(t.has_been_rounded());
+        let t = cm::Time::with_secs(1.5, 2);
+        assert_eq!((t.value, t.scale), (3, 2));
+        assert!(!t.has_been_rounded());
+        let t = cm::Time::with_secs(1e300, 600);
+        assert!(t.is_pos_infinity());
+        let t = cm::Time::with_secs(1e15, 90000);
+        assert!(t.scale < 90000);
+        assert_eq!(t.as_secs(), 1e15);
+    }
+
+    #[test]
+    fn non_numeric() {
+        let inf = cm::Time::infinity();
+        let neg_inf = cm::Time::neg_infinity();
+        let indefinite = cm::Time::indefinit();
+        let invalid = cm::Time::invalid();
+        let one = cm::Time::new(1, 1);
+
+        assert!(inf.add(inf).is_pos_infinity());
+        assert!(inf.add(neg_inf).is_invalid());
+        assert!(inf.sub(neg_inf).is_pos_infinity());
+        assert!(inf.sub(inf).is_invalid());
+        assert!(neg_inf.sub(one).is_neg_infinity());
+        assert!(inf.add(indefinite).is_pos_infinity());
+        assert!(one.add(indefinite).is_indefinite());
+        assert!(one.add(invalid).is_invalid());
+        assert!(indefinite.add(invalid).is_invalid());
+
+        assert!(inf.mul_i32(-2).is_neg_infinity());
+        assert!(inf.mul_i32(0).is_invalid());
+        assert!(indefinite.mul_f64(2.0).is_indefinite());
+        assert!(one.mul_f64(f64::INFINITY).is_pos_infinity());
+        assert!(neg_inf.abs().is_pos_infinity());
+        assert!(
+            indefinite
+                .convert_scale(10, Default::default())
+                .is_indefinite()
+        );
+
+        assert!(indefinite.as_secs().is_nan());
+        assert!(invalid.as_secs().is_nan());
+        assert_eq!(neg_inf.as_secs(), f64::NEG_INFINITY);
+
+        let mut sorted = [invalid, inf, one, indefinite, neg_inf, cm::Time::zero()];
+        sorted.sort();
+        assert!(sorted[0].is_neg_infinity());
+        assert_eq!(sorted[1], cm::Time::zero());
+        assert_eq!(sorted[2], one);
+        assert!(sorted[3].is_indefinite());
+        assert!(sorted[4].is_pos_infinity());
+        assert!(sorted[5].is_invalid());
+    }
+
+    #[cfg(target_vendor = "apple")]
+    fn same(l: cm::Time, r: cm::Time) -> bool {
+        if l.is_numeric() {
+            r.is_numeric()
+                && l.value == r.value
+                && l.scale == r.scale
+                && l.epoch == r.epoch
+                && l.has_been_rounded() == r.has_been_rounded()
+        } else {
+            l.flags == r.flags
+        }
+    }
+
+    #[cfg(target_vendor = "apple")]
+    #[test]
+    fn cross_check() {
+        unsafe {
+            assert!(same(cm::Time::zero(), super::kCMTimeZero));
+            assert!(same(cm::Time::invalid(), super::kCMTimeInvalid));
+            assert!(same(cm::Time::indefinit(), super::kCMTimeIndefinite));
+            assert!(same(cm::Time::infinity(), super::kCMTimePositiveInfinity));
+            assert!(same(
+                cm::Time::neg_infinity(),
+                super::kCMTimeNegativeInfinity
+            ));
+        }
+
+        let times = [
+            cm::Time::new(0, 1),
+            cm::Time::new(1, 3),
+            cm::Time::new(-7, 4),
+            cm::Time::new(1001, 30000),
+            cm::Time::new(-5, 90000),
+            cm::Time::new(i64::MAX, 1000),
+            cm::Time::new(i64::MIN + 1, 7),
+            cm::Time::new(1, cm::TIME_SCALE_MAX),
+            cm::Time::new(3, cm::TIME_SCALE_MAX - 1),
+            cm::Time::with_epoch(10, 600, 1),
+            cm::Time::with_epoch(10, 600, 2),
+            cm::Time::with_secs(0.1, 3),
+            cm::Time::infinity(),
+            cm::Time::neg_infinity(),
+            cm::Time::indefinit(),
+            cm::Time::invalid(),
+        ];
+        let methods = [
+            cm::TimeRoundingMethod::RoundHalfAwayFromZero,
+            cm::TimeRoundingMethod::RoundTowardZero,
+            cm::TimeRoundingMethod::RoundAwayFromZero,
+            cm::TimeRoundingMethod::QuickTime,
+            cm::TimeRoundingMethod::RoundTowardPositiveInfinity,
+            cm::TimeRoundingMethod::RoundTowardNegativeInfinity,
+        ];
+        for a in times {
+            assert!(same(a.abs(), a.cm_abs()), "abs {a:?}");
+            for m in [0, 1, -1, 3, 1000, i32::MAX] {
+                assert!(same(a.mul_i32(m), a.cm_mul_i32(m)), "{a:?} * {m}");
+            }
+            for m in [0.0, 0.5, -1.5, 1e10] {
+                assert!(same(a.mul_f64(m), a.cm_mul_f64(m)), "{a:?} * {m}");
+            }
+            for scale in [1, 2, 3, 10, 600, 44100] {
+                for method in methods {
+                    let l = a.convert_scale(scale, method);
+                    let r = a.cm_convert_scale(scale, method);
+                    assert!(same(l, r), "{a:?} to {scale} {method:?}: {l:?} {r:?}");
+                }
+            }
+            for b in times {
+                assert!(same(a.add(b), a.cm_add(b)), "{a:?} + {b:?}");
+                assert!(same(a.sub(b), a.cm_sub(b)), "{a:?} - {b:?}");
+                assert_eq!(a.compare(&b), a.cm_compare(&b), "{a:?} <> {b:?}");
+            }
+        }
+        for secs in [0.0, 0.1, -2.5, 1.0 / 3.0, 1e12, f64::INFINITY, f64::NAN] {
+            for scale in [1, 3, 600, 90000, cm::TIME_SCALE_MAX] {
+                let l = cm::Time::with_secs(secs, scale);
+                let r = cm::Time::cm_with_secs(secs, scale);
+                assert!(same(l, r), "{secs} {scale}: {l:?} {r:?}");
+            }
+        }
+    }
 }
 
+#[cfg(target_vendor = "apple")]
 #[link(name = "CoreMedia", kind = "framework")]
 unsafe extern "C-unwind" {
+    #[cfg(test)]
     static kCMTimeInvalid: Time;
+    #[cfg(test)]
     static kCMTimeIndefinite: Time;
+    #[cfg(test)]
     static kCMTimePositiveInfinity: Time;
+    #[cfg(test)]
     static kCMTimeNegativeInfinity: Time;
+    #[cfg(test)]
     static kCMTimeZero: Time;
 
     fn CMTimeMake(value: TimeValue, timescale: TimeScale) -> Time;
@@ -9,6 +9,18 @@ pub struct Range {
 }
 
 impl Range {
+    #[doc(alias = "CMTimeRangeMake")]
+    #[inline]
+    pub const fn new(start: cm::Time, duration: cm::Time) -> Self {
+        Self { start, duration }
+    }
+
+    #[doc(alias = "CMTimeRangeFromTimeToTime")]
+    #[inline]
+    pub fn with_start_end(start: cm::Time, end: cm::Time) -> Self {
+        Self::new(start, end.sub(start))
+    }
+
     #[doc(alias = "CMTIMERANGE_IS_INVALID")]
     #[inline]
     pub const fn is_valid(&self) -> bool {
@@ -28,36 +40,273 @@ impl Range {
         self.is_valid() && self.duration == cm::Time::zero()
     }
 
-    #[doc(alias = "CMTimeRangeContainsTime")]
+    /// `start + duration`, the first time after the range.
+    #[doc(alias = "CMTimeRangeGetEnd")]
     #[inline]
+    pub fn end(&self) -> cm::Time {
+        if !self.is_valid() {
+            return cm::Time::invalid();
+        }
+        self.start.add(self.duration)
+    }
+
+    /// `start <= time < end`
+    ///
+    /// ```
+    /// use cidre::cm;
+    ///
+    /// let range = cm::TimeRange::new(cm::Time::new(1, 1), cm::Time::new(2, 1));
+    /// assert!(range.contains_time(&cm::Time::new(10, 10)));
+    /// assert!(!range.contains_time(&cm::Time::new(3, 1)));
+    /// ```
+    #[doc(alias = "CMTimeRangeContainsTime")]
     pub fn contains_time(&self, time: &cm::Time) -> bool {
-        unsafe { CMTimeRangeContainsTime(*self, *time) }
+        self.is_valid() && time.is_valid() && *time >= self.start && *time < self.end()
+    }
+
+    #[doc(alias = "CMTimeRangeContainsTimeRange")]
+    pub fn contains_range(&self, other: &Self) -> bool {
+        self.is_valid()
+            && other.is_valid()
+            && other.start >= self.start
+            && other.end() <= self.end()
+    }
+
+    /// The smallest range containing both ranges.
+    #[doc(alias = "CMTimeRangeGetUnion")]
+    pub fn union(&self, other: &Self) -> Self {
+        if !self.is_valid() || !other.is_valid() {
+            return Self::invalid();
+        }
+        let start = cm::Time::min(self.start, other.start);
+        let end = cm::Time::max(self.end(), other.end());
+        Self::with_start_end(start, end)
+    }
+
+    /// Overlapping part of the ranges, `cm::TimeRange::zero()` if they don't overlap.
+    ///
+    /// ```
+    /// use cidre::cm;
+    ///
+    /// let a = cm::TimeRange::new(cm::Time::new(0, 1), cm::Time::new(3, 1));
+    /// let b = cm::TimeRange::new(cm::Time::new(2, 1), cm::Time::new(3, 1));
+    /// let c = a.intersection(&b);
+    /// assert_eq!(c.start, cm::Time::new(2, 1));
+    /// assert_eq!(c.duration, cm::Time::new(1, 1));
+    /// assert_eq!(a.union(&b).end(), cm::Time::new(5, 1));
+    /// ```
+    #[doc(alias = "CMTimeRangeGetIntersection")]
+    pub fn intersection(&self, other: &Self) -> Self {
+        if !self.is_valid() || !other.is_valid() {
+            return Self::invalid();
+        }
+        let start = cm::Time::max(self.start, other.start);
+        let end = cm::Time::min(self.end(), other.end());
+        if end < start {
+            return Self::zero();
+        }
+        Self::with_start_end(start, end)
+    }
+
+    /// Clamps the time to `start..=end`.
+    #[doc(alias = "CMTimeClampToRange")]
+    pub fn clamp(&self, time: cm::Time) -> cm::Time {
+        if !self.is_valid() || time.is_invalid() {
+            return cm::Time::invalid();
+        }
+        cm::Time::min(cm::Time::max(time, self.start), self.end())
     }
 
     #[doc(alias = "kCMTimeRangeZero")]
     #[inline]
     pub const fn zero() -> Self {
-        unsafe { kCMTimeRangeZero }
+        Self::new(cm::Time::zero(), cm::Time::zero())
     }
 
     #[doc(alias = "kCMTimeRangeInvalid")]
     #[inline]
     pub const fn invalid() -> Self {
-        unsafe { kCMTimeRangeInvalid }
+        Self::new(cm::Time::invalid(), cm::Time::invalid())
+    }
+
+    /// Maps the time so that `from.start` maps to `to.start` and `from.end()` to `to.end()`.
+    #[doc(alias = "CMTimeMapTimeFromRangeToRange")]
+    pub fn map_time(time: cm::Time, from: &Self, to: &Self) -> cm::Time {
+        if !from.is_valid() || !to.is_valid() {
+            return cm::Time::invalid();
+        }
+        Self::map_duration(time.sub(from.start), from, to).add(to.start)
+    }
+
+    /// Scales the duration by `to.duration / from.duration`.
+    #[doc(alias = "CMTimeMapDurationFromRangeToRange")]
+    pub fn map_duration(duration: cm::Time, from: &Self, to: &Self) -> cm::Time {
+        let (f, t) = (from.duration, to.duration);
+        if !duration.is_numeric() || !f.is_numeric() || !t.is_numeric() {
+            return cm::Time::invalid();
+        }
+        if f.value == 0 || duration.scale <= 0 {
+            return cm::Time::invalid();
+        }
+        // duration * (t.value / t.scale) / (f.value / f.scale)
+        let num = t.value as i128 * f.scale as i128;
+        let den = f.value as i128 * t.scale as i128;
+        let g = gcd(num, den);
+        let (num, den) = (num / g, den / g);
+        match (duration.value as i128).checked_mul(num) {
+            Some(n) => {
+                let (num, den) = if den < 0 { (-n, -den) } else { (n, den) };
+                let den = den * duration.scale as i128;
+                let g = gcd(num, den);
+                let (num, den) = (num / g, den / g);
+                // keep exact value if possible
+                let scale = den / gcd(den, duration.scale as i128) * duration.scale as i128;
+                let scale = scale.min(cm::TIME_SCALE_MAX as i128) as cm::TimeScale;
+                let rounded = duration.has_been_rounded();
+                cm::Time::with_rational_scale(num, den, scale, duration.epoch, rounded)
+            }
+            None => duration.mul_f64(num as f64 / den as f64),
+        }
     }
 }
 
+const fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+/// Maps times between source and target ranges of an edit.
+///
+/// Empty edit (no source media) has non numeric `source.start`.
+#[doc(alias = "CMTimeMapping")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
 pub struct Mapping {
     pub source: cm::TimeRange,
     pub target: cm::TimeRange,
 }
 
+impl Mapping {
+    #[doc(alias = "CMTimeMappingMake")]
+    #[inline]
+    pub const fn new(source: cm::TimeRange, target: cm::TimeRange) -> Self {
+        Self { source, target }
+    }
+
+    #[doc(alias = "CMTimeMappingMakeEmpty")]
+    #[inline]
+    pub const fn empty(target: cm::TimeRange) -> Self {
+        Self::new(cm::TimeRange::invalid(), target)
+    }
+
+    #[doc(alias = "kCMTimeMappingInvalid")]
+    #[inline]
+    pub const fn invalid() -> Self {
+        Self::new(cm::TimeRange::invalid(), cm::TimeRange::invalid())
+    }
+
+    #[doc(alias = "CMTIMEMAPPING_IS_VALID")]
+    #[inline]
+    pub const fn is_valid(&self) -> bool {
+        self.target.is_valid()
+    }
+
+    #[doc(alias = "CMTIMEMAPPING_IS_EMPTY")]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        !self.source.start.is_numeric() && self.target.is_valid()
+    }
+
+    /// Maps target time into source media time. Invalid for empty edits.
+    pub fn source_time(&self, target_time: cm::Time) -> cm::Time {
+        if self.is_empty() {
+            return cm::Time::invalid();
+        }
+        Range::map_time(target_time, &self.target, &self.source)
+    }
+
+    /// Maps source media time into target time.
+    pub fn target_time(&self, source_time: cm::Time) -> cm::Time {
+        if self.is_empty() {
+            return cm::Time::invalid();
+        }
+        Range::map_time(source_time, &self.source, &self.target)
+    }
+
+    /// Part of the edit whose target range lies inside `target`.
+    pub fn intersection(&self, target: &cm::TimeRange) -> Self {
+        let target = self.target.intersection(target);
+        if self.is_empty() || !target.is_valid() {
+            return Self::new(self.source, target);
+        }
+        let start = self.source_time(target.start);
+        let end = self.source_time(target.end());
+        Self::new(Range::with_start_end(start, end), target)
+    }
+}
+
+/// CoreMedia implementations, useful for cross-checking.
+#[cfg(target_vendor = "apple")]
+impl Range {
+    #[doc(alias = "CMTimeRangeContainsTime")]
+    #[inline]
+    pub fn cm_contains_time(&self, time: &cm::Time) -> bool {
+        unsafe { CMTimeRangeContainsTime(*self, *time) }
+    }
+
+    #[doc(alias = "CMTimeRangeContainsTimeRange")]
+    #[inline]
+    pub fn cm_contains_range(&self, other: &Self) -> bool {
+        unsafe { CMTimeRangeContainsTimeRange(*self, *other) }
+    }
+
+    #[doc(alias = "CMTimeRangeGetEnd")]
+    #[inline]
+    pub fn cm_end(&self) -> cm::Time {
+        unsafe { CMTimeRangeGetEnd(*self) }
+    }
+
+    #[doc(alias = "CMTimeRangeGetUnion")]
+    #[inline]
+    pub fn cm_union(&self, other: &Self) -> Self {
+        unsafe { CMTimeRangeGetUnion(*self, *other) }
+    }
+
+    #[doc(alias = "CMTimeRangeGetIntersection")]
+    #[inline]
+    pub fn cm_intersection(&self, other: &Self) -> Self {
+        unsafe { CMTimeRangeGetIntersection(*self, *other) }
+    }
+
+    #[doc(alias = "CMTimeMapTimeFromRangeToRange")]
+    #[inline]
+    pub fn cm_map_time(time: cm::Time, from: &Self, to: &Self) -> cm::Time {
+        unsafe { CMTimeMapTimeFromRangeToRange(time, *from, *to) }
+    }
+
+    #[doc(alias = "CMTimeMapDurationFromRangeToRange")]
+    #[inline]
+    pub fn cm_map_duration(duration: cm::Time, from: &Self, to: &Self) -> cm::Time {
+        unsafe { CMTimeMapDurationFromRangeToRange(duration, *from, *to) }
+    }
+}
+
+#[cfg(target_vendor = "apple")]
 #[link(name = "CoreMedia", kind = "framework")]
 unsafe extern "C-unwind" {
-    static kCMTimeRangeZero: Range;
-    static kCMTimeRangeInvalid: Range;
-
     fn CMTimeRangeContainsTime(range: Range, time: cm::Time) -> bool;
+    fn CMTimeRangeContainsTimeRange(range: Range, other_range: Range) -> bool;
+    fn CMTimeRangeGetEnd(range: Range) -> cm::Time;
+    fn CMTimeRangeGetUnion(range: Range, other_range: Range) -> Range;
+    fn CMTimeRangeGetIntersection(range: Range, other_range: Range) -> Range;
+    fn CMTimeMapTimeFromRangeToRange(t: cm::Time, from_range: Range, to_range: Range) -> cm::Time;
+    fn CMTimeMapDurationFromRangeToRange(
+        dur: cm::Time,
+        from_range: Range,
+        to_range: Range,
+    ) -> cm::Time;
 }
 
 #[cfg(test)]
@@ -73,5 +322,102 @@ mod tests {
         let range = cm::TimeRange::invalid();
         assert!(!range.is_valid());
         assert!(!range.is_empty());
+        assert_eq!(range.start.flags, cm::TimeFlags(0));
+    }
+
+    #[test]
+    fn ops() {
+        let secs = |v, s| cm::Time::new(v, s);
+        let a = cm::TimeRange::new(secs(1, 1), secs(2, 1));
+        let b = cm::TimeRange::new(secs(30, 10), secs(1, 2));
+        assert_eq!(a.end(), secs(3, 1));
+        assert!(!a.contains_time(&secs(3, 1)));
+        assert!(a.contains_time(&secs(2999, 1000)));
+        assert!(!a.contains_range(&b));
+
+        let u = a.union(&b);
+        assert_eq!(u.start, secs(1, 1));
+        assert_eq!(u.end(), secs(7, 2));
+
+        // touching ranges intersect in the empty range
+        let i = a.intersection(&b);
+        assert!(i.is_empty());
+        assert_eq!(i.start, secs(3, 1));
+
+        let c = cm::TimeRange::new(secs(10, 1), secs(1, 1));
+        assert_eq!(a.intersection(&c), cm::TimeRange::zero());
+        assert_eq!(a.clamp(secs(10, 1)), secs(3, 1));
+        assert_eq!(a.clamp(secs(-10, 1)), secs(1, 1));
+
+        let infinite = cm::TimeRange::new(cm::Time::zero(), cm::Time::infinity());
+        assert!(infinite.end().is_pos_infinity());
+        assert!(infinite.contains_range(&a));
+        assert!(infinite.contains_time(&secs(1 << 40, 1)));
+    }
+
+    #[test]
+    fn mapping() {
+        let secs = |v, s| cm::Time::new(v, s);
+        // 2x slow motion of source 10..12 into target 0..4
+        let source = cm::TimeRange::new(secs(10, 1), secs(2, 1));
+        let target = cm::TimeRange::new(secs(0, 1), secs(4, 1));
+        let mapping = cm::TimeMapping::new(source, target);
+        assert!(mapping.is_valid());
+        assert!(!mapping.is_empty());
+        assert_eq!(mapping.target_time(secs(11, 1)), secs(2, 1));
+        assert_eq!(mapping.source_time(secs(3, 1)), secs(23, 2));
+        assert_eq!(
+            cm::TimeRange::map_duration(secs(1, 3), &source, &target),
+            secs(2, 3)
+        );
+
+        let part = mapping.intersection(&cm::TimeRange::new(secs(1, 1), secs(10, 1)));
+        assert_eq!(part.target.start, secs(1, 1));
+        assert_eq!(part.target.duration, secs(3, 1));
+        assert_eq!(part.source.start, secs(21, 2));
+        assert_eq!(part.source.duration, secs(3, 2));
+
+        let empty = cm::TimeMapping::empty(target);
+        assert!(empty.is_empty());
+        assert!(empty.target_time(secs(1, 1)).is_invalid());
+    }
+
+    #[cfg(target_vendor = "apple")]
+    #[test]
+    fn cross_check() {
+        let secs = |v, s| cm::Time::new(v, s);
+        let ranges = [
+            cm::TimeRange::new(secs(1, 1), secs(2, 1)),
+            cm::TimeRange::new(secs(30, 10), secs(1, 2)),
+            cm::TimeRange::new(secs(-7, 3), secs(100, 7)),
+            cm::TimeRange::new(secs(10, 1), secs(1, 1)),
+            cm::TimeRange::new(cm::Time::zero(), cm::Time::infinity()),
+            cm::TimeRange::zero(),
+            cm::TimeRange::invalid(),
+        ];
+        let times = [
+            secs(0, 1),
+            secs(3, 1),
+            secs(5, 2),
+            secs(-1, 3),
+            cm::Time::infinity(),
+        ];
+        for a in &ranges {
+            assert_eq!(a.end(), a.cm_end());
+            for t in &times {
+                assert_eq!(a.contains_time(t), a.cm_contains_time(t));
+            }
+            for b in &ranges {
+                assert_eq!(a.contains_range(b), a.cm_contains_range(b));
+                assert_eq!(a.union(b), a.cm_union(b), "{a:?} {b:?}");
+                assert_eq!(a.intersection(b), a.cm_intersection(b), "{a:?} {b:?}");
+                if a.duration.is_numeric() && b.duration.is_numeric() && a.duration.value != 0 {
+                    let t = secs(7, 5);
+                    let l = cm::TimeRange::map_time(t, a, b);
+                    let r = cm::TimeRange::cm_map_time(t, a, b);
+                    assert_eq!(l.as_secs(), r.as_secs());
+                }
+            }
+        }
     }
 }
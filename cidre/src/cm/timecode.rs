@@ -0,0 +1,677 @@
+//! SMPTE ST 12-1 timecode with drop-frame arithmetic and `tmcd` media samples.
+//!
+//! [`Timecode`] is a frame count since `00:00:00:00` at a [`Rate`], labels are
+//! derived from it, so arithmetic stays correct across dropped labels.
+
+use crate::{
+    cm::{self, format_desc_err as err},
+    define_opts, os,
+};
+
+#[cfg(feature = "cat")]
+use crate::cat;
+
+#[cfg(target_vendor = "apple")]
+use crate::arc;
+
+/// Sample format of timecode media.
+#[doc(alias = "CMTimeCodeFormatType")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct FormatType(pub u32);
+
+impl FormatType {
+    /// Big-endian `i32` frame number.
+    #[doc(alias = "kCMTimeCodeFormatType_TimeCode32")]
+    pub const TIME_CODE_32: Self = Self(u32::from_be_bytes(*b"tmcd"));
+
+    /// Big-endian `i64` frame number.
+    #[doc(alias = "kCMTimeCodeFormatType_TimeCode64")]
+    pub const TIME_CODE_64: Self = Self(u32::from_be_bytes(*b"tc64"));
+
+    #[doc(alias = "kCMTimeCodeFormatType_Counter32")]
+    pub const COUNTER_32: Self = Self(u32::from_be_bytes(*b"cn32"));
+
+    #[doc(alias = "kCMTimeCodeFormatType_Counter64")]
+    pub const COUNTER_64: Self = Self(u32::from_be_bytes(*b"cn64"));
+}
+
+define_opts!(
+    /// Flags of timecode format description.
+    pub Flags(u32)
+);
+
+impl Flags {
+    #[doc(alias = "kCMTimeCodeFlag_DropFrame")]
+    pub const DROP_FRAME: Self = Self(1 << 0);
+
+    /// Timecode wraps at 24 hours.
+    #[doc(alias = "kCMTimeCodeFlag_24HourMax")]
+    pub const _24_HOUR_MAX: Self = Self(1 << 1);
+
+    #[doc(alias = "kCMTimeCodeFlag_NegTimesOK")]
+    pub const NEG_TIMES_OK: Self = Self(1 << 2);
+}
+
+/// Timecode rate, same fields as timecode format description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rate {
+    /// Duration of a frame in `time_scale` units.
+    pub frame_duration: i64,
+    pub time_scale: i32,
+
+    /// Nominal frames per second, frame labels count up to it.
+    pub frame_quanta: u32,
+
+    /// Skips frame labels 0 and 1 (0 to 3 at 60) of each minute except every tenth.
+    pub drop_frame: bool,
+}
+
+impl Rate {
+    pub const _23_976: Self = Self::new(1001, 24_000, 24, false);
+    pub const _24: Self = Self::new(1000, 24_000, 24, false);
+    pub const _25: Self = Self::new(1000, 25_000, 25, false);
+    pub const _29_97: Self = Self::new(1001, 30_000, 30, false);
+    pub const _29_97_DROP: Self = Self::new(1001, 30_000, 30, true);
+    pub const _30: Self = Self::new(1000, 30_000, 30, false);
+    pub const _30_DROP: Self = Self::new(1000, 30_000, 30, true);
+    pub const _50: Self = Self::new(1000, 50_000, 50, false);
+    pub const _59_94: Self = Self::new(1001, 60_000, 60, false);
+    pub const _59_94_DROP: Self = Self::new(1001, 60_000, 60, true);
+    pub const _60: Self = Self::new(1000, 60_000, 60, false);
+    pub const _60_DROP: Self = Self::new(1000, 60_000, 60, true);
+
+    #[inline]
+    pub const fn new(
+        frame_duration: i64,
+        time_scale: i32,
+        frame_quanta: u32,
+        drop_frame: bool,
+    ) -> Self {
+        Self {
+            frame_duration,
+            time_scale,
+            frame_quanta,
+            drop_frame,
+        }
+    }
+
+    /// Rate of timecode format description fields.
+    pub fn with_tmcd(frame_duration: cm::Time, frame_quanta: u32, flags: Flags) -> Self {
+        Self::new(
+            frame_duration.value,
+            frame_duration.scale,
+            frame_quanta,
+            flags.contains(Flags::DROP_FRAME),
+        )
+    }
+
+    /// Drop frame needs multiple of 30 frame quanta.
+    pub fn is_valid(&self) -> bool {
+        self.frame_duration > 0
+            && self.time_scale > 0
+            && self.frame_quanta > 0
+            && (!self.drop_frame || self.frame_quanta.is_multiple_of(30))
+    }
+
+    #[inline]
+    pub fn frame_duration(&self) -> cm::Time {
+        cm::Time::new(self.frame_duration, self.time_scale)
+    }
+
+    #[inline]
+    pub fn fps(&self) -> f64 {
+        self.time_scale as f64 / self.frame_duration as f64
+    }
+
+    /// Frame labels skipped at the start of minute.
+    #[inline]
+    pub fn dropped_labels(&self) -> u32 {
+        if self.drop_frame {
+            self.frame_quanta / 15
+        } else {
+            0
+        }
+    }
+
+    pub fn frames_per_hour(&self) -> i64 {
+        self.frame_quanta as i64 * 3600 - self.dropped_labels() as i64 * 54
+    }
+
+    #[inline]
+    pub fn frames_per_day(&self) -> i64 {
+        self.frames_per_hour() * 24
+    }
+
+    /// Flags for timecode format description.
+    pub fn flags(&self) -> Flags {
+        let mut res = Flags::_24_HOUR_MAX;
+        if self.drop_frame {
+            res |= Flags::DROP_FRAME;
+        }
+        res
+    }
+
+    /// Timecode format description of the rate.
+    #[cfg(target_vendor = "apple")]
+    pub fn format_desc(
+        &self,
+        format_type: FormatType,
+    ) -> os::Result<arc::R<cm::TimeCodeFormatDesc>> {
+        cm::TimeCodeFormatDesc::with_time_code(
+            format_type,
+            self.frame_duration(),
+            self.frame_quanta,
+            self.flags(),
+            None,
+        )
+    }
+
+    /// Rate of timecode format description.
+    #[cfg(target_vendor = "apple")]
+    pub fn with_format_desc(desc: &cm::TimeCodeFormatDesc) -> Self {
+        Self::with_tmcd(
+            desc.time_code_frame_duration(),
+            desc.time_code_frame_quanta(),
+            desc.time_code_flags(),
+        )
+    }
+
+    #[cfg(feature = "cat")]
+    pub fn with_smpte_type(val: cat::audio::SMPTETimeType) -> Option<Self> {
+        use cat::audio::SMPTETimeType as T;
+        Some(match val {
+            T::_23_98 => Self::_23_976,
+            T::_24 => Self::_24,
+            T::_25 => Self::_25,
+            T::_29_97 => Self::_29_97,
+            T::_29_97_DROP => Self::_29_97_DROP,
+            T::_30 => Self::_30,
+            T::_30_DROP => Self::_30_DROP,
+            T::_50 => Self::_50,
+            T::_59_94 => Self::_59_94,
+            T::_59_94_DROP => Self::_59_94_DROP,
+            T::_60 => Self::_60,
+            T::_60_DROP => Self::_60_DROP,
+            _ => return None,
+        })
+    }
+
+    #[cfg(feature = "cat")]
+    pub fn smpte_type(&self) -> Option<cat::audio::SMPTETimeType> {
+        use cat::audio::SMPTETimeType as T;
+        if !self.is_valid() {
+            return None;
+        }
+        let frames_duration = self.frame_duration as i128 * self.frame_quanta as i128;
+        let ntsc = frames_duration * 1000 == self.time_scale as i128 * 1001;
+        let exact = frames_duration == self.time_scale as i128;
+        Some(match (self.frame_quanta, self.drop_frame, ntsc, exact) {
+            (24, false, true, _) => T::_23_98,
+            (24, false, _, true) => T::_24,
+            (25, false, _, true) => T::_25,
+            (30, false, true, _) => T::_29_97,
+            (30, true, true, _) => T::_29_97_DROP,
+            (30, false, _, true) => T::_30,
+            (30, true, _, true) => T::_30_DROP,
+            (50, false, _, true) => T::_50,
+            (60, false, true, _) => T::_59_94,
+            (60, true, true, _) => T::_59_94_DROP,
+            (60, false, _, true) => T::_60,
+            (60, true, _, true) => T::_60_DROP,
+            _ => return None,
+        })
+    }
+}
+
+/// `HH:MM:SS:FF` fields of a timecode.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Labels {
+    pub negative: bool,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+    pub frames: u32,
+}
+
+/// Frame number since `00:00:00:00` at a rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timecode {
+    pub frame: i64,
+    pub rate: Rate,
+}
+
+impl Timecode {
+    #[inline]
+    pub const fn new(frame: i64, rate: Rate) -> Self {
+        Self { frame, rate }
+    }
+
+    /// Fails on out of range fields and labels dropped by drop frame rate.
+    pub fn with_labels(labels: &Labels, rate: Rate) -> os::Result<Self> {
+        if !rate.is_valid() {
+            return Err(err::INVALID_PARAM);
+        }
+        let Labels {
+            negative,
+            hours,
+            minutes,
+            seconds,
+            frames,
+        } = *labels;
+        let dropped = rate.dropped_labels();
+        if minutes >= 60
+            || seconds >= 60
+            || frames >= rate.frame_quanta
+            || (seconds == 0 && !minutes.is_multiple_of(10) && frames < dropped)
+        {
+            return Err(err::INVALID_PARAM);
+        }
+        let total_minutes = hours as i64 * 60 + minutes as i64;
+        let secs = total_minutes * 60 + seconds as i64;
+        let frame = secs * rate.frame_quanta as i64 + frames as i64
+            - dropped as i64 * (total_minutes - total_minutes / 10);
+        Ok(Self::new(if negative { -frame } else { frame }, rate))
+    }
+
+    /// Hours saturate at `u32::MAX`.
+    pub fn labels(&self) -> Labels {
+        // wide enough to add dropped labels to any frame number
+        let q = self.rate.frame_quanta.max(1) as i128;
+        let d = self.rate.dropped_labels() as i128;
+        let mut f = self.frame.unsigned_abs() as i128;
+        if d > 0 {
+            let per_10_min = q * 600 - d * 9;
+            let per_min = q * 60 - d;
+            let tens = f / per_10_min;
+            let rem = f % per_10_min;
+            f += d * 9 * tens;
+            if rem > d {
+                f += d * ((rem - d) / per_min);
+            }
+        }
+        Labels {
+            negative: self.frame < 0,
+            hours: u32::try_from(f / (q * 3600)).unwrap_or(u32::MAX),
+            minutes: (f / (q * 60) % 60) as u32,
+            seconds: (f / q % 60) as u32,
+            frames: (f % q) as u32,
+        }
+    }
+
+    /// Time of the frame start, infinite if it overflows `cm::TimeValue`.
+    pub fn time(&self) -> cm::Time {
+        match self.frame.checked_mul(self.rate.frame_duration) {
+            Some(value) => cm::Time::new(value, self.rate.time_scale),
+            None if (self.frame < 0) != (self.rate.frame_duration < 0) => cm::Time::neg_infinity(),
+            None => cm::Time::infinity(),
+        }
+    }
+
+    /// Frame containing `time`.
+    pub fn with_time(time: cm::Time, rate: Rate) -> Option<Self> {
+        if !time.is_numeric() || time.scale <= 0 || !rate.is_valid() {
+            return None;
+        }
+        let num = time.value as i128 * rate.time_scale as i128;
+        let den = time.scale as i128 * rate.frame_duration as i128;
+        let frame = num.div_euclid(den);
+        Some(Self::new(i64::try_from(frame).ok()?, rate))
+    }
+
+    /// Frames of `val` at this rate, converted through time if rates differ.
+    fn frames_of(&self, val: &Self) -> i64 {
+        if val.rate == self.rate {
+            return val.frame;
+        }
+        Self::with_time(val.time(), self.rate).map_or(0, |t| t.frame)
+    }
+
+    #[inline]
+    pub fn add_frames(self, frames: i64) -> Self {
+        Self::new(self.frame + frames, self.rate)
+    }
+
+    /// Wraps into `00:00:00:00..24:00:00:00`, timecode of invalid rate is returned as is.
+    pub fn wrapped_day(self) -> Self {
+        if !self.rate.is_valid() {
+            return self;
+        }
+        Self::new(self.frame.rem_euclid(self.rate.frames_per_day()), self.rate)
+    }
+
+    /// Parses `HH:MM:SS:FF`, frames may be separated with `;`, `.` or `,`
+    /// as drop frame timecode is often written, `-` prefix is negative.
+    pub fn parse(s: &str, rate: Rate) -> os::Result<Self> {
+        let (negative, s) = match s.trim().strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s.trim()),
+        };
+        let mut fields = [0u32; 4];
+        let mut parts = s.split([':', ';', '.', ',']);
+        for field in fields.iter_mut() {
+            let part = parts.next().ok_or(err::INVALID_PARAM)?;
+            if part.is_empty() || !part.bytes().all(|c| c.is_ascii_digit()) {
+                return Err(err::INVALID_PARAM);
+            }
+            *field = part.parse().map_err(|_| err::INVALID_PARAM)?;
+        }
+        if parts.next().is_some() {
+            return Err(err::INVALID_PARAM);
+        }
+        let [hours, minutes, seconds, frames] = fields;
+        let labels = Labels {
+            negative,
+            hours,
+            minutes,
+            seconds,
+            frames,
+        };
+        Self::with_labels(&labels, rate)
+    }
+
+    /// `tmcd` sample of [`FormatType::TIME_CODE_32`] and [`FormatType::COUNTER_32`],
+    /// fails if the frame doesn't fit `i32`.
+    #[inline]
+    pub fn to_tmcd32(&self) -> os::Result<[u8; 4]> {
+        let frame = i32::try_from(self.frame).map_err(|_| err::INVALID_PARAM)?;
+        Ok(frame.to_be_bytes())
+    }
+
+    /// `tc64` sample of [`FormatType::TIME_CODE_64`] and [`FormatType::COUNTER_64`].
+    #[inline]
+    pub fn to_tmcd64(&self) -> [u8; 8] {
+        self.frame.to_be_bytes()
+    }
+
+    pub fn with_tmcd32(sample: &[u8], rate: Rate) -> os::Result<Self> {
+        let b = sample.get(..4).ok_or(err::INVALID_PARAM)?;
+        let frame = i32::from_be_bytes(b.try_into().unwrap());
+        Ok(Self::new(frame as i64, rate))
+    }
+
+    /// Fails on frames which start time doesn't fit `cm::TimeValue`.
+    pub fn with_tmcd64(sample: &[u8], rate: Rate) -> os::Result<Self> {
+        let b = sample.get(..8).ok_or(err::INVALID_PARAM)?;
+        let frame = i64::from_be_bytes(b.try_into().unwrap());
+        if frame.checked_mul(rate.frame_duration).is_none() {
+            return Err(err::INVALID_PARAM);
+        }
+        Ok(Self::new(frame, rate))
+    }
+
+    /// Sample payload of `format_type`.
+    pub fn to_tmcd(&self, format_type: FormatType) -> os::Result<Vec<u8>> {
+        match format_type {
+            FormatType::TIME_CODE_64 | FormatType::COUNTER_64 => Ok(self.to_tmcd64().to_vec()),
+            _ => Ok(self.to_tmcd32()?.to_vec()),
+        }
+    }
+
+    pub fn with_tmcd(sample: &[u8], format_type: FormatType, rate: Rate) -> os::Result<Self> {
+        match format_type {
+            FormatType::TIME_CODE_32 | FormatType::COUNTER_32 => Self::with_tmcd32(sample, rate),
+            FormatType::TIME_CODE_64 | FormatType::COUNTER_64 => Self::with_tmcd64(sample, rate),
+            _ => Err(err::INVALID_PARAM),
+        }
+    }
+
+    /// Wrapped into 24 hours, `None` for rates without [`cat::audio::SMPTETimeType`].
+    #[cfg(feature = "cat")]
+    pub fn smpte_time(&self) -> Option<cat::audio::SMPTETime> {
+        let r#type = self.rate.smpte_type()?;
+        let labels = self.wrapped_day().labels();
+        Some(cat::audio::SMPTETime {
+            r#type,
+            flags: cat::audio::SMPTETimeFlags::VALID,
+            hours: labels.hours as i16,
+            minutes: labels.minutes as i16,
+            seconds: labels.seconds as i16,
+            frames: labels.frames as i16,
+            ..Default::default()
+        })
+    }
+
+    /// Subframes are truncated.
+    #[cfg(feature = "cat")]
+    pub fn with_smpte_time(val: &cat::audio::SMPTETime) -> os::Result<Self> {
+        let rate = Rate::with_smpte_type(val.r#type).ok_or(err::INVALID_PARAM)?;
+        let field = |v: i16| u32::try_from(v).map_err(|_| err::INVALID_PARAM);
+        let labels = Labels {
+            negative: false,
+            hours: field(val.hours)?,
+            minutes: field(val.minutes)?,
+            seconds: field(val.seconds)?,
+            frames: field(val.frames)?,
+        };
+        Self::with_labels(&labels, rate)
+    }
+}
+
+/// `rhs` is a duration, the result keeps rate of `self`.
+impl std::ops::Add for Timecode {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.add_frames(self.frames_of(&rhs))
+    }
+}
+
+impl std::ops::Sub for Timecode {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.add_frames(-self.frames_of(&rhs))
+    }
+}
+
+impl std::fmt::Display for Timecode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let l = self.labels();
+        let sign = if l.negative { "-" } else { "" };
+        let sep = if self.rate.drop_frame { ';' } else { ':' };
+        write!(
+            f,
+            "{sign}{:02}:{:02}:{:02}{sep}{:02}",
+            l.hours, l.minutes, l.seconds, l.frames
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::{
+        self,
+        timecode::{FormatType, Labels, Rate, Timecode},
+    };
+
+    const RATES: [Rate; 12] = [
+        Rate::_23_976,
+        Rate::_24,
+        Rate::_25,
+        Rate::_29_97,
+        Rate::_29_97_DROP,
+        Rate::_30,
+        Rate::_30_DROP,
+        Rate::_50,
+        Rate::_59_94,
+        Rate::_59_94_DROP,
+        Rate::_60,
+        Rate::_60_DROP,
+    ];
+
+    #[test]
+    fn drop_frame_labels() {
+        let tc = |frame| Timecode::new(frame, Rate::_29_97_DROP).to_string();
+        assert_eq!(tc(1799), "00:00:59;29");
+        assert_eq!(tc(1800), "00:01:00;02");
+        assert_eq!(tc(17982), "00:10:00;00");
+        assert_eq!(tc(107892), "01:00:00;00");
+        assert_eq!(tc(-1800), "-00:01:00;02");
+        assert_eq!(Rate::_29_97_DROP.frames_per_day(), 2_589_408);
+
+        let tc = |frame| Timecode::new(frame, Rate::_59_94_DROP).to_string();
+        assert_eq!(tc(3599), "00:00:59;59");
+        assert_eq!(tc(3600), "00:01:00;04");
+        assert_eq!(Timecode::new(1800, Rate::_29_97).to_string(), "00:01:00:00");
+
+        // labels skipped by drop frame
+        assert!(Timecode::parse("00:01:00;00", Rate::_29_97_DROP).is_err());
+        assert!(Timecode::parse("00:10:00;00", Rate::_29_97_DROP).is_ok());
+
+        for rate in RATES {
+            for frame in (0..rate.frames_per_day()).step_by(997) {
+                let tc = Timecode::new(frame, rate);
+                let back = Timecode::with_labels(&tc.labels(), rate).unwrap();
+                assert_eq!(back, tc, "{rate:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn parse_and_arithmetic() {
+        let rate = Rate::_29_97_DROP;
+        let a = Timecode::parse("00:00:59;29", rate).unwrap();
+        let b = Timecode::parse("00:00:00:01", rate).unwrap();
+        assert_eq!((a + b).to_string(), "00:01:00;02");
+        assert_eq!(a + b - b, a);
+        assert_eq!((b - a).to_string(), "-00:00:59;28");
+        assert_eq!(
+            Timecode::parse("23:59:59;29", rate)
+                .unwrap()
+                .add_frames(1)
+                .wrapped_day()
+                .frame,
+            0
+        );
+        let invalid = Timecode::new(-5, Rate::new(1, 30, 0, false));
+        assert_eq!(invalid.wrapped_day(), invalid);
+
+        // one second at 25 is 30 frames at 30
+        let sec = Timecode::parse("00:00:01:00", Rate::_25).unwrap();
+        let tc = Timecode::new(0, Rate::_30) + sec;
+        assert_eq!(tc.frame, 30);
+
+        assert!(Timecode::parse("01:02:03", rate).is_err());
+        assert!(Timecode::parse("01:02:03:04:05", rate).is_err());
+        assert!(Timecode::parse("01:60:03:04", rate).is_err());
+        assert!(Timecode::parse("01:02:03:30", rate).is_err());
+        assert!(Timecode::parse("01:+2:03:04", rate).is_err());
+        assert_eq!(
+            Timecode::parse(" -01:02:03.04", rate).unwrap().labels(),
+            Labels {
+                negative: true,
+                hours: 1,
+                minutes: 2,
+                seconds: 3,
+                frames: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn time() {
+        let tc = Timecode::parse("01:00:00;00", Rate::_29_97_DROP).unwrap();
+        let time = tc.time();
+        assert_eq!(time, cm::Time::new(107_892 * 1001, 30_000));
+        // drop frame keeps labels close to wall clock
+        assert!((time.as_secs() - 3600.0).abs() < 0.004);
+
+        for rate in RATES {
+            let tc = Timecode::new(123_457, rate);
+            assert_eq!(Timecode::with_time(tc.time(), rate), Some(tc));
+            let mid = tc.time().add(cm::Time::new(1, rate.time_scale));
+            assert_eq!(Timecode::with_time(mid, rate), Some(tc));
+        }
+        assert_eq!(
+            Timecode::with_time(cm::Time::new(-1, 600), Rate::_24).map(|t| t.frame),
+            Some(-1)
+        );
+        assert_eq!(Timecode::with_time(cm::Time::invalid(), Rate::_24), None);
+    }
+
+    #[test]
+    fn tmcd() {
+        let tc = Timecode::parse("10:00:00:00", Rate::_25).unwrap();
+        assert_eq!(tc.to_tmcd32(), Ok(900_000i32.to_be_bytes()));
+        assert_eq!(
+            Timecode::with_tmcd(
+                &tc.to_tmcd(FormatType::TIME_CODE_32).unwrap(),
+                FormatType::TIME_CODE_32,
+                Rate::_25
+            ),
+            Ok(tc)
+        );
+        assert_eq!(
+            Timecode::with_tmcd(
+                &tc.to_tmcd(FormatType::TIME_CODE_64).unwrap(),
+                FormatType::TIME_CODE_64,
+                Rate::_25
+            ),
+            Ok(tc)
+        );
+        assert_eq!(tc.to_tmcd(FormatType::COUNTER_64).unwrap().len(), 8);
+        assert!(Timecode::with_tmcd32(&[0, 1], Rate::_25).is_err());
+
+        // out of range frames
+        let big = Timecode::new(i32::MAX as i64 + 1, Rate::_25);
+        assert!(big.to_tmcd32().is_err());
+        assert!(big.to_tmcd(FormatType::COUNTER_32).is_err());
+        assert_eq!(big.to_tmcd64(), (i32::MAX as i64 + 1).to_be_bytes());
+        let max = i64::MAX.to_be_bytes();
+        assert!(Timecode::with_tmcd64(&max, Rate::_29_97_DROP).is_err());
+        let frame = i64::MAX / 1001;
+        let tc = Timecode::with_tmcd64(&frame.to_be_bytes(), Rate::_29_97_DROP).unwrap();
+        assert_eq!(tc.time().value, frame * 1001);
+
+        for frame in [i64::MAX, i64::MIN] {
+            let tc = Timecode::new(frame, Rate::_29_97_DROP);
+            assert!(!tc.to_string().is_empty());
+            assert_eq!(tc.labels().negative, frame < 0);
+        }
+        assert!(
+            Timecode::new(i64::MAX, Rate::_29_97_DROP)
+                .time()
+                .is_pos_infinity()
+        );
+        assert!(Timecode::new(i64::MIN, Rate::_24).time().is_neg_infinity());
+        let tc = Timecode::new(i64::MAX, Rate::new(1, 1, 1, false));
+        assert_eq!(tc.labels().hours, u32::MAX);
+
+        let rate = Rate::with_tmcd(cm::Time::new(1001, 30_000), 30, Rate::_29_97_DROP.flags());
+        assert_eq!(rate, Rate::_29_97_DROP);
+    }
+
+    #[cfg(feature = "cat")]
+    #[test]
+    fn smpte_time() {
+        use crate::cat::audio::{SMPTETime, SMPTETimeType};
+
+        for rate in RATES {
+            let smpte_type = rate.smpte_type().unwrap();
+            assert_eq!(Rate::with_smpte_type(smpte_type), Some(rate));
+        }
+        assert_eq!(Rate::new(1, 48, 48, false).smpte_type(), None);
+        assert_eq!(Rate::new(0, 0, 30, false).smpte_type(), None);
+        assert!(
+            Timecode::new(5, Rate::new(1, 30, 0, false))
+                .smpte_time()
+                .is_none()
+        );
+
+        let tc = Timecode::parse("01:02:03;04", Rate::_59_94_DROP).unwrap();
+        let smpte = tc.smpte_time().unwrap();
+        assert_eq!(smpte.r#type, SMPTETimeType::_59_94_DROP);
+        assert_eq!(
+            (smpte.hours, smpte.minutes, smpte.seconds, smpte.frames),
+            (1, 2, 3, 4)
+        );
+        assert_eq!(Timecode::with_smpte_time(&smpte), Ok(tc));
+
+        let invalid = SMPTETime {
+            r#type: SMPTETimeType(100),
+            ..Default::default()
+        };
+        assert!(Timecode::with_smpte_time(&invalid).is_err());
+    }
+}
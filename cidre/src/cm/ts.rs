@@ -0,0 +1,811 @@
+//! MPEG-2 Transport Stream (ISO/IEC 13818-1) muxer for H.264, HEVC and AAC.
+//!
+//! Access units come through [`fmp4::Sample`] trait, so the same `cm::SampleBuf`
+//! stream may feed both fMP4 and TS outputs. Packetizer is pure, output depends
+//! only on the input, which keeps it testable without CoreMedia.
+
+use crate::{
+    cm::{self, fmp4, format_desc_err as err, nal},
+    os,
+};
+
+#[cfg(feature = "cat")]
+use crate::cat;
+
+pub const PACKET_LEN: usize = 188;
+
+const SYNC_BYTE: u8 = 0x47;
+const PAYLOAD_LEN: usize = PACKET_LEN - 4;
+const PAT_PID: u16 = 0x0000;
+const NULL_PID: u16 = 0x1fff;
+
+/// PID of the only program map table.
+pub const PMT_PID: u16 = 0x1000;
+
+/// PTS, DTS and PCR base are 33 bit wide.
+const TS_MASK: i64 = (1 << 33) - 1;
+
+/// `stream_type` of PMT entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct StreamType(pub u8);
+
+impl StreamType {
+    /// AAC with ADTS transport syntax.
+    pub const AAC_ADTS: Self = Self(0x0f);
+    pub const H264: Self = Self(0x1b);
+    pub const HEVC: Self = Self(0x24);
+
+    pub fn with_video_codec(codec: cm::VideoCodec) -> Option<Self> {
+        match nal::NalCodec::with_video_codec(codec)? {
+            nal::NalCodec::H264 => Some(Self::H264),
+            nal::NalCodec::Hevc => Some(Self::HEVC),
+        }
+    }
+
+    #[cfg(feature = "cat")]
+    pub fn with_audio_format(format: cat::audio::Format) -> Option<Self> {
+        match format {
+            cat::audio::Format::MPEG4_AAC => Some(Self::AAC_ADTS),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn is_video(&self) -> bool {
+        *self == Self::H264 || *self == Self::HEVC
+    }
+}
+
+/// Elementary stream input format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Codec {
+    /// Length prefixed NAL units, converted to Annex-B with access unit delimiter.
+    /// `param_sets` are inserted before IDR pictures which don't carry them.
+    Video {
+        codec: nal::NalCodec,
+        nal_unit_len: u8,
+        param_sets: Vec<Vec<u8>>,
+    },
+
+    /// Raw AAC frames, prefixed with ADTS header of the core config.
+    #[cfg(feature = "cat")]
+    Aac(cat::audio::aac::Asc),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stream {
+    pub pid: u16,
+    pub codec: Codec,
+}
+
+impl Stream {
+    pub fn avc(pid: u16, cfg: &cm::AvcCfg) -> Self {
+        Self {
+            pid,
+            codec: Codec::Video {
+                codec: nal::NalCodec::H264,
+                nal_unit_len: cfg.nal_unit_len,
+                param_sets: cfg.param_sets().map(|ps| ps.to_vec()).collect(),
+            },
+        }
+    }
+
+    pub fn hevc(pid: u16, cfg: &cm::HevcCfg) -> Self {
+        Self {
+            pid,
+            codec: Codec::Video {
+                codec: nal::NalCodec::Hevc,
+                nal_unit_len: cfg.nal_unit_len,
+                param_sets: cfg.param_sets().map(|ps| ps.to_vec()).collect(),
+            },
+        }
+    }
+
+    /// AAC stream from AudioSpecificConfig.
+    ///
+    /// ADTS can't signal explicit sampling frequency and object types above 4,
+    /// HE-AAC (SBR, PS) is written as its core AAC LC stream.
+    #[cfg(feature = "cat")]
+    pub fn aac(pid: u16, asc: &[u8]) -> os::Result<Self> {
+        let asc = cat::audio::aac::Asc::from_bytes(asc).map_err(|_| err::INVALID_PARAM)?;
+        cat::audio::aac::Adts::with_asc(&asc, 0).map_err(|_| err::INVALID_PARAM)?;
+        Ok(Self {
+            pid,
+            codec: Codec::Aac(asc),
+        })
+    }
+
+    /// H.264, HEVC or AAC stream from format description of compressed samples.
+    #[cfg(target_vendor = "apple")]
+    pub fn with_format_desc(pid: u16, desc: &cm::FormatDesc) -> os::Result<Self> {
+        match desc.media_sub_type().to_be_bytes() {
+            [b'a', b'v', b'c', b'1'] => Ok(Self::avc(pid, &desc.avc_cfg()?)),
+            [b'h', b'v', b'c', b'1'] | [b'h', b'e', b'v', b'1'] => {
+                Ok(Self::hevc(pid, &desc.hevc_cfg()?))
+            }
+            #[cfg(feature = "cat")]
+            [b'a', b'a', b'c', b' '] => {
+                let Some(cookie) = desc.magic_cookie() else {
+                    return Err(err::VALUE_NOT_AVAILABLE);
+                };
+                let asc = fmp4::asc_from_cookie(cookie).ok_or(err::INVALID_PARAM)?;
+                Self::aac(pid, &asc)
+            }
+            _ => Err(err::INVALID_PARAM),
+        }
+    }
+
+    pub fn stream_type(&self) -> StreamType {
+        match &self.codec {
+            Codec::Video {
+                codec: nal::NalCodec::H264,
+                ..
+            } => StreamType::H264,
+            Codec::Video {
+                codec: nal::NalCodec::Hevc,
+                ..
+            } => StreamType::HEVC,
+            #[cfg(feature = "cat")]
+            Codec::Aac(_) => StreamType::AAC_ADTS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MuxerCfg {
+    /// Added to PTS and DTS while PCR follows plain DTS, so decoders have
+    /// this much time to buffer.
+    pub mux_delay: cm::Time,
+
+    /// PAT and PMT are written before every sync sample of the video PCR stream
+    /// and at least this often, audio only streams rely on the interval alone.
+    pub tables_interval: cm::Time,
+}
+
+impl Default for MuxerCfg {
+    fn default() -> Self {
+        Self {
+            mux_delay: cm::Time::new(700, 1000),
+            tables_interval: cm::Time::new(100, 1000),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct StreamState {
+    stream_id: u8,
+    cc: u8,
+}
+
+/// Single program transport stream muxer.
+///
+/// PCR is carried by the first video stream, or the first stream if there is no video.
+pub struct Muxer {
+    streams: Vec<Stream>,
+    states: Vec<StreamState>,
+    cfg: MuxerCfg,
+    pcr_index: usize,
+    pat_cc: u8,
+    pmt_cc: u8,
+    tables_dts: Option<cm::Time>,
+    buf: Vec<u8>,
+}
+
+impl Muxer {
+    pub fn new(streams: Vec<Stream>, cfg: MuxerCfg) -> os::Result<Self> {
+        if streams.is_empty() || streams.len() > 16 {
+            return Err(err::INVALID_PARAM);
+        }
+        for (i, s) in streams.iter().enumerate() {
+            if !(0x0010..NULL_PID).contains(&s.pid)
+                || s.pid == PMT_PID
+                || streams[..i].iter().any(|o| o.pid == s.pid)
+            {
+                return Err(err::INVALID_PARAM);
+            }
+        }
+        let mut videos = 0;
+        let mut audios = 0;
+        let states = streams
+            .iter()
+            .map(|s| {
+                let stream_id = if s.stream_type().is_video() {
+                    videos += 1;
+                    0xe0 + videos - 1
+                } else {
+                    audios += 1;
+                    0xc0 + audios - 1
+                };
+                StreamState { stream_id, cc: 0 }
+            })
+            .collect();
+        let pcr_index = streams
+            .iter()
+            .position(|s| s.stream_type().is_video())
+            .unwrap_or(0);
+        Ok(Self {
+            streams,
+            states,
+            cfg,
+            pcr_index,
+            pat_cc: 0,
+            pmt_cc: 0,
+            tables_dts: None,
+            buf: Vec::new(),
+        })
+    }
+
+    #[inline]
+    pub fn streams(&self) -> &[Stream] {
+        &self.streams
+    }
+
+    #[inline]
+    pub fn pcr_pid(&self) -> u16 {
+        self.streams[self.pcr_index].pid
+    }
+
+    /// Appends PAT and PMT packets, call it at the start of every HLS segment.
+    pub fn write_tables(&mut self, out: &mut Vec<u8>) {
+        let mut section = Vec::with_capacity(32);
+
+        // PAT
+        section.extend_from_slice(&[0x00, 0xb0, 13, 0x00, 0x01, 0xc1, 0x00, 0x00]);
+        section.extend_from_slice(&[0x00, 0x01]);
+        section.put_u16(0xe000 | PMT_PID);
+        write_section(out, PAT_PID, &mut self.pat_cc, &mut section);
+
+        // PMT
+        let len = 9 + 5 * self.streams.len() + 4;
+        section.extend_from_slice(&[0x02, 0xb0, len as u8, 0x00, 0x01, 0xc1, 0x00, 0x00]);
+        section.put_u16(0xe000 | self.pcr_pid());
+        section.extend_from_slice(&[0xf0, 0x00]);
+        for s in &self.streams {
+            section.push(s.stream_type().0);
+            section.put_u16(0xe000 | s.pid);
+            section.extend_from_slice(&[0xf0, 0x00]);
+        }
+        write_section(out, PMT_PID, &mut self.pmt_cc, &mut section);
+    }
+
+    /// Appends TS packets of one access unit to `out`.
+    ///
+    /// Tables are written before the first access unit, so the output may
+    /// start right away.
+    pub fn write<S: fmp4::Sample + ?Sized>(
+        &mut self,
+        stream_index: usize,
+        sample: &S,
+        out: &mut Vec<u8>,
+    ) -> os::Result {
+        if stream_index >= self.streams.len() {
+            return Err(err::INVALID_PARAM);
+        }
+        let pts = sample.pts();
+        let dts = sample.dts();
+        let dts = if dts.is_numeric() { dts } else { pts };
+        if !pts.is_numeric() {
+            return Err(err::INVALID_PARAM);
+        }
+        let sync = sample.flags().is_sync();
+        let is_pcr = stream_index == self.pcr_index;
+
+        // PES is built first, so nothing is written on error
+        let mut pes = std::mem::take(&mut self.buf);
+        pes.clear();
+        let res = self.write_pes(stream_index, pts, dts, sample, &mut pes);
+        if res.is_ok() {
+            let key = is_pcr && sync && self.streams[stream_index].stream_type().is_video();
+            let tables_due = match self.tables_dts {
+                None => true,
+                Some(t) => key || dts.sub(t) >= self.cfg.tables_interval,
+            };
+            if tables_due {
+                self.write_tables(out);
+                self.tables_dts = Some(dts);
+            }
+            let pcr = is_pcr.then(|| ticks(dts) * 300);
+            let state = &mut self.states[stream_index];
+            let pid = self.streams[stream_index].pid;
+            write_packets(out, pid, &mut state.cc, &pes, pcr, sync);
+        }
+        self.buf = pes;
+        res
+    }
+
+    fn write_pes<S: fmp4::Sample + ?Sized>(
+        &self,
+        stream_index: usize,
+        pts: cm::Time,
+        dts: cm::Time,
+        sample: &S,
+        pes: &mut Vec<u8>,
+    ) -> os::Result {
+        let delay = self.cfg.mux_delay;
+        let pts = ticks(pts.add(delay));
+        let dts = ticks(dts.add(delay));
+        let with_dts = pts != dts;
+        let stream_id = self.states[stream_index].stream_id;
+
+        pes.extend_from_slice(&[0x00, 0x00, 0x01, stream_id, 0x00, 0x00]);
+        // marker bits and data_alignment_indicator
+        pes.push(0x84);
+        if with_dts {
+            pes.extend_from_slice(&[0xc0, 10]);
+            put_ts(pes, 0x3, pts);
+            put_ts(pes, 0x1, dts);
+        } else {
+            pes.extend_from_slice(&[0x80, 5]);
+            put_ts(pes, 0x2, pts);
+        }
+
+        match &self.streams[stream_index].codec {
+            Codec::Video {
+                codec,
+                nal_unit_len,
+                param_sets,
+            } => {
+                let mut data = Vec::new();
+                sample.append_data(&mut data)?;
+                let has_aud = nal::len_prefixed_nal_units(&data, *nal_unit_len)?
+                    .next()
+                    .and_then(|n| n.ok())
+                    .is_some_and(|n| codec.is_aud(n));
+                if !has_aud {
+                    pes.extend_from_slice(&nal::START_CODE);
+                    match codec {
+                        nal::NalCodec::H264 => pes.extend_from_slice(&[0x09, 0xf0]),
+                        nal::NalCodec::Hevc => pes.extend_from_slice(&[0x46, 0x01, 0x50]),
+                    }
+                }
+                nal::len_prefixed_to_annex_b_with_param_sets(
+                    &data,
+                    *nal_unit_len,
+                    *codec,
+                    param_sets.iter().map(|ps| ps.as_slice()),
+                    pes,
+                )?;
+                // PES_packet_length may be zero for video only
+                let len = pes.len() - 6;
+                if len <= u16::MAX as usize {
+                    pes[4..6].copy_from_slice(&(len as u16).to_be_bytes());
+                }
+            }
+            #[cfg(feature = "cat")]
+            Codec::Aac(asc) => {
+                use cat::audio::aac::Adts;
+
+                let start = pes.len();
+                pes.extend_from_slice(&[0u8; Adts::LEN]);
+                sample.append_data(pes)?;
+                let adts = Adts::with_asc(asc, pes.len() - start - Adts::LEN)
+                    .and_then(|h| h.to_vec())
+                    .map_err(|_| err::INVALID_PARAM)?;
+                pes[start..start + Adts::LEN].copy_from_slice(&adts);
+                let len = u16::try_from(pes.len() - 6).map_err(|_| err::INVALID_PARAM)?;
+                pes[4..6].copy_from_slice(&len.to_be_bytes());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 90 kHz clock ticks wrapped to 33 bits.
+fn ticks(time: cm::Time) -> u64 {
+    let t = time.convert_scale(90_000, cm::TimeRoundingMethod::RoundHalfAwayFromZero);
+    (t.value & TS_MASK) as u64
+}
+
+/// PTS or DTS with 4 bit prefix and marker bits.
+fn put_ts(buf: &mut Vec<u8>, prefix: u8, ts: u64) {
+    buf.extend_from_slice(&[
+        (prefix << 4) | ((ts >> 29) as u8 & 0x0e) | 1,
+        (ts >> 22) as u8,
+        ((ts >> 14) as u8 & 0xfe) | 1,
+        (ts >> 7) as u8,
+        ((ts << 1) as u8 & 0xfe) | 1,
+    ]);
+}
+
+trait Put {
+    fn put_u16(&mut self, val: u16);
+}
+
+impl Put for Vec<u8> {
+    #[inline]
+    fn put_u16(&mut self, val: u16) {
+        self.extend_from_slice(&val.to_be_bytes());
+    }
+}
+
+#[inline]
+fn put_header(out: &mut Vec<u8>, pid: u16, start: bool, adaptation: bool, cc: &mut u8) {
+    let pusi = if start { 0x40 } else { 0x00 };
+    let afc = if adaptation { 0x30 } else { 0x10 };
+    out.extend_from_slice(&[SYNC_BYTE, pusi | (pid >> 8) as u8, pid as u8, afc | *cc]);
+    *cc = (*cc + 1) & 0x0f;
+}
+
+/// Appends CRC and writes the section in one packet with `pointer_field`, clears `section`.
+fn write_section(out: &mut Vec<u8>, pid: u16, cc: &mut u8, section: &mut Vec<u8>) {
+    let crc = crc32(section);
+    section.extend_from_slice(&crc.to_be_bytes());
+    let start = out.len();
+    put_header(out, pid, true, false, cc);
+    out.push(0x00);
+    out.extend_from_slice(section);
+    out.resize(start + PACKET_LEN, 0xff);
+    section.clear();
+}
+
+/// Splits PES into packets, the first one gets adaptation field with `pcr`
+/// and random access indicator, the last one is padded with stuffing bytes.
+fn write_packets(
+    out: &mut Vec<u8>,
+    pid: u16,
+    cc: &mut u8,
+    pes: &[u8],
+    pcr: Option<u64>,
+    random_access: bool,
+) {
+    out.reserve(pes.len().div_ceil(PAYLOAD_LEN - 8) * PACKET_LEN);
+    let mut rest = pes;
+    let mut first = true;
+    while !rest.is_empty() {
+        let mut flags = 0u8;
+        let mut fields_len = 0;
+        if first {
+            if random_access {
+                flags |= 0x40;
+            }
+            if pcr.is_some() {
+                flags |= 0x10;
+                fields_len = 6;
+            }
+        }
+        // adaptation field length byte, flags and fields
+        let mut af_len = if flags != 0 { 2 + fields_len } else { 0 };
+        let payload_len = rest.len().min(PAYLOAD_LEN - af_len);
+        af_len = PAYLOAD_LEN - payload_len;
+
+        let start = out.len();
+        put_header(out, pid, first, af_len > 0, cc);
+        if af_len > 0 {
+            out.push(af_len as u8 - 1);
+        }
+        if af_len > 1 {
+            out.push(flags);
+            if let Some(pcr) = pcr.filter(|_| first) {
+                let base = (pcr / 300) & TS_MASK as u64;
+                let ext = pcr % 300;
+                out.extend_from_slice(&[
+                    (base >> 25) as u8,
+                    (base >> 17) as u8,
+                    (base >> 9) as u8,
+                    (base >> 1) as u8,
+                    ((base & 1) << 7) as u8 | 0x7e | (ext >> 8) as u8,
+                    ext as u8,
+                ]);
+            }
+            out.resize(start + 4 + af_len, 0xff);
+        }
+        out.extend_from_slice(&rest[..payload_len]);
+        rest = &rest[payload_len..];
+        first = false;
+    }
+}
+
+/// CRC-32/MPEG-2 of PSI sections.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for &b in data {
+        crc ^= (b as u32) << 24;
+        for _ in 0..8 {
+            crc = if crc & 0x8000_0000 != 0 {
+                (crc << 1) ^ 0x04c1_1db7
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "cat")]
+    use crate::cat;
+    use crate::cm::{self, fmp4, ts};
+
+    const AVC_SPS: &[u8] = &[
+        0x67, 0x64, 0x00, 0x28, 0xac, 0xd9, 0x40, 0x78, 0x02, 0x27, 0xe5, 0xc0, 0x5b, 0x80, 0x80,
+        0x80, 0xa0, 0x00, 0x00, 0x03, 0x00, 0x20, 0x00, 0x00, 0x0f, 0x10, 0x80,
+    ];
+    const AVC_PPS: &[u8] = &[0x68, 0xeb, 0xe3, 0xcb, 0x22, 0xc0];
+
+    struct Au {
+        pts: cm::Time,
+        dts: cm::Time,
+        sync: bool,
+        data: Vec<u8>,
+    }
+
+    impl fmp4::Sample for Au {
+        fn dts(&self) -> cm::Time {
+            self.dts
+        }
+
+        fn pts(&self) -> cm::Time {
+            self.pts
+        }
+
+        fn duration(&self) -> cm::Time {
+            cm::Time::invalid()
+        }
+
+        fn flags(&self) -> fmp4::SampleFlags {
+            if self.sync {
+                fmp4::SampleFlags::SYNC
+            } else {
+                fmp4::SampleFlags::IS_NON_SYNC | fmp4::SampleFlags::DEPENDS_ON_OTHERS
+            }
+        }
+
+        fn append_data(&self, dst: &mut Vec<u8>) -> crate::os::Result {
+            dst.extend_from_slice(&self.data);
+            Ok(())
+        }
+    }
+
+    /// Reassembles payloads of `pid`, checks continuity counters.
+    fn payload(ts: &[u8], pid: u16) -> Vec<Vec<u8>> {
+        assert_eq!(ts.len() % ts::PACKET_LEN, 0);
+        let mut res: Vec<Vec<u8>> = Vec::new();
+        let mut cc = None;
+        for p in ts.chunks(ts::PACKET_LEN) {
+            assert_eq!(p[0], 0x47);
+            if u16::from_be_bytes([p[1] & 0x1f, p[2]]) != pid {
+                continue;
+            }
+            if let Some(cc) = cc {
+                assert_eq!(p[3] & 0x0f, (cc + 1) & 0x0f);
+            }
+            cc = Some(p[3] & 0x0f);
+            let start = if p[3] & 0x20 != 0 {
+                5 + p[4] as usize
+            } else {
+                4
+            };
+            if p[1] & 0x40 != 0 {
+                res.push(Vec::new());
+            }
+            res.last_mut().unwrap().extend_from_slice(&p[start..]);
+        }
+        res
+    }
+
+    fn read_ts(b: &[u8]) -> u64 {
+        ((b[0] as u64 >> 1) & 7) << 30
+            | (b[1] as u64) << 22
+            | (b[2] as u64 >> 1) << 15
+            | (b[3] as u64) << 7
+            | b[4] as u64 >> 1
+    }
+
+    #[cfg(feature = "cat")]
+    #[test]
+    fn tables() {
+        let cfg = cm::AvcCfg::with_param_sets(&[AVC_SPS], &[AVC_PPS], 4).unwrap();
+        let mut mux = ts::Muxer::new(
+            vec![
+                ts::Stream::avc(0x100, &cfg),
+                ts::Stream::aac(0x101, &[0x12, 0x10]).unwrap(),
+            ],
+            Default::default(),
+        )
+        .unwrap();
+        assert_eq!(mux.pcr_pid(), 0x100);
+
+        let mut out = Vec::new();
+        mux.write_tables(&mut out);
+        assert_eq!(out.len(), 2 * ts::PACKET_LEN);
+        assert_eq!(
+            &out[..21],
+            &[
+                0x47, 0x40, 0x00, 0x10, 0x00, 0x00, 0xb0, 0x0d, 0x00, 0x01, 0xc1, 0x00, 0x00, 0x00,
+                0x01, 0xf0, 0x00, 0x2a, 0xb1, 0x04, 0xb2
+            ]
+        );
+        assert!(out[21..188].iter().all(|b| *b == 0xff));
+
+        let pmt = &out[188..];
+        assert_eq!(&pmt[..4], &[0x47, 0x50, 0x00, 0x10]);
+        assert_eq!(
+            &pmt[5..27],
+            &[
+                0x02, 0xb0, 0x17, 0x00, 0x01, 0xc1, 0x00, 0x00, 0xe1, 0x00, 0xf0, 0x00, 0x1b, 0xe1,
+                0x00, 0xf0, 0x00, 0x0f, 0xe1, 0x01, 0xf0, 0x00
+            ]
+        );
+        // CRC over section with CRC gives zero residue
+        assert_eq!(ts::crc32(&pmt[5..31]), 0);
+
+        mux.write_tables(&mut out);
+        assert_eq!(out[188 * 2 + 3], 0x11);
+    }
+
+    #[test]
+    fn video() {
+        let cfg = cm::AvcCfg::with_param_sets(&[AVC_SPS], &[AVC_PPS], 4).unwrap();
+        let mut mux =
+            ts::Muxer::new(vec![ts::Stream::avc(0x100, &cfg)], Default::default()).unwrap();
+
+        let idr: Vec<u8> = [0x65]
+            .into_iter()
+            .chain((0..400).map(|i| i as u8))
+            .collect();
+        let mut data = (idr.len() as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(&idr);
+        let key = Au {
+            pts: cm::Time::new(2, 30),
+            dts: cm::Time::new(1, 30),
+            sync: true,
+            data,
+        };
+        let mut out = Vec::new();
+        mux.write(0, &key, &mut out).unwrap();
+        let delta = Au {
+            pts: cm::Time::new(3, 30),
+            dts: cm::Time::new(3, 30),
+            sync: false,
+            data: vec![0, 0, 0, 3, 0x41, 0x9a, 0x02],
+        };
+        mux.write(0, &delta, &mut out).unwrap();
+
+        // PAT, PMT, 3 packets of IDR, 1 of delta
+        assert_eq!(out.len(), 6 * ts::PACKET_LEN);
+
+        let first = &out[2 * 188..3 * 188];
+        assert_eq!(&first[..6], &[0x47, 0x41, 0x00, 0x30, 7, 0x50]);
+        // PCR follows DTS: 3000 ticks
+        assert_eq!(&first[6..12], &[0x00, 0x00, 0x05, 0xdc, 0x7e, 0x00]);
+
+        let pes = payload(&out, 0x100);
+        assert_eq!(pes.len(), 2);
+        let p = &pes[0];
+        assert_eq!(&p[..4], &[0, 0, 1, 0xe0]);
+        assert_eq!(u16::from_be_bytes([p[4], p[5]]) as usize, p.len() - 6);
+        assert_eq!(&p[6..9], &[0x84, 0xc0, 10]);
+        assert_eq!(p[9] >> 4, 3);
+        assert_eq!(read_ts(&p[9..]), 63_000 + 6000);
+        assert_eq!(p[14] >> 4, 1);
+        assert_eq!(read_ts(&p[14..]), 63_000 + 3000);
+
+        let mut es = vec![0, 0, 0, 1, 0x09, 0xf0, 0, 0, 0, 1];
+        es.extend_from_slice(AVC_SPS);
+        es.extend_from_slice(&[0, 0, 0, 1]);
+        es.extend_from_slice(AVC_PPS);
+        es.extend_from_slice(&[0, 0, 0, 1]);
+        es.extend_from_slice(&idr);
+        assert_eq!(&p[19..], &es);
+
+        let p = &pes[1];
+        assert_eq!(&p[6..9], &[0x84, 0x80, 5]);
+        assert_eq!(read_ts(&p[9..]), 63_000 + 9000);
+        assert_eq!(
+            &p[14..],
+            &[0, 0, 0, 1, 0x09, 0xf0, 0, 0, 0, 1, 0x41, 0x9a, 0x02]
+        );
+
+        // delta is stuffed, PCR without random access
+        let last = &out[5 * 188..];
+        assert_eq!(last[3] & 0x30, 0x30);
+        assert_eq!(last[5], 0x10);
+        assert_eq!(last[1] & 0x40, 0x40);
+    }
+
+    #[cfg(feature = "cat")]
+    #[test]
+    fn audio() {
+        let mut mux = ts::Muxer::new(
+            vec![ts::Stream::aac(0x101, &[0x12, 0x10]).unwrap()],
+            Default::default(),
+        )
+        .unwrap();
+        let mut out = Vec::new();
+        for i in 0..3 {
+            let au = Au {
+                pts: cm::Time::new(i * 1024, 44_100),
+                dts: cm::Time::invalid(),
+                sync: true,
+                data: vec![0x21; 10],
+            };
+            mux.write(0, &au, &mut out).unwrap();
+        }
+        // audio sync samples don't repeat tables within the interval
+        assert_eq!(out.len(), 5 * ts::PACKET_LEN);
+        let pes = payload(&out, 0x101);
+        assert_eq!(pes.len(), 3);
+        let p = &pes[2];
+        assert_eq!(&p[..6], &[0, 0, 1, 0xc0, 0, 25]);
+        assert_eq!(read_ts(&p[9..]), 63_000 + 4180);
+        assert_eq!(&p[14..21], &[0xff, 0xf1, 0x50, 0x80, 0x02, 0x3f, 0xfc]);
+        assert_eq!(&p[21..], &[0x21; 10]);
+
+        let mut mux = ts::Muxer::new(
+            vec![ts::Stream::aac(0x101, &[0x12, 0x10]).unwrap()],
+            ts::MuxerCfg {
+                tables_interval: cm::Time::new(40, 1000),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let mut out = Vec::new();
+        for i in 0..3 {
+            let au = Au {
+                pts: cm::Time::new(i * 1024, 44_100),
+                dts: cm::Time::invalid(),
+                sync: true,
+                data: vec![0x21; 10],
+            };
+            mux.write(0, &au, &mut out).unwrap();
+        }
+        // tables at 0 and 46 ms
+        assert_eq!(out.len(), 7 * ts::PACKET_LEN);
+        assert_eq!(payload(&out, ts::PMT_PID).len(), 2);
+
+        // HE-AAC v1 with explicit SBR signaling
+        let he = ts::Stream::aac(0x101, &[0x2b, 0x92, 0x08, 0x00]).unwrap();
+        let ts::Codec::Aac(asc) = he.codec else {
+            panic!("{:?}", he.codec);
+        };
+        assert_eq!(asc.object_type, cat::audio::Mpeg4Object::AAC_LC);
+        assert_eq!(asc.sample_rate, 22_050);
+        assert_eq!(asc.channel_cfg, 2);
+    }
+
+    #[cfg(feature = "cat")]
+    #[test]
+    fn invalid() {
+        let aac = || ts::Stream::aac(0x100, &[0x12, 0x10]).unwrap();
+        assert!(ts::Muxer::new(vec![], Default::default()).is_err());
+        assert!(ts::Muxer::new(vec![aac(), aac()], Default::default()).is_err());
+        let mut s = aac();
+        s.pid = ts::PMT_PID;
+        assert!(ts::Muxer::new(vec![s], Default::default()).is_err());
+
+        // explicit frequency, AAC Main with unsupported channel config and AAC LD
+        assert!(ts::Stream::aac(0x100, &[0x17, 0x80]).is_err());
+        assert!(ts::Stream::aac(0x100, &[0x0a, 0x00]).is_err());
+        assert!(ts::Stream::aac(0x100, &[0xba, 0x10, 0x00]).is_err());
+
+        let mut mux = ts::Muxer::new(vec![aac()], Default::default()).unwrap();
+        let au = Au {
+            pts: cm::Time::invalid(),
+            dts: cm::Time::invalid(),
+            sync: true,
+            data: vec![],
+        };
+        let mut out = Vec::new();
+        assert!(mux.write(0, &au, &mut out).is_err());
+        assert!(mux.write(1, &au, &mut out).is_err());
+
+        // frame over ADTS limit leaves output and tables state untouched
+        let au = Au {
+            pts: cm::Time::new(0, 44_100),
+            dts: cm::Time::invalid(),
+            sync: true,
+            data: vec![0x21; 1 << 13],
+        };
+        assert!(mux.write(0, &au, &mut out).is_err());
+        assert!(out.is_empty());
+        let au = Au {
+            data: vec![0x21; 10],
+            ..au
+        };
+        mux.write(0, &au, &mut out).unwrap();
+        assert_eq!(out.len(), 3 * ts::PACKET_LEN);
+        assert_eq!(out[3], 0x10);
+    }
+}
@@ -0,0 +1,1132 @@
+use crate::{
+    bits::{BitReader, ByteReader},
+    cm::{self, format_desc_err as err, nal::unescape},
+    os,
+};
+
+const AVC_NAL_SPS: u8 = 7;
+
+const HEVC_NAL_VPS: u8 = 32;
+const HEVC_NAL_SPS: u8 = 33;
+const HEVC_NAL_PPS: u8 = 34;
+
+/// AVCDecoderConfigurationRecord (ISO/IEC 14496-15 5.3.3), the payload of `avcC` atom.
+#[doc(alias = "AVCDecoderConfigurationRecord")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvcCfg {
+    pub profile_idc: u8,
+    pub profile_compatibility: u8,
+    pub level_idc: u8,
+
+    /// Size of NAL unit length prefix in bytes: 1, 2 or 4.
+    pub nal_unit_len: u8,
+    pub sps: Vec<Vec<u8>>,
+    pub pps: Vec<Vec<u8>>,
+
+    /// Only for profiles with chroma format in SPS (High and above).
+    pub ext: Option<AvcCfgExt>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvcCfgExt {
+    pub chroma_format_idc: u8,
+    pub bit_depth_luma: u8,
+    pub bit_depth_chroma: u8,
+    pub sps_ext: Vec<Vec<u8>>,
+}
+
+impl AvcCfg {
+    /// Builds record from raw SPS and PPS NAL units (with NAL header, without start codes).
+    ///
+    /// Profile, level and High profile extension are taken from the first SPS.
+    pub fn with_param_sets(sps: &[&[u8]], pps: &[&[u8]], nal_unit_len: u8) -> os::Result<Self> {
+        check_nal_unit_len(nal_unit_len)?;
+        let Some(first) = sps.first() else {
+            return Err(err::INVALID_PARAM);
+        };
+        if first.len() < 4 || first[0] & 0x1f != AVC_NAL_SPS {
+            return Err(err::INVALID_PARAM);
+        }
+        let ext = if has_avc_ext(first[1]) {
+            let info = Sps::from_h264(first)?;
+            Some(AvcCfgExt {
+                chroma_format_idc: info.chroma_format_idc,
+                bit_depth_luma: info.bit_depth_luma,
+                bit_depth_chroma: info.bit_depth_chroma,
+                sps_ext: vec![],
+            })
+        } else {
+            None
+        };
+        Ok(Self {
+            profile_idc: first[1],
+            profile_compatibility: first[2],
+            level_idc: first[3],
+            nal_unit_len,
+            sps: sps.iter().map(|s| s.to_vec()).collect(),
+            pps: pps.iter().map(|s| s.to_vec()).collect(),
+            ext,
+        })
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> os::Result<Self> {
+        Self::read(&mut ByteReader::new(bytes)).ok_or(err::INVALID_PARAM)
+    }
+
+    fn read(r: &mut ByteReader) -> Option<Self> {
+        if r.u8()? != 1 {
+            return None;
+        }
+        let profile_idc = r.u8()?;
+        let profile_compatibility = r.u8()?;
+        let level_idc = r.u8()?;
+        let nal_unit_len = (r.u8()? & 0b11) + 1;
+        check_nal_unit_len(nal_unit_len).ok()?;
+        let sps_count = r.u8()? & 0x1f;
+        let sps = read_nal_units(r, sps_count as usize)?;
+        let pps_count = r.u8()?;
+        let pps = read_nal_units(r, pps_count as usize)?;
+
+        // Some muxers omit extension even for High profiles
+        let ext = if has_avc_ext(profile_idc) && r.remaining() >= 4 {
+            let chroma_format_idc = r.u8()? & 0b11;
+            let bit_depth_luma = (r.u8()? & 0b111) + 8;
+            let bit_depth_chroma = (r.u8()? & 0b111) + 8;
+            let count = r.u8()?;
+            Some(AvcCfgExt {
+                chroma_format_idc,
+                bit_depth_luma,
+                bit_depth_chroma,
+                sps_ext: read_nal_units(r, count as usize)?,
+            })
+        } else {
+            None
+        };
+
+        Some(Self {
+            profile_idc,
+            profile_compatibility,
+            level_idc,
+            nal_unit_len,
+            sps,
+            pps,
+            ext,
+        })
+    }
+
+    pub fn write_to(&self, buf: &mut Vec<u8>) -> os::Result {
+        check_nal_unit_len(self.nal_unit_len)?;
+        check_nal_units(&self.sps, 0x1f)?;
+        check_nal_units(&self.pps, 0xff)?;
+        if let Some(ext) = &self.ext {
+            check_nal_units(&ext.sps_ext, 0xff)?;
+            if ext.bit_depth_luma < 8 || ext.bit_depth_chroma < 8 {
+                return Err(err::INVALID_PARAM);
+            }
+        }
+        buf.extend_from_slice(&[
+            1,
+            self.profile_idc,
+            self.profile_compatibility,
+            self.level_idc,
+            0xfc | (self.nal_unit_len - 1),
+            0xe0 | self.sps.len() as u8,
+        ]);
+        write_nal_units(buf, &self.sps);
+        buf.push(self.pps.len() as u8);
+        write_nal_units(buf, &self.pps);
+        if let Some(ext) = &self.ext {
+            buf.extend_from_slice(&[
+                0xfc | (ext.chroma_format_idc & 0b11),
+                0xf8 | ((ext.bit_depth_luma - 8) & 0b111),
+                0xf8 | ((ext.bit_depth_chroma - 8) & 0b111),
+                ext.sps_ext.len() as u8,
+            ]);
+            write_nal_units(buf, &ext.sps_ext);
+        }
+        Ok(())
+    }
+
+    pub fn to_vec(&self) -> os::Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(64);
+        self.write_to(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// SPS and PPS in the order [`cm::VideoFormatDesc::with_h264_param_sets`] expects them.
+    pub fn param_sets(&self) -> impl Iterator<Item = &[u8]> {
+        self.sps.iter().chain(self.pps.iter()).map(|v| v.as_slice())
+    }
+
+    /// Decodes first SPS.
+    pub fn sps_info(&self) -> os::Result<Sps> {
+        let Some(sps) = self.sps.first() else {
+            return Err(err::VALUE_NOT_AVAILABLE);
+        };
+        Sps::from_h264(sps)
+    }
+}
+
+/// One `NAL_unit_type` array in [`HevcCfg`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HevcNalArray {
+    pub array_completeness: bool,
+    pub nal_unit_type: u8,
+    pub nal_units: Vec<Vec<u8>>,
+}
+
+/// HEVCDecoderConfigurationRecord (ISO/IEC 14496-15 8.3.3), the payload of `hvcC` atom.
+#[doc(alias = "HEVCDecoderConfigurationRecord")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HevcCfg {
+    pub general_profile_space: u8,
+    pub general_tier_flag: bool,
+    pub general_profile_idc: u8,
+    pub general_profile_compatibility_flags: u32,
+
+    /// 48 bits
+    pub general_constraint_indicator_flags: u64,
+    pub general_level_idc: u8,
+    pub min_spatial_segmentation_idc: u16,
+    pub parallelism_type: u8,
+    pub chroma_format_idc: u8,
+    pub bit_depth_luma: u8,
+    pub bit_depth_chroma: u8,
+    pub avg_frame_rate: u16,
+    pub constant_frame_rate: u8,
+    pub num_temporal_layers: u8,
+    pub temporal_id_nested: bool,
+
+    /// Size of NAL unit length prefix in bytes: 1, 2 or 4.
+    pub nal_unit_len: u8,
+    pub arrays: Vec<HevcNalArray>,
+}
+
+impl HevcCfg {
+    /// Builds record from raw VPS, SPS and PPS NAL units (with NAL header, without start codes).
+    ///
+    /// Profile, tier, level, chroma format and bit depths are taken from the first SPS.
+    pub fn with_param_sets(
+        vps: &[&[u8]],
+        sps: &[&[u8]],
+        pps: &[&[u8]],
+        nal_unit_len: u8,
+    ) -> os::Result<Self> {
+        check_nal_unit_len(nal_unit_len)?;
+        let Some(first) = sps.first() else {
+            return Err(err::INVALID_PARAM);
+        };
+        let info = Sps::from_hevc(first)?;
+        let ptl = info.hevc_ptl.ok_or(err::INVALID_PARAM)?;
+
+        let array = |nal_unit_type, nal_units: &[&[u8]]| HevcNalArray {
+            array_completeness: true,
+            nal_unit_type,
+            nal_units: nal_units.iter().map(|v| v.to_vec()).collect(),
+        };
+
+        Ok(Self {
+            general_profile_space: ptl.profile_space,
+            general_tier_flag: ptl.tier_flag,
+            general_profile_idc: ptl.profile_idc,
+            general_profile_compatibility_flags: ptl.profile_compatibility_flags,
+            general_constraint_indicator_flags: ptl.constraint_indicator_flags,
+            general_level_idc: info.level_idc,
+            min_spatial_segmentation_idc: 0,
+            parallelism_type: 0,
+            chroma_format_idc: info.chroma_format_idc,
+            bit_depth_luma: info.bit_depth_luma,
+            bit_depth_chroma: info.bit_depth_chroma,
+            avg_frame_rate: 0,
+            constant_frame_rate: 0,
+            num_temporal_layers: ptl.max_sub_layers,
+            temporal_id_nested: ptl.temporal_id_nesting,
+            nal_unit_len,
+            arrays: vec![
+                array(HEVC_NAL_VPS, vps),
+                array(HEVC_NAL_SPS, sps),
+                array(HEVC_NAL_PPS, pps),
+            ],
+        })
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> os::Result<Self> {
+        Self::read(&mut ByteReader::new(bytes)).ok_or(err::INVALID_PARAM)
+    }
+
+    fn read(r: &mut ByteReader) -> Option<Self> {
+        if r.u8()? != 1 {
+            return None;
+        }
+        let b = r.u8()?;
+        let general_profile_compatibility_flags = r.u32()?;
+        let hi = r.u16()? as u64;
+        let lo = r.u32()? as u64;
+        let general_level_idc = r.u8()?;
+        let min_spatial_segmentation_idc = r.u16()? & 0x0fff;
+        let parallelism_type = r.u8()? & 0b11;
+        let chroma_format_idc = r.u8()? & 0b11;
+        let bit_depth_luma = (r.u8()? & 0b111) + 8;
+        let bit_depth_chroma = (r.u8()? & 0b111) + 8;
+        let avg_frame_rate = r.u16()?;
+        let f = r.u8()?;
+        let nal_unit_len = (f & 0b11) + 1;
+        check_nal_unit_len(nal_unit_len).ok()?;
+        let count = r.u8()?;
+        let mut arrays = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let t = r.u8()?;
+            let n = r.u16()?;
+            arrays.push(HevcNalArray {
+                array_completeness: t & 0x80 != 0,
+                nal_unit_type: t & 0x3f,
+                nal_units: read_nal_units(r, n as usize)?,
+            });
+        }
+
+        Some(Self {
+            general_profile_space: b >> 6,
+            general_tier_flag: b & 0x20 != 0,
+            general_profile_idc: b & 0x1f,
+            general_profile_compatibility_flags,
+            general_constraint_indicator_flags: (hi << 32) | lo,
+            general_level_idc,
+            min_spatial_segmentation_idc,
+            parallelism_type,
+            chroma_format_idc,
+            bit_depth_luma,
+            bit_depth_chroma,
+            avg_frame_rate,
+            constant_frame_rate: f >> 6,
+            num_temporal_layers: (f >> 3) & 0b111,
+            temporal_id_nested: f & 0b100 != 0,
+            nal_unit_len,
+            arrays,
+        })
+    }
+
+    pub fn write_to(&self, buf: &mut Vec<u8>) -> os::Result {
+        check_nal_unit_len(self.nal_unit_len)?;
+        if self.arrays.len() > 0xff || self.bit_depth_luma < 8 || self.bit_depth_chroma < 8 {
+            return Err(err::INVALID_PARAM);
+        }
+        for a in &self.arrays {
+            check_nal_units(&a.nal_units, 0xffff)?;
+        }
+        let c = self.general_constraint_indicator_flags;
+        buf.push(1);
+        buf.push(
+            ((self.general_profile_space & 0b11) << 6)
+                | ((self.general_tier_flag as u8) << 5)
+                | (self.general_profile_idc & 0x1f),
+        );
+        buf.extend_from_slice(&self.general_profile_compatibility_flags.to_be_bytes());
+        buf.extend_from_slice(&c.to_be_bytes()[2..]);
+        buf.push(self.general_level_idc);
+        buf.extend_from_slice(
+            &(0xf000 | (self.min_spatial_segmentation_idc & 0x0fff)).to_be_bytes(),
+        );
+        buf.extend_from_slice(&[
+            0xfc | (self.parallelism_type & 0b11),
+            0xfc | (self.chroma_format_idc & 0b11),
+            0xf8 | ((self.bit_depth_luma - 8) & 0b111),
+            0xf8 | ((self.bit_depth_chroma - 8) & 0b111),
+        ]);
+        buf.extend_from_slice(&self.avg_frame_rate.to_be_bytes());
+        buf.push(
+            ((self.constant_frame_rate & 0b11) << 6)
+                | ((self.num_temporal_layers & 0b111) << 3)
+                | ((self.temporal_id_nested as u8) << 2)
+                | (self.nal_unit_len - 1),
+        );
+        buf.push(self.arrays.len() as u8);
+        for a in &self.arrays {
+            buf.push(((a.array_completeness as u8) << 7) | (a.nal_unit_type & 0x3f));
+            buf.extend_from_slice(&(a.nal_units.len() as u16).to_be_bytes());
+            write_nal_units(buf, &a.nal_units);
+        }
+        Ok(())
+    }
+
+    pub fn to_vec(&self) -> os::Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(128);
+        self.write_to(&mut buf)?;
+        Ok(buf)
+    }
+
+    pub fn nal_units(&self, nal_unit_type: u8) -> impl Iterator<Item = &[u8]> {
+        self.arrays
+            .iter()
+            .filter(move |a| a.nal_unit_type == nal_unit_type)
+            .flat_map(|a| a.nal_units.iter().map(|v| v.as_slice()))
+    }
+
+    pub fn vps(&self) -> impl Iterator<Item = &[u8]> {
+        self.nal_units(HEVC_NAL_VPS)
+    }
+
+    pub fn sps(&self) -> impl Iterator<Item = &[u8]> {
+        self.nal_units(HEVC_NAL_SPS)
+    }
+
+    pub fn pps(&self) -> impl Iterator<Item = &[u8]> {
+        self.nal_units(HEVC_NAL_PPS)
+    }
+
+    /// VPS, SPS and PPS in the order [`cm::VideoFormatDesc::with_hevc_param_sets`] expects them.
+    pub fn param_sets(&self) -> impl Iterator<Item = &[u8]> {
+        self.vps().chain(self.sps()).chain(self.pps())
+    }
+
+    /// Decodes first SPS.
+    pub fn sps_info(&self) -> os::Result<Sps> {
+        let Some(sps) = self.sps().next() else {
+            return Err(err::VALUE_NOT_AVAILABLE);
+        };
+        Sps::from_hevc(sps)
+    }
+}
+
+/// Colour description from VUI `video_signal_type`.
+///
+/// When VUI doesn't carry colour description, primaries, transfer and matrix
+/// are 2 (unspecified) as the spec infers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VideoSignal {
+    pub video_format: u8,
+    pub full_range: bool,
+    pub colour_primaries: u8,
+    pub transfer_characteristics: u8,
+    pub matrix_coeffs: u8,
+}
+
+impl Default for VideoSignal {
+    fn default() -> Self {
+        Self {
+            video_format: 5,
+            full_range: false,
+            colour_primaries: 2,
+            transfer_characteristics: 2,
+            matrix_coeffs: 2,
+        }
+    }
+}
+
+/// General `profile_tier_level` of HEVC SPS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HevcPtl {
+    pub profile_space: u8,
+    pub tier_flag: bool,
+    pub profile_idc: u8,
+    pub profile_compatibility_flags: u32,
+
+    /// 48 bits
+    pub constraint_indicator_flags: u64,
+    pub max_sub_layers: u8,
+    pub temporal_id_nesting: bool,
+}
+
+/// Decoded fields of H.264 or H.265 sequence parameter set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sps {
+    pub profile_idc: u8,
+    pub level_idc: u8,
+    pub chroma_format_idc: u8,
+    pub bit_depth_luma: u8,
+    pub bit_depth_chroma: u8,
+
+    /// Width in luma samples after cropping
+    pub width: u32,
+
+    /// Height in luma samples after cropping
+    pub height: u32,
+    pub sample_aspect_ratio: Option<(u16, u16)>,
+    pub video_signal: Option<VideoSignal>,
+
+    /// `num_units_in_tick` and `time_scale` from VUI
+    pub timing: Option<(u32, u32)>,
+
+    /// Only for H.265
+    pub hevc_ptl: Option<HevcPtl>,
+}
+
+impl Sps {
+    /// Decodes H.264 SPS NAL unit (with NAL header).
+    pub fn from_h264(nal: &[u8]) -> os::Result<Self> {
+        match nal.first() {
+            Some(h) if h & 0x1f == AVC_NAL_SPS => {}
+            _ => return Err(err::INVALID_PARAM),
+        }
+        let rbsp = unescape(&nal[1..]);
+        read_h264_sps(&mut BitReader::new(&rbsp)).ok_or(err::INVALID_PARAM)
+    }
+
+    /// Decodes H.265 SPS NAL unit (with NAL header).
+    pub fn from_hevc(nal: &[u8]) -> os::Result<Self> {
+        if nal.len() < 2 || (nal[0] >> 1) & 0x3f != HEVC_NAL_SPS {
+            return Err(err::INVALID_PARAM);
+        }
+        let rbsp = unescape(&nal[2..]);
+        read_hevc_sps(&mut BitReader::new(&rbsp)).ok_or(err::INVALID_PARAM)
+    }
+
+    pub fn dims(&self) -> cm::VideoDimensions {
+        cm::VideoDimensions {
+            width: self.width as i32,
+            height: self.height as i32,
+        }
+    }
+}
+
+/// Profiles with chroma format and bit depths in SPS, and so with `avcC` extension.
+fn has_avc_ext(profile_idc: u8) -> bool {
+    matches!(
+        profile_idc,
+        100 | 110 | 122 | 144 | 244 | 44 | 83 | 86 | 118 | 128 | 138 | 139 | 134 | 135
+    )
+}
+
+/// `ue(v)` value limited by the spec to `0..=max`.
+fn ue_max(r: &mut BitReader, max: u32) -> Option<u32> {
+    r.ue().filter(|v| *v <= max)
+}
+
+fn check_nal_unit_len(len: u8) -> os::Result {
+    match len {
+        1 | 2 | 4 => Ok(()),
+        _ => Err(err::INVALID_PARAM),
+    }
+}
+
+fn read_nal_units(r: &mut ByteReader, count: usize) -> Option<Vec<Vec<u8>>> {
+    let mut res = Vec::with_capacity(count);
+    for _ in 0..count {
+        let len = r.u16()?;
+        res.push(r.take(len as usize)?.to_vec());
+    }
+    Some(res)
+}
+
+fn check_nal_units(units: &[Vec<u8>], max_count: usize) -> os::Result {
+    if units.len() > max_count || units.iter().any(|u| u.len() > u16::MAX as usize) {
+        return Err(err::INVALID_PARAM);
+    }
+    Ok(())
+}
+
+/// Units have to be checked with [`check_nal_units`] first.
+fn write_nal_units(buf: &mut Vec<u8>, units: &[Vec<u8>]) {
+    for u in units {
+        buf.extend_from_slice(&(u.len() as u16).to_be_bytes());
+        buf.extend_from_slice(u);
+    }
+}
+
+fn read_sar(r: &mut BitReader) -> Option<Option<(u16, u16)>> {
+    const SAR: [(u16, u16); 17] = [
+        (0, 0),
+        (1, 1),
+        (12, 11),
+        (10, 11),
+        (16, 11),
+        (40, 33),
+        (24, 11),
+        (20, 11),
+        (32, 11),
+        (80, 33),
+        (18, 11),
+        (15, 11),
+        (64, 33),
+        (160, 99),
+        (4, 3),
+        (3, 2),
+        (2, 1),
+    ];
+    if !r.bit()? {
+        return Some(None);
+    }
+    let idc = r.u8(8)?;
+    if idc == 255 {
+        let w = r.bits(16)? as u16;
+        let h = r.bits(16)? as u16;
+        return Some(Some((w, h)));
+    }
+    Some(SAR.get(idc as usize).copied().filter(|s| s.0 != 0))
+}
+
+fn read_video_signal(r: &mut BitReader) -> Option<Option<VideoSignal>> {
+    if !r.bit()? {
+        return Some(None);
+    }
+    let mut res = VideoSignal {
+        video_format: r.u8(3)?,
+        full_range: r.bit()?,
+        ..Default::default()
+    };
+    if r.bit()? {
+        res.colour_primaries = r.u8(8)?;
+        res.transfer_characteristics = r.u8(8)?;
+        res.matrix_coeffs = r.u8(8)?;
+    }
+    Some(Some(res))
+}
+
+fn read_timing(r: &mut BitReader) -> Option<Option<(u32, u32)>> {
+    if !r.bit()? {
+        return Some(None);
+    }
+    let num_units_in_tick = r.bits(32)?;
+    let time_scale = r.bits(32)?;
+    Some(Some((num_units_in_tick, time_scale)))
+}
+
+fn skip_h264_scaling_list(r: &mut BitReader, size: usize) -> Option<()> {
+    let mut last = 8i32;
+    let mut next = 8i32;
+    for _ in 0..size {
+        if next != 0 {
+            let delta = r.se().filter(|d| (-128..=127).contains(d))?;
+            next = (last + delta + 256) % 256;
+        }
+        if next != 0 {
+            last = next;
+        }
+    }
+    Some(())
+}
+
+fn read_h264_sps(r: &mut BitReader) -> Option<Sps> {
+    let profile_idc = r.u8(8)?;
+    r.skip(8)?; // constraint flags
+    let level_idc = r.u8(8)?;
+    r.ue()?; // seq_parameter_set_id
+
+    let mut chroma_format_idc = 1;
+    let mut separate_colour_plane = false;
+    let mut bit_depth_luma = 8;
+    let mut bit_depth_chroma = 8;
+    if has_avc_ext(profile_idc) {
+        chroma_format_idc = ue_max(r, 3)? as u8;
+        if chroma_format_idc == 3 {
+            separate_colour_plane = r.bit()?;
+        }
+        bit_depth_luma = ue_max(r, 6)? as u8 + 8;
+        bit_depth_chroma = ue_max(r, 6)? as u8 + 8;
+        r.skip(1)?; // qpprime_y_zero_transform_bypass_flag
+        if r.bit()? {
+            let count = if chroma_format_idc != 3 { 8 } else { 12 };
+            for i in 0..count {
+                if r.bit()? {
+                    skip_h264_scaling_list(r, if i < 6 { 16 } else { 64 })?;
+                }
+            }
+        }
+    }
+
+    ue_max(r, 12)?; // log2_max_frame_num_minus4
+    match ue_max(r, 2)? {
+        0 => {
+            ue_max(r, 12)?; // log2_max_pic_order_cnt_lsb_minus4
+        }
+        1 => {
+            r.skip(1)?; // delta_pic_order_always_zero_flag
+            r.se()?; // offset_for_non_ref_pic
+            r.se()?; // offset_for_top_to_bottom_field
+            let n = ue_max(r, 255)?;
+            for _ in 0..n {
+                r.se()?;
+            }
+        }
+        _ => {}
+    }
+    r.ue()?; // max_num_ref_frames
+    r.skip(1)?; // gaps_in_frame_num_value_allowed_flag
+    let width_in_mbs = r.ue()? as u64 + 1;
+    let height_in_map_units = r.ue()? as u64 + 1;
+    let frame_mbs_only = r.bit()? as u64;
+    if frame_mbs_only == 0 {
+        r.skip(1)?; // mb_adaptive_frame_field_flag
+    }
+    r.skip(1)?; // direct_8x8_inference_flag
+
+    let mut crop = [0u64; 4];
+    if r.bit()? {
+        for c in crop.iter_mut() {
+            *c = r.ue()? as u64;
+        }
+    }
+    let chroma_array_type = if separate_colour_plane {
+        0
+    } else {
+        chroma_format_idc
+    };
+    let (sub_w, sub_h) = match chroma_array_type {
+        1 => (2, 2),
+        2 => (2, 1),
+        _ => (1, 1),
+    };
+    let crop_unit_x = sub_w;
+    let crop_unit_y = sub_h * (2 - frame_mbs_only);
+    let width = (width_in_mbs * 16).checked_sub(crop_unit_x * (crop[0] + crop[1]))?;
+    let height = ((2 - frame_mbs_only) * height_in_map_units * 16)
+        .checked_sub(crop_unit_y * (crop[2] + crop[3]))?;
+
+    let mut res = Sps {
+        profile_idc,
+        level_idc,
+        chroma_format_idc,
+        bit_depth_luma,
+        bit_depth_chroma,
+        width: u32::try_from(width).ok()?,
+        height: u32::try_from(height).ok()?,
+        sample_aspect_ratio: None,
+        video_signal: None,
+        timing: None,
+        hevc_ptl: None,
+    };
+
+    // vui_parameters_present_flag
+    if r.bit()? {
+        res.sample_aspect_ratio = read_sar(r)?;
+        if r.bit()? {
+            r.skip(1)?; // overscan_appropriate_flag
+        }
+        res.video_signal = read_video_signal(r)?;
+        if r.bit()? {
+            r.ue()?; // chroma_sample_loc_type_top_field
+            r.ue()?; // chroma_sample_loc_type_bottom_field
+        }
+        res.timing = read_timing(r)?;
+    }
+
+    Some(res)
+}
+
+fn read_hevc_ptl(r: &mut BitReader, max_sub_layers_minus1: u8) -> Option<(HevcPtl, u8)> {
+    let b = r.u8(8)?;
+    let profile_compatibility_flags = r.bits(32)?;
+    let hi = r.bits(16)? as u64;
+    let lo = r.bits(32)? as u64;
+    let level_idc = r.u8(8)?;
+
+    let mut sub_layer_profile_present = [false; 8];
+    let mut sub_layer_level_present = [false; 8];
+    for i in 0..max_sub_layers_minus1 as usize {
+        sub_layer_profile_present[i] = r.bit()?;
+        sub_layer_level_present[i] = r.bit()?;
+    }
+    if max_sub_layers_minus1 > 0 {
+        for _ in max_sub_layers_minus1..8 {
+            r.skip(2)?; // reserved_zero_2bits
+        }
+    }
+    for i in 0..max_sub_layers_minus1 as usize {
+        if sub_layer_profile_present[i] {
+            r.skip(88)?;
+        }
+        if sub_layer_level_present[i] {
+            r.skip(8)?;
+        }
+    }
+
+    let ptl = HevcPtl {
+        profile_space: b >> 6,
+        tier_flag: b & 0x20 != 0,
+        profile_idc: b & 0x1f,
+        profile_compatibility_flags,
+        constraint_indicator_flags: (hi << 32) | lo,
+        max_sub_layers: max_sub_layers_minus1 + 1,
+        temporal_id_nesting: false,
+    };
+    Some((ptl, level_idc))
+}
+
+fn skip_hevc_scaling_list_data(r: &mut BitReader) -> Option<()> {
+    for size_id in 0..4 {
+        let step = if size_id == 3 { 3 } else { 1 };
+        for _ in (0..6).step_by(step) {
+            if !r.bit()? {
+                r.ue()?; // scaling_list_pred_matrix_id_delta
+            } else {
+                let coef_num = 64.min(1 << (4 + (size_id << 1)));
+                if size_id > 1 {
+                    r.se()?; // scaling_list_dc_coef_minus8
+                }
+                for _ in 0..coef_num {
+                    r.se()?;
+                }
+            }
+        }
+    }
+    Some(())
+}
+
+/// Skips `st_ref_pic_set(idx)` and returns its NumDeltaPocs.
+fn skip_hevc_st_ref_pic_set(r: &mut BitReader, idx: u32, num_delta_pocs: &[u32]) -> Option<u32> {
+    let inter_ref_pic_set_prediction = idx != 0 && r.bit()?;
+    if inter_ref_pic_set_prediction {
+        r.skip(1)?; // delta_rps_sign
+        r.ue()?; // abs_delta_rps_minus1
+        let ref_num = *num_delta_pocs.get(idx as usize - 1)?;
+        let mut res = 0;
+        for _ in 0..=ref_num {
+            let used_by_curr_pic = r.bit()?;
+            let use_delta = if used_by_curr_pic { true } else { r.bit()? };
+            if use_delta {
+                res += 1;
+            }
+        }
+        Some(res)
+    } else {
+        let neg = r.ue()?;
+        let pos = r.ue()?;
+        if neg > 16 || pos > 16 {
+            return None;
+        }
+        for _ in 0..neg + pos {
+            r.ue()?; // delta_poc_sx_minus1
+            r.skip(1)?; // used_by_curr_pic_sx_flag
+        }
+        Some(neg + pos)
+    }
+}
+
+fn read_hevc_sps(r: &mut BitReader) -> Option<Sps> {
+    r.skip(4)?; // sps_video_parameter_set_id
+    let max_sub_layers_minus1 = r.u8(3)?;
+    let temporal_id_nesting = r.bit()?;
+    let (mut ptl, level_idc) = read_hevc_ptl(r, max_sub_layers_minus1)?;
+    ptl.temporal_id_nesting = temporal_id_nesting;
+
+    r.ue()?; // sps_seq_parameter_set_id
+    let chroma_format_idc = ue_max(r, 3)? as u8;
+    let separate_colour_plane = chroma_format_idc == 3 && r.bit()?;
+    let mut width = r.ue()? as u64;
+    let mut height = r.ue()? as u64;
+    if r.bit()? {
+        let chroma_array_type = if separate_colour_plane {
+            0
+        } else {
+            chroma_format_idc
+        };
+        let (sub_w, sub_h) = match chroma_array_type {
+            1 => (2, 2),
+            2 => (2, 1),
+            _ => (1, 1),
+        };
+        let left = r.ue()? as u64;
+        let right = r.ue()? as u64;
+        let top = r.ue()? as u64;
+        let bottom = r.ue()? as u64;
+        width = width.checked_sub(sub_w * (left + right))?;
+        height = height.checked_sub(sub_h * (top + bottom))?;
+    }
+    let bit_depth_luma = ue_max(r, 8)? as u8 + 8;
+    let bit_depth_chroma = ue_max(r, 8)? as u8 + 8;
+
+    let mut res = Sps {
+        profile_idc: ptl.profile_idc,
+        level_idc,
+        chroma_format_idc,
+        bit_depth_luma,
+        bit_depth_chroma,
+        width: u32::try_from(width).ok()?,
+        height: u32::try_from(height).ok()?,
+        sample_aspect_ratio: None,
+        video_signal: None,
+        timing: None,
+        hevc_ptl: Some(ptl),
+    };
+
+    // Everything below is only needed to reach VUI. If the SPS is truncated
+    // or uses something we don't understand, return what we have.
+    let _ = read_hevc_vui(r, max_sub_layers_minus1, &mut res);
+
+    Some(res)
+}
+
+fn read_hevc_vui(r: &mut BitReader, max_sub_layers_minus1: u8, sps: &mut Sps) -> Option<()> {
+    let log2_max_poc_lsb = ue_max(r, 12)? + 4;
+    let sub_layer_ordering_info_present = r.bit()?;
+    let first = if sub_layer_ordering_info_present {
+        0
+    } else {
+        max_sub_layers_minus1
+    };
+    for _ in first..=max_sub_layers_minus1 {
+        r.ue()?; // sps_max_dec_pic_buffering_minus1
+        r.ue()?; // sps_max_num_reorder_pics
+        r.ue()?; // sps_max_latency_increase_plus1
+    }
+    r.ue()?; // log2_min_luma_coding_block_size_minus3
+    r.ue()?; // log2_diff_max_min_luma_coding_block_size
+    r.ue()?; // log2_min_luma_transform_block_size_minus2
+    r.ue()?; // log2_diff_max_min_luma_transform_block_size
+    r.ue()?; // max_transform_hierarchy_depth_inter
+    r.ue()?; // max_transform_hierarchy_depth_intra
+    if r.bit()? && r.bit()? {
+        skip_hevc_scaling_list_data(r)?;
+    }
+    r.skip(2)?; // amp_enabled_flag, sample_adaptive_offset_enabled_flag
+    if r.bit()? {
+        // pcm sample bit depths, log2 sizes and loop filter flag
+        r.skip(8)?;
+        r.ue()?;
+        r.ue()?;
+        r.skip(1)?;
+    }
+    let num_short_term_ref_pic_sets = r.ue()?;
+    if num_short_term_ref_pic_sets > 64 {
+        return None;
+    }
+    let mut num_delta_pocs = Vec::with_capacity(num_short_term_ref_pic_sets as usize);
+    for i in 0..num_short_term_ref_pic_sets {
+        let n = skip_hevc_st_ref_pic_set(r, i, &num_delta_pocs)?;
+        num_delta_pocs.push(n);
+    }
+    if r.bit()? {
+        let n = r.ue()?;
+        for _ in 0..n {
+            r.skip(log2_max_poc_lsb as usize + 1)?; // lt_ref_pic_poc_lsb_sps, used_by_curr_pic_lt_sps_flag
+        }
+    }
+    r.skip(2)?; // sps_temporal_mvp_enabled_flag, strong_intra_smoothing_enabled_flag
+
+    // vui_parameters_present_flag
+    if !r.bit()? {
+        return Some(());
+    }
+    sps.sample_aspect_ratio = read_sar(r)?;
+    if r.bit()? {
+        r.skip(1)?; // overscan_appropriate_flag
+    }
+    sps.video_signal = read_video_signal(r)?;
+    if r.bit()? {
+        r.ue()?; // chroma_sample_loc_type_top_field
+        r.ue()?; // chroma_sample_loc_type_bottom_field
+    }
+    r.skip(3)?; // neutral_chroma_indication_flag, field_seq_flag, frame_field_info_present_flag
+    if r.bit()? {
+        // default display window offsets
+        for _ in 0..4 {
+            r.ue()?;
+        }
+    }
+    sps.timing = read_timing(r)?;
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bits::{BitReader, BitWriter},
+        cm,
+    };
+
+    // 1920x1080 High@4.0 4:2:0 8-bit, cropped from 1088, VUI with bt709 full range and timing
+    const AVC_SPS: &[u8] = &[
+        0x67, 0x64, 0x00, 0x28, 0xac, 0xd9, 0x40, 0x78, 0x02, 0x27, 0xe5, 0xc0, 0x5b, 0x80, 0x80,
+        0x80, 0xa0, 0x00, 0x00, 0x03, 0x00, 0x20, 0x00, 0x00, 0x0f, 0x10, 0x80,
+    ];
+    const AVC_PPS: &[u8] = &[0x68, 0xeb, 0xe3, 0xcb, 0x22, 0xc0];
+
+    // 1920x1080 Main@4.0 with two short-term RPS (one predicted) and BT.2020 PQ VUI
+    const HEVC_VPS: &[u8] = &[
+        0x40, 0x01, 0x0c, 0x01, 0xff, 0xff, 0x01, 0x60, 0x00, 0x00, 0x03, 0x00, 0x90, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x03, 0x00, 0x78, 0x95, 0x98, 0x09,
+    ];
+    const HEVC_SPS: &[u8] = &[
+        0x42, 0x01, 0x01, 0x01, 0x60, 0x00, 0x00, 0x03, 0x00, 0x90, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x03, 0x00, 0x78, 0xa0, 0x03, 0xc0, 0x80, 0x11, 0x07, 0xcb, 0x96, 0x57, 0x92, 0x4c, 0x9a,
+        0xfe, 0xbc, 0x05, 0xa8, 0x48, 0x80, 0x48, 0x20, 0x00, 0x00, 0x7d, 0x20, 0x00, 0x1d, 0x4c,
+        0x01,
+    ];
+    const HEVC_PPS: &[u8] = &[0x44, 0x01, 0xc1, 0x73, 0xd1, 0x89];
+
+    #[test]
+    fn avc() {
+        let sps = cm::Sps::from_h264(AVC_SPS).unwrap();
+        assert_eq!(sps.profile_idc, 100);
+        assert_eq!(sps.level_idc, 40);
+        assert_eq!(sps.chroma_format_idc, 1);
+        assert_eq!(sps.bit_depth_luma, 8);
+        assert_eq!(
+            sps.dims(),
+            cm::VideoDimensions {
+                width: 1920,
+                height: 1080
+            }
+        );
+        assert_eq!(sps.sample_aspect_ratio, Some((1, 1)));
+        let signal = sps.video_signal.unwrap();
+        assert!(signal.full_range);
+        assert_eq!(signal.colour_primaries, 1);
+        assert_eq!(signal.transfer_characteristics, 1);
+        assert_eq!(signal.matrix_coeffs, 1);
+        assert_eq!(sps.timing, Some((1, 120)));
+
+        let cfg = cm::AvcCfg::with_param_sets(&[AVC_SPS], &[AVC_PPS], 4).unwrap();
+        assert_eq!(cfg.ext.as_ref().unwrap().chroma_format_idc, 1);
+        let bytes = cfg.to_vec().unwrap();
+        assert_eq!(&bytes[..6], &[1, 0x64, 0x00, 0x28, 0xff, 0xe1]);
+
+        let parsed = cm::AvcCfg::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, cfg);
+        assert_eq!(parsed.param_sets().count(), 2);
+        assert_eq!(parsed.sps_info().unwrap(), sps);
+
+        // baseline record without extension
+        let mut bytes = bytes;
+        bytes.truncate(bytes.len() - 4);
+        let parsed = cm::AvcCfg::from_bytes(&bytes).unwrap();
+        assert!(parsed.ext.is_none());
+
+        assert!(cm::AvcCfg::from_bytes(&bytes[..10]).is_err());
+        assert!(cm::AvcCfg::with_param_sets(&[AVC_SPS], &[AVC_PPS], 3).is_err());
+    }
+
+    #[test]
+    fn hevc() {
+        let sps = cm::Sps::from_hevc(HEVC_SPS).unwrap();
+        assert_eq!(sps.profile_idc, 1);
+        assert_eq!(sps.level_idc, 120);
+        assert_eq!(sps.chroma_format_idc, 1);
+        assert_eq!(sps.bit_depth_luma, 8);
+        assert_eq!(sps.bit_depth_chroma, 8);
+        assert_eq!(
+            sps.dims(),
+            cm::VideoDimensions {
+                width: 1920,
+                height: 1080
+            }
+        );
+        let signal = sps.video_signal.unwrap();
+        assert!(!signal.full_range);
+        assert_eq!(signal.colour_primaries, 9);
+        assert_eq!(signal.transfer_characteristics, 16);
+        assert_eq!(signal.matrix_coeffs, 9);
+        assert_eq!(sps.timing, Some((1001, 60000)));
+
+        let cfg = cm::HevcCfg::with_param_sets(&[HEVC_VPS], &[HEVC_SPS], &[HEVC_PPS], 4).unwrap();
+        assert_eq!(cfg.general_profile_idc, 1);
+        assert_eq!(cfg.general_profile_compatibility_flags, 0x6000_0000);
+        assert_eq!(cfg.general_constraint_indicator_flags, 0x9000_0000_0000);
+
+        let bytes = cfg.to_vec().unwrap();
+        assert_eq!(
+            bytes.len(),
+            23 + 3 * 3 + HEVC_VPS.len() + HEVC_SPS.len() + HEVC_PPS.len() + 3 * 2
+        );
+        let parsed = cm::HevcCfg::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, cfg);
+        assert_eq!(parsed.vps().next(), Some(HEVC_VPS));
+        assert_eq!(parsed.pps().next(), Some(HEVC_PPS));
+        assert_eq!(parsed.param_sets().count(), 3);
+        assert_eq!(parsed.sps_info().unwrap(), sps);
+
+        assert!(cm::HevcCfg::from_bytes(&bytes[..22]).is_err());
+        assert!(cm::Sps::from_hevc(AVC_SPS).is_err());
+
+        let mut cfg = cfg;
+        cfg.min_spatial_segmentation_idc = 0x1234;
+        let bytes = cfg.to_vec().unwrap();
+        assert_eq!(&bytes[13..15], &[0xf2, 0x34]);
+        let parsed = cm::HevcCfg::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed.min_spatial_segmentation_idc, 0x234);
+    }
+
+    fn ue(w: &mut BitWriter, v: u32) {
+        let v = v as u64 + 1;
+        let n = 64 - v.leading_zeros();
+        w.bits(n - 1, 0);
+        w.bits(n, v as u32);
+    }
+
+    /// High profile SPS of 1920x1088 without VUI.
+    fn high_sps(chroma_format_idc: u32, bit_depth_luma_minus8: u32, log2_poc_lsb: u32) -> Vec<u8> {
+        let mut buf = vec![0x67, 100, 0, 40];
+        let mut w = BitWriter::new(&mut buf);
+        ue(&mut w, 0); // seq_parameter_set_id
+        ue(&mut w, chroma_format_idc);
+        if chroma_format_idc == 3 {
+            w.bit(false); // separate_colour_plane
+        }
+        ue(&mut w, bit_depth_luma_minus8);
+        ue(&mut w, 0); // bit_depth_chroma_minus8
+        w.bits(2, 0); // qpprime_y_zero_transform_bypass, seq_scaling_matrix_present
+        ue(&mut w, 0); // log2_max_frame_num_minus4
+        ue(&mut w, 0); // pic_order_cnt_type
+        ue(&mut w, log2_poc_lsb);
+        ue(&mut w, 1); // max_num_ref_frames
+        w.bit(false); // gaps_in_frame_num_value_allowed
+        ue(&mut w, 119);
+        ue(&mut w, 67);
+        w.bits(4, 0b1100); // frame_mbs_only, direct_8x8_inference, no cropping, no VUI
+        w.bit(true);
+        buf
+    }
+
+    #[test]
+    fn out_of_range() {
+        let sps = cm::Sps::from_h264(&high_sps(1, 2, 0)).unwrap();
+        assert_eq!((sps.width, sps.height), (1920, 1088));
+        assert_eq!(sps.bit_depth_luma, 10);
+
+        assert!(cm::Sps::from_h264(&high_sps(4, 0, 0)).is_err());
+        assert!(cm::Sps::from_h264(&high_sps(256, 0, 0)).is_err());
+        assert!(cm::Sps::from_h264(&high_sps(1, 7, 0)).is_err());
+        assert!(cm::Sps::from_h264(&high_sps(1, 248, 0)).is_err());
+        assert!(cm::Sps::from_h264(&high_sps(1, 0, 13)).is_err());
+        assert!(cm::Sps::from_h264(&high_sps(1, 0, u32::MAX - 1)).is_err());
+
+        // High 4:4:4 Predictive carries extension too
+        let mut sps = high_sps(3, 0, 0);
+        sps[1] = 244;
+        let sps = sps.as_slice();
+        let cfg = cm::AvcCfg::with_param_sets(&[sps], &[AVC_PPS], 4).unwrap();
+        assert_eq!(cfg.ext.as_ref().unwrap().chroma_format_idc, 3);
+
+        // nothing is written on error
+        let mut cfg = cfg;
+        cfg.pps.push(vec![0; 0x10000]);
+        let mut buf = vec![0xaa];
+        assert!(cfg.write_to(&mut buf).is_err());
+        assert_eq!(buf, [0xaa]);
+    }
+
+    #[test]
+    fn nal_unit_len() {
+        let cfg = cm::AvcCfg::with_param_sets(&[AVC_SPS], &[AVC_PPS], 4).unwrap();
+        let mut bytes = cfg.to_vec().unwrap();
+        bytes[4] = 0xfe;
+        assert!(cm::AvcCfg::from_bytes(&bytes).is_err());
+
+        let cfg = cm::HevcCfg::with_param_sets(&[HEVC_VPS], &[HEVC_SPS], &[HEVC_PPS], 4).unwrap();
+        let mut bytes = cfg.to_vec().unwrap();
+        bytes[21] = (bytes[21] & !0b11) | 0b10;
+        assert!(cm::HevcCfg::from_bytes(&bytes).is_err());
+        bytes[21] |= 0b11;
+        assert_eq!(cm::HevcCfg::from_bytes(&bytes).unwrap(), cfg);
+    }
+
+    #[test]
+    fn scaling_list() {
+        let skip = |delta: i32| {
+            let mut buf = vec![];
+            let mut w = BitWriter::new(&mut buf);
+            let v = if delta > 0 {
+                delta as u32 * 2 - 1
+            } else {
+                delta.unsigned_abs() * 2
+            };
+            ue(&mut w, v);
+            w.bit(true);
+            super::skip_h264_scaling_list(&mut BitReader::new(&buf), 1)
+        };
+        assert!(skip(127).is_some());
+        assert!(skip(-128).is_some());
+        assert!(skip(128).is_none());
+        assert!(skip(-129).is_none());
+        assert!(skip(i32::MAX).is_none());
+    }
+}
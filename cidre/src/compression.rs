@@ -1,3 +1,8 @@
+pub mod lz4;
+mod lz77;
+pub mod lzfse;
+pub mod lzvn;
+
 #[doc(alias = "compression_algorithm")]
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[repr(transparent)]
@@ -15,6 +20,11 @@ impl Algorithm {
 /// Apple-specific algorithms
 impl Algorithm {
     pub const LZFSE: Self = Self(0x801);
+
+    /// Only with libcompression on Apple platforms.
+    ///
+    /// The format is undocumented, so unlike LZFSE it has no pure Rust codec
+    /// and [`decode`] returns [`Error::Unsupported`] for it.
     pub const LZBITMAP: Self = Self(0x702);
 }
 
@@ -35,6 +45,61 @@ pub enum Status {
     End = 1,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// No pure Rust implementation for the algorithm.
+    Unsupported(Algorithm),
+
+    /// Input ended in the middle of a block.
+    Truncated,
+
+    /// Malformed stream, with the reason.
+    Corrupt(&'static str),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported(algorithm) => write!(f, "unsupported algorithm {:#x}", algorithm.0),
+            Self::Truncated => f.write_str("truncated compressed stream"),
+            Self::Corrupt(reason) => write!(f, "corrupt compressed stream: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Pure Rust decoder for [`Algorithm::LZFSE`], [`Algorithm::LZ4`] and [`Algorithm::LZ4_RAW`].
+///
+/// Available on every platform, `decode_buf` and `stream` fall back to it
+/// where libcompression is missing. [`Algorithm::LZBITMAP`] isn't covered.
+pub fn decode(src: &[u8], algorithm: Algorithm) -> Result<Vec<u8>, Error> {
+    decode_prefix(src, algorithm, usize::MAX)
+}
+
+/// Like [`decode`], but stops once `limit` bytes are decoded.
+///
+/// The rest of the input isn't checked then.
+pub fn decode_prefix(src: &[u8], algorithm: Algorithm, limit: usize) -> Result<Vec<u8>, Error> {
+    match algorithm {
+        Algorithm::LZFSE => lzfse::decode_prefix(src, limit),
+        Algorithm::LZ4 => lz4::decode_prefix(src, limit),
+        Algorithm::LZ4_RAW => lz4::decode_raw_prefix(src, limit),
+        _ => Err(Error::Unsupported(algorithm)),
+    }
+}
+
+/// Pure Rust encoder for [`Algorithm::LZFSE`], [`Algorithm::LZ4`] and [`Algorithm::LZ4_RAW`].
+pub fn encode(src: &[u8], algorithm: Algorithm) -> Result<Vec<u8>, Error> {
+    match algorithm {
+        Algorithm::LZFSE => Ok(lzfse::encode(src)),
+        Algorithm::LZ4 => Ok(lz4::encode(src)),
+        Algorithm::LZ4_RAW => Ok(lz4::encode_raw(src)),
+        _ => Err(Error::Unsupported(algorithm)),
+    }
+}
+
+#[cfg(target_vendor = "apple")]
 #[doc(alias = "compression_encode_buffer")]
 #[inline]
 pub fn encode_buf(dst: &mut [u8], src: &[u8], algorithm: Algorithm) -> usize {
@@ -50,6 +115,7 @@ pub fn encode_buf(dst: &mut [u8], src: &[u8], algorithm: Algorithm) -> usize {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 #[doc(alias = "compression_decode_buffer")]
 #[inline]
 pub fn decode_buf(dst: &mut [u8], src: &[u8], algorithm: Algorithm) -> usize {
@@ -65,8 +131,10 @@ pub fn decode_buf(dst: &mut [u8], src: &[u8], algorithm: Algorithm) -> usize {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 const COMPRESSION_STREAM_FINALIZE: i32 = 0x0001;
 
+#[cfg(target_vendor = "apple")]
 #[doc(alias = "compression_stream_init")]
 #[doc(alias = "compression_stream_process")]
 #[doc(alias = "compression_stream_destroy")]
@@ -113,6 +181,51 @@ pub fn stream(op: StreamOp, src: &[u8], algorithm: Algorithm) -> Result<Vec<u8>,
     }
 }
 
+/// Returns 0 if `dst` is too small, like libcompression.
+#[cfg(not(target_vendor = "apple"))]
+pub fn encode_buf(dst: &mut [u8], src: &[u8], algorithm: Algorithm) -> usize {
+    match encode(src, algorithm) {
+        Ok(buf) if buf.len() <= dst.len() => {
+            dst[..buf.len()].copy_from_slice(&buf);
+            buf.len()
+        }
+        _ => 0,
+    }
+}
+
+/// Decodes no more than `dst` holds, returns 0 on error like libcompression.
+#[cfg(not(target_vendor = "apple"))]
+pub fn decode_buf(dst: &mut [u8], src: &[u8], algorithm: Algorithm) -> usize {
+    match decode_prefix(src, algorithm, dst.len()) {
+        Ok(buf) => {
+            dst[..buf.len()].copy_from_slice(&buf);
+            buf.len()
+        }
+        Err(_) => 0,
+    }
+}
+
+#[cfg(not(target_vendor = "apple"))]
+pub fn stream(op: StreamOp, src: &[u8], algorithm: Algorithm) -> Result<Vec<u8>, Status> {
+    match op {
+        StreamOp::Encode => encode(src, algorithm),
+        StreamOp::Decode => decode(src, algorithm),
+    }
+    .map_err(|_| Status::Err)
+}
+
+/// Pure Rust codecs work without scratch buffers.
+#[cfg(not(target_vendor = "apple"))]
+pub fn encode_scratch_buf_len(_algorithm: Algorithm) -> usize {
+    0
+}
+
+#[cfg(not(target_vendor = "apple"))]
+pub fn decode_scratch_buf_len(_algorithm: Algorithm) -> usize {
+    0
+}
+
+#[cfg(target_vendor = "apple")]
 #[link(name = "compression", kind = "dylib")]
 unsafe extern "C" {
     #[doc(alias = "compression_encode_scratch_buffer_size")]
@@ -152,6 +265,7 @@ unsafe extern "C" {
     fn compression_stream_destroy(stream: *mut Stream) -> Status;
 }
 
+#[cfg(target_vendor = "apple")]
 #[repr(C)]
 struct Stream {
     dst_ptr: *mut u8,
@@ -164,6 +278,8 @@ struct Stream {
 #[cfg(test)]
 mod tests {
     use crate::compression::*;
+
+    #[cfg(target_vendor = "apple")]
     #[test]
     fn basics() {
         let len = encode_scratch_buf_len(Algorithm::ZLIB);
@@ -194,4 +310,153 @@ mod tests {
         let decoded_buf = stream(StreamOp::Decode, &encoded_buf, Algorithm::LZMA).unwrap();
         assert_eq!(&decoded_buf, data.as_bytes());
     }
+
+    fn samples() -> Vec<Vec<u8>> {
+        let mut x = 0x1234_5678u32;
+        let noise: Vec<u8> = (0..100_000)
+            .map(|_| {
+                x ^= x << 13;
+                x ^= x >> 17;
+                x ^= x << 5;
+                x as u8
+            })
+            .collect();
+        let text = b"The quick brown fox jumps over the lazy dog. ".repeat(3_000);
+        let mixed: Vec<u8> = noise
+            .chunks(1_000)
+            .zip(text.chunks(700))
+            .flat_map(|(a, b)| [&a[..a.len() / 8], b].concat())
+            .collect();
+        vec![
+            vec![],
+            b"a".to_vec(),
+            b"hello compression!!!!".to_vec(),
+            vec![0; 70_000],
+            noise,
+            text,
+            mixed,
+        ]
+    }
+
+    #[test]
+    fn lz4_raw() {
+        // lz4 -9 block of "The quick brown fox jumps over the lazy dog. " x4 + "END"
+        let block = [
+            0xff, 0x1e, 0x54, 0x68, 0x65, 0x20, 0x71, 0x75, 0x69, 0x63, 0x6b, 0x20, 0x62, 0x72,
+            0x6f, 0x77, 0x6e, 0x20, 0x66, 0x6f, 0x78, 0x20, 0x6a, 0x75, 0x6d, 0x70, 0x73, 0x20,
+            0x6f, 0x76, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x7a, 0x79, 0x20,
+            0x64, 0x6f, 0x67, 0x2e, 0x20, 0x2d, 0x00, 0x72, 0x50, 0x2e, 0x20, 0x45, 0x4e, 0x44,
+        ];
+        let mut expected = b"The quick brown fox jumps over the lazy dog. ".repeat(4);
+        expected.extend_from_slice(b"END");
+        assert_eq!(decode(&block, Algorithm::LZ4_RAW).unwrap(), expected);
+
+        assert_eq!(
+            decode(&block[..20], Algorithm::LZ4_RAW),
+            Err(Error::Truncated)
+        );
+        assert!(matches!(
+            lz4::decode_raw(&[0x10, b'a', 0x05, 0x00]),
+            Err(Error::Corrupt(_))
+        ));
+    }
+
+    #[test]
+    fn round_trip() {
+        for algorithm in [Algorithm::LZFSE, Algorithm::LZ4, Algorithm::LZ4_RAW] {
+            for data in samples() {
+                let encoded = encode(&data, algorithm).unwrap();
+                assert_eq!(decode(&encoded, algorithm).unwrap(), data);
+            }
+        }
+        for data in samples() {
+            assert_eq!(lzvn::decode(&lzvn::encode(&data)).unwrap(), data);
+        }
+
+        let text = b"The quick brown fox jumps over the lazy dog. ".repeat(3_000);
+        assert!(lzfse::encode(&text).len() < text.len() / 20);
+        assert_eq!(lzfse::encode(&[]), b"bvx$");
+        assert_eq!(
+            decode(b"bvx-\x03\0\0\0abcbvx$", Algorithm::LZFSE).unwrap(),
+            b"abc"
+        );
+        assert_eq!(decode(b"bvx-", Algorithm::LZFSE), Err(Error::Truncated));
+        assert_eq!(
+            decode(b"", Algorithm::ZLIB),
+            Err(Error::Unsupported(Algorithm::ZLIB))
+        );
+    }
+
+    #[test]
+    fn prefix() {
+        for algorithm in [Algorithm::LZFSE, Algorithm::LZ4, Algorithm::LZ4_RAW] {
+            for data in samples() {
+                let encoded = encode(&data, algorithm).unwrap();
+                for limit in [0, 1, 100, 5_000, data.len(), data.len() + 5] {
+                    let decoded = decode_prefix(&encoded, algorithm, limit).unwrap();
+                    assert_eq!(decoded, &data[..limit.min(data.len())], "{algorithm:?}");
+                }
+            }
+        }
+
+        // decoding stops at `dst` instead of inflating everything
+        let zeros = vec![0u8; 1 << 24];
+        for algorithm in [Algorithm::LZFSE, Algorithm::LZ4, Algorithm::LZ4_RAW] {
+            let encoded = encode(&zeros, algorithm).unwrap();
+            let mut dst = [1u8; 16];
+            assert_eq!(decode_buf(&mut dst, &encoded, algorithm), 16);
+            assert_eq!(dst, [0; 16]);
+        }
+
+        // no pure Rust codec, libcompression only
+        assert_eq!(
+            decode_prefix(b"", Algorithm::LZBITMAP, 1),
+            Err(Error::Unsupported(Algorithm::LZBITMAP))
+        );
+    }
+
+    #[test]
+    fn buf() {
+        let data = b"hello hello hello hello hello hello!";
+        let mut encoded = vec![0u8; 128];
+        let n = encode_buf(&mut encoded, data, Algorithm::LZFSE);
+        assert!(n > 0);
+
+        let mut decoded = vec![0u8; 128];
+        let len = decode_buf(&mut decoded, &encoded[..n], Algorithm::LZFSE);
+        assert_eq!(&decoded[..len], data);
+
+        let decoded = stream(StreamOp::Decode, &encoded[..n], Algorithm::LZFSE).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    /// libcompression output must decode with the pure Rust codecs and vice versa.
+    #[cfg(target_vendor = "apple")]
+    #[test]
+    fn libcompression() {
+        for algorithm in [Algorithm::LZFSE, Algorithm::LZ4, Algorithm::LZ4_RAW] {
+            for data in samples() {
+                if data.is_empty() {
+                    continue;
+                }
+                let mut buf = vec![0u8; data.len() * 2 + 1024];
+                let n = encode_buf(&mut buf, &data, algorithm);
+                assert!(n > 0);
+                assert_eq!(decode(&buf[..n], algorithm).unwrap(), data);
+
+                let encoded = encode(&data, algorithm).unwrap();
+                let mut decoded = vec![0u8; data.len()];
+                let n = decode_buf(&mut decoded, &encoded, algorithm);
+                assert_eq!(n, data.len());
+                assert_eq!(decoded, data);
+            }
+        }
+
+        // large compressible input gets FSE coded `bvx2` blocks from Apple's encoder
+        let text = b"The quick brown fox jumps over the lazy dog. ".repeat(3_000);
+        let mut buf = vec![0u8; text.len()];
+        let n = encode_buf(&mut buf, &text, Algorithm::LZFSE);
+        assert_eq!(&buf[..4], b"bvx2");
+        assert_eq!(decode(&buf[..n], Algorithm::LZFSE).unwrap(), text);
+    }
 }
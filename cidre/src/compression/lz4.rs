@@ -0,0 +1,172 @@
+use crate::compression::{Error, lz77, lzvn::copy_match};
+
+const BLOCK_MAGIC: &[u8; 4] = b"bv41";
+const RAW_BLOCK_MAGIC: &[u8; 4] = b"bv4-";
+const END_MAGIC: &[u8; 4] = b"bv4$";
+
+/// Largest input covered by a single `bv41` block.
+const BLOCK_SIZE: usize = 1 << 20;
+
+fn u32_at(src: &[u8], pos: usize) -> Result<usize, Error> {
+    let b = src.get(pos..pos + 4).ok_or(Error::Truncated)?;
+    Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+}
+
+/// Decodes a raw LZ4 block, the format of [`crate::compression::Algorithm::LZ4_RAW`].
+///
+/// ```
+/// use cidre::compression::lz4;
+///
+/// let data = b"hello hello hello hello!".to_vec();
+/// assert_eq!(lz4::decode_raw(&lz4::encode_raw(&data)).unwrap(), data);
+/// ```
+pub fn decode_raw(src: &[u8]) -> Result<Vec<u8>, Error> {
+    decode_raw_prefix(src, usize::MAX)
+}
+
+/// Like [`decode_raw`], but stops once `limit` bytes are decoded.
+pub fn decode_raw_prefix(src: &[u8], limit: usize) -> Result<Vec<u8>, Error> {
+    let mut dst = Vec::with_capacity((src.len() * 2).min(limit));
+    decode_raw_into(src, &mut dst, limit)?;
+    dst.truncate(limit);
+    Ok(dst)
+}
+
+fn ext_len(src: &[u8], pos: &mut usize, mut len: usize) -> Result<usize, Error> {
+    if len == 15 {
+        loop {
+            let b = *src.get(*pos).ok_or(Error::Truncated)?;
+            *pos += 1;
+            len += b as usize;
+            if b != 255 {
+                break;
+            }
+        }
+    }
+    Ok(len)
+}
+
+fn decode_raw_into(src: &[u8], dst: &mut Vec<u8>, limit: usize) -> Result<(), Error> {
+    let mut pos = 0;
+    while pos < src.len() && dst.len() < limit {
+        let token = src[pos];
+        pos += 1;
+
+        let literals = ext_len(src, &mut pos, (token >> 4) as usize)?;
+        let lits = src.get(pos..pos + literals).ok_or(Error::Truncated)?;
+        dst.extend_from_slice(lits);
+        pos += literals;
+
+        // the last sequence carries literals only
+        if pos == src.len() {
+            break;
+        }
+
+        let d = src.get(pos..pos + 2).ok_or(Error::Truncated)?;
+        let d = u16::from_le_bytes([d[0], d[1]]) as usize;
+        pos += 2;
+        if d == 0 || d > dst.len() {
+            return Err(Error::Corrupt("LZ4 match offset out of range"));
+        }
+        let len = ext_len(src, &mut pos, (token & 0xf) as usize)? + 4;
+        copy_match(dst, d, len);
+    }
+    Ok(())
+}
+
+/// Decodes the block framed format of [`crate::compression::Algorithm::LZ4`].
+pub fn decode(src: &[u8]) -> Result<Vec<u8>, Error> {
+    decode_prefix(src, usize::MAX)
+}
+
+/// Like [`decode`], but stops once `limit` bytes are decoded.
+pub fn decode_prefix(src: &[u8], limit: usize) -> Result<Vec<u8>, Error> {
+    let mut dst = Vec::with_capacity((src.len() * 2).min(limit));
+    let mut pos = 0;
+    loop {
+        if dst.len() >= limit {
+            dst.truncate(limit);
+            return Ok(dst);
+        }
+        let magic = src.get(pos..pos + 4).ok_or(Error::Truncated)?;
+        if magic == END_MAGIC {
+            return Ok(dst);
+        }
+        let raw_len = u32_at(src, pos + 4)?;
+        let start = dst.len();
+        if magic == BLOCK_MAGIC {
+            let payload_len = u32_at(src, pos + 8)?;
+            pos += 12;
+            let payload = src.get(pos..pos + payload_len).ok_or(Error::Truncated)?;
+            decode_raw_into(payload, &mut dst, limit)?;
+            pos += payload_len;
+        } else if magic == RAW_BLOCK_MAGIC {
+            pos += 8;
+            let raw = src.get(pos..pos + raw_len).ok_or(Error::Truncated)?;
+            dst.extend_from_slice(raw);
+            pos += raw_len;
+        } else {
+            return Err(Error::Corrupt("unknown LZ4 block magic"));
+        }
+        if dst.len() - start != raw_len && dst.len() < limit {
+            return Err(Error::Corrupt("LZ4 block size mismatch"));
+        }
+    }
+}
+
+fn push_len(dst: &mut Vec<u8>, mut len: usize) {
+    while len >= 255 {
+        dst.push(255);
+        len -= 255;
+    }
+    dst.push(len as u8);
+}
+
+fn push_seq(dst: &mut Vec<u8>, lits: &[u8], m: Option<(usize, usize)>) {
+    let l = lits.len();
+    let ml = m.map_or(0, |(len, _)| len - 4);
+    dst.push(((l.min(15) << 4) | ml.min(15)) as u8);
+    if l >= 15 {
+        push_len(dst, l - 15);
+    }
+    dst.extend_from_slice(lits);
+    if let Some((_, d)) = m {
+        dst.extend_from_slice(&(d as u16).to_le_bytes());
+        if ml >= 15 {
+            push_len(dst, ml - 15);
+        }
+    }
+}
+
+/// Encodes `src` as a single raw LZ4 block.
+pub fn encode_raw(src: &[u8]) -> Vec<u8> {
+    let parser = lz77::Parser {
+        max_distance: 0xffff,
+        end_literals: 5,
+        end_match_start: 12,
+    };
+    let (seqs, tail) = parser.parse(src);
+    let mut dst = Vec::with_capacity(src.len() / 2 + 16);
+    let mut pos = 0;
+    for seq in seqs {
+        let lits = &src[pos..pos + seq.literals];
+        push_seq(&mut dst, lits, Some((seq.len, seq.distance)));
+        pos += seq.literals + seq.len;
+    }
+    push_seq(&mut dst, &src[pos..pos + tail], None);
+    dst
+}
+
+/// Encodes `src` in the block framed format of [`crate::compression::Algorithm::LZ4`].
+pub fn encode(src: &[u8]) -> Vec<u8> {
+    let mut dst = Vec::with_capacity(src.len() / 2 + 16);
+    for chunk in src.chunks(BLOCK_SIZE) {
+        let payload = encode_raw(chunk);
+        dst.extend_from_slice(BLOCK_MAGIC);
+        dst.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+        dst.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        dst.extend_from_slice(&payload);
+    }
+    dst.extend_from_slice(END_MAGIC);
+    dst
+}
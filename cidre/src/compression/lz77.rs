@@ -0,0 +1,72 @@
+/// Literal run followed by a back reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Seq {
+    pub literals: usize,
+    pub len: usize,
+    pub distance: usize,
+}
+
+/// Greedy single-probe LZ77 parser shared by the encoders.
+pub(super) struct Parser {
+    /// Largest distance the format can encode.
+    pub max_distance: usize,
+    /// Trailing bytes that must stay literals.
+    pub end_literals: usize,
+    /// Matches must start at least this many bytes before the end.
+    pub end_match_start: usize,
+}
+
+const HASH_BITS: u32 = 15;
+const MIN_MATCH: usize = 4;
+
+#[inline]
+fn hash(src: &[u8], pos: usize) -> usize {
+    let v = u32::from_le_bytes([src[pos], src[pos + 1], src[pos + 2], src[pos + 3]]);
+    (v.wrapping_mul(0x9E37_79B1) >> (32 - HASH_BITS)) as usize
+}
+
+impl Parser {
+    /// Splits `src` into sequences, returns them with the number of trailing literals.
+    pub fn parse(&self, src: &[u8]) -> (Vec<Seq>, usize) {
+        let mut seqs = Vec::new();
+        let mut table = vec![usize::MAX; 1 << HASH_BITS];
+        let match_end = src.len().saturating_sub(self.end_literals);
+        let last_start = src
+            .len()
+            .saturating_sub(self.end_match_start.max(MIN_MATCH));
+        let mut anchor = 0;
+        let mut pos = 0;
+        while pos < last_start {
+            let h = hash(src, pos);
+            let candidate = table[h];
+            table[h] = pos;
+            if candidate != usize::MAX
+                && pos - candidate <= self.max_distance
+                && src[candidate..candidate + MIN_MATCH] == src[pos..pos + MIN_MATCH]
+            {
+                let mut len = MIN_MATCH;
+                while pos + len < match_end && src[candidate + len] == src[pos + len] {
+                    len += 1;
+                }
+                if pos + len <= match_end {
+                    seqs.push(Seq {
+                        literals: pos - anchor,
+                        len,
+                        distance: pos - candidate,
+                    });
+                    let end = pos + len;
+                    pos += 1;
+                    while pos < end.min(last_start) {
+                        table[hash(src, pos)] = pos;
+                        pos += 1;
+                    }
+                    pos = end;
+                    anchor = end;
+                    continue;
+                }
+            }
+            pos += 1;
+        }
+        (seqs, src.len() - anchor)
+    }
+}
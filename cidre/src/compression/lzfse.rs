@@ -0,0 +1,880 @@
+use crate::compression::{Error, lz77, lzvn};
+
+const END_MAGIC: &[u8; 4] = b"bvx$";
+const RAW_MAGIC: &[u8; 4] = b"bvx-";
+const V1_MAGIC: &[u8; 4] = b"bvx1";
+const V2_MAGIC: &[u8; 4] = b"bvx2";
+const LZVN_MAGIC: &[u8; 4] = b"bvxn";
+
+/// `sizeof(lzfse_compressed_block_header_v1)`, including tail padding.
+const V1_HEADER_LEN: usize = 772;
+const V2_HEADER_LEN: usize = 32;
+
+const L_SYMBOLS: usize = 20;
+const M_SYMBOLS: usize = 20;
+const D_SYMBOLS: usize = 64;
+const LITERAL_SYMBOLS: usize = 256;
+const FREQ_LEN: usize = L_SYMBOLS + M_SYMBOLS + D_SYMBOLS + LITERAL_SYMBOLS;
+
+const L_STATES: u32 = 64;
+const M_STATES: u32 = 64;
+const D_STATES: u32 = 256;
+const LITERAL_STATES: u32 = 1024;
+
+const MATCHES_PER_BLOCK: usize = 10_000;
+const LITERALS_PER_BLOCK: usize = 4 * MATCHES_PER_BLOCK;
+
+/// Inputs below this size are encoded as a single LZVN block.
+const LZVN_THRESHOLD: usize = 4096;
+
+const L_BITS: [u8; L_SYMBOLS] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 5, 8];
+const M_BITS: [u8; M_SYMBOLS] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 5, 8, 11];
+const D_BITS: [u8; D_SYMBOLS] = {
+    let mut bits = [0u8; D_SYMBOLS];
+    let mut i = 0;
+    while i < D_SYMBOLS {
+        bits[i] = (i / 4) as u8;
+        i += 1;
+    }
+    bits
+};
+
+/// Each symbol covers `base[i]..base[i] + (1 << bits[i])`.
+const fn bases<const N: usize>(bits: &[u8; N]) -> [u32; N] {
+    let mut base = [0u32; N];
+    let mut i = 1;
+    while i < N {
+        base[i] = base[i - 1] + (1 << bits[i - 1]);
+        i += 1;
+    }
+    base
+}
+
+const L_BASE: [u32; L_SYMBOLS] = bases(&L_BITS);
+const M_BASE: [u32; M_SYMBOLS] = bases(&M_BITS);
+const D_BASE: [u32; D_SYMBOLS] = bases(&D_BITS);
+
+const MAX_L: u32 = L_BASE[L_SYMBOLS - 1] + (1 << L_BITS[L_SYMBOLS - 1]) - 1;
+const MAX_M: u32 = M_BASE[M_SYMBOLS - 1] + (1 << M_BITS[M_SYMBOLS - 1]) - 1;
+const MAX_D: u32 = D_BASE[D_SYMBOLS - 1] + (1 << D_BITS[D_SYMBOLS - 1]) - 1;
+
+fn u32_at(src: &[u8], pos: usize) -> Result<u32, Error> {
+    let b = src.get(pos..pos + 4).ok_or(Error::Truncated)?;
+    Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn u64_at(src: &[u8], pos: usize) -> Result<u64, Error> {
+    let b = src.get(pos..pos + 8).ok_or(Error::Truncated)?;
+    Ok(u64::from_le_bytes(b.try_into().unwrap()))
+}
+
+#[inline]
+fn field(v: u64, offset: u32, bits: u32) -> u32 {
+    ((v >> offset) & ((1 << bits) - 1)) as u32
+}
+
+/// Decoded block header, the v1 layout which v2 headers expand to.
+#[derive(Debug, Default)]
+struct Header {
+    n_raw_bytes: u32,
+    n_literals: u32,
+    n_matches: u32,
+    n_literal_payload_bytes: u32,
+    n_lmd_payload_bytes: u32,
+    literal_bits: i32,
+    literal_state: [u16; 4],
+    lmd_bits: i32,
+    l_state: u16,
+    m_state: u16,
+    d_state: u16,
+    freq: Vec<u16>,
+}
+
+impl Header {
+    fn l_freq(&self) -> &[u16] {
+        &self.freq[..L_SYMBOLS]
+    }
+
+    fn m_freq(&self) -> &[u16] {
+        &self.freq[L_SYMBOLS..L_SYMBOLS + M_SYMBOLS]
+    }
+
+    fn d_freq(&self) -> &[u16] {
+        &self.freq[L_SYMBOLS + M_SYMBOLS..L_SYMBOLS + M_SYMBOLS + D_SYMBOLS]
+    }
+
+    fn literal_freq(&self) -> &[u16] {
+        &self.freq[L_SYMBOLS + M_SYMBOLS + D_SYMBOLS..]
+    }
+
+    /// Parses a v1 header, returns it with its length.
+    fn v1(src: &[u8]) -> Result<(Self, usize), Error> {
+        if src.len() < V1_HEADER_LEN {
+            return Err(Error::Truncated);
+        }
+        let u16_at = |pos: usize| u16::from_le_bytes([src[pos], src[pos + 1]]);
+        let header = Self {
+            n_raw_bytes: u32_at(src, 4)?,
+            n_literals: u32_at(src, 12)?,
+            n_matches: u32_at(src, 16)?,
+            n_literal_payload_bytes: u32_at(src, 20)?,
+            n_lmd_payload_bytes: u32_at(src, 24)?,
+            literal_bits: u32_at(src, 28)? as i32,
+            literal_state: [u16_at(32), u16_at(34), u16_at(36), u16_at(38)],
+            lmd_bits: u32_at(src, 40)? as i32,
+            l_state: u16_at(44),
+            m_state: u16_at(46),
+            d_state: u16_at(48),
+            freq: (0..FREQ_LEN).map(|i| u16_at(50 + 2 * i)).collect(),
+        };
+        Ok((header, V1_HEADER_LEN))
+    }
+
+    /// Parses a v2 header with its packed fields and frequency tables.
+    fn v2(src: &[u8]) -> Result<(Self, usize), Error> {
+        let n_raw_bytes = u32_at(src, 4)?;
+        let v0 = u64_at(src, 8)?;
+        let v1 = u64_at(src, 16)?;
+        let v2 = u64_at(src, 24)?;
+        let len = field(v2, 0, 32) as usize;
+        if len < V2_HEADER_LEN {
+            return Err(Error::Corrupt("LZFSE header too short"));
+        }
+        let tables = src.get(V2_HEADER_LEN..len).ok_or(Error::Truncated)?;
+
+        let mut freq = vec![0u16; FREQ_LEN];
+        if !tables.is_empty() {
+            let mut bytes = tables.iter();
+            let mut accum = 0u32;
+            let mut accum_bits = 0u32;
+            for f in freq.iter_mut() {
+                while accum_bits + 8 <= 32 {
+                    let Some(&b) = bytes.next() else { break };
+                    accum |= (b as u32) << accum_bits;
+                    accum_bits += 8;
+                }
+                let (value, bits) = decode_freq(accum);
+                if bits > accum_bits {
+                    return Err(Error::Corrupt("LZFSE frequency table truncated"));
+                }
+                *f = value;
+                accum >>= bits;
+                accum_bits -= bits;
+            }
+            if accum_bits >= 8 || bytes.next().is_some() {
+                return Err(Error::Corrupt("LZFSE frequency table size mismatch"));
+            }
+        }
+
+        let header = Self {
+            n_raw_bytes,
+            n_literals: field(v0, 0, 20),
+            n_literal_payload_bytes: field(v0, 20, 20),
+            n_matches: field(v0, 40, 20),
+            literal_bits: field(v0, 60, 3) as i32 - 7,
+            literal_state: [
+                field(v1, 0, 10) as u16,
+                field(v1, 10, 10) as u16,
+                field(v1, 20, 10) as u16,
+                field(v1, 30, 10) as u16,
+            ],
+            n_lmd_payload_bytes: field(v1, 40, 20),
+            lmd_bits: field(v1, 60, 3) as i32 - 7,
+            l_state: field(v2, 32, 10) as u16,
+            m_state: field(v2, 42, 10) as u16,
+            d_state: field(v2, 52, 10) as u16,
+            freq,
+        };
+        Ok((header, len))
+    }
+
+    fn write_v2(&self, dst: &mut Vec<u8>) {
+        let v0 = self.n_literals as u64
+            | (self.n_literal_payload_bytes as u64) << 20
+            | (self.n_matches as u64) << 40
+            | ((self.literal_bits + 7) as u64) << 60;
+        let v1 = self.literal_state[0] as u64
+            | (self.literal_state[1] as u64) << 10
+            | (self.literal_state[2] as u64) << 20
+            | (self.literal_state[3] as u64) << 30
+            | (self.n_lmd_payload_bytes as u64) << 40
+            | ((self.lmd_bits + 7) as u64) << 60;
+
+        let mut tables = Vec::with_capacity(FREQ_LEN);
+        let mut accum = 0u32;
+        let mut accum_bits = 0u32;
+        for &f in &self.freq {
+            let (code, bits) = encode_freq(f);
+            accum |= code << accum_bits;
+            accum_bits += bits;
+            while accum_bits >= 8 {
+                tables.push(accum as u8);
+                accum >>= 8;
+                accum_bits -= 8;
+            }
+        }
+        if accum_bits > 0 {
+            tables.push(accum as u8);
+        }
+
+        let v2 = (V2_HEADER_LEN + tables.len()) as u64
+            | (self.l_state as u64) << 32
+            | (self.m_state as u64) << 42
+            | (self.d_state as u64) << 52;
+
+        dst.extend_from_slice(V2_MAGIC);
+        dst.extend_from_slice(&self.n_raw_bytes.to_le_bytes());
+        dst.extend_from_slice(&v0.to_le_bytes());
+        dst.extend_from_slice(&v1.to_le_bytes());
+        dst.extend_from_slice(&v2.to_le_bytes());
+        dst.extend_from_slice(&tables);
+    }
+}
+
+/// Fixed prefix code for frequency values, read from the least significant bit.
+fn decode_freq(bits: u32) -> (u16, u32) {
+    const LEN: [u8; 32] = [
+        2, 3, 2, 5, 2, 3, 2, 8, 2, 3, 2, 5, 2, 3, 2, 14, 2, 3, 2, 5, 2, 3, 2, 8, 2, 3, 2, 5, 2, 3,
+        2, 14,
+    ];
+    const VALUE: [u8; 32] = [
+        0, 2, 1, 4, 0, 3, 1, 0, 0, 2, 1, 5, 0, 3, 1, 0, 0, 2, 1, 6, 0, 3, 1, 0, 0, 2, 1, 7, 0, 3,
+        1, 0,
+    ];
+    let b = (bits & 31) as usize;
+    match LEN[b] {
+        8 => (8 + ((bits >> 4) & 0xf) as u16, 8),
+        14 => (24 + ((bits >> 4) & 0x3ff) as u16, 14),
+        n => (VALUE[b] as u16, n as u32),
+    }
+}
+
+fn encode_freq(value: u16) -> (u32, u32) {
+    const CODE: [(u32, u32); 8] = [
+        (0, 2),
+        (2, 2),
+        (1, 3),
+        (5, 3),
+        (3, 5),
+        (11, 5),
+        (19, 5),
+        (27, 5),
+    ];
+    match value {
+        0..8 => CODE[value as usize],
+        8..24 => (((value as u32 - 8) << 4) | 7, 8),
+        _ => (((value as u32 - 24) << 4) | 15, 14),
+    }
+}
+
+/// Reads an FSE stream backwards from its end, most recently written bits first.
+struct BitReader<'a> {
+    src: &'a [u8],
+    pos: usize,
+    accum: u64,
+    bits: u32,
+}
+
+impl<'a> BitReader<'a> {
+    /// `n` in `-7..=0` is the number of unused bits in the last byte, negated.
+    fn new(src: &'a [u8], start: usize, end: usize, n: i32) -> Result<Self, Error> {
+        if !(-7..=0).contains(&n) {
+            return Err(Error::Corrupt("LZFSE invalid bit count"));
+        }
+        let len = if n == 0 { 7 } else { 8 };
+        if end < start + len || end > src.len() {
+            return Err(Error::Corrupt("LZFSE payload too short"));
+        }
+        let pos = end - len;
+        let mut buf = [0u8; 8];
+        buf[..len].copy_from_slice(&src[pos..end]);
+        let accum = u64::from_le_bytes(buf);
+        let bits = (len as i32 * 8 + n) as u32;
+        if accum >> bits != 0 {
+            return Err(Error::Corrupt("LZFSE stream has stray bits"));
+        }
+        Ok(Self {
+            src,
+            pos,
+            accum,
+            bits,
+        })
+    }
+
+    /// Refills to at least 56 bits.
+    fn flush(&mut self) -> Result<(), Error> {
+        let n = ((63 - self.bits) / 8) as usize;
+        if n == 0 {
+            return Ok(());
+        }
+        if self.pos < n {
+            return Err(Error::Corrupt("LZFSE stream overrun"));
+        }
+        self.pos -= n;
+        let mut buf = [0u8; 8];
+        buf[..n].copy_from_slice(&self.src[self.pos..self.pos + n]);
+        self.accum = (self.accum << (n * 8)) | u64::from_le_bytes(buf);
+        self.bits += n as u32 * 8;
+        Ok(())
+    }
+
+    fn pull(&mut self, n: u32) -> Result<u32, Error> {
+        self.bits = self
+            .bits
+            .checked_sub(n)
+            .ok_or(Error::Corrupt("LZFSE stream overrun"))?;
+        let res = self.accum >> self.bits;
+        self.accum &= (1u64 << self.bits) - 1;
+        Ok(res as u32)
+    }
+}
+
+/// Collects bits, newest at the top, and stores them little endian.
+struct BitWriter {
+    dst: Vec<u8>,
+    accum: u64,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self {
+            dst: Vec::new(),
+            accum: 0,
+            bits: 0,
+        }
+    }
+
+    fn push(&mut self, n: u32, value: u32) {
+        self.accum |= (value as u64) << self.bits;
+        self.bits += n;
+    }
+
+    fn flush(&mut self) {
+        let n = self.bits / 8;
+        self.dst
+            .extend_from_slice(&self.accum.to_le_bytes()[..n as usize]);
+        self.accum >>= n * 8;
+        self.bits -= n * 8;
+    }
+
+    /// Returns the payload and the negated count of unused bits in its last byte.
+    ///
+    /// Short payloads get zeros in front, the decoder loads 8 bytes at once
+    /// but never consumes anything before the first written bit.
+    fn finish(mut self) -> (Vec<u8>, i32) {
+        let n = self.bits.div_ceil(8);
+        self.dst
+            .extend_from_slice(&self.accum.to_le_bytes()[..n as usize]);
+        if self.dst.len() < 8 {
+            self.dst
+                .splice(0..0, std::iter::repeat_n(0, 8 - self.dst.len()));
+        }
+        (self.dst, self.bits as i32 - n as i32 * 8)
+    }
+}
+
+#[inline]
+fn shift(freq: u32, states: u32) -> u32 {
+    freq.leading_zeros() - states.leading_zeros()
+}
+
+fn check_freq(freq: &[u16], states: u32) -> Result<(), Error> {
+    if freq.iter().map(|&f| f as u32).sum::<u32>() > states {
+        return Err(Error::Corrupt("LZFSE frequencies exceed state count"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    bits: u32,
+    symbol: u8,
+    delta: u32,
+}
+
+fn decoder_table(freq: &[u16], states: u32) -> Result<Vec<Entry>, Error> {
+    check_freq(freq, states)?;
+    let mut table = Vec::with_capacity(states as usize);
+    for (symbol, &f) in freq.iter().enumerate() {
+        let f = f as u32;
+        if f == 0 {
+            continue;
+        }
+        let k = shift(f, states);
+        let j0 = ((2 * states) >> k) - f;
+        for j in 0..f {
+            table.push(if j < j0 {
+                Entry {
+                    bits: k,
+                    symbol: symbol as u8,
+                    delta: ((f + j) << k) - states,
+                }
+            } else {
+                Entry {
+                    bits: k - 1,
+                    symbol: symbol as u8,
+                    delta: (j - j0) << (k - 1),
+                }
+            });
+        }
+    }
+    Ok(table)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ValueEntry {
+    entry: Entry,
+    value_bits: u32,
+    base: u32,
+}
+
+fn value_decoder_table(
+    freq: &[u16],
+    states: u32,
+    bits: &[u8],
+    base: &[u32],
+) -> Result<Vec<ValueEntry>, Error> {
+    Ok(decoder_table(freq, states)?
+        .into_iter()
+        .map(|entry| ValueEntry {
+            entry,
+            value_bits: bits[entry.symbol as usize] as u32,
+            base: base[entry.symbol as usize],
+        })
+        .collect())
+}
+
+#[inline]
+fn decode_symbol(table: &[Entry], state: &mut u32, r: &mut BitReader) -> Result<u8, Error> {
+    let e = table
+        .get(*state as usize)
+        .ok_or(Error::Corrupt("LZFSE state out of range"))?;
+    *state = e.delta + r.pull(e.bits)?;
+    Ok(e.symbol)
+}
+
+#[inline]
+fn decode_value(table: &[ValueEntry], state: &mut u32, r: &mut BitReader) -> Result<u32, Error> {
+    let e = table
+        .get(*state as usize)
+        .ok_or(Error::Corrupt("LZFSE state out of range"))?;
+    let v = r.pull(e.entry.bits + e.value_bits)?;
+    *state = e.entry.delta + (v >> e.value_bits);
+    Ok(e.base + (v & ((1 << e.value_bits) - 1)))
+}
+
+/// Decodes an FSE compressed block, `src` spans the whole input so the
+/// bit readers may peek before the payload like the reference decoder does.
+fn decode_block(
+    src: &[u8],
+    payload: usize,
+    header: &Header,
+    dst: &mut Vec<u8>,
+    limit: usize,
+) -> Result<usize, Error> {
+    if header.n_literals as usize > LITERALS_PER_BLOCK
+        || header.n_matches as usize > MATCHES_PER_BLOCK
+    {
+        return Err(Error::Corrupt("LZFSE block too large"));
+    }
+
+    let literal_table = decoder_table(header.literal_freq(), LITERAL_STATES)?;
+    let l_table = value_decoder_table(header.l_freq(), L_STATES, &L_BITS, &L_BASE)?;
+    let m_table = value_decoder_table(header.m_freq(), M_STATES, &M_BITS, &M_BASE)?;
+    let d_table = value_decoder_table(header.d_freq(), D_STATES, &D_BITS, &D_BASE)?;
+
+    let literals_end = payload + header.n_literal_payload_bytes as usize;
+    let lmd_end = literals_end + header.n_lmd_payload_bytes as usize;
+    if lmd_end > src.len() {
+        return Err(Error::Truncated);
+    }
+
+    let mut literals = vec![0u8; (header.n_literals as usize).next_multiple_of(4)];
+    let mut r = BitReader::new(src, 0, literals_end, header.literal_bits)?;
+    let mut states = header.literal_state.map(|s| s as u32);
+    for chunk in literals.chunks_exact_mut(4) {
+        r.flush()?;
+        for (lit, state) in chunk.iter_mut().zip(states.iter_mut()) {
+            *lit = decode_symbol(&literal_table, state, &mut r)?;
+        }
+    }
+
+    let mut r = BitReader::new(src, literals_end, lmd_end, header.lmd_bits)?;
+    let (mut l_state, mut m_state, mut d_state) = (
+        header.l_state as u32,
+        header.m_state as u32,
+        header.d_state as u32,
+    );
+    let start = dst.len();
+    let mut lit = 0usize;
+    let mut d = None;
+    for _ in 0..header.n_matches {
+        if dst.len() >= limit {
+            return Ok(lmd_end);
+        }
+        r.flush()?;
+        let l = decode_value(&l_table, &mut l_state, &mut r)? as usize;
+        let m = decode_value(&m_table, &mut m_state, &mut r)? as usize;
+        let new_d = decode_value(&d_table, &mut d_state, &mut r)? as usize;
+        if new_d != 0 {
+            d = Some(new_d);
+        }
+
+        let lits = literals
+            .get(lit..lit + l)
+            .ok_or(Error::Corrupt("LZFSE literal overrun"))?;
+        dst.extend_from_slice(lits);
+        lit += l;
+
+        match d {
+            Some(d) if d <= dst.len() => lzvn::copy_match(dst, d, m),
+            _ => return Err(Error::Corrupt("LZFSE match distance out of range")),
+        }
+    }
+    if dst.len() - start != header.n_raw_bytes as usize {
+        return Err(Error::Corrupt("LZFSE block size mismatch"));
+    }
+    Ok(lmd_end)
+}
+
+/// Decodes an LZFSE stream, blocks up to the `bvx$` end marker.
+///
+/// Compressed (`bvx1`, `bvx2`), LZVN (`bvxn`) and uncompressed (`bvx-`) blocks are supported.
+///
+/// ```
+/// use cidre::compression::lzfse;
+///
+/// let data = b"the quick brown fox jumps over the lazy dog. ".repeat(200);
+/// assert_eq!(lzfse::decode(&lzfse::encode(&data)).unwrap(), data);
+/// ```
+pub fn decode(src: &[u8]) -> Result<Vec<u8>, Error> {
+    decode_prefix(src, usize::MAX)
+}
+
+/// Like [`decode`], but stops once `limit` bytes are decoded,
+/// the rest of the input isn't checked then.
+pub fn decode_prefix(src: &[u8], limit: usize) -> Result<Vec<u8>, Error> {
+    let mut dst = Vec::with_capacity((src.len() * 4).min(limit));
+    let mut pos = 0;
+    loop {
+        if dst.len() >= limit {
+            dst.truncate(limit);
+            return Ok(dst);
+        }
+        let magic: &[u8; 4] = src
+            .get(pos..pos + 4)
+            .ok_or(Error::Truncated)?
+            .try_into()
+            .unwrap();
+        match magic {
+            END_MAGIC => return Ok(dst),
+            RAW_MAGIC => {
+                let n = u32_at(src, pos + 4)? as usize;
+                pos += 8;
+                let raw = src.get(pos..pos + n).ok_or(Error::Truncated)?;
+                dst.extend_from_slice(raw);
+                pos += n;
+            }
+            LZVN_MAGIC => {
+                let n_raw = u32_at(src, pos + 4)? as usize;
+                let n_payload = u32_at(src, pos + 8)? as usize;
+                pos += 12;
+                let payload = src.get(pos..pos + n_payload).ok_or(Error::Truncated)?;
+                let start = dst.len();
+                lzvn::decode_into(payload, &mut dst, limit)?;
+                if dst.len() - start != n_raw && dst.len() < limit {
+                    return Err(Error::Corrupt("LZVN block size mismatch"));
+                }
+                pos += n_payload;
+            }
+            V1_MAGIC | V2_MAGIC => {
+                let (header, len) = if magic == V1_MAGIC {
+                    Header::v1(&src[pos..])?
+                } else {
+                    Header::v2(&src[pos..])?
+                };
+                pos = decode_block(src, pos + len, &header, &mut dst, limit)?;
+            }
+            _ => return Err(Error::Corrupt("unknown LZFSE block magic")),
+        }
+    }
+}
+
+/// Scales symbol counts to frequencies summing up to `states`, every used symbol keeps at least one.
+fn normalize(counts: &[u32], states: u32) -> Vec<u16> {
+    let total: u64 = counts.iter().map(|&c| c as u64).sum();
+    if total == 0 {
+        return vec![0; counts.len()];
+    }
+    let mut freq: Vec<u32> = counts
+        .iter()
+        .map(|&c| {
+            if c == 0 {
+                0
+            } else {
+                ((c as u64 * states as u64 + total / 2) / total).max(1) as u32
+            }
+        })
+        .collect();
+    let mut sum: u32 = freq.iter().sum();
+    while sum != states {
+        let (i, _) = freq.iter().enumerate().max_by_key(|&(_, &f)| f).unwrap();
+        if sum < states {
+            freq[i] += states - sum;
+            sum = states;
+        } else {
+            // take from the largest symbols, never dropping one below 1
+            let take = (sum - states).min(freq[i] - 1).max(1);
+            freq[i] -= take;
+            sum -= take;
+        }
+    }
+    freq.into_iter().map(|f| f as u16).collect()
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct EncoderEntry {
+    s0: u32,
+    k: u32,
+    delta0: i32,
+    delta1: i32,
+}
+
+fn encoder_table(freq: &[u16], states: u32) -> Vec<EncoderEntry> {
+    let mut offset = 0i32;
+    freq.iter()
+        .map(|&f| {
+            let f = f as u32;
+            if f == 0 {
+                return EncoderEntry::default();
+            }
+            let k = shift(f, states);
+            let e = EncoderEntry {
+                s0: (f << k) - states,
+                k,
+                delta0: offset - f as i32 + (states >> k) as i32,
+                delta1: if k > 0 {
+                    offset - f as i32 + (states >> (k - 1)) as i32
+                } else {
+                    0
+                },
+            };
+            offset += f as i32;
+            e
+        })
+        .collect()
+}
+
+#[inline]
+fn encode_symbol(table: &[EncoderEntry], state: &mut u32, w: &mut BitWriter, symbol: usize) {
+    let e = table[symbol];
+    let (bits, delta) = if *state >= e.s0 {
+        (e.k, e.delta0)
+    } else {
+        (e.k - 1, e.delta1)
+    };
+    w.push(bits, *state & ((1 << bits) - 1));
+    *state = (delta + (*state >> bits) as i32) as u32;
+}
+
+fn value_symbol(value: u32, base: &[u32]) -> usize {
+    base.partition_point(|&b| b <= value) - 1
+}
+
+fn value_counts(values: impl Iterator<Item = u32>, base: &[u32]) -> Vec<u32> {
+    let mut counts = vec![0u32; base.len()];
+    for v in values {
+        counts[value_symbol(v, base)] += 1;
+    }
+    counts
+}
+
+/// Literal run, match length and distance as stored in the L, M, D streams, `d == 0` repeats.
+#[derive(Debug, Clone, Copy)]
+struct Lmd {
+    l: u32,
+    m: u32,
+    d: u32,
+}
+
+fn encode_block(literals: &[u8], lmds: &[Lmd], n_raw_bytes: u32, dst: &mut Vec<u8>) {
+    let mut literals = literals.to_vec();
+    literals.resize(literals.len().next_multiple_of(4), 0);
+
+    let mut literal_counts = vec![0u32; LITERAL_SYMBOLS];
+    for &b in &literals {
+        literal_counts[b as usize] += 1;
+    }
+    let literal_freq = normalize(&literal_counts, LITERAL_STATES);
+    let l_freq = normalize(&value_counts(lmds.iter().map(|x| x.l), &L_BASE), L_STATES);
+    let m_freq = normalize(&value_counts(lmds.iter().map(|x| x.m), &M_BASE), M_STATES);
+    let d_freq = normalize(&value_counts(lmds.iter().map(|x| x.d), &D_BASE), D_STATES);
+
+    // symbols go in backwards so the decoder reads them front to back
+    let table = encoder_table(&literal_freq, LITERAL_STATES);
+    let mut w = BitWriter::new();
+    let mut literal_state = [0u32; 4];
+    for chunk in literals.chunks_exact(4).rev() {
+        for i in (0..4).rev() {
+            encode_symbol(&table, &mut literal_state[i], &mut w, chunk[i] as usize);
+        }
+        w.flush();
+    }
+    let (literal_payload, literal_bits) = w.finish();
+
+    let l_table = encoder_table(&l_freq, L_STATES);
+    let m_table = encoder_table(&m_freq, M_STATES);
+    let d_table = encoder_table(&d_freq, D_STATES);
+    let mut w = BitWriter::new();
+    let (mut l_state, mut m_state, mut d_state) = (0, 0, 0);
+    for lmd in lmds.iter().rev() {
+        let values = [
+            (lmd.d, &D_BASE[..], &D_BITS[..], &d_table, &mut d_state),
+            (lmd.m, &M_BASE[..], &M_BITS[..], &m_table, &mut m_state),
+            (lmd.l, &L_BASE[..], &L_BITS[..], &l_table, &mut l_state),
+        ];
+        for (value, base, bits, table, state) in values {
+            let symbol = value_symbol(value, base);
+            w.push(bits[symbol] as u32, value - base[symbol]);
+            encode_symbol(table, state, &mut w, symbol);
+        }
+        w.flush();
+    }
+    let (lmd_payload, lmd_bits) = w.finish();
+
+    let header = Header {
+        n_raw_bytes,
+        n_literals: literals.len() as u32,
+        n_matches: lmds.len() as u32,
+        n_literal_payload_bytes: literal_payload.len() as u32,
+        n_lmd_payload_bytes: lmd_payload.len() as u32,
+        literal_bits,
+        literal_state: literal_state.map(|s| s as u16),
+        lmd_bits,
+        l_state: l_state as u16,
+        m_state: m_state as u16,
+        d_state: d_state as u16,
+        freq: [l_freq, m_freq, d_freq, literal_freq].concat(),
+    };
+    header.write_v2(dst);
+    dst.extend_from_slice(&literal_payload);
+    dst.extend_from_slice(&lmd_payload);
+}
+
+/// Accumulates L, M, D triples into blocks within the format limits.
+struct BlockEncoder {
+    dst: Vec<u8>,
+    literals: Vec<u8>,
+    lmds: Vec<Lmd>,
+    n_raw_bytes: u32,
+    d_prev: Option<u32>,
+}
+
+impl BlockEncoder {
+    fn push(&mut self, literals: &[u8], m: u32, d: u32) {
+        if self.lmds.len() == MATCHES_PER_BLOCK
+            || self.literals.len() + literals.len() > LITERALS_PER_BLOCK
+        {
+            self.finish_block();
+        }
+        let d = if self.d_prev == Some(d) { 0 } else { d };
+        if d != 0 {
+            self.d_prev = Some(d);
+        }
+        self.literals.extend_from_slice(literals);
+        self.lmds.push(Lmd {
+            l: literals.len() as u32,
+            m,
+            d,
+        });
+        self.n_raw_bytes += literals.len() as u32 + m;
+    }
+
+    /// Splits literal runs and matches the L and M symbols can't hold.
+    fn push_seq(&mut self, mut literals: &[u8], mut m: u32, d: u32) {
+        while literals.len() > MAX_L as usize {
+            let (head, tail) = literals.split_at(MAX_L as usize);
+            // literal only triples still need a valid distance
+            let d = self.d_prev.unwrap_or(1);
+            self.push(head, 0, d);
+            literals = tail;
+        }
+        let n = m.min(MAX_M);
+        self.push(literals, n, d);
+        m -= n;
+        while m > 0 {
+            let n = m.min(MAX_M);
+            self.push(&[], n, d);
+            m -= n;
+        }
+    }
+
+    fn finish_block(&mut self) {
+        if !self.lmds.is_empty() {
+            encode_block(&self.literals, &self.lmds, self.n_raw_bytes, &mut self.dst);
+        }
+        self.literals.clear();
+        self.lmds.clear();
+        self.n_raw_bytes = 0;
+        // the decoder forgets the previous distance at block boundaries
+        self.d_prev = None;
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        self.finish_block();
+        self.dst.extend_from_slice(END_MAGIC);
+        self.dst
+    }
+}
+
+/// Encodes `src` as an LZFSE stream.
+///
+/// Small inputs use a single LZVN block, larger ones FSE compressed `bvx2` blocks.
+pub fn encode(src: &[u8]) -> Vec<u8> {
+    let mut dst = Vec::with_capacity(src.len() / 2 + 64);
+    if src.is_empty() {
+        dst.extend_from_slice(END_MAGIC);
+        return dst;
+    }
+    if src.len() < LZVN_THRESHOLD {
+        let payload = lzvn::encode(src);
+        if payload.len() < src.len() {
+            dst.extend_from_slice(LZVN_MAGIC);
+            dst.extend_from_slice(&(src.len() as u32).to_le_bytes());
+            dst.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+            dst.extend_from_slice(&payload);
+        } else {
+            dst.extend_from_slice(RAW_MAGIC);
+            dst.extend_from_slice(&(src.len() as u32).to_le_bytes());
+            dst.extend_from_slice(src);
+        }
+        dst.extend_from_slice(END_MAGIC);
+        return dst;
+    }
+
+    let parser = lz77::Parser {
+        max_distance: MAX_D as usize,
+        end_literals: 0,
+        end_match_start: 0,
+    };
+    let (seqs, tail) = parser.parse(src);
+    let mut encoder = BlockEncoder {
+        dst,
+        literals: Vec::with_capacity(LITERALS_PER_BLOCK),
+        lmds: Vec::with_capacity(MATCHES_PER_BLOCK),
+        n_raw_bytes: 0,
+        d_prev: None,
+    };
+    let mut pos = 0;
+    for seq in seqs {
+        let lits = &src[pos..pos + seq.literals];
+        encoder.push_seq(lits, seq.len as u32, seq.distance as u32);
+        pos += seq.literals + seq.len;
+    }
+    if tail > 0 {
+        let d = encoder.d_prev.unwrap_or(1);
+        encoder.push_seq(&src[pos..], 0, d);
+    }
+    encoder.finish()
+}
@@ -0,0 +1,178 @@
+use crate::compression::{Error, lz77};
+
+const EOS: [u8; 8] = [0x06, 0, 0, 0, 0, 0, 0, 0];
+
+/// Decodes an LZVN payload, stopping at the end of stream opcode.
+///
+/// ```
+/// use cidre::compression::lzvn;
+///
+/// let data = b"abcabcabcabcabcabc".repeat(4);
+/// assert_eq!(lzvn::decode(&lzvn::encode(&data)).unwrap(), data);
+/// ```
+pub fn decode(src: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut dst = Vec::with_capacity(src.len() * 2);
+    decode_into(src, &mut dst, usize::MAX)?;
+    Ok(dst)
+}
+
+/// Appends decoded bytes to `dst`, matches may reach back into what `dst` already holds.
+///
+/// Returns the number of source bytes consumed, including the end of stream opcode.
+/// Stops early once `dst` reaches `limit` bytes, it may overshoot by one opcode.
+pub(crate) fn decode_into(src: &[u8], dst: &mut Vec<u8>, limit: usize) -> Result<usize, Error> {
+    let mut pos = 0;
+    let mut d_prev = 0usize;
+
+    let byte = |i: usize| src.get(i).copied().ok_or(Error::Truncated);
+
+    while dst.len() < limit {
+        let op = byte(pos)?;
+        let (len, literals, m, d) = match op {
+            0x06 => return Ok((pos + EOS.len()).min(src.len())),
+            0x0e | 0x16 => (1, 0, 0, None),
+            0xa0..=0xbf => {
+                let (b1, b2) = (byte(pos + 1)? as usize, byte(pos + 2)? as usize);
+                let l = (op as usize >> 3) & 3;
+                let m = (((op as usize) & 7) << 2 | (b1 & 3)) + 3;
+                (3, l, m, Some((b2 << 6) | (b1 >> 2)))
+            }
+            0xe0 => (2, byte(pos + 1)? as usize + 16, 0, None),
+            0xe1..=0xef => (1, (op & 0xf) as usize, 0, None),
+            0xf0 => (2, 0, byte(pos + 1)? as usize + 16, Some(d_prev)),
+            0xf1..=0xff => (1, 0, (op & 0xf) as usize, Some(d_prev)),
+            0x70..=0x7f | 0xd0..=0xdf => return Err(Error::Corrupt("undefined LZVN opcode")),
+            _ if op & 7 == 6 && op < 0x40 => {
+                return Err(Error::Corrupt("undefined LZVN opcode"));
+            }
+            _ => {
+                let l = (op >> 6) as usize;
+                let m = ((op >> 3) & 7) as usize + 3;
+                match op & 7 {
+                    6 => (1, l, m, Some(d_prev)),
+                    7 => {
+                        let d = u16::from_le_bytes([byte(pos + 1)?, byte(pos + 2)?]);
+                        (3, l, m, Some(d as usize))
+                    }
+                    hi => (2, l, m, Some((hi as usize) << 8 | byte(pos + 1)? as usize)),
+                }
+            }
+        };
+        pos += len;
+
+        let lits = src.get(pos..pos + literals).ok_or(Error::Truncated)?;
+        dst.extend_from_slice(lits);
+        pos += literals;
+
+        if let Some(d) = d {
+            if d == 0 || d > dst.len() {
+                return Err(Error::Corrupt("LZVN match distance out of range"));
+            }
+            copy_match(dst, d, m);
+            d_prev = d;
+        }
+    }
+    Ok(pos)
+}
+
+/// Byte by byte copy so overlapping matches repeat the pattern.
+#[inline]
+pub(crate) fn copy_match(dst: &mut Vec<u8>, distance: usize, len: usize) {
+    let start = dst.len() - distance;
+    if distance >= len {
+        dst.extend_from_within(start..start + len);
+    } else {
+        dst.reserve(len);
+        for i in 0..len {
+            let b = dst[start + i];
+            dst.push(b);
+        }
+    }
+}
+
+fn emit_literals(dst: &mut Vec<u8>, mut lits: &[u8]) {
+    while !lits.is_empty() {
+        let n = lits.len().min(271);
+        if n < 16 {
+            dst.push(0xe0 | n as u8);
+        } else {
+            dst.push(0xe0);
+            dst.push((n - 16) as u8);
+        }
+        dst.extend_from_slice(&lits[..n]);
+        lits = &lits[n..];
+    }
+}
+
+fn emit_match_len(dst: &mut Vec<u8>, mut m: usize) {
+    while m > 0 {
+        let n = m.min(271);
+        if n < 16 {
+            dst.push(0xf0 | n as u8);
+        } else {
+            dst.push(0xf0);
+            dst.push((n - 16) as u8);
+        }
+        m -= n;
+    }
+}
+
+/// Encodes `src` as an LZVN payload terminated by the end of stream opcode.
+pub fn encode(src: &[u8]) -> Vec<u8> {
+    let parser = lz77::Parser {
+        max_distance: 0xffff,
+        end_literals: 0,
+        end_match_start: 0,
+    };
+    let (seqs, tail) = parser.parse(src);
+    let mut dst = Vec::with_capacity(src.len() / 2 + EOS.len());
+    let mut pos = 0;
+    let mut d_prev = 0;
+    for seq in seqs {
+        let lits = &src[pos..pos + seq.literals];
+        pos += seq.literals + seq.len;
+
+        // up to 3 literals ride along with the distance opcode
+        let l = lits.len() & 3;
+        let (head, lits) = lits.split_at(lits.len() - l);
+        emit_literals(&mut dst, head);
+
+        let (d, mut m) = (seq.distance, seq.len);
+        if d == d_prev && l == 0 {
+            emit_match_len(&mut dst, m);
+            continue;
+        }
+        // short opcodes lose match length bits to the literal count
+        let short_max = 10 - 2 * l;
+        if d == d_prev {
+            let n = m.min(short_max);
+            dst.push((l << 6 | (n - 3) << 3 | 6) as u8);
+            dst.extend_from_slice(lits);
+            m -= n;
+        } else if d < 0x600 && m <= short_max {
+            let n = m.min(short_max);
+            dst.push((l << 6 | (n - 3) << 3 | d >> 8) as u8);
+            dst.push(d as u8);
+            dst.extend_from_slice(lits);
+            m -= n;
+        } else if d < 0x4000 {
+            let n = m.min(34);
+            dst.push((0xa0 | l << 3 | (n - 3) >> 2) as u8);
+            dst.push(((d & 0x3f) << 2 | (n - 3) & 3) as u8);
+            dst.push((d >> 6) as u8);
+            dst.extend_from_slice(lits);
+            m -= n;
+        } else {
+            let n = m.min(short_max);
+            dst.push((l << 6 | (n - 3) << 3 | 7) as u8);
+            dst.extend_from_slice(&(d as u16).to_le_bytes());
+            dst.extend_from_slice(lits);
+            m -= n;
+        }
+        emit_match_len(&mut dst, m);
+        d_prev = d;
+    }
+    emit_literals(&mut dst, &src[pos..pos + tail]);
+    dst.extend_from_slice(&EOS);
+    dst
+}
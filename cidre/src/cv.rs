@@ -32,6 +32,11 @@ pub mod pixel_format_description;
 pub use pixel_format_description::all_pixel_formats as pixel_format_desc_array_with_all_pixel_formats;
 pub use pixel_format_description::create as pixel_format_desc_create;
 
+pub mod pixel_format_info;
+pub use pixel_format_info::ColorModel as PixelColorModel;
+pub use pixel_format_info::Info as PixelFormatInfo;
+pub use pixel_format_info::Plane as PixelFormatPlane;
+
 #[cfg(feature = "mtl")]
 pub mod metal;
 #[cfg(feature = "mtl")]
@@ -473,6 +473,59 @@ impl PixelFormat {
     #[doc(alias = "kCVPixelFormatType_128RGBAFloat")]
     pub const _128_RGBA_FLOAT: Self = Self(os::Type::from_be_bytes(*b"RGfA"));
 
+    /// Planar Component Y'CbCr 8-bit 4:2:0, video range.
+    #[doc(alias = "kCVPixelFormatType_420YpCbCr8Planar")]
+    pub const _420_YP_CB_CR_8_PLANAR: Self = Self(os::Type::from_be_bytes(*b"y420"));
+
+    /// Component Y'CbCr 8-bit 4:2:2, ordered Y'0 Cb Y'1 Cr
+    #[doc(alias = "kCVPixelFormatType_422YpCbCr8_yuvs")]
+    pub const _422_YP_CB_CR_8_YUVS: Self = Self(os::Type::from_be_bytes(*b"yuvs"));
+
+    /// Component Y'CbCr 8-bit 4:2:2, full range, ordered Y'0 Cb Y'1 Cr
+    #[doc(alias = "kCVPixelFormatType_422YpCbCr8FullRange")]
+    pub const _422_YP_CB_CR_8_FULL_RANGE: Self = Self(os::Type::from_be_bytes(*b"yuvf"));
+
+    /// 10 bit little-endian one component, stored as 10 MSBs of 16 bits, zeros in the LSBs.
+    #[doc(alias = "kCVPixelFormatType_OneComponent10")]
+    pub const ONE_COMPONENT_10: Self = Self(os::Type::from_be_bytes(*b"L010"));
+
+    #[doc(alias = "kCVPixelFormatType_OneComponent16")]
+    pub const ONE_COMPONENT_16: Self = Self(os::Type::from_be_bytes(*b"L016"));
+
+    #[doc(alias = "kCVPixelFormatType_TwoComponent8")]
+    pub const TWO_COMPONENT_8: Self = Self(os::Type::from_be_bytes(*b"2C08"));
+
+    #[doc(alias = "kCVPixelFormatType_TwoComponent16")]
+    pub const TWO_COMPONENT_16: Self = Self(os::Type::from_be_bytes(*b"2C16"));
+
+    /// Little-endian packed 10 bit extended range BGR, the same as `mtl::PixelFormat::Bgr10Xr`
+    #[doc(alias = "kCVPixelFormatType_30RGBLEPackedWideGamut")]
+    pub const _30_RGB_LE_PACKED_WIDE_GAMUT: Self = Self(os::Type::from_be_bytes(*b"w30r"));
+
+    #[doc(alias = "kCVPixelFormatType_DisparityFloat16")]
+    pub const DISPARITY_F16: Self = Self(os::Type::from_be_bytes(*b"hdis"));
+
+    #[doc(alias = "kCVPixelFormatType_DisparityFloat32")]
+    pub const DISPARITY_F32: Self = Self(os::Type::from_be_bytes(*b"fdis"));
+
+    #[doc(alias = "kCVPixelFormatType_DepthFloat16")]
+    pub const DEPTH_F16: Self = Self(os::Type::from_be_bytes(*b"hdep"));
+
+    #[doc(alias = "kCVPixelFormatType_DepthFloat32")]
+    pub const DEPTH_F32: Self = Self(os::Type::from_be_bytes(*b"fdep"));
+
+    /// Single plane Bayer 16-bit little-endian sensor element, used by ProRes RAW.
+    #[doc(alias = "kCVPixelFormatType_16VersatileBayer")]
+    pub const _16_VERSATILE_BAYER: Self = Self(os::Type::from_be_bytes(*b"bp16"));
+
+    /// 64 bit RGBA 16-bit little-endian samples, downscaled ProRes RAW.
+    #[doc(alias = "kCVPixelFormatType_64RGBA_DownscaledProResRAW")]
+    pub const _64_RGBA_DOWNSCALED_PRO_RES_RAW: Self = Self(os::Type::from_be_bytes(*b"bp64"));
+
+    /// Single plane 12-bit Bayer, 8 elements packed into 96 bits, used by ProRes RAW.
+    #[doc(alias = "kCVPixelFormatType_96VersatileBayerPacked12")]
+    pub const _96_VERSATILE_BAYER_PACKED_12: Self = Self(os::Type::from_be_bytes(*b"btp2"));
+
     pub fn from_cf_number(number: &cf::Number) -> Self {
         Self(number.to_i32().unwrap_or(0) as u32)
     }
@@ -0,0 +1,591 @@
+#[cfg(feature = "mtl")]
+use crate::mtl;
+
+use crate::cv::{self, PixelFormat as F};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorModel {
+    Rgb,
+    YCbCr,
+
+    /// One or two generic components
+    Gray,
+
+    /// Depth or disparity
+    Depth,
+
+    /// Raw sensor elements
+    Bayer,
+}
+
+/// Layout of one plane of pixel buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Plane {
+    /// Significant bits per component, may be less than storage (10 bits in 16 for `x420`)
+    pub bits_per_component: u8,
+
+    /// Components stored in the plane
+    pub components: u8,
+
+    /// Horizontal subsampling relative to the full image width
+    pub h_subsampling: u8,
+
+    /// Vertical subsampling relative to the full image height
+    pub v_subsampling: u8,
+
+    /// Pixels per block, (1, 1) for ordinary formats, (2, 1) for 4:2:2 packed
+    pub block_size: (u8, u8),
+
+    /// Bytes per block, 0 for compressed formats which layout is opaque
+    pub bytes_per_block: u8,
+
+    /// Format to create `mtl::Texture` for the plane with
+    #[cfg(feature = "mtl")]
+    pub mtl_format: Option<mtl::PixelFormat>,
+}
+
+impl Plane {
+    /// Plane width for the image `width`.
+    #[inline]
+    pub const fn width(&self, width: usize) -> usize {
+        width.div_ceil(self.h_subsampling as usize)
+    }
+
+    /// Plane height for the image `height`.
+    #[inline]
+    pub const fn height(&self, height: usize) -> usize {
+        height.div_ceil(self.v_subsampling as usize)
+    }
+
+    /// Minimal (unaligned) bytes per row for the image `width`.
+    pub const fn bytes_per_row(&self, width: usize) -> usize {
+        let blocks = self.width(width).div_ceil(self.block_size.0 as usize);
+        blocks * self.bytes_per_block as usize
+    }
+}
+
+/// Static description of `cv::PixelFormat`.
+///
+/// ```
+/// use cidre::cv;
+///
+/// let info = cv::PixelFormat::_420V.info().unwrap();
+/// assert_eq!(info.planes.len(), 2);
+/// assert!(!info.full_range);
+/// assert_eq!(info.chroma_subsampling(), Some((2, 2)));
+/// assert_eq!(info.planes[1].bytes_per_row(1920), 1920);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Info {
+    pub pixel_format: cv::PixelFormat,
+    pub color_model: ColorModel,
+
+    /// Full range or video range for YCbCr formats, true for the rest
+    pub full_range: bool,
+    pub has_alpha: bool,
+
+    /// Lossless or lossy compressed, not readable by CPU
+    pub compressed: bool,
+    pub planes: &'static [Plane],
+}
+
+impl Info {
+    #[inline]
+    pub const fn is_planar(&self) -> bool {
+        self.planes.len() > 1
+    }
+
+    #[inline]
+    pub const fn is_y_cb_cr(&self) -> bool {
+        matches!(self.color_model, ColorModel::YCbCr)
+    }
+
+    /// Max significant bits per component across planes.
+    pub const fn bits_per_component(&self) -> u8 {
+        let mut i = 0;
+        let mut bits = 0;
+        while i < self.planes.len() {
+            if self.planes[i].bits_per_component > bits {
+                bits = self.planes[i].bits_per_component;
+            }
+            i += 1;
+        }
+        bits
+    }
+
+    /// Horizontal and vertical chroma subsampling of YCbCr formats:
+    /// (2, 2) for 4:2:0, (2, 1) for 4:2:2 and (1, 1) for 4:4:4.
+    pub const fn chroma_subsampling(&self) -> Option<(u8, u8)> {
+        if !self.is_y_cb_cr() {
+            return None;
+        }
+        let p = &self.planes[0];
+        // packed formats interleave subsampled chroma in blocks,
+        // the plane after them (if any) is alpha
+        if p.components >= 3 || self.planes.len() == 1 {
+            return Some(if p.block_size.0 > 1 { (2, 1) } else { (1, 1) });
+        }
+        let p = &self.planes[1];
+        Some((p.h_subsampling, p.v_subsampling))
+    }
+
+    /// Minimal bytes of all planes for the image size, 0 for compressed formats.
+    pub const fn data_size(&self, width: usize, height: usize) -> usize {
+        let mut i = 0;
+        let mut size = 0;
+        while i < self.planes.len() {
+            let p = &self.planes[i];
+            size += p.bytes_per_row(width) * p.height(height).div_ceil(p.block_size.1 as usize);
+            i += 1;
+        }
+        size
+    }
+}
+
+macro_rules! plane {
+    ($bits:expr, $components:expr, $sub:expr, ($bw:expr, $bh:expr, $bytes:expr), $mtl:ident) => {
+        Plane {
+            bits_per_component: $bits,
+            components: $components,
+            h_subsampling: $sub.0,
+            v_subsampling: $sub.1,
+            block_size: ($bw, $bh),
+            bytes_per_block: $bytes,
+            #[cfg(feature = "mtl")]
+            mtl_format: Some(mtl::PixelFormat::$mtl),
+        }
+    };
+    ($bits:expr, $components:expr, $sub:expr, ($bw:expr, $bh:expr, $bytes:expr)) => {
+        Plane {
+            bits_per_component: $bits,
+            components: $components,
+            h_subsampling: $sub.0,
+            v_subsampling: $sub.1,
+            block_size: ($bw, $bh),
+            bytes_per_block: $bytes,
+            #[cfg(feature = "mtl")]
+            mtl_format: None,
+        }
+    };
+    ($bits:expr, $components:expr, $bytes:expr, $mtl:ident) => {
+        plane!($bits, $components, (1, 1), (1, 1, $bytes), $mtl)
+    };
+    ($bits:expr, $components:expr, $bytes:expr) => {
+        plane!($bits, $components, (1, 1), (1, 1, $bytes))
+    };
+}
+
+const FULL: (u8, u8) = (1, 1);
+const H: (u8, u8) = (2, 1);
+const HV: (u8, u8) = (2, 2);
+
+const Y8: Plane = plane!(8, 1, 1, R8UNorm);
+const Y16: Plane = plane!(10, 1, 2, R16UNorm);
+const CB_CR_8_420: Plane = plane!(8, 2, HV, (1, 1, 2), Rg8UNorm);
+const CB_CR_16_420: Plane = plane!(10, 2, HV, (1, 1, 4), Rg16UNorm);
+const CB_CR_16_422: Plane = plane!(10, 2, H, (1, 1, 4), Rg16UNorm);
+const CB_CR_16_444: Plane = plane!(10, 2, FULL, (1, 1, 4), Rg16UNorm);
+const C8_420: Plane = plane!(8, 1, HV, (1, 1, 1), R8UNorm);
+
+const fn compressed(plane: Plane) -> Plane {
+    Plane {
+        bytes_per_block: 0,
+        ..plane
+    }
+}
+
+const fn packed_10(plane: Plane) -> Plane {
+    Plane {
+        bytes_per_block: 0,
+        bits_per_component: 10,
+        ..plane
+    }
+}
+
+const fn info(
+    pixel_format: cv::PixelFormat,
+    color_model: ColorModel,
+    full_range: bool,
+    has_alpha: bool,
+    planes: &'static [Plane],
+) -> Info {
+    Info {
+        pixel_format,
+        color_model,
+        full_range,
+        has_alpha,
+        compressed: false,
+        planes,
+    }
+}
+
+const fn yuv(pixel_format: cv::PixelFormat, full_range: bool, planes: &'static [Plane]) -> Info {
+    info(pixel_format, ColorModel::YCbCr, full_range, false, planes)
+}
+
+const fn rgb(pixel_format: cv::PixelFormat, has_alpha: bool, plane: &'static [Plane]) -> Info {
+    info(pixel_format, ColorModel::Rgb, true, has_alpha, plane)
+}
+
+const fn lossy(info: Info, planes: &'static [Plane]) -> Info {
+    Info {
+        compressed: true,
+        planes,
+        ..info
+    }
+}
+
+const I420V: Info = yuv(F::_420V, false, &[Y8, CB_CR_8_420]);
+const I420F: Info = yuv(F::_420F, true, &[Y8, CB_CR_8_420]);
+const IX420: Info = yuv(
+    F::_420_YP_CB_CR_10_BI_PLANAR_VIDEO_RANGE,
+    false,
+    &[Y16, CB_CR_16_420],
+);
+const IX422: Info = yuv(
+    F::_422_YP_CB_CR_10_BI_PLANAR_VIDEO_RANGE,
+    false,
+    &[Y16, CB_CR_16_422],
+);
+const IX444: Info = yuv(
+    F::_444_YP_CB_CR_10_BI_PLANAR_VIDEO_RANGE,
+    false,
+    &[Y16, CB_CR_16_444],
+);
+const IXF20: Info = yuv(
+    F::_420_YP_CB_CR_10_BI_PLANAR_FULL_RANGE,
+    true,
+    &[Y16, CB_CR_16_420],
+);
+const IXF22: Info = yuv(
+    F::_422_YP_CB_CR_10_BI_PLANAR_FULL_RANGE,
+    true,
+    &[Y16, CB_CR_16_422],
+);
+const IXF44: Info = yuv(
+    F::_444_YP_CB_CR_10_BI_PLANAR_FULL_RANGE,
+    true,
+    &[Y16, CB_CR_16_444],
+);
+const IY420: Info = yuv(F::_420_YP_CB_CR_8_PLANAR, false, &[Y8, C8_420, C8_420]);
+const IF420: Info = yuv(
+    F::_420_YP_CB_CR_8_PLANAR_FULL_RANGE,
+    true,
+    &[Y8, C8_420, C8_420],
+);
+const I2VUY: Info = yuv(F::_2VUY, false, &[plane!(8, 3, FULL, (2, 1, 4), Bgrg422)]);
+const IYUVS: Info = yuv(
+    F::_422_YP_CB_CR_8_YUVS,
+    false,
+    &[plane!(8, 3, FULL, (2, 1, 4), Gbgr422)],
+);
+const IYUVF: Info = yuv(
+    F::_422_YP_CB_CR_8_FULL_RANGE,
+    true,
+    &[plane!(8, 3, FULL, (2, 1, 4), Gbgr422)],
+);
+const IV210: Info = yuv(
+    F::_422_YP_CB_CR_10,
+    false,
+    &[plane!(10, 3, FULL, (6, 1, 16))],
+);
+const IV216: Info = yuv(
+    F::_422_YP_CB_CR_16,
+    false,
+    &[plane!(16, 3, FULL, (2, 1, 8))],
+);
+const IV308: Info = yuv(F::_444_YP_CB_CR_8, false, &[plane!(8, 3, 3)]);
+const IV410: Info = yuv(F::_444_YP_CB_CR_10, false, &[plane!(10, 3, 4)]);
+const IV408: Info = Info {
+    has_alpha: true,
+    ..yuv(F::_4444_YP_CB_CR_A_8, false, &[plane!(8, 4, 4)])
+};
+const IY416: Info = Info {
+    has_alpha: true,
+    ..yuv(
+        F::_4444_A_YP_CB_CR_16,
+        false,
+        &[plane!(16, 4, 8, Rgba16UNorm)],
+    )
+};
+const IA2VY: Info = Info {
+    has_alpha: true,
+    ..yuv(
+        F::_422_YP_CB_CR_4_A_8_BI_PLANAR,
+        false,
+        &[plane!(8, 3, FULL, (2, 1, 4), Bgrg422), Y8],
+    )
+};
+
+const BGRA8: Plane = plane!(8, 4, 4, Bgra8UNorm);
+const RGBA16F: Plane = plane!(16, 4, 8, Rgba16Float);
+
+const IBGRA: Info = rgb(F::_32_BGRA, true, &[BGRA8]);
+const IRGBA: Info = rgb(F::_32_RGBA, true, &[plane!(8, 4, 4, Rgba8UNorm)]);
+const IARGB: Info = rgb(F::_32_ARGB, true, &[plane!(8, 4, 4)]);
+const IABGR: Info = rgb(F::_32_ABGR, true, &[plane!(8, 4, 4)]);
+const I24RGB: Info = rgb(F::_24_RGB, false, &[plane!(8, 3, 3)]);
+const I24BGR: Info = rgb(F::_24_BGR, false, &[plane!(8, 3, 3)]);
+const IB64A: Info = rgb(F::_64_ARGB, true, &[plane!(16, 4, 8)]);
+const IL64R: Info = rgb(F::_64_RGBALE, true, &[plane!(16, 4, 8, Rgba16UNorm)]);
+const IRGHA: Info = rgb(F::_64_RGBA_HALF, true, &[RGBA16F]);
+const IRGFA: Info = rgb(F::_128_RGBA_FLOAT, true, &[plane!(32, 4, 16, Rgba32Float)]);
+const IL10R: Info = rgb(
+    F::ARGB_2101010_LE_PACKED,
+    true,
+    &[plane!(10, 4, 4, Bgr10A2UNorm)],
+);
+const IW30R: Info = rgb(
+    F::_30_RGB_LE_PACKED_WIDE_GAMUT,
+    false,
+    &[plane!(10, 3, 4, Bgr10Xr)],
+);
+const IR10K: Info = rgb(F::_30_RGB, false, &[plane!(10, 3, 4)]);
+const IR210: Info = rgb(F::_30_RGB_R210, false, &[plane!(10, 3, 4)]);
+
+const fn gray(pixel_format: cv::PixelFormat, plane: &'static [Plane]) -> Info {
+    info(pixel_format, ColorModel::Gray, true, false, plane)
+}
+
+const IL008: Info = gray(F::ONE_COMPONENT_8, &[Y8]);
+const IL010: Info = gray(F::ONE_COMPONENT_10, &[Y16]);
+const IL016: Info = gray(F::ONE_COMPONENT_16, &[plane!(16, 1, 2, R16UNorm)]);
+const IL00H: Info = gray(F::ONE_COMPONENT_H16, &[plane!(16, 1, 2, R16Float)]);
+const IL00F: Info = gray(F::ONE_COMPONENT_F32, &[plane!(32, 1, 4, R32Float)]);
+const I2C08: Info = gray(F::TWO_COMPONENT_8, &[plane!(8, 2, 2, Rg8UNorm)]);
+const I2C16: Info = gray(F::TWO_COMPONENT_16, &[plane!(16, 2, 4, Rg16UNorm)]);
+const I2C0H: Info = gray(F::TWO_COMPONENT_H16, &[plane!(16, 2, 4, Rg16Float)]);
+const I2C0F: Info = gray(F::TWO_COMPONENT_F32, &[plane!(32, 2, 8, Rg32Float)]);
+
+const fn depth(pixel_format: cv::PixelFormat, plane: &'static [Plane]) -> Info {
+    info(pixel_format, ColorModel::Depth, true, false, plane)
+}
+
+const HALF: Plane = plane!(16, 1, 2, R16Float);
+const FLOAT: Plane = plane!(32, 1, 4, R32Float);
+const IHDIS: Info = depth(F::DISPARITY_F16, &[HALF]);
+const IFDIS: Info = depth(F::DISPARITY_F32, &[FLOAT]);
+const IHDEP: Info = depth(F::DEPTH_F16, &[HALF]);
+const IFDEP: Info = depth(F::DEPTH_F32, &[FLOAT]);
+
+const IBP16: Info = info(
+    F::_16_VERSATILE_BAYER,
+    ColorModel::Bayer,
+    true,
+    false,
+    &[plane!(16, 1, 2, R16UNorm)],
+);
+const IBTP2: Info = info(
+    F::_96_VERSATILE_BAYER_PACKED_12,
+    ColorModel::Bayer,
+    true,
+    false,
+    &[plane!(12, 1, FULL, (8, 1, 12))],
+);
+const IBP64: Info = rgb(
+    F::_64_RGBA_DOWNSCALED_PRO_RES_RAW,
+    true,
+    &[plane!(16, 4, 8, Rgba16UNorm)],
+);
+
+const LOSSLESS_BGRA: Info = Info {
+    pixel_format: F::LOSSLESS_32_BGRA,
+    ..lossy(IBGRA, &[compressed(BGRA8)])
+};
+const LOSSLESS_RGHA: Info = Info {
+    pixel_format: F::LOSSLESS_64_RGBA_HALF,
+    ..lossy(IRGHA, &[compressed(RGBA16F)])
+};
+const LOSSY_BGRA: Info = Info {
+    pixel_format: F::LOSSY_32_BGRA,
+    ..LOSSLESS_BGRA
+};
+const LOSSLESS_420V: Info = Info {
+    pixel_format: F::LOSSLESS_420V,
+    ..lossy(I420V, &[compressed(Y8), compressed(CB_CR_8_420)])
+};
+const LOSSLESS_420F: Info = Info {
+    pixel_format: F::LOSSLESS_420F,
+    ..lossy(I420F, &[compressed(Y8), compressed(CB_CR_8_420)])
+};
+const LOSSY_420V: Info = Info {
+    pixel_format: F::LOSSY_420V,
+    ..LOSSLESS_420V
+};
+const LOSSY_420F: Info = Info {
+    pixel_format: F::LOSSY_420F,
+    ..LOSSLESS_420F
+};
+const LOSSLESS_XV0: Info = Info {
+    pixel_format: F::LOSSLESS_420_YP_CB_CR_10_PACKED_BI_PLANAR_VIDEO_RANGE,
+    ..lossy(IX420, &[packed_10(Y16), packed_10(CB_CR_16_420)])
+};
+const LOSSLESS_XV2: Info = Info {
+    pixel_format: F::LOSSLESS_422_YP_CB_CR_10_PACKED_BI_PLANAR_VIDEO_RANGE,
+    ..lossy(IX422, &[packed_10(Y16), packed_10(CB_CR_16_422)])
+};
+const LOSSY_XV0: Info = Info {
+    pixel_format: F::LOSSY_420_YP_CB_CR_10_PACKED_BI_PLANAR_VIDEO_RANGE,
+    ..LOSSLESS_XV0
+};
+const LOSSY_XV2: Info = Info {
+    pixel_format: F::LOSSY_422_YP_CB_CR_10_PACKED_BI_PLANAR_VIDEO_RANGE,
+    ..LOSSLESS_XV2
+};
+
+/// All formats with static description.
+pub static ALL: &[Info] = &[
+    I420V,
+    I420F,
+    IX420,
+    IX422,
+    IX444,
+    IXF20,
+    IXF22,
+    IXF44,
+    IY420,
+    IF420,
+    I2VUY,
+    IYUVS,
+    IYUVF,
+    IV210,
+    IV216,
+    IV308,
+    IV410,
+    IV408,
+    IY416,
+    IA2VY,
+    IBGRA,
+    IRGBA,
+    IARGB,
+    IABGR,
+    I24RGB,
+    I24BGR,
+    IB64A,
+    IL64R,
+    IRGHA,
+    IRGFA,
+    IL10R,
+    IW30R,
+    IR10K,
+    IR210,
+    IL008,
+    IL010,
+    IL016,
+    IL00H,
+    IL00F,
+    I2C08,
+    I2C16,
+    I2C0H,
+    I2C0F,
+    IHDIS,
+    IFDIS,
+    IHDEP,
+    IFDEP,
+    IBP16,
+    IBTP2,
+    IBP64,
+    LOSSLESS_BGRA,
+    LOSSLESS_RGHA,
+    LOSSY_BGRA,
+    LOSSLESS_420V,
+    LOSSLESS_420F,
+    LOSSY_420V,
+    LOSSY_420F,
+    LOSSLESS_XV0,
+    LOSSLESS_XV2,
+    LOSSY_XV0,
+    LOSSY_XV2,
+];
+
+impl cv::PixelFormat {
+    /// Static description of the format without asking CoreVideo.
+    pub const fn info(self) -> Option<&'static Info> {
+        let mut i = 0;
+        while i < ALL.len() {
+            if ALL[i].pixel_format.0 == self.0 {
+                return Some(&ALL[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cv;
+
+    #[test]
+    fn basics() {
+        let info = cv::PixelFormat::_420_YP_CB_CR_10_BI_PLANAR_FULL_RANGE
+            .info()
+            .unwrap();
+        assert!(info.full_range && info.is_planar() && info.is_y_cb_cr());
+        assert_eq!(info.bits_per_component(), 10);
+        assert_eq!(info.data_size(1920, 1080), 1920 * 1080 * 2 * 3 / 2);
+
+        let info = cv::PixelFormat::_2VUY.info().unwrap();
+        assert_eq!(info.chroma_subsampling(), Some((2, 1)));
+        assert_eq!(info.planes[0].bytes_per_row(1921), 3844);
+
+        let info = cv::PixelFormat::_422_YP_CB_CR_4_A_8_BI_PLANAR
+            .info()
+            .unwrap();
+        assert!(info.has_alpha && info.is_planar());
+        assert_eq!(info.chroma_subsampling(), Some((2, 1)));
+        assert_eq!(info.data_size(1920, 1080), 1920 * 1080 * 3);
+
+        let info = cv::PixelFormat::_420_YP_CB_CR_8_PLANAR.info().unwrap();
+        assert_eq!(info.chroma_subsampling(), Some((2, 2)));
+        assert_eq!(
+            cv::PixelFormat::_444_YP_CB_CR_8
+                .info()
+                .unwrap()
+                .chroma_subsampling(),
+            Some((1, 1))
+        );
+
+        let info = cv::PixelFormat::_422_YP_CB_CR_10.info().unwrap();
+        assert_eq!(info.planes[0].bytes_per_row(1920), 5120);
+
+        let info = cv::PixelFormat::LOSSY_420_YP_CB_CR_10_PACKED_BI_PLANAR_VIDEO_RANGE
+            .info()
+            .unwrap();
+        assert!(info.compressed);
+        assert_eq!(info.data_size(1920, 1080), 0);
+
+        let info = cv::PixelFormat::_32_BGRA.info().unwrap();
+        assert!(info.has_alpha);
+        assert_eq!(info.color_model, cv::PixelColorModel::Rgb);
+        assert!(cv::PixelFormat::_1_MONOCHROME.info().is_none());
+
+        for info in cv::pixel_format_info::ALL {
+            assert_eq!(info.pixel_format.info(), Some(info));
+        }
+    }
+
+    #[cfg(feature = "mtl")]
+    #[test]
+    fn mtl() {
+        use crate::mtl;
+
+        let info = cv::PixelFormat::_420V.info().unwrap();
+        assert_eq!(info.planes[0].mtl_format, Some(mtl::PixelFormat::R8UNorm));
+        assert_eq!(info.planes[1].mtl_format, Some(mtl::PixelFormat::Rg8UNorm));
+
+        for info in cv::pixel_format_info::ALL {
+            for plane in info.planes {
+                let Some(format) = plane.mtl_format else {
+                    continue;
+                };
+                if info.compressed {
+                    continue;
+                }
+                let (w, _) = format.block_size();
+                assert_eq!(w, plane.block_size.0 as usize, "{info:?}");
+                assert_eq!(format.bytes_per_block(), plane.bytes_per_block as usize);
+            }
+        }
+    }
+}
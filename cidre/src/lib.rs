@@ -27,6 +27,11 @@ pub mod av;
 #[cfg(all(target_os = "macos", feature = "ax"))]
 pub mod ax;
 
+/// Bit and byte cursors of pure Rust codecs
+#[cfg(any(feature = "cat", feature = "cm"))]
+#[cfg_attr(not(feature = "cm"), allow(dead_code))]
+mod bits;
+
 /// cidre vision of obj-c blocks impl in rust
 #[cfg(feature = "blocks")]
 pub mod blocks;
@@ -41,7 +46,7 @@ pub mod ca;
 pub mod cat;
 
 /// Core Foundation
-#[cfg(feature = "cf")]
+#[cfg(all(target_vendor = "apple", feature = "cf"))]
 pub mod cf;
 
 /// Core Graphics
@@ -147,6 +152,10 @@ pub mod objc;
 pub mod os;
 pub mod sys;
 
+/// Property lists without Core Foundation
+#[cfg(feature = "plist")]
+pub mod plist;
+
 /// Security
 #[cfg(feature = "sec")]
 pub mod sec;
@@ -159,7 +168,7 @@ pub mod vt;
 #[cfg(feature = "vdsp")]
 pub mod vdsp;
 
-#[cfg(feature = "vdsp")]
+#[cfg(all(target_vendor = "apple", feature = "vdsp"))]
 pub mod vimage;
 
 #[cfg(feature = "cblas")]
@@ -335,7 +344,7 @@ macro_rules! define_opts {
     };
 }
 
-#[cfg(test)]
+#[cfg(all(test, target_vendor = "apple", feature = "cf"))]
 mod tests {
     use crate::cf;
 
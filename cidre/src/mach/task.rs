@@ -33,7 +33,7 @@ unsafe extern "C-unwind" {
     ) -> mach::KernReturn;
 }
 
-#[cfg(test)]
+#[cfg(all(test, target_vendor = "apple"))]
 mod tests {
     use crate::mach;
 
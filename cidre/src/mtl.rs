@@ -31,6 +31,8 @@ pub use acceleration_structure_types::PackedF32x3;
 pub use acceleration_structure_types::PackedF32x4x3;
 
 mod pixel_format;
+pub use pixel_format::Compression as PixelCompression;
+pub use pixel_format::ComponentType as PixelComponentType;
 pub use pixel_format::PixelFormat;
 
 mod argument;
@@ -250,6 +252,14 @@ pub use vertex_descriptor::VertexBufLayoutDescArray;
 pub use vertex_descriptor::VertexFormat;
 pub use vertex_descriptor::VertexStepFn;
 
+pub mod vertex_layout;
+pub use vertex_layout::Attr as VertexAttrType;
+pub use vertex_layout::AttrLayout as VertexAttrLayout;
+pub use vertex_layout::BufLayout as VertexBufLayout;
+pub use vertex_layout::Builder as VertexLayoutBuilder;
+pub use vertex_layout::Layout as VertexLayout;
+pub use vertex_layout::Normalized as VertexNormalized;
+
 mod render_pass;
 pub use render_pass::AttachDesc as RenderPassAttachDesc;
 pub use render_pass::ClearColor;
@@ -1,3 +1,5 @@
+use crate::mtl;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
 #[repr(usize)]
 pub enum PixelFormat {
@@ -337,6 +339,22 @@ pub enum PixelFormat {
     #[doc(alias = "MTLPixelFormatBC3_RGBA_sRGB")]
     Bc3RgbaSrgb = 135,
 
+    /// Compressed format with one normalized unsigned integer component.
+    #[doc(alias = "MTLPixelFormatBC4_RUnorm")]
+    Bc4RUNorm = 140,
+
+    /// Compressed format with one normalized signed integer component.
+    #[doc(alias = "MTLPixelFormatBC4_RSnorm")]
+    Bc4RSNorm = 141,
+
+    /// Compressed format with two normalized unsigned integer components.
+    #[doc(alias = "MTLPixelFormatBC5_RGUnorm")]
+    Bc5RgUNorm = 142,
+
+    /// Compressed format with two normalized signed integer components.
+    #[doc(alias = "MTLPixelFormatBC5_RGSnorm")]
+    Bc5RgSNorm = 143,
+
     /// Compressed format with four floating-point components.
     #[doc(alias = "MTLPixelFormatBC6H_RGBFloat")]
     Bc6HRgbFloat = 150,
@@ -354,6 +372,74 @@ pub enum PixelFormat {
     #[doc(alias = "MTLPixelFormatBC7_RGBAUnorm_sRGB")]
     Bc7RgbaUNormSrgb = 153,
 
+    #[doc(alias = "MTLPixelFormatPVRTC_RGB_2BPP")]
+    PvrtcRgb2Bpp = 160,
+
+    #[doc(alias = "MTLPixelFormatPVRTC_RGB_2BPP_sRGB")]
+    PvrtcRgb2BppSrgb = 161,
+
+    #[doc(alias = "MTLPixelFormatPVRTC_RGB_4BPP")]
+    PvrtcRgb4Bpp = 162,
+
+    #[doc(alias = "MTLPixelFormatPVRTC_RGB_4BPP_sRGB")]
+    PvrtcRgb4BppSrgb = 163,
+
+    #[doc(alias = "MTLPixelFormatPVRTC_RGBA_2BPP")]
+    PvrtcRgba2Bpp = 164,
+
+    #[doc(alias = "MTLPixelFormatPVRTC_RGBA_2BPP_sRGB")]
+    PvrtcRgba2BppSrgb = 165,
+
+    #[doc(alias = "MTLPixelFormatPVRTC_RGBA_4BPP")]
+    PvrtcRgba4Bpp = 166,
+
+    #[doc(alias = "MTLPixelFormatPVRTC_RGBA_4BPP_sRGB")]
+    PvrtcRgba4BppSrgb = 167,
+
+    /// ETC2 compressed format with one 11-bit normalized unsigned integer component.
+    #[doc(alias = "MTLPixelFormatEAC_R11Unorm")]
+    EacR11UNorm = 170,
+
+    /// ETC2 compressed format with one 11-bit normalized signed integer component.
+    #[doc(alias = "MTLPixelFormatEAC_R11Snorm")]
+    EacR11SNorm = 172,
+
+    /// ETC2 compressed format with two 11-bit normalized unsigned integer components.
+    #[doc(alias = "MTLPixelFormatEAC_RG11Unorm")]
+    EacRg11UNorm = 174,
+
+    /// ETC2 compressed format with two 11-bit normalized signed integer components.
+    #[doc(alias = "MTLPixelFormatEAC_RG11Snorm")]
+    EacRg11SNorm = 176,
+
+    /// ETC2 compressed format with four 8-bit normalized unsigned integer components.
+    #[doc(alias = "MTLPixelFormatEAC_RGBA8")]
+    EacRgba8 = 178,
+
+    /// ETC2 compressed format with four 8-bit normalized unsigned integer components,
+    /// with conversion between sRGB and linear space.
+    #[doc(alias = "MTLPixelFormatEAC_RGBA8_sRGB")]
+    EacRgba8Srgb = 179,
+
+    /// ETC2 compressed format with three 8-bit normalized unsigned integer components.
+    #[doc(alias = "MTLPixelFormatETC2_RGB8")]
+    Etc2Rgb8 = 180,
+
+    /// ETC2 compressed format with three 8-bit normalized unsigned integer components,
+    /// with conversion between sRGB and linear space.
+    #[doc(alias = "MTLPixelFormatETC2_RGB8_sRGB")]
+    Etc2Rgb8Srgb = 181,
+
+    /// ETC2 compressed format with three 8-bit normalized unsigned integer color components
+    /// and one 1-bit alpha component.
+    #[doc(alias = "MTLPixelFormatETC2_RGB8A1")]
+    Etc2Rgb8A1 = 182,
+
+    /// ETC2 compressed format with three 8-bit normalized unsigned integer color components
+    /// and one 1-bit alpha component, with conversion between sRGB and linear space.
+    #[doc(alias = "MTLPixelFormatETC2_RGB8A1_sRGB")]
+    Etc2Rgb8A1Srgb = 183,
+
     /// ASTC-compressed format with low-dynamic-range content,
     /// conversion between sRGB and linear space, a block width of 4, and a block height of 4.
     #[doc(alias = "MTLPixelFormatASTC_4x4_sRGB")]
@@ -501,3 +587,623 @@ pub enum PixelFormat {
     #[doc(alias = "MTLPixelFormatUnspecialized")]
     Unspecialized = 263,
 }
+
+/// Numeric representation of pixel format components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentType {
+    /// Normalized unsigned integer, sampled as `0.0..=1.0`
+    UNorm,
+
+    /// Normalized signed integer, sampled as `-1.0..=1.0`
+    SNorm,
+    UInt,
+    SInt,
+    Float,
+}
+
+/// Block compression family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// BC1-BC7 (S3TC, RGTC and BPTC)
+    Bc,
+    Astc,
+
+    /// ETC2 and EAC
+    Etc,
+    Pvrtc,
+}
+
+impl PixelFormat {
+    /// Width and height of the block, (1, 1) for ordinary formats
+    /// and (2, 1) for 422 subsampled formats.
+    ///
+    /// ```
+    /// use cidre::mtl;
+    ///
+    /// assert_eq!(mtl::PixelFormat::Rgba8UNorm.block_size(), (1, 1));
+    /// assert_eq!(mtl::PixelFormat::Astc10x6Ldr.block_size(), (10, 6));
+    /// assert_eq!(mtl::PixelFormat::Bc7RgbaUNorm.bytes_per_block(), 16);
+    /// ```
+    pub const fn block_size(self) -> (usize, usize) {
+        use PixelFormat as F;
+        match self {
+            F::Invalid | F::Unspecialized => (0, 0),
+            F::Gbgr422 | F::Bgrg422 => (2, 1),
+            F::PvrtcRgb2Bpp | F::PvrtcRgb2BppSrgb | F::PvrtcRgba2Bpp | F::PvrtcRgba2BppSrgb => {
+                (8, 4)
+            }
+            F::Astc4x4Srgb | F::Astc4x4Ldr | F::Astc4x4Hdr => (4, 4),
+            F::Astc5x4Srgb | F::Astc5x4Ldr | F::Astc5x4Hdr => (5, 4),
+            F::Astc5x5Srgb | F::Astc5x5Ldr | F::Astc5x5Hdr => (5, 5),
+            F::Astc6x5Srgb | F::Astc6x5Ldr | F::Astc6x5Hdr => (6, 5),
+            F::Astc6x6Srgb | F::Astc6x6Ldr | F::Astc6x6Hdr => (6, 6),
+            F::Astc8x5Srgb | F::Astc8x5Ldr | F::Astc8x5Hdr => (8, 5),
+            F::Astc8x6Srgb | F::Astc8x6Ldr | F::Astc8x6Hdr => (8, 6),
+            F::Astc8x8Srgb | F::Astc8x8Ldr | F::Astc8x8Hdr => (8, 8),
+            F::Astc10x5Srgb | F::Astc10x5Ldr | F::Astc10x5Hdr => (10, 5),
+            F::Astc10x6Srgb | F::Astc10x6Ldr | F::Astc10x6Hdr => (10, 6),
+            F::Astc10x8Srgb | F::Astc10x8Ldr | F::Astc10x8Hdr => (10, 8),
+            F::Astc10x10Srgb | F::Astc10x10Ldr | F::Astc10x10Hdr => (10, 10),
+            F::Astc12x10Srgb | F::Astc12x10Ldr | F::Astc12x10Hdr => (12, 10),
+            F::Astc12x12Srgb | F::Astc12x12Ldr | F::Asrc12x12Hdr => (12, 12),
+            _ if self.is_compressed() => (4, 4),
+            _ => (1, 1),
+        }
+    }
+
+    /// Bytes per block, bytes per pixel for ordinary formats.
+    ///
+    /// Depth32FloatStencil8 and its stencil view are reported as 8 bytes,
+    /// the way most devices allocate them.
+    pub const fn bytes_per_block(self) -> usize {
+        use PixelFormat as F;
+        match self {
+            F::Invalid | F::Unspecialized => 0,
+
+            F::A8UNorm
+            | F::R8UNorm
+            | F::R8UNormSrgb
+            | F::R8SNorm
+            | F::R8UInt
+            | F::R8SInt
+            | F::Stencil8 => 1,
+
+            F::R16UNorm
+            | F::R16SNorm
+            | F::R16UInt
+            | F::R16SInt
+            | F::R16Float
+            | F::Rg8UNorm
+            | F::Rg8UNormSrgb
+            | F::Rg8SNorm
+            | F::Rg8UInt
+            | F::Rg8SInt
+            | F::B5G6R5UNorm
+            | F::A1Bgr5UNorm
+            | F::Abgr4UNorm
+            | F::Bgr5A1UNorm
+            | F::Depth16Unorm => 2,
+
+            F::Rg32UInt
+            | F::Rg32SInt
+            | F::Rg32Float
+            | F::Rgba16UNorm
+            | F::Rgba16SNorm
+            | F::Rgba16UInt
+            | F::Rgba16SInt
+            | F::Rgba16Float
+            | F::Bgra10Xr
+            | F::Bgra10XrSrgb
+            | F::Depth32FloatStencil8
+            | F::X32Stencil8 => 8,
+
+            F::Rgba32UInt | F::Rgba32SInt | F::Rgba32Float => 16,
+
+            F::Bc1Rgba
+            | F::Bc1RgbaSrgb
+            | F::Bc4RUNorm
+            | F::Bc4RSNorm
+            | F::EacR11UNorm
+            | F::EacR11SNorm
+            | F::Etc2Rgb8
+            | F::Etc2Rgb8Srgb
+            | F::Etc2Rgb8A1
+            | F::Etc2Rgb8A1Srgb => 8,
+
+            F::Bc2Rgba
+            | F::Bc2RgbaSrgb
+            | F::Bc3Rgba
+            | F::Bc3RgbaSrgb
+            | F::Bc5RgUNorm
+            | F::Bc5RgSNorm
+            | F::Bc6HRgbFloat
+            | F::Bc6HRgbUFloat
+            | F::Bc7RgbaUNorm
+            | F::Bc7RgbaUNormSrgb
+            | F::EacRg11UNorm
+            | F::EacRg11SNorm
+            | F::EacRgba8
+            | F::EacRgba8Srgb => 16,
+
+            _ if self.is_pvrtc() => 8,
+            _ if self.is_astc() => 16,
+
+            // the rest of 32 bit formats, including 422 blocks of two pixels
+            _ => 4,
+        }
+    }
+
+    /// Bits per pixel, `None` for block compressed formats.
+    ///
+    /// ```
+    /// use cidre::mtl;
+    ///
+    /// assert_eq!(mtl::PixelFormat::Bgra8UNorm.bits_per_pixel(), Some(32));
+    /// assert_eq!(mtl::PixelFormat::Bgrg422.bits_per_pixel(), Some(16));
+    /// assert_eq!(mtl::PixelFormat::Bc1Rgba.bits_per_pixel(), None);
+    /// ```
+    pub const fn bits_per_pixel(self) -> Option<usize> {
+        if self.is_compressed() {
+            return None;
+        }
+        let (w, h) = self.block_size();
+        if w == 0 {
+            return None;
+        }
+        Some(self.bytes_per_block() * 8 / (w * h))
+    }
+
+    /// Number of components, depth and stencil count as components.
+    pub const fn component_count(self) -> usize {
+        use PixelFormat as F;
+        match self {
+            F::Invalid | F::Unspecialized => 0,
+
+            F::A8UNorm
+            | F::R8UNorm
+            | F::R8UNormSrgb
+            | F::R8SNorm
+            | F::R8UInt
+            | F::R8SInt
+            | F::R16UNorm
+            | F::R16SNorm
+            | F::R16UInt
+            | F::R16SInt
+            | F::R16Float
+            | F::R32UInt
+            | F::R32SInt
+            | F::R32Float
+            | F::Bc4RUNorm
+            | F::Bc4RSNorm
+            | F::EacR11UNorm
+            | F::EacR11SNorm
+            | F::Depth16Unorm
+            | F::Depth32Float
+            | F::Stencil8
+            | F::X32Stencil8
+            | F::X24Stencil8 => 1,
+
+            F::Rg8UNorm
+            | F::Rg8UNormSrgb
+            | F::Rg8SNorm
+            | F::Rg8UInt
+            | F::Rg8SInt
+            | F::Rg16UNorm
+            | F::Rg16SNorm
+            | F::Rg16UInt
+            | F::Rg16SInt
+            | F::Rg16Float
+            | F::Rg32UInt
+            | F::Rg32SInt
+            | F::Rg32Float
+            | F::Bc5RgUNorm
+            | F::Bc5RgSNorm
+            | F::EacRg11UNorm
+            | F::EacRg11SNorm
+            | F::Depth24UnormStencil8
+            | F::Depth32FloatStencil8 => 2,
+
+            F::B5G6R5UNorm
+            | F::Rg11B10Float
+            | F::Rgb9E5Float
+            | F::Bgr10Xr
+            | F::Bgr10XrSrgb
+            | F::Bc6HRgbFloat
+            | F::Bc6HRgbUFloat
+            | F::Etc2Rgb8
+            | F::Etc2Rgb8Srgb
+            | F::PvrtcRgb2Bpp
+            | F::PvrtcRgb2BppSrgb
+            | F::PvrtcRgb4Bpp
+            | F::PvrtcRgb4BppSrgb
+            | F::Gbgr422
+            | F::Bgrg422 => 3,
+
+            _ => 4,
+        }
+    }
+
+    /// Representation of components, depth for combined depth stencil formats.
+    pub const fn component_type(self) -> Option<ComponentType> {
+        use ComponentType as T;
+        use PixelFormat as F;
+        let t = match self {
+            F::Invalid | F::Unspecialized => return None,
+
+            F::R8SNorm
+            | F::R16SNorm
+            | F::Rg8SNorm
+            | F::Rg16SNorm
+            | F::Rgba8SNorm
+            | F::Rgba16SNorm
+            | F::Bc4RSNorm
+            | F::Bc5RgSNorm
+            | F::EacR11SNorm
+            | F::EacRg11SNorm => T::SNorm,
+
+            F::R8UInt
+            | F::R16UInt
+            | F::R32UInt
+            | F::Rg8UInt
+            | F::Rg16UInt
+            | F::Rg32UInt
+            | F::Rgba8UInt
+            | F::Rgba16UInt
+            | F::Rgba32UInt
+            | F::Rgb10A2UInt
+            | F::Stencil8
+            | F::X32Stencil8
+            | F::X24Stencil8 => T::UInt,
+
+            F::R8SInt
+            | F::R16SInt
+            | F::R32SInt
+            | F::Rg8SInt
+            | F::Rg16SInt
+            | F::Rg32SInt
+            | F::Rgba8SInt
+            | F::Rgba16SInt
+            | F::Rgba32SInt => T::SInt,
+
+            F::R16Float
+            | F::R32Float
+            | F::Rg16Float
+            | F::Rg32Float
+            | F::Rgba16Float
+            | F::Rgba32Float
+            | F::Rg11B10Float
+            | F::Rgb9E5Float
+            | F::Bc6HRgbFloat
+            | F::Bc6HRgbUFloat
+            | F::Depth32Float
+            | F::Depth32FloatStencil8 => T::Float,
+
+            F::Astc4x4Hdr
+            | F::Astc5x4Hdr
+            | F::Astc5x5Hdr
+            | F::Astc6x5Hdr
+            | F::Astc6x6Hdr
+            | F::Astc8x5Hdr
+            | F::Astc8x6Hdr
+            | F::Astc8x8Hdr
+            | F::Astc10x5Hdr
+            | F::Astc10x6Hdr
+            | F::Astc10x8Hdr
+            | F::Astc10x10Hdr
+            | F::Astc12x10Hdr
+            | F::Asrc12x12Hdr => T::Float,
+
+            _ => T::UNorm,
+        };
+        Some(t)
+    }
+
+    /// Performs conversion between sRGB and linear space on read and write.
+    pub const fn is_srgb(self) -> bool {
+        use PixelFormat as F;
+        matches!(
+            self,
+            F::R8UNormSrgb
+                | F::Rg8UNormSrgb
+                | F::Rgba8UNormSrgb
+                | F::Bgra8UNormSrgb
+                | F::Bgr10XrSrgb
+                | F::Bgra10XrSrgb
+                | F::Bc1RgbaSrgb
+                | F::Bc2RgbaSrgb
+                | F::Bc3RgbaSrgb
+                | F::Bc7RgbaUNormSrgb
+                | F::PvrtcRgb2BppSrgb
+                | F::PvrtcRgb4BppSrgb
+                | F::PvrtcRgba2BppSrgb
+                | F::PvrtcRgba4BppSrgb
+                | F::EacRgba8Srgb
+                | F::Etc2Rgb8Srgb
+                | F::Etc2Rgb8A1Srgb
+                | F::Astc4x4Srgb
+                | F::Astc5x4Srgb
+                | F::Astc5x5Srgb
+                | F::Astc6x5Srgb
+                | F::Astc6x6Srgb
+                | F::Astc8x5Srgb
+                | F::Astc8x6Srgb
+                | F::Astc8x8Srgb
+                | F::Astc10x5Srgb
+                | F::Astc10x6Srgb
+                | F::Astc10x8Srgb
+                | F::Astc10x10Srgb
+                | F::Astc12x10Srgb
+                | F::Astc12x12Srgb
+        )
+    }
+
+    pub const fn is_depth(self) -> bool {
+        use PixelFormat as F;
+        matches!(
+            self,
+            F::Depth16Unorm | F::Depth32Float | F::Depth24UnormStencil8 | F::Depth32FloatStencil8
+        )
+    }
+
+    pub const fn is_stencil(self) -> bool {
+        use PixelFormat as F;
+        matches!(
+            self,
+            F::Stencil8
+                | F::Depth24UnormStencil8
+                | F::Depth32FloatStencil8
+                | F::X32Stencil8
+                | F::X24Stencil8
+        )
+    }
+
+    /// Several components share one 16 or 32 bit word.
+    pub const fn is_packed(self) -> bool {
+        use PixelFormat as F;
+        matches!(
+            self,
+            F::B5G6R5UNorm
+                | F::A1Bgr5UNorm
+                | F::Abgr4UNorm
+                | F::Bgr5A1UNorm
+                | F::Rgb10A2UNorm
+                | F::Rgb10A2UInt
+                | F::Rg11B10Float
+                | F::Rgb9E5Float
+                | F::Bgr10A2UNorm
+                | F::Bgr10Xr
+                | F::Bgr10XrSrgb
+        )
+    }
+
+    pub const fn compression(self) -> Option<Compression> {
+        let v = self as usize;
+        if v >= 130 && v < 160 {
+            Some(Compression::Bc)
+        } else if v >= 160 && v < 170 {
+            Some(Compression::Pvrtc)
+        } else if v >= 170 && v < 186 {
+            Some(Compression::Etc)
+        } else if v >= 186 && v < 240 {
+            Some(Compression::Astc)
+        } else {
+            None
+        }
+    }
+
+    /// Block compressed format. 422 formats are not considered compressed here.
+    #[inline]
+    pub const fn is_compressed(self) -> bool {
+        self.compression().is_some()
+    }
+
+    #[inline]
+    pub const fn is_bc(self) -> bool {
+        matches!(self.compression(), Some(Compression::Bc))
+    }
+
+    #[inline]
+    pub const fn is_astc(self) -> bool {
+        matches!(self.compression(), Some(Compression::Astc))
+    }
+
+    #[inline]
+    pub const fn is_etc(self) -> bool {
+        matches!(self.compression(), Some(Compression::Etc))
+    }
+
+    #[inline]
+    pub const fn is_pvrtc(self) -> bool {
+        matches!(self.compression(), Some(Compression::Pvrtc))
+    }
+
+    /// sRGB counterpart of the format, self for sRGB formats.
+    ///
+    /// ```
+    /// use cidre::mtl;
+    ///
+    /// let f = mtl::PixelFormat::Astc6x6Ldr;
+    /// assert_eq!(f.to_srgb(), Some(mtl::PixelFormat::Astc6x6Srgb));
+    /// assert_eq!(f.to_srgb().unwrap().to_linear(), Some(f));
+    /// assert_eq!(mtl::PixelFormat::Rgba16Float.to_srgb(), None);
+    /// ```
+    pub const fn to_srgb(self) -> Option<Self> {
+        use PixelFormat as F;
+        if self.is_srgb() {
+            return Some(self);
+        }
+        let f = match self {
+            F::R8UNorm => F::R8UNormSrgb,
+            F::Rg8UNorm => F::Rg8UNormSrgb,
+            F::Rgba8UNorm => F::Rgba8UNormSrgb,
+            F::Bgra8UNorm => F::Bgra8UNormSrgb,
+            F::Bgr10Xr => F::Bgr10XrSrgb,
+            F::Bgra10Xr => F::Bgra10XrSrgb,
+            F::Bc1Rgba => F::Bc1RgbaSrgb,
+            F::Bc2Rgba => F::Bc2RgbaSrgb,
+            F::Bc3Rgba => F::Bc3RgbaSrgb,
+            F::Bc7RgbaUNorm => F::Bc7RgbaUNormSrgb,
+            F::PvrtcRgb2Bpp => F::PvrtcRgb2BppSrgb,
+            F::PvrtcRgb4Bpp => F::PvrtcRgb4BppSrgb,
+            F::PvrtcRgba2Bpp => F::PvrtcRgba2BppSrgb,
+            F::PvrtcRgba4Bpp => F::PvrtcRgba4BppSrgb,
+            F::EacRgba8 => F::EacRgba8Srgb,
+            F::Etc2Rgb8 => F::Etc2Rgb8Srgb,
+            F::Etc2Rgb8A1 => F::Etc2Rgb8A1Srgb,
+            F::Astc4x4Ldr => F::Astc4x4Srgb,
+            F::Astc5x4Ldr => F::Astc5x4Srgb,
+            F::Astc5x5Ldr => F::Astc5x5Srgb,
+            F::Astc6x5Ldr => F::Astc6x5Srgb,
+            F::Astc6x6Ldr => F::Astc6x6Srgb,
+            F::Astc8x5Ldr => F::Astc8x5Srgb,
+            F::Astc8x6Ldr => F::Astc8x6Srgb,
+            F::Astc8x8Ldr => F::Astc8x8Srgb,
+            F::Astc10x5Ldr => F::Astc10x5Srgb,
+            F::Astc10x6Ldr => F::Astc10x6Srgb,
+            F::Astc10x8Ldr => F::Astc10x8Srgb,
+            F::Astc10x10Ldr => F::Astc10x10Srgb,
+            F::Astc12x10Ldr => F::Astc12x10Srgb,
+            F::Astc12x12Ldr => F::Astc12x12Srgb,
+            _ => return None,
+        };
+        Some(f)
+    }
+
+    /// Linear counterpart of sRGB format, self for formats which have sRGB variant.
+    pub const fn to_linear(self) -> Option<Self> {
+        use PixelFormat as F;
+        if !self.is_srgb() {
+            return if self.to_srgb().is_some() {
+                Some(self)
+            } else {
+                None
+            };
+        }
+        let f = match self {
+            F::R8UNormSrgb => F::R8UNorm,
+            F::Rg8UNormSrgb => F::Rg8UNorm,
+            F::Rgba8UNormSrgb => F::Rgba8UNorm,
+            F::Bgra8UNormSrgb => F::Bgra8UNorm,
+            F::Bgr10XrSrgb => F::Bgr10Xr,
+            F::Bgra10XrSrgb => F::Bgra10Xr,
+            F::Bc1RgbaSrgb => F::Bc1Rgba,
+            F::Bc2RgbaSrgb => F::Bc2Rgba,
+            F::Bc3RgbaSrgb => F::Bc3Rgba,
+            F::Bc7RgbaUNormSrgb => F::Bc7RgbaUNorm,
+            F::PvrtcRgb2BppSrgb => F::PvrtcRgb2Bpp,
+            F::PvrtcRgb4BppSrgb => F::PvrtcRgb4Bpp,
+            F::PvrtcRgba2BppSrgb => F::PvrtcRgba2Bpp,
+            F::PvrtcRgba4BppSrgb => F::PvrtcRgba4Bpp,
+            F::EacRgba8Srgb => F::EacRgba8,
+            F::Etc2Rgb8Srgb => F::Etc2Rgb8,
+            F::Etc2Rgb8A1Srgb => F::Etc2Rgb8A1,
+            F::Astc4x4Srgb => F::Astc4x4Ldr,
+            F::Astc5x4Srgb => F::Astc5x4Ldr,
+            F::Astc5x5Srgb => F::Astc5x5Ldr,
+            F::Astc6x5Srgb => F::Astc6x5Ldr,
+            F::Astc6x6Srgb => F::Astc6x6Ldr,
+            F::Astc8x5Srgb => F::Astc8x5Ldr,
+            F::Astc8x6Srgb => F::Astc8x6Ldr,
+            F::Astc8x8Srgb => F::Astc8x8Ldr,
+            F::Astc10x5Srgb => F::Astc10x5Ldr,
+            F::Astc10x6Srgb => F::Astc10x6Ldr,
+            F::Astc10x8Srgb => F::Astc10x8Ldr,
+            F::Astc10x10Srgb => F::Astc10x10Ldr,
+            F::Astc12x10Srgb => F::Astc12x10Ldr,
+            F::Astc12x12Srgb => F::Astc12x12Ldr,
+            _ => return None,
+        };
+        Some(f)
+    }
+
+    /// Bytes per row of blocks for the texture `width` at mip `level`.
+    ///
+    /// ```
+    /// use cidre::mtl;
+    ///
+    /// let f = mtl::PixelFormat::Bc7RgbaUNorm;
+    /// assert_eq!(f.bytes_per_row(1000, 0), 250 * 16);
+    /// assert_eq!(f.bytes_per_row(1000, 9), 16);
+    ///
+    /// let size = mtl::Size { width: 1920, height: 1080, depth: 1 };
+    /// assert_eq!(mtl::PixelFormat::Bgra8UNorm.bytes_per_image(size, 1), 960 * 4 * 540);
+    /// assert_eq!(mtl::PixelFormat::Astc8x8Ldr.bytes_per_image(size, 0), 240 * 135 * 16);
+    /// ```
+    pub const fn bytes_per_row(self, width: usize, level: usize) -> usize {
+        let (block_w, _) = self.block_size();
+        if block_w == 0 {
+            return 0;
+        }
+        mip_dim(width, level).div_ceil(block_w) * self.bytes_per_block()
+    }
+
+    /// Bytes of one 2D slice for the texture `size` at mip `level`, depth is ignored.
+    pub const fn bytes_per_image(self, size: mtl::Size, level: usize) -> usize {
+        let (_, block_h) = self.block_size();
+        if block_h == 0 {
+            return 0;
+        }
+        let rows = mip_dim(size.height, level).div_ceil(block_h);
+        self.bytes_per_row(size.width, level) * rows
+    }
+
+    /// Bytes of all slices of 3D texture `size` at mip `level`.
+    pub const fn bytes_per_level(self, size: mtl::Size, level: usize) -> usize {
+        self.bytes_per_image(size, level) * mip_dim(size.depth, level)
+    }
+}
+
+const fn mip_dim(dim: usize, level: usize) -> usize {
+    let dim = if level < usize::BITS as usize {
+        dim >> level
+    } else {
+        0
+    };
+    if dim == 0 { 1 } else { dim }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mtl;
+
+    #[test]
+    fn basics() {
+        let f = mtl::PixelFormat::Rgba16Float;
+        assert_eq!(f.bits_per_pixel(), Some(64));
+        assert_eq!(f.component_count(), 4);
+        assert_eq!(f.component_type(), Some(mtl::PixelComponentType::Float));
+        assert!(!f.is_srgb() && !f.is_packed() && !f.is_compressed());
+
+        let f = mtl::PixelFormat::Depth32FloatStencil8;
+        assert!(f.is_depth() && f.is_stencil());
+        assert_eq!(f.component_count(), 2);
+        assert!(mtl::PixelFormat::X24Stencil8.is_stencil());
+        assert!(!mtl::PixelFormat::X24Stencil8.is_depth());
+
+        let f = mtl::PixelFormat::Rgb10A2UInt;
+        assert!(f.is_packed());
+        assert_eq!(f.component_type(), Some(mtl::PixelComponentType::UInt));
+
+        let f = mtl::PixelFormat::Etc2Rgb8A1Srgb;
+        assert_eq!(f.compression(), Some(mtl::PixelCompression::Etc));
+        assert_eq!(f.to_linear(), Some(mtl::PixelFormat::Etc2Rgb8A1));
+        assert_eq!(f.bytes_per_block(), 8);
+
+        let f = mtl::PixelFormat::PvrtcRgba2Bpp;
+        assert_eq!(f.block_size(), (8, 4));
+        assert_eq!(f.bytes_per_row(64, 0), 64);
+        assert!(mtl::PixelFormat::Asrc12x12Hdr.is_astc());
+        assert!(mtl::PixelFormat::Bc6HRgbUFloat.is_bc());
+        assert_eq!(mtl::PixelFormat::Invalid.bytes_per_row(100, 0), 0);
+
+        let size = mtl::Size {
+            width: 64,
+            height: 32,
+            depth: 16,
+        };
+        let f = mtl::PixelFormat::R32Float;
+        assert_eq!(f.bytes_per_level(size, 0), 64 * 32 * 16 * 4);
+        assert_eq!(f.bytes_per_level(size, 2), 16 * 8 * 4 * 4);
+        assert_eq!(f.bytes_per_level(size, 100), 4);
+    }
+}
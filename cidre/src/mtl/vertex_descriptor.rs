@@ -213,6 +213,42 @@ pub enum VertexFormat {
     F16 = 53,
 }
 
+impl VertexFormat {
+    /// Size in bytes of the attribute data.
+    pub const fn size(self) -> usize {
+        use VertexFormat::*;
+        match self {
+            Invalid => 0,
+            U8 | I8 | U8Normalized | I8Normalized => 1,
+            U8x2 | I8x2 | U8x2Normalized | I8x2Normalized | U16 | I16 | U16Normalized
+            | I16Normalized | F16 => 2,
+            U8x3 | I8x3 | U8x3Normalized | I8x3Normalized => 3,
+            U8x4 | I8x4 | U8x4Normalized | I8x4Normalized | U8x4NormalizedBGRA | U16x2 | I16x2
+            | U16x2Normalized | I16x2Normalized | F16x2 | F32 | I32 | U32 | I1010102Normalized
+            | U1010102Normalized => 4,
+            U16x3 | I16x3 | U16x3Normalized | I16x3Normalized | F16x3 => 6,
+            U16x4 | I16x4 | U16x4Normalized | I16x4Normalized | F16x4 | F32x2 | I32x2 | U32x2 => 8,
+            F32x3 | I32x3 | U32x3 => 12,
+            F32x4 | I32x4 | U32x4 => 16,
+        }
+    }
+
+    /// Alignment of the attribute data, the size of a component.
+    pub const fn align(self) -> usize {
+        use VertexFormat::*;
+        match self {
+            F32 | F32x2 | F32x3 | F32x4 | I32 | I32x2 | I32x3 | I32x4 | U32 | U32x2 | U32x3
+            | U32x4 | I1010102Normalized | U1010102Normalized => 4,
+            U16 | U16x2 | U16x3 | U16x4 | I16 | I16x2 | I16x3 | I16x4 | U16Normalized
+            | U16x2Normalized | U16x3Normalized | U16x4Normalized | I16Normalized
+            | I16x2Normalized | I16x3Normalized | I16x4Normalized | F16 | F16x2 | F16x3 | F16x4 => {
+                2
+            }
+            _ => 1,
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 #[repr(usize)]
 pub enum VertexStepFn {
@@ -0,0 +1,382 @@
+use crate::{arc, mtl, simd::Simd};
+
+/// Rust type that vertex functions can read with a [`mtl::VertexFormat`].
+pub trait Attr {
+    const FORMAT: mtl::VertexFormat;
+}
+
+/// Integer vertex data that vertex functions read as normalized floats.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Normalized<T>(pub T);
+
+macro_rules! impl_attr {
+    ($($t:ty => $f:ident),* $(,)?) => {
+        $(
+            impl Attr for $t {
+                const FORMAT: mtl::VertexFormat = mtl::VertexFormat::$f;
+            }
+        )*
+    };
+}
+
+macro_rules! impl_attrs {
+    ($t:ty: $x1:ident, $x2:ident, $x3:ident, $x4:ident) => {
+        impl_attr!(
+            $t => $x1,
+            [$t; 2] => $x2,
+            [$t; 3] => $x3,
+            [$t; 4] => $x4,
+            Simd<$t, 2, 2> => $x2,
+            Simd<$t, 4, 3> => $x3,
+            Simd<$t, 4, 4> => $x4,
+        );
+    };
+}
+
+macro_rules! impl_normalized_attrs {
+    ($t:ty: $x1:ident, $x2:ident, $x3:ident, $x4:ident) => {
+        impl_attr!(
+            Normalized<$t> => $x1,
+            Normalized<[$t; 2]> => $x2,
+            Normalized<[$t; 3]> => $x3,
+            Normalized<[$t; 4]> => $x4,
+            Normalized<Simd<$t, 2, 2>> => $x2,
+            Normalized<Simd<$t, 4, 3>> => $x3,
+            Normalized<Simd<$t, 4, 4>> => $x4,
+        );
+    };
+}
+
+impl_attrs!(u8: U8, U8x2, U8x3, U8x4);
+impl_attrs!(i8: I8, I8x2, I8x3, I8x4);
+impl_attrs!(u16: U16, U16x2, U16x3, U16x4);
+impl_attrs!(i16: I16, I16x2, I16x3, I16x4);
+impl_attrs!(u32: U32, U32x2, U32x3, U32x4);
+impl_attrs!(i32: I32, I32x2, I32x3, I32x4);
+impl_attrs!(f32: F32, F32x2, F32x3, F32x4);
+#[cfg(feature = "half")]
+impl_attrs!(half::f16: F16, F16x2, F16x3, F16x4);
+
+impl_normalized_attrs!(u8: U8Normalized, U8x2Normalized, U8x3Normalized, U8x4Normalized);
+impl_normalized_attrs!(i8: I8Normalized, I8x2Normalized, I8x3Normalized, I8x4Normalized);
+impl_normalized_attrs!(u16: U16Normalized, U16x2Normalized, U16x3Normalized, U16x4Normalized);
+impl_normalized_attrs!(i16: I16Normalized, I16x2Normalized, I16x3Normalized, I16x4Normalized);
+
+#[cfg(target_arch = "aarch64")]
+impl_attr!(crate::simd::f32x2 => F32x2, crate::simd::f32x4 => F32x4);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AttrLayout {
+    pub index: usize,
+    pub format: mtl::VertexFormat,
+    pub offset: usize,
+    pub buf_index: usize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BufLayout {
+    pub index: usize,
+    pub stride: usize,
+    pub step_fn: mtl::VertexStepFn,
+    pub step_rate: usize,
+}
+
+/// Plain description of a [`mtl::VertexDesc`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Layout {
+    pub attrs: Vec<AttrLayout>,
+    pub bufs: Vec<BufLayout>,
+}
+
+impl Layout {
+    #[inline]
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+
+    #[inline]
+    pub fn attr(&self, index: usize) -> Option<&AttrLayout> {
+        self.attrs.iter().find(|a| a.index == index)
+    }
+
+    #[inline]
+    pub fn buf(&self, index: usize) -> Option<&BufLayout> {
+        self.bufs.iter().find(|b| b.index == index)
+    }
+
+    pub fn apply(&self, desc: &mut mtl::VertexDesc) {
+        let attrs = desc.attrs();
+        for a in &self.attrs {
+            let mut attr = attrs.get(a.index);
+            attr.set_format(a.format);
+            attr.set_offset(a.offset);
+            attr.set_buf_index(a.buf_index);
+        }
+        let layouts = desc.layouts();
+        for b in &self.bufs {
+            let mut layout = layouts.get(b.index);
+            layout.set_stride(b.stride);
+            layout.set_step_fn(b.step_fn);
+            layout.set_step_rate(b.step_rate);
+        }
+    }
+
+    pub fn to_desc(&self) -> arc::R<mtl::VertexDesc> {
+        let mut desc = mtl::VertexDesc::new();
+        self.apply(&mut desc);
+        desc
+    }
+}
+
+/// Builds [`Layout`] with attribute offsets and strides of `#[repr(C)]` vertex structs.
+///
+/// Each [`Builder::buf`] starts a buffer and following attributes are placed one after
+/// another like struct fields, attributes before the first `buf` go to buffer 0.
+/// Computed strides are rounded up to a multiple of 4 bytes as Metal requires.
+#[derive(Debug, Clone, Default)]
+pub struct Builder {
+    layout: Layout,
+    /// end of the last field in the current buffer
+    end: usize,
+    align: usize,
+    explicit_stride: bool,
+}
+
+impl Builder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    #[inline]
+    pub fn finish(self) -> Layout {
+        self.layout
+    }
+
+    pub fn buf(&mut self, index: usize) -> &mut Self {
+        self.layout.bufs.push(BufLayout {
+            index,
+            stride: 0,
+            step_fn: mtl::VertexStepFn::PerVertex,
+            step_rate: 1,
+        });
+        self.end = 0;
+        self.align = 1;
+        self.explicit_stride = false;
+        self
+    }
+
+    fn current_buf(&mut self) -> &mut BufLayout {
+        if self.layout.bufs.is_empty() {
+            self.buf(0);
+        }
+        self.layout.bufs.last_mut().unwrap()
+    }
+
+    fn field(&mut self, offset: usize, size: usize, align: usize) {
+        self.current_buf();
+        self.end = self.end.max(offset + size);
+        self.align = self.align.max(align);
+        if !self.explicit_stride {
+            let stride = self.end.next_multiple_of(self.align.max(4));
+            self.current_buf().stride = stride;
+        }
+    }
+
+    fn push(&mut self, index: usize, format: mtl::VertexFormat, offset: usize) {
+        let buf_index = self.current_buf().index;
+        self.layout.attrs.push(AttrLayout {
+            index,
+            format,
+            offset,
+            buf_index,
+        });
+    }
+
+    /// Attribute `index` placed after the previous field.
+    pub fn attr<T: Attr>(&mut self, index: usize) -> &mut Self {
+        let offset = self.end.next_multiple_of(align_of::<T>());
+        self.attr_at::<T>(index, offset)
+    }
+
+    /// Attribute `index` at `offset`, usually from `std::mem::offset_of!`.
+    pub fn attr_at<T: Attr>(&mut self, index: usize, offset: usize) -> &mut Self {
+        self.push(index, T::FORMAT, offset);
+        self.field(offset, size_of::<T>(), align_of::<T>());
+        self
+    }
+
+    /// Attribute with a format without Rust type, like [`mtl::VertexFormat::U1010102Normalized`].
+    pub fn attr_format(&mut self, index: usize, format: mtl::VertexFormat) -> &mut Self {
+        let offset = self.end.next_multiple_of(format.align());
+        self.push(index, format, offset);
+        self.field(offset, format.size(), format.align());
+        self
+    }
+
+    /// Field that isn't read by the vertex function.
+    pub fn skip<T>(&mut self) -> &mut Self {
+        let offset = self.end.next_multiple_of(align_of::<T>());
+        self.field(offset, size_of::<T>(), align_of::<T>());
+        self
+    }
+
+    /// Overrides computed stride of the current buffer.
+    pub fn stride(&mut self, val: usize) -> &mut Self {
+        self.current_buf().stride = val;
+        self.explicit_stride = true;
+        self
+    }
+
+    /// Step rate should be 0 for [`mtl::VertexStepFn::Constant`].
+    pub fn step(&mut self, step_fn: mtl::VertexStepFn, rate: usize) -> &mut Self {
+        let buf = self.current_buf();
+        buf.step_fn = step_fn;
+        buf.step_rate = rate;
+        self
+    }
+
+    #[inline]
+    pub fn per_instance(&mut self, rate: usize) -> &mut Self {
+        self.step(mtl::VertexStepFn::PerInstance, rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{mtl, simd};
+
+    #[repr(C)]
+    struct Vertex {
+        pos: simd::f32x3,
+        uv: [f32; 2],
+        color: mtl::VertexNormalized<simd::u8x4>,
+        flags: u16,
+    }
+
+    #[test]
+    fn formats() {
+        use mtl::{VertexAttrType, VertexFormat as F, VertexNormalized as N};
+
+        assert_eq!(simd::f32x2::FORMAT, F::F32x2);
+        assert_eq!(simd::f32x3::FORMAT, F::F32x3);
+        assert_eq!(simd::f32x4::FORMAT, F::F32x4);
+        assert_eq!(<[f32; 3]>::FORMAT, F::F32x3);
+        assert_eq!(simd::u8x4::FORMAT, F::U8x4);
+        assert_eq!(N::<simd::u8x4>::FORMAT, F::U8x4Normalized);
+        assert_eq!(N::<[i16; 2]>::FORMAT, F::I16x2Normalized);
+        assert_eq!(N::<u8>::FORMAT, F::U8Normalized);
+        assert_eq!(u32::FORMAT, F::U32);
+
+        assert_eq!(F::F32x3.size(), 12);
+        assert_eq!(F::F32x3.align(), 4);
+        assert_eq!(F::F16x3.size(), 6);
+        assert_eq!(F::U8x4NormalizedBGRA.size(), 4);
+        assert_eq!(F::U1010102Normalized.align(), 4);
+    }
+
+    #[test]
+    fn offsets() {
+        let mut b = mtl::VertexLayout::builder();
+        b.attr::<simd::f32x3>(0)
+            .attr::<[f32; 2]>(1)
+            .attr::<mtl::VertexNormalized<simd::u8x4>>(2)
+            .attr::<u16>(3);
+        let layout = b.finish();
+
+        let offsets: Vec<_> = layout.attrs.iter().map(|a| a.offset).collect();
+        assert_eq!(
+            offsets,
+            [
+                std::mem::offset_of!(Vertex, pos),
+                std::mem::offset_of!(Vertex, uv),
+                std::mem::offset_of!(Vertex, color),
+                std::mem::offset_of!(Vertex, flags),
+            ]
+        );
+        let buf = layout.buf(0).unwrap();
+        assert_eq!(buf.stride, size_of::<Vertex>());
+        assert_eq!(buf.step_fn, mtl::VertexStepFn::PerVertex);
+        assert_eq!(buf.step_rate, 1);
+        assert!(layout.attrs.iter().all(|a| a.buf_index == 0));
+
+        let mut b = mtl::VertexLayout::builder();
+        b.attr::<u8>(0)
+            .skip::<u8>()
+            .attr_format(1, mtl::VertexFormat::U1010102Normalized)
+            .attr::<u16>(2);
+        let layout = b.finish();
+        assert_eq!(layout.attr(1).unwrap().offset, 4);
+        assert_eq!(layout.attr(2).unwrap().offset, 8);
+        assert_eq!(layout.buf(0).unwrap().stride, 12);
+
+        // padded to 4 bytes, unlike `size_of` of the same structs
+        let mut b = mtl::VertexLayout::builder();
+        b.attr::<u16>(0)
+            .buf(1)
+            .attr::<mtl::VertexNormalized<[u8; 3]>>(1)
+            .buf(2)
+            .attr::<[u16; 3]>(2)
+            .buf(3)
+            .attr::<u8>(3)
+            .stride(2);
+        let layout = b.finish();
+        let strides: Vec<_> = layout.bufs.iter().map(|b| b.stride).collect();
+        assert_eq!(strides, [4, 4, 8, 2]);
+    }
+
+    #[test]
+    fn bufs() {
+        let mut b = mtl::VertexLayout::builder();
+        b.buf(0)
+            .attr::<simd::f32x2>(0)
+            .buf(2)
+            .per_instance(1)
+            .attr::<simd::f32x4>(1)
+            .attr::<simd::f32x4>(2)
+            .buf(3)
+            .step(mtl::VertexStepFn::Constant, 0)
+            .attr_at::<f32>(3, 8)
+            .stride(64);
+        let layout = b.finish();
+
+        assert_eq!(layout.bufs.len(), 3);
+        assert_eq!(layout.buf(0).unwrap().stride, 8);
+        let inst = layout.buf(2).unwrap();
+        assert_eq!(inst.stride, 32);
+        assert_eq!(inst.step_fn, mtl::VertexStepFn::PerInstance);
+        assert_eq!(layout.attr(2).unwrap().offset, 16);
+        assert_eq!(layout.attr(2).unwrap().buf_index, 2);
+        let c = layout.buf(3).unwrap();
+        assert_eq!((c.stride, c.step_rate), (64, 0));
+        assert_eq!(layout.attr(3).unwrap().offset, 8);
+        assert_eq!(layout.attr(3).unwrap().buf_index, 3);
+    }
+
+    #[test]
+    fn apply() {
+        let mut b = mtl::VertexLayout::builder();
+        b.attr::<simd::f32x3>(0)
+            .attr::<[f32; 2]>(1)
+            .buf(1)
+            .per_instance(2)
+            .attr::<mtl::VertexNormalized<simd::u8x4>>(2);
+        let desc = b.finish().to_desc();
+
+        let attr = desc.attrs().get(1);
+        assert_eq!(attr.format(), mtl::VertexFormat::F32x2);
+        assert_eq!(attr.offset(), 16);
+        assert_eq!(attr.buf_index(), 0);
+        assert_eq!(desc.attrs().get(2).buf_index(), 1);
+        let layout = desc.layouts().get(1);
+        assert_eq!(layout.stride(), 4);
+        assert_eq!(layout.step_fn(), mtl::VertexStepFn::PerInstance);
+        assert_eq!(layout.step_rate(), 2);
+    }
+}
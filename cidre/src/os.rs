@@ -46,6 +46,18 @@ impl Error {
     pub const fn status(self) -> Status {
         Status(self.0.get())
     }
+
+    /// Catalog entries of the code, more than one if frameworks share it.
+    #[inline]
+    pub fn catalog(&self) -> &'static [catalog::Entry] {
+        catalog::lookup(self.0.get())
+    }
+
+    /// Symbolic name like `kVTParameterErr`.
+    #[inline]
+    pub fn name(&self) -> Option<&'static str> {
+        self.catalog().first().map(|e| e.name)
+    }
 }
 
 impl From<Error> for Status {
@@ -54,33 +66,52 @@ impl From<Error> for Status {
     }
 }
 
+fn debug_code(name: &str, val: i32, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let mut fcc = val.to_be_bytes();
+    let mut s = f.debug_struct(name);
+    s.field("raw", &val);
+    match catalog::lookup(val) {
+        [] => {}
+        [entry] => {
+            s.field("name", &entry.name);
+        }
+        entries => {
+            let names: Vec<_> = entries.iter().map(|e| e.name).collect();
+            s.field("names", &names);
+        }
+    }
+    s.field("fcc", &four_cc_to_str(&mut fcc))
+        .field("help", &format!("https://www.osstatus.com?search={}", val))
+        .finish()
+}
+
 impl std::fmt::Debug for Status {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let val = self.0;
-        let mut fcc = val.to_be_bytes();
-        f.debug_struct("os::Status")
-            .field("raw", &val)
-            .field("fcc", &four_cc_to_str(&mut fcc))
-            .field("help", &format!("https://www.osstatus.com?search={}", val))
-            .finish()
+        debug_code("os::Status", self.0, f)
     }
 }
 
 impl std::fmt::Debug for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let val = self.0.get();
-        let mut fcc = val.to_be_bytes();
-        f.debug_struct("os::Error")
-            .field("raw", &val)
-            .field("fcc", &four_cc_to_str(&mut fcc))
-            .field("help", &format!("https://www.osstatus.com?search={}", val))
-            .finish()
+        debug_code("os::Error", self.0.get(), f)
     }
 }
 
+/// `errSecTimestampWaiting (Security, -67896): A timestamp transaction is waiting`, all
+/// entries if frameworks share the code and `Debug` output for unknown codes.
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Debug::fmt(&self, f)
+        let entries = self.catalog();
+        if entries.is_empty() {
+            return std::fmt::Debug::fmt(&self, f);
+        }
+        for (i, entry) in entries.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" | ")?;
+            }
+            entry.fmt(f)?;
+        }
+        Ok(())
     }
 }
 
@@ -111,6 +142,8 @@ where
 
 pub type Type = FourCharCode;
 
+pub mod catalog;
+
 pub mod lock;
 
 impl PartialEq<i32> for Status {
@@ -128,6 +161,12 @@ impl PartialEq<Status> for Error {
 impl Status {
     pub const NO_ERR: Self = Self(0);
 
+    /// Symbolic name like `kVTParameterErr`.
+    #[inline]
+    pub fn name(&self) -> Option<&'static str> {
+        catalog::lookup(self.0).first().map(|e| e.name)
+    }
+
     #[inline]
     pub fn is_ok(&self) -> bool {
         *self == Self::NO_ERR
@@ -0,0 +1,409 @@
+//! Error codes defined across cidre with symbolic names, owning frameworks and descriptions.
+//!
+//! The table is pure data, so logs collected on other platforms can be decoded too.
+//! It is generated from `os::Error` constants of the source tree, run
+//! `CIDRE_UPDATE_CATALOG=1 cargo test os::catalog` after adding new ones.
+
+use crate::os;
+
+mod entries;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Entry {
+    pub code: i32,
+
+    /// Name in Apple headers like `kVTParameterErr`, or the Rust path if unknown.
+    pub name: &'static str,
+    pub framework: &'static str,
+
+    /// Rust constant, like `vt::errors::PARAMETER`.
+    pub path: &'static str,
+    pub desc: &'static str,
+}
+
+impl Entry {
+    #[inline]
+    const fn new(
+        code: i32,
+        name: &'static str,
+        framework: &'static str,
+        path: &'static str,
+        desc: &'static str,
+    ) -> Self {
+        Self {
+            code,
+            name,
+            framework,
+            path,
+            desc,
+        }
+    }
+
+    #[inline]
+    pub const fn error(&self) -> os::Error {
+        os::Error::new_unchecked(self.code)
+    }
+}
+
+impl std::fmt::Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}, {})", self.name, self.framework, self.code)?;
+        if !self.desc.is_empty() {
+            write!(f, ": {}", self.desc)?;
+        }
+        Ok(())
+    }
+}
+
+/// All entries sorted by code.
+#[inline]
+pub fn entries() -> &'static [Entry] {
+    entries::ENTRIES
+}
+
+/// Entries with the code, frameworks share some codes like `-50`.
+pub fn lookup(code: i32) -> &'static [Entry] {
+    let all = entries();
+    let start = all.partition_point(|e| e.code < code);
+    let end = start + all[start..].partition_point(|e| e.code == code);
+    &all[start..end]
+}
+
+/// Entry of the code in the framework.
+pub fn lookup_in(code: i32, framework: &str) -> Option<&'static Entry> {
+    lookup(code).iter().find(|e| e.framework == framework)
+}
+
+/// Entry by symbolic name or Rust path.
+pub fn find(name: &str) -> Option<&'static Entry> {
+    entries().iter().find(|e| e.name == name || e.path == name)
+}
+
+/// Case insensitive search in names, paths, frameworks and descriptions.
+pub fn search(query: &str) -> impl Iterator<Item = &'static Entry> + '_ {
+    let query = query.to_lowercase();
+    entries().iter().filter(move |e| {
+        [e.name, e.path, e.framework, e.desc]
+            .iter()
+            .any(|s| s.to_lowercase().contains(&query))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use crate::os::{self, catalog};
+
+    #[test]
+    fn lookup() {
+        let entries = catalog::entries();
+        assert!(entries.windows(2).all(|w| w[0].code <= w[1].code));
+
+        let entry = catalog::find("kVTParameterErr").unwrap();
+        assert_eq!(entry.code, -12902);
+        assert_eq!(entry.framework, "VideoToolbox");
+        assert_eq!(entry.path, "vt::errors::PARAMETER");
+        assert_eq!(catalog::find("vt::errors::PARAMETER"), Some(entry));
+
+        let entry = catalog::lookup_in(i32::from_be_bytes(*b"wht?"), "AudioToolbox").unwrap();
+        assert_eq!(entry.name, "kAudioFileUnspecifiedError");
+        assert_eq!(entry.path, "at::audio::file_err::UNSPECIFIED");
+
+        assert!(catalog::lookup(-50).len() > 1);
+        assert!(catalog::lookup(0).is_empty());
+        assert!(catalog::search("ROSETTA").count() >= 2);
+    }
+
+    #[test]
+    fn display() {
+        let err = os::Error::new_unchecked(-12902);
+        assert_eq!(err.name(), Some("kVTParameterErr"));
+        assert_eq!(err.to_string(), "kVTParameterErr (VideoToolbox, -12902)");
+        assert_eq!(err.status().name(), Some("kVTParameterErr"));
+        let debug = format!("{err:?}");
+        assert_eq!(debug.matches("name").count(), 1);
+        assert!(debug.contains("name: \"kVTParameterErr\""));
+
+        let debug = format!("{:?}", os::Error::new_unchecked(-50));
+        assert!(!debug.contains("name:"));
+        assert!(debug.contains("names: [\""));
+
+        let err = os::Error::new_unchecked(-67902);
+        assert_eq!(
+            err.to_string(),
+            "errSecCertificateIsCA (Security, -67902): \
+             The verified certificate is a CA rather than an end-entity"
+        );
+
+        let err = os::Error::new_unchecked(-1_234_567);
+        assert_eq!(err.name(), None);
+        assert!(err.to_string().contains("-1234567"));
+    }
+
+    /// Regenerates `catalog/entries.rs` with `CIDRE_UPDATE_CATALOG=1`, checks it otherwise.
+    #[test]
+    fn entries_are_fresh() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/os/catalog/entries.rs");
+        let generated = generate();
+        if std::env::var_os("CIDRE_UPDATE_CATALOG").is_some() {
+            fs::write(&path, generated).unwrap();
+        } else {
+            let current = fs::read_to_string(&path).unwrap();
+            assert!(
+                current == generated,
+                "os catalog is stale, run `CIDRE_UPDATE_CATALOG=1 cargo test os::catalog`"
+            );
+        }
+    }
+
+    /// Framework of module file, `None` for modules without error codes.
+    fn framework(module: &str) -> Option<&'static str> {
+        const FRAMEWORKS: &[(&str, &str)] = &[
+            ("at/", "AudioToolbox"),
+            ("ax/", "ApplicationServices"),
+            ("cat/", "CoreAudioTypes"),
+            ("cg/image/animation", "ImageIO"),
+            ("cm/io/", "CoreMediaIO"),
+            ("cm/", "CoreMedia"),
+            ("core_audio/", "CoreAudio"),
+            ("cv/", "CoreVideo"),
+            ("mach/", "Mach"),
+            ("sec/", "Security"),
+            ("vt/", "VideoToolbox"),
+        ];
+        FRAMEWORKS
+            .iter()
+            .find(|(prefix, _)| module.starts_with(prefix))
+            .map(|(_, name)| *name)
+    }
+
+    struct Parsed {
+        code: i32,
+        name: String,
+        framework: &'static str,
+        path: String,
+        desc: String,
+    }
+
+    fn rs_files(dir: &Path, res: &mut Vec<PathBuf>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                rs_files(&path, res);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                res.push(path);
+            }
+        }
+    }
+
+    fn generate() -> String {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut files = Vec::new();
+        rs_files(&src, &mut files);
+        files.sort();
+
+        let mut entries = Vec::new();
+        for file in files {
+            let module = file.strip_prefix(&src).unwrap().with_extension("");
+            let module = module.to_str().unwrap().replace('\\', "/");
+            let text = fs::read_to_string(&file).unwrap();
+            if module.starts_with("os") || !text.contains("os::Error") {
+                continue;
+            }
+            let found = parse_file(&src, &module, &text);
+            if found.is_empty() {
+                continue;
+            }
+            let framework =
+                framework(&module).unwrap_or_else(|| panic!("no framework for {module}"));
+            entries.extend(found.into_iter().map(|(code, name, path, desc)| Parsed {
+                code,
+                name,
+                framework,
+                path,
+                desc,
+            }));
+        }
+        entries.sort_by(|a, b| (a.code, a.framework, &a.path).cmp(&(b.code, b.framework, &b.path)));
+        entries.dedup_by(|a, b| a.code == b.code && a.name == b.name);
+
+        let mut res = String::from(
+            "//! Generated by `os::catalog::tests::entries_are_fresh`, do not edit.\n\n\
+             use super::Entry;\n\n\
+             #[rustfmt::skip]\n\
+             pub(super) static ENTRIES: &[Entry] = &[\n",
+        );
+        for e in entries {
+            res.push_str(&format!(
+                "    Entry::new({}, {:?}, {:?}, {:?}, {:?}),\n",
+                e.code, e.name, e.framework, e.path, e.desc
+            ));
+        }
+        res.push_str("];\n");
+        res
+    }
+
+    /// Code, symbolic name, path and description of each `os::Error` constant.
+    fn parse_file(src: &Path, module: &str, text: &str) -> Vec<(i32, String, String, String)> {
+        let file_mods: Vec<&str> = module.split('/').collect();
+        let mut res = Vec::new();
+        let mut doc = Vec::new();
+        let mut alias = None;
+        let mut mods: Vec<(String, i32)> = Vec::new();
+        let mut depth = 0i32;
+        let mut local: Vec<(Vec<String>, String, i32)> = Vec::new();
+
+        for line in text.lines() {
+            let line = line.trim();
+            if let Some(d) = line.strip_prefix("///") {
+                doc.push(d.trim().to_string());
+                continue;
+            }
+            if let Some(a) = line
+                .strip_prefix("#[doc(alias = \"")
+                .and_then(|a| a.strip_suffix("\")]"))
+            {
+                alias.get_or_insert_with(|| a.to_string());
+                continue;
+            }
+            if line.starts_with("#[") || line.starts_with("//") {
+                continue;
+            }
+            if let Some(rest) = line
+                .strip_prefix("pub mod ")
+                .or_else(|| line.strip_prefix("mod "))
+                && let Some(name) = rest.strip_suffix(" {")
+            {
+                mods.push((name.to_string(), depth));
+            }
+            let inline: Vec<String> = mods.iter().map(|(n, _)| n.clone()).collect();
+            let code_line = line.split(" //").next().unwrap_or(line);
+            if let Some(rest) = code_line.strip_prefix("pub const ")
+                && let Some((name, rest)) = rest.split_once(": ")
+                && let Some(code) = const_code(rest, &inline, &local)
+            {
+                local.push((inline.clone(), name.to_string(), code));
+                let path = public_path(src, &file_mods, &inline, name);
+                let desc = description(&doc);
+                res.push((
+                    code,
+                    alias.take().unwrap_or_else(|| path.clone()),
+                    path,
+                    desc,
+                ));
+            }
+            depth += line.matches('{').count() as i32 - line.matches('}').count() as i32;
+            while mods.last().is_some_and(|(_, d)| depth <= *d) {
+                mods.pop();
+            }
+            doc.clear();
+            alias = None;
+        }
+        res
+    }
+
+    /// Code of `Error = Error::new_unchecked(-1);` like constant value.
+    fn const_code(
+        decl: &str,
+        inline: &[String],
+        local: &[(Vec<String>, String, i32)],
+    ) -> Option<i32> {
+        let value = decl
+            .strip_prefix("Error = ")
+            .or_else(|| decl.strip_prefix("os::Error = "))?
+            .strip_suffix(';')?;
+        let value = value.strip_prefix("os::").unwrap_or(value);
+        if let Some(fcc) = value
+            .strip_prefix("Error::from_be_bytes(*b\"")
+            .and_then(|v| v.strip_suffix("\")"))
+        {
+            return Some(i32::from_be_bytes(fcc.as_bytes().try_into().ok()?));
+        }
+        if let Some(fcc) = value
+            .strip_prefix("Error::new_unchecked(i32::from_be_bytes(*b\"")
+            .and_then(|v| v.strip_suffix("\"))"))
+        {
+            return Some(i32::from_be_bytes(fcc.as_bytes().try_into().ok()?));
+        }
+        if let Some(num) = value
+            .strip_prefix("Error::new_unchecked(")
+            .and_then(|v| v.strip_suffix(')'))
+        {
+            let num = num.replace('_', "");
+            return if let Some(hex) = num.strip_prefix("0x") {
+                u32::from_str_radix(hex, 16).ok().map(|v| v as i32)
+            } else {
+                num.parse().ok()
+            };
+        }
+        local
+            .iter()
+            .find(|(mods, name, _)| mods == inline && name == value)
+            .map(|(_, _, code)| *code)
+    }
+
+    /// Path through `pub use module::*;` and `pub use module::err as alias;` re-exports
+    /// of the parent module.
+    fn public_path(src: &Path, file_mods: &[&str], inline: &[String], name: &str) -> String {
+        let mut segments: Vec<String> = file_mods.iter().map(|s| s.to_string()).collect();
+        let mut inline = inline.to_vec();
+        if let Some((last, parent)) = file_mods.split_last() {
+            let parent_file = if parent.is_empty() {
+                src.join("lib.rs")
+            } else {
+                src.join(parent.join("/")).with_extension("rs")
+            };
+            let parent_text = fs::read_to_string(parent_file).unwrap_or_default();
+            let glob = format!("pub use {last}::*;");
+            let item = inline
+                .first()
+                .map(|first| format!("pub use {last}::{first}"));
+            for line in parent_text.lines().map(str::trim) {
+                let rest = item
+                    .as_ref()
+                    .and_then(|item| line.strip_prefix(item.as_str()));
+                if let Some(alias) = rest
+                    .and_then(|r| r.strip_prefix(" as "))
+                    .and_then(|r| r.strip_suffix(';'))
+                {
+                    segments.pop();
+                    inline[0] = alias.to_string();
+                    break;
+                }
+                if rest == Some(";") || line == glob {
+                    segments.pop();
+                    break;
+                }
+            }
+        }
+        segments.extend(inline);
+        segments.push(name.to_string());
+        segments.join("::")
+    }
+
+    /// First sentence of doc comment, empty for undocumented constants.
+    fn description(doc: &[String]) -> String {
+        let is_code = |l: &str| {
+            l.starts_with("0x")
+                || l.starts_with('\'')
+                || l.chars()
+                    .all(|c| c.is_ascii_digit() || c == '-' || c == ',' || c == ' ')
+        };
+        let text = doc
+            .iter()
+            .filter(|l| !is_code(l))
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let text = text.trim();
+        let first = match text.find(". ") {
+            Some(end) => &text[..end],
+            None => text,
+        };
+        first.trim_end_matches('.').to_string()
+    }
+}
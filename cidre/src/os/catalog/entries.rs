@@ -0,0 +1,847 @@
+//! Generated by `os::catalog::tests::entries_are_fresh`, do not edit.
+
+use super::Entry;
+
+#[rustfmt::skip]
+pub(super) static ENTRIES: &[Entry] = &[
+    Entry::new(-67903, "errSecCertificateDuplicateExtension", "Security", "sec::err::CERT_DUPLICATE_EXTENSION", "The certificate contains multiple extensions with the same extension ID"),
+    Entry::new(-67902, "errSecCertificateIsCA", "Security", "sec::err::CERT_IS_CA", "The verified certificate is a CA rather than an end-entity"),
+    Entry::new(-67901, "errSecCertificateValidityPeriodTooLong", "Security", "sec::err::CERT_VALIDITY_PERIOD_TOO_LONG", "The validity period in the certificate exceeds the maximum allowed"),
+    Entry::new(-67900, "errSecCertificateNameNotAllowed", "Security", "sec::err::CERT_NAME_NOT_ALLOWED", "The requested name is not allowed for this certificate"),
+    Entry::new(-67899, "errSecCertificatePolicyNotAllowed", "Security", "sec::err::CERT_POLICY_NOT_ALLOWED", "The requested policy is not allowed for this certificate"),
+    Entry::new(-67898, "errSecTimestampRevocationNotification", "Security", "sec::err::TIMESTAMP_REVOCATION_NOTIFICATION", "A timestamp authority revocation notification was issued"),
+    Entry::new(-67897, "errSecTimestampRevocationWarning", "Security", "sec::err::TIMESTAMP_REVOCATION_WARNING", "A timestamp authority revocation warning was issued"),
+    Entry::new(-67896, "errSecTimestampWaiting", "Security", "sec::err::TIMESTAMP_WAITING", "A timestamp transaction is waiting"),
+    Entry::new(-67895, "errSecTimestampRejection", "Security", "sec::err::TIMESTAMP_REJECTION", "A timestamp transaction was rejected"),
+    Entry::new(-67894, "errSecSigningTimeMissing", "Security", "sec::err::SIGNING_TIME_MISSING", "A signing time was expected but was not found"),
+    Entry::new(-67893, "errSecTimestampSystemFailure", "Security", "sec::err::TIMESTAMP_SYSTEM_FAILURE", "The timestamp request cannot be handled due to system failure"),
+    Entry::new(-67892, "errSecTimestampAddInfoNotAvailable", "Security", "sec::err::TIMESTAMP_ADD_INFO_NOT_AVAILABLE", "The additional information requested is not available"),
+    Entry::new(-67891, "errSecTimestampUnacceptedExtension", "Security", "sec::err::TIMESTAMP_UNACCEPTED_EXTENSION", "The requested extension is not supported by the Timestamp Authority"),
+    Entry::new(-67890, "errSecTimestampUnacceptedPolicy", "Security", "sec::err::TIMESTAMP_UNACCEPTED_POLICY", "The requested policy is not supported by the Timestamp Authority"),
+    Entry::new(-67889, "errSecTimestampTimeNotAvailable", "Security", "sec::err::TIMESTAMP_TIME_NOT_AVAILABLE", "The time source for the Timestamp Authority is not available"),
+    Entry::new(-67888, "errSecTimestampBadDataFormat", "Security", "sec::err::TIMESTAMP_BAD_DATA_FORMAT", "The timestamp data submitted has the wrong format"),
+    Entry::new(-67887, "errSecTimestampBadRequest", "Security", "sec::err::TIMESTAMP_BAD_REQUEST", "The timestamp transaction is not permitted or supported"),
+    Entry::new(-67886, "errSecTimestampBadAlg", "Security", "sec::err::TIMESTAMP_BAD_ALG", "An unrecognized or unsupported Algorithm Identifier in timestamp"),
+    Entry::new(-67885, "errSecTimestampServiceNotAvailable", "Security", "sec::err::TIMESTAMP_SERVICE_NOT_AVAILABLE", "The timestamp service is not available"),
+    Entry::new(-67884, "errSecTimestampNotTrusted", "Security", "sec::err::TIMESTAMP_NOT_TRUSTED", "The timestamp was not trusted"),
+    Entry::new(-67883, "errSecTimestampInvalid", "Security", "sec::err::TIMESTAMP_INVALID", "The timestamp was not valid"),
+    Entry::new(-67882, "errSecTimestampMissing", "Security", "sec::err::TIMESTAMP_MISSING", "A timestamp was expected but was not found"),
+    Entry::new(-67881, "errSecExtendedKeyUsageNotCritical", "Security", "sec::err::EXTENDED_KEY_USAGE_NOT_CRITICAL", "The extended key usage extension was not marked critical"),
+    Entry::new(-67880, "errSecMissingRequiredExtension", "Security", "sec::err::MISSING_REQUIRED_EXTENSION", "A required certificate extension is missing"),
+    Entry::new(-67879, "errSecInvalidModifyMode", "Security", "sec::err::INVALID_MODIFY_MODE", "The modify mode is not valid"),
+    Entry::new(-67878, "errSecInvalidNewOwner", "Security", "sec::err::INVALID_NEW_OWNER", "The new owner is not valid"),
+    Entry::new(-67877, "errSecInvalidIndexInfo", "Security", "sec::err::INVALID_INDEX_INFO", "The index information is not valid"),
+    Entry::new(-67876, "errSecInvalidAccessRequest", "Security", "sec::err::INVALID_ACCESS_REQUEST", "The access request is not valid"),
+    Entry::new(-67875, "errSecInvalidDBLocation", "Security", "sec::err::INVALID_DB_LOCATION", "The database location is not valid"),
+    Entry::new(-67874, "errSecUnsupportedOperator", "Security", "sec::err::UNSUPPORTED_OPERATOR", "The operator is not supported"),
+    Entry::new(-67873, "errSecUnsupportedNumSelectionPreds", "Security", "sec::err::UNSUPPORTED_NUM_SELECTION_PREDS", "The number of selection predicates is not supported"),
+    Entry::new(-67872, "errSecUnsupportedQueryLimits", "Security", "sec::err::UNSUPPORTED_QUERY_LIMITS", "The query limits are not supported"),
+    Entry::new(-67871, "errSecMissingValue", "Security", "sec::err::MISSING_VALUE", "A missing value was detected"),
+    Entry::new(-67870, "errSecDatastoreIsOpen", "Security", "sec::err::DATASTORE_IS_OPEN", "The data store is open"),
+    Entry::new(-67869, "errSecDatabaseLocked", "Security", "sec::err::DATABASE_LOCKED", "The database is locked"),
+    Entry::new(-67868, "errSecInvalidParsingModule", "Security", "sec::err::INVALID_PARSING_MODULE", "The parsing module was not valid"),
+    Entry::new(-67867, "errSecIncompatibleFieldFormat", "Security", "sec::err::INCOMPATIBLE_FIELD_FORMAT", "The field format was incompatible"),
+    Entry::new(-67866, "errSecFieldSpecifiedMultiple", "Security", "sec::err::FIELD_SPECIFIED_MULTIPLE", "Too many fields were specified"),
+    Entry::new(-67865, "errSecUnsupportedNumRecordTypes", "Security", "sec::err::UNSUPPORTED_NUM_RECORD_TYPES", "The number of record types is not supported"),
+    Entry::new(-67864, "errSecUnsupportedNumIndexes", "Security", "sec::err::UNSUPPORTED_NUM_INDEXES", "The number of indexes is not supported"),
+    Entry::new(-67863, "errSecUnsupportedNumAttributes", "Security", "sec::err::UNSUPPORTED_NUM_ATTRIBUTES", "The number of attributes is not supported"),
+    Entry::new(-67862, "errSecUnsupportedLocality", "Security", "sec::err::UNSUPPORTED_LOCALITY", "The locality is not supported"),
+    Entry::new(-67861, "errSecUnsupportedIndexInfo", "Security", "sec::err::UNSUPPORTED_INDEX_INFO", "The index information is not supported"),
+    Entry::new(-67860, "errSecUnsupportedFieldFormat", "Security", "sec::err::UNSUPPORTED_FIELD_FORMAT", "The field format is not supported"),
+    Entry::new(-67859, "errSecNoFieldValues", "Security", "sec::err::NO_FIELD_VALUES", "No field values were detected"),
+    Entry::new(-67858, "errSecInvalidCRLIndex", "Security", "sec::err::INVALID_CRL_INDEX", "The CRL index was not valid"),
+    Entry::new(-67857, "errSecInvalidBundleInfo", "Security", "sec::err::INVALID_BUNDLE_INFO", "The bundle information was not valid"),
+    Entry::new(-67856, "errSecRequestDescriptor", "Security", "sec::err::REQUEST_DESCRIPTOR", "The request descriptor was not valid"),
+    Entry::new(-67855, "errSecInvalidRequestor", "Security", "sec::err::INVALID_REQUESTOR", "The requestor was not valid"),
+    Entry::new(-67854, "errSecInvalidValidityPeriod", "Security", "sec::err::INVALID_VALIDITY_PERIOD", "The validity period was not valid"),
+    Entry::new(-67853, "errSecInvalidEncoding", "Security", "sec::err::INVALID_ENCODING", "The encoding was not valid"),
+    Entry::new(-67852, "errSecInvalidTupleCredentials", "Security", "sec::err::INVALID_TUPLE_CREDENTIALS", "The tuple credentials are not valid"),
+    Entry::new(-67851, "errSecInvalidBaseACLs", "Security", "sec::err::INVALID_BASE_ACLS", "The base ACLs are not valid"),
+    Entry::new(-67850, "errSecInvalidTupleGroup", "Security", "sec::err::INVALID_TUPLE_GROUP", "The tuple group was not valid"),
+    Entry::new(-67849, "errSecUnsupportedService", "Security", "sec::err::UNSUPPORTED_SERVICE", "The service is not supported"),
+    Entry::new(-67848, "errSecUnsupportedAddressType", "Security", "sec::err::UNSUPPORTED_ADDRESS_TYPE", "The address type is not supported"),
+    Entry::new(-67847, "errSecRequestRejected", "Security", "sec::err::REQUEST_REJECTED", "The request was rejected"),
+    Entry::new(-67846, "errSecRequestLost", "Security", "sec::err::REQUEST_LOST", "The request was lost"),
+    Entry::new(-67845, "errSecRejectedForm", "Security", "sec::err::REJECTED_FORM", "The trust policy had a rejected form"),
+    Entry::new(-67844, "errSecNoDefaultAuthority", "Security", "sec::err::NO_DEFAULT_AUTHORITY", "No default authority was detected"),
+    Entry::new(-67843, "errSecNotTrusted", "Security", "sec::err::NOT_TRUSTED", "The certificate was not trusted"),
+    Entry::new(-67842, "errSecMultipleValuesUnsupported", "Security", "sec::err::MULTIPLE_VALUES_UNSUPPORTED", "Multiple values are not supported"),
+    Entry::new(-67841, "errSecInvalidTuple", "Security", "sec::err::INVALID_TUPLE", "The tuple was not valid"),
+    Entry::new(-67840, "errSecInvalidStopOnPolicy", "Security", "sec::err::INVALID_STOP_ON_POLICY", "The stop-on policy was not valid"),
+    Entry::new(-67839, "errSecInvalidResponseVector", "Security", "sec::err::INVALID_RESPONSE_VECTOR", "The response vector was not valid"),
+    Entry::new(-67838, "errSecInvalidRequestInputs", "Security", "sec::err::INVALID_REQUEST_INPUTS", "The request inputs are not valid"),
+    Entry::new(-67837, "errSecInvalidReason", "Security", "sec::err::INVALID_REASON", "The trust policy reason was not valid"),
+    Entry::new(-67836, "errSecInvalidTimeString", "Security", "sec::err::INVALID_TIME_STRING", "The time specified was not valid"),
+    Entry::new(-67835, "errSecInvalidPolicyIdentifiers", "Security", "sec::err::INVALID_POLICY_IDENTIFIERS", "The policy identifiers are not valid"),
+    Entry::new(-67834, "errSecInvalidIndex", "Security", "sec::err::INVALID_INDEX", "The index was not valid"),
+    Entry::new(-67833, "errSecInvalidIdentifier", "Security", "sec::err::INVALID_IDENTIFIER", "The identifier was not valid"),
+    Entry::new(-67832, "errSecInvalidID", "Security", "sec::err::INVALID_ID", "The ID was not valid"),
+    Entry::new(-67831, "errSecInvalidFormType", "Security", "sec::err::INVALID_FORM_TYPE", "The form type was not valid"),
+    Entry::new(-67830, "errSecInvalidCRL", "Security", "sec::err::INVALID_CRL", "The CRL was not valid"),
+    Entry::new(-67829, "errSecInvalidCRLType", "Security", "sec::err::INVALID_CRL_TYPE", "The CRL type was not valid"),
+    Entry::new(-67828, "errSecInvalidCRLEncoding", "Security", "sec::err::INVALID_CRL_ENCODING", "The CRL encoding was not valid"),
+    Entry::new(-67827, "errSecInvalidCRLAuthority", "Security", "sec::err::INVALID_CRL_AUTHORITY", "The CRL authority was not valid"),
+    Entry::new(-67826, "errSecInvalidCertAuthority", "Security", "sec::err::INVALID_CERT_AUTHORITY", "The certificate authority was not valid"),
+    Entry::new(-67825, "errSecVerifyActionFailed", "Security", "sec::err::VERIFY_ACTION_FAILED", "A verify action has failed"),
+    Entry::new(-67824, "errSecInvalidAuthority", "Security", "sec::err::INVALID_AUTHORITY", "The authority was not valid"),
+    Entry::new(-67823, "errSecInvalidAction", "Security", "sec::err::INVALID_ACTION", "The action was not valid"),
+    Entry::new(-67822, "errSecInsufficientCredentials", "Security", "sec::err::INSUFFICIENT_CREDENTIALS", "Insufficient credentials were detected"),
+    Entry::new(-67821, "errSecCertificateSuspended", "Security", "sec::err::CERT_SUSPENDED", "The certificate was suspended"),
+    Entry::new(-67820, "errSecCertificateRevoked", "Security", "sec::err::CERT_REVOKED", "The certificate was revoked"),
+    Entry::new(-67819, "errSecCertificateNotValidYet", "Security", "sec::err::CERT_NOT_VALID_YET", "The certificate is not yet valid"),
+    Entry::new(-67818, "errSecCertificateExpired", "Security", "sec::err::CERT_EXPIRED", "An expired certificate was detected"),
+    Entry::new(-67817, "errSecCertificateCannotOperate", "Security", "sec::err::CERT_CANNOT_OPERATE", "The certificate cannot operate"),
+    Entry::new(-67816, "errSecInvalidCRLGroup", "Security", "sec::err::INVALID_CRL_GROUP", "An invalid CRL group was detected"),
+    Entry::new(-67815, "errSecInvalidDigestAlgorithm", "Security", "sec::err::INVALID_DIGEST_ALGORITHM", "An invalid digest algorithm was detected"),
+    Entry::new(-67814, "errSecAlreadyLoggedIn", "Security", "sec::err::ALREADY_LOGGED_IN", "The user is already logged in"),
+    Entry::new(-67813, "errSecInvalidLoginName", "Security", "sec::err::INVALID_LOGIN_NAME", "An invalid login name was detected"),
+    Entry::new(-67812, "errSecDeviceVerifyFailed", "Security", "sec::err::DEVICE_VERIFY_FAILED", "A device verification failure has occurred"),
+    Entry::new(-67811, "errSecPublicKeyInconsistent", "Security", "sec::err::PUBLIC_KEY_INCONSISTENT", "The public key was inconsistent"),
+    Entry::new(-67810, "errSecBlockSizeMismatch", "Security", "sec::err::BLOCK_SIZE_MISMATCH", "A block size mismatch occurred"),
+    Entry::new(-67809, "errSecQuerySizeUnknown", "Security", "sec::err::QUERY_SIZE_UNKNOWN", "The query size is unknown"),
+    Entry::new(-67808, "errSecVerifyFailed", "Security", "sec::err::VERIFY_FAILED", "A cryptographic verification failure has occurred"),
+    Entry::new(-67807, "errSecStagedOperationNotStarted", "Security", "sec::err::STAGED_OPERATION_NOT_STARTED", "A staged operation was not started"),
+    Entry::new(-67806, "errSecStagedOperationInProgress", "Security", "sec::err::STAGED_OPERATION_IN_PROGRESS", "A staged operation is in progress"),
+    Entry::new(-67805, "errSecMissingAttributeWrappedKeyFormat", "Security", "sec::err::MISSING_ATTRIBUTE_WRAPPED_KEY_FORMAT", "A wrapped key format attribute was missing"),
+    Entry::new(-67804, "errSecInvalidAttributeWrappedKeyFormat", "Security", "sec::err::INVALID_ATTRIBUTE_WRAPPED_KEY_FORMAT", "A wrapped key format attribute was not valid"),
+    Entry::new(-67803, "errSecMissingAttributeSymmetricKeyFormat", "Security", "sec::err::MISSING_ATTRIBUTE_SYMMETRIC_KEY_FORMAT", "A symmetric key format attribute was missing"),
+    Entry::new(-67802, "errSecInvalidAttributeSymmetricKeyFormat", "Security", "sec::err::INVALID_ATTRIBUTE_SYMMETRIC_KEY_FORMAT", "A symmetric key format attribute was not valid"),
+    Entry::new(-67801, "errSecMissingAttributePrivateKeyFormat", "Security", "sec::err::MISSING_ATTRIBUTE_PRIVATE_KEY_FORMAT", "A private key format attribute was missing"),
+    Entry::new(-67800, "errSecInvalidAttributePrivateKeyFormat", "Security", "sec::err::INVALID_ATTRIBUTE_PRIVATE_KEY_FORMAT", "A private key format attribute was not valid"),
+    Entry::new(-67799, "errSecMissingAttributePublicKeyFormat", "Security", "sec::err::MISSING_ATTRIBUTE_PUBLIC_KEY_FORMAT", "A public key format attribute was missing"),
+    Entry::new(-67798, "errSecInvalidAttributePublicKeyFormat", "Security", "sec::err::INVALID_ATTRIBUTE_PUBLIC_KEY_FORMAT", "A public key format attribute was not valid"),
+    Entry::new(-67797, "errSecMissingAttributeAccessCredentials", "Security", "sec::err::MISSING_ATTRIBUTE_ACCESS_CREDENTIALS", "An access credentials attribute was missing"),
+    Entry::new(-67796, "errSecInvalidAttributeAccessCredentials", "Security", "sec::err::INVALID_ATTRIBUTE_ACCESS_CREDENTIALS", "An access credentials attribute was not valid"),
+    Entry::new(-67795, "errSecMissingAttributeDLDBHandle", "Security", "sec::err::MISSING_ATTRIBUTE_DLDBHANDLE", "A database handle attribute was missing"),
+    Entry::new(-67794, "errSecInvalidAttributeDLDBHandle", "Security", "sec::err::INVALID_ATTRIBUTE_DLDB_HANDLE", "A database handle attribute was not valid"),
+    Entry::new(-67793, "errSecMissingAttributeIterationCount", "Security", "sec::err::MISSING_ATTRIBUTE_ITERATION_COUNT", "An iteration count attribute was missing"),
+    Entry::new(-67792, "errSecInvalidAttributeIterationCount", "Security", "sec::err::INVALID_ATTRIBUTE_ITERATION_COUNT", "An iteration count attribute was not valid"),
+    Entry::new(-67791, "errSecMissingAttributeSubprime", "Security", "sec::err::MISSING_ATTRIBUTE_SUBPRIME", "A subprime attribute was missing"),
+    Entry::new(-67790, "errSecInvalidAttributeSubprime", "Security", "sec::err::INVALID_ATTRIBUTE_SUBPRIME", "A subprime attribute was not valid"),
+    Entry::new(-67789, "errSecMissingAttributeBase", "Security", "sec::err::MISSING_ATTRIBUTE_BASE", "A base attribute was missing"),
+    Entry::new(-67788, "errSecInvalidAttributeBase", "Security", "sec::err::INVALID_ATTRIBUTE_BASE", "A base attribute was not valid"),
+    Entry::new(-67787, "errSecMissingAttributePrime", "Security", "sec::err::MISSING_ATTRIBUTE_PRIME", "A prime attribute was missing"),
+    Entry::new(-67786, "errSecInvalidAttributePrime", "Security", "sec::err::INVALID_ATTRIBUTE_PRIME", "A prime attribute was not valid"),
+    Entry::new(-67785, "errSecMissingAttributeVersion", "Security", "sec::err::MISSING_ATTRIBUTE_VERSION", "A version attribute was missing"),
+    Entry::new(-67784, "errSecInvalidAttributeVersion", "Security", "sec::err::INVALID_ATTRIBUTE_VERSION", "A version attribute was not valid"),
+    Entry::new(-67783, "errSecMissingAttributeEndDate", "Security", "sec::err::MISSING_ATTRIBUTE_END_DATE", "An end date attribute was missing"),
+    Entry::new(-67782, "errSecInvalidAttributeEndDate", "Security", "sec::err::INVALID_ATTRIBUTE_END_DATE", "An end date attribute was not valid"),
+    Entry::new(-67781, "errSecMissingAttributeStartDate", "Security", "sec::err::MISSING_ATTRIBUTE_START_DATE", "A start date attribute was missing"),
+    Entry::new(-67780, "errSecInvalidAttributeStartDate", "Security", "sec::err::INVALID_ATTRIBUTE_START_DATE", "A start date attribute was not valid"),
+    Entry::new(-67779, "errSecMissingAttributeEffectiveBits", "Security", "sec::err::MISSING_ATTRIBUTE_EFFECTIVE_BITS", "An effective bits attribute was missing"),
+    Entry::new(-67778, "errSecInvalidAttributeEffectiveBits", "Security", "sec::err::INVALID_ATTRIBUTE_EFFECTIVE_BITS", "An effective bits attribute was not valid"),
+    Entry::new(-67777, "errSecMissingAttributeMode", "Security", "sec::err::MISSING_ATTRIBUTE_MODE", "A mode attribute was missing"),
+    Entry::new(-67776, "errSecInvalidAttributeMode", "Security", "sec::err::INVALID_ATTRIBUTE_MODE", "A mode attribute was not valid"),
+    Entry::new(-67775, "errSecMissingAttributeKeyType", "Security", "sec::err::MISSING_ATTRIBUTE_KEY_TYPE", "A key type attribute was missing"),
+    Entry::new(-67774, "errSecInvalidAttributeKeyType", "Security", "sec::err::INVALID_ATTRIBUTE_KEY_TYPE", "A key type attribute was not valid"),
+    Entry::new(-67773, "errSecMissingAttributeLabel", "Security", "sec::err::MISSING_ATTRIBUTE_LABEL", "A label attribute was missing"),
+    Entry::new(-67772, "errSecInvalidAttributeLabel", "Security", "sec::err::INVALID_ATTRIBUTE_LABEL", "A label attribute was not valid"),
+    Entry::new(-67771, "errSecMissingAlgorithmParms", "Security", "sec::err::MISSING_ALGORITHM_PARMS", "An algorithm parameters attribute was missing"),
+    Entry::new(-67770, "errSecInvalidAlgorithmParms", "Security", "sec::err::INVALID_ALGORITHM_PARMS", "An algorithm parameters attribute was not valid"),
+    Entry::new(-67769, "errSecMissingAttributeRounds", "Security", "sec::err::MISSING_ATTRIBUTE_ROUNDS", "The number of rounds attribute was missing"),
+    Entry::new(-67768, "errSecInvalidAttributeRounds", "Security", "sec::err::INVALID_ATTRIBUTE_ROUNDS", "The number of rounds attribute was not valid"),
+    Entry::new(-67767, "errSecMissingAttributeOutputSize", "Security", "sec::err::MISSING_ATTRIBUTE_OUTPUT_SIZE", "An output size attribute was missing"),
+    Entry::new(-67766, "errSecInvalidAttributeOutputSize", "Security", "sec::err::INVALID_ATTRIBUTE_OUTPUT_SIZE", "An output size attribute was not valid"),
+    Entry::new(-67765, "errSecMissingAttributeBlockSize", "Security", "sec::err::MISSING_ATTRIBUTE_BLOCK_SIZE", "A block size attribute was missing"),
+    Entry::new(-67764, "errSecInvalidAttributeBlockSize", "Security", "sec::err::INVALID_ATTRIBUTE_BLOCK_SIZE", "A block size attribute was not valid"),
+    Entry::new(-67763, "errSecMissingAttributeKeyLength", "Security", "sec::err::MISSING_ATTRIBUTE_KEY_LENGTH", "A key length attribute was missing"),
+    Entry::new(-67762, "errSecInvalidAttributeKeyLength", "Security", "sec::err::INVALID_ATTRIBUTE_KEY_LENGTH", "A key length attribute was not valid"),
+    Entry::new(-67761, "errSecMissingAttributePassphrase", "Security", "sec::err::MISSING_ATTRIBUTE_PASSPHRASE", "A passphrase attribute was missing"),
+    Entry::new(-67760, "errSecInvalidAttributePassphrase", "Security", "sec::err::INVALID_ATTRIBUTE_PASSPHRASE", "A passphrase attribute was not valid"),
+    Entry::new(-67759, "errSecMissingAttributeSeed", "Security", "sec::err::MISSING_ATTRIBUTE_SEED", "A seed attribute was missing"),
+    Entry::new(-67758, "errSecInvalidAttributeSeed", "Security", "sec::err::INVALID_ATTRIBUTE_SEED", "A seed attribute was not valid"),
+    Entry::new(-67757, "errSecMissingAttributeRandom", "Security", "sec::err::MISSING_ATTRIBUTE_RANDOM", "A random number attribute was missing"),
+    Entry::new(-67756, "errSecInvalidAttributeRandom", "Security", "sec::err::INVALID_ATTRIBUTE_RANDOM", "A random number attribute was not valid"),
+    Entry::new(-67755, "errSecMissingAttributePadding", "Security", "sec::err::MISSING_ATTRIBUTE_PADDING", "A padding attribute was missing"),
+    Entry::new(-67754, "errSecInvalidAttributePadding", "Security", "sec::err::INVALID_ATTRIBUTE_PADDING", "A padding attribute was not valid"),
+    Entry::new(-67753, "errSecMissingAttributeSalt", "Security", "sec::err::MISSING_ATTRIBUTE_SALT", "A salt attribute was missing"),
+    Entry::new(-67752, "errSecInvalidAttributeSalt", "Security", "sec::err::INVALID_ATTRIBUTE_SALT", "A salt attribute was not valid"),
+    Entry::new(-67751, "errSecMissingAttributeInitVector", "Security", "sec::err::MISSING_ATTRIBUTE_INIT_VECTOR", "An init vector attribute was missing"),
+    Entry::new(-67750, "errSecInvalidAttributeInitVector", "Security", "sec::err::INVALID_ATTRIBUTE_INIT_VECTOR", "An init vector attribute was not valid"),
+    Entry::new(-67749, "errSecMissingAttributeKey", "Security", "sec::err::MISSING_ATTRIBUTE_KEY", "A key attribute was missing"),
+    Entry::new(-67748, "errSecInvalidAttributeKey", "Security", "sec::err::INVALID_ATTRIBUTE_KEY", "A key attribute was not valid"),
+    Entry::new(-67747, "errSecInvalidAlgorithm", "Security", "sec::err::INVALID_ALGORITHM", "An invalid algorithm was encountered"),
+    Entry::new(-67746, "errSecInvalidContext", "Security", "sec::err::INVALID_CONTEXT", "An invalid context was encountered"),
+    Entry::new(-67745, "errSecInvalidOutputVector", "Security", "sec::err::INVALID_OUTPUT_VECTOR", "The output vector is not valid"),
+    Entry::new(-67744, "errSecInvalidInputVector", "Security", "sec::err::INVALID_INPUT_VECTOR", "The input vector is not valid"),
+    Entry::new(-67743, "errSecUnsupportedVectorOfBuffers", "Security", "sec::err::UNSUPPORTED_VECTOR_OF_BUFFERS", "The vector of buffers is not supported"),
+    Entry::new(-67742, "errSecInvalidKeyFormat", "Security", "sec::err::INVALID_KEY_FORMAT", "The key format is not valid"),
+    Entry::new(-67741, "errSecUnsupportedKeyLabel", "Security", "sec::err::ERR_SEC_UNSUPPORTED_KEY_LABEL", "The key label is not supported"),
+    Entry::new(-67740, "errSecInvalidKeyLabel", "Security", "sec::err::INVALID_KEY_LABEL", "The key label is not valid"),
+    Entry::new(-67739, "errSecUnsupportedKeyAttributeMask", "Security", "sec::err::UNSUPPORTED_KEY_ATTRIBUTE_MASK", "The key attribute mask is not supported"),
+    Entry::new(-67738, "errSecInvalidKeyAttributeMask", "Security", "sec::err::INVALID_KEY_ATTRIBUTE_MASK", "The key attribute mask is not valid"),
+    Entry::new(-67737, "errSecUnsupportedKeyUsageMask", "Security", "sec::err::UNSUPPORTED_KEY_USAGE_MASK", "The key usage mask is not supported"),
+    Entry::new(-67736, "errSecInvalidKeyUsageMask", "Security", "sec::err::INVALID_KEY_USAGE_MASK", "The key usage mask is not valid"),
+    Entry::new(-67735, "errSecUnsupportedKeySize", "Security", "sec::err::UNSUPPORTED_KEY_SIZE", "The key size is not supported"),
+    Entry::new(-67734, "errSecUnsupportedKeyFormat", "Security", "sec::err::UNSUPPORTED_KEY_FORMAT", "The key header format is not supported"),
+    Entry::new(-67733, "errSecKeyHeaderInconsistent", "Security", "sec::err::KEY_HEADER_INCONSISTENT", "The key header is inconsistent"),
+    Entry::new(-67732, "errSecKeyBlobTypeIncorrect", "Security", "sec::err::KEY_BLOB_TYPE_INCORRECT", "The key blob type is incorrect"),
+    Entry::new(-67731, "errSecKeyUsageIncorrect", "Security", "sec::err::KEY_USAGE_INCORRECT", "The key usage is incorrect"),
+    Entry::new(-67730, "errSecAlgorithmMismatch", "Security", "sec::err::ALGORITHM_MISMATCH", "An algorithm mismatch was encountered"),
+    Entry::new(-67729, "errSecNotLoggedIn", "Security", "sec::err::NOT_LOGGED_IN", "You are not logged in"),
+    Entry::new(-67728, "errSecAttachHandleBusy", "Security", "sec::err::ATTACH_HANDLE_BUSY", "The CSP handle was busy"),
+    Entry::new(-67727, "errSecDeviceError", "Security", "sec::err::DEVICE_ERROR", "A device error was encountered"),
+    Entry::new(-67726, "errSecPrivilegeNotSupported", "Security", "sec::err::PRIVILEGE_NOT_SUPPORTED", "The privilege is not supported"),
+    Entry::new(-67725, "errSecOutputLengthError", "Security", "sec::err::OUTPUT_LENGTH_ERROR", "An output length error was encountered"),
+    Entry::new(-67724, "errSecInputLengthError", "Security", "sec::err::INPUT_LENGTH_ERROR", "An input length error was encountered"),
+    Entry::new(-67723, "errSecEventNotificationCallbackNotFound", "Security", "sec::err::EVENT_NOTIFICATION_CALLBACK_NOT_FOUND", "An event notification callback was not found"),
+    Entry::new(-67722, "errSecModuleManagerNotFound", "Security", "sec::err::MODULE_MANAGER_NOT_FOUND", "A module was not found"),
+    Entry::new(-67721, "errSecModuleManagerInitializeFailed", "Security", "sec::err::MODULE_MANAGER_INITIALIZE_FAILED", "A module failed to initialize"),
+    Entry::new(-67720, "errSecAttributeNotInContext", "Security", "sec::err::ATTRIBUTE_NOT_IN_CONTEXT", "An attribute was not in the context"),
+    Entry::new(-67719, "errSecInvalidSubServiceID", "Security", "sec::err::INVALID_SUB_SERVICE_ID", "An invalid subservice ID was encountered"),
+    Entry::new(-67718, "errSecModuleNotLoaded", "Security", "sec::err::MODULE_NOT_LOADED", "A module was not loaded"),
+    Entry::new(-67717, "errSecInvalidServiceMask", "Security", "sec::err::INVALID_SERVICE_MASK", "An invalid service mask was encountered"),
+    Entry::new(-67716, "errSecInvalidAddinFunctionTable", "Security", "sec::err::INVALID_ADDIN_FUNCTION_TABLE", "An invalid add-in function table was encountered"),
+    Entry::new(-67715, "errSecLibraryReferenceNotFound", "Security", "sec::err::LIBRARY_REFERENCE_NOT_FOUND", "A library reference was not found"),
+    Entry::new(-67714, "errSecAddinUnloadFailed", "Security", "sec::err::ADDIN_UNLOAD_FAILED", "The add-in unload operation has failed"),
+    Entry::new(-67713, "errSecInvalidKeyHierarchy", "Security", "sec::err::INVALID_KEY_HIERARCHY", "An invalid key hierarchy was encountered"),
+    Entry::new(-67712, "errSecInvalidKeyRef", "Security", "sec::err::INVALID_KEY_REF", "An invalid key was encountered"),
+    Entry::new(-67711, "errSecAddinLoadFailed", "Security", "sec::err::ADDIN_LOAD_FAILED", "The add-in load operation has failed"),
+    Entry::new(-67710, "errSecEMMUnloadFailed", "Security", "sec::err::EMM_UNLOAD_FAILED", "The EMM unload has failed"),
+    Entry::new(-67709, "errSecEMMLoadFailed", "Security", "sec::err::EMM_LOAD_FAILED", "The EMM load has failed"),
+    Entry::new(-67708, "errSecInvalidPVC", "Security", "sec::err::INVALID_PVC", "An invalid PVC was encountered"),
+    Entry::new(-67707, "errSecPVCAlreadyConfigured", "Security", "sec::err::PVC_ALREADY_CONFIGURED", "The PVC is already configured"),
+    Entry::new(-67706, "errSecInvalidScope", "Security", "sec::err::INVALID_SCOPE", "An invalid scope was encountered"),
+    Entry::new(-67705, "errSecPrivilegeNotGranted", "Security", "sec::err::PRIVILEGE_NOT_GRANTED", "The privilege was not granted"),
+    Entry::new(-67704, "errSecIncompatibleVersion", "Security", "sec::err::INCOMPATIBLE_VERSION", "An incompatible version was encountered"),
+    Entry::new(-67703, "errSecInvalidSampleValue", "Security", "sec::err::INVALID_SAMPLE_VALUE", "An invalid sample value was encountered"),
+    Entry::new(-67702, "errSecInvalidACL", "Security", "sec::err::INVALID_ACL", "An invalid ACL was encountered"),
+    Entry::new(-67701, "errSecInvalidRecord", "Security", "sec::err::INVALID_RECORD", "An invalid record was encountered"),
+    Entry::new(-67700, "errSecInvalidAccessCredentials", "Security", "sec::err::INVALID_ACCESS_CREDENTIALS", ""),
+    Entry::new(-67699, "errSecACLChangeFailed", "Security", "sec::err::ACL_CHANGE_FAILED", "An ACL change operation has failed"),
+    Entry::new(-67698, "errSecACLAddFailed", "Security", "sec::err::ACL_ADD_FAILED", "An ACL add operation has failed"),
+    Entry::new(-67697, "errSecACLReplaceFailed", "Security", "sec::err::ACL_REPLACE_FAILED", "An ACL replace operation has failed"),
+    Entry::new(-67696, "errSecACLDeleteFailed", "Security", "sec::err::ACL_DELETE_FAILED", "An ACL delete operation has failed"),
+    Entry::new(-67695, "errSecCallbackFailed", "Security", "sec::err::CALLBACK_FAILED", "A callback has failed"),
+    Entry::new(-67694, "errSecInvalidValue", "Security", "sec::err::INVALID_VALUE", "An invalid value was detected"),
+    Entry::new(-67693, "errSecInvalidQuery", "Security", "sec::err::INVALID_QUERY", "The specified query was not valid"),
+    Entry::new(-67692, "errSecTagNotFound", "Security", "sec::err::TAG_NOT_FOUND", "The specified tag was not found"),
+    Entry::new(-67691, "errSecInvalidCertificateGroup", "Security", "sec::err::INVALID_CERT_GROUP", "An invalid certificate group was encountered"),
+    Entry::new(-67690, "errSecInvalidCertificateRef", "Security", "sec::err::INVALID_CERT_REF", "An invalid certificate reference was encountered"),
+    Entry::new(-67689, "errSecInvalidName", "Security", "sec::err::INVALID_NAME", "An invalid name was encountered"),
+    Entry::new(-67688, "errSecInvalidSignature", "Security", "sec::err::INVALID_SIGNATURE", "An invalid signature was encountered"),
+    Entry::new(-67687, "errSecUnknownTag", "Security", "sec::err::UNKNOWN_TAG", "An unknown tag was encountered"),
+    Entry::new(-67686, "errSecVerificationFailure", "Security", "sec::err::VERIFICATION_FAILURE", "A verification failure occurred"),
+    Entry::new(-67685, "errSecInvalidNumberOfFields", "Security", "sec::err::INVALID_NUMBER_OF_FIELDS", "An invalid number of fields were encountered"),
+    Entry::new(-67684, "errSecCRLAlreadySigned", "Security", "sec::err::CRL_ALREADY_SIGNED", "The certificate revocation list is already signed"),
+    Entry::new(-67683, "errSecInvalidNetworkAddress", "Security", "sec::err::INVALID_NETWORK_ADDRESS", "An invalid network address was encountered"),
+    Entry::new(-67682, "errSecInvalidPassthroughID", "Security", "sec::err::INVALID_PASSTHROUGH_ID", "An invalid passthrough ID was encountered"),
+    Entry::new(-67681, "errSecInvalidDBList", "Security", "sec::err::INVALID_DB_LIST", "An invalid DB list was encountered"),
+    Entry::new(-67680, "errSecInvalidHandle", "Security", "sec::err::INVALID_HANDLE", "An invalid handle was encountered"),
+    Entry::new(-67679, "errSecInvalidGUID", "Security", "sec::err::INVALID_GUID", "An invalid GUID was encountered"),
+    Entry::new(-67678, "errSecModuleManifestVerifyFailed", "Security", "sec::err::MODULE_MANIFEST_VERIFY_FAILED", "A module manifest verification failure has occurred"),
+    Entry::new(-67677, "errSecFunctionFailed", "Security", "sec::err::FUNCTION_FAILED", "A function has failed"),
+    Entry::new(-67676, "errSecSelfCheckFailed", "Security", "sec::err::SELF_CHECK_FAILED", "Self-check has failed"),
+    Entry::new(-67675, "errSecInvalidPointer", "Security", "sec::err::INVALID_POINTER", "An invalid pointer was encountered"),
+    Entry::new(-67674, "errSecMDSError", "Security", "sec::err::MDS_ERROR", "A Module Directory Service error has occurred"),
+    Entry::new(-67673, "errSecInvalidData", "Security", "sec::err::INVALID_DATA", "Invalid data was encountered"),
+    Entry::new(-67672, "errSecMemoryError", "Security", "sec::err::MEMORY_ERR", "A memory error has occurred"),
+    Entry::new(-67671, "errSecInternalError", "Security", "sec::err::INTERNAL_ERR", "An internal error has occurred"),
+    Entry::new(-67670, "errSecFunctionIntegrityFail", "Security", "sec::err::FUNCTION_INTEGRITY_FAIL", "A function address was not within the verified module"),
+    Entry::new(-67669, "errSecPVCReferentNotFound", "Security", "sec::err::PVC_REFERENT_NOT_FOUND", "A reference to the calling module was not found in the list of authorized callers"),
+    Entry::new(-67668, "errSecInvalidHandleUsage", "Security", "sec::err::INVALID_HANDLE_USAGE", "The CSSM handle does not match with the service type"),
+    Entry::new(-67667, "errSecNotInitialized", "Security", "sec::err::NOT_INITIALIZED", "A function was called without initializing CSSM"),
+    Entry::new(-67666, "errSecMobileMeFailedConsistencyCheck", "Security", "sec::err::MOBILE_ME_FAILED_CONSISTENCY_CHECK", ""),
+    Entry::new(-67665, "errSecMobileMeCSRVerifyFailure", "Security", "sec::err::MOBILE_ME_CSR_VERIFY_FAILURE", ""),
+    Entry::new(-67664, "errSecMobileMeNoRequestPending", "Security", "sec::err::MOBILE_ME_NO_REQUEST_PENDING", ""),
+    Entry::new(-67663, "errSecMobileMeRequestAlreadyPending", "Security", "sec::err::MOBILE_ME_REQUEST_ALREADY_PENDING", ""),
+    Entry::new(-67662, "errSecMobileMeServerServiceErr", "Security", "sec::err::MOBILE_ME_SERVER_SERVICE_ERR", ""),
+    Entry::new(-67661, "errSecMobileMeServerAlreadyExists", "Security", "sec::err::MOBILE_ME_SERVER_ALREADY_EXISTS", ""),
+    Entry::new(-67660, "errSecMobileMeServerNotAvailable", "Security", "sec::err::MOBILE_ME_SERVER_NOT_AVAILABLE", ""),
+    Entry::new(-67659, "errSecMobileMeServerError", "Security", "sec::err::MOBILE_ME_SERVER_ERROR", ""),
+    Entry::new(-67658, "errSecMobileMeRequestRedirected", "Security", "sec::err::MOBILE_ME_REQUEST_REDIRECTED", ""),
+    Entry::new(-67657, "errSecMobileMeRequestQueued", "Security", "sec::err::MOBILE_ME_REQUEST_QUEUED", ""),
+    Entry::new(-67656, "errSecUnknownQualifiedCertStatement", "Security", "sec::err::UNKNOWN_QUALIFIED_CERT_STATEMENT", "An unknown qualified certificate statement was encountered"),
+    Entry::new(-67655, "errSecInvalidSubjectName", "Security", "sec::err::INVALID_SUBJECT_NAME", "An invalid certificate subject name was encountered"),
+    Entry::new(-67654, "errSecTrustSettingDeny", "Security", "sec::err::TRUST_SETTING_DENY", "The trust setting for this policy was set to Deny"),
+    Entry::new(-67653, "errSecResourceSignBadExtKeyUsage", "Security", "sec::err::RESOURCE_SIGN_BAD_EXT_KEY_USAGE", "Resource signing has encountered an error in the extended key usage"),
+    Entry::new(-67652, "errSecResourceSignBadCertChainLength", "Security", "sec::err::RESOURCE_SIGN_BAD_CERT_CHAIN_LENGTH", "Resource signing has encountered an incorrect certificate chain length"),
+    Entry::new(-67651, "errSecCodeSigningDevelopment", "Security", "sec::err::CODE_SIGNING_DEVELOPMENT", "Code signing indicated use of a development-only certificate"),
+    Entry::new(-67650, "errSecCodeSigningNoExtendedKeyUsage", "Security", "sec::err::CODE_SIGNING_NO_EXTENDED_KEY_USAGE", "Code signing found no extended key usage"),
+    Entry::new(-67649, "errSecCodeSigningBadPathLengthConstraint", "Security", "sec::err::CODE_SIGNING_BAD_PATH_LENGTH_CONSTRAINT", "Code signing encountered an incorrect path length constraint"),
+    Entry::new(-67648, "errSecCodeSigningNoBasicConstraints", "Security", "sec::err::CODE_SIGNING_NO_BASIC_CONSTRAINTS", "Code signing found no basic constraints"),
+    Entry::new(-67647, "errSecCodeSigningBadCertChainLength", "Security", "sec::err::CODE_SIGNING_BAD_CERT_CHAIN_LENGTH", "Code signing encountered an incorrect certificate chain length"),
+    Entry::new(-67646, "errSecOCSPResponseNonceMismatch", "Security", "sec::err::OCSP_RESPONSE_NONCE_MISMATCH", "The OCSP response nonce did not match the request"),
+    Entry::new(-67645, "errSecOCSPResponderUnauthorized", "Security", "sec::err::OCSP_RESPONDER_UNAUTHORIZED", "The OCSP responder rejected this request as unauthorized"),
+    Entry::new(-67644, "errSecOCSPResponderSignatureRequired", "Security", "sec::err::OCSP_RESPONDER_SIGNATURE_REQUIRED", "The OCSP responder requires a signature"),
+    Entry::new(-67643, "errSecOCSPResponderTryLater", "Security", "sec::err::OCSP_RESPONDER_TRY_LATER", "The OCSP responder is busy, try again later"),
+    Entry::new(-67642, "errSecOCSPResponderInternalError", "Security", "sec::err::OCSP_RESPONDER_INTERNAL_ERR", "The OCSP responder encountered an internal error"),
+    Entry::new(-67641, "errSecOCSPResponderMalformedReq", "Security", "sec::err::OCSP_RESPONDER_MALFORMED_REQ", "The OCSP responder was given a malformed request"),
+    Entry::new(-67640, "errSecOCSPNoSigner", "Security", "sec::err::OCSP_NO_SIGNER", "The OCSP response had no signer"),
+    Entry::new(-67639, "errSecOCSPSignatureError", "Security", "sec::err::OCSP_SIGNATURE_ERROR", "The OCSP response had an invalid signature"),
+    Entry::new(-67638, "errSecRecordModified", "Security", "sec::err::RECORD_MODIFIED", "The record was modified"),
+    Entry::new(-67637, "errSecOCSPNotTrustedToAnchor", "Security", "sec::err::OCSP_NOT_TRUSTED_TO_ANCHOR", "The OCSP response was not trusted to a root or anchor certificate"),
+    Entry::new(-67636, "errSecNetworkFailure", "Security", "sec::err::NETWORK_FAILURE", "A network failure occurred"),
+    Entry::new(-67635, "errSecIncompleteCertRevocationCheck", "Security", "sec::err::INCOMPLETE_CERT_REVOCATION_CHECK", "An incomplete certificate revocation check occurred"),
+    Entry::new(-67634, "errSecEndOfData", "Security", "sec::err::END_OF_DATA", "An end-of-data was detected"),
+    Entry::new(-67633, "errSecOCSPStatusUnrecognized", "Security", "sec::err::OCSP_STATUS_UNRECOGNIZED", "The OCSP server did not recognize this certificate"),
+    Entry::new(-67632, "errSecOCSPUnavailable", "Security", "sec::err::OCSP_UNAVAILABLE", "OCSP service is unavailable"),
+    Entry::new(-67631, "errSecOCSPBadRequest", "Security", "sec::err::OCSP_BAD_REQUEST", "The OCSP request was incorrect or could not be parsed"),
+    Entry::new(-67630, "errSecOCSPBadResponse", "Security", "sec::err::OCSP_BAD_RESPONSE", "The OCSP response was incorrect or could not be parsed"),
+    Entry::new(-67629, "errSecSSLBadExtendedKeyUsage", "Security", "sec::err::SSL_BAD_EXTENDED_KEY_USAGE", "The appropriate extended key usage for SSL was not found"),
+    Entry::new(-67628, "errSecSMIMESubjAltNameNotCritical", "Security", "sec::err::SMIME_SUBJ_ALT_NAME_NOT_CRITICAL", "The subject alternative name extension is not marked as critical"),
+    Entry::new(-67627, "errSecSMIMENoEmailAddress", "Security", "sec::err::SMIME_NO_EMAIL_ADDRESS", "No email address was found in the certificate"),
+    Entry::new(-67626, "errSecSMIMEKeyUsageNotCritical", "Security", "sec::err::SMIME_KEY_USAGE_NOT_CRITICAL", "The key usage extension is not marked as critical"),
+    Entry::new(-67625, "errSecSMIMEBadKeyUsage", "Security", "sec::err::SMIME_BAD_KEY_USAGE", "The key usage is not compatible with SMIME"),
+    Entry::new(-67624, "errSecSMIMEBadExtendedKeyUsage", "Security", "sec::err::SMIME_BAD_EXTENDED_KEY_USAGE", "The appropriate extended key usage for SMIME was not found"),
+    Entry::new(-67623, "errSecSMIMEEmailAddressesNotFound", "Security", "sec::err::SMIME_EMAIL_ADDRESSES_NOT_FOUND", "An email address mismatch was encountered"),
+    Entry::new(-67622, "errSecIDPFailure", "Security", "sec::err::IDP_FAILURE", "The issuing distribution point was not valid"),
+    Entry::new(-67621, "errSecCRLPolicyFailed", "Security", "sec::err::CRL_POLICY_FAILED", "The CRL policy failed"),
+    Entry::new(-67620, "errSecCRLNotTrusted", "Security", "sec::err::CRL_NOT_TRUSTED", "The CRL is not trusted"),
+    Entry::new(-67619, "errSecUnknownCRLExtension", "Security", "sec::err::UNKNOWN_CRL_EXTENSION", "An unknown CRL extension was encountered"),
+    Entry::new(-67618, "errSecUnknownCertExtension", "Security", "sec::err::UNKNOWN_CERT_EXTENSION", "An unknown certificate extension was encountered"),
+    Entry::new(-67617, "errSecCRLBadURI", "Security", "sec::err::CRL_BAD_URI", "The CRL has a bad Uniform Resource Identifier"),
+    Entry::new(-67616, "errSecCRLServerDown", "Security", "sec::err::CRL_SERVER_DOWN", "The CRL server is down"),
+    Entry::new(-67615, "errSecCRLNotFound", "Security", "sec::err::CRL_NOT_FOUND", "The CRL was not found"),
+    Entry::new(-67614, "errSecCRLNotValidYet", "Security", "sec::err::CRL_NOT_VALID_YET", "The CRL is not yet valid"),
+    Entry::new(-67613, "errSecCRLExpired", "Security", "sec::err::CRL_EXPIRED", "The CRL has expired"),
+    Entry::new(-67612, "errSecInvalidRoot", "Security", "sec::err::INVALID_ROOT", "The root or anchor certificate is not valid"),
+    Entry::new(-67611, "errSecPathLengthConstraintExceeded", "Security", "sec::err::PATH_LENGTH_CONSTRAINT_EXCEEDED", "The path length constraint was exceeded"),
+    Entry::new(-67610, "errSecInvalidIDLinkage", "Security", "sec::err::INVALID_ID_LINKAGE", "The ID linkage is not valid"),
+    Entry::new(-67609, "errSecInvalidExtendedKeyUsage", "Security", "sec::err::INVALID_EXTENDED_KEY_USAGE", "The extended key usage is not valid"),
+    Entry::new(-67608, "errSecInvalidKeyUsageForPolicy", "Security", "sec::err::INVALID_KEY_USAGE_FOR_POLICY", "The key usage is not valid for the specified policy"),
+    Entry::new(-67607, "errSecInvalidSubjectKeyID", "Security", "sec::err::INVALID_SUBJECT_KEY_ID", "The subject key ID is not valid"),
+    Entry::new(-67606, "errSecInvalidAuthorityKeyID", "Security", "sec::err::INVALID_AUTHORITY_KEY_ID", "The authority key ID is not valid"),
+    Entry::new(-67605, "errSecNoBasicConstraintsCA", "Security", "sec::err::NO_BASIC_CONSTRAINTS_CA", "No basic CA constraints were found"),
+    Entry::new(-67604, "errSecNoBasicConstraints", "Security", "sec::err::NO_BASIC_CONSTRAINTS", "No basic constraints were found"),
+    Entry::new(-67603, "errSecUnknownCriticalExtensionFlag", "Security", "sec::err::UNKNOWN_CRITICAL_EXTENSION_FLAG", "There is an unknown critical extension flag"),
+    Entry::new(-67602, "errSecHostNameMismatch", "Security", "sec::err::HOST_NAME_MISMATCH", "A host name mismatch has occurred"),
+    Entry::new(-67601, "errSecIncompatibleKeyBlob", "Security", "sec::err::INCOMPATIBLE_KEY_BLOB", "The specified database has an incompatible key blob"),
+    Entry::new(-67600, "errSecIncompatibleDatabaseBlob", "Security", "sec::err::INCOMPATIBLE_DATABASE_BLOB", "The specified database has an incompatible blob"),
+    Entry::new(-67599, "errSecInvalidKeyBlob", "Security", "sec::err::INVALID_KEY_BLOB", "The specified database has an invalid key blob"),
+    Entry::new(-67598, "errSecInvalidDatabaseBlob", "Security", "sec::err::INVALID_DATABASE_BLOB", "The specified database has an invalid blob"),
+    Entry::new(-67597, "errSecFileTooBig", "Security", "sec::err::FILE_TOO_BIG", "The file is too big"),
+    Entry::new(-67596, "errSecQuotaExceeded", "Security", "sec::err::QUOTA_EXCEEDED", "The quota was exceeded"),
+    Entry::new(-67595, "errSecAppleSSLv2Rollback", "Security", "sec::err::APPLE_SSL_V2_ROLLBACK", "A SSLv2 rollback error has occurred"),
+    Entry::new(-67594, "errSecConversionError", "Security", "sec::err::CONVERSION_ERROR", "A conversion error has occurred"),
+    Entry::new(-67593, "errSecAppleInvalidKeyEndDate", "Security", "sec::err::APPLE_INVALID_KEY_END_DATE", "The specified key has an invalid end date"),
+    Entry::new(-67592, "errSecAppleInvalidKeyStartDate", "Security", "sec::err::APPLE_INVALID_KEY_START_DATE", "The specified key has an invalid start date"),
+    Entry::new(-67591, "errSecAppleSignatureMismatch", "Security", "sec::err::APPLE_SIGNATURE_MISMATCH", "A signature mismatch has occurred"),
+    Entry::new(-67590, "errSecApplePublicKeyIncomplete", "Security", "sec::err::APPLE_PUBLIC_KEY_INCOMPLETE", "The public key is incomplete"),
+    Entry::new(-67589, "errSecAppleAddAppACLSubject", "Security", "sec::err::APPLE_ADD_APP_ACL_SUBJECT", "Adding an application ACL subject failed"),
+    Entry::new(-67588, "errSecDeviceFailed", "Security", "sec::err::DEVICE_FAILED", "A device failure has occurred"),
+    Entry::new(-67587, "errSecDeviceReset", "Security", "sec::err::DEVICE_RESET", "A device reset has occurred"),
+    Entry::new(-67586, "errSecInsufficientClientID", "Security", "sec::err::INSUFFICIENT_CLIENT_ID", "The client ID is not correct"),
+    Entry::new(-67585, "errSecServiceNotAvailable", "Security", "sec::err::SERVICE_NOT_AVAILABLE", "The required service is not available"),
+    Entry::new(-66754, "kAudioComponentErr_InstanceTimedOut", "AudioToolbox", "at::audio::unit::component_err::INSTANCE_TIMED_OUT", ""),
+    Entry::new(-66753, "kAudioUnitErr_MIDIOutputBufferFull", "AudioToolbox", "at::audio::unit::err::MIDI_OUTPUT_BUFFER_FULL", "Returned during the render call, if the audio unit produces more MIDI output, than the default allocated buffer"),
+    Entry::new(-66752, "kAudioComponentErr_DuplicateDescription", "AudioToolbox", "at::audio::unit::component_err::DUPLICATE_DESCRIPTION", "a non-unique component description was provided to AudioOutputUnitPublish"),
+    Entry::new(-66751, "kAudioComponentErr_UnsupportedType", "AudioToolbox", "at::audio::unit::component_err::UNSUPPORTED_TYPE", "an unsupported component type was provided to AudioOutputUnitPublish"),
+    Entry::new(-66750, "kAudioComponentErr_TooManyInstances", "AudioToolbox", "at::audio::unit::component_err::TOO_MANY_INSTANCES", "components published via AudioOutputUnitPublish may only have one instance"),
+    Entry::new(-66749, "kAudioComponentErr_InstanceInvalidated", "AudioToolbox", "at::audio::unit::component_err::INSTANCE_INVALIDATED", ""),
+    Entry::new(-66749, "kAudioUnitErr_ComponentManagerNotSupported", "AudioToolbox", "at::audio::unit::err::COMPONENT_MANAGER_NOT_SUPPORTED", "The operation can not be performed for a component instance instantiated using the deprecated Component Manager"),
+    Entry::new(-66748, "kAudioComponentErr_NotPermitted", "AudioToolbox", "at::audio::unit::component_err::NOT_PERMITTED", "app needs \"inter-app-audio\" entitlement or host app needs \"audio\" in its UIBackgroundModes"),
+    Entry::new(-66747, "kAudioComponentErr_InitializationTimedOut", "AudioToolbox", "at::audio::unit::component_err::INITIALIZATION_TIMED_OUT", "host did not render in a timely manner; must uninitialize and reinitialize"),
+    Entry::new(-66746, "kAudioComponentErr_InvalidFormat", "AudioToolbox", "at::audio::unit::component_err::INVALID_FORMAT", "inter-app AU element formats must have sample rates matching the hardware"),
+    Entry::new(-66745, "kAudioUnitErr_RenderTimeout", "AudioToolbox", "at::audio::unit::err::RENDER_TIMEOUT", "The audio unit did not satisfy the render request in time"),
+    Entry::new(-66744, "kAudioUnitErr_ExtensionNotFound", "AudioToolbox", "at::audio::unit::err::EXTENSION_NOT_FOUND", "The specified identifier did not match any Audio Unit Extensions"),
+    Entry::new(-66743, "kAudioUnitErr_InvalidParameterValue", "AudioToolbox", "at::audio::unit::err::INVALID_PARAMETER_VALUE", "The parameter value is not supported, e.g"),
+    Entry::new(-66742, "kAudioUnitErr_InvalidFilePath", "AudioToolbox", "at::audio::unit::err::INVALID_FILE_PATH", "The file path that was passed is not supported"),
+    Entry::new(-66741, "kAudioUnitErr_MissingKey", "AudioToolbox", "at::audio::unit::err::MISSING_KEY", "A required key is missing from a dictionary object"),
+    Entry::new(-66687, "kAudioQueueErr_InvalidBuffer", "AudioToolbox", "at::audio::queue_err::INVALID_BUFFER", ""),
+    Entry::new(-66686, "kAudioQueueErr_BufferEmpty", "AudioToolbox", "at::audio::queue_err::BUFFER_EMPTY", ""),
+    Entry::new(-66685, "kAudioQueueErr_DisposalPending", "AudioToolbox", "at::audio::queue_err::DISPOSAL_PENDING", ""),
+    Entry::new(-66684, "kAudioQueueErr_InvalidProperty", "AudioToolbox", "at::audio::queue_err::INVALID_PROP", ""),
+    Entry::new(-66683, "kAudioQueueErr_InvalidPropertySize", "AudioToolbox", "at::audio::queue_err::INVALID_PROP_SIZE", ""),
+    Entry::new(-66682, "kAudioQueueErr_InvalidParameter", "AudioToolbox", "at::audio::queue_err::INVALID_PARAM", ""),
+    Entry::new(-66681, "kAudioQueueErr_CannotStart", "AudioToolbox", "at::audio::queue_err::CANNOT_START", ""),
+    Entry::new(-66680, "kAudioQueueErr_InvalidDevice", "AudioToolbox", "at::audio::queue_err::INVALID_DEVICE", ""),
+    Entry::new(-66679, "kAudioQueueErr_BufferInQueue", "AudioToolbox", "at::audio::queue_err::BUFFER_IN_QUEUE", ""),
+    Entry::new(-66678, "kAudioQueueErr_InvalidRunState", "AudioToolbox", "at::audio::queue_err::INVALID_RUN_STATE", ""),
+    Entry::new(-66677, "kAudioQueueErr_InvalidQueueType", "AudioToolbox", "at::audio::queue_err::INVALID_QUEUE_TYPE", ""),
+    Entry::new(-66676, "kAudioQueueErr_Permissions", "AudioToolbox", "at::audio::queue_err::PERMISSIONS", ""),
+    Entry::new(-66675, "kAudioQueueErr_InvalidPropertyValue", "AudioToolbox", "at::audio::queue_err::INVALID_PROP_VALUE", ""),
+    Entry::new(-66674, "kAudioQueueErr_PrimeTimedOut", "AudioToolbox", "at::audio::queue_err::PRIME_TIMED_OUT", ""),
+    Entry::new(-66673, "kAudioQueueErr_CodecNotFound", "AudioToolbox", "at::audio::queue_err::CODEC_NOT_FOUND", ""),
+    Entry::new(-66672, "kAudioQueueErr_InvalidCodecAccess", "AudioToolbox", "at::audio::queue_err::INVALID_CODEC_ACCESS", ""),
+    Entry::new(-66671, "kAudioQueueErr_QueueInvalidated", "AudioToolbox", "at::audio::queue_err::QUEUE_INVALIDATED", ""),
+    Entry::new(-66670, "kAudioQueueErr_TooManyTaps", "AudioToolbox", "at::audio::queue_err::TOO_MANY_TAPS", ""),
+    Entry::new(-66669, "kAudioQueueErr_InvalidTapContext", "AudioToolbox", "at::audio::queue_err::INVALID_TAP_CONTEXT", ""),
+    Entry::new(-66668, "kAudioQueueErr_RecordUnderrun", "AudioToolbox", "at::audio::queue_err::RECORD_UNDERRUN", ""),
+    Entry::new(-66667, "kAudioQueueErr_InvalidTapType", "AudioToolbox", "at::audio::queue_err::INVALID_TAP_TYPE", ""),
+    Entry::new(-66666, "kAudioQueueErr_BufferEnqueuedTwice", "AudioToolbox", "at::audio::queue_err::BUFFER_ENQUEUED_TWICE", ""),
+    Entry::new(-66665, "kAudioQueueErr_CannotStartYet", "AudioToolbox", "at::audio::queue_err::CANNOT_START_YET", ""),
+    Entry::new(-66635, "kAudioUnitErr_MultipleVoiceProcessors", "AudioToolbox", "at::audio::unit::err::MULTIPLE_VOICE_PROCESSORS", "On some platforms, this error is returned when a client attempts to initialize a voice processor instance while another is initialized"),
+    Entry::new(-66632, "kAudioQueueErr_EnqueueDuringReset", "AudioToolbox", "at::audio::queue_err::ENQUEUE_DURING_RESET", ""),
+    Entry::new(-66626, "kAudioQueueErr_InvalidOfflineMode", "AudioToolbox", "at::audio::queue_err::INVALID_OFFLINE_MODE", ""),
+    Entry::new(-66570, "kExtAudioFileError_AsyncWriteBufferOverflow", "AudioToolbox", "at::ext_audio_file_err::ASYNC_WRITE_BUF_OVERFLOW", ""),
+    Entry::new(-66569, "kExtAudioFileError_AsyncWriteTooLarge", "AudioToolbox", "at::ext_audio_file_err::ASYNC_WRITE_TOO_LARGE", ""),
+    Entry::new(-66568, "kExtAudioFileError_InvalidSeek", "AudioToolbox", "at::ext_audio_file_err::INVALID_SEEK", ""),
+    Entry::new(-66567, "kExtAudioFileError_MaxPacketSizeUnknown", "AudioToolbox", "at::ext_audio_file_err::MAX_PACKET_SIZE_UNKNOWN", ""),
+    Entry::new(-66566, "kExtAudioFileError_InvalidDataFormat", "AudioToolbox", "at::ext_audio_file_err::INVALID_DATA_FORMAT", ""),
+    Entry::new(-66565, "kExtAudioFileError_InvalidOperationOrder", "AudioToolbox", "at::ext_audio_file_err::INVALID_OP_ORDER", ""),
+    Entry::new(-66564, "kExtAudioFileError_InvalidChannelMap", "AudioToolbox", "at::ext_audio_file_err::INVALID_CHANNEL_MAP", ""),
+    Entry::new(-66563, "kExtAudioFileError_NonPCMClientFormat", "AudioToolbox", "at::ext_audio_file_err::NON_PCM_CLIENT_FORMAT", ""),
+    Entry::new(-66562, "kExtAudioFileError_InvalidPropertySize", "AudioToolbox", "at::ext_audio_file_err::INVALID_PROP_SIZE", ""),
+    Entry::new(-66561, "kExtAudioFileError_InvalidProperty", "AudioToolbox", "at::ext_audio_file_err::INVALID_PROP", ""),
+    Entry::new(-34020, "errSecRestrictedAPI", "Security", "sec::err::RESTRICTED_API", "Client is restricted and is not permitted to perform this operation"),
+    Entry::new(-34018, "errSecMissingEntitlement", "Security", "sec::err::MISSING_ENTITLEMENT", "A required entitlement isn't present"),
+    Entry::new(-26275, "errSecDecode", "Security", "sec::err::DECODE", "Unable to decode the provided data"),
+    Entry::new(-26267, "errSecNotSigner", "Security", "sec::err::NOT_SIGNER", "A certificate was not signed by its proposed parent"),
+    Entry::new(-25320, "errSecInDarkWake", "Security", "sec::err::IN_DARK_WAKE", "In dark wake, no UI possible"),
+    Entry::new(-25319, "errSecInvalidPrefsDomain", "Security", "sec::err::INVALID_PREFS_DOMAIN", "The specified preferences domain is not valid"),
+    Entry::new(-25318, "errSecCreateChainFailed", "Security", "sec::err::CREATE_CHAIN_FAILED", "One or more certificates required to validate this certificate cannot be found"),
+    Entry::new(-25317, "errSecDataNotModifiable", "Security", "sec::err::DATA_NOT_MODIFIABLE", "The contents of this item cannot be modified"),
+    Entry::new(-25316, "errSecDataNotAvailable", "Security", "sec::err::DATA_NOT_AVAILABLE", "The contents of this item cannot be retrieved"),
+    Entry::new(-25315, "errSecInteractionRequired", "Security", "sec::err::INTERACTION_REQUIRED", "User interaction is required, but is currently not allowed"),
+    Entry::new(-25314, "errSecNoPolicyModule", "Security", "sec::err::NO_POLICY_MODULE", "A required component (policy module) could not be loaded"),
+    Entry::new(-25313, "errSecNoCertificateModule", "Security", "sec::err::NO_CERT_MODULE", "A required component (certificate module) could not be loaded"),
+    Entry::new(-25312, "errSecNoStorageModule", "Security", "sec::err::NO_STORAGE_MODULE", "A required component (data storage module) could not be loaded"),
+    Entry::new(-25311, "errSecKeySizeNotAllowed", "Security", "sec::err::KEY_SIZE_NOT_ALLOWED", "This item specifies a key size which is too large or too small"),
+    Entry::new(-25310, "errSecWrongSecVersion", "Security", "sec::err::WRONG_SEC_VERSION", "This keychain was created by a different version of the system software and cannot be opened"),
+    Entry::new(-25309, "errSecReadOnlyAttr", "Security", "sec::err::READ_ONLY_ATTR", "The specified attribute could not be modified"),
+    Entry::new(-25308, "errSecInteractionNotAllowed", "Security", "sec::err::INTERACTION_NOT_ALLOWED", "User interaction is not allowed"),
+    Entry::new(-25307, "errSecNoDefaultKeychain", "Security", "sec::err::NO_DEFAULT_KEYCHAIN", "A default keychain could not be found"),
+    Entry::new(-25306, "errSecNoSuchClass", "Security", "sec::err::NO_SUCH_CLASS", "The specified item does not appear to be a valid keychain item"),
+    Entry::new(-25305, "errSecInvalidSearchRef", "Security", "sec::err::INVALID_SEARCH_REF", "Unable to search the current keychain"),
+    Entry::new(-25304, "errSecInvalidItemRef", "Security", "sec::err::INVALID_ITEM_REF", "The specified item is no longer valid"),
+    Entry::new(-25303, "errSecNoSuchAttr", "Security", "sec::err::NO_SUCH_ATTR", "The specified attribute does not exist"),
+    Entry::new(-25302, "errSecDataTooLarge", "Security", "sec::err::DATA_TOO_LARGE", "This item contains information which is too large or in a format that cannot be displayed"),
+    Entry::new(-25301, "errSecBufferTooSmall", "Security", "sec::err::BUF_TOO_SMALL", "There is not enough memory available to use the specified item"),
+    Entry::new(-25300, "errSecItemNotFound", "Security", "sec::err::ITEM_NOT_FOUND", "The specified item could not be found in the keychain"),
+    Entry::new(-25299, "errSecDuplicateItem", "Security", "sec::err::DUPLICATE_ITEM", "The specified item already exists in the keychain"),
+    Entry::new(-25298, "errSecInvalidCallback", "Security", "sec::err::INVALID_CB", "The specified callback function is not valid"),
+    Entry::new(-25297, "errSecDuplicateCallback", "Security", "sec::err::DUPLICATE_CB", "The specified callback function is already installed"),
+    Entry::new(-25296, "errSecDuplicateKeychain", "Security", "sec::err::DUPLICATE_KEYCHAIN", "A keychain with the same name already exists"),
+    Entry::new(-25295, "errSecInvalidKeychain", "Security", "sec::err::INVALID_KEYCHAIN", "The specified keychain is not a valid keychain file"),
+    Entry::new(-25294, "errSecNoSuchKeychain", "Security", "sec::err::NO_SUCH_KEYCHAIN", "The specified keychain could not be found"),
+    Entry::new(-25293, "errSecAuthFailed", "Security", "sec::err::AUTH_FAILED", "The user name or passphrase you entered is not correct"),
+    Entry::new(-25292, "errSecReadOnly", "Security", "sec::err::READ_ONLY", "This keychain cannot be modified"),
+    Entry::new(-25291, "errSecNotAvailable", "Security", "sec::err::NOT_AVAILABLE", "No keychain is available"),
+    Entry::new(-25264, "errSecPkcs12VerifyFailure", "Security", "sec::err::PKCS12_VERIFY_FAILURE", "MAC verification failed during PKCS12 import (wrong password?)"),
+    Entry::new(-25263, "errSecNoTrustSettings", "Security", "sec::err::NO_TRUST_SETTINGS", "No Trust Settings were found"),
+    Entry::new(-25262, "errSecInvalidTrustSettings", "Security", "sec::err::INVALID_TRUST_SETTINGS", "The Trust Settings Record was corrupted"),
+    Entry::new(-25261, "errSecInvalidPasswordRef", "Security", "sec::err::INVALID_PASSWORD_REF", "The password reference was invalid"),
+    Entry::new(-25260, "errSecPassphraseRequired", "Security", "sec::err::PASSPHRASE_REQUIRED", "Passphrase is required for import/export"),
+    Entry::new(-25259, "errSecMultiplePrivKeys", "Security", "sec::err::MULTIPLE_PRIV_KEYS", "An attempt was made to import multiple private keys"),
+    Entry::new(-25258, "errSecKeyIsSensitive", "Security", "sec::err::KEY_IS_SENSITIVE", "Key material must be wrapped for export"),
+    Entry::new(-25257, "errSecUnknownFormat", "Security", "sec::err::UNKNOWN_FORMAT", "Unknown format in import"),
+    Entry::new(-25256, "errSecUnsupportedFormat", "Security", "sec::err::UNSUPPORTED_FORMAT", "Import/Export format unsupported"),
+    Entry::new(-25245, "errSecTrustNotAvailable", "Security", "sec::err::TRUST_NOT_AVAILABLE", "No trust results are available"),
+    Entry::new(-25244, "errSecInvalidOwnerEdit", "Security", "sec::err::INVALID_OWNER_EDIT", "Invalid attempt to change the owner of this item"),
+    Entry::new(-25243, "errSecNoAccessForItem", "Security", "sec::err::NO_ACCESS_FOR_ITEM", "The specified item has no access control"),
+    Entry::new(-25242, "errSecInvalidTrustSetting", "Security", "sec::err::INVALID_TRUST_SETTING", "The specified trust setting is invalid"),
+    Entry::new(-25241, "errSecPolicyNotFound", "Security", "sec::err::POLICY_NOT_FOUND", "The specified policy cannot be found"),
+    Entry::new(-25240, "errSecACLNotSimple", "Security", "sec::err::ACL_NOT_SIMPLE", "The specified access control list is not in standard (simple) form"),
+    Entry::new(-25214, "kAXErrorNotEnoughPrecision", "ApplicationServices", "ax::err::NOT_ENOUGH_PRECISION", "Not enough precision"),
+    Entry::new(-25213, "kAXErrorParameterizedAttributeUnsupported", "ApplicationServices", "ax::err::PARAMETERIZED_ATTR_UNSUPPORTED", "The parameterized attribute is not supported by the ax::UiElement ref"),
+    Entry::new(-25212, "kAXErrorNoValue", "ApplicationServices", "ax::err::NO_VALUE", "The requested value or AXUIElementRef does not exist"),
+    Entry::new(-25211, "kAXErrorAPIDisabled", "ApplicationServices", "ax::err::API_DISABLED", "The accessibility API is disabled (as when, for example, the user deselects \"Enable access for assistive devices\" in Universal Access Preferences)"),
+    Entry::new(-25210, "kAXErrorNotificationNotRegistered", "ApplicationServices", "ax::err::NOTIFICATION_NOT_REGISTERED", "Indicates that a notification is not registered yet"),
+    Entry::new(-25209, "kAXErrorNotificationAlreadyRegistered", "ApplicationServices", "ax::err::NOTIFICATION_ALREADY_REGISTERED", "This notification has already been registered for"),
+    Entry::new(-25208, "kAXErrorNotImplemented", "ApplicationServices", "ax::err::NOT_IMPLEMENTED", "Indicates that the function or method is not implemented (this can be returned if a process does not support the accessibility API)"),
+    Entry::new(-25207, "kAXErrorNotificationUnsupported", "ApplicationServices", "ax::err::NOTIFICATION_UNSUPPORTED", "The notification is not supported by the ax::UiElement ref"),
+    Entry::new(-25206, "kAXErrorActionUnsupported", "ApplicationServices", "ax::err::ACTION_UNSUPPORTED", "The action is not supported by the ax::UiElement ref"),
+    Entry::new(-25205, "kAXErrorAttributeUnsupported", "ApplicationServices", "ax::err::ATTR_UNSUPPORTED", "The attribute is not supported by the ax::UiElement ref"),
+    Entry::new(-25204, "kAXErrorCannotComplete", "ApplicationServices", "ax::err::CANNOT_COMPLETE", "The function cannot complete because messaging failed in some way or because the application with which the function is communicating is busy or unresponsive"),
+    Entry::new(-25203, "kAXErrorInvalidUIElementObserver", "ApplicationServices", "ax::err::INVALID_UI_ELEMENT_OBSERVER", "The ax::Observer ref passed to the function is not a valid observer"),
+    Entry::new(-25202, "kAXErrorInvalidUIElement", "ApplicationServices", "ax::err::INVALID_UI_ELEMENT", "The ax::UiElement ref passed to the function is invalid"),
+    Entry::new(-25201, "kAXErrorIllegalArgument", "ApplicationServices", "ax::err::ILLEGAL_ARGUMENT", "An illegal argument was passed to the function"),
+    Entry::new(-25200, "kAXErrorFailure", "ApplicationServices", "ax::err::FAILURE", "A system error occurred, such as the failure to allocate an object"),
+    Entry::new(-22143, "kCGImageAnimationStatus_AllocationFailure", "ImageIO", "cg::image::animation_err::ALLOC_FAILURE", "A required resource could not be created"),
+    Entry::new(-22143, "kCGImageAnimationStatus_IncompleteInputImage", "ImageIO", "cg::image::animation_err::INCOMPLETE_INPUT_IMAGE", "An image can be read from the given source, but it is incomplete"),
+    Entry::new(-22142, "kCGImageAnimationStatus_UnsupportedFormat", "ImageIO", "cg::image::animation_err::UNSUPPORTED_FORMAT", "The image format is not applicable to animation"),
+    Entry::new(-22141, "kCGImageAnimationStatus_CorruptInputImage", "ImageIO", "cg::image::animation_err::CORRUPT_INPUT_IMAGE", "An image cannot be read from the given source"),
+    Entry::new(-22140, "kCGImageAnimationStatus_ParameterError", "ImageIO", "cg::image::animation_err::PARAM_ERR", "None/null or invalid parameter passed to API"),
+    Entry::new(-17695, "kVTVideoDecoderCallbackMessagingErr", "VideoToolbox", "vt::errors::VIDEO_DECODER_CALLBACK_MESSAGING", ""),
+    Entry::new(-17694, "kVTVideoDecoderReferenceMissingErr", "VideoToolbox", "vt::errors::VIDEO_DECODER_REFERENCE_MISSING", ""),
+    Entry::new(-17693, "kVTVideoEncoderNeedsRosettaErr", "VideoToolbox", "vt::errors::VIDEO_ENCODER_NEEDS_ROSETTA", ""),
+    Entry::new(-17692, "kVTVideoDecoderNeedsRosettaErr", "VideoToolbox", "vt::errors::VIDEO_DECODER_NEEDS_ROSETTA", ""),
+    Entry::new(-17691, "kVTSessionMalfunctionErr", "VideoToolbox", "vt::errors::SESSION_MALFUNCTION", ""),
+    Entry::new(-17690, "kVTVideoDecoderRemovedErr", "VideoToolbox", "vt::errors::VIDEO_DECODER_REMOVED", ""),
+    Entry::new(-16751, "kCMSampleBufferError_DataCanceled", "CoreMedia", "cm::sample_buf_err::DATA_CANCELED", "the sample buffer's data loading operation was canceled"),
+    Entry::new(-16750, "kCMSampleBufferError_DataFailed", "CoreMedia", "cm::sample_buf_err::DATA_FAILED", "the sample buffer's data loading operation failed (generic error)"),
+    Entry::new(-15782, "kCMTaggedBufferGroupError_InternalError", "CoreMedia", "cm::tagged_buf_group_err::INTERNAL", ""),
+    Entry::new(-15781, "kCMTaggedBufferGroupError_AllocationFailed", "CoreMedia", "cm::tagged_buf_group_err::ALLOC_FAILED", ""),
+    Entry::new(-15780, "kCMTaggedBufferGroupError_ParamErr", "CoreMedia", "cm::tagged_buf_group_err::PARAM_ERR", ""),
+    Entry::new(-15749, "kCMTagCollectionError_NotYetImplemented", "CoreMedia", "cm::tag_collection_err::NOT_YET_IMPLEMENTED", "Returned if the function is not yet implemented"),
+    Entry::new(-15748, "kCMTagCollectionError_ExhaustedBufferSize", "CoreMedia", "cm::tag_collection_err::EXHAUSTED_BUF_SIZE", "Returned if the buffer size to retrieve cm::Tags is smaller than necessary"),
+    Entry::new(-15747, "kCMTagCollectionError_InvalidTagCollectionDataVersion", "CoreMedia", "cm::tag_collection_err::INVALID_TAG_COLLECTION_DATA_VERSION", "Returned if the CFData deserialized to create a cm::TagCollection has an unknown version"),
+    Entry::new(-15746, "kCMTagCollectionError_TagNotFound", "CoreMedia", "cm::tag_collection_err::TAG_NOT_FOUND", "Returned if a search for a cm::Tag in the collection failed, including if the collection is empty"),
+    Entry::new(-15745, "kCMTagCollectionError_InvalidTagCollectionData", "CoreMedia", "cm::tag_collection_err::INVALID_TAG_COLLECTION_DATA", "Returned if structure of the CFData being deserialized is not valid to create a cm::TagCollection"),
+    Entry::new(-15744, "kCMTagCollectionError_InvalidTagCollectionDictionary", "CoreMedia", "cm::tag_collection_err::INVALID_TAG_COLLECTION_DICTIONARY", "Returned if the CFDictionary being deserialized is not valid to create a cm::TagCollection"),
+    Entry::new(-15743, "kCMTagCollectionError_InvalidTag", "CoreMedia", "cm::tag_collection_err::INVALID_TAG", "Returned if the tag is kCMTagInvalid"),
+    Entry::new(-15742, "kCMTagCollectionError_InternalError", "CoreMedia", "cm::tag_collection_err::INTERNAL", "/ Returned if some kind of internal implementation error occurred"),
+    Entry::new(-15741, "kCMTagCollectionError_AllocationFailed", "CoreMedia", "cm::tag_collection_err::ALLOC_FAILED", "Returned if a necessary allocation failed"),
+    Entry::new(-15740, "kCMTagCollectionError_ParamErr", "CoreMedia", "cm::tag_collection_err::PARAM_ERR", "When caller passes incorrect input or output parameters"),
+    Entry::new(-15731, "kCMTagError_AllocationFailed", "CoreMedia", "cm::tag_err::ALLOC_FAILED", "Returned if a necessary allocation failed"),
+    Entry::new(-15730, "kCMTagError_ParamErr", "CoreMedia", "cm::tag_err::PARAM_ERR", "When caller passes incorrect input or output parameters"),
+    Entry::new(-12919, "kVTColorSyncTransformConvertFailedErr", "VideoToolbox", "vt::errors::COLOR_SYNC_TRANSFORM_CONVERT_FAILED", ""),
+    Entry::new(-12918, "kVTCouldNotCreateColorCorrectionDataErr", "VideoToolbox", "vt::errors::COULD_NOT_CREATE_COLOR_CORRECTION_DATA", ""),
+    Entry::new(-12917, "kVTInsufficientSourceColorDataErr", "VideoToolbox", "vt::errors::INSUFFICIENT_SOURCE_COLOR_DATA", ""),
+    Entry::new(-12916, "kVTFormatDescriptionChangeNotSupportedErr", "VideoToolbox", "vt::errors::FORMAT_DESCRIPTION_CHANGE_NOT_SUPPORTED", ""),
+    Entry::new(-12915, "kVTVideoEncoderNotAvailableNowErr", "VideoToolbox", "vt::errors::VIDEO_ENCODER_NOT_AVAILABLE_NOW", ""),
+    Entry::new(-12914, "kVTImageRotationNotSupportedErr", "VideoToolbox", "vt::errors::IMAGE_ROTATION_NOT_SUPPORTED", ""),
+    Entry::new(-12913, "kVTVideoDecoderNotAvailableNowErr", "VideoToolbox", "vt::errors::VIDEO_DECODER_NOT_AVAILABLE_NOW", ""),
+    Entry::new(-12912, "kVTVideoEncoderMalfunctionErr", "VideoToolbox", "vt::errors::VIDEO_ENCODER_MALFUNCTION", ""),
+    Entry::new(-12911, "kVTVideoDecoderMalfunctionErr", "VideoToolbox", "vt::errors::VIDEO_DECODER_MALFUNCTION", ""),
+    Entry::new(-12910, "kVTVideoDecoderUnsupportedDataFormatErr", "VideoToolbox", "vt::errors::VIDEO_DECODER_UNSUPPORTED_DATA_FORMAT", ""),
+    Entry::new(-12909, "kVTVideoDecoderBadDataErr", "VideoToolbox", "vt::errors::VIDEO_DECODER_BAD_DATA", ""),
+    Entry::new(-12908, "kVTCouldNotFindVideoEncoderErr", "VideoToolbox", "vt::errors::COULD_NOT_FIND_VIDEO_ENCODER", ""),
+    Entry::new(-12907, "kVTCouldNotCreateInstanceErr", "VideoToolbox", "vt::errors::COULD_NOT_CREATE_INSTANCE", ""),
+    Entry::new(-12906, "kVTCouldNotFindVideoDecoderErr", "VideoToolbox", "vt::errors::COULD_NOT_FIND_VIDEO_DECODER", ""),
+    Entry::new(-12905, "kVTPixelTransferNotSupportedErr", "VideoToolbox", "vt::errors::PIXEL_TRANSFER_NOT_SUPPORTED", ""),
+    Entry::new(-12904, "kVTAllocationFailedErr", "VideoToolbox", "vt::errors::ALLOC_FAILED", ""),
+    Entry::new(-12903, "kVTInvalidSessionErr", "VideoToolbox", "vt::errors::INVALID_SESSION", ""),
+    Entry::new(-12902, "kVTParameterErr", "VideoToolbox", "vt::errors::PARAMETER", ""),
+    Entry::new(-12901, "kVTPropertyReadOnlyErr", "VideoToolbox", "vt::errors::PROPERTY_READ_ONLY", ""),
+    Entry::new(-12900, "kVTPropertyNotSupportedErr", "VideoToolbox", "vt::errors::PROPERTY_NOT_SUPPORTED", ""),
+    Entry::new(-12773, "kCMSimpleQueueError_QueueIsFull", "CoreMedia", "cm::simple_queue_err::QUEUE_IS_FULL", "Operation failed because queue was full"),
+    Entry::new(-12772, "kCMSimpleQueueError_ParameterOutOfRange", "CoreMedia", "cm::simple_queue_err::PARAM_OUT_OF_RANGE", "An out-of-range value was passed for a parameter with a restricted valid range"),
+    Entry::new(-12771, "kCMSimpleQueueError_RequiredParameterMissing", "CoreMedia", "cm::simple_queue_err::REQUIRED_PARAM_MISSING", "NULL or 0 was passed for a required parameter"),
+    Entry::new(-12770, "kCMSimpleQueueError_AllocationFailed", "CoreMedia", "cm::simple_queue_err::ALLOC_FAILED", "An allocation failed"),
+    Entry::new(-12769, "kCMBufferQueueError_InvalidBuffer", "CoreMedia", "cm::buf_queue_err::INVALID_BUF", ""),
+    Entry::new(-12768, "kCMBufferQueueError_InvalidTriggerToken", "CoreMedia", "cm::buf_queue_err::INVALID_TRIGGER_TOKEN", ""),
+    Entry::new(-12767, "kCMBufferQueueError_InvalidTriggerCondition", "CoreMedia", "cm::buf_queue_err::INVALID_TRIGGER_CONDITION", ""),
+    Entry::new(-12766, "kCMBufferQueueError_CannotModifyQueueFromTriggerCallback", "CoreMedia", "cm::buf_queue_err::CANNOT_MODIFY_QUEUE_FROM_TRIGGER_CALLBACK", ""),
+    Entry::new(-12765, "kCMBufferQueueError_BadTriggerDuration", "CoreMedia", "cm::buf_queue_err::BAD_TRIGGER_DURATION", ""),
+    Entry::new(-12764, "kCMBufferQueueError_QueueIsFull", "CoreMedia", "cm::buf_queue_err::QUEUE_IS_FULL", ""),
+    Entry::new(-12763, "kCMBufferQueueError_EnqueueAfterEndOfData", "CoreMedia", "cm::buf_queue_err::ENQUEUE_AFTER_END_OF_DATA", ""),
+    Entry::new(-12762, "kCMBufferQueueError_InvalidCMBufferCallbacksStruct", "CoreMedia", "cm::buf_queue_err::INVALID_CM_BUF_CALLBACKS_STRUCT", ""),
+    Entry::new(-12761, "kCMBufferQueueError_RequiredParameterMissing", "CoreMedia", "cm::buf_queue_err::REQUIRED_PARAM_MISSING", ""),
+    Entry::new(-12760, "kCMBufferQueueError_AllocationFailed", "CoreMedia", "cm::buf_queue_err::ALLOC_FAILED", ""),
+    Entry::new(-12757, "kCMTimebaseError_ReadOnly", "CoreMedia", "cm::sync::timebase_err::READ_ONLY", ""),
+    Entry::new(-12756, "kCMClockError_UnsupportedOperation", "CoreMedia", "cm::sync::clock_err::UNSUPPORTED_OP", ""),
+    Entry::new(-12755, "kCMSyncError_RateMustBeNonZero", "CoreMedia", "cm::sync::sync_err::RATE_MUST_BE_NON_ZERO", ""),
+    Entry::new(-12754, "kCMSyncError_AllocationFailed", "CoreMedia", "cm::sync::sync_err::ALLOC_FAILED", ""),
+    Entry::new(-12753, "kCMSyncError_InvalidParameter", "CoreMedia", "cm::sync::sync_err::INVALID_PARAMETER", ""),
+    Entry::new(-12752, "kCMSyncError_MissingRequiredParameter", "CoreMedia", "cm::sync::sync_err::MISSING_REQUIRED_PARAMETER", ""),
+    Entry::new(-12751, "kCMTimebaseError_TimerIntervalTooShort", "CoreMedia", "cm::sync::timebase_err::TIMER_INTERVAL_TOO_SHORT", ""),
+    Entry::new(-12750, "kCMTimebaseError_AllocationFailed", "CoreMedia", "cm::sync::timebase_err::ALLOC_FAILED", ""),
+    Entry::new(-12749, "kCMTimebaseError_InvalidParameter", "CoreMedia", "cm::sync::timebase_err::INVALID_PARAM", ""),
+    Entry::new(-12748, "kCMTimebaseError_MissingRequiredParameter", "CoreMedia", "cm::sync::timebase_err::MISSING_REQUIRED_PARAM", ""),
+    Entry::new(-12747, "kCMClockError_AllocationFailed", "CoreMedia", "cm::sync::clock_err::ALLOC_FAILED", ""),
+    Entry::new(-12746, "kCMClockError_InvalidParameter", "CoreMedia", "cm::sync::clock_err::INVALID_PARAM", ""),
+    Entry::new(-12745, "kCMClockError_MissingRequiredParameter", "CoreMedia", "cm::sync::clock_err::MISSING_REQUIRED_PARAM", ""),
+    Entry::new(-12744, "kCMSampleBufferError_Invalidated", "CoreMedia", "cm::sample_buf_err::INVALIDATED", "the sample buffer was invalidated"),
+    Entry::new(-12743, "kCMSampleBufferError_InvalidMediaFormat", "CoreMedia", "cm::sample_buf_err::INVALID_MEDIA_FORMAT", "The format of the given media does not match the given format description (eg"),
+    Entry::new(-12742, "kCMSampleBufferError_InvalidSampleData", "CoreMedia", "cm::sample_buf_err::INVALID_SAMPLE_DATA", "Buffer contains bad data"),
+    Entry::new(-12741, "kCMSampleBufferError_InvalidMediaTypeForOperation", "CoreMedia", "cm::sample_buf_err::INVALID_MEDIA_TYPE_FOR_OPERATION", "the media type specified by a format description is not valid for the given operation (eg"),
+    Entry::new(-12740, "kCMSampleBufferError_SampleTimingInfoInvalid", "CoreMedia", "cm::sample_buf_err::SAMPLE_TIMING_INFO_INVALID", "buffer unexpectedly contains a non-numeric sample timing info"),
+    Entry::new(-12739, "kCMSampleBufferError_CannotSubdivide", "CoreMedia", "cm::sample_buf_err::CANNOT_SUBDIVIDE", "Sample buffer does not contain sample sizes"),
+    Entry::new(-12738, "kCMSampleBufferError_InvalidEntryCount", "CoreMedia", "cm::sample_buf_err::INVALID_ENTRY_COUNT", "Timing info or size array entry count was not 0, 1, or numSamples"),
+    Entry::new(-12737, "kCMSampleBufferError_ArrayTooSmall", "CoreMedia", "cm::sample_buf_err::ARRAY_TOO_SMALL", "Output array was not large enough for the array being requested"),
+    Entry::new(-12736, "kCMSampleBufferError_BufferHasNoSampleTimingInfo", "CoreMedia", "cm::sample_buf_err::BUFFER_HAS_NO_SAMPLE_TIMING_INFO", "Attempt to get sample timing information when there was none"),
+    Entry::new(-12735, "kCMSampleBufferError_BufferHasNoSampleSizes", "CoreMedia", "cm::sample_buf_err::BUFFER_HAS_NO_SAMPLE_SIZES", "Attempt to get sample size information when there was none"),
+    Entry::new(-12734, "kCMSampleBufferError_SampleIndexOutOfRange", "CoreMedia", "cm::sample_buf_err::SAMPLE_INDEX_OUT_OF_RANGE", "Sample index was not between 0 and numSamples-1, inclusive"),
+    Entry::new(-12733, "kCMSampleBufferError_BufferNotReady", "CoreMedia", "cm::sample_buf_err::BUFFER_NOT_READY", "Buffer could not be made ready"),
+    Entry::new(-12732, "kCMSampleBufferError_AlreadyHasDataBuffer", "CoreMedia", "cm::sample_buf_err::ALREADY_HAS_DATA_BUFFER", "Attempt was made to set a dataBuffer on a cm::SampleBuffer that already has one"),
+    Entry::new(-12731, "kCMSampleBufferError_RequiredParameterMissing", "CoreMedia", "cm::sample_buf_err::REQUIRED_PARAMETER_MISSING", "NULL or 0 was passed for a required parameter"),
+    Entry::new(-12730, "kCMSampleBufferError_AllocationFailed", "CoreMedia", "cm::sample_buf_err::ALLOC_FAILED", "An allocation failed"),
+    Entry::new(-12719, "kCMFormatDescriptionBridgeError_InvalidSlice", "CoreMedia", "cm::format_desc_bridge_err::INVALID_SLICE", "Returned when the slice has an invalid value"),
+    Entry::new(-12718, "kCMFormatDescriptionError_ValueNotAvailable", "CoreMedia", "cm::format_desc_err::VALUE_NOT_AVAILABLE", "Returned when the cm::FormatDesc does not carry such a value"),
+    Entry::new(-12717, "kCMFormatDescriptionBridgeError_UnsupportedSampleDescriptionFlavor", "CoreMedia", "cm::format_desc_bridge_err::UNSUPPORTED_SAMPLE_DESCRIPTION_FLAVOR", "Returned when the sample description is unsupported for the specified format flavor"),
+    Entry::new(-12716, "kCMFormatDescriptionBridgeError_IncompatibleFormatDescription", "CoreMedia", "cm::format_desc_bridge_err::INCOMPATIBLE_FORMAT_DESCRIPTION", "Returned when the format description has an incompatible format (e.g"),
+    Entry::new(-12715, "kCMFormatDescriptionBridgeError_InvalidFormatDescription", "CoreMedia", "cm::format_desc_bridge_err::INVALID_FORMAT_DESCRIPTION", "Returned when the format description is invalid (e.g"),
+    Entry::new(-12714, "kCMFormatDescriptionBridgeError_InvalidSerializedSampleDescription", "CoreMedia", "cm::format_desc_bridge_err::INVALID_SERIALIZED_SAMPLE_DESCRIPTION", "Returned when the sample description is invalid (e.g"),
+    Entry::new(-12713, "kCMFormatDescriptionBridgeError_AllocationFailed", "CoreMedia", "cm::format_desc_bridge_err::ALLOC_FAILED", "Returned when an allocation fails"),
+    Entry::new(-12712, "kCMFormatDescriptionBridgeError_InvalidParameter", "CoreMedia", "cm::format_desc_bridge_err::INVALID_PARAM", "Invalid parameter"),
+    Entry::new(-12711, "kCMFormatDescriptionError_AllocationFailed", "CoreMedia", "cm::format_desc_err::ALLOC_FAILED", "Returned when an allocation fails"),
+    Entry::new(-12710, "kCMFormatDescriptionError_InvalidParameter", "CoreMedia", "cm::format_desc_err::INVALID_PARAM", "Invalid parameter"),
+    Entry::new(-12708, "kCMBlockBufferInsufficientSpaceErr", "CoreMedia", "cm::block_buf_err::INSUFFICIENT_SPACE", ""),
+    Entry::new(-12707, "kCMBlockBufferUnallocatedBlockErr", "CoreMedia", "cm::block_buf_err::UNALLOCATED_BLOCK", "An unallocated memory block was encountered"),
+    Entry::new(-12706, "kCMBlockBufferEmptyBBufErr", "CoreMedia", "cm::block_buf_err::EMPTY_BUF", "Expected a non-empty cm::BlockBuffer"),
+    Entry::new(-12705, "kCMBlockBufferBadPointerParameterErr", "CoreMedia", "cm::block_buf_err::BAD_POINTER_PARAM", "A pointer parameter (e.g"),
+    Entry::new(-12704, "kCMBlockBufferBadLengthParameterErr", "CoreMedia", "cm::block_buf_err::BAD_LEN_PARAM", "The length provided to an API is out of the range of the relevent cm::BlockBuffer, or is not allowed to be zero"),
+    Entry::new(-12703, "kCMBlockBufferBadOffsetParameterErr", "CoreMedia", "cm::block_buf_err::BAD_OFFSET_PARAM", "The offset provided to an API is out of the range of the relevent cm::BlockBuffer"),
+    Entry::new(-12702, "kCMBlockBufferBadCustomBlockSourceErr", "CoreMedia", "cm::block_buf_err::BAD_CUSTOM_BLOCK_SRC", "The custom block source’s Allocate() routine was NULL when an allocation was attempted"),
+    Entry::new(-12701, "kCMBlockBufferBlockAllocationFailedErr", "CoreMedia", "cm::block_buf_err::BLOCK_ALLOC_FAILED", "Returned when the allocator provided to allocate a memory block (as distinct from cm::BlockBuffer structures) fails"),
+    Entry::new(-12700, "kCMBlockBufferStructureAllocationFailedErr", "CoreMedia", "cm::block_buf_err::STRUCT_ALLOC_FAILED", "Returned when a cm::BlockBuffer-creating API gets a failure from the cf::Allocator provided for cm::BlockBuffer construction"),
+    Entry::new(-12219, "kVTColorCorrectionImageRotationFailedErr", "VideoToolbox", "vt::errors::COLOR_CORRECTION_IMAGE_ROTATION_FAILED", ""),
+    Entry::new(-12218, "kVTPixelTransferNotPermittedErr", "VideoToolbox", "vt::errors::PIXEL_TRANSFER_NOT_PERMITTED", ""),
+    Entry::new(-12217, "kVTCouldNotFindTemporalFilterErr", "VideoToolbox", "vt::errors::COULD_NOT_FIND_TEMPORAL_FILTER", ""),
+    Entry::new(-12216, "kVTFrameSiloInvalidTimeRangeErr", "VideoToolbox", "vt::errors::FRAME_SILO_INVALID_TIME_RANGE", ""),
+    Entry::new(-12215, "kVTFrameSiloInvalidTimeStampErr", "VideoToolbox", "vt::errors::FRAME_SILO_INVALID_TIME_STAMP", ""),
+    Entry::new(-12214, "kVTMultiPassStorageInvalidErr", "VideoToolbox", "vt::errors::MULTI_PASS_STORAGE_INVALID", ""),
+    Entry::new(-12213, "kVTMultiPassStorageIdentifierMismatchErr", "VideoToolbox", "vt::errors::MULTI_PASS_STORAGE_IDENTIFIER_MISMATCH", ""),
+    Entry::new(-12212, "kVTColorCorrectionPixelTransferFailedErr", "VideoToolbox", "vt::errors::COLOR_CORRECTION_PIXEL_TRANSFER_FAILED", ""),
+    Entry::new(-12211, "kVTVideoEncoderAuthorizationErr", "VideoToolbox", "vt::errors::VIDEO_ENCODER_AUTHORIZATION", ""),
+    Entry::new(-12210, "kVTVideoDecoderAuthorizationErr", "VideoToolbox", "vt::errors::VIDEO_DECODER_AUTHORIZATION", ""),
+    Entry::new(-10879, "kAudioUnitErr_InvalidProperty", "AudioToolbox", "at::audio::unit::err::INVALID_PROPERTY", "The property is not supported"),
+    Entry::new(-10878, "kAudioUnitErr_InvalidParameter", "AudioToolbox", "at::audio::unit::err::INVALID_PARAM", "The parameter is not supported"),
+    Entry::new(-10877, "kAudioUnitErr_InvalidElement", "AudioToolbox", "at::audio::unit::err::INVALID_ELEMENT", "The specified element is not valid"),
+    Entry::new(-10876, "kAudioUnitErr_NoConnection", "AudioToolbox", "at::audio::unit::err::NO_CONNECTION", "There is no connection (generally an audio unit is asked to render but it has not input from which to gather data)"),
+    Entry::new(-10875, "kAudioUnitErr_FailedInitialization", "AudioToolbox", "at::audio::unit::err::FAILED_INITIALIZATION", "The audio unit is unable to be initialized"),
+    Entry::new(-10874, "kAudioUnitErr_TooManyFramesToProcess", "AudioToolbox", "at::audio::unit::err::TOO_MANY_FRAMES_TO_PROCESS", "When an audio unit is initialized it has a value which specifies the max number of frames it will be asked to render at any given time"),
+    Entry::new(-10871, "kAudioUnitErr_InvalidFile", "AudioToolbox", "at::audio::unit::err::INVALID_FILE", "If an audio unit uses external files as a data source, this error is returned if a file is invalid (Apple's DLS synth returns this error)"),
+    Entry::new(-10870, "kAudioUnitErr_UnknownFileType", "AudioToolbox", "at::audio::unit::err::UNKNOWN_FILE_TYPE", "If an audio unit uses external files as a data source, this error is returned if a file is invalid (Apple's DLS synth returns this error)"),
+    Entry::new(-10869, "kAudioUnitErr_FileNotSpecified", "AudioToolbox", "at::audio::unit::err::FILE_NOT_SPECIFIED", "If an audio unit uses external files as a data source, this error is returned if a file hasn't been set on it (Apple's DLS synth returns this error)"),
+    Entry::new(-10868, "kAudioUnitErr_FormatNotSupported", "AudioToolbox", "at::audio::unit::err::FORMAT_NOT_SUPPORTED", "Returned if an input or output format is not supported"),
+    Entry::new(-10867, "kAudioUnitErr_Uninitialized", "AudioToolbox", "at::audio::unit::err::UNINITIALIZED", "Returned if an operation requires an audio unit to be initialized and it is not"),
+    Entry::new(-10866, "kAudioUnitErr_InvalidScope", "AudioToolbox", "at::audio::unit::err::INVALID_SCOPE", "The specified scope is invalid"),
+    Entry::new(-10865, "kAudioUnitErr_PropertyNotWritable", "AudioToolbox", "at::audio::unit::err::PROPERTY_NOT_WRITABLE", "The property cannot be written"),
+    Entry::new(-10863, "kAudioUnitErr_CannotDoInCurrentContext", "AudioToolbox", "at::audio::unit::err::CANNOT_DO_IN_CURRENT_CONTEXT", "Returned when an audio unit is in a state where it can't perform the requested action now - but it could later"),
+    Entry::new(-10851, "kAudioUnitErr_InvalidPropertyValue", "AudioToolbox", "at::audio::unit::err::INVALID_PROPERTY_VALUE", "The property is valid, but the value of the property being provided is not"),
+    Entry::new(-10850, "kAudioUnitErr_PropertyNotInUse", "AudioToolbox", "at::audio::unit::err::PROPERTY_NOT_IN_USE", "Returned when a property is valid, but it hasn't been set to a valid value at this time"),
+    Entry::new(-10849, "kAudioUnitErr_Initialized", "AudioToolbox", "at::audio::unit::err::INITIALIZED", "Indicates the operation cannot be performed because the audio unit is initialized"),
+    Entry::new(-10848, "kAudioUnitErr_InvalidOfflineRender", "AudioToolbox", "at::audio::unit::err::INVALID_OFFLINE_RENDER", "Used to indicate that the offline render operation is invalid"),
+    Entry::new(-10847, "kAudioUnitErr_Unauthorized", "AudioToolbox", "at::audio::unit::err::UNAUTHORIZED", "Returned by either Open or Initialize, this error is used to indicate that the audio unit is not authorised, that it cannot be used"),
+    Entry::new(-9890, "errSSLEarlyDataRejected", "Security", "sec::ssl_err::EARLY_DATA_REJECTED", "Early application data rejected by peer"),
+    Entry::new(-9886, "errSSLATSCertificateTrustViolation", "Security", "sec::ssl_err::ATS_CERT_TRUST_VIOLATION", "ATS violation: peer certificate is not issued by trusted peer"),
+    Entry::new(-9885, "errSSLATSCertificateHashAlgorithmViolation", "Security", "sec::ssl_err::ATS_CERT_HASH_ALGORITHM_VIOLATION", "ATS violation: peer certificate hash algorithm is not ATS compliant"),
+    Entry::new(-9884, "errSSLATSLeafCertificateHashAlgorithmViolation", "Security", "sec::ssl_err::ATS_LEAF_CERT_HASH_ALGORITHM_VIOLATION", "ATS violation: peer leaf certificate hash algorithm is not ATS compliant"),
+    Entry::new(-9883, "errSSLATSMinimumKeySizeViolation", "Security", "sec::ssl_err::ATS_MIN_KEY_SIZE_VIOLATION", "ATS violation: peer key size is not ATS compliant"),
+    Entry::new(-9882, "errSSLATSCiphersuiteViolation", "Security", "sec::ssl_err::ATS_CIPHERSUITE_VIOLATION", "ATS violation: selected ciphersuite is not ATS compliant"),
+    Entry::new(-9881, "errSSLATSMinimumVersionViolation", "Security", "sec::ssl_err::ATS_MIN_VERSION_VIOLATION", "ATS violation: minimum protocol version is not ATS compliant"),
+    Entry::new(-9880, "errSSLATSViolation", "Security", "sec::ssl_err::ATS_VIOLATION", "ATS violation"),
+    Entry::new(-9865, "errSSLUnrecognizedName", "Security", "sec::ssl_err::UNRECOGNIZED_NAME", "Unknown or unrecognized name"),
+    Entry::new(-9864, "errSSLUnknownPSKIdentity", "Security", "sec::ssl_err::UNKNOWN_PSK_IDENTITY", "Unknown PSK identity"),
+    Entry::new(-9863, "errSSLCertificateRequired", "Security", "sec::ssl_err::CERT_REQUIRED", "Certificate required"),
+    Entry::new(-9862, "errSSLBadCertificateStatusResponse", "Security", "sec::ssl_err::BAD_CERT_STATUS_RESPONSE", "Bad OCSP response"),
+    Entry::new(-9861, "errSSLMissingExtension", "Security", "sec::ssl_err::MISSING_EXTENSION", "Missing extension"),
+    Entry::new(-9860, "errSSLInappropriateFallback", "Security", "sec::ssl_err::INAPPROPRIATE_FALLBACK", "Inappropriate fallback"),
+    Entry::new(-9859, "errSSLDecodeError", "Security", "sec::ssl_err::DECODE_ERROR", "Decode failed"),
+    Entry::new(-9858, "errSSLHandshakeFail", "Security", "sec::ssl_err::HANDSHAKE_FAIL", "Handshake failed"),
+    Entry::new(-9857, "errSSLDecompressFail", "Security", "sec::ssl_err::DECOMPRESS_FAIL", "Decompression failed"),
+    Entry::new(-9856, "errSSLUnexpectedMessage", "Security", "sec::ssl_err::UNEXPECTED_MESSAGE", "Peer rejected unexpected message"),
+    Entry::new(-9855, "errSSLUnsupportedExtension", "Security", "sec::ssl_err::UNSUPPORTED_EXTENSION", "Unsupported TLS extension"),
+    Entry::new(-9854, "errSSLConfigurationFailed", "Security", "sec::ssl_err::CONFIGURATION_FAILED", "TLS configuration failed"),
+    Entry::new(-9853, "errSSLNetworkTimeout", "Security", "sec::ssl_err::NETWORK_TIMEOUT", "Network timeout triggered"),
+    Entry::new(-9852, "errSSLTransportReset", "Security", "sec::ssl_err::TRANSPORT_RESET", "Transport (socket) shutdown, e.g., TCP RST or FIN"),
+    Entry::new(-9851, "errSSLClientHelloReceived", "Security", "sec::ssl_err::CLIENT_HELLO_RECEIVED", "SNI"),
+    Entry::new(-9850, "errSSLWeakPeerEphemeralDHKey", "Security", "sec::ssl_err::WEAK_PEER_EPHEMERAL_DH_KEY", "Weak ephemeral dh key"),
+    Entry::new(-9849, "errSSLUnexpectedRecord", "Security", "sec::ssl_err::UNEXPECTED_RECORD", "Unexpected (skipped) record in DTLS"),
+    Entry::new(-9848, "errSSLBadConfiguration", "Security", "sec::ssl_err::BAD_CONFIGURATION", "Configuration error"),
+    Entry::new(-9847, "errSSLRecordOverflow", "Security", "sec::ssl_err::RECORD_OVERFLOW", "Record overflow"),
+    Entry::new(-9846, "errSSLBadRecordMac", "Security", "sec::ssl_err::BAD_RECORD_MAC", "Bad MAC"),
+    Entry::new(-9845, "errSSLDecryptionFail", "Security", "sec::ssl_err::DECRYPTION_FAIL", "Decryption failure"),
+    Entry::new(-9844, "errSSLConnectionRefused", "Security", "sec::ssl_err::CONNECTION_REFUSED", "Peer dropped connection before responding"),
+    Entry::new(-9843, "errSSLHostNameMismatch", "Security", "sec::ssl_err::HOST_NAME_MISMATCH", "Peer host name mismatch"),
+    Entry::new(-9842, "errSSLClientCertRequested", "Security", "sec::ssl_err::CLIENT_CERT_REQUESTED", "Server has requested a client cert"),
+    Entry::new(-9841, "errSSLPeerAuthCompleted", "Security", "sec::ssl_err::PEER_AUTH_COMPLETED", "Peer cert is valid, or was ignored if verification disabled"),
+    Entry::new(-9840, "errSSLPeerNoRenegotiation", "Security", "sec::ssl_err::PEER_NO_RENEGOTIATION", "No renegotiation allowed"),
+    Entry::new(-9839, "errSSLPeerUserCancelled", "Security", "sec::ssl_err::PEER_USER_CANCELLED", "User canceled"),
+    Entry::new(-9838, "errSSLPeerInternalError", "Security", "sec::ssl_err::PEER_INTERNAL_ERR", "Internal error"),
+    Entry::new(-9837, "errSSLPeerInsufficientSecurity", "Security", "sec::ssl_err::PEER_INSUFFICIENT_SECURITY", "Insufficient security"),
+    Entry::new(-9836, "errSSLPeerProtocolVersion", "Security", "sec::ssl_err::PEER_PROTOCOL_VERSION", "Bad protocol version"),
+    Entry::new(-9835, "errSSLPeerExportRestriction", "Security", "sec::ssl_err::PEER_EXPORT_RESTRICTION", "Export restriction"),
+    Entry::new(-9834, "errSSLPeerDecryptError", "Security", "sec::ssl_err::PEER_DECRYPT_ERROR", "Decryption error"),
+    Entry::new(-9833, "errSSLPeerDecodeError", "Security", "sec::ssl_err::PEER_DECODE_ERROR", "Decoding error"),
+    Entry::new(-9832, "errSSLPeerAccessDenied", "Security", "sec::ssl_err::PEER_ACCESS_DENIED", "Access denied"),
+    Entry::new(-9831, "errSSLPeerUnknownCA", "Security", "sec::ssl_err::PEER_UNKNOWN_CA", "Unknown Cert Authority"),
+    Entry::new(-9830, "errSSLIllegalParam", "Security", "sec::ssl_err::ILLEGAL_PARAM", "Illegal parameter"),
+    Entry::new(-9829, "errSSLPeerCertUnknown", "Security", "sec::ssl_err::PEER_CERT_UNKNOWN", "Unknown certificate"),
+    Entry::new(-9828, "errSSLPeerCertExpired", "Security", "sec::ssl_err::PEER_CERT_EXPIRED", "Certificate expired"),
+    Entry::new(-9827, "errSSLPeerCertRevoked", "Security", "sec::ssl_err::PEER_CERT_REVOKED", "Certificate revoked"),
+    Entry::new(-9826, "errSSLPeerUnsupportedCert", "Security", "sec::ssl_err::PEER_UNSUPPORTED_CERT", "Bad unsupported cert format"),
+    Entry::new(-9825, "errSSLPeerBadCert", "Security", "sec::ssl_err::PEER_BAD_CERT", "Misc"),
+    Entry::new(-9824, "errSSLPeerHandshakeFail", "Security", "sec::ssl_err::PEER_HANDSHAKE_FAIL", "Handshake failure"),
+    Entry::new(-9823, "errSSLPeerDecompressFail", "Security", "sec::ssl_err::PEER_DECOMPRESS_FAIL", "Decompression failure"),
+    Entry::new(-9822, "errSSLPeerRecordOverflow", "Security", "sec::ssl_err::PEER_RECORD_OVERFLOW", "Record overflow"),
+    Entry::new(-9821, "errSSLPeerDecryptionFail", "Security", "sec::ssl_err::PEER_DECRYPTION_FAIL", "Decryption failed"),
+    Entry::new(-9820, "errSSLPeerBadRecordMac", "Security", "sec::ssl_err::PEER_BAD_RECORD_MAC", "Bad MAC"),
+    Entry::new(-9819, "errSSLPeerUnexpectedMsg", "Security", "sec::ssl_err::PEER_UNEXPECTED_MSG", "Unexpected message received"),
+    Entry::new(-9818, "errSSLBadCipherSuite", "Security", "sec::ssl_err::BAD_CIPHER_SUITE", "bad SSLCipherSuite"),
+    Entry::new(-9817, "errSSLBufferOverflow", "Security", "sec::ssl_err::BUF_OVERFLOW", "Insufficient buffer provided"),
+    Entry::new(-9816, "errSSLClosedNoNotify", "Security", "sec::ssl_err::CLOSED_NO_NOTIFY", "Server closed session with no notification"),
+    Entry::new(-9815, "errSSLCertNotYetValid", "Security", "sec::ssl_err::CERT_NOT_YET_VALID", "Chain had a cert not yet valid"),
+    Entry::new(-9814, "errSSLCertExpired", "Security", "sec::ssl_err::CERT_EXPIRED", "Chain had an expired cert"),
+    Entry::new(-9813, "errSSLNoRootCert", "Security", "sec::ssl_err::NO_ROOT_CERT", "Cert chain not verified by root"),
+    Entry::new(-9812, "errSSLUnknownRootCert", "Security", "sec::ssl_err::UNKNOWN_ROOT_CERT", "Valid cert chain, untrusted root"),
+    Entry::new(-9811, "errSSLModuleAttach", "Security", "sec::ssl_err::MODULE_ATTACH", "Module attach failure"),
+    Entry::new(-9810, "errSSLInternal", "Security", "sec::ssl_err::INTERNAL", "Internal error"),
+    Entry::new(-9809, "errSSLCrypto", "Security", "sec::ssl_err::CRYPTO", "Underlying cryptographic error"),
+    Entry::new(-9808, "errSSLBadCert", "Security", "sec::ssl_err::BAD_CERT", "Bad certificate format"),
+    Entry::new(-9807, "errSSLXCertChainInvalid", "Security", "sec::ssl_err::CERT_CHAIN_INVALID", "Invalid certificate chain"),
+    Entry::new(-9806, "errSSLClosedAbort", "Security", "sec::ssl_err::CLOSED_ABORT", "Connection closed via error"),
+    Entry::new(-9805, "errSSLClosedGraceful", "Security", "sec::ssl_err::CLOSED_GRACEFUL", "Connection closed gracefully"),
+    Entry::new(-9804, "errSSLSessionNotFound", "Security", "sec::ssl_err::SESSION_NOT_FOUND", "Attempt to restore an unknown session"),
+    Entry::new(-9803, "errSSLWouldBlock", "Security", "sec::ssl_err::WOULD_BLOCK", "I/O would block (not fatal)"),
+    Entry::new(-9802, "errSSLFatalAlert", "Security", "sec::ssl_err::FATAL_ALERT", "Fatal alert"),
+    Entry::new(-9801, "errSSLNegotiation", "Security", "sec::ssl_err::NEGOTIATION", "Cipher Suite negotiation failure"),
+    Entry::new(-9800, "errSSLProtocol", "Security", "sec::ssl_err::PROTOCOL", "SSL protocol error"),
+    Entry::new(-6699, "kCVReturnLast", "CoreVideo", "cv::err::LAST", "Placeholder to mark the end of the range of cv::err codes"),
+    Entry::new(-6692, "kCVReturnRetry", "CoreVideo", "cv::err::RETRY", "A scan hasn't completely traversed the CVBufferPool due to a concurrent operation"),
+    Entry::new(-6691, "kCVReturnInvalidPoolAttributes", "CoreVideo", "cv::err::INVALID_POOL_ATTRS", "A cv::BufPool cannot be created with the given attributes"),
+    Entry::new(-6690, "kCVReturnPoolAllocationFailed", "CoreVideo", "cv::err::POOL_ALLOC_FAILED", "The allocation for the buffer pool failed"),
+    Entry::new(-6689, "kCVReturnWouldExceedAllocationThreshold", "CoreVideo", "cv::err::WOULD_EXCEED_ALLOCATION_THRESHOLD", "The allocation request failed because it would have exceeded a specified allocation threshold (see kCVPixelBufferPoolAllocationThresholdKey)"),
+    Entry::new(-6684, "kCVReturnPixelBufferNotMetalCompatible", "CoreVideo", "cv::err::PIXEL_BUF_NOT_METAL_COMPATIBLE", "The Buffer cannot be used with Metal as either its size, pixelformat or attributes are not supported by Metal"),
+    Entry::new(-6683, "kCVReturnPixelBufferNotOpenGLCompatible", "CoreVideo", "cv::err::PIXEL_BUF_NOT_OPEN_GL_COMPATIBLE", "The Buffer cannot be used with OpenGL as either its size, pixelformat or attributes are not supported by OpenGL"),
+    Entry::new(-6682, "kCVReturnInvalidPixelBufferAttributes", "CoreVideo", "cv::err::INVALID_PIXEL_BUF_ATTRS", "A cv::Buffer cannot be created with the given attributes"),
+    Entry::new(-6681, "kCVReturnInvalidSize", "CoreVideo", "cv::err::INVALID_SIZE", "The requested size (most likely too big) is not supported for the cv::Buf type"),
+    Entry::new(-6680, "kCVReturnInvalidPixelFormat", "CoreVideo", "cv::err::INVALID_PIXEL_FORMAT", "The requested pixelformat is not supported for the cv::Buf type"),
+    Entry::new(-6673, "kCVReturnDisplayLinkCallbacksNotSet", "CoreVideo", "cv::err::DISPLAY_LINK_CALLBACKS_NOT_SET", "The output callback is not set"),
+    Entry::new(-6672, "kCVReturnDisplayLinkNotRunning", "CoreVideo", "cv::err::DISPLAY_LINK_NOT_RUNNING", "The cv::DisplayLink has not been started"),
+    Entry::new(-6671, "kCVReturnDisplayLinkAlreadyRunning", "CoreVideo", "cv::err::DISPLAY_LINK_ALREADY_RUNNING", "The cv::DisplayLink is already started and running"),
+    Entry::new(-6670, "kCVReturnInvalidDisplay", "CoreVideo", "cv::err::INVALID_DISPLAY", "A cv::DisplayLink cannot be created for the given DisplayRef"),
+    Entry::new(-6663, "kCVReturnUnsupported", "CoreVideo", "cv::err::UNSUPPORTED", ""),
+    Entry::new(-6662, "kCVReturnAllocationFailed", "CoreVideo", "cv::err::ALLOC_FAILED", "The allocation for a buffer or buffer pool failed"),
+    Entry::new(-6661, "kCVReturnInvalidArgument", "CoreVideo", "cv::err::INVALID_ARG", "At least one of the arguments passed in is not valid"),
+    Entry::new(-6660, "kCVReturnError", "CoreVideo", "cv::err::ERROR", ""),
+    Entry::new(-6660, "kCVReturnFirst", "CoreVideo", "cv::err::FIRST", "Placeholder to mark the beginning of the range of cv::err codes"),
+    Entry::new(-4960, "errSecCoreFoundationUnknown", "Security", "sec::err::CORE_FOUNDATION_UNKNOWN", ""),
+    Entry::new(-2070, "errSecInternalComponent", "Security", "sec::err::INTERNAL_COMPONENT", ""),
+    Entry::new(-909, "errSecBadReq", "Security", "sec::err::BAD_REQ", "Bad parameter or invalid state for operation"),
+    Entry::new(-128, "errSecUserCanceled", "Security", "sec::err::USER_CANCELED", "User canceled the operation"),
+    Entry::new(-108, "kAudio_MemFullError", "CoreAudioTypes", "cat::audio::err::MEM_FULL", "Not enough room in heap zone"),
+    Entry::new(-108, "errSecAllocate", "Security", "sec::err::ALLOCATE", "Failed to allocate memory"),
+    Entry::new(-61, "errSecWrPerm", "Security", "sec::err::WR_PERM", "Write permissions error"),
+    Entry::new(-54, "kAudio_FilePermissionError", "CoreAudioTypes", "cat::audio::err::FILE_PERMISSION", "File cannot be opened due to either file, directory, or sandbox permissions"),
+    Entry::new(-50, "kAudio_ParamError", "CoreAudioTypes", "cat::audio::err::PARAM", "Error in user parameter list"),
+    Entry::new(-50, "errSecParam", "Security", "sec::err::PARAM", "One or more parameters passed to a function were not valid"),
+    Entry::new(-49, "errSecOpWr", "Security", "sec::err::OP_WR", "File already open with write permission"),
+    Entry::new(-43, "kAudioFileFileNotFoundError", "AudioToolbox", "at::audio::file_err::FILE_NOT_FOUND", "File not found"),
+    Entry::new(-43, "kAudio_FileNotFoundError", "CoreAudioTypes", "cat::audio::err::FILE_NOT_FOUND", "File not found"),
+    Entry::new(-42, "kAudio_TooManyFilesOpenError", "CoreAudioTypes", "cat::audio::err::TOO_MANY_FILES_OPEN", "File cannot be opened because too many files are already open"),
+    Entry::new(-40, "kAudioFilePositionError", "AudioToolbox", "at::audio::file_err::POS", "Invalid file position"),
+    Entry::new(-39, "kAudioFileEndOfFileError", "AudioToolbox", "at::audio::file_err::END_OF_FILE", "End of file"),
+    Entry::new(-38, "kAudioFileNotOpenError", "AudioToolbox", "at::audio::file_err::NOT_OPEN", "The file is closed"),
+    Entry::new(-36, "errSecIO", "Security", "sec::err::IO", "I/O error"),
+    Entry::new(-34, "errSecDiskFull", "Security", "sec::err::DISK_FULL", "Disk Full error"),
+    Entry::new(-4, "kAudio_UnimplementedError", "CoreAudioTypes", "cat::audio::err::UNIMPLEMENTED", "Unimplemented core routine"),
+    Entry::new(-4, "errSecUnimplemented", "Security", "sec::err::UNIMPLEMENTED", "Function or operation not implemented"),
+    Entry::new(1, "KERN_INVALID_ADDRESS", "Mach", "mach::kern_err::INVALID_ADDRESS", "Specified address is not currently valid"),
+    Entry::new(2, "KERN_PROTECTION_FAILURE", "Mach", "mach::kern_err::PROTECTION_FAILURE", "Specified memory is valid, but does not permit the required forms of access"),
+    Entry::new(3, "KERN_NO_SPACE", "Mach", "mach::kern_err::NO_SPACE", "The address range specified is already in use, or no address range of the size specified could be found"),
+    Entry::new(4, "KERN_INVALID_ARGUMENT", "Mach", "mach::kern_err::INVALID_ARGUMENT", "The function requested was not applicable to this type of argument, or an argument is invalid"),
+    Entry::new(5, "KERN_FAILURE", "Mach", "mach::kern_err::FAILURE", "The function could not be performed"),
+    Entry::new(6, "KERN_RESOURCE_SHORTAGE", "Mach", "mach::kern_err::RESOURCE_SHORTAGE", "A system resource could not be allocated to fulfill this request"),
+    Entry::new(7, "KERN_NOT_RECEIVER", "Mach", "mach::kern_err::NOT_RECEIVER", "The task in question does not hold receive rights for the port argument"),
+    Entry::new(8, "KERN_NO_ACCESS", "Mach", "mach::kern_err::NO_ACCESS", "Bogus access restriction"),
+    Entry::new(9, "KERN_MEMORY_FAILURE", "Mach", "mach::kern_err::MEMORY_FAILURE", "During a page fault, the target address refers to a memory object that has been destroyed"),
+    Entry::new(10, "KERN_MEMORY_ERROR", "Mach", "mach::kern_err::MEMORY_ERROR", "During a page fault, the memory object indicated that the data could not be returned"),
+    Entry::new(11, "KERN_ALREADY_IN_SET", "Mach", "mach::kern_err::ALREADY_IN_SET", "The receive right is already a member of the portset"),
+    Entry::new(12, "KERN_NOT_IN_SET", "Mach", "mach::kern_err::NOT_IN_SET", "The receive right is not a member of a port set"),
+    Entry::new(13, "KERN_NAME_EXISTS", "Mach", "mach::kern_err::NAME_EXISTS", "The name already denotes a right in the task"),
+    Entry::new(14, "KERN_ABORTED", "Mach", "mach::kern_err::ABORTED", "The operation was aborted"),
+    Entry::new(15, "KERN_INVALID_NAME", "Mach", "mach::kern_err::INVALID_NAME", "The name doesn't denote a right in the task"),
+    Entry::new(16, "KERN_INVALID_TASK", "Mach", "mach::kern_err::INVALID_TASK", "Target task isn't an active task"),
+    Entry::new(17, "KERN_INVALID_RIGHT", "Mach", "mach::kern_err::INVALID_RIGHT", "The name denotes a right, but not an appropriate right"),
+    Entry::new(18, "KERN_INVALID_VALUE", "Mach", "mach::kern_err::INVALID_VALUE", "A blatant range error"),
+    Entry::new(19, "KERN_UREFS_OVERFLOW", "Mach", "mach::kern_err::UREFS_OVERFLOW", "Operation would overflow limit on user-references"),
+    Entry::new(20, "KERN_INVALID_CAPABILITY", "Mach", "mach::kern_err::INVALID_CAPABILITY", "The supplied (port) capability is improper"),
+    Entry::new(21, "KERN_RIGHT_EXISTS", "Mach", "mach::kern_err::RIGHT_EXISTS", "The task already has send or receive rights for the port under another name"),
+    Entry::new(22, "KERN_INVALID_HOST", "Mach", "mach::kern_err::INVALID_HOST", "Target host isn't actually a host"),
+    Entry::new(23, "KERN_MEMORY_PRESENT", "Mach", "mach::kern_err::MEMORY_PRESENT", "An attempt was made to supply \"precious\" data for memory that is already present in a memory object"),
+    Entry::new(24, "KERN_MEMORY_DATA_MOVED", "Mach", "mach::kern_err::MEMORY_DATA_MOVED", "A page was requested of a memory manager via memory_object_data_request for an object using a MEMORY_OBJECT_COPY_CALL strategy, with the VM_PROT_WANTS_COPY flag being used to specify that the page desired is for a copy of the object, and the memory manager has detected the page was pushed into a copy of the object while the kernel was walking the shadow chain from the copy to the object"),
+    Entry::new(25, "KERN_MEMORY_RESTART_COPY", "Mach", "mach::kern_err::MEMORY_RESTART_COPY", "A strategic copy was attempted of an object upon which a quicker copy is now possible"),
+    Entry::new(26, "KERN_INVALID_PROCESSOR_SET", "Mach", "mach::kern_err::INVALID_PROCESSOR_SET", "An argument applied to assert processor set privilege was not a processor set control port"),
+    Entry::new(27, "KERN_POLICY_LIMIT", "Mach", "mach::kern_err::POLICY_LIMIT", "The specified scheduling attributes exceed the thread's limits"),
+    Entry::new(28, "KERN_INVALID_POLICY", "Mach", "mach::kern_err::INVALID_POLICY", "The specified scheduling policy is not currently enabled for the processor set"),
+    Entry::new(29, "KERN_INVALID_OBJECT", "Mach", "mach::kern_err::INVALID_OBJECT", "The external memory manager failed to initialize the memory object"),
+    Entry::new(30, "KERN_ALREADY_WAITING", "Mach", "mach::kern_err::ALREADY_WAITING", "A thread is attempting to wait for an event for which there is already a waiting thread"),
+    Entry::new(31, "KERN_DEFAULT_SET", "Mach", "mach::kern_err::DEFAULT_SET", "An attempt was made to destroy the default processor set"),
+    Entry::new(32, "KERN_EXCEPTION_PROTECTED", "Mach", "mach::kern_err::EXCEPTION_PROTECTED", "An attempt was made to fetch an exception port that is protected, or to abort a thread while processing a protected exception"),
+    Entry::new(33, "KERN_INVALID_LEDGER", "Mach", "mach::kern_err::INVALID_LEDGER", "A ledger was required but not supplied"),
+    Entry::new(34, "KERN_INVALID_MEMORY_CONTROL", "Mach", "mach::kern_err::INVALID_MEMORY_CONTROL", "The port was not a memory cache control port"),
+    Entry::new(35, "KERN_INVALID_SECURITY", "Mach", "mach::kern_err::INVALID_SECURITY", "An argument supplied to assert security privilege was not a host security port"),
+    Entry::new(36, "KERN_NOT_DEPRESSED", "Mach", "mach::kern_err::NOT_DEPRESSED", "thread_depress_abort was called on a thread which was not currently depressed"),
+    Entry::new(37, "KERN_TERMINATED", "Mach", "mach::kern_err::TERMINATED", "Object has been terminated and is no longer available"),
+    Entry::new(38, "KERN_LOCK_SET_DESTROYED", "Mach", "mach::kern_err::LOCK_SET_DESTROYED", "Lock set has been destroyed and is no longer available"),
+    Entry::new(39, "KERN_LOCK_UNSTABLE", "Mach", "mach::kern_err::LOCK_UNSTABLE", "The thread holding the lock terminated before releasing the lock"),
+    Entry::new(40, "KERN_LOCK_OWNED", "Mach", "mach::kern_err::LOCK_OWNED", "The lock is already owned by another thread"),
+    Entry::new(41, "KERN_LOCK_OWNED_SELF", "Mach", "mach::kern_err::LOCK_OWNED_SELF", "The lock is already owned by the calling thread"),
+    Entry::new(42, "KERN_SEMAPHORE_DESTROYED", "Mach", "mach::kern_err::SEMAPHORE_DESTROYED", "Semaphore has been destroyed and is no longer available"),
+    Entry::new(43, "KERN_RPC_SERVER_TERMINATED", "Mach", "mach::kern_err::RPC_SERVER_TERMINATED", "Return from RPC indicating the target server was terminated before it successfully replied"),
+    Entry::new(44, "KERN_RPC_TERMINATE_ORPHAN", "Mach", "mach::kern_err::RPC_TERMINATE_ORPHAN", "Terminate an orphaned activation"),
+    Entry::new(45, "KERN_RPC_CONTINUE_ORPHAN", "Mach", "mach::kern_err::RPC_CONTINUE_ORPHAN", "Allow an orphaned activation to continue executing"),
+    Entry::new(46, "KERN_NOT_SUPPORTED", "Mach", "mach::kern_err::NOT_SUPPORTED", "Empty thread activation (No thread linked to it)"),
+    Entry::new(47, "KERN_NODE_DOWN", "Mach", "mach::kern_err::NODE_DOWN", "Remote node down or inaccessible"),
+    Entry::new(48, "KERN_NOT_WAITING", "Mach", "mach::kern_err::NOT_WAITING", "A signalled thread was not actually waiting"),
+    Entry::new(49, "KERN_OPERATION_TIMED_OUT", "Mach", "mach::kern_err::OPERATION_TIMED_OUT", "Some thread-oriented operation (semaphore_wait) timed out"),
+    Entry::new(50, "KERN_CODESIGN_ERROR", "Mach", "mach::kern_err::CODESIGN_ERROR", "During a page fault, indicates that the page was rejected as a result of a signature check"),
+    Entry::new(51, "KERN_POLICY_STATIC", "Mach", "mach::kern_err::POLICY_STATIC", "The requested property cannot be changed at this time"),
+    Entry::new(52, "KERN_INSUFFICIENT_BUFFER_SIZE", "Mach", "mach::kern_err::INSUFFICIENT_BUF_SIZE", "The provided buffer is of insufficient size for the requested data"),
+    Entry::new(53, "KERN_DENIED", "Mach", "mach::kern_err::DENIED", "Denied by security policy"),
+    Entry::new(54, "KERN_MISSING_KC", "Mach", "mach::kern_err::MISSING_KC", "The KC on which the function is operating is missing"),
+    Entry::new(55, "KERN_INVALID_KC", "Mach", "mach::kern_err::INVALID_KC", "The KC on which the function is operating is invalid"),
+    Entry::new(56, "KERN_NOT_FOUND", "Mach", "mach::kern_err::NOT_FOUND", "A search or query operation did not return a result"),
+    Entry::new(256, "KERN_RETURN_MAX", "Mach", "mach::kern_err::RETURN_MAX", "Maximum return value allowable"),
+    Entry::new(1024, "MACH_MSG_VM_KERNEL", "Mach", "mach::msg_err::VM_KERNEL", "Kernel resource shortage handling out-of-line memory"),
+    Entry::new(2048, "MACH_MSG_IPC_KERNEL", "Mach", "mach::msg_err::IPC_KERNEL", "Kernel resource shortage handling an IPC capability"),
+    Entry::new(4096, "MACH_MSG_VM_SPACE", "Mach", "mach::msg_err::VM_SPACE", "No room in VM address space for out-of-line memory"),
+    Entry::new(8192, "MACH_MSG_IPC_SPACE", "Mach", "mach::msg_err::IPC_SPACE", "No room in IPC name space for another capability name"),
+    Entry::new(15872, "MACH_MSG_MASK", "Mach", "mach::msg_err::MASK", ""),
+    Entry::new(268435457, "MACH_SEND_IN_PROGRESS", "Mach", "mach::msg_err::SEND_IN_PROGRESS", "Thread is waiting to send"),
+    Entry::new(268435458, "MACH_SEND_INVALID_DATA", "Mach", "mach::msg_err::SEND_INVALID_DATA", "Bogus in-line data"),
+    Entry::new(268435459, "MACH_SEND_INVALID_DEST", "Mach", "mach::msg_err::SEND_INVALID_DEST", "Bogus destination port"),
+    Entry::new(268435460, "MACH_SEND_TIMED_OUT", "Mach", "mach::msg_err::SEND_TIMED_OUT", "Message not sent before timeout expired"),
+    Entry::new(268435461, "MACH_SEND_INVALID_VOUCHER", "Mach", "mach::msg_err::SEND_INVALID_VOUCHER", "Bogus voucher port"),
+    Entry::new(268435463, "MACH_SEND_INTERRUPTED", "Mach", "mach::msg_err::SEND_INTERRUPTED", "Software interrupt"),
+    Entry::new(268435464, "MACH_SEND_MSG_TOO_SMALL", "Mach", "mach::msg_err::SEND_SEND_MSG_TOO_SMALL", "Data doesn't contain a complete message"),
+    Entry::new(268435465, "MACH_SEND_INVALID_REPLY", "Mach", "mach::msg_err::SEND_INVALID_REPLY", "Bogus reply port"),
+    Entry::new(268435466, "MACH_SEND_INVALID_RIGHT", "Mach", "mach::msg_err::SEND_INVALID_RIGHT", "Bogus port rights in the message body"),
+    Entry::new(268435467, "MACH_SEND_INVALID_NOTIFY", "Mach", "mach::msg_err::SEND_INVALID_NOTIFY", "Bogus notify port argument"),
+    Entry::new(268435468, "MACH_SEND_INVALID_MEMORY", "Mach", "mach::msg_err::SEND_INVALID_MEMORY", "Invalid out-of-line memory pointer"),
+    Entry::new(268435469, "MACH_SEND_NO_BUFFER", "Mach", "mach::msg_err::SEND_NO_BUFFER", "No message buffer is available"),
+    Entry::new(268435470, "MACH_SEND_TOO_LARGE", "Mach", "mach::msg_err::SEND_TOO_LARGE", "Send is too large for port"),
+    Entry::new(268435471, "MACH_SEND_INVALID_TYPE", "Mach", "mach::msg_err::SEND_INVALID_TYPE", "Invalid msg-type specification"),
+    Entry::new(268435472, "MACH_SEND_INVALID_HEADER", "Mach", "mach::msg_err::SEND_INVALID_HEADER", "A field in the header had a bad value"),
+    Entry::new(268435473, "MACH_SEND_INVALID_TRAILER", "Mach", "mach::msg_err::SEND_INVALID_TRAILER", "The trailer to be sent does not match kernel format"),
+    Entry::new(268435474, "MACH_SEND_INVALID_CONTEXT", "Mach", "mach::msg_err::SEND_INVALID_CONTEXT", "The sending thread context did not match the context on the dest port"),
+    Entry::new(268435475, "MACH_SEND_INVALID_OPTIONS", "Mach", "mach::msg_err::SEND_INVALID_OPTIONS", "Send options are invalid"),
+    Entry::new(268435477, "MACH_SEND_INVALID_RT_OOL_SIZE", "Mach", "mach::msg_err::SEND_INVALID_RT_OOL_SIZE", "Compatibility: no longer a returned error"),
+    Entry::new(268435478, "MACH_SEND_NO_GRANT_DEST", "Mach", "mach::msg_err::SEND_NO_GRANT_DEST", "The destination port doesn't accept ports in body"),
+    Entry::new(268435479, "MACH_SEND_MSG_FILTERED", "Mach", "mach::msg_err::SEND_MSG_FILTERED", "Message send was rejected by message filter"),
+    Entry::new(268435480, "MACH_SEND_AUX_TOO_SMALL", "Mach", "mach::msg_err::SEND_AUX_TOO_SMALL", "Message auxiliary data is too small"),
+    Entry::new(268435481, "MACH_SEND_AUX_TOO_LARGE", "Mach", "mach::msg_err::SEND_SEND_AUX_TOO_LARGE", "Message auxiliary data is too large"),
+    Entry::new(268451841, "MACH_RCV_IN_PROGRESS", "Mach", "mach::msg_err::RCV_IN_PROGRESS", "Thread is waiting for receive"),
+    Entry::new(268451842, "MACH_RCV_INVALID_NAME", "Mach", "mach::msg_err::RCV_INVALID_NAME", "Bogus name for receive port/port-set"),
+    Entry::new(268451843, "MACH_RCV_TIMED_OUT", "Mach", "mach::msg_err::RCV_TIMED_OUT", "Didn't get a message within the timeout value"),
+    Entry::new(268451844, "MACH_RCV_TOO_LARGE", "Mach", "mach::msg_err::RCV_TOO_LARGE", "Message buffer is not large enough for inline data"),
+    Entry::new(268451845, "MACH_RCV_INTERRUPTED", "Mach", "mach::msg_err::RCV_INTERRUPTED", "Software interrupt"),
+    Entry::new(268451846, "MACH_RCV_PORT_CHANGED", "Mach", "mach::msg_err::RCV_PORT_CHANGED", "compatibility: no longer a returned error"),
+    Entry::new(268451847, "MACH_RCV_INVALID_NOTIFY", "Mach", "mach::msg_err::RCV_INVALID_NOTIFY", "Bogus notify port argument"),
+    Entry::new(268451848, "MACH_RCV_INVALID_DATA", "Mach", "mach::msg_err::RCV_INVALID_DATA", "Bogus message buffer for inline data"),
+    Entry::new(268451849, "MACH_RCV_PORT_DIED", "Mach", "mach::msg_err::RCV_PORT_DIED", "Port/set was sent away/died during receive"),
+    Entry::new(268451850, "MACH_RCV_IN_SET", "Mach", "mach::msg_err::RCV_IN_SET", "compatibility: no longer a returned error"),
+    Entry::new(268451851, "MACH_RCV_HEADER_ERROR", "Mach", "mach::msg_err::RCV_HEADER_ERROR", "Error receiving message header"),
+    Entry::new(268451852, "MACH_RCV_BODY_ERROR", "Mach", "mach::msg_err::RCV_BODY_ERROR", "Error receiving message body"),
+    Entry::new(268451853, "MACH_RCV_INVALID_TYPE", "Mach", "mach::msg_err::RCV_INVALID_TYPE", "Invalid msg-type specification in scatter list"),
+    Entry::new(268451854, "MACH_RCV_SCATTER_SMALL", "Mach", "mach::msg_err::RCV_SCATTER_SMALL", "Out-of-line overwrite region is not large enough"),
+    Entry::new(268451855, "MACH_RCV_INVALID_TRAILER", "Mach", "mach::msg_err::RCV_INVALID_TRAILER", "trailer type or number of trailer elements not supported"),
+    Entry::new(268451857, "MACH_RCV_IN_PROGRESS_TIMED", "Mach", "mach::msg_err::RCV_IN_PROGRESS_TIMED", "Waiting for receive with timeout"),
+    Entry::new(268451858, "MACH_RCV_INVALID_REPLY", "Mach", "mach::msg_err::RCV_INVALID_REPLY", "invalid reply port used in a STRICT_REPLY message"),
+    Entry::new(268451859, "MACH_RCV_INVALID_ARGUMENTS", "Mach", "mach::msg_err::RCV_INVALID_ARGUMENTS", "invalid receive arguments, receive has not started"),
+    Entry::new(560226676, "kAudioDeviceUnsupportedFormatError", "CoreAudio", "core_audio::hardware_err::UNSUPPORTED_FORMAT", "The AudioStream doesn't support the requested format"),
+    Entry::new(560226676, "kCMIODeviceUnsupportedFormatError", "CoreMediaIO", "cm::io::err::DEVICE_UNSUPPORTED_FORMAT", "The 'cm::io::Stream' doesn't support the requested format"),
+    Entry::new(560227702, "kAudioHardwareBadDeviceError", "CoreAudio", "core_audio::hardware_err::BAD_DEVICE", "The AudioObjectID passed to the function doesn't map to a valid AudioDevice"),
+    Entry::new(560227702, "kCMIOHardwareBadDeviceError", "CoreMediaIO", "cm::io::err::HW_BAD_DEVICE", "The 'cm::io::ObjectId' passed to the function doesn't map to a valid cm::io::Device"),
+    Entry::new(560492391, "kAudioDevicePermissionsError", "CoreAudio", "core_audio::hardware_err::PERMISSIONS", "The requested operation can't be completed because the process doesn't have permission"),
+    Entry::new(560492391, "kCMIODevicePermissionsError", "CoreMediaIO", "cm::io::err::DEVICE_PERMISSIONS", "The requested operation can't be completed because the process doesn't have permission"),
+    Entry::new(560558962, "kAudioConverterErr_InputSampleRateOutOfRange", "AudioToolbox", "at::audio::converter_err::INPUT_SAMPLE_RATE_OUT_OF_RANGE", ""),
+    Entry::new(560947818, "kAudioHardwareBadObjectError", "CoreAudio", "core_audio::hardware_err::BAD_OBJ", "The AudioObjectID passed to the function doesn't map to a valid AudioObject"),
+    Entry::new(560947818, "kCMIOHardwareBadObjectError", "CoreMediaIO", "cm::io::err::HW_BAD_OBJECT", "The 'cm::io::ObjectId' passed to the function doesn't map to a valid 'cm::io::Object'"),
+    Entry::new(560952178, "kAudioConverterErr_OutputSampleRateOutOfRange", "AudioToolbox", "at::audio::converter_err::OUTPUT_SAMPLE_RATE_OUT_OF_RANGE", ""),
+    Entry::new(561015652, "kAudioConverterErr_RequiresPacketDescriptionsError", "AudioToolbox", "at::audio::converter_err::REQUIRES_PACKET_DESCRIPTIONS_ERROR", ""),
+    Entry::new(561017453, "kAudioFilePermissionsError", "AudioToolbox", "at::audio::file_err::PERMISSIONS", "The operation violated the file permissions"),
+    Entry::new(561017960, "kAudio_BadFilePathError", "CoreAudioTypes", "cat::audio::err::BAD_FILE_PATH", "File cannot be opened because the specified path is malformed"),
+    Entry::new(561211770, "kAudioConverterErr_BadPropertySizeError", "AudioToolbox", "at::audio::converter_err::BAD_PROPERTY_SIZE_ERROR", ""),
+    Entry::new(561211770, "kAudioFileBadPropertySizeError", "AudioToolbox", "at::audio::file_err::BAD_PROPERTY_SIZE", "The size of the property data was not correct"),
+    Entry::new(561211770, "kAudioHardwareBadPropertySizeError", "CoreAudio", "core_audio::hardware_err::BAD_PROP_SIZE", "An improperly sized buffer was provided when accessing the data of a property"),
+    Entry::new(561211770, "kCMIOHardwareBadPropertySizeError", "CoreMediaIO", "cm::io::err::HW_BAD_PROP_SIZE", "An improperly sized buffer was provided when accessing the data of a property"),
+    Entry::new(561214578, "kAudioHardwareBadStreamError", "CoreAudio", "core_audio::hardware_err::BAD_STREAM", "The AudioObjectID passed to the function doesn't map to a valid AudioStream"),
+    Entry::new(561214578, "kCMIOHardwareBadStreamError", "CoreMediaIO", "cm::io::err::HW_BAD_STREAM", "The 'cm::io::StreamId' passed to the function doesn't map to a valid 'cm::io::Stream'"),
+    Entry::new(1667787583, "kAudioFileInvalidChunkError", "AudioToolbox", "at::audio::file_err::INVALID_CHUNK", "Either the chunk does not exist in the file or it is not supported by the file"),
+    Entry::new(1684369017, "kCMIOHardwareSuspendedBySystemError", "CoreMediaIO", "cm::io::err::HW_SUSPENDED_BY_SYSTEM", "The function call failed because because access been suspended by the system"),
+    Entry::new(1684369471, "kAudioFileInvalidPacketDependencyError", "AudioToolbox", "at::audio::file_err::INVALID_PACKET_DEPENDENCY", "The file offset was too large for the file type"),
+    Entry::new(1685348671, "kAudioFileInvalidFileError", "AudioToolbox", "at::audio::file_err::INVALID_FILE", "The file is malformed, or otherwise not a valid instance of an audio file of its type"),
+    Entry::new(1718449215, "kAudioConverterErr_FormatNotSupported", "AudioToolbox", "at::audio::converter_err::FORMAT_NOT_SUPPORTED", ""),
+    Entry::new(1718449215, "kAudioFileUnsupportedDataFormatError", "AudioToolbox", "at::audio::file_err::UNSUPPORTED_DATA_FORMAT", "The data format is not supported by this file type"),
+    Entry::new(1752656245, "kAudioConverterErr_HardwareInUse", "AudioToolbox", "at::audio::converter_err::HARDWARE_IN_USE", "Returned from AudioConverterFillComplexBuffer if the underlying hardware codec has become unavailable, probably due to an interruption"),
+    Entry::new(1768846202, "kAudioConverterErr_InvalidInputSize", "AudioToolbox", "at::audio::converter_err::INVALID_INPUT_SIZE", ""),
+    Entry::new(1852797029, "kAudioHardwareIllegalOperationError", "CoreAudio", "core_audio::hardware_err::ILLEGAL_OP", "The requested operation couldn't be completed"),
+    Entry::new(1852797029, "kCMIOHardwareIllegalOperationError", "CoreMediaIO", "cm::io::err::HW_ILLEGAL_OPERATION", "The requested operation couldn't be completed"),
+    Entry::new(1852990585, "kAudioHardwareNotReadyError", "CoreAudio", "core_audio::hardware_err::NOT_READY", "The AudioObject isn't ready to do the requested operation"),
+    Entry::new(1868981823, "kAudioFileDoesNotAllow64BitDataSizeError", "AudioToolbox", "at::audio::file_err::DOES_NOT_ALLOW64_BIT_DATA_SIZE", "The file offset was too large for the file type"),
+    Entry::new(1869627199, "kAudioConverterErr_OperationNotSupported", "AudioToolbox", "at::audio::converter_err::OPERATION_NOT_SUPPORTED", ""),
+    Entry::new(1869627199, "kAudioFileOperationNotSupportedError", "AudioToolbox", "at::audio::file_err::OPERATION_NOT_SUPPORTED", "The file is malformed, or otherwise not a valid instance of an audio file of its type"),
+    Entry::new(1869640813, "kAudioFileNotOptimizedError", "AudioToolbox", "at::audio::file_err::NOT_OPTIMIZED", "The chunks following the audio data chunk are preventing the extension of the audio data chunk"),
+    Entry::new(1869902714, "kAudioConverterErr_InvalidOutputSize", "AudioToolbox", "at::audio::converter_err::INVALID_OUTPUT_SIZE", ""),
+    Entry::new(1885563711, "kAudioFileInvalidPacketOffsetError", "AudioToolbox", "at::audio::file_err::INVALID_PACKET_OFFSET", "The file offset was too large for the file type"),
+    Entry::new(1885696621, "kAudioConverterErr_NoHardwarePermission", "AudioToolbox", "at::audio::converter_err::NO_HARDWARE_PERMISSION", "Returned from AudioConverterNew if the new converter would use a hardware codec which the application does not have permission to use"),
+    Entry::new(1886547824, "kAudioConverterErr_PropertyNotSupported", "AudioToolbox", "at::audio::converter_err::PROPERTY_NOT_SUPPORTED", ""),
+    Entry::new(1886681407, "kAudioFileUnsupportedPropertyError", "AudioToolbox", "at::audio::file_err::UNSUPPORTED_PROPERTY", "The property is not supported"),
+    Entry::new(1920298528, "kCMIOHardwareNotStoppedError", "CoreMediaIO", "cm::io::err::HW_NOT_STOPPED", "The function call requires that the hardware be stopped but it isn't"),
+    Entry::new(1937010544, "kAudioHardwareNotRunningError", "CoreAudio", "core_audio::hardware_err::NOT_RUNNING", "The function call requires that the hardware be running but it isn't"),
+    Entry::new(1937010544, "kCMIOHardwareNotRunningError", "CoreMediaIO", "cm::io::err::HW_NOT_RUNNING", "The function call requires that the hardware be running but it isn't"),
+    Entry::new(1954115647, "kAudioFileUnsupportedFileTypeError", "AudioToolbox", "at::audio::file_err::UNSUPPORTED_FILE_TYPE", "The file type is not supported"),
+    Entry::new(1970171760, "kAudioHardwareUnsupportedOperationError", "CoreAudio", "core_audio::hardware_err::UNSUPPORTED_OP", "The AudioObject doesn't support the requested operation"),
+    Entry::new(1970171760, "kCMIOHardwareUnsupportedOperationError", "CoreMediaIO", "cm::io::err::HW_UNSUPPORTED_OPERATION", "The 'cm::io::Object' doesn't support the requested operation"),
+    Entry::new(2003329396, "kAudioConverterErr_UnspecifiedError", "AudioToolbox", "at::audio::converter_err::UNSPECIFIED_ERROR", "e.g"),
+    Entry::new(2003329396, "kAudioHardwareUnspecifiedError", "CoreAudio", "core_audio::hardware_err::UNSPECIFIED", "The function call failed while doing something that doesn't provide any error messages"),
+    Entry::new(2003329396, "kCMIOHardwareUnspecifiedError", "CoreMediaIO", "cm::io::err::HW_UNSPECIFIED", "The function call failed while doing something that doesn't provide any error messages"),
+    Entry::new(2003332927, "kAudioHardwareUnknownPropertyError", "CoreAudio", "core_audio::hardware_err::UNKNOWN_PROP", "The AudioObject doesn't know about the property at the given address"),
+    Entry::new(2003332927, "kCMIOHardwareUnknownPropertyError", "CoreMediaIO", "cm::io::err::HW_UNKNOWN_PROP", "The CMIOObject doesn't know about the property at the given address"),
+    Entry::new(2003334207, "kAudioFileUnspecifiedError", "AudioToolbox", "at::audio::file_err::UNSPECIFIED", "An unspecified error has occurred"),
+];
@@ -0,0 +1,491 @@
+pub mod binary;
+pub mod keyed_archive;
+pub mod xml;
+
+pub use keyed_archive::KeyedArchive;
+
+use std::collections::HashMap;
+
+#[cfg(all(target_vendor = "apple", feature = "cf"))]
+use crate::{arc, cf};
+
+/// Seconds between 1970-01-01 and 2001-01-01
+const UNIX_TO_ABS_TIME: f64 = 978_307_200.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Xml,
+    Binary,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Neither `bplist00` nor XML plist.
+    UnknownFormat,
+
+    /// Malformed binary plist, with the reason.
+    Binary(&'static str),
+
+    /// Malformed XML plist, byte offset and the reason.
+    Xml(usize, &'static str),
+
+    /// Value can't be represented by Core Foundation property list.
+    NotPlist,
+
+    /// Valid property list, but not a well formed `ns::KeyedArchiver` archive.
+    Archive(&'static str),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownFormat => f.write_str("unknown property list format"),
+            Self::Binary(reason) => write!(f, "invalid binary property list: {reason}"),
+            Self::Xml(pos, reason) => {
+                write!(f, "invalid XML property list at {pos}: {reason}")
+            }
+            Self::NotPlist => f.write_str("not a property list"),
+            Self::Archive(reason) => write!(f, "invalid keyed archive: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T = ()> = std::result::Result<T, Error>;
+
+/// Reference to an object in `ns::KeyedArchiver` `$objects` array.
+#[doc(alias = "CFKeyedArchiverUID")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Uid(pub u64);
+
+/// Seconds since 2001-01-01 00:00:00 UTC, the same as `cf::AbsTime`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[repr(transparent)]
+pub struct Date(pub f64);
+
+impl Date {
+    #[inline]
+    pub fn with_unix_time(secs: f64) -> Self {
+        Self(secs - UNIX_TO_ABS_TIME)
+    }
+
+    #[inline]
+    pub fn unix_time(&self) -> f64 {
+        self.0 + UNIX_TO_ABS_TIME
+    }
+
+    /// Formats as `YYYY-MM-DDTHH:MM:SSZ` (XML plist date). Fractional seconds are dropped.
+    pub fn to_iso8601(&self) -> String {
+        let secs = self.unix_time().floor() as i64;
+        let days = secs.div_euclid(86_400);
+        let rem = secs.rem_euclid(86_400);
+        let (y, m, d) = civil_from_days(days);
+        format!(
+            "{y:04}-{m:02}-{d:02}T{:02}:{:02}:{:02}Z",
+            rem / 3600,
+            (rem / 60) % 60,
+            rem % 60
+        )
+    }
+
+    /// Parses `YYYY-MM-DDTHH:MM:SSZ`.
+    pub fn from_iso8601(str: &str) -> Option<Self> {
+        let b = str.as_bytes();
+        if b.len() != 20
+            || b[4] != b'-'
+            || b[7] != b'-'
+            || b[10] != b'T'
+            || b[13] != b':'
+            || b[16] != b':'
+            || b[19] != b'Z'
+        {
+            return None;
+        }
+        let num = |range: std::ops::Range<usize>| -> Option<i64> { str.get(range)?.parse().ok() };
+        let (y, m, d) = (num(0..4)?, num(5..7)?, num(8..10)?);
+        let (hh, mm, ss) = (num(11..13)?, num(14..16)?, num(17..19)?);
+        if !(1..=12).contains(&m) || !(1..=31).contains(&d) || hh > 23 || mm > 59 || ss > 60 {
+            return None;
+        }
+        let days = days_from_civil(y, m, d);
+        let secs = days * 86_400 + hh * 3600 + mm * 60 + ss;
+        Some(Self::with_unix_time(secs as f64))
+    }
+}
+
+// http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn civil_from_days(z: i64) -> (i64, i64, i64) {
+    let z = z + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+    (y, m, d)
+}
+
+/// Dictionary which keeps insertion order, so written plists look like their sources.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Dict(Vec<(String, Value)>);
+
+impl Dict {
+    #[inline]
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Value> {
+        self.0.iter_mut().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    #[inline]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Inserts or replaces value, returning the previous one.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<Value>) -> Option<Value> {
+        let key = key.into();
+        let value = value.into();
+        match self.get_mut(&key) {
+            Some(v) => Some(std::mem::replace(v, value)),
+            None => {
+                self.0.push((key, value));
+                None
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        let idx = self.0.iter().position(|(k, _)| k == key)?;
+        Some(self.0.remove(idx).1)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, &Value)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    #[inline]
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &str> {
+        self.0.iter().map(|(k, _)| k.as_str())
+    }
+}
+
+/// Later duplicate keys replace earlier values in one pass, so decoding
+/// large dictionaries doesn't scan per key.
+impl<K: Into<String>, V: Into<Value>> FromIterator<(K, V)> for Dict {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut entries: Vec<(String, Value)> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for (k, v) in iter {
+            let (k, v) = (k.into(), v.into());
+            match index.get(&k) {
+                Some(&i) => entries[i].1 = v,
+                None => {
+                    index.insert(k.clone(), entries.len());
+                    entries.push((k, v));
+                }
+            }
+        }
+        Self(entries)
+    }
+}
+
+impl IntoIterator for Dict {
+    type Item = (String, Value);
+    type IntoIter = std::vec::IntoIter<(String, Value)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Property list value.
+///
+/// Integers are signed 64-bit as `cf::Number` stores them; bplist 128-bit
+/// integers out of that range are rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Dict(Dict),
+    Array(Vec<Value>),
+    String(String),
+    Data(Vec<u8>),
+    Date(Date),
+    Int(i64),
+    Real(f64),
+    Bool(bool),
+    Uid(Uid),
+}
+
+impl Value {
+    /// Reads binary or XML property list, detecting format by header.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(Self::from_bytes_with_format(bytes)?.0)
+    }
+
+    pub fn from_bytes_with_format(bytes: &[u8]) -> Result<(Self, Format)> {
+        if bytes.starts_with(binary::MAGIC) {
+            Ok((binary::read(bytes)?, Format::Binary))
+        } else if xml::is_xml(bytes) {
+            Ok((xml::read(bytes)?, Format::Xml))
+        } else {
+            Err(Error::UnknownFormat)
+        }
+    }
+
+    pub fn to_vec(&self, format: Format) -> Vec<u8> {
+        match format {
+            Format::Xml => xml::write(self),
+            Format::Binary => binary::write(self),
+        }
+    }
+
+    #[inline]
+    pub fn as_dict(&self) -> Option<&Dict> {
+        match self {
+            Self::Dict(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_dict_mut(&mut self) -> Option<&mut Dict> {
+        match self {
+            Self::Dict(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Self::Array(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_data(&self) -> Option<&[u8]> {
+        match self {
+            Self::Data(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_date(&self) -> Option<Date> {
+        match self {
+            Self::Date(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Real or integer value as f64.
+    #[inline]
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Real(v) => Some(*v),
+            Self::Int(v) => Some(*v as f64),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_uid(&self) -> Option<Uid> {
+        match self {
+            Self::Uid(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Value by key if this is a dictionary.
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.as_dict()?.get(key)
+    }
+}
+
+macro_rules! impl_from {
+    ($($t:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$t> for Value {
+                #[inline]
+                fn from(value: $t) -> Self {
+                    Self::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(
+    Dict => Dict,
+    Vec<Value> => Array,
+    String => String,
+    &str => String,
+    Vec<u8> => Data,
+    &[u8] => Data,
+    Date => Date,
+    i64 => Int,
+    i32 => Int,
+    u32 => Int,
+    f64 => Real,
+    bool => Bool,
+    Uid => Uid,
+);
+
+#[cfg(all(target_vendor = "apple", feature = "cf"))]
+impl Value {
+    /// Converts to Core Foundation property list.
+    ///
+    /// UIDs become `CFKeyedArchiverUID` objects just like `cf::Plist::from_data` does.
+    pub fn to_cf(&self) -> Result<arc::R<cf::Plist>> {
+        let data = cf::Data::from_slice(&binary::write(self)).ok_or(Error::NotPlist)?;
+        cf::Plist::from_data(&data, cf::PlistMutabilityOpts::IMMUTABLE).map_err(|_| Error::NotPlist)
+    }
+
+    pub fn with_cf(plist: &cf::Plist) -> Result<Self> {
+        let data = plist
+            .to_cf_data(cf::PlistFormat::BinaryV1_0)
+            .map_err(|_| Error::NotPlist)?;
+        binary::read(data.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::plist;
+
+    pub fn sample() -> plist::Value {
+        let mut info = plist::Dict::new();
+        info.insert("CFBundleIdentifier", "com.example.<box> & \"friends\"");
+        info.insert("CFBundleVersion", 42i64);
+        info.insert("LSRequiresIPhoneOS", true);
+        info.insert("Negative", -1_000_000_000_000i64);
+        info.insert("Pi", std::f64::consts::PI);
+        info.insert("Blob", vec![0u8, 1, 2, 0xff, 0xfe]);
+        info.insert("Created", plist::Date::with_unix_time(1_700_000_000.0));
+        info.insert("Unicode", "Привет, 世界 🦀");
+        info.insert("Root", plist::Uid(1));
+        info.insert(
+            "UIRequiredDeviceCapabilities",
+            vec![plist::Value::from("arm64"), plist::Value::from("metal")],
+        );
+        info.insert("Empty", plist::Dict::new());
+        info.insert("EmptyArray", Vec::<plist::Value>::new());
+        info.insert("EmptyString", "");
+        plist::Value::Dict(info)
+    }
+
+    #[test]
+    fn dates() {
+        let date = plist::Date::from_iso8601("2001-01-01T00:00:00Z").unwrap();
+        assert_eq!(date, plist::Date(0.0));
+
+        let date = plist::Date::from_iso8601("2023-11-14T22:13:20Z").unwrap();
+        assert_eq!(date.unix_time(), 1_700_000_000.0);
+        assert_eq!(date.to_iso8601(), "2023-11-14T22:13:20Z");
+
+        let date = plist::Date::with_unix_time(-1.0);
+        assert_eq!(date.to_iso8601(), "1969-12-31T23:59:59Z");
+
+        assert!(plist::Date::from_iso8601("2023-13-14T22:13:20Z").is_none());
+        assert!(plist::Date::from_iso8601("2023-11-14 22:13:20").is_none());
+    }
+
+    #[test]
+    fn formats() {
+        let value = sample();
+        for format in [plist::Format::Xml, plist::Format::Binary] {
+            let bytes = value.to_vec(format);
+            let (parsed, detected) = plist::Value::from_bytes_with_format(&bytes).unwrap();
+            assert_eq!(detected, format);
+            assert_eq!(parsed, value);
+        }
+        assert_eq!(
+            plist::Value::from_bytes(b"{ a = b; }"),
+            Err(plist::Error::UnknownFormat)
+        );
+    }
+
+    #[test]
+    fn dict() {
+        let mut dict: plist::Dict = [("b", 1i64), ("a", 2i64)].into_iter().collect();
+        assert_eq!(dict.keys().collect::<Vec<_>>(), ["b", "a"]);
+        assert_eq!(dict.insert("b", 3i64), Some(plist::Value::Int(1)));
+        assert_eq!(dict.get("b").and_then(|v| v.as_i64()), Some(3));
+        assert_eq!(dict.remove("a"), Some(plist::Value::Int(2)));
+        assert_eq!(dict.len(), 1);
+
+        let dict: plist::Dict = [("a", 1i64), ("b", 2), ("a", 3)].into_iter().collect();
+        assert_eq!(dict.keys().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(dict.get("a").and_then(|v| v.as_i64()), Some(3));
+    }
+
+    #[cfg(all(target_vendor = "apple", feature = "cf"))]
+    #[test]
+    fn cf() {
+        let value = sample();
+        let cf = value.to_cf().unwrap();
+        let back = plist::Value::with_cf(&cf).unwrap();
+        // CF doesn't keep dictionary order
+        let (a, b) = (value.as_dict().unwrap(), back.as_dict().unwrap());
+        assert_eq!(a.len(), b.len());
+        for (k, v) in a.iter() {
+            assert_eq!(b.get(k), Some(v), "{k}");
+        }
+    }
+}
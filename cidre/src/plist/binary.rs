@@ -0,0 +1,519 @@
+use std::collections::HashMap;
+
+use crate::plist::{Date, Dict, Error, Result, Uid, Value};
+
+pub const MAGIC: &[u8] = b"bplist00";
+
+const TRAILER_LEN: usize = 32;
+pub(super) const MAX_DEPTH: usize = 512;
+/// Shared refs expand into copies, so decoded values are limited
+/// to this many nodes per input byte.
+const MAX_NODES_PER_BYTE: usize = 16;
+
+fn err<T>(reason: &'static str) -> Result<T> {
+    Err(Error::Binary(reason))
+}
+
+#[inline]
+fn be_uint(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64)
+}
+
+/// Reads `bplist00` binary property list.
+pub fn read(bytes: &[u8]) -> Result<Value> {
+    if !bytes.starts_with(MAGIC) {
+        return err("missing bplist00 header");
+    }
+    if bytes.len() < MAGIC.len() + 1 + TRAILER_LEN {
+        return err("too short");
+    }
+    let trailer = &bytes[bytes.len() - TRAILER_LEN..];
+    let offset_size = trailer[6] as usize;
+    let ref_size = trailer[7] as usize;
+    let num_objects = be_uint(&trailer[8..16]);
+    let top = be_uint(&trailer[16..24]);
+    let table_offset = be_uint(&trailer[24..32]);
+
+    if !(1..=8).contains(&offset_size) || !(1..=8).contains(&ref_size) {
+        return err("invalid trailer int sizes");
+    }
+    let objects_end = (bytes.len() - TRAILER_LEN) as u64;
+    if num_objects == 0 || top >= num_objects || table_offset < MAGIC.len() as u64 {
+        return err("invalid trailer");
+    }
+    let table_len = num_objects.checked_mul(offset_size as u64);
+    match table_len.and_then(|l| l.checked_add(table_offset)) {
+        Some(end) if end <= objects_end => {}
+        _ => return err("offset table out of bounds"),
+    }
+    let table = &bytes[table_offset as usize..];
+    let offsets = table
+        .chunks_exact(offset_size)
+        .take(num_objects as usize)
+        .map(|c| be_uint(c) as usize)
+        .collect();
+
+    let mut reader = Reader {
+        bytes: &bytes[..table_offset as usize],
+        offsets,
+        ref_size,
+        stack: Vec::new(),
+        seen: vec![false; num_objects as usize],
+        cache: vec![None; num_objects as usize],
+        nodes: 0,
+        max_nodes: bytes.len().saturating_mul(MAX_NODES_PER_BYTE),
+    };
+    reader.object(top as usize)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offsets: Vec<usize>,
+    ref_size: usize,
+    stack: Vec<usize>,
+    seen: Vec<bool>,
+    /// Shared objects with their node count
+    cache: Vec<Option<(Value, usize)>>,
+    nodes: usize,
+    max_nodes: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn slice(&self, start: usize, len: usize) -> Result<&'a [u8]> {
+        let end = start.checked_add(len);
+        match end.and_then(|end| self.bytes.get(start..end)) {
+            Some(s) => Ok(s),
+            None => err("object out of bounds"),
+        }
+    }
+
+    /// Returns count from marker low nibble or following int object and the position after it.
+    fn count(&self, marker_pos: usize) -> Result<(usize, usize)> {
+        let lo = self.bytes[marker_pos] & 0x0f;
+        if lo != 0x0f {
+            return Ok((lo as usize, marker_pos + 1));
+        }
+        let m = *self.slice(marker_pos + 1, 1)?.first().unwrap();
+        if m >> 4 != 0x1 || m & 0x0f > 3 {
+            return err("invalid count");
+        }
+        let len = 1usize << (m & 0x0f);
+        let count = be_uint(self.slice(marker_pos + 2, len)?);
+        Ok((count as usize, marker_pos + 2 + len))
+    }
+
+    fn refs(&self, start: usize, count: usize) -> Result<Vec<usize>> {
+        let len = count.checked_mul(self.ref_size);
+        let Some(len) = len else {
+            return err("too many refs");
+        };
+        Ok(self
+            .slice(start, len)?
+            .chunks_exact(self.ref_size)
+            .map(|c| be_uint(c) as usize)
+            .collect())
+    }
+
+    fn add_nodes(&mut self, count: usize) -> Result<()> {
+        self.nodes += count;
+        if self.nodes > self.max_nodes {
+            return err("too many shared refs");
+        }
+        Ok(())
+    }
+
+    fn object(&mut self, idx: usize) -> Result<Value> {
+        let Some(&pos) = self.offsets.get(idx) else {
+            return err("object ref out of bounds");
+        };
+        if let Some((_, count)) = self.cache[idx] {
+            self.add_nodes(count)?;
+            return Ok(self.cache[idx].as_ref().unwrap().0.clone());
+        }
+        if self.stack.contains(&idx) {
+            return err("reference cycle");
+        }
+        if self.stack.len() >= MAX_DEPTH {
+            return err("too deep");
+        }
+        self.stack.push(idx);
+        let start = self.nodes;
+        let res = self.object_at(pos);
+        self.stack.pop();
+        let value = res?;
+        self.add_nodes(1)?;
+        // keep only objects referenced more than once
+        if self.seen[idx] {
+            self.cache[idx] = Some((value.clone(), self.nodes - start));
+        }
+        self.seen[idx] = true;
+        Ok(value)
+    }
+
+    fn object_at(&mut self, pos: usize) -> Result<Value> {
+        let marker = *self.slice(pos, 1)?.first().unwrap();
+        let lo = marker & 0x0f;
+        let value = match marker >> 4 {
+            0x0 => match marker {
+                0x08 => Value::Bool(false),
+                0x09 => Value::Bool(true),
+                _ => return err("unsupported null or fill object"),
+            },
+            0x1 => match lo {
+                0..=2 => Value::Int(be_uint(self.slice(pos + 1, 1 << lo)?) as i64),
+                3 => Value::Int(be_uint(self.slice(pos + 1, 8)?) as i64),
+                // 128-bit, CF writes u64 values above i64::MAX this way
+                4 => {
+                    let b = self.slice(pos + 1, 16)?;
+                    let v = i128::from_be_bytes(b.try_into().unwrap());
+                    match i64::try_from(v) {
+                        Ok(v) => Value::Int(v),
+                        Err(_) => return err("int out of range"),
+                    }
+                }
+                _ => return err("invalid int size"),
+            },
+            0x2 => match lo {
+                2 => {
+                    let b = self.slice(pos + 1, 4)?;
+                    Value::Real(f32::from_be_bytes(b.try_into().unwrap()) as f64)
+                }
+                3 => {
+                    let b = self.slice(pos + 1, 8)?;
+                    Value::Real(f64::from_be_bytes(b.try_into().unwrap()))
+                }
+                _ => return err("invalid real size"),
+            },
+            0x3 if marker == 0x33 => {
+                let b = self.slice(pos + 1, 8)?;
+                Value::Date(Date(f64::from_be_bytes(b.try_into().unwrap())))
+            }
+            0x4 => {
+                let (count, start) = self.count(pos)?;
+                Value::Data(self.slice(start, count)?.to_vec())
+            }
+            0x5 => {
+                let (count, start) = self.count(pos)?;
+                let b = self.slice(start, count)?;
+                if !b.is_ascii() {
+                    return err("non ascii string");
+                }
+                Value::String(String::from_utf8(b.to_vec()).unwrap())
+            }
+            0x6 => {
+                let (count, start) = self.count(pos)?;
+                let Some(len) = count.checked_mul(2) else {
+                    return err("string too long");
+                };
+                let units: Vec<u16> = self
+                    .slice(start, len)?
+                    .chunks_exact(2)
+                    .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                    .collect();
+                match String::from_utf16(&units) {
+                    Ok(s) => Value::String(s),
+                    Err(_) => return err("invalid utf16 string"),
+                }
+            }
+            0x8 => Value::Uid(Uid(be_uint(self.slice(pos + 1, lo as usize + 1)?))),
+            0xa => {
+                let (count, start) = self.count(pos)?;
+                let refs = self.refs(start, count)?;
+                let mut items = Vec::with_capacity(refs.len());
+                for r in refs {
+                    items.push(self.object(r)?);
+                }
+                Value::Array(items)
+            }
+            0xd => {
+                let (count, start) = self.count(pos)?;
+                let keys = self.refs(start, count)?;
+                let values = self.refs(start + count * self.ref_size, count)?;
+                let mut entries = Vec::with_capacity(count);
+                for (k, v) in keys.into_iter().zip(values) {
+                    let Value::String(key) = self.object(k)? else {
+                        return err("non string dictionary key");
+                    };
+                    entries.push((key, self.object(v)?));
+                }
+                Value::Dict(Dict::from_iter(entries))
+            }
+            _ => return err("unknown object marker"),
+        };
+        Ok(value)
+    }
+}
+
+enum Node<'a> {
+    Leaf(&'a Value),
+    Str(&'a str),
+    Array(Vec<usize>),
+    Dict(Vec<usize>, Vec<usize>),
+}
+
+struct Writer<'a> {
+    nodes: Vec<Node<'a>>,
+    strings: HashMap<&'a str, usize>,
+}
+
+impl<'a> Writer<'a> {
+    fn string(&mut self, s: &'a str) -> usize {
+        if let Some(idx) = self.strings.get(s) {
+            return *idx;
+        }
+        let idx = self.nodes.len();
+        self.nodes.push(Node::Str(s));
+        self.strings.insert(s, idx);
+        idx
+    }
+
+    fn flatten(&mut self, value: &'a Value) -> usize {
+        match value {
+            Value::String(s) => self.string(s),
+            Value::Array(items) => {
+                let idx = self.nodes.len();
+                self.nodes.push(Node::Array(vec![]));
+                let refs = items.iter().map(|v| self.flatten(v)).collect();
+                self.nodes[idx] = Node::Array(refs);
+                idx
+            }
+            Value::Dict(dict) => {
+                let idx = self.nodes.len();
+                self.nodes.push(Node::Dict(vec![], vec![]));
+                let keys = dict.keys().map(|k| self.string(k)).collect();
+                let values = dict.iter().map(|(_, v)| self.flatten(v)).collect();
+                self.nodes[idx] = Node::Dict(keys, values);
+                idx
+            }
+            v => {
+                let idx = self.nodes.len();
+                self.nodes.push(Node::Leaf(v));
+                idx
+            }
+        }
+    }
+}
+
+#[inline]
+fn int_size(v: u64) -> usize {
+    match v {
+        0..=0xff => 1,
+        0x100..=0xffff => 2,
+        0x1_0000..=0xffff_ffff => 4,
+        _ => 8,
+    }
+}
+
+#[inline]
+fn push_be(buf: &mut Vec<u8>, v: u64, size: usize) {
+    buf.extend_from_slice(&v.to_be_bytes()[8 - size..]);
+}
+
+fn push_int(buf: &mut Vec<u8>, v: i64) {
+    // negative numbers are always 8 bytes
+    let size = if v < 0 { 8 } else { int_size(v as u64) };
+    buf.push(0x10 | size.trailing_zeros() as u8);
+    push_be(buf, v as u64, size);
+}
+
+fn push_marker(buf: &mut Vec<u8>, kind: u8, count: usize) {
+    if count < 0x0f {
+        buf.push((kind << 4) | count as u8);
+    } else {
+        buf.push((kind << 4) | 0x0f);
+        push_int(buf, count as i64);
+    }
+}
+
+/// Writes `bplist00` binary property list. Equal strings are stored once.
+pub fn write(value: &Value) -> Vec<u8> {
+    let mut w = Writer {
+        nodes: Vec::new(),
+        strings: HashMap::new(),
+    };
+    w.flatten(value);
+
+    let ref_size = int_size(w.nodes.len() as u64 - 1);
+    let mut buf = Vec::with_capacity(64 + w.nodes.len() * 16);
+    buf.extend_from_slice(MAGIC);
+    let mut offsets = Vec::with_capacity(w.nodes.len());
+
+    let push_refs = |buf: &mut Vec<u8>, refs: &[usize]| {
+        for r in refs {
+            push_be(buf, *r as u64, ref_size);
+        }
+    };
+
+    for node in &w.nodes {
+        offsets.push(buf.len() as u64);
+        match node {
+            Node::Str(s) => {
+                if s.is_ascii() {
+                    push_marker(&mut buf, 0x5, s.len());
+                    buf.extend_from_slice(s.as_bytes());
+                } else {
+                    let units: Vec<u16> = s.encode_utf16().collect();
+                    push_marker(&mut buf, 0x6, units.len());
+                    for u in units {
+                        buf.extend_from_slice(&u.to_be_bytes());
+                    }
+                }
+            }
+            Node::Array(refs) => {
+                push_marker(&mut buf, 0xa, refs.len());
+                push_refs(&mut buf, refs);
+            }
+            Node::Dict(keys, values) => {
+                push_marker(&mut buf, 0xd, keys.len());
+                push_refs(&mut buf, keys);
+                push_refs(&mut buf, values);
+            }
+            Node::Leaf(v) => match v {
+                Value::Bool(false) => buf.push(0x08),
+                Value::Bool(true) => buf.push(0x09),
+                Value::Int(v) => push_int(&mut buf, *v),
+                Value::Real(v) => {
+                    buf.push(0x23);
+                    buf.extend_from_slice(&v.to_be_bytes());
+                }
+                Value::Date(d) => {
+                    buf.push(0x33);
+                    buf.extend_from_slice(&d.0.to_be_bytes());
+                }
+                Value::Data(d) => {
+                    push_marker(&mut buf, 0x4, d.len());
+                    buf.extend_from_slice(d);
+                }
+                Value::Uid(uid) => {
+                    let size = int_size(uid.0);
+                    buf.push(0x80 | (size - 1) as u8);
+                    push_be(&mut buf, uid.0, size);
+                }
+                Value::String(_) | Value::Array(_) | Value::Dict(_) => unreachable!(),
+            },
+        }
+    }
+
+    let table_offset = buf.len() as u64;
+    let offset_size = int_size(table_offset);
+    for off in offsets {
+        push_be(&mut buf, off, offset_size);
+    }
+
+    buf.extend_from_slice(&[0u8; 6]);
+    buf.push(offset_size as u8);
+    buf.push(ref_size as u8);
+    buf.extend_from_slice(&(w.nodes.len() as u64).to_be_bytes());
+    buf.extend_from_slice(&0u64.to_be_bytes());
+    buf.extend_from_slice(&table_offset.to_be_bytes());
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::plist::{self, binary};
+
+    #[test]
+    fn basics() {
+        // <dict><key>a</key><array><integer>1</integer><true/></array></dict> as CF writes it
+        let bytes = [
+            0x62, 0x70, 0x6c, 0x69, 0x73, 0x74, 0x30, 0x30, 0xd1, 0x01, 0x02, 0x51, 0x61, 0xa2,
+            0x03, 0x04, 0x10, 0x01, 0x09, 0x08, 0x0b, 0x0d, 0x10, 0x12, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13,
+        ];
+        let value = binary::read(&bytes).unwrap();
+        let arr = value.get("a").unwrap().as_array().unwrap();
+        assert_eq!(arr, [plist::Value::Int(1), plist::Value::Bool(true)]);
+        assert_eq!(binary::write(&value), bytes);
+    }
+
+    #[test]
+    fn large() {
+        let items: Vec<plist::Value> = (0..300).map(|i| plist::Value::Int(i * 1000)).collect();
+        let value = plist::Value::Array(items);
+        let bytes = binary::write(&value);
+        assert_eq!(binary::read(&bytes).unwrap(), value);
+
+        let dict: plist::Dict = (0..20_000i64).map(|i| (format!("k{i}"), i)).collect();
+        let value = plist::Value::Dict(dict);
+        let bytes = binary::write(&value);
+        assert_eq!(binary::read(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn int128() {
+        let read = |v: i128| {
+            let mut bytes = binary::MAGIC.to_vec();
+            bytes.push(0x14);
+            bytes.extend_from_slice(&v.to_be_bytes());
+            bytes.push(8);
+            bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0, 1, 1]);
+            bytes.extend_from_slice(&1u64.to_be_bytes());
+            bytes.extend_from_slice(&0u64.to_be_bytes());
+            bytes.extend_from_slice(&25u64.to_be_bytes());
+            binary::read(&bytes)
+        };
+        assert_eq!(read(-1), Ok(plist::Value::Int(-1)));
+        assert_eq!(read(i64::MAX as i128), Ok(plist::Value::Int(i64::MAX)));
+        assert!(read(i64::MAX as i128 + 1).is_err());
+        assert!(read(u64::MAX as i128).is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        let bytes = binary::write(&crate::plist::tests::sample());
+        for len in [0, 8, 20, bytes.len() - 1] {
+            assert!(binary::read(&bytes[..len]).is_err());
+        }
+
+        // array which contains itself
+        let mut cycle = b"bplist00".to_vec();
+        cycle.extend_from_slice(&[0xa1, 0x00, 0x08]);
+        cycle.extend_from_slice(&[0; 6]);
+        cycle.extend_from_slice(&[1, 1]);
+        cycle.extend_from_slice(&1u64.to_be_bytes());
+        cycle.extend_from_slice(&0u64.to_be_bytes());
+        cycle.extend_from_slice(&10u64.to_be_bytes());
+        assert_eq!(
+            binary::read(&cycle),
+            Err(plist::Error::Binary("reference cycle"))
+        );
+    }
+
+    #[test]
+    fn shared_refs() {
+        // each array refers to the next one twice
+        let dag = |levels: u8| {
+            let mut bytes = b"bplist00".to_vec();
+            let mut offsets = vec![];
+            for i in 0..levels {
+                offsets.push(bytes.len() as u8);
+                bytes.extend_from_slice(&[0xa2, i + 1, i + 1]);
+            }
+            offsets.push(bytes.len() as u8);
+            bytes.extend_from_slice(&[0x10, 0x01]);
+            let table = bytes.len() as u64;
+            bytes.extend_from_slice(&offsets);
+            bytes.extend_from_slice(&[0; 6]);
+            bytes.extend_from_slice(&[1, 1]);
+            bytes.extend_from_slice(&(levels as u64 + 1).to_be_bytes());
+            bytes.extend_from_slice(&0u64.to_be_bytes());
+            bytes.extend_from_slice(&table.to_be_bytes());
+            bytes
+        };
+
+        let one = plist::Value::Int(1);
+        let pair = plist::Value::Array(vec![one.clone(), one]);
+        let quad = plist::Value::Array(vec![pair.clone(), pair]);
+        assert_eq!(
+            binary::read(&dag(3)).unwrap(),
+            plist::Value::Array(vec![quad.clone(), quad])
+        );
+
+        assert_eq!(
+            binary::read(&dag(64)),
+            Err(plist::Error::Binary("too many shared refs"))
+        );
+    }
+}
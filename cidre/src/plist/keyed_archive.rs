@@ -0,0 +1,748 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::plist::{Date, Dict, Error, Format, Result, Uid, Value};
+
+const ARCHIVER: &str = "NSKeyedArchiver";
+const VERSION: i64 = 100_000;
+const NULL: &str = "$null";
+const MAX_DEPTH: usize = 512;
+/// Shared objects are decoded into copies, so decoded graph is limited
+/// to this many objects per value in the archive.
+const MAX_NODES_PER_VALUE: usize = 16;
+
+fn err<T>(reason: &'static str) -> Result<T> {
+    Err(Error::Archive(reason))
+}
+
+/// Object of a class decoder doesn't know about.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Custom {
+    pub class_name: String,
+
+    /// `$classes`, class hierarchy starting with `class_name`
+    pub classes: Vec<String>,
+
+    /// Encoded keys except `$class`, in archive order
+    pub fields: Vec<(String, Obj)>,
+}
+
+impl Custom {
+    pub fn field(&self, key: &str) -> Option<&Obj> {
+        self.fields.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+}
+
+/// Decoded `ns::KeyedArchiver` object graph node.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Obj {
+    /// `$null` (nil) or NSNull
+    Null,
+    String(String),
+    Data(Vec<u8>),
+    Date(Date),
+
+    /// NSNumber with integer value
+    Int(i64),
+
+    /// NSNumber with floating point value
+    Real(f64),
+
+    /// NSNumber with boolean value
+    Bool(bool),
+    Array(Vec<Obj>),
+    Set(Vec<Obj>),
+    Dict(Vec<(Obj, Obj)>),
+    Uuid([u8; 16]),
+    Url {
+        base: Option<Box<Obj>>,
+        relative: String,
+    },
+    Custom(Custom),
+
+    /// Object which nested [`Obj::Ref`]s with the same UID point back to.
+    ///
+    /// UID is the one from the archive the object was decoded from,
+    /// encoder assigns its own.
+    Referenced(Uid, Box<Obj>),
+
+    /// Reference back to the enclosing [`Obj::Referenced`] (cycle in object graph).
+    Ref(Uid),
+}
+
+impl Obj {
+    /// Object without [`Obj::Referenced`] wrapper.
+    #[inline]
+    pub fn target(&self) -> &Obj {
+        match self {
+            Self::Referenced(_, obj) => obj.target(),
+            obj => obj,
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        match self.target() {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_custom(&self) -> Option<&Custom> {
+        match self.target() {
+            Self::Custom(c) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Value by string key of NSDictionary or field of custom object.
+    pub fn get(&self, key: &str) -> Option<&Obj> {
+        match self.target() {
+            Self::Dict(entries) => entries
+                .iter()
+                .find(|(k, _)| k.as_str() == Some(key))
+                .map(|(_, v)| v),
+            Self::Custom(c) => c.field(key),
+            _ => None,
+        }
+    }
+
+    /// Encodes object graph as `NSKeyedArchiver` plist with `root` top key.
+    pub fn archive(&self) -> Value {
+        KeyedArchive::archive([("root", self)])
+    }
+
+    pub fn archive_to_vec(&self, format: Format) -> Vec<u8> {
+        self.archive().to_vec(format)
+    }
+
+    /// Decodes `root` object of `NSKeyedArchiver` plist bytes.
+    pub fn unarchive(bytes: &[u8]) -> Result<Self> {
+        KeyedArchive::from_bytes(bytes)?.decode_root()
+    }
+}
+
+/// `NSKeyedArchiver` property list: `$archiver`, `$version`, `$objects` and `$top`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyedArchive {
+    objects: Vec<Value>,
+    top: Dict,
+}
+
+impl KeyedArchive {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::with_plist(Value::from_bytes(bytes)?)
+    }
+
+    pub fn with_plist(value: Value) -> Result<Self> {
+        let Value::Dict(mut dict) = value else {
+            return err("archive is not a dictionary");
+        };
+        match dict.get("$archiver").and_then(|v| v.as_str()) {
+            Some(ARCHIVER) => {}
+            _ => return err("unknown $archiver"),
+        }
+        let Some(Value::Array(objects)) = dict.remove("$objects") else {
+            return err("missing $objects");
+        };
+        let Some(Value::Dict(top)) = dict.remove("$top") else {
+            return err("missing $top");
+        };
+        Ok(Self { objects, top })
+    }
+
+    /// Keys of `$top`, usually just `root`.
+    pub fn top_keys(&self) -> impl ExactSizeIterator<Item = &str> {
+        self.top.keys()
+    }
+
+    /// Raw `$objects` array.
+    pub fn objects(&self) -> &[Value] {
+        &self.objects
+    }
+
+    pub fn decode_root(&self) -> Result<Obj> {
+        self.decode("root")
+    }
+
+    pub fn decode(&self, key: &str) -> Result<Obj> {
+        let Some(value) = self.top.get(key) else {
+            return err("no such top key");
+        };
+        let values: usize = self.objects.iter().map(value_count).sum();
+        let mut decoder = Decoder {
+            objects: &self.objects,
+            stack: Vec::new(),
+            cache: HashMap::new(),
+            targets: HashSet::new(),
+            refs: 0,
+            nodes: 0,
+            max_nodes: values.saturating_mul(MAX_NODES_PER_VALUE),
+        };
+        decoder.value(value)
+    }
+
+    /// Builds archive plist with several top level objects.
+    pub fn archive<'a>(top: impl IntoIterator<Item = (&'a str, &'a Obj)>) -> Value {
+        let mut encoder = Encoder {
+            objects: vec![Value::from(NULL)],
+            strings: HashMap::new(),
+            classes: HashMap::new(),
+            refs: HashMap::new(),
+            pending_ref: None,
+        };
+        let top: Dict = top
+            .into_iter()
+            .map(|(k, obj)| (k, Value::Uid(encoder.obj(obj))))
+            .collect();
+
+        let mut res = Dict::new();
+        res.insert("$version", VERSION);
+        res.insert("$archiver", ARCHIVER);
+        res.insert("$top", top);
+        res.insert("$objects", encoder.objects);
+        Value::Dict(res)
+    }
+}
+
+fn value_count(value: &Value) -> usize {
+    match value {
+        Value::Array(items) => 1 + items.iter().map(value_count).sum::<usize>(),
+        Value::Dict(dict) => 1 + dict.iter().map(|(_, v)| value_count(v)).sum::<usize>(),
+        _ => 1,
+    }
+}
+
+struct Decoder<'a> {
+    objects: &'a [Value],
+    stack: Vec<u64>,
+    /// Decoded objects without back references with their object count
+    cache: HashMap<u64, (Obj, usize)>,
+    /// UIDs of objects being decoded that have back references
+    targets: HashSet<u64>,
+    /// Number of back references so far
+    refs: usize,
+    nodes: usize,
+    max_nodes: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn object(&self, uid: Uid) -> Result<&'a Value> {
+        match self.objects.get(uid.0 as usize) {
+            Some(v) => Ok(v),
+            None => err("uid out of bounds"),
+        }
+    }
+
+    fn add_nodes(&mut self, count: usize) -> Result<()> {
+        self.nodes += count;
+        if self.nodes > self.max_nodes {
+            return err("too many shared objects");
+        }
+        Ok(())
+    }
+
+    fn uid(&mut self, uid: Uid) -> Result<Obj> {
+        if let Some((_, count)) = self.cache.get(&uid.0) {
+            self.add_nodes(*count)?;
+            return Ok(self.cache[&uid.0].0.clone());
+        }
+        if self.stack.contains(&uid.0) {
+            self.targets.insert(uid.0);
+            self.refs += 1;
+            return Ok(Obj::Ref(uid));
+        }
+        if self.stack.len() >= MAX_DEPTH {
+            return err("too deep");
+        }
+        self.stack.push(uid.0);
+        let (nodes, refs) = (self.nodes, self.refs);
+        let res = self.object(uid).and_then(|v| self.object_value(v));
+        self.stack.pop();
+        let mut obj = res?;
+        self.add_nodes(1)?;
+        if self.targets.remove(&uid.0) {
+            obj = Obj::Referenced(uid, Box::new(obj));
+        }
+        // objects with back references depend on where they are decoded from
+        if self.refs == refs {
+            self.cache.insert(uid.0, (obj.clone(), self.nodes - nodes));
+        }
+        Ok(obj)
+    }
+
+    /// Resolves UID or converts inline value.
+    fn value(&mut self, value: &'a Value) -> Result<Obj> {
+        match value {
+            Value::Uid(uid) => self.uid(*uid),
+            Value::Array(items) => {
+                let mut res = Vec::with_capacity(items.len());
+                for v in items {
+                    res.push(self.value(v)?);
+                }
+                Ok(Obj::Array(res))
+            }
+            v => self.object_value(v),
+        }
+    }
+
+    fn uids(&mut self, value: Option<&'a Value>) -> Result<Vec<Obj>> {
+        let Some(Value::Array(items)) = value else {
+            return err("expected array of uids");
+        };
+        let mut res = Vec::with_capacity(items.len());
+        for v in items {
+            res.push(self.value(v)?);
+        }
+        Ok(res)
+    }
+
+    fn string(&mut self, value: Option<&'a Value>) -> Result<String> {
+        match value.map(|v| self.value(v)).transpose()? {
+            Some(Obj::String(s)) => Ok(s),
+            _ => err("expected string"),
+        }
+    }
+
+    fn object_value(&mut self, value: &'a Value) -> Result<Obj> {
+        let dict = match value {
+            Value::String(s) if s == NULL => return Ok(Obj::Null),
+            Value::String(s) => return Ok(Obj::String(s.clone())),
+            Value::Data(d) => return Ok(Obj::Data(d.clone())),
+            Value::Date(d) => return Ok(Obj::Date(*d)),
+            Value::Int(i) => return Ok(Obj::Int(*i)),
+            Value::Real(r) => return Ok(Obj::Real(*r)),
+            Value::Bool(b) => return Ok(Obj::Bool(*b)),
+            Value::Uid(_) | Value::Array(_) => return self.value(value),
+            Value::Dict(dict) => dict,
+        };
+
+        let Some(Value::Uid(class_uid)) = dict.get("$class") else {
+            return err("object without $class");
+        };
+        let class = self.object(*class_uid)?;
+        let Some(class_name) = class.get("$classname").and_then(|v| v.as_str()) else {
+            return err("class without $classname");
+        };
+
+        let obj = match class_name {
+            "NSArray" | "NSMutableArray" => Obj::Array(self.uids(dict.get("NS.objects"))?),
+            "NSSet" | "NSMutableSet" => Obj::Set(self.uids(dict.get("NS.objects"))?),
+            "NSDictionary" | "NSMutableDictionary" => {
+                let keys = self.uids(dict.get("NS.keys"))?;
+                let values = self.uids(dict.get("NS.objects"))?;
+                if keys.len() != values.len() {
+                    return err("NS.keys and NS.objects mismatch");
+                }
+                Obj::Dict(keys.into_iter().zip(values).collect())
+            }
+            "NSString" | "NSMutableString" => Obj::String(self.string(dict.get("NS.string"))?),
+            "NSData" | "NSMutableData" => match dict.get("NS.data").map(|v| self.value(v)) {
+                Some(Ok(Obj::Data(d))) => Obj::Data(d),
+                _ => return err("expected NS.data"),
+            },
+            "NSDate" => match dict.get("NS.time").and_then(|v| v.as_f64()) {
+                Some(t) => Obj::Date(Date(t)),
+                None => return err("expected NS.time"),
+            },
+            "NSUUID" => match dict.get("NS.uuidbytes").and_then(|v| v.as_data()) {
+                Some(b) if b.len() == 16 => Obj::Uuid(b.try_into().unwrap()),
+                _ => return err("expected NS.uuidbytes"),
+            },
+            "NSURL" => {
+                let base = match dict.get("NS.base").map(|v| self.value(v)).transpose()? {
+                    None | Some(Obj::Null) => None,
+                    Some(base) => Some(Box::new(base)),
+                };
+                let relative = self.string(dict.get("NS.relative"))?;
+                Obj::Url { base, relative }
+            }
+            "NSNull" => Obj::Null,
+            _ => {
+                let classes = class
+                    .get("$classes")
+                    .and_then(|v| v.as_array())
+                    .map(|a| {
+                        a.iter()
+                            .filter_map(|c| c.as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let mut fields = Vec::with_capacity(dict.len() - 1);
+                for (k, v) in dict.iter() {
+                    if k != "$class" {
+                        fields.push((k.to_string(), self.value(v)?));
+                    }
+                }
+                Obj::Custom(Custom {
+                    class_name: class_name.to_string(),
+                    classes,
+                    fields,
+                })
+            }
+        };
+        Ok(obj)
+    }
+}
+
+struct Encoder {
+    objects: Vec<Value>,
+    strings: HashMap<String, Uid>,
+    classes: HashMap<String, Uid>,
+    /// Source UIDs of [`Obj::Referenced`] being encoded to the new ones
+    refs: HashMap<Uid, Uid>,
+    /// Source UID of [`Obj::Referenced`] waiting for its object slot
+    pending_ref: Option<Uid>,
+}
+
+impl Encoder {
+    fn push(&mut self, value: impl Into<Value>) -> Uid {
+        let uid = Uid(self.objects.len() as u64);
+        self.objects.push(value.into());
+        uid
+    }
+
+    fn string(&mut self, s: &str) -> Uid {
+        if let Some(uid) = self.strings.get(s) {
+            return *uid;
+        }
+        let uid = self.push(s);
+        self.strings.insert(s.to_string(), uid);
+        uid
+    }
+
+    fn class(&mut self, classes: &[&str]) -> Uid {
+        if let Some(uid) = self.classes.get(classes[0]) {
+            return *uid;
+        }
+        let mut dict = Dict::new();
+        dict.insert("$classname", classes[0]);
+        dict.insert(
+            "$classes",
+            classes.iter().map(|c| Value::from(*c)).collect::<Vec<_>>(),
+        );
+        let uid = self.push(dict);
+        self.classes.insert(classes[0].to_string(), uid);
+        uid
+    }
+
+    /// Reserves slot so children get greater UIDs, like NSKeyedArchiver does.
+    fn object(&mut self, classes: &[&str], fill: impl FnOnce(&mut Self, &mut Dict)) -> Uid {
+        let uid = self.push(NULL);
+        if let Some(src) = self.pending_ref.take() {
+            self.refs.insert(src, uid);
+        }
+        let mut dict = Dict::new();
+        fill(self, &mut dict);
+        let class = self.class(classes);
+        dict.insert("$class", class);
+        self.objects[uid.0 as usize] = Value::Dict(dict);
+        uid
+    }
+
+    fn obj(&mut self, obj: &Obj) -> Uid {
+        match obj {
+            Obj::Null => Uid(0),
+            Obj::String(s) => self.string(s),
+            Obj::Data(d) => self.push(d.clone()),
+            Obj::Int(i) => self.push(*i),
+            Obj::Real(r) => self.push(*r),
+            Obj::Bool(b) => self.push(*b),
+            // dangling refs become nil
+            Obj::Ref(src) => self.refs.get(src).copied().unwrap_or(Uid(0)),
+            Obj::Referenced(src, obj) => {
+                let outer = self.refs.get(src).copied();
+                self.pending_ref = Some(*src);
+                let uid = self.obj(obj);
+                // object without children can't be referenced back
+                self.pending_ref = None;
+                match outer {
+                    Some(outer) => self.refs.insert(*src, outer),
+                    None => self.refs.remove(src),
+                };
+                uid
+            }
+            Obj::Date(d) => self.object(&["NSDate", "NSObject"], |_, dict| {
+                dict.insert("NS.time", d.0);
+            }),
+            Obj::Array(items) => self.object(&["NSArray", "NSObject"], |e, dict| {
+                let refs = items
+                    .iter()
+                    .map(|o| Value::Uid(e.obj(o)))
+                    .collect::<Vec<_>>();
+                dict.insert("NS.objects", refs);
+            }),
+            Obj::Set(items) => self.object(&["NSSet", "NSObject"], |e, dict| {
+                let refs = items
+                    .iter()
+                    .map(|o| Value::Uid(e.obj(o)))
+                    .collect::<Vec<_>>();
+                dict.insert("NS.objects", refs);
+            }),
+            Obj::Dict(entries) => self.object(&["NSDictionary", "NSObject"], |e, dict| {
+                let keys = entries
+                    .iter()
+                    .map(|(k, _)| Value::Uid(e.obj(k)))
+                    .collect::<Vec<_>>();
+                let values = entries
+                    .iter()
+                    .map(|(_, v)| Value::Uid(e.obj(v)))
+                    .collect::<Vec<_>>();
+                dict.insert("NS.keys", keys);
+                dict.insert("NS.objects", values);
+            }),
+            Obj::Uuid(bytes) => self.object(&["NSUUID", "NSObject"], |_, dict| {
+                dict.insert("NS.uuidbytes", &bytes[..]);
+            }),
+            Obj::Url { base, relative } => self.object(&["NSURL", "NSObject"], |e, dict| {
+                let base = base.as_deref().map_or(Uid(0), |b| e.obj(b));
+                dict.insert("NS.base", base);
+                dict.insert("NS.relative", e.string(relative));
+            }),
+            Obj::Custom(custom) => {
+                let classes: Vec<&str> = if custom.classes.is_empty() {
+                    vec![&custom.class_name, "NSObject"]
+                } else {
+                    custom.classes.iter().map(|c| c.as_str()).collect()
+                };
+                self.object(&classes, |e, dict| {
+                    for (k, v) in &custom.fields {
+                        // scalars are stored inline the way encodeInt:forKey: does
+                        let value = match v {
+                            Obj::Int(i) => Value::Int(*i),
+                            Obj::Real(r) => Value::Real(*r),
+                            Obj::Bool(b) => Value::Bool(*b),
+                            v => Value::Uid(e.obj(v)),
+                        };
+                        dict.insert(k.as_str(), value);
+                    }
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::plist::{self, keyed_archive::Custom, keyed_archive::Obj};
+
+    // NSKeyedArchiver output for
+    // @{@"title": @"Hello", @"count": @3, @"tags": @[@"a", @"b"], @"id": [NSUUID UUID]}
+    fn archive() -> plist::Value {
+        let src = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>$archiver</key>
+	<string>NSKeyedArchiver</string>
+	<key>$objects</key>
+	<array>
+		<string>$null</string>
+		<dict>
+			<key>$class</key>
+			<dict><key>CF$UID</key><integer>12</integer></dict>
+			<key>NS.keys</key>
+			<array>
+				<dict><key>CF$UID</key><integer>2</integer></dict>
+				<dict><key>CF$UID</key><integer>3</integer></dict>
+				<dict><key>CF$UID</key><integer>4</integer></dict>
+				<dict><key>CF$UID</key><integer>5</integer></dict>
+			</array>
+			<key>NS.objects</key>
+			<array>
+				<dict><key>CF$UID</key><integer>6</integer></dict>
+				<dict><key>CF$UID</key><integer>7</integer></dict>
+				<dict><key>CF$UID</key><integer>8</integer></dict>
+				<dict><key>CF$UID</key><integer>10</integer></dict>
+			</array>
+		</dict>
+		<string>title</string>
+		<string>count</string>
+		<string>tags</string>
+		<string>id</string>
+		<string>Hello</string>
+		<integer>3</integer>
+		<dict>
+			<key>$class</key>
+			<dict><key>CF$UID</key><integer>9</integer></dict>
+			<key>NS.objects</key>
+			<array>
+				<dict><key>CF$UID</key><integer>13</integer></dict>
+				<dict><key>CF$UID</key><integer>14</integer></dict>
+			</array>
+		</dict>
+		<dict>
+			<key>$classes</key>
+			<array><string>NSArray</string><string>NSObject</string></array>
+			<key>$classname</key>
+			<string>NSArray</string>
+		</dict>
+		<dict>
+			<key>$class</key>
+			<dict><key>CF$UID</key><integer>11</integer></dict>
+			<key>NS.uuidbytes</key>
+			<data>AAECAwQFBgcICQoLDA0ODw==</data>
+		</dict>
+		<dict>
+			<key>$classes</key>
+			<array><string>NSUUID</string><string>NSObject</string></array>
+			<key>$classname</key>
+			<string>NSUUID</string>
+		</dict>
+		<dict>
+			<key>$classes</key>
+			<array><string>NSDictionary</string><string>NSObject</string></array>
+			<key>$classname</key>
+			<string>NSDictionary</string>
+		</dict>
+		<string>a</string>
+		<string>b</string>
+	</array>
+	<key>$top</key>
+	<dict>
+		<key>root</key>
+		<dict><key>CF$UID</key><integer>1</integer></dict>
+	</dict>
+	<key>$version</key>
+	<integer>100000</integer>
+</dict>
+</plist>
+"#;
+        plist::Value::from_bytes(src.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn decode() {
+        let archive = plist::KeyedArchive::with_plist(archive()).unwrap();
+        assert_eq!(archive.top_keys().collect::<Vec<_>>(), ["root"]);
+        let root = archive.decode_root().unwrap();
+        assert_eq!(root.get("title").unwrap().as_str(), Some("Hello"));
+        assert_eq!(root.get("count"), Some(&Obj::Int(3)));
+        assert_eq!(
+            root.get("tags"),
+            Some(&Obj::Array(vec![
+                Obj::String("a".into()),
+                Obj::String("b".into())
+            ]))
+        );
+        let uuid: [u8; 16] = std::array::from_fn(|i| i as u8);
+        assert_eq!(root.get("id"), Some(&Obj::Uuid(uuid)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let activity = Obj::Custom(Custom {
+            class_name: "NSUserActivity".into(),
+            classes: vec![],
+            fields: vec![
+                ("type".into(), Obj::String("com.example.view".into())),
+                ("eligibleForSearch".into(), Obj::Bool(true)),
+                ("version".into(), Obj::Int(2)),
+                (
+                    "url".into(),
+                    Obj::Url {
+                        base: None,
+                        relative: "https://example.com/a".into(),
+                    },
+                ),
+                ("created".into(), Obj::Date(plist::Date(700_000_000.5))),
+                ("payload".into(), Obj::Data(vec![1, 2, 3])),
+                (
+                    "userInfo".into(),
+                    Obj::Dict(vec![
+                        (Obj::String("k".into()), Obj::Set(vec![Obj::Int(1)])),
+                        (Obj::String("n".into()), Obj::Null),
+                    ]),
+                ),
+            ],
+        });
+
+        for format in [plist::Format::Binary, plist::Format::Xml] {
+            let bytes = activity.archive_to_vec(format);
+            let decoded = Obj::unarchive(&bytes).unwrap();
+            let custom = decoded.as_custom().unwrap();
+            assert_eq!(custom.class_name, "NSUserActivity");
+            assert_eq!(custom.classes, ["NSUserActivity", "NSObject"]);
+            assert_eq!(custom.fields, activity.as_custom().unwrap().fields);
+        }
+    }
+
+    #[test]
+    fn cycle() {
+        // node whose "parent" field points to itself, not at the root and
+        // with UID that doesn't match the new numbering
+        let node = Obj::Referenced(
+            plist::Uid(42),
+            Box::new(Obj::Custom(Custom {
+                class_name: "Node".into(),
+                classes: vec![],
+                fields: vec![
+                    ("name".into(), Obj::String("n".into())),
+                    ("parent".into(), Obj::Ref(plist::Uid(42))),
+                ],
+            })),
+        );
+        let mut archive = Obj::Array(vec![Obj::String("first".into()), node]).archive();
+        let decoded = plist::KeyedArchive::with_plist(archive.clone())
+            .unwrap()
+            .decode_root()
+            .unwrap();
+        let Obj::Array(items) = &decoded else {
+            panic!("expected array");
+        };
+        let Obj::Referenced(uid, _) = &items[1] else {
+            panic!("expected referenced node");
+        };
+        assert_ne!(*uid, plist::Uid(42));
+        assert_eq!(items[1].get("parent"), Some(&Obj::Ref(*uid)));
+        assert_eq!(items[1].get("name").unwrap().as_str(), Some("n"));
+
+        // archive of decoded graph decodes to the same graph
+        let again = Obj::unarchive(&decoded.archive_to_vec(plist::Format::Binary)).unwrap();
+        assert_eq!(again, decoded);
+
+        archive.as_dict_mut().unwrap().insert("$archiver", "Other");
+        assert!(plist::KeyedArchive::with_plist(archive).is_err());
+    }
+
+    #[test]
+    fn shared() {
+        // each array refers to the next one twice
+        let dag = |levels: u64| {
+            let mut objects = vec![plist::Value::from("$null")];
+            let class = plist::Uid(levels + 2);
+            for i in 0..levels {
+                let mut dict = plist::Dict::new();
+                dict.insert("$class", class);
+                let next = plist::Value::Uid(plist::Uid(i + 2));
+                dict.insert("NS.objects", vec![next.clone(), next]);
+                objects.push(plist::Value::Dict(dict));
+            }
+            objects.push(plist::Value::from("leaf"));
+            let mut dict = plist::Dict::new();
+            dict.insert("$classname", "NSArray");
+            objects.push(plist::Value::Dict(dict));
+
+            let mut top = plist::Dict::new();
+            top.insert("root", plist::Uid(1));
+            let mut archive = plist::Dict::new();
+            archive.insert("$archiver", "NSKeyedArchiver");
+            archive.insert("$top", top);
+            archive.insert("$objects", objects);
+            plist::KeyedArchive::with_plist(plist::Value::Dict(archive)).unwrap()
+        };
+
+        let leaf = Obj::String("leaf".into());
+        let pair = Obj::Array(vec![leaf.clone(), leaf]);
+        let quad = Obj::Array(vec![pair.clone(), pair]);
+        assert_eq!(
+            dag(3).decode_root(),
+            Ok(Obj::Array(vec![quad.clone(), quad]))
+        );
+
+        assert_eq!(
+            dag(64).decode_root(),
+            Err(plist::Error::Archive("too many shared objects"))
+        );
+    }
+}
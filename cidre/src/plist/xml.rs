@@ -0,0 +1,601 @@
+use std::borrow::Cow;
+
+use crate::plist::{Date, Dict, Error, Result, Uid, Value, binary::MAX_DEPTH};
+
+const HEADER: &str = concat!(
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+    "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" ",
+    "\"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+    "<plist version=\"1.0\">\n"
+);
+
+const UID_KEY: &str = "CF$UID";
+
+const BOM: &[u8] = b"\xef\xbb\xbf";
+
+/// Checks for XML declaration, plist doctype or `<plist>` root.
+pub fn is_xml(bytes: &[u8]) -> bool {
+    let bytes = bytes.strip_prefix(BOM).unwrap_or(bytes);
+    let start = bytes
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    let bytes = &bytes[start..];
+    bytes.starts_with(b"<?xml") || bytes.starts_with(b"<!DOCTYPE") || bytes.starts_with(b"<plist")
+}
+
+/// Writes XML property list the way `plutil` formats it (tab indented).
+pub fn write(value: &Value) -> Vec<u8> {
+    let mut buf = String::with_capacity(1024);
+    buf.push_str(HEADER);
+    write_value(&mut buf, value, 0);
+    buf.push_str("</plist>\n");
+    buf.into_bytes()
+}
+
+fn indent(buf: &mut String, level: usize) {
+    for _ in 0..level {
+        buf.push('\t');
+    }
+}
+
+fn escape_into(buf: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            c => buf.push(c),
+        }
+    }
+}
+
+fn write_value(buf: &mut String, value: &Value, level: usize) {
+    indent(buf, level);
+    match value {
+        Value::Dict(dict) if dict.is_empty() => buf.push_str("<dict/>\n"),
+        Value::Dict(dict) => {
+            buf.push_str("<dict>\n");
+            for (k, v) in dict.iter() {
+                indent(buf, level + 1);
+                buf.push_str("<key>");
+                escape_into(buf, k);
+                buf.push_str("</key>\n");
+                write_value(buf, v, level + 1);
+            }
+            indent(buf, level);
+            buf.push_str("</dict>\n");
+        }
+        Value::Array(items) if items.is_empty() => buf.push_str("<array/>\n"),
+        Value::Array(items) => {
+            buf.push_str("<array>\n");
+            for v in items {
+                write_value(buf, v, level + 1);
+            }
+            indent(buf, level);
+            buf.push_str("</array>\n");
+        }
+        Value::String(s) => {
+            buf.push_str("<string>");
+            escape_into(buf, s);
+            buf.push_str("</string>\n");
+        }
+        Value::Data(d) => {
+            buf.push_str("<data>");
+            base64_encode(buf, d);
+            buf.push_str("</data>\n");
+        }
+        Value::Date(d) => {
+            buf.push_str("<date>");
+            buf.push_str(&d.to_iso8601());
+            buf.push_str("</date>\n");
+        }
+        Value::Int(i) => {
+            buf.push_str(&format!("<integer>{i}</integer>\n"));
+        }
+        Value::Real(r) => {
+            let s = if r.is_nan() {
+                "nan".to_string()
+            } else if r.is_infinite() {
+                if *r > 0.0 { "+infinity" } else { "-infinity" }.to_string()
+            } else {
+                format!("{r}")
+            };
+            buf.push_str(&format!("<real>{s}</real>\n"));
+        }
+        Value::Bool(true) => buf.push_str("<true/>\n"),
+        Value::Bool(false) => buf.push_str("<false/>\n"),
+        Value::Uid(uid) => {
+            // The way Core Foundation writes CFKeyedArchiverUID
+            buf.push_str("<dict>\n");
+            indent(buf, level + 1);
+            buf.push_str(&format!("<key>{UID_KEY}</key>\n"));
+            indent(buf, level + 1);
+            buf.push_str(&format!("<integer>{}</integer>\n", uid.0));
+            indent(buf, level);
+            buf.push_str("</dict>\n");
+        }
+    }
+}
+
+/// Reads XML property list.
+pub fn read(bytes: &[u8]) -> Result<Value> {
+    let bytes = bytes.strip_prefix(BOM).unwrap_or(bytes);
+    let Ok(src) = std::str::from_utf8(bytes) else {
+        return Err(Error::Xml(0, "not utf8"));
+    };
+    let mut p = Parser {
+        src,
+        pos: 0,
+        depth: 0,
+    };
+    p.skip_misc()?;
+    let tag = p.open_tag()?;
+    if tag.name != "plist" {
+        // CF accepts plist without root element
+        let value = p.value_for(tag)?;
+        return Ok(value);
+    }
+    if tag.empty {
+        return p.err("empty plist");
+    }
+    p.skip_misc()?;
+    let tag = p.open_tag()?;
+    let value = p.value_for(tag)?;
+    p.skip_misc()?;
+    p.close_tag("plist")?;
+    Ok(value)
+}
+
+struct Tag<'a> {
+    name: &'a str,
+    /// `<tag/>`
+    empty: bool,
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn err<T>(&self, reason: &'static str) -> Result<T> {
+        Err(Error::Xml(self.pos, reason))
+    }
+
+    #[inline]
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn skip_until(&mut self, pat: &str) -> Result {
+        match self.rest().find(pat) {
+            Some(i) => {
+                self.pos += i + pat.len();
+                Ok(())
+            }
+            None => self.err("unterminated markup"),
+        }
+    }
+
+    /// Skips whitespace, comments, processing instructions and doctype.
+    fn skip_misc(&mut self) -> Result {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if trimmed.starts_with("<!--") {
+                self.skip_until("-->")?;
+            } else if trimmed.starts_with("<?") {
+                self.skip_until("?>")?;
+            } else if trimmed.starts_with("<!DOCTYPE") {
+                self.skip_until(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn open_tag(&mut self) -> Result<Tag<'a>> {
+        let rest = self.rest();
+        if !rest.starts_with('<') || rest.starts_with("</") {
+            return self.err("expected element");
+        }
+        let Some(end) = rest.find('>') else {
+            return self.err("unterminated tag");
+        };
+        let inner = &rest[1..end];
+        let (inner, empty) = match inner.strip_suffix('/') {
+            Some(inner) => (inner, true),
+            None => (inner, false),
+        };
+        let name_end = inner
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(inner.len());
+        self.pos += end + 1;
+        Ok(Tag {
+            name: &inner[..name_end],
+            empty,
+        })
+    }
+
+    fn close_tag(&mut self, name: &str) -> Result {
+        let rest = self.rest();
+        let Some(rest) = rest.strip_prefix("</") else {
+            return self.err("expected closing tag");
+        };
+        let Some(rest) = rest.strip_prefix(name) else {
+            return self.err("mismatched closing tag");
+        };
+        let trimmed = rest.trim_start();
+        if !trimmed.starts_with('>') {
+            return self.err("mismatched closing tag");
+        }
+        self.pos = self.src.len() - trimmed.len() + 1;
+        Ok(())
+    }
+
+    /// Reads character data up to `</name>`, resolving entities and CDATA sections.
+    fn text(&mut self, tag: &Tag, name: &str) -> Result<Cow<'a, str>> {
+        if tag.empty {
+            return Ok(Cow::Borrowed(""));
+        }
+        let start = self.pos;
+        let rest = self.rest();
+        let end = rest.find('<').unwrap_or(rest.len());
+        if !rest[..end].contains('&') && rest[end..].starts_with("</") {
+            self.pos += end;
+            self.close_tag(name)?;
+            return Ok(Cow::Borrowed(&self.src[start..start + end]));
+        }
+
+        let mut res = String::new();
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.close_tag(name)?;
+                return Ok(Cow::Owned(res));
+            } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+                let Some(end) = cdata.find("]]>") else {
+                    return self.err("unterminated CDATA");
+                };
+                res.push_str(&cdata[..end]);
+                self.pos += "<![CDATA[".len() + end + 3;
+            } else if rest.starts_with("<!--") {
+                self.skip_until("-->")?;
+            } else if rest.starts_with('<') {
+                return self.err("unexpected element in text");
+            } else if let Some(entity) = rest.strip_prefix('&') {
+                let Some(end) = entity.find(';') else {
+                    return self.err("unterminated entity");
+                };
+                let c = match &entity[..end] {
+                    "amp" => '&',
+                    "lt" => '<',
+                    "gt" => '>',
+                    "quot" => '"',
+                    "apos" => '\'',
+                    e => {
+                        let code = if let Some(hex) = e.strip_prefix("#x") {
+                            u32::from_str_radix(hex, 16).ok()
+                        } else if let Some(dec) = e.strip_prefix('#') {
+                            dec.parse().ok()
+                        } else {
+                            None
+                        };
+                        match code.and_then(char::from_u32) {
+                            Some(c) => c,
+                            None => return self.err("unknown entity"),
+                        }
+                    }
+                };
+                res.push(c);
+                self.pos += end + 2;
+            } else if rest.is_empty() {
+                return self.err("unexpected end");
+            } else {
+                let end = rest.find(['<', '&']).unwrap_or(rest.len());
+                res.push_str(&rest[..end]);
+                self.pos += end;
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        self.skip_misc()?;
+        let tag = self.open_tag()?;
+        self.value_for(tag)
+    }
+
+    fn value_for(&mut self, tag: Tag<'a>) -> Result<Value> {
+        if self.depth >= MAX_DEPTH {
+            return self.err("too deep");
+        }
+        self.depth += 1;
+        let res = self.element(tag);
+        self.depth -= 1;
+        res
+    }
+
+    fn dict(&mut self, tag: &Tag<'a>) -> Result<Value> {
+        let mut entries = Vec::new();
+        if !tag.empty {
+            loop {
+                self.skip_misc()?;
+                if self.rest().starts_with("</") {
+                    self.close_tag("dict")?;
+                    break;
+                }
+                let key_tag = self.open_tag()?;
+                if key_tag.name != "key" {
+                    return self.err("expected key");
+                }
+                let key = self.text(&key_tag, "key")?.into_owned();
+                entries.push((key, self.value()?));
+            }
+        }
+        let dict: Dict = entries.into_iter().collect();
+        if dict.len() == 1
+            && let Some(Value::Int(uid)) = dict.get(UID_KEY)
+        {
+            Ok(Value::Uid(Uid(*uid as u64)))
+        } else {
+            Ok(Value::Dict(dict))
+        }
+    }
+
+    /// Containers are parsed apart from scalars to keep recursive frames small.
+    fn element(&mut self, tag: Tag<'a>) -> Result<Value> {
+        match tag.name {
+            "dict" => self.dict(&tag),
+            "array" => self.array(&tag),
+            _ => self.scalar(tag),
+        }
+    }
+
+    fn array(&mut self, tag: &Tag<'a>) -> Result<Value> {
+        let mut items = Vec::new();
+        if !tag.empty {
+            loop {
+                self.skip_misc()?;
+                if self.rest().starts_with("</") {
+                    self.close_tag("array")?;
+                    break;
+                }
+                items.push(self.value()?);
+            }
+        }
+        Ok(Value::Array(items))
+    }
+
+    fn scalar(&mut self, tag: Tag<'a>) -> Result<Value> {
+        let pos = self.pos;
+        let value = match tag.name {
+            "string" => Value::String(self.text(&tag, "string")?.into_owned()),
+            "data" => {
+                let text = self.text(&tag, "data")?;
+                match base64_decode(&text) {
+                    Some(d) => Value::Data(d),
+                    None => return Err(Error::Xml(pos, "invalid base64")),
+                }
+            }
+            "date" => {
+                let text = self.text(&tag, "date")?;
+                match Date::from_iso8601(text.trim()) {
+                    Some(d) => Value::Date(d),
+                    None => return Err(Error::Xml(pos, "invalid date")),
+                }
+            }
+            "integer" => {
+                let text = self.text(&tag, "integer")?;
+                match parse_int(text.trim()) {
+                    Some(i) => Value::Int(i),
+                    None => return Err(Error::Xml(pos, "invalid integer")),
+                }
+            }
+            "real" => {
+                let text = self.text(&tag, "real")?;
+                let text = text.trim();
+                let r = match text.to_ascii_lowercase().as_str() {
+                    "nan" => Some(f64::NAN),
+                    "inf" | "infinity" | "+infinity" | "+inf" => Some(f64::INFINITY),
+                    "-inf" | "-infinity" => Some(f64::NEG_INFINITY),
+                    _ => text.parse().ok(),
+                };
+                match r {
+                    Some(r) => Value::Real(r),
+                    None => return Err(Error::Xml(pos, "invalid real")),
+                }
+            }
+            "true" | "false" => {
+                if !tag.empty {
+                    self.skip_misc()?;
+                    self.close_tag(tag.name)?;
+                }
+                Value::Bool(tag.name == "true")
+            }
+            _ => return Err(Error::Xml(pos, "unknown element")),
+        };
+        Ok(value)
+    }
+}
+
+fn parse_int(text: &str) -> Option<i64> {
+    let (neg, digits) = match text.strip_prefix('-') {
+        Some(d) => (true, d),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let v = if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        u64::from_str_radix(hex, 16).ok()?
+    } else {
+        digits.parse::<u64>().ok()?
+    };
+    if neg {
+        // allow i64::MIN
+        if v > i64::MIN.unsigned_abs() {
+            return None;
+        }
+        Some((v as i64).wrapping_neg())
+    } else {
+        i64::try_from(v).ok()
+    }
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(buf: &mut String, data: &[u8]) {
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                buf.push(BASE64[((n >> (18 - i * 6)) & 0x3f) as usize] as char);
+            } else {
+                buf.push('=');
+            }
+        }
+    }
+}
+
+fn base64_decode(text: &str) -> Option<Vec<u8>> {
+    let mut res = Vec::with_capacity(text.len() * 3 / 4);
+    let mut acc = 0u32;
+    let mut bits = 0;
+    for c in text.bytes() {
+        let v = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            c if c.is_ascii_whitespace() => continue,
+            _ => return None,
+        };
+        acc = (acc << 6) | v as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            res.push((acc >> bits) as u8);
+        }
+    }
+    Some(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::plist::{self, xml};
+
+    #[test]
+    fn basics() {
+        let src = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<!-- comment -->
+	<key>com.apple.security.app-sandbox</key>
+	<true/>
+	<key>Name</key>
+	<string>Tom &amp; Jerry &#x1F980; <![CDATA[<raw>]]></string>
+	<key>Data</key>
+	<data>
+	AAEC/w==
+	</data>
+	<key>Count</key>
+	<integer> -12 </integer>
+	<key>Hex</key>
+	<integer>0x10</integer>
+	<key>Scale</key>
+	<real>0.5</real>
+	<key>Inf</key>
+	<real>-infinity</real>
+	<key>Empty</key>
+	<string/>
+	<key>Ref</key>
+	<dict>
+		<key>CF$UID</key>
+		<integer>7</integer>
+	</dict>
+</dict>
+</plist>
+"#;
+        let value = xml::read(src.as_bytes()).unwrap();
+        assert_eq!(
+            value.get("com.apple.security.app-sandbox"),
+            Some(&plist::Value::Bool(true))
+        );
+        assert_eq!(
+            value.get("Name").unwrap().as_str(),
+            Some("Tom & Jerry 🦀 <raw>")
+        );
+        assert_eq!(
+            value.get("Data").unwrap().as_data(),
+            Some(&[0, 1, 2, 0xff][..])
+        );
+        assert_eq!(value.get("Count").unwrap().as_i64(), Some(-12));
+        assert_eq!(value.get("Hex").unwrap().as_i64(), Some(16));
+        assert_eq!(value.get("Scale").unwrap().as_f64(), Some(0.5));
+        assert_eq!(value.get("Inf").unwrap().as_f64(), Some(f64::NEG_INFINITY));
+        assert_eq!(value.get("Empty").unwrap().as_str(), Some(""));
+        assert_eq!(value.get("Ref").unwrap().as_uid(), Some(plist::Uid(7)));
+    }
+
+    #[test]
+    fn write() {
+        let mut dict = plist::Dict::new();
+        dict.insert("a", vec![plist::Value::Bool(false)]);
+        dict.insert("b", "x<y");
+        let out = xml::write(&plist::Value::Dict(dict));
+        let out = std::str::from_utf8(&out).unwrap();
+        assert!(out.ends_with(
+            "<plist version=\"1.0\">\n<dict>\n\t<key>a</key>\n\t<array>\n\t\t<false/>\n\t</array>\n\t<key>b</key>\n\t<string>x&lt;y</string>\n</dict>\n</plist>\n"
+        ));
+    }
+
+    #[test]
+    fn base64() {
+        for len in 0..8 {
+            let data: Vec<u8> = (0..len).map(|i| i * 37).collect();
+            let mut s = String::new();
+            super::base64_encode(&mut s, &data);
+            assert_eq!(s.len() % 4, 0);
+            assert_eq!(super::base64_decode(&s).unwrap(), data);
+        }
+        let mut s = String::new();
+        super::base64_encode(&mut s, b"hello");
+        assert_eq!(s, "aGVsbG8=");
+    }
+
+    #[test]
+    fn malformed() {
+        for src in [
+            "<plist><dict><key>a</key></dict></plist>",
+            "<plist><array><string>a</array></plist>",
+            "<plist><integer>x</integer></plist>",
+            "<plist><foo/></plist>",
+            "<plist><string>&bogus;</string></plist>",
+            "<plist><integer>9223372036854775808</integer></plist>",
+        ] {
+            assert!(xml::read(src.as_bytes()).is_err(), "{src}");
+        }
+        let max = "<integer>9223372036854775807</integer>";
+        assert_eq!(xml::read(max.as_bytes()), Ok(plist::Value::Int(i64::MAX)));
+
+        let nested = |n| format!("{}{}", "<array>".repeat(n), "</array>".repeat(n));
+        assert!(xml::read(nested(500).as_bytes()).is_ok());
+        let dicts = "<dict><key>a</key>".repeat(499) + "<dict/>" + &"</dict>".repeat(499);
+        assert!(xml::read(dicts.as_bytes()).is_ok());
+        assert_eq!(
+            xml::read(nested(200_000).as_bytes()),
+            Err(plist::Error::Xml(513 * 7, "too deep"))
+        );
+    }
+}
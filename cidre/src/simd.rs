@@ -1,6 +1,11 @@
 pub mod vector_types;
 pub use vector_types::Simd;
 
+pub use cidre_macros::MslLayout;
+
+mod matrix;
+mod quaternion;
+
 #[allow(non_camel_case_types)]
 pub type i8x2 = Simd<i8, 2, 2>;
 #[allow(non_camel_case_types)]
@@ -290,27 +295,27 @@ pub struct f16x2x3(pub [f16x3; 2]);
 #[allow(non_camel_case_types)]
 pub struct f16x3x3(pub [f16x3; 3]);
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct f32x2x2(pub [f32x2; 2]);
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct f32x3x2(pub [f32x2; 3]);
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct f32x4x2(pub [f32x2; 4]);
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct f32x2x3(pub [f32x3; 2]);
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct f32x3x3(pub [f32x3; 3]);
@@ -341,6 +346,21 @@ impl f32x3x3 {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f32x4x3(pub [f32x3; 4]);
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f32x2x4(pub [f32x4; 2]);
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f32x3x4(pub [f32x4; 3]);
+
 #[cfg(not(target_arch = "aarch64"))]
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
@@ -673,7 +693,6 @@ impl std::ops::DerefMut for f32quat {
     }
 }
 
-#[cfg(target_arch = "aarch64")]
 impl f32quat {
     pub fn load(vals: &[f32; 4]) -> Self {
         Self(f32x4::load(vals))
@@ -788,6 +807,28 @@ mod tests {
         let _y = f32x2x2([f32x2::with_xy(1.0, 0.0), f32x2::with_xy(1.0, 0.0)]);
     }
 
+    #[test]
+    fn msl_layout() {
+        use crate::simd::{self, MslLayout};
+
+        #[derive(MslLayout)]
+        #[repr(C, align(16))]
+        struct Uniforms {
+            mvp: simd::f32x4x4,
+            light: simd::f32x3,
+            power: f32,
+            uv: simd::packed::f32x2,
+        }
+
+        assert_eq!(
+            Uniforms::MSL,
+            "struct Uniforms {\n    float4x4 mvp;\n    float3 light;\n    float power;\n    packed_float2 uv;\n};\n"
+        );
+        assert_eq!(Uniforms::MSL_SIZE, 96);
+        assert_eq!(Uniforms::MSL_ALIGN, 16);
+        assert_eq!(std::mem::offset_of!(Uniforms, uv), 84);
+    }
+
     #[cfg(feature = "half")]
     #[test]
     fn f16quat() {
@@ -0,0 +1,562 @@
+//! Matrix math for the `f32` matrix types.
+//!
+//! Matrices are column-major like `simd_floatNxM`: `f32x3x2` has three columns of `f32x2`.
+//! All math goes through plain `f32` lanes in the same order on every target,
+//! so results are identical on aarch64 and with the generic vector types.
+
+use super::{
+    f32x2, f32x2x2, f32x2x3, f32x2x4, f32x3, f32x3x2, f32x3x3, f32x3x4, f32x4, f32x4x2, f32x4x3,
+    f32x4x4,
+};
+
+pub(super) trait Lanes<const N: usize>: Copy {
+    fn to_array(self) -> [f32; N];
+    fn from_array(a: [f32; N]) -> Self;
+}
+
+impl Lanes<2> for f32x2 {
+    #[inline]
+    fn to_array(self) -> [f32; 2] {
+        [self.x(), self.y()]
+    }
+
+    #[inline]
+    fn from_array(a: [f32; 2]) -> Self {
+        Self::load(&a)
+    }
+}
+
+impl Lanes<3> for f32x3 {
+    #[inline]
+    fn to_array(self) -> [f32; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+
+    #[inline]
+    fn from_array(a: [f32; 3]) -> Self {
+        Self::with_xyz_f32(a[0], a[1], a[2])
+    }
+}
+
+impl Lanes<4> for f32x4 {
+    #[inline]
+    fn to_array(self) -> [f32; 4] {
+        [self.x(), self.y(), self.z(), self.w()]
+    }
+
+    #[inline]
+    fn from_array(a: [f32; 4]) -> Self {
+        Self::load(&a)
+    }
+}
+
+/// Matrix with `C` columns of `R` rows.
+pub(super) trait Cols<const C: usize, const R: usize>: Sized {
+    fn cols(&self) -> [[f32; R]; C];
+    fn with_cols(cols: [[f32; R]; C]) -> Self;
+}
+
+macro_rules! cols {
+    ($($mat:ty: $c:literal x $r:literal),*) => {
+        $(
+            impl Cols<$c, $r> for $mat {
+                #[inline]
+                fn cols(&self) -> [[f32; $r]; $c] {
+                    self.0.map(Lanes::to_array)
+                }
+
+                #[inline]
+                fn with_cols(cols: [[f32; $r]; $c]) -> Self {
+                    Self(cols.map(Lanes::from_array))
+                }
+            }
+        )*
+    };
+}
+
+cols!(
+    f32x2x2: 2 x 2,
+    f32x3x2: 3 x 2,
+    f32x4x2: 4 x 2,
+    f32x2x3: 2 x 3,
+    f32x3x3: 3 x 3,
+    f32x4x3: 4 x 3,
+    f32x2x4: 2 x 4,
+    f32x3x4: 3 x 4
+);
+
+impl Cols<4, 4> for f32x4x4 {
+    #[inline]
+    fn cols(&self) -> [[f32; 4]; 4] {
+        self.columns().map(Lanes::to_array)
+    }
+
+    #[inline]
+    fn with_cols(cols: [[f32; 4]; 4]) -> Self {
+        Self::with_columns(cols.map(Lanes::from_array))
+    }
+}
+
+impl f32x4x4 {
+    #[cfg(not(target_arch = "aarch64"))]
+    #[inline]
+    pub fn with_columns(cols: [f32x4; 4]) -> Self {
+        Self(cols)
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[inline]
+    pub fn with_columns(cols: [f32x4; 4]) -> Self {
+        Self(std::arch::aarch64::float32x4x4_t(
+            cols[0].0, cols[1].0, cols[2].0, cols[3].0,
+        ))
+    }
+
+    #[inline]
+    pub fn columns(&self) -> [f32x4; 4] {
+        [self[0], self[1], self[2], self[3]]
+    }
+}
+
+fn mul<const C: usize, const R: usize, const K: usize>(
+    a: &[[f32; R]; C],
+    b: &[[f32; C]; K],
+) -> [[f32; R]; K] {
+    let mut out = [[0.0; R]; K];
+    for k in 0..K {
+        for r in 0..R {
+            let mut sum = a[0][r] * b[k][0];
+            for c in 1..C {
+                sum += a[c][r] * b[k][c];
+            }
+            out[k][r] = sum;
+        }
+    }
+    out
+}
+
+fn transpose<const C: usize, const R: usize>(a: &[[f32; R]; C]) -> [[f32; C]; R] {
+    let mut out = [[0.0; C]; R];
+    for (c, col) in a.iter().enumerate() {
+        for (r, v) in col.iter().enumerate() {
+            out[r][c] = *v;
+        }
+    }
+    out
+}
+
+macro_rules! mat {
+    ($mat:ty: $col:ty, $row:ty, $t:ty; $($rhs:ty => $out:ty),*) => {
+        impl $mat {
+            #[inline]
+            pub fn transpose(&self) -> $t {
+                <$t>::with_cols(transpose(&self.cols()))
+            }
+        }
+
+        impl std::ops::Mul<$row> for $mat {
+            type Output = $col;
+
+            #[inline]
+            fn mul(self, rhs: $row) -> $col {
+                <$col>::from_array(mul(&self.cols(), &[rhs.to_array()])[0])
+            }
+        }
+
+        $(
+            impl std::ops::Mul<$rhs> for $mat {
+                type Output = $out;
+
+                #[inline]
+                fn mul(self, rhs: $rhs) -> $out {
+                    <$out>::with_cols(mul(&self.cols(), &rhs.cols()))
+                }
+            }
+        )*
+    };
+}
+
+// matrix: column type, vector it multiplies, transposed type; rhs => product
+mat!(f32x2x2: f32x2, f32x2, f32x2x2; f32x2x2 => f32x2x2, f32x3x2 => f32x3x2, f32x4x2 => f32x4x2);
+mat!(f32x3x2: f32x2, f32x3, f32x2x3; f32x2x3 => f32x2x2, f32x3x3 => f32x3x2, f32x4x3 => f32x4x2);
+mat!(f32x4x2: f32x2, f32x4, f32x2x4; f32x2x4 => f32x2x2, f32x3x4 => f32x3x2, f32x4x4 => f32x4x2);
+mat!(f32x2x3: f32x3, f32x2, f32x3x2; f32x2x2 => f32x2x3, f32x3x2 => f32x3x3, f32x4x2 => f32x4x3);
+mat!(f32x3x3: f32x3, f32x3, f32x3x3; f32x2x3 => f32x2x3, f32x3x3 => f32x3x3, f32x4x3 => f32x4x3);
+mat!(f32x4x3: f32x3, f32x4, f32x3x4; f32x2x4 => f32x2x3, f32x3x4 => f32x3x3, f32x4x4 => f32x4x3);
+mat!(f32x2x4: f32x4, f32x2, f32x4x2; f32x2x2 => f32x2x4, f32x3x2 => f32x3x4, f32x4x2 => f32x4x4);
+mat!(f32x3x4: f32x4, f32x3, f32x4x3; f32x2x3 => f32x2x4, f32x3x3 => f32x3x4, f32x4x3 => f32x4x4);
+mat!(f32x4x4: f32x4, f32x4, f32x4x4; f32x2x4 => f32x2x4, f32x3x4 => f32x3x4, f32x4x4 => f32x4x4);
+
+#[inline]
+fn scaled<const C: usize, const R: usize>(mut a: [[f32; R]; C], s: f32) -> [[f32; R]; C] {
+    for v in a.iter_mut().flatten() {
+        *v *= s;
+    }
+    a
+}
+
+impl f32x2x2 {
+    pub fn diagonal(v: f32x2) -> Self {
+        Self([f32x2::with_xy(v.x(), 0.0), f32x2::with_xy(0.0, v.y())])
+    }
+
+    pub fn identity() -> Self {
+        Self::diagonal(f32x2::with_xy(1.0, 1.0))
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let [a, b] = self.cols();
+        a[0] * b[1] - b[0] * a[1]
+    }
+
+    /// Like `simd_inverse`, singular matrices give non-finite values.
+    pub fn inverse(&self) -> Self {
+        let [a, b] = self.cols();
+        let det = self.determinant();
+        Self::with_cols(scaled([[b[1], -a[1]], [-b[0], a[0]]], 1.0 / det))
+    }
+}
+
+#[inline]
+pub(super) fn dot3(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline]
+pub(super) fn cross3(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+#[inline]
+pub(super) fn normalize3(a: [f32; 3]) -> [f32; 3] {
+    let inv = 1.0 / dot3(a, a).sqrt();
+    [a[0] * inv, a[1] * inv, a[2] * inv]
+}
+
+#[inline]
+fn sub3(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+impl f32x3x3 {
+    pub fn determinant(&self) -> f32 {
+        let [a, b, c] = self.cols();
+        dot3(a, cross3(b, c))
+    }
+
+    /// Like `simd_inverse`, singular matrices give non-finite values.
+    pub fn inverse(&self) -> Self {
+        let [a, b, c] = self.cols();
+        // rows of the inverse are the cross products of the columns
+        let rows = [cross3(b, c), cross3(c, a), cross3(a, b)];
+        let det = dot3(a, rows[0]);
+        Self::with_cols(scaled(transpose(&rows), 1.0 / det))
+    }
+}
+
+impl f32x4x4 {
+    /// Cofactors of the matrix and its determinant.
+    fn cofactors(&self) -> ([f32; 16], f32) {
+        let c = self.cols();
+        let m: [f32; 16] = std::array::from_fn(|i| c[i / 4][i % 4]);
+        let mut inv = [0.0f32; 16];
+        inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+            + m[9] * m[7] * m[14]
+            + m[13] * m[6] * m[11]
+            - m[13] * m[7] * m[10];
+        inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+            - m[8] * m[7] * m[14]
+            - m[12] * m[6] * m[11]
+            + m[12] * m[7] * m[10];
+        inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+            + m[8] * m[7] * m[13]
+            + m[12] * m[5] * m[11]
+            - m[12] * m[7] * m[9];
+        inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+            - m[8] * m[6] * m[13]
+            - m[12] * m[5] * m[10]
+            + m[12] * m[6] * m[9];
+        inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+            - m[9] * m[3] * m[14]
+            - m[13] * m[2] * m[11]
+            + m[13] * m[3] * m[10];
+        inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+            + m[8] * m[3] * m[14]
+            + m[12] * m[2] * m[11]
+            - m[12] * m[3] * m[10];
+        inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+            - m[8] * m[3] * m[13]
+            - m[12] * m[1] * m[11]
+            + m[12] * m[3] * m[9];
+        inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+            + m[8] * m[2] * m[13]
+            + m[12] * m[1] * m[10]
+            - m[12] * m[2] * m[9];
+        inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+            + m[5] * m[3] * m[14]
+            + m[13] * m[2] * m[7]
+            - m[13] * m[3] * m[6];
+        inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+            - m[4] * m[3] * m[14]
+            - m[12] * m[2] * m[7]
+            + m[12] * m[3] * m[6];
+        inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+            + m[4] * m[3] * m[13]
+            + m[12] * m[1] * m[7]
+            - m[12] * m[3] * m[5];
+        inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+            - m[4] * m[2] * m[13]
+            - m[12] * m[1] * m[6]
+            + m[12] * m[2] * m[5];
+        inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+            - m[5] * m[3] * m[10]
+            - m[9] * m[2] * m[7]
+            + m[9] * m[3] * m[6];
+        inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+            + m[4] * m[3] * m[10]
+            + m[8] * m[2] * m[7]
+            - m[8] * m[3] * m[6];
+        inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+            - m[4] * m[3] * m[9]
+            - m[8] * m[1] * m[7]
+            + m[8] * m[3] * m[5];
+        inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+            + m[4] * m[2] * m[9]
+            + m[8] * m[1] * m[6]
+            - m[8] * m[2] * m[5];
+        let det = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
+        (inv, det)
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.cofactors().1
+    }
+
+    /// Like `simd_inverse`, singular matrices give non-finite values.
+    pub fn inverse(&self) -> Self {
+        let (inv, det) = self.cofactors();
+        let inv_det = 1.0 / det;
+        Self::with_cols(std::array::from_fn(|c| {
+            std::array::from_fn(|r| inv[c * 4 + r] * inv_det)
+        }))
+    }
+
+    #[inline]
+    fn with_rows(rows: [[f32; 4]; 4]) -> Self {
+        Self::with_cols(transpose(&rows))
+    }
+
+    pub fn scale(sx: f32, sy: f32, sz: f32) -> Self {
+        Self::diagonal(f32x4::with_xyzw(sx, sy, sz, 1.0))
+    }
+
+    /// Right-handed perspective projection with Metal's `0..1` clip space depth.
+    ///
+    /// `fovy` is the vertical field of view in radians.
+    pub fn perspective_rh(fovy: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let ys = 1.0 / (fovy * 0.5).tan();
+        let xs = ys / aspect;
+        let zs = far / (near - far);
+        Self::with_rows([
+            [xs, 0.0, 0.0, 0.0],
+            [0.0, ys, 0.0, 0.0],
+            [0.0, 0.0, zs, near * zs],
+            [0.0, 0.0, -1.0, 0.0],
+        ])
+    }
+
+    /// Left-handed perspective projection with Metal's `0..1` clip space depth.
+    pub fn perspective_lh(fovy: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let ys = 1.0 / (fovy * 0.5).tan();
+        let xs = ys / aspect;
+        let zs = far / (far - near);
+        Self::with_rows([
+            [xs, 0.0, 0.0, 0.0],
+            [0.0, ys, 0.0, 0.0],
+            [0.0, 0.0, zs, -near * zs],
+            [0.0, 0.0, 1.0, 0.0],
+        ])
+    }
+
+    /// Right-handed orthographic projection with Metal's `0..1` clip space depth.
+    pub fn ortho_rh(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        Self::with_rows([
+            [
+                2.0 / (right - left),
+                0.0,
+                0.0,
+                (left + right) / (left - right),
+            ],
+            [
+                0.0,
+                2.0 / (top - bottom),
+                0.0,
+                (top + bottom) / (bottom - top),
+            ],
+            [0.0, 0.0, -1.0 / (far - near), near / (near - far)],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Left-handed orthographic projection with Metal's `0..1` clip space depth.
+    pub fn ortho_lh(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        Self::with_rows([
+            [
+                2.0 / (right - left),
+                0.0,
+                0.0,
+                (left + right) / (left - right),
+            ],
+            [
+                0.0,
+                2.0 / (top - bottom),
+                0.0,
+                (top + bottom) / (bottom - top),
+            ],
+            [0.0, 0.0, 1.0 / (far - near), near / (near - far)],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    fn look_at(x: [f32; 3], y: [f32; 3], z: [f32; 3], eye: [f32; 3]) -> Self {
+        Self::with_rows([
+            [x[0], x[1], x[2], -dot3(x, eye)],
+            [y[0], y[1], y[2], -dot3(y, eye)],
+            [z[0], z[1], z[2], -dot3(z, eye)],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Right-handed view matrix, the camera looks down its negative z axis.
+    pub fn look_at_rh(eye: f32x3, target: f32x3, up: f32x3) -> Self {
+        let (eye, target, up) = (eye.to_array(), target.to_array(), up.to_array());
+        let z = normalize3(sub3(eye, target));
+        let x = normalize3(cross3(up, z));
+        Self::look_at(x, cross3(z, x), z, eye)
+    }
+
+    /// Left-handed view matrix, the camera looks down its positive z axis.
+    pub fn look_at_lh(eye: f32x3, target: f32x3, up: f32x3) -> Self {
+        let (eye, target, up) = (eye.to_array(), target.to_array(), up.to_array());
+        let z = normalize3(sub3(target, eye));
+        let x = normalize3(cross3(up, z));
+        Self::look_at(x, cross3(z, x), z, eye)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simd::{
+        f32x2, f32x2x2, f32x2x3, f32x3, f32x3x2, f32x3x3, f32x4, f32x4x2, f32x4x3, f32x4x4,
+    };
+
+    use super::{Cols, Lanes};
+
+    fn approx<const C: usize, const R: usize>(a: &impl Cols<C, R>, b: &impl Cols<C, R>) -> bool {
+        let (a, b) = (a.cols(), b.cols());
+        a.iter()
+            .flatten()
+            .zip(b.iter().flatten())
+            .all(|(a, b)| (a - b).abs() < 1e-5)
+    }
+
+    fn m4() -> f32x4x4 {
+        f32x4x4::with_cols([
+            [2.0, 0.5, 0.0, 0.0],
+            [1.0, 3.0, -1.0, 0.0],
+            [0.0, 1.0, 4.0, 0.5],
+            [5.0, -2.0, 1.0, 1.0],
+        ])
+    }
+
+    #[test]
+    fn mul() {
+        // 2 columns of 3 rows times 3 columns of 2 rows
+        let a = f32x2x3::with_cols([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = f32x3x2::with_cols([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+        let ab: f32x3x3 = a * b;
+        assert_eq!(
+            ab.cols(),
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [5.0, 7.0, 9.0]]
+        );
+        let ba: f32x2x2 = b * a;
+        assert_eq!(ba.cols(), [[4.0, 5.0], [10.0, 11.0]]);
+        assert_eq!(a.transpose().transpose(), a);
+        assert_eq!((a * b).transpose(), b.transpose() * a.transpose());
+
+        let v = a * f32x2::with_xy(1.0, -1.0);
+        assert_eq!(v.to_array(), [-3.0, -3.0, -3.0]);
+
+        let t = f32x4x4::translate(1.0, 2.0, 3.0);
+        let p = t * f32x4::with_xyzw(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(p.to_array(), [2.0, 3.0, 4.0, 1.0]);
+        assert_eq!(t * f32x4x4::identity(), t);
+
+        let wide = f32x4x2::with_cols([[1.0, 0.0], [0.0, 1.0], [1.0, 0.0], [0.0, 1.0]]);
+        let tall: f32x2x2 = wide * f32x4x4::identity().transpose() * wide.transpose();
+        assert_eq!(tall.cols(), [[2.0, 0.0], [0.0, 2.0]]);
+        let _: f32x4x3 = f32x3x3::identity() * f32x4x3::with_cols([[1.0; 3]; 4]);
+    }
+
+    #[test]
+    fn inverse() {
+        let m2 = f32x2x2::with_cols([[4.0, 2.0], [7.0, 6.0]]);
+        assert_eq!(m2.determinant(), 10.0);
+        assert!(approx(&(m2 * m2.inverse()), &f32x2x2::identity()));
+
+        let m3 = f32x3x3::with_cols([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+        assert_eq!(m3.determinant(), 6.0);
+        assert!(approx(&(m3 * m3.inverse()), &f32x3x3::identity()));
+        assert!(approx(&(m3.inverse() * m3), &f32x3x3::identity()));
+
+        let m = m4();
+        assert!((m.determinant() - m.transpose().determinant()).abs() < 1e-4);
+        assert!(approx(&(m * m.inverse()), &f32x4x4::identity()));
+        assert!(approx(
+            &f32x4x4::translate(1.0, 2.0, 3.0).inverse(),
+            &f32x4x4::translate(-1.0, -2.0, -3.0)
+        ));
+        assert!(
+            !f32x2x2::with_cols([[1.0, 2.0], [2.0, 4.0]])
+                .inverse()
+                .cols()[0][0]
+                .is_finite()
+        );
+    }
+
+    #[test]
+    fn projection() {
+        let project = |m: &f32x4x4, p: [f32; 3]| {
+            let v = (*m * f32x4::with_xyzw(p[0], p[1], p[2], 1.0)).to_array();
+            [v[0] / v[3], v[1] / v[3], v[2] / v[3]]
+        };
+        let rh = f32x4x4::perspective_rh(std::f32::consts::FRAC_PI_2, 2.0, 1.0, 100.0);
+        assert!((project(&rh, [0.0, 0.0, -1.0])[2]).abs() < 1e-6);
+        assert!((project(&rh, [0.0, 0.0, -100.0])[2] - 1.0).abs() < 1e-6);
+        let edge = project(&rh, [2.0, 1.0, -1.0]);
+        assert!((edge[0] - 1.0).abs() < 1e-6 && (edge[1] - 1.0).abs() < 1e-6);
+
+        let lh = f32x4x4::perspective_lh(std::f32::consts::FRAC_PI_2, 1.0, 0.5, 10.0);
+        assert!((project(&lh, [0.0, 0.0, 0.5])[2]).abs() < 1e-6);
+        assert!((project(&lh, [0.0, 0.0, 10.0])[2] - 1.0).abs() < 1e-6);
+
+        let o = f32x4x4::ortho_rh(0.0, 100.0, 0.0, 50.0, 0.0, 10.0);
+        assert_eq!(project(&o, [0.0, 0.0, 0.0]), [-1.0, -1.0, 0.0]);
+        assert_eq!(project(&o, [100.0, 50.0, -10.0]), [1.0, 1.0, 1.0]);
+        let o = f32x4x4::ortho_lh(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0);
+        assert_eq!(project(&o, [1.0, -1.0, 3.0]), [1.0, -1.0, 1.0]);
+
+        let up = f32x3::with_xyz(0.0, 1.0, 0.0);
+        let eye = f32x3::with_xyz(0.0, 0.0, 5.0);
+        let view = f32x4x4::look_at_rh(eye, f32x3::with_xyz(0.0, 0.0, 0.0), up);
+        assert_eq!(project(&view, [0.0, 0.0, 0.0]), [0.0, 0.0, -5.0]);
+        assert_eq!(project(&view, [1.0, 0.0, 5.0]), [1.0, 0.0, 0.0]);
+        let view = f32x4x4::look_at_lh(eye, f32x3::with_xyz(0.0, 0.0, 0.0), up);
+        assert_eq!(project(&view, [0.0, 0.0, 0.0]), [0.0, 0.0, 5.0]);
+        assert_eq!(project(&view, [1.0, 0.0, 5.0]), [-1.0, 0.0, 0.0]);
+    }
+}
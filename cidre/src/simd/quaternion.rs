@@ -0,0 +1,316 @@
+//! `simd_quatf` math, the vector part is `xyz` and the real part is `w`.
+
+use super::matrix::{Cols, Lanes, cross3, dot3, normalize3};
+use super::{f32quat, f32x3, f32x3x3, f32x4, f32x4x4};
+
+impl f32quat {
+    #[inline]
+    pub fn new(ix: f32, iy: f32, iz: f32, r: f32) -> Self {
+        Self(f32x4::with_xyzw(ix, iy, iz, r))
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    #[inline]
+    pub fn with_imag_real(imag: f32x3, real: f32) -> Self {
+        Self::new(imag.x(), imag.y(), imag.z(), real)
+    }
+
+    /// Shortest rotation taking unit vector `from` to unit vector `to`.
+    pub fn with_from_to(from: f32x3, to: f32x3) -> Self {
+        let (a, b) = (from.to_array(), to.to_array());
+        let d = dot3(a, b);
+        if d < -1.0 + 1e-6 {
+            // opposite vectors, rotate half a turn around any perpendicular axis
+            let axis = if a[0].abs() < 0.9 {
+                cross3([1.0, 0.0, 0.0], a)
+            } else {
+                cross3([0.0, 1.0, 0.0], a)
+            };
+            let [x, y, z] = normalize3(axis);
+            return Self::new(x, y, z, 0.0);
+        }
+        let [x, y, z] = cross3(a, b);
+        Self::new(x, y, z, 1.0 + d).normalize()
+    }
+
+    /// Rotation part of an orthonormal matrix.
+    pub fn with_matrix(m: &f32x3x3) -> Self {
+        let [c0, c1, c2] = m.cols();
+        let trace = c0[0] + c1[1] + c2[2];
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Self::new(
+                (c1[2] - c2[1]) / s,
+                (c2[0] - c0[2]) / s,
+                (c0[1] - c1[0]) / s,
+                0.25 * s,
+            )
+        } else if c0[0] > c1[1] && c0[0] > c2[2] {
+            let s = (1.0 + c0[0] - c1[1] - c2[2]).sqrt() * 2.0;
+            Self::new(
+                0.25 * s,
+                (c1[0] + c0[1]) / s,
+                (c2[0] + c0[2]) / s,
+                (c1[2] - c2[1]) / s,
+            )
+        } else if c1[1] > c2[2] {
+            let s = (1.0 + c1[1] - c0[0] - c2[2]).sqrt() * 2.0;
+            Self::new(
+                (c1[0] + c0[1]) / s,
+                0.25 * s,
+                (c2[1] + c1[2]) / s,
+                (c2[0] - c0[2]) / s,
+            )
+        } else {
+            let s = (1.0 + c2[2] - c0[0] - c1[1]).sqrt() * 2.0;
+            Self::new(
+                (c2[0] + c0[2]) / s,
+                (c2[1] + c1[2]) / s,
+                0.25 * s,
+                (c0[1] - c1[0]) / s,
+            )
+        };
+        q.normalize()
+    }
+
+    #[inline]
+    pub fn imag(&self) -> f32x3 {
+        f32x3::with_xyz_f32(self.x(), self.y(), self.z())
+    }
+
+    #[inline]
+    pub fn real(&self) -> f32 {
+        self.w()
+    }
+
+    #[inline]
+    pub fn dot(&self, other: &Self) -> f32 {
+        let (a, b) = (self.0.to_array(), other.0.to_array());
+        a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3]
+    }
+
+    #[inline]
+    pub fn length(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    #[inline]
+    fn scaled(&self, s: f32) -> Self {
+        let [x, y, z, w] = self.0.to_array();
+        Self::new(x * s, y * s, z * s, w * s)
+    }
+
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        self.scaled(1.0 / self.length())
+    }
+
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        let [x, y, z, w] = self.0.to_array();
+        Self::new(-x, -y, -z, w)
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        self.conjugate().scaled(1.0 / self.dot(self))
+    }
+
+    /// Rotation angle in radians.
+    #[inline]
+    pub fn angle(&self) -> f32 {
+        let imag = self.imag().to_array();
+        2.0 * dot3(imag, imag).sqrt().atan2(self.real())
+    }
+
+    /// Rotation axis, x axis for the identity rotation.
+    pub fn axis(&self) -> f32x3 {
+        let imag = self.imag().to_array();
+        if dot3(imag, imag) == 0.0 {
+            return f32x3::with_xyz_f32(1.0, 0.0, 0.0);
+        }
+        f32x3::from_array(normalize3(imag))
+    }
+
+    /// Rotates `v` by the unit quaternion.
+    pub fn act(&self, v: f32x3) -> f32x3 {
+        let (q, w, v) = (self.imag().to_array(), self.real(), v.to_array());
+        let c = cross3(q, v);
+        let t = [c[0] * 2.0, c[1] * 2.0, c[2] * 2.0];
+        let u = cross3(q, t);
+        f32x3::with_xyz_f32(
+            v[0] + w * t[0] + u[0],
+            v[1] + w * t[1] + u[1],
+            v[2] + w * t[2] + u[2],
+        )
+    }
+
+    /// Spherical interpolation along the shortest arc.
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        let mut d = self.dot(other);
+        let mut other = *other;
+        if d < 0.0 {
+            d = -d;
+            other = other.scaled(-1.0);
+        }
+        let (a, b) = (self.0.to_array(), other.0.to_array());
+        let (s0, s1) = if d > 0.9995 {
+            // nearly parallel, fall back to normalized lerp
+            (1.0 - t, t)
+        } else {
+            let theta = d.acos();
+            let sin = theta.sin();
+            (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+        let r: [f32; 4] = std::array::from_fn(|i| a[i] * s0 + b[i] * s1);
+        Self::new(r[0], r[1], r[2], r[3]).normalize()
+    }
+}
+
+impl std::ops::Mul for f32quat {
+    type Output = Self;
+
+    /// Hamilton product, `self * rhs` rotates by `rhs` first.
+    fn mul(self, rhs: Self) -> Self {
+        let [ax, ay, az, aw] = self.0.to_array();
+        let [bx, by, bz, bw] = rhs.0.to_array();
+        Self::new(
+            aw * bx + ax * bw + ay * bz - az * by,
+            aw * by - ax * bz + ay * bw + az * bx,
+            aw * bz + ax * by - ay * bx + az * bw,
+            aw * bw - ax * bx - ay * by - az * bz,
+        )
+    }
+}
+
+fn rotation(q: &f32quat) -> [[f32; 3]; 3] {
+    let [x, y, z, w] = q.0.to_array();
+    let (xx, yy, zz) = (x * x, y * y, z * z);
+    let (xy, xz, yz) = (x * y, x * z, y * z);
+    let (wx, wy, wz) = (w * x, w * y, w * z);
+    [
+        [1.0 - 2.0 * (yy + zz), 2.0 * (xy + wz), 2.0 * (xz - wy)],
+        [2.0 * (xy - wz), 1.0 - 2.0 * (xx + zz), 2.0 * (yz + wx)],
+        [2.0 * (xz + wy), 2.0 * (yz - wx), 1.0 - 2.0 * (xx + yy)],
+    ]
+}
+
+impl f32x3x3 {
+    /// Rotation matrix of the unit quaternion.
+    pub fn with_quat(q: &f32quat) -> Self {
+        Self::with_cols(rotation(q))
+    }
+}
+
+impl f32x4x4 {
+    /// Rotation matrix of the unit quaternion.
+    pub fn with_quat(q: &f32quat) -> Self {
+        let [a, b, c] = rotation(q);
+        Self::with_cols([
+            [a[0], a[1], a[2], 0.0],
+            [b[0], b[1], b[2], 0.0],
+            [c[0], c[1], c[2], 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use crate::simd::{f32quat, f32x3, f32x3x3, f32x4, f32x4x4};
+
+    use super::Lanes;
+
+    fn approx<const N: usize>(a: [f32; N], b: [f32; N]) -> bool {
+        a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-5)
+    }
+
+    fn v(x: f32, y: f32, z: f32) -> f32x3 {
+        f32x3::with_xyz(x, y, z)
+    }
+
+    #[test]
+    fn basics() {
+        let z90 = f32quat::with_angle(FRAC_PI_2, v(0.0, 0.0, 1.0));
+        assert!(approx(
+            z90.act(v(1.0, 0.0, 0.0)).to_array(),
+            [0.0, 1.0, 0.0]
+        ));
+        assert!((z90.angle() - FRAC_PI_2).abs() < 1e-6);
+        assert!(approx(z90.axis().to_array(), [0.0, 0.0, 1.0]));
+        assert_eq!(f32quat::identity().axis().to_array(), [1.0, 0.0, 0.0]);
+
+        let x90 = f32quat::with_angle(FRAC_PI_2, v(1.0, 0.0, 0.0));
+        // rhs applies first
+        let q = z90 * x90;
+        let p = q.act(v(0.0, 1.0, 0.0));
+        assert!(approx(
+            p.to_array(),
+            z90.act(x90.act(v(0.0, 1.0, 0.0))).to_array()
+        ));
+        assert!(approx(p.to_array(), [0.0, 0.0, 1.0]));
+
+        let id = (q * q.inverse()).0.to_array();
+        assert!(approx(id, [0.0, 0.0, 0.0, 1.0]));
+        assert_eq!(q.conjugate().real(), q.real());
+
+        let from = v(1.0, 0.0, 0.0);
+        let to = v(0.0, 0.6, 0.8);
+        let r = f32quat::with_from_to(from, to);
+        assert!(approx(r.act(from).to_array(), to.to_array()));
+        let r = f32quat::with_from_to(from, v(-1.0, 0.0, 0.0));
+        assert!(approx(r.act(from).to_array(), [-1.0, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn slerp() {
+        let axis = v(0.0, 1.0, 0.0);
+        let a = f32quat::with_angle(0.0, axis);
+        let b = f32quat::with_angle(FRAC_PI_2, axis);
+        let mid = a.slerp(&b, 0.5);
+        assert!((mid.angle() - FRAC_PI_2 / 2.0).abs() < 1e-5);
+        assert!(approx(a.slerp(&b, 0.0).0.to_array(), a.0.to_array()));
+        assert!(approx(a.slerp(&b, 1.0).0.to_array(), b.0.to_array()));
+
+        // takes the short way around even when signs differ
+        let c = f32quat::with_angle(PI * 1.5, axis);
+        let m = a.slerp(&c, 0.5);
+        let p = m.act(v(1.0, 0.0, 0.0)).to_array();
+        assert!(approx(p, [(PI / 4.0).cos(), 0.0, (PI / 4.0).sin()]));
+    }
+
+    #[test]
+    fn matrix() {
+        let n = 14.0f32.sqrt();
+        let q = f32quat::with_angle(0.7, v(1.0 / n, 2.0 / n, 3.0 / n));
+        let m = f32x3x3::with_quat(&q);
+        let p = v(0.3, -1.0, 2.0);
+        assert!(approx((m * p).to_array(), q.act(p).to_array()));
+        assert!(approx(
+            f32quat::with_matrix(&m).0.to_array(),
+            q.0.to_array()
+        ));
+
+        let m4 = f32x4x4::translate(1.0, 2.0, 3.0) * f32x4x4::with_quat(&q);
+        let r = m4 * f32x4::with_xyzw(p.x(), p.y(), p.z(), 1.0);
+        let e = q.act(p).to_array();
+        assert!(approx(
+            r.to_array(),
+            [e[0] + 1.0, e[1] + 2.0, e[2] + 3.0, 1.0]
+        ));
+
+        for angle in [0.0, 2.0, 3.1, -3.1] {
+            for axis in [v(1.0, 0.0, 0.0), v(0.0, 1.0, 0.0), v(0.0, 0.0, 1.0)] {
+                let q = f32quat::with_angle(angle, axis);
+                let back = f32quat::with_matrix(&f32x3x3::with_quat(&q));
+                assert!((back.dot(&q).abs() - 1.0).abs() < 1e-5, "{angle} {axis:?}");
+            }
+        }
+    }
+}
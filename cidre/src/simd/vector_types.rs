@@ -159,6 +159,12 @@ impl<T: Default + Copy, const LANES: usize, const N: usize> Default for Simd<T,
     }
 }
 
+impl<T: PartialEq, const LANES: usize, const N: usize> PartialEq<[T; LANES]> for Simd<T, LANES, N> {
+    fn eq(&self, other: &[T; LANES]) -> bool {
+        &self.0 == other
+    }
+}
+
 impl<T, const LANES: usize, const N: usize> std::ops::Index<usize> for Simd<T, LANES, N> {
     type Output = T;
 
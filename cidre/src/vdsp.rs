@@ -1,5 +1,14 @@
 use std::ptr::NonNull;
 
+/// Portable implementations of the raw vDSP functions.
+///
+/// Used in place of Accelerate on non Apple targets.
+#[cfg(any(test, not(target_vendor = "apple")))]
+mod soft;
+
+#[cfg(not(target_vendor = "apple"))]
+pub use soft::*;
+
 #[doc(alias = "vDSP_Length")]
 pub type Len = usize;
 
@@ -140,7 +149,8 @@ pub struct Fft<T>(NonNull<FftSetup<T>>, FftVt<T>);
 impl<T> Fft<T> {
     #[inline]
     pub fn zr_io(&mut self, re_io: &mut [T], im_io: &mut [T], direction: FftDirection) {
-        let log2n = (re_io.len() as f64).log2().ceil();
+        // split complex holds two real values per element
+        let log2n = (re_io.len() as f64).log2().ceil() + 1.0;
         let mut split = SplitComplex::new_mut(re_io, im_io);
         unsafe { (self.1.zrip)(self.0.as_mut(), &mut split, 1, log2n as _, direction) }
     }
@@ -1269,6 +1279,7 @@ pub fn u16_f32(a: &[u16], c: &mut [f32]) {
     unsafe { _u16_f32(a.as_ptr(), 1, c.as_mut_ptr(), 1, n) };
 }
 
+#[cfg(target_vendor = "apple")]
 #[link(name = "Accelerate", kind = "framework")]
 unsafe extern "C-unwind" {
     #[link_name = "vDSP_vadd"]
@@ -2058,6 +2069,22 @@ mod tests {
         assert_eq!(r, a);
     }
 
+    #[test]
+    fn div_i32() {
+        let a = [7, -7, 5];
+        let b = [2, 2, -5];
+        let mut c = [1; 3];
+        vdsp::div_i32(&a, &b, &mut c);
+        assert_eq!(c, [3, -3, -1]);
+
+        // Accelerate leaves these unspecified, the portable fallback doesn't trap
+        #[cfg(not(target_vendor = "apple"))]
+        {
+            vdsp::div_i32(&[5, 0, i32::MIN], &[0, 0, -1], &mut c);
+            assert_eq!(c, [0, 0, i32::MIN]);
+        }
+    }
+
     #[test]
     fn basics() {
         const N: usize = 1_000;
@@ -2178,6 +2205,61 @@ mod tests {
         vdsp::zaspec_f32(&split, &mut spec);
     }
 
+    #[test]
+    fn fft_scaling() {
+        let n = 16usize;
+        let log2n = n.ilog2() as usize;
+        let signal: Vec<f32> = (0..n).map(|i| ((i * 7) % 5) as f32 - 1.5).collect();
+        let dft = |k: usize| {
+            signal
+                .iter()
+                .enumerate()
+                .fold((0.0f64, 0.0f64), |acc, (i, &x)| {
+                    let a = -std::f64::consts::TAU * (i * k) as f64 / n as f64;
+                    (acc.0 + x as f64 * a.cos(), acc.1 + x as f64 * a.sin())
+                })
+        };
+
+        let mut fft = vdsp::Fft::new_f32(log2n, vdsp::FftRadix::_2).unwrap();
+
+        // real forward is scaled by 2 with nyquist packed into im[0]
+        let mut re = vec![0.0f32; n / 2];
+        let mut im = vec![0.0f32; n / 2];
+        vdsp::actoz_f32(&signal, &mut re, &mut im);
+        fft.zr_io(&mut re, &mut im, vdsp::FftDirection::Forward);
+        assert!((re[0] as f64 - 2.0 * dft(0).0).abs() < 1e-4);
+        assert!((im[0] as f64 - 2.0 * dft(n / 2).0).abs() < 1e-4);
+        for k in 1..n / 2 {
+            let (xr, xi) = dft(k);
+            assert!((re[k] as f64 - 2.0 * xr).abs() < 1e-4);
+            assert!((im[k] as f64 - 2.0 * xi).abs() < 1e-4);
+        }
+
+        // real round trip is scaled by 2N
+        fft.zr_io(&mut re, &mut im, vdsp::FftDirection::Inverse);
+        let mut c = vec![vdsp::Complex::<f32>::default(); n / 2];
+        vdsp::ztoc_f32(&re, &im, &mut c);
+        for (i, x) in signal.iter().enumerate() {
+            let v = if i % 2 == 0 { c[i / 2].re } else { c[i / 2].im };
+            assert!((v - x * 2.0 * n as f32).abs() < 1e-3);
+        }
+
+        // complex forward is unscaled, round trip is scaled by N
+        let mut re = signal.clone();
+        let mut im = vec![0.0f32; n];
+        fft.forward_io(&mut re, &mut im);
+        for k in 0..n {
+            let (xr, xi) = dft(k);
+            assert!((re[k] as f64 - xr).abs() < 1e-4);
+            assert!((im[k] as f64 - xi).abs() < 1e-4);
+        }
+        fft.inverse_io(&mut re, &mut im);
+        for i in 0..n {
+            assert!((re[i] - signal[i] * n as f32).abs() < 1e-3);
+            assert!(im[i].abs() < 1e-3);
+        }
+    }
+
     #[test]
     fn ztoc() {
         let re = [1.0, 2.0, 3.0];
@@ -0,0 +1,2153 @@
+#![allow(non_snake_case, clippy::missing_safety_doc)]
+
+use std::{
+    ops::{Add, Mul, Neg, Sub},
+    ptr::NonNull,
+};
+
+use crate::vdsp::{Complex, FftDirection, FftRadix, FftSetup, Len, SplitComplex, Stride};
+
+#[inline]
+unsafe fn at<T>(p: *const T, stride: Stride, i: Len) -> *const T {
+    unsafe { p.offset(i as isize * stride) }
+}
+
+#[inline]
+unsafe fn at_mut<T>(p: *mut T, stride: Stride, i: Len) -> *mut T {
+    unsafe { p.offset(i as isize * stride) }
+}
+
+#[inline]
+unsafe fn map<A: Copy, C>(
+    a: *const A,
+    ia: Stride,
+    c: *mut C,
+    ic: Stride,
+    n: Len,
+    f: impl Fn(A) -> C,
+) {
+    for i in 0..n {
+        unsafe { *at_mut(c, ic, i) = f(*at(a, ia, i)) }
+    }
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments)]
+unsafe fn map2<T: Copy>(
+    a: *const T,
+    ia: Stride,
+    b: *const T,
+    ib: Stride,
+    c: *mut T,
+    ic: Stride,
+    n: Len,
+    f: impl Fn(T, T) -> T,
+) {
+    for i in 0..n {
+        unsafe { *at_mut(c, ic, i) = f(*at(a, ia, i), *at(b, ib, i)) }
+    }
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments)]
+unsafe fn map3<T: Copy>(
+    a: *const T,
+    ia: Stride,
+    b: *const T,
+    ib: Stride,
+    c: *const T,
+    ic: Stride,
+    d: *mut T,
+    id: Stride,
+    n: Len,
+    f: impl Fn(T, T, T) -> T,
+) {
+    for i in 0..n {
+        unsafe { *at_mut(d, id, i) = f(*at(a, ia, i), *at(b, ib, i), *at(c, ic, i)) }
+    }
+}
+
+#[inline]
+unsafe fn fill<T: Copy>(a: T, c: *mut T, ic: Stride, n: Len) {
+    for i in 0..n {
+        unsafe { *at_mut(c, ic, i) = a }
+    }
+}
+
+#[inline]
+unsafe fn fold<T: Copy, R>(a: *const T, ia: Stride, n: Len, init: R, f: impl Fn(R, T) -> R) -> R {
+    let mut acc = init;
+    for i in 0..n {
+        acc = f(acc, unsafe { *at(a, ia, i) });
+    }
+    acc
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments)]
+unsafe fn fold2<T: Copy>(
+    a: *const T,
+    ia: Stride,
+    b: *const T,
+    ib: Stride,
+    n: Len,
+    init: T,
+    f: impl Fn(T, T, T) -> T,
+) -> T {
+    let mut acc = init;
+    for i in 0..n {
+        acc = f(acc, unsafe { *at(a, ia, i) }, unsafe { *at(b, ib, i) });
+    }
+    acc
+}
+
+#[doc(alias = "vDSP_vadd")]
+pub unsafe extern "C-unwind" fn _add_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __B: *const f32,
+    __IB: Stride,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map2(__A, __IA, __B, __IB, __C, __IC, __N, |a, b| a + b) }
+}
+
+#[doc(alias = "vDSP_vaddD")]
+pub unsafe extern "C-unwind" fn _add_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __B: *const f64,
+    __IB: Stride,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map2(__A, __IA, __B, __IB, __C, __IC, __N, |a, b| a + b) }
+}
+
+#[doc(alias = "vDSP_vaddi")]
+pub unsafe extern "C-unwind" fn _add_i32(
+    __A: *const i32,
+    __IA: Stride,
+    __B: *const i32,
+    __IB: Stride,
+    __C: *mut i32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map2(__A, __IA, __B, __IB, __C, __IC, __N, i32::wrapping_add) }
+}
+
+#[doc(alias = "vDSP_vsub")]
+pub unsafe extern "C-unwind" fn _sub_f32(
+    __B: *const f32,
+    __IB: Stride,
+    __A: *const f32,
+    __IA: Stride,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map2(__A, __IA, __B, __IB, __C, __IC, __N, |a, b| a - b) }
+}
+
+#[doc(alias = "vDSP_vsubD")]
+pub unsafe extern "C-unwind" fn _sub_f64(
+    __B: *const f64,
+    __IB: Stride,
+    __A: *const f64,
+    __IA: Stride,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map2(__A, __IA, __B, __IB, __C, __IC, __N, |a, b| a - b) }
+}
+
+#[doc(alias = "vDSP_vsubi")]
+pub unsafe extern "C-unwind" fn _sub_i32(
+    __B: *const i32,
+    __IB: Stride,
+    __A: *const i32,
+    __IA: Stride,
+    __C: *mut i32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map2(__A, __IA, __B, __IB, __C, __IC, __N, i32::wrapping_sub) }
+}
+
+#[doc(alias = "vDSP_vmul")]
+pub unsafe extern "C-unwind" fn _mul_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __B: *const f32,
+    __IB: Stride,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map2(__A, __IA, __B, __IB, __C, __IC, __N, |a, b| a * b) }
+}
+
+#[doc(alias = "vDSP_vmulD")]
+pub unsafe extern "C-unwind" fn _mul_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __B: *const f64,
+    __IB: Stride,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map2(__A, __IA, __B, __IB, __C, __IC, __N, |a, b| a * b) }
+}
+
+#[doc(alias = "vDSP_vdiv")]
+pub unsafe extern "C-unwind" fn _div_f32(
+    __B: *const f32,
+    __IB: Stride,
+    __A: *const f32,
+    __IA: Stride,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map2(__A, __IA, __B, __IB, __C, __IC, __N, |a, b| a / b) }
+}
+
+#[doc(alias = "vDSP_vdivD")]
+pub unsafe extern "C-unwind" fn _div_f64(
+    __B: *const f64,
+    __IB: Stride,
+    __A: *const f64,
+    __IA: Stride,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map2(__A, __IA, __B, __IB, __C, __IC, __N, |a, b| a / b) }
+}
+
+#[doc(alias = "vDSP_vdivi")]
+pub unsafe extern "C-unwind" fn _div_i32(
+    __B: *const i32,
+    __IB: Stride,
+    __A: *const i32,
+    __IA: Stride,
+    __C: *mut i32,
+    __IC: Stride,
+    __N: Len,
+) {
+    // zero divisors give 0 and `i32::MIN / -1` wraps, like arm64 `sdiv`, instead of trapping
+    unsafe {
+        map2(__A, __IA, __B, __IB, __C, __IC, __N, |a, b| {
+            if b == 0 { 0 } else { a.wrapping_div(b) }
+        })
+    }
+}
+
+#[doc(alias = "vDSP_vsmul")]
+pub unsafe extern "C-unwind" fn _smul_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __B: &f32,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    let b = *__B;
+    unsafe { map(__A, __IA, __C, __IC, __N, |a| a * b) }
+}
+
+#[doc(alias = "vDSP_vsmulD")]
+pub unsafe extern "C-unwind" fn _smul_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __B: &f64,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    let b = *__B;
+    unsafe { map(__A, __IA, __C, __IC, __N, |a| a * b) }
+}
+
+#[doc(alias = "vDSP_vsq")]
+pub unsafe extern "C-unwind" fn _sq_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __IC, __N, |a| a * a) }
+}
+
+#[doc(alias = "vDSP_vsqD")]
+pub unsafe extern "C-unwind" fn _sq_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __IC, __N, |a| a * a) }
+}
+
+#[doc(alias = "vDSP_vssq")]
+pub unsafe extern "C-unwind" fn _ssq_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __IC, __N, |a| a * a.abs()) }
+}
+
+#[doc(alias = "vDSP_vssqD")]
+pub unsafe extern "C-unwind" fn _ssq_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __IC, __N, |a| a * a.abs()) }
+}
+
+#[doc(alias = "vDSP_meanv")]
+pub unsafe extern "C-unwind" fn _mean_f32(__A: *const f32, __IA: Stride, __C: *mut f32, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc, a| acc + a) / __N as f32 }
+}
+
+#[doc(alias = "vDSP_meanvD")]
+pub unsafe extern "C-unwind" fn _mean_f64(__A: *const f64, __IA: Stride, __C: *mut f64, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc, a| acc + a) / __N as f64 }
+}
+
+#[doc(alias = "vDSP_measqv")]
+pub unsafe extern "C-unwind" fn _meansq_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __C: *mut f32,
+    __N: Len,
+) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc, a| acc + a * a) / __N as f32 }
+}
+
+#[doc(alias = "vDSP_measqvD")]
+pub unsafe extern "C-unwind" fn _meansq_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __C: *mut f64,
+    __N: Len,
+) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc, a| acc + a * a) / __N as f64 }
+}
+
+#[doc(alias = "vDSP_distancesq")]
+pub unsafe extern "C-unwind" fn _distance_sq_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __B: *const f32,
+    __IB: Stride,
+    __C: *mut f32,
+    __N: Len,
+) {
+    unsafe {
+        *__C = fold2(__A, __IA, __B, __IB, __N, 0.0, |acc, a, b| {
+            acc + (a - b) * (a - b)
+        })
+    }
+}
+
+#[doc(alias = "vDSP_distancesqD")]
+pub unsafe extern "C-unwind" fn _distance_sq_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __B: *const f64,
+    __IB: Stride,
+    __C: *mut f64,
+    __N: Len,
+) {
+    unsafe {
+        *__C = fold2(__A, __IA, __B, __IB, __N, 0.0, |acc, a, b| {
+            acc + (a - b) * (a - b)
+        })
+    }
+}
+
+#[doc(alias = "vDSP_dotpr")]
+pub unsafe extern "C-unwind" fn _dotpr_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __B: *const f32,
+    __IB: Stride,
+    __C: *mut f32,
+    __N: Len,
+) {
+    unsafe { *__C = fold2(__A, __IA, __B, __IB, __N, 0.0, |acc, a, b| acc + a * b) }
+}
+
+#[doc(alias = "vDSP_dotprD")]
+pub unsafe extern "C-unwind" fn _dotpr_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __B: *const f64,
+    __IB: Stride,
+    __C: *mut f64,
+    __N: Len,
+) {
+    unsafe { *__C = fold2(__A, __IA, __B, __IB, __N, 0.0, |acc, a, b| acc + a * b) }
+}
+
+#[doc(alias = "vDSP_vam")]
+pub unsafe extern "C-unwind" fn _am_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __B: *const f32,
+    __IB: Stride,
+    __C: *const f32,
+    __IC: Stride,
+    __D: *mut f32,
+    __ID: Stride,
+    __N: Len,
+) {
+    unsafe {
+        map3(
+            __A,
+            __IA,
+            __B,
+            __IB,
+            __C,
+            __IC,
+            __D,
+            __ID,
+            __N,
+            |a, b, c| (a + b) * c,
+        )
+    }
+}
+
+#[doc(alias = "vDSP_vamD")]
+pub unsafe extern "C-unwind" fn _am_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __B: *const f64,
+    __IB: Stride,
+    __C: *const f64,
+    __IC: Stride,
+    __D: *mut f64,
+    __ID: Stride,
+    __N: Len,
+) {
+    unsafe {
+        map3(
+            __A,
+            __IA,
+            __B,
+            __IB,
+            __C,
+            __IC,
+            __D,
+            __ID,
+            __N,
+            |a, b, c| (a + b) * c,
+        )
+    }
+}
+
+#[doc(alias = "vDSP_vma")]
+pub unsafe extern "C-unwind" fn _ma_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __B: *const f32,
+    __IB: Stride,
+    __C: *const f32,
+    __IC: Stride,
+    __D: *mut f32,
+    __ID: Stride,
+    __N: Len,
+) {
+    unsafe {
+        map3(
+            __A,
+            __IA,
+            __B,
+            __IB,
+            __C,
+            __IC,
+            __D,
+            __ID,
+            __N,
+            |a, b, c| a * b + c,
+        )
+    }
+}
+
+#[doc(alias = "vDSP_vmaD")]
+pub unsafe extern "C-unwind" fn _ma_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __B: *const f64,
+    __IB: Stride,
+    __C: *const f64,
+    __IC: Stride,
+    __D: *mut f64,
+    __ID: Stride,
+    __N: Len,
+) {
+    unsafe {
+        map3(
+            __A,
+            __IA,
+            __B,
+            __IB,
+            __C,
+            __IC,
+            __D,
+            __ID,
+            __N,
+            |a, b, c| a * b + c,
+        )
+    }
+}
+
+#[doc(alias = "vDSP_vdpsp")]
+pub unsafe extern "C-unwind" fn _f64_f32(
+    __A: *const f64,
+    __IA: Stride,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __IC, __N, |a| a as f32) }
+}
+
+#[doc(alias = "vDSP_vspdp")]
+pub unsafe extern "C-unwind" fn _f32_f64(
+    __A: *const f32,
+    __IA: Stride,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __IC, __N, |a| a as f64) }
+}
+
+#[doc(alias = "vDSP_vabs")]
+pub unsafe extern "C-unwind" fn _abs_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __IC, __N, f32::abs) }
+}
+
+#[doc(alias = "vDSP_vabsD")]
+pub unsafe extern "C-unwind" fn _abs_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __IC, __N, f64::abs) }
+}
+
+#[doc(alias = "vDSP_vabsi")]
+pub unsafe extern "C-unwind" fn _abs_i32(
+    __A: *const i32,
+    __IA: Stride,
+    __C: *mut i32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __IC, __N, i32::wrapping_abs) }
+}
+
+#[doc(alias = "vDSP_veqvi")]
+pub unsafe extern "C-unwind" fn _eqv_i32(
+    __A: *const i32,
+    __IA: Stride,
+    __B: *const i32,
+    __IB: Stride,
+    __C: *mut i32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map2(__A, __IA, __B, __IB, __C, __IC, __N, |a, b| !(a ^ b)) }
+}
+
+#[doc(alias = "vDSP_vfill")]
+pub unsafe extern "C-unwind" fn _fill_f32(__A: &f32, __C: *mut f32, __IC: Stride, __N: Len) {
+    unsafe { fill(*__A, __C, __IC, __N) }
+}
+
+#[doc(alias = "vDSP_vfillD")]
+pub unsafe extern "C-unwind" fn _fill_f64(__A: &f64, __C: *mut f64, __IC: Stride, __N: Len) {
+    unsafe { fill(*__A, __C, __IC, __N) }
+}
+
+#[doc(alias = "vDSP_vfilli")]
+pub unsafe extern "C-unwind" fn _fill_i32(__A: &i32, __C: *mut i32, __IC: Stride, __N: Len) {
+    unsafe { fill(*__A, __C, __IC, __N) }
+}
+
+#[doc(alias = "vDSP_vclr")]
+pub unsafe extern "C-unwind" fn _clr_f32(__C: *mut f32, __IC: Stride, __N: Len) {
+    unsafe { fill(0.0, __C, __IC, __N) }
+}
+
+#[doc(alias = "vDSP_vclrD")]
+pub unsafe extern "C-unwind" fn _clr_f64(__C: *mut f64, __IC: Stride, __N: Len) {
+    unsafe { fill(0.0, __C, __IC, __N) }
+}
+
+#[doc(alias = "vDSP_vsadd")]
+pub unsafe extern "C-unwind" fn _sadd_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __B: &f32,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    let b = *__B;
+    unsafe { map(__A, __IA, __C, __IC, __N, |a| a + b) }
+}
+
+#[doc(alias = "vDSP_vsaddD")]
+pub unsafe extern "C-unwind" fn _sadd_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __B: &f64,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    let b = *__B;
+    unsafe { map(__A, __IA, __C, __IC, __N, |a| a + b) }
+}
+
+#[doc(alias = "vDSP_vsaddi")]
+pub unsafe extern "C-unwind" fn _sadd_i32(
+    __A: *const i32,
+    __IA: Stride,
+    __B: &i32,
+    __C: *mut i32,
+    __IC: Stride,
+    __N: Len,
+) {
+    let b = *__B;
+    unsafe { map(__A, __IA, __C, __IC, __N, |a| a.wrapping_add(b)) }
+}
+
+#[doc(alias = "vDSP_sve")]
+pub unsafe extern "C-unwind" fn _se_f32(__A: *const f32, __IA: Stride, __C: *mut f32, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc, a| acc + a) }
+}
+
+#[doc(alias = "vDSP_sveD")]
+pub unsafe extern "C-unwind" fn _se_f64(__A: *const f64, __IA: Stride, __C: *mut f64, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc, a| acc + a) }
+}
+
+#[doc(alias = "vDSP_svemg")]
+pub unsafe extern "C-unwind" fn _semg_f32(__A: *const f32, __IA: Stride, __C: *mut f32, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc, a| acc + a.abs()) }
+}
+
+#[doc(alias = "vDSP_svemgD")]
+pub unsafe extern "C-unwind" fn _semg_f64(__A: *const f64, __IA: Stride, __C: *mut f64, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc, a| acc + a.abs()) }
+}
+
+#[doc(alias = "vDSP_svesq")]
+pub unsafe extern "C-unwind" fn _sesq_f32(__A: *const f32, __IA: Stride, __C: *mut f32, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc, a| acc + a * a) }
+}
+
+#[doc(alias = "vDSP_svesqD")]
+pub unsafe extern "C-unwind" fn _sesq_f64(__A: *const f64, __IA: Stride, __C: *mut f64, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc, a| acc + a * a) }
+}
+
+#[doc(alias = "vDSP_svs")]
+pub unsafe extern "C-unwind" fn _svs_f32(__A: *const f32, __IA: Stride, __C: *mut f32, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc, a| acc + a * a.abs()) }
+}
+
+#[doc(alias = "vDSP_svsD")]
+pub unsafe extern "C-unwind" fn _svs_f64(__A: *const f64, __IA: Stride, __C: *mut f64, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc, a| acc + a * a.abs()) }
+}
+
+#[doc(alias = "vDSP_maxmgv")]
+pub unsafe extern "C-unwind" fn _maxmg_f32(__A: *const f32, __IA: Stride, __C: *mut f32, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc: f32, a| acc.max(a.abs())) }
+}
+
+#[doc(alias = "vDSP_maxmgvD")]
+pub unsafe extern "C-unwind" fn _maxmg_f64(__A: *const f64, __IA: Stride, __C: *mut f64, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, 0.0, |acc: f64, a| acc.max(a.abs())) }
+}
+
+#[doc(alias = "vDSP_minmgv")]
+pub unsafe extern "C-unwind" fn _minmg_f32(__A: *const f32, __IA: Stride, __C: *mut f32, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, f32::INFINITY, |acc, a| acc.min(a.abs())) }
+}
+
+#[doc(alias = "vDSP_minmgvD")]
+pub unsafe extern "C-unwind" fn _minmg_f64(__A: *const f64, __IA: Stride, __C: *mut f64, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, f64::INFINITY, |acc, a| acc.min(a.abs())) }
+}
+
+#[doc(alias = "vDSP_maxv")]
+pub unsafe extern "C-unwind" fn _max_f32(__A: *const f32, __IA: Stride, __C: *mut f32, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, f32::NEG_INFINITY, f32::max) }
+}
+
+#[doc(alias = "vDSP_maxvD")]
+pub unsafe extern "C-unwind" fn _max_f64(__A: *const f64, __IA: Stride, __C: *mut f64, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, f64::NEG_INFINITY, f64::max) }
+}
+
+#[doc(alias = "vDSP_minv")]
+pub unsafe extern "C-unwind" fn _min_f32(__A: *const f32, __IA: Stride, __C: *mut f32, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, f32::INFINITY, f32::min) }
+}
+
+#[doc(alias = "vDSP_minvD")]
+pub unsafe extern "C-unwind" fn _min_f64(__A: *const f64, __IA: Stride, __C: *mut f64, __N: Len) {
+    unsafe { *__C = fold(__A, __IA, __N, f64::INFINITY, f64::min) }
+}
+
+#[doc(alias = "vDSP_vgen")]
+pub unsafe extern "C-unwind" fn _gen_f32(
+    __A: &f32,
+    __B: &f32,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    let (a, b) = (*__A, *__B);
+    let d = if __N > 1 {
+        (b - a) / (__N - 1) as f32
+    } else {
+        0.0
+    };
+    for i in 0..__N {
+        unsafe { *at_mut(__C, __IC, i) = a + d * i as f32 }
+    }
+}
+
+#[doc(alias = "vDSP_vgenD")]
+pub unsafe extern "C-unwind" fn _gen_f64(
+    __A: &f64,
+    __B: &f64,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    let (a, b) = (*__A, *__B);
+    let d = if __N > 1 {
+        (b - a) / (__N - 1) as f64
+    } else {
+        0.0
+    };
+    for i in 0..__N {
+        unsafe { *at_mut(__C, __IC, i) = a + d * i as f64 }
+    }
+}
+
+#[doc(alias = "vDSP_vramp")]
+pub unsafe extern "C-unwind" fn _ramp_f32(
+    __A: &f32,
+    __B: &f32,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    let (a, b) = (*__A, *__B);
+    for i in 0..__N {
+        unsafe { *at_mut(__C, __IC, i) = a + b * i as f32 }
+    }
+}
+
+#[doc(alias = "vDSP_vrampD")]
+pub unsafe extern "C-unwind" fn _ramp_f64(
+    __A: &f64,
+    __B: &f64,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    let (a, b) = (*__A, *__B);
+    for i in 0..__N {
+        unsafe { *at_mut(__C, __IC, i) = a + b * i as f64 }
+    }
+}
+
+#[doc(alias = "vDSP_vrampmul")]
+pub unsafe extern "C-unwind" fn _rampmul_f32(
+    __I: *const f32,
+    __IS: Stride,
+    __Start: &mut f32,
+    __Step: &f32,
+    __O: *mut f32,
+    __OS: Stride,
+    __N: Len,
+) {
+    for i in 0..__N {
+        unsafe { *at_mut(__O, __OS, i) = *__Start * *at(__I, __IS, i) };
+        *__Start += *__Step;
+    }
+}
+
+#[doc(alias = "vDSP_vrampmulD")]
+pub unsafe extern "C-unwind" fn _rampmul_f64(
+    __I: *const f64,
+    __IS: Stride,
+    __Start: &mut f64,
+    __Step: &f64,
+    __O: *mut f64,
+    __OS: Stride,
+    __N: Len,
+) {
+    for i in 0..__N {
+        unsafe { *at_mut(__O, __OS, i) = *__Start * *at(__I, __IS, i) };
+        *__Start += *__Step;
+    }
+}
+
+#[doc(alias = "vDSP_vrampmul2")]
+pub unsafe extern "C-unwind" fn _rampmul2_f32(
+    __I0: *const f32,
+    __I1: *const f32,
+    __IS: Stride,
+    __Start: &mut f32,
+    __Step: &f32,
+    __O0: *mut f32,
+    __O1: *mut f32,
+    __OS: Stride,
+    __N: Len,
+) {
+    for i in 0..__N {
+        unsafe {
+            *at_mut(__O0, __OS, i) = *__Start * *at(__I0, __IS, i);
+            *at_mut(__O1, __OS, i) = *__Start * *at(__I1, __IS, i);
+        }
+        *__Start += *__Step;
+    }
+}
+
+#[doc(alias = "vDSP_vrampmul2D")]
+pub unsafe extern "C-unwind" fn _rampmul2_f64(
+    __I0: *const f64,
+    __I1: *const f64,
+    __IS: Stride,
+    __Start: &mut f64,
+    __Step: &f64,
+    __O0: *mut f64,
+    __O1: *mut f64,
+    __OS: Stride,
+    __N: Len,
+) {
+    for i in 0..__N {
+        unsafe {
+            *at_mut(__O0, __OS, i) = *__Start * *at(__I0, __IS, i);
+            *at_mut(__O1, __OS, i) = *__Start * *at(__I1, __IS, i);
+        }
+        *__Start += *__Step;
+    }
+}
+
+#[doc(alias = "vDSP_vneg")]
+pub unsafe extern "C-unwind" fn _neg_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __IC, __N, |a: f32| -a) }
+}
+
+#[doc(alias = "vDSP_vnegD")]
+pub unsafe extern "C-unwind" fn _neg_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __IC, __N, |a: f64| -a) }
+}
+
+#[doc(alias = "vDSP_vtmerg")]
+pub unsafe extern "C-unwind" fn _tmerg_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __B: *const f32,
+    __IB: Stride,
+    __C: *mut f32,
+    __IC: Stride,
+    __N: Len,
+) {
+    let d = if __N > 1 { 1.0 / (__N - 1) as f32 } else { 0.0 };
+    for i in 0..__N {
+        unsafe {
+            let (a, b) = (*at(__A, __IA, i), *at(__B, __IB, i));
+            *at_mut(__C, __IC, i) = a + (b - a) * (d * i as f32);
+        }
+    }
+}
+
+#[doc(alias = "vDSP_vtmergD")]
+pub unsafe extern "C-unwind" fn _tmerg_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __B: *const f64,
+    __IB: Stride,
+    __C: *mut f64,
+    __IC: Stride,
+    __N: Len,
+) {
+    let d = if __N > 1 { 1.0 / (__N - 1) as f64 } else { 0.0 };
+    for i in 0..__N {
+        unsafe {
+            let (a, b) = (*at(__A, __IA, i), *at(__B, __IB, i));
+            *at_mut(__C, __IC, i) = a + (b - a) * (d * i as f64);
+        }
+    }
+}
+
+/// `IC` counts scalars, so complex elements are `IC / 2` apart.
+unsafe fn ctoz<T: Copy>(
+    c: *const Complex<T>,
+    ic: Stride,
+    z: *const SplitComplex<T>,
+    iz: Stride,
+    n: Len,
+) {
+    unsafe {
+        let z = &*z;
+        for i in 0..n {
+            let v = *at(c, ic / 2, i);
+            *at_mut(z.re, iz, i) = v.re;
+            *at_mut(z.im, iz, i) = v.im;
+        }
+    }
+}
+
+unsafe fn ztoc<T: Copy>(
+    z: *const SplitComplex<T>,
+    iz: Stride,
+    c: *mut Complex<T>,
+    ic: Stride,
+    n: Len,
+) {
+    unsafe {
+        let z = &*z;
+        for i in 0..n {
+            *at_mut(c, ic / 2, i) = Complex {
+                re: *at(z.re, iz, i),
+                im: *at(z.im, iz, i),
+            };
+        }
+    }
+}
+
+#[doc(alias = "vDSP_ctoz")]
+pub unsafe extern "C-unwind" fn _ctoz_f32(
+    __C: *const Complex<f32>,
+    __IC: Stride,
+    __Z: *const SplitComplex<f32>,
+    __IZ: Stride,
+    __N: Len,
+) {
+    unsafe { ctoz(__C, __IC, __Z, __IZ, __N) }
+}
+
+#[doc(alias = "vDSP_ctozD")]
+pub unsafe extern "C-unwind" fn _ctoz_f64(
+    __C: *const Complex<f64>,
+    __IC: Stride,
+    __Z: *const SplitComplex<f64>,
+    __IZ: Stride,
+    __N: Len,
+) {
+    unsafe { ctoz(__C, __IC, __Z, __IZ, __N) }
+}
+
+#[doc(alias = "vDSP_ztoc")]
+pub unsafe extern "C-unwind" fn _ztoc_f32(
+    __Z: *const SplitComplex<f32>,
+    __IZ: Stride,
+    __C: *mut Complex<f32>,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { ztoc(__Z, __IZ, __C, __IC, __N) }
+}
+
+#[doc(alias = "vDSP_ztocD")]
+pub unsafe extern "C-unwind" fn _ztoc_f64(
+    __Z: *const SplitComplex<f64>,
+    __IZ: Stride,
+    __C: *mut Complex<f64>,
+    __IC: Stride,
+    __N: Len,
+) {
+    unsafe { ztoc(__Z, __IZ, __C, __IC, __N) }
+}
+
+trait Real:
+    Copy + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    fn from_f64(v: f64) -> Self;
+}
+
+impl Real for f32 {
+    #[inline]
+    fn from_f64(v: f64) -> Self {
+        v as f32
+    }
+}
+
+impl Real for f64 {
+    #[inline]
+    fn from_f64(v: f64) -> Self {
+        v
+    }
+}
+
+/// Twiddle factors `cos` and `sin` of `2πj / 2^log2n` for the first half of the circle.
+///
+/// Smaller transforms pick every `2^(log2n - log2m)`th entry.
+struct Setup<T> {
+    log2n: Len,
+    cos: Vec<T>,
+    sin: Vec<T>,
+}
+
+impl<T: Real> Setup<T> {
+    fn new(log2n: Len) -> Option<Self> {
+        if log2n >= usize::BITS as usize - 1 {
+            return None;
+        }
+        let n = 1usize << log2n;
+        let (cos, sin) = (0..n / 2)
+            .map(|j| {
+                let (s, c) = (std::f64::consts::TAU * j as f64 / n as f64).sin_cos();
+                (T::from_f64(c), T::from_f64(s))
+            })
+            .unzip();
+        Some(Self { log2n, cos, sin })
+    }
+
+    fn create(log2n: Len) -> Option<NonNull<FftSetup<T>>> {
+        let setup = Box::new(Self::new(log2n)?);
+        NonNull::new(Box::into_raw(setup) as *mut FftSetup<T>)
+    }
+
+    unsafe fn destroy(setup: *mut FftSetup<T>) {
+        if !setup.is_null() {
+            drop(unsafe { Box::from_raw(setup as *mut Self) });
+        }
+    }
+
+    unsafe fn get<'a>(setup: *mut FftSetup<T>, log2n: Len) -> &'a Self {
+        let setup = unsafe { &*(setup as *const Self) };
+        assert!(log2n <= setup.log2n, "FFT setup is too small");
+        setup
+    }
+
+    /// `e^(∓2πik / 2^log2n)`, sign chosen by direction.
+    #[inline]
+    fn twiddle(&self, k: usize, log2n: Len, inverse: bool) -> (T, T) {
+        let i = k << (self.log2n - log2n);
+        let s = self.sin[i];
+        (self.cos[i], if inverse { s } else { -s })
+    }
+
+    /// Unscaled in-place radix-2 complex transform.
+    fn zip(&self, re: &mut [T], im: &mut [T], inverse: bool) {
+        let n = re.len();
+        if n < 2 {
+            return;
+        }
+        let log2n = n.trailing_zeros() as Len;
+        let shift = usize::BITS - log2n as u32;
+        for i in 0..n {
+            let j = i.reverse_bits() >> shift;
+            if i < j {
+                re.swap(i, j);
+                im.swap(i, j);
+            }
+        }
+        let mut log2len = 1;
+        while log2len <= log2n {
+            let len = 1 << log2len;
+            let half = len / 2;
+            for start in (0..n).step_by(len) {
+                for k in 0..half {
+                    let (wr, wi) = self.twiddle(k, log2len, inverse);
+                    let (a, b) = (start + k, start + k + half);
+                    let tr = re[b] * wr - im[b] * wi;
+                    let ti = re[b] * wi + im[b] * wr;
+                    re[b] = re[a] - tr;
+                    im[b] = im[a] - ti;
+                    re[a] = re[a] + tr;
+                    im[a] = im[a] + ti;
+                }
+            }
+            log2len += 1;
+        }
+    }
+
+    /// Real transform of `2^log2n` values packed as even/odd pairs in `re`/`im`.
+    ///
+    /// Forward yields `2 * X[k]` with `X[N/2]` stored in `im[0]`;
+    /// inverse takes the same packing and returns the unscaled signal.
+    fn zrip(&self, re: &mut [T], im: &mut [T], log2n: Len, inverse: bool) {
+        let m = re.len();
+        if m == 0 {
+            return;
+        }
+        let two = T::from_f64(2.0);
+        if inverse {
+            let (y0, ym) = (re[0], im[0]);
+            let (mut zr, mut zi) = (vec![T::default(); m], vec![T::default(); m]);
+            zr[0] = y0 + ym;
+            zi[0] = y0 - ym;
+            for k in 1..m {
+                let (yr, yi) = (re[k], im[k]);
+                let (cr, ci) = (re[m - k], -im[m - k]);
+                let (er, ei) = (yr + cr, yi + ci);
+                let (dr, di) = (yr - cr, yi - ci);
+                let (c, s) = self.twiddle(k, log2n, true);
+                zr[k] = er - s * dr - c * di;
+                zi[k] = ei - s * di + c * dr;
+            }
+            self.zip(&mut zr, &mut zi, true);
+            re.copy_from_slice(&zr);
+            im.copy_from_slice(&zi);
+        } else {
+            self.zip(re, im, false);
+            let (zr, zi) = (re.to_vec(), im.to_vec());
+            re[0] = two * (zr[0] + zi[0]);
+            im[0] = two * (zr[0] - zi[0]);
+            for k in 1..m {
+                let (cr, ci) = (zr[m - k], -zi[m - k]);
+                let (ar, ai) = (zr[k] + cr, zi[k] + ci);
+                let (br, bi) = (zr[k] - cr, zi[k] - ci);
+                let (c, s) = self.twiddle(k, log2n, true);
+                re[k] = ar - (s * br - c * bi);
+                im[k] = ai - (s * bi + c * br);
+            }
+        }
+    }
+}
+
+unsafe fn gather<T: Copy>(c: *const SplitComplex<T>, ic: Stride, n: Len) -> (Vec<T>, Vec<T>) {
+    unsafe {
+        let c = &*c;
+        let re = (0..n).map(|i| *at(c.re, ic, i)).collect();
+        let im = (0..n).map(|i| *at(c.im, ic, i)).collect();
+        (re, im)
+    }
+}
+
+unsafe fn scatter<T: Copy>(c: *const SplitComplex<T>, ic: Stride, re: &[T], im: &[T]) {
+    unsafe {
+        let c = &*c;
+        for i in 0..re.len() {
+            *at_mut(c.re, ic, i) = re[i];
+            *at_mut(c.im, ic, i) = im[i];
+        }
+    }
+}
+
+unsafe fn fft_zip<T: Real>(
+    setup: *mut FftSetup<T>,
+    c: *const SplitComplex<T>,
+    ic: Stride,
+    log2n: Len,
+    direction: FftDirection,
+) {
+    unsafe {
+        let setup = Setup::get(setup, log2n);
+        let (mut re, mut im) = gather(c, ic, 1 << log2n);
+        setup.zip(&mut re, &mut im, direction == FftDirection::Inverse);
+        scatter(c, ic, &re, &im);
+    }
+}
+
+unsafe fn fft_zrip<T: Real>(
+    setup: *mut FftSetup<T>,
+    c: *const SplitComplex<T>,
+    ic: Stride,
+    log2n: Len,
+    direction: FftDirection,
+) {
+    if log2n == 0 {
+        return;
+    }
+    unsafe {
+        let setup = Setup::get(setup, log2n);
+        let (mut re, mut im) = gather(c, ic, 1 << (log2n - 1));
+        setup.zrip(&mut re, &mut im, log2n, direction == FftDirection::Inverse);
+        scatter(c, ic, &re, &im);
+    }
+}
+
+/// Radix 3 and 5 setups are accepted, the tables cover power of two lengths only.
+#[doc(alias = "vDSP_create_fftsetup")]
+pub unsafe extern "C-unwind" fn _create_fftsetup_f32(
+    __Log2n: Len,
+    __Radix: FftRadix,
+) -> Option<NonNull<FftSetup<f32>>> {
+    Setup::create(__Log2n)
+}
+
+#[doc(alias = "vDSP_create_fftsetupD")]
+pub unsafe extern "C-unwind" fn _create_fftsetup_f64(
+    __Log2n: Len,
+    __Radix: FftRadix,
+) -> Option<NonNull<FftSetup<f64>>> {
+    Setup::create(__Log2n)
+}
+
+#[doc(alias = "vDSP_destroy_fftsetup")]
+pub unsafe extern "C-unwind" fn _destroy_fftsetup_f32(setup: *mut FftSetup<f32>) {
+    unsafe { Setup::destroy(setup) }
+}
+
+#[doc(alias = "vDSP_destroy_fftsetupD")]
+pub unsafe extern "C-unwind" fn _destroy_fftsetup_f64(setup: *mut FftSetup<f64>) {
+    unsafe { Setup::destroy(setup) }
+}
+
+#[doc(alias = "vDSP_fft_zip")]
+pub unsafe extern "C-unwind" fn _fft_zip_f32(
+    __Setup: *mut FftSetup<f32>,
+    __C: *mut SplitComplex<f32>,
+    __IC: Stride,
+    __Log2N: Len,
+    __Direction: FftDirection,
+) {
+    unsafe { fft_zip(__Setup, __C, __IC, __Log2N, __Direction) }
+}
+
+#[doc(alias = "vDSP_fft_zipD")]
+pub unsafe extern "C-unwind" fn _fft_zip_f64(
+    __Setup: *mut FftSetup<f64>,
+    __C: *mut SplitComplex<f64>,
+    __IC: Stride,
+    __Log2N: Len,
+    __Direction: FftDirection,
+) {
+    unsafe { fft_zip(__Setup, __C, __IC, __Log2N, __Direction) }
+}
+
+#[doc(alias = "vDSP_fft_zipt")]
+pub unsafe extern "C-unwind" fn _fft_zipt_f32(
+    __Setup: *mut FftSetup<f32>,
+    __C: *const SplitComplex<f32>,
+    __IC: Stride,
+    __Buffer: *mut SplitComplex<f32>,
+    __Log2N: Len,
+    __Direction: FftDirection,
+) {
+    unsafe { fft_zip(__Setup, __C, __IC, __Log2N, __Direction) }
+}
+
+#[doc(alias = "vDSP_fft_ziptD")]
+pub unsafe extern "C-unwind" fn _fft_zipt_f64(
+    __Setup: *mut FftSetup<f64>,
+    __C: *const SplitComplex<f64>,
+    __IC: Stride,
+    __Buffer: *mut SplitComplex<f64>,
+    __Log2N: Len,
+    __Direction: FftDirection,
+) {
+    unsafe { fft_zip(__Setup, __C, __IC, __Log2N, __Direction) }
+}
+
+#[doc(alias = "vDSP_fft_zrip")]
+pub unsafe extern "C-unwind" fn _fft_zrip_f32(
+    __Setup: *mut FftSetup<f32>,
+    __C: *const SplitComplex<f32>,
+    __IC: Stride,
+    __Log2N: Len,
+    __Direction: FftDirection,
+) {
+    unsafe { fft_zrip(__Setup, __C, __IC, __Log2N, __Direction) }
+}
+
+#[doc(alias = "vDSP_fft_zripD")]
+pub unsafe extern "C-unwind" fn _fft_zrip_f64(
+    __Setup: *mut FftSetup<f64>,
+    __C: *const SplitComplex<f64>,
+    __IC: Stride,
+    __Log2N: Len,
+    __Direction: FftDirection,
+) {
+    unsafe { fft_zrip(__Setup, __C, __IC, __Log2N, __Direction) }
+}
+
+#[doc(alias = "vDSP_zaspec")]
+pub unsafe extern "C-unwind" fn _zaspec_f32(
+    __A: *const SplitComplex<f32>,
+    __C: *mut f32,
+    __N: Len,
+) {
+    unsafe {
+        let a = &*__A;
+        for i in 0..__N {
+            let (re, im) = (*a.re.add(i), *a.im.add(i));
+            *__C.add(i) += re * re + im * im;
+        }
+    }
+}
+
+#[doc(alias = "vDSP_zaspecD")]
+pub unsafe extern "C-unwind" fn _zaspec_f64(
+    __A: *const SplitComplex<f64>,
+    __C: *mut f64,
+    __N: Len,
+) {
+    unsafe {
+        let a = &*__A;
+        for i in 0..__N {
+            let (re, im) = (*a.re.add(i), *a.im.add(i));
+            *__C.add(i) += re * re + im * im;
+        }
+    }
+}
+
+#[doc(alias = "vDSP_vflt16")]
+pub unsafe extern "C-unwind" fn _i16_f32(
+    __A: *const i16,
+    __IA: Stride,
+    __C: *mut f32,
+    __CI: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __CI, __N, f32::from) }
+}
+
+#[doc(alias = "vDSP_vfltu16")]
+pub unsafe extern "C-unwind" fn _u16_f32(
+    __A: *const u16,
+    __IA: Stride,
+    __C: *mut f32,
+    __CI: Stride,
+    __N: Len,
+) {
+    unsafe { map(__A, __IA, __C, __CI, __N, f32::from) }
+}
+
+/// `F == 0` converts power, anything else amplitude.
+#[doc(alias = "vDSP_vdbcon")]
+pub unsafe extern "C-unwind" fn _dbcon_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __B: *const f32,
+    __C: *mut f32,
+    __CI: Stride,
+    __N: Len,
+    __F: u32,
+) {
+    let alpha = if __F == 0 { 10.0f32 } else { 20.0 };
+    let b = unsafe { *__B };
+    unsafe { map(__A, __IA, __C, __CI, __N, |a| alpha * (a / b).log10()) }
+}
+
+#[doc(alias = "vDSP_vdbconD")]
+pub unsafe extern "C-unwind" fn _dbcon_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __B: *const f64,
+    __C: *mut f64,
+    __CI: Stride,
+    __N: Len,
+    __F: u32,
+) {
+    let alpha = if __F == 0 { 10.0f64 } else { 20.0 };
+    let b = unsafe { *__B };
+    unsafe { map(__A, __IA, __C, __CI, __N, |a| alpha * (a / b).log10()) }
+}
+
+#[doc(alias = "vDSP_vclip")]
+pub unsafe extern "C-unwind" fn _clip_f32(
+    __A: *const f32,
+    __IA: Stride,
+    __B: *const f32,
+    __C: *const f32,
+    __D: *mut f32,
+    __ID: Stride,
+    __N: Len,
+) {
+    let (lo, hi) = unsafe { (*__B, *__C) };
+    unsafe {
+        map(__A, __IA, __D, __ID, __N, |a| {
+            if a < lo {
+                lo
+            } else if a > hi {
+                hi
+            } else {
+                a
+            }
+        })
+    }
+}
+
+#[doc(alias = "vDSP_vclipD")]
+pub unsafe extern "C-unwind" fn _clip_f64(
+    __A: *const f64,
+    __IA: Stride,
+    __B: *const f64,
+    __C: *const f64,
+    __D: *mut f64,
+    __ID: Stride,
+    __N: Len,
+) {
+    let (lo, hi) = unsafe { (*__B, *__C) };
+    unsafe {
+        map(__A, __IA, __D, __ID, __N, |a| {
+            if a < lo {
+                lo
+            } else if a > hi {
+                hi
+            } else {
+                a
+            }
+        })
+    }
+}
+
+#[doc(alias = "vDSP_rmsqv")]
+pub unsafe extern "C-unwind" fn _rmsq_f32(__A: *const f32, __IA: Stride, __C: *mut f32, __N: Len) {
+    unsafe {
+        _meansq_f32(__A, __IA, __C, __N);
+        *__C = (*__C).sqrt();
+    }
+}
+
+#[cfg(all(test, target_vendor = "apple"))]
+mod tests {
+    use crate::vdsp::{self, soft};
+
+    fn signal(n: usize, seed: u32) -> Vec<f32> {
+        let mut x = seed;
+        (0..n)
+            .map(|_| {
+                x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                (x >> 8) as f32 / (1 << 23) as f32 - 1.0
+            })
+            .collect()
+    }
+
+    fn signal_f64(n: usize, seed: u32) -> Vec<f64> {
+        signal(n, seed).into_iter().map(f64::from).collect()
+    }
+
+    fn assert_close(a: &[f32], b: &[f32], eps: f32) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() <= eps * (1.0 + y.abs()), "{x} != {y}");
+        }
+    }
+
+    fn assert_close_f64(a: &[f64], b: &[f64], eps: f64) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() <= eps * (1.0 + y.abs()), "{x} != {y}");
+        }
+    }
+
+    #[test]
+    fn vector() {
+        let n = 257;
+        let a = signal(n, 1);
+        let b = signal(n, 2);
+        let c = signal(n, 3);
+        let mut x = vec![0.0f32; n];
+        let mut y = vec![0.0f32; n];
+
+        type Bin = unsafe extern "C-unwind" fn(
+            *const f32,
+            isize,
+            *const f32,
+            isize,
+            *mut f32,
+            isize,
+            usize,
+        );
+        let bins: [(Bin, Bin); 4] = [
+            (vdsp::_add_f32, soft::_add_f32),
+            (vdsp::_sub_f32, soft::_sub_f32),
+            (vdsp::_mul_f32, soft::_mul_f32),
+            (vdsp::_div_f32, soft::_div_f32),
+        ];
+        for (accelerate, portable) in bins {
+            unsafe {
+                accelerate(a.as_ptr(), 2, b.as_ptr(), 1, x.as_mut_ptr(), 1, n / 2);
+                portable(a.as_ptr(), 2, b.as_ptr(), 1, y.as_mut_ptr(), 1, n / 2);
+            }
+            assert_close(&x, &y, 1e-6);
+        }
+
+        type Un = unsafe extern "C-unwind" fn(*const f32, isize, *mut f32, isize, usize);
+        let uns: [(Un, Un); 4] = [
+            (vdsp::_sq_f32, soft::_sq_f32),
+            (vdsp::_ssq_f32, soft::_ssq_f32),
+            (vdsp::_abs_f32, soft::_abs_f32),
+            (vdsp::_neg_f32, soft::_neg_f32),
+        ];
+        for (accelerate, portable) in uns {
+            unsafe {
+                accelerate(a.as_ptr(), 1, x.as_mut_ptr(), 1, n);
+                portable(a.as_ptr(), 1, y.as_mut_ptr(), 1, n);
+            }
+            assert_close(&x, &y, 1e-6);
+        }
+
+        type Red = unsafe extern "C-unwind" fn(*const f32, isize, *mut f32, usize);
+        let reds: [(Red, Red); 11] = [
+            (vdsp::_mean_f32, soft::_mean_f32),
+            (vdsp::_meansq_f32, soft::_meansq_f32),
+            (vdsp::_se_f32, soft::_se_f32),
+            (vdsp::_semg_f32, soft::_semg_f32),
+            (vdsp::_sesq_f32, soft::_sesq_f32),
+            (vdsp::_svs_f32, soft::_svs_f32),
+            (vdsp::_maxmg_f32, soft::_maxmg_f32),
+            (vdsp::_minmg_f32, soft::_minmg_f32),
+            (vdsp::_max_f32, soft::_max_f32),
+            (vdsp::_min_f32, soft::_min_f32),
+            (vdsp::_rmsq_f32, soft::_rmsq_f32),
+        ];
+        for (accelerate, portable) in reds {
+            let (mut r0, mut r1) = (0.0f32, 0.0f32);
+            unsafe {
+                accelerate(a.as_ptr(), 3, &mut r0, n / 3);
+                portable(a.as_ptr(), 3, &mut r1, n / 3);
+            }
+            assert_close(&[r0], &[r1], 1e-4);
+        }
+
+        unsafe {
+            vdsp::_am_f32(
+                a.as_ptr(),
+                1,
+                b.as_ptr(),
+                1,
+                c.as_ptr(),
+                1,
+                x.as_mut_ptr(),
+                1,
+                n,
+            );
+            soft::_am_f32(
+                a.as_ptr(),
+                1,
+                b.as_ptr(),
+                1,
+                c.as_ptr(),
+                1,
+                y.as_mut_ptr(),
+                1,
+                n,
+            );
+        }
+        assert_close(&x, &y, 1e-6);
+
+        unsafe {
+            vdsp::_ma_f32(
+                a.as_ptr(),
+                1,
+                b.as_ptr(),
+                1,
+                c.as_ptr(),
+                1,
+                x.as_mut_ptr(),
+                1,
+                n,
+            );
+            soft::_ma_f32(
+                a.as_ptr(),
+                1,
+                b.as_ptr(),
+                1,
+                c.as_ptr(),
+                1,
+                y.as_mut_ptr(),
+                1,
+                n,
+            );
+        }
+        assert_close(&x, &y, 1e-6);
+
+        type Gen = unsafe extern "C-unwind" fn(&f32, &f32, *mut f32, isize, usize);
+        let gens: [(Gen, Gen); 2] = [
+            (vdsp::_gen_f32, soft::_gen_f32),
+            (vdsp::_ramp_f32, soft::_ramp_f32),
+        ];
+        for (accelerate, portable) in gens {
+            unsafe {
+                accelerate(&-1.0, &3.0, x.as_mut_ptr(), 1, n);
+                portable(&-1.0, &3.0, y.as_mut_ptr(), 1, n);
+            }
+            assert_close(&x, &y, 1e-5);
+        }
+
+        type Scalar = unsafe extern "C-unwind" fn(*const f32, isize, &f32, *mut f32, isize, usize);
+        let scalars: [(Scalar, Scalar); 2] = [
+            (vdsp::_smul_f32, soft::_smul_f32),
+            (vdsp::_sadd_f32, soft::_sadd_f32),
+        ];
+        for (accelerate, portable) in scalars {
+            unsafe {
+                accelerate(a.as_ptr(), 1, &0.75, x.as_mut_ptr(), 1, n);
+                portable(a.as_ptr(), 1, &0.75, y.as_mut_ptr(), 1, n);
+            }
+            assert_close(&x, &y, 1e-6);
+        }
+
+        type Pair =
+            unsafe extern "C-unwind" fn(*const f32, isize, *const f32, isize, *mut f32, usize);
+        let pairs: [(Pair, Pair); 2] = [
+            (vdsp::_distance_sq_f32, soft::_distance_sq_f32),
+            (vdsp::_dotpr_f32, soft::_dotpr_f32),
+        ];
+        for (accelerate, portable) in pairs {
+            let (mut r0, mut r1) = (0.0f32, 0.0f32);
+            unsafe {
+                accelerate(a.as_ptr(), 1, b.as_ptr(), 2, &mut r0, n / 2);
+                portable(a.as_ptr(), 1, b.as_ptr(), 2, &mut r1, n / 2);
+            }
+            assert_close(&[r0], &[r1], 1e-4);
+        }
+
+        let positive: Vec<f32> = a.iter().map(|v| v.abs() + 0.1).collect();
+        for flag in [0, 1] {
+            unsafe {
+                vdsp::_dbcon_f32(positive.as_ptr(), 1, &0.5, x.as_mut_ptr(), 1, n, flag);
+                soft::_dbcon_f32(positive.as_ptr(), 1, &0.5, y.as_mut_ptr(), 1, n, flag);
+            }
+            assert_close(&x, &y, 1e-5);
+        }
+
+        unsafe {
+            vdsp::_fill_f32(&0.5, x.as_mut_ptr(), 2, n / 2);
+            soft::_fill_f32(&0.5, y.as_mut_ptr(), 2, n / 2);
+            vdsp::_clr_f32(x.as_mut_ptr(), 3, n / 3);
+            soft::_clr_f32(y.as_mut_ptr(), 3, n / 3);
+        }
+        assert_close(&x, &y, 0.0);
+
+        unsafe {
+            vdsp::_tmerg_f32(a.as_ptr(), 1, b.as_ptr(), 1, x.as_mut_ptr(), 1, n);
+            soft::_tmerg_f32(a.as_ptr(), 1, b.as_ptr(), 1, y.as_mut_ptr(), 1, n);
+        }
+        assert_close(&x, &y, 1e-5);
+
+        let (lo, hi) = (-0.25f32, 0.5f32);
+        unsafe {
+            vdsp::_clip_f32(a.as_ptr(), 1, &lo, &hi, x.as_mut_ptr(), 1, n);
+            soft::_clip_f32(a.as_ptr(), 1, &lo, &hi, y.as_mut_ptr(), 1, n);
+        }
+        assert_close(&x, &y, 0.0);
+
+        let (mut s0, mut s1) = (0.5f32, 0.5f32);
+        unsafe {
+            vdsp::_rampmul_f32(a.as_ptr(), 1, &mut s0, &0.01, x.as_mut_ptr(), 1, n);
+            soft::_rampmul_f32(a.as_ptr(), 1, &mut s1, &0.01, y.as_mut_ptr(), 1, n);
+        }
+        assert_close(&x, &y, 1e-4);
+        assert_close(&[s0], &[s1], 1e-4);
+
+        let (mut x1, mut y1) = (vec![0.0f32; n], vec![0.0f32; n]);
+        let (mut s0, mut s1) = (0.5f32, 0.5f32);
+        unsafe {
+            vdsp::_rampmul2_f32(
+                a.as_ptr(),
+                b.as_ptr(),
+                1,
+                &mut s0,
+                &0.01,
+                x.as_mut_ptr(),
+                x1.as_mut_ptr(),
+                1,
+                n,
+            );
+            soft::_rampmul2_f32(
+                a.as_ptr(),
+                b.as_ptr(),
+                1,
+                &mut s1,
+                &0.01,
+                y.as_mut_ptr(),
+                y1.as_mut_ptr(),
+                1,
+                n,
+            );
+        }
+        assert_close(&x, &y, 1e-4);
+        assert_close(&x1, &y1, 1e-4);
+        assert_close(&[s0], &[s1], 1e-4);
+    }
+
+    #[test]
+    fn vector_f64() {
+        let n = 257;
+        let a = signal_f64(n, 1);
+        let b = signal_f64(n, 2);
+        let c = signal_f64(n, 3);
+        let mut x = vec![0.0f64; n];
+        let mut y = vec![0.0f64; n];
+
+        type Bin = unsafe extern "C-unwind" fn(
+            *const f64,
+            isize,
+            *const f64,
+            isize,
+            *mut f64,
+            isize,
+            usize,
+        );
+        let bins: [(Bin, Bin); 5] = [
+            (vdsp::_add_f64, soft::_add_f64),
+            (vdsp::_sub_f64, soft::_sub_f64),
+            (vdsp::_mul_f64, soft::_mul_f64),
+            (vdsp::_div_f64, soft::_div_f64),
+            (vdsp::_tmerg_f64, soft::_tmerg_f64),
+        ];
+        for (accelerate, portable) in bins {
+            unsafe {
+                accelerate(a.as_ptr(), 2, b.as_ptr(), 1, x.as_mut_ptr(), 1, n / 2);
+                portable(a.as_ptr(), 2, b.as_ptr(), 1, y.as_mut_ptr(), 1, n / 2);
+            }
+            assert_close_f64(&x, &y, 1e-12);
+        }
+
+        type Un = unsafe extern "C-unwind" fn(*const f64, isize, *mut f64, isize, usize);
+        let uns: [(Un, Un); 4] = [
+            (vdsp::_sq_f64, soft::_sq_f64),
+            (vdsp::_ssq_f64, soft::_ssq_f64),
+            (vdsp::_abs_f64, soft::_abs_f64),
+            (vdsp::_neg_f64, soft::_neg_f64),
+        ];
+        for (accelerate, portable) in uns {
+            unsafe {
+                accelerate(a.as_ptr(), 1, x.as_mut_ptr(), 1, n);
+                portable(a.as_ptr(), 1, y.as_mut_ptr(), 1, n);
+            }
+            assert_close_f64(&x, &y, 1e-12);
+        }
+
+        type Red = unsafe extern "C-unwind" fn(*const f64, isize, *mut f64, usize);
+        let reds: [(Red, Red); 10] = [
+            (vdsp::_mean_f64, soft::_mean_f64),
+            (vdsp::_meansq_f64, soft::_meansq_f64),
+            (vdsp::_se_f64, soft::_se_f64),
+            (vdsp::_semg_f64, soft::_semg_f64),
+            (vdsp::_sesq_f64, soft::_sesq_f64),
+            (vdsp::_svs_f64, soft::_svs_f64),
+            (vdsp::_maxmg_f64, soft::_maxmg_f64),
+            (vdsp::_minmg_f64, soft::_minmg_f64),
+            (vdsp::_max_f64, soft::_max_f64),
+            (vdsp::_min_f64, soft::_min_f64),
+        ];
+        for (accelerate, portable) in reds {
+            let (mut r0, mut r1) = (0.0f64, 0.0f64);
+            unsafe {
+                accelerate(a.as_ptr(), 3, &mut r0, n / 3);
+                portable(a.as_ptr(), 3, &mut r1, n / 3);
+            }
+            assert_close_f64(&[r0], &[r1], 1e-10);
+        }
+
+        type Pair =
+            unsafe extern "C-unwind" fn(*const f64, isize, *const f64, isize, *mut f64, usize);
+        let pairs: [(Pair, Pair); 2] = [
+            (vdsp::_distance_sq_f64, soft::_distance_sq_f64),
+            (vdsp::_dotpr_f64, soft::_dotpr_f64),
+        ];
+        for (accelerate, portable) in pairs {
+            let (mut r0, mut r1) = (0.0f64, 0.0f64);
+            unsafe {
+                accelerate(a.as_ptr(), 1, b.as_ptr(), 2, &mut r0, n / 2);
+                portable(a.as_ptr(), 1, b.as_ptr(), 2, &mut r1, n / 2);
+            }
+            assert_close_f64(&[r0], &[r1], 1e-10);
+        }
+
+        type Tri = unsafe extern "C-unwind" fn(
+            *const f64,
+            isize,
+            *const f64,
+            isize,
+            *const f64,
+            isize,
+            *mut f64,
+            isize,
+            usize,
+        );
+        let tris: [(Tri, Tri); 2] = [
+            (vdsp::_am_f64, soft::_am_f64),
+            (vdsp::_ma_f64, soft::_ma_f64),
+        ];
+        for (accelerate, portable) in tris {
+            unsafe {
+                let (a, b, c) = (a.as_ptr(), b.as_ptr(), c.as_ptr());
+                accelerate(a, 1, b, 1, c, 1, x.as_mut_ptr(), 1, n);
+                portable(a, 1, b, 1, c, 1, y.as_mut_ptr(), 1, n);
+            }
+            assert_close_f64(&x, &y, 1e-12);
+        }
+
+        type Gen = unsafe extern "C-unwind" fn(&f64, &f64, *mut f64, isize, usize);
+        let gens: [(Gen, Gen); 2] = [
+            (vdsp::_gen_f64, soft::_gen_f64),
+            (vdsp::_ramp_f64, soft::_ramp_f64),
+        ];
+        for (accelerate, portable) in gens {
+            unsafe {
+                accelerate(&-1.0, &3.0, x.as_mut_ptr(), 1, n);
+                portable(&-1.0, &3.0, y.as_mut_ptr(), 1, n);
+            }
+            assert_close_f64(&x, &y, 1e-12);
+        }
+
+        type Scalar = unsafe extern "C-unwind" fn(*const f64, isize, &f64, *mut f64, isize, usize);
+        let scalars: [(Scalar, Scalar); 2] = [
+            (vdsp::_smul_f64, soft::_smul_f64),
+            (vdsp::_sadd_f64, soft::_sadd_f64),
+        ];
+        for (accelerate, portable) in scalars {
+            unsafe {
+                accelerate(a.as_ptr(), 1, &0.75, x.as_mut_ptr(), 1, n);
+                portable(a.as_ptr(), 1, &0.75, y.as_mut_ptr(), 1, n);
+            }
+            assert_close_f64(&x, &y, 1e-12);
+        }
+
+        let positive: Vec<f64> = a.iter().map(|v| v.abs() + 0.1).collect();
+        for flag in [0, 1] {
+            unsafe {
+                vdsp::_dbcon_f64(positive.as_ptr(), 1, &0.5, x.as_mut_ptr(), 1, n, flag);
+                soft::_dbcon_f64(positive.as_ptr(), 1, &0.5, y.as_mut_ptr(), 1, n, flag);
+            }
+            assert_close_f64(&x, &y, 1e-10);
+        }
+
+        let (lo, hi) = (-0.25f64, 0.5f64);
+        unsafe {
+            vdsp::_clip_f64(a.as_ptr(), 1, &lo, &hi, x.as_mut_ptr(), 1, n);
+            soft::_clip_f64(a.as_ptr(), 1, &lo, &hi, y.as_mut_ptr(), 1, n);
+            vdsp::_fill_f64(&0.5, x.as_mut_ptr(), 2, n / 2);
+            soft::_fill_f64(&0.5, y.as_mut_ptr(), 2, n / 2);
+            vdsp::_clr_f64(x.as_mut_ptr(), 3, n / 3);
+            soft::_clr_f64(y.as_mut_ptr(), 3, n / 3);
+        }
+        assert_close_f64(&x, &y, 0.0);
+
+        let (mut x1, mut y1) = (vec![0.0f64; n], vec![0.0f64; n]);
+        let (mut s0, mut s1) = (0.5f64, 0.5f64);
+        unsafe {
+            vdsp::_rampmul_f64(a.as_ptr(), 1, &mut s0, &0.01, x.as_mut_ptr(), 1, n);
+            soft::_rampmul_f64(a.as_ptr(), 1, &mut s1, &0.01, y.as_mut_ptr(), 1, n);
+        }
+        assert_close_f64(&x, &y, 1e-10);
+        assert_close_f64(&[s0], &[s1], 1e-10);
+        unsafe {
+            vdsp::_rampmul2_f64(
+                a.as_ptr(),
+                b.as_ptr(),
+                1,
+                &mut s0,
+                &0.01,
+                x.as_mut_ptr(),
+                x1.as_mut_ptr(),
+                1,
+                n,
+            );
+            soft::_rampmul2_f64(
+                a.as_ptr(),
+                b.as_ptr(),
+                1,
+                &mut s1,
+                &0.01,
+                y.as_mut_ptr(),
+                y1.as_mut_ptr(),
+                1,
+                n,
+            );
+        }
+        assert_close_f64(&x, &y, 1e-10);
+        assert_close_f64(&x1, &y1, 1e-10);
+        assert_close_f64(&[s0], &[s1], 1e-10);
+    }
+
+    /// Zero divisors and `i32::MIN / -1` aren't compared, Accelerate leaves them unspecified.
+    #[test]
+    fn vector_i32() {
+        let n = 257;
+        let a: Vec<i32> = (0..n as i32)
+            .map(|i| i.wrapping_mul(7_919) - 1_000)
+            .collect();
+        let b: Vec<i32> = (0..n as i32).map(|i| (i % 23) - 11).collect();
+        let divisors: Vec<i32> = b.iter().map(|&v| if v == 0 { 3 } else { v }).collect();
+        let mut x = vec![0i32; n];
+        let mut y = vec![0i32; n];
+
+        type Bin = unsafe extern "C-unwind" fn(
+            *const i32,
+            isize,
+            *const i32,
+            isize,
+            *mut i32,
+            isize,
+            usize,
+        );
+        let bins: [(Bin, Bin, &[i32]); 4] = [
+            (vdsp::_add_i32, soft::_add_i32, &b),
+            (vdsp::_sub_i32, soft::_sub_i32, &b),
+            (vdsp::_div_i32, soft::_div_i32, &divisors),
+            (vdsp::_eqv_i32, soft::_eqv_i32, &b),
+        ];
+        for (accelerate, portable, b) in bins {
+            unsafe {
+                accelerate(b.as_ptr(), 1, a.as_ptr(), 2, x.as_mut_ptr(), 1, n / 2);
+                portable(b.as_ptr(), 1, a.as_ptr(), 2, y.as_mut_ptr(), 1, n / 2);
+            }
+            assert_eq!(x, y);
+        }
+
+        unsafe {
+            vdsp::_abs_i32(a.as_ptr(), 1, x.as_mut_ptr(), 1, n);
+            soft::_abs_i32(a.as_ptr(), 1, y.as_mut_ptr(), 1, n);
+        }
+        assert_eq!(x, y);
+
+        unsafe {
+            vdsp::_sadd_i32(a.as_ptr(), 1, &-5, x.as_mut_ptr(), 1, n);
+            soft::_sadd_i32(a.as_ptr(), 1, &-5, y.as_mut_ptr(), 1, n);
+            vdsp::_fill_i32(&9, x.as_mut_ptr(), 3, n / 3);
+            soft::_fill_i32(&9, y.as_mut_ptr(), 3, n / 3);
+        }
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn convert() {
+        let n = 257;
+        let a = signal(n, 1);
+        let d = signal_f64(n, 2);
+        let (mut x, mut y) = (vec![0.0f32; n], vec![0.0f32; n]);
+        let (mut xd, mut yd) = (vec![0.0f64; n], vec![0.0f64; n]);
+        let ints: Vec<i16> = (0..n).map(|i| (i as i16).wrapping_mul(331)).collect();
+        let uints: Vec<u16> = ints.iter().map(|&v| v as u16).collect();
+        unsafe {
+            vdsp::_f32_f64(a.as_ptr(), 1, xd.as_mut_ptr(), 1, n);
+            soft::_f32_f64(a.as_ptr(), 1, yd.as_mut_ptr(), 1, n);
+            vdsp::_f64_f32(d.as_ptr(), 1, x.as_mut_ptr(), 1, n);
+            soft::_f64_f32(d.as_ptr(), 1, y.as_mut_ptr(), 1, n);
+        }
+        assert_close_f64(&xd, &yd, 0.0);
+        assert_close(&x, &y, 0.0);
+
+        unsafe {
+            vdsp::_i16_f32(ints.as_ptr(), 1, x.as_mut_ptr(), 1, n);
+            soft::_i16_f32(ints.as_ptr(), 1, y.as_mut_ptr(), 1, n);
+        }
+        assert_close(&x, &y, 0.0);
+        unsafe {
+            vdsp::_u16_f32(uints.as_ptr(), 1, x.as_mut_ptr(), 1, n);
+            soft::_u16_f32(uints.as_ptr(), 1, y.as_mut_ptr(), 1, n);
+        }
+        assert_close(&x, &y, 0.0);
+    }
+
+    #[test]
+    fn complex() {
+        let n = 64;
+        let c: Vec<vdsp::Complex<f32>> = signal(n * 2, 1)
+            .chunks(2)
+            .map(|p| vdsp::Complex { re: p[0], im: p[1] })
+            .collect();
+        let (mut re0, mut im0) = (vec![0.0f32; n], vec![0.0f32; n]);
+        let (mut re1, mut im1) = (vec![0.0f32; n], vec![0.0f32; n]);
+        let mut c0 = vec![vdsp::Complex::default(); n];
+        let mut c1 = vec![vdsp::Complex::default(); n];
+        let (mut s0, mut s1) = (vec![1.0f32; n], vec![1.0f32; n]);
+        unsafe {
+            let z0 = vdsp::SplitComplex::new_mut(&mut re0, &mut im0);
+            let z1 = vdsp::SplitComplex::new_mut(&mut re1, &mut im1);
+            vdsp::_ctoz_f32(c.as_ptr(), 2, &z0, 1, n);
+            soft::_ctoz_f32(c.as_ptr(), 2, &z1, 1, n);
+            vdsp::_ztoc_f32(&z0, 1, c0.as_mut_ptr(), 2, n);
+            soft::_ztoc_f32(&z1, 1, c1.as_mut_ptr(), 2, n);
+            vdsp::_zaspec_f32(&z0, s0.as_mut_ptr(), n);
+            soft::_zaspec_f32(&z1, s1.as_mut_ptr(), n);
+        }
+        assert_close(&re0, &re1, 0.0);
+        assert_close(&im0, &im1, 0.0);
+        let flat =
+            |c: &[vdsp::Complex<f32>]| c.iter().flat_map(|c| [c.re, c.im]).collect::<Vec<_>>();
+        assert_close(&flat(&c0), &flat(&c1), 0.0);
+        assert_close(&s0, &s1, 1e-6);
+
+        let c: Vec<vdsp::Complex<f64>> = signal_f64(n * 2, 2)
+            .chunks(2)
+            .map(|p| vdsp::Complex { re: p[0], im: p[1] })
+            .collect();
+        let (mut re0, mut im0) = (vec![0.0f64; n], vec![0.0f64; n]);
+        let (mut re1, mut im1) = (vec![0.0f64; n], vec![0.0f64; n]);
+        let mut c0 = vec![vdsp::Complex::default(); n];
+        let mut c1 = vec![vdsp::Complex::default(); n];
+        let (mut s0, mut s1) = (vec![1.0f64; n], vec![1.0f64; n]);
+        unsafe {
+            let z0 = vdsp::SplitComplex::new_mut(&mut re0, &mut im0);
+            let z1 = vdsp::SplitComplex::new_mut(&mut re1, &mut im1);
+            vdsp::_ctoz_f64(c.as_ptr(), 2, &z0, 1, n);
+            soft::_ctoz_f64(c.as_ptr(), 2, &z1, 1, n);
+            vdsp::_ztoc_f64(&z0, 1, c0.as_mut_ptr(), 2, n);
+            soft::_ztoc_f64(&z1, 1, c1.as_mut_ptr(), 2, n);
+            vdsp::_zaspec_f64(&z0, s0.as_mut_ptr(), n);
+            soft::_zaspec_f64(&z1, s1.as_mut_ptr(), n);
+        }
+        assert_close_f64(&re0, &re1, 0.0);
+        assert_close_f64(&im0, &im1, 0.0);
+        let flat =
+            |c: &[vdsp::Complex<f64>]| c.iter().flat_map(|c| [c.re, c.im]).collect::<Vec<_>>();
+        assert_close_f64(&flat(&c0), &flat(&c1), 0.0);
+        assert_close_f64(&s0, &s1, 1e-12);
+    }
+
+    #[test]
+    fn fft() {
+        for log2n in 1..=10 {
+            let n = 1usize << log2n;
+            let input = signal(n, log2n as u32);
+
+            let setup = unsafe { vdsp::_create_fftsetup_f32(log2n, vdsp::FftRadix::_2) }.unwrap();
+            let soft_setup =
+                unsafe { soft::_create_fftsetup_f32(log2n, vdsp::FftRadix::_2) }.unwrap();
+
+            for direction in [vdsp::FftDirection::Forward, vdsp::FftDirection::Inverse] {
+                let (mut re0, mut im0) = (input.clone(), signal(n, 7));
+                let (mut re1, mut im1) = (re0.clone(), im0.clone());
+                unsafe {
+                    let mut c = vdsp::SplitComplex::new_mut(&mut re0, &mut im0);
+                    vdsp::_fft_zip_f32(setup.as_ptr(), &mut c, 1, log2n, direction);
+                    let mut c = vdsp::SplitComplex::new_mut(&mut re1, &mut im1);
+                    soft::_fft_zip_f32(soft_setup.as_ptr(), &mut c, 1, log2n, direction);
+                }
+                assert_close(&re1, &re0, 1e-4 * n as f32);
+                assert_close(&im1, &im0, 1e-4 * n as f32);
+
+                let (mut re0, mut im0) = (input.clone(), signal(n, 9));
+                let (mut re1, mut im1) = (re0.clone(), im0.clone());
+                let (mut buf_re, mut buf_im) = (vec![0.0f32; n], vec![0.0f32; n]);
+                unsafe {
+                    let mut buf = vdsp::SplitComplex::new_mut(&mut buf_re, &mut buf_im);
+                    let c = vdsp::SplitComplex::new_mut(&mut re0, &mut im0);
+                    vdsp::_fft_zipt_f32(setup.as_ptr(), &c, 1, &mut buf, log2n, direction);
+                    let c = vdsp::SplitComplex::new_mut(&mut re1, &mut im1);
+                    soft::_fft_zipt_f32(soft_setup.as_ptr(), &c, 1, &mut buf, log2n, direction);
+                }
+                assert_close(&re1, &re0, 1e-4 * n as f32);
+                assert_close(&im1, &im0, 1e-4 * n as f32);
+
+                let mut re0 = vec![0.0f32; n / 2];
+                let mut im0 = vec![0.0f32; n / 2];
+                vdsp::actoz_f32(&input, &mut re0, &mut im0);
+                let (mut re1, mut im1) = (re0.clone(), im0.clone());
+                unsafe {
+                    let c = vdsp::SplitComplex::new_mut(&mut re0, &mut im0);
+                    vdsp::_fft_zrip_f32(setup.as_ptr(), &c, 1, log2n, direction);
+                    let c = vdsp::SplitComplex::new_mut(&mut re1, &mut im1);
+                    soft::_fft_zrip_f32(soft_setup.as_ptr(), &c, 1, log2n, direction);
+                }
+                assert_close(&re1, &re0, 1e-4 * n as f32);
+                assert_close(&im1, &im0, 1e-4 * n as f32);
+            }
+
+            unsafe {
+                vdsp::_destroy_fftsetup_f32(setup.as_ptr());
+                soft::_destroy_fftsetup_f32(soft_setup.as_ptr());
+            }
+        }
+    }
+
+    #[test]
+    fn fft_f64() {
+        for log2n in 1..=10 {
+            let n = 1usize << log2n;
+            let input = signal_f64(n, log2n as u32);
+
+            let setup = unsafe { vdsp::_create_fftsetup_f64(log2n, vdsp::FftRadix::_2) }.unwrap();
+            let soft_setup =
+                unsafe { soft::_create_fftsetup_f64(log2n, vdsp::FftRadix::_2) }.unwrap();
+
+            for direction in [vdsp::FftDirection::Forward, vdsp::FftDirection::Inverse] {
+                let (mut re0, mut im0) = (input.clone(), signal_f64(n, 7));
+                let (mut re1, mut im1) = (re0.clone(), im0.clone());
+                unsafe {
+                    let mut c = vdsp::SplitComplex::new_mut(&mut re0, &mut im0);
+                    vdsp::_fft_zip_f64(setup.as_ptr(), &mut c, 1, log2n, direction);
+                    let mut c = vdsp::SplitComplex::new_mut(&mut re1, &mut im1);
+                    soft::_fft_zip_f64(soft_setup.as_ptr(), &mut c, 1, log2n, direction);
+                }
+                assert_close_f64(&re1, &re0, 1e-10 * n as f64);
+                assert_close_f64(&im1, &im0, 1e-10 * n as f64);
+
+                let (mut re0, mut im0) = (input.clone(), signal_f64(n, 9));
+                let (mut re1, mut im1) = (re0.clone(), im0.clone());
+                let (mut buf_re, mut buf_im) = (vec![0.0f64; n], vec![0.0f64; n]);
+                unsafe {
+                    let mut buf = vdsp::SplitComplex::new_mut(&mut buf_re, &mut buf_im);
+                    let c = vdsp::SplitComplex::new_mut(&mut re0, &mut im0);
+                    vdsp::_fft_zipt_f64(setup.as_ptr(), &c, 1, &mut buf, log2n, direction);
+                    let c = vdsp::SplitComplex::new_mut(&mut re1, &mut im1);
+                    soft::_fft_zipt_f64(soft_setup.as_ptr(), &c, 1, &mut buf, log2n, direction);
+                }
+                assert_close_f64(&re1, &re0, 1e-10 * n as f64);
+                assert_close_f64(&im1, &im0, 1e-10 * n as f64);
+
+                let mut re0 = vec![0.0f64; n / 2];
+                let mut im0 = vec![0.0f64; n / 2];
+                let c: Vec<vdsp::Complex<f64>> = input
+                    .chunks(2)
+                    .map(|p| vdsp::Complex { re: p[0], im: p[1] })
+                    .collect();
+                vdsp::ctoz_f64(&c, &mut re0, &mut im0);
+                let (mut re1, mut im1) = (re0.clone(), im0.clone());
+                unsafe {
+                    let c = vdsp::SplitComplex::new_mut(&mut re0, &mut im0);
+                    vdsp::_fft_zrip_f64(setup.as_ptr(), &c, 1, log2n, direction);
+                    let c = vdsp::SplitComplex::new_mut(&mut re1, &mut im1);
+                    soft::_fft_zrip_f64(soft_setup.as_ptr(), &c, 1, log2n, direction);
+                }
+                assert_close_f64(&re1, &re0, 1e-10 * n as f64);
+                assert_close_f64(&im1, &im0, 1e-10 * n as f64);
+            }
+
+            unsafe {
+                vdsp::_destroy_fftsetup_f64(setup.as_ptr());
+                soft::_destroy_fftsetup_f64(soft_setup.as_ptr());
+            }
+        }
+    }
+}
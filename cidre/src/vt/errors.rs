@@ -1,40 +1,76 @@
 use crate::{define_opts, os::Error};
 
+#[doc(alias = "kVTPropertyNotSupportedErr")]
 pub const PROPERTY_NOT_SUPPORTED: Error = Error::new_unchecked(-12900);
+#[doc(alias = "kVTPropertyReadOnlyErr")]
 pub const PROPERTY_READ_ONLY: Error = Error::new_unchecked(-12901);
+#[doc(alias = "kVTParameterErr")]
 pub const PARAMETER: Error = Error::new_unchecked(-12902);
+#[doc(alias = "kVTInvalidSessionErr")]
 pub const INVALID_SESSION: Error = Error::new_unchecked(-12903);
+#[doc(alias = "kVTAllocationFailedErr")]
 pub const ALLOC_FAILED: Error = Error::new_unchecked(-12904);
+#[doc(alias = "kVTPixelTransferNotSupportedErr")]
 pub const PIXEL_TRANSFER_NOT_SUPPORTED: Error = Error::new_unchecked(-12905); // c.f. -8961 ?
+#[doc(alias = "kVTCouldNotFindVideoDecoderErr")]
 pub const COULD_NOT_FIND_VIDEO_DECODER: Error = Error::new_unchecked(-12906);
+#[doc(alias = "kVTCouldNotCreateInstanceErr")]
 pub const COULD_NOT_CREATE_INSTANCE: Error = Error::new_unchecked(-12907);
+#[doc(alias = "kVTCouldNotFindVideoEncoderErr")]
 pub const COULD_NOT_FIND_VIDEO_ENCODER: Error = Error::new_unchecked(-12908);
+#[doc(alias = "kVTVideoDecoderBadDataErr")]
 pub const VIDEO_DECODER_BAD_DATA: Error = Error::new_unchecked(-12909); // c.f. -8969
+#[doc(alias = "kVTVideoDecoderUnsupportedDataFormatErr")]
 pub const VIDEO_DECODER_UNSUPPORTED_DATA_FORMAT: Error = Error::new_unchecked(-12910); // c.f. -8970
+#[doc(alias = "kVTVideoDecoderMalfunctionErr")]
 pub const VIDEO_DECODER_MALFUNCTION: Error = Error::new_unchecked(-12911); // c.f. -8960
+#[doc(alias = "kVTVideoEncoderMalfunctionErr")]
 pub const VIDEO_ENCODER_MALFUNCTION: Error = Error::new_unchecked(-12912);
+#[doc(alias = "kVTVideoDecoderNotAvailableNowErr")]
 pub const VIDEO_DECODER_NOT_AVAILABLE_NOW: Error = Error::new_unchecked(-12913);
+#[doc(alias = "kVTImageRotationNotSupportedErr")]
 pub const IMAGE_ROTATION_NOT_SUPPORTED: Error = Error::new_unchecked(-12914);
+#[doc(alias = "kVTVideoEncoderNotAvailableNowErr")]
 pub const VIDEO_ENCODER_NOT_AVAILABLE_NOW: Error = Error::new_unchecked(-12915);
+#[doc(alias = "kVTFormatDescriptionChangeNotSupportedErr")]
 pub const FORMAT_DESCRIPTION_CHANGE_NOT_SUPPORTED: Error = Error::new_unchecked(-12916);
+#[doc(alias = "kVTInsufficientSourceColorDataErr")]
 pub const INSUFFICIENT_SOURCE_COLOR_DATA: Error = Error::new_unchecked(-12917);
+#[doc(alias = "kVTCouldNotCreateColorCorrectionDataErr")]
 pub const COULD_NOT_CREATE_COLOR_CORRECTION_DATA: Error = Error::new_unchecked(-12918);
+#[doc(alias = "kVTColorSyncTransformConvertFailedErr")]
 pub const COLOR_SYNC_TRANSFORM_CONVERT_FAILED: Error = Error::new_unchecked(-12919);
+#[doc(alias = "kVTVideoDecoderAuthorizationErr")]
 pub const VIDEO_DECODER_AUTHORIZATION: Error = Error::new_unchecked(-12210);
+#[doc(alias = "kVTVideoEncoderAuthorizationErr")]
 pub const VIDEO_ENCODER_AUTHORIZATION: Error = Error::new_unchecked(-12211);
+#[doc(alias = "kVTColorCorrectionPixelTransferFailedErr")]
 pub const COLOR_CORRECTION_PIXEL_TRANSFER_FAILED: Error = Error::new_unchecked(-12212);
+#[doc(alias = "kVTMultiPassStorageIdentifierMismatchErr")]
 pub const MULTI_PASS_STORAGE_IDENTIFIER_MISMATCH: Error = Error::new_unchecked(-12213);
+#[doc(alias = "kVTMultiPassStorageInvalidErr")]
 pub const MULTI_PASS_STORAGE_INVALID: Error = Error::new_unchecked(-12214);
+#[doc(alias = "kVTFrameSiloInvalidTimeStampErr")]
 pub const FRAME_SILO_INVALID_TIME_STAMP: Error = Error::new_unchecked(-12215);
+#[doc(alias = "kVTFrameSiloInvalidTimeRangeErr")]
 pub const FRAME_SILO_INVALID_TIME_RANGE: Error = Error::new_unchecked(-12216);
+#[doc(alias = "kVTCouldNotFindTemporalFilterErr")]
 pub const COULD_NOT_FIND_TEMPORAL_FILTER: Error = Error::new_unchecked(-12217);
+#[doc(alias = "kVTPixelTransferNotPermittedErr")]
 pub const PIXEL_TRANSFER_NOT_PERMITTED: Error = Error::new_unchecked(-12218);
+#[doc(alias = "kVTColorCorrectionImageRotationFailedErr")]
 pub const COLOR_CORRECTION_IMAGE_ROTATION_FAILED: Error = Error::new_unchecked(-12219);
+#[doc(alias = "kVTVideoDecoderRemovedErr")]
 pub const VIDEO_DECODER_REMOVED: Error = Error::new_unchecked(-17690);
+#[doc(alias = "kVTSessionMalfunctionErr")]
 pub const SESSION_MALFUNCTION: Error = Error::new_unchecked(-17691);
+#[doc(alias = "kVTVideoDecoderNeedsRosettaErr")]
 pub const VIDEO_DECODER_NEEDS_ROSETTA: Error = Error::new_unchecked(-17692);
+#[doc(alias = "kVTVideoEncoderNeedsRosettaErr")]
 pub const VIDEO_ENCODER_NEEDS_ROSETTA: Error = Error::new_unchecked(-17693);
+#[doc(alias = "kVTVideoDecoderReferenceMissingErr")]
 pub const VIDEO_DECODER_REFERENCE_MISSING: Error = Error::new_unchecked(-17694);
+#[doc(alias = "kVTVideoDecoderCallbackMessagingErr")]
 pub const VIDEO_DECODER_CALLBACK_MESSAGING: Error = Error::new_unchecked(-17695);
 
 define_opts!(